@@ -34,6 +34,10 @@ pub fn bench() {
     glwe_secret_key_creation::bench::<CoreEngine, GlweSecretKey64>(&mut criterion);
     lwe_bootstrap_key_conversion::bench::<CoreEngine, LweBootstrapKey32, FourierLweBootstrapKey32>(&mut criterion);
     lwe_bootstrap_key_conversion::bench::<CoreEngine, LweBootstrapKey64, FourierLweBootstrapKey64>(&mut criterion);
+    lwe_bootstrap_key_conversion::bench::<CoreEngine, FourierLweBootstrapKey32, LweBootstrapKey32>(&mut criterion);
+    lwe_bootstrap_key_conversion::bench::<CoreEngine, FourierLweBootstrapKey64, LweBootstrapKey64>(&mut criterion);
+    lwe_bootstrap_key_conversion::bench::<CoreEngine, LweBootstrapKey32, LweBootstrapKey64>(&mut criterion);
+    lwe_bootstrap_key_conversion::bench::<CoreEngine, LweBootstrapKey64, LweBootstrapKey32>(&mut criterion);
     lwe_bootstrap_key_creation::bench::<CoreEngine, LweSecretKey32, GlweSecretKey32, LweBootstrapKey32>(&mut criterion);
     lwe_bootstrap_key_creation::bench::<CoreEngine, LweSecretKey64, GlweSecretKey64, LweBootstrapKey64>(&mut criterion);
     lwe_ciphertext_fusing_addition::bench::<CoreEngine, LweCiphertext32, LweCiphertext32>(&mut criterion);
@@ -54,6 +58,8 @@ pub fn bench() {
     lwe_ciphertext_discarding_addition::bench::<CoreEngine, LweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_vector_discarding_affine_transformation::bench::<CoreEngine, LweCiphertextVector32, CleartextVector32, Plaintext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_vector_discarding_affine_transformation::bench::<CoreEngine, LweCiphertextVector64, CleartextVector64, Plaintext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_vector_discarding_bootstrap::bench::<CoreEngine, FourierLweBootstrapKey32, GlweCiphertextVector32, LweCiphertextVector32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_discarding_bootstrap::bench::<CoreEngine, FourierLweBootstrapKey64, GlweCiphertextVector64, LweCiphertextVector64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_discarding_decryption::bench::<CoreEngine, LweSecretKey32, LweCiphertext32, Plaintext32>(&mut criterion);
     lwe_ciphertext_discarding_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertext64, Plaintext64>(&mut criterion);
     lwe_ciphertext_discarding_encryption::bench::<CoreEngine, LweSecretKey32, Plaintext32, LweCiphertext32>(&mut criterion);
@@ -62,8 +68,14 @@ pub fn bench() {
     lwe_ciphertext_discarding_extraction::bench::<CoreEngine, GlweCiphertext64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey32, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey64, LweCiphertext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_discarding_loading::bench::<CoreEngine, LweCiphertextVector32, LweCiphertext32>(&mut criterion);
+    lwe_ciphertext_discarding_loading::bench::<CoreEngine, LweCiphertextVector64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_discarding_negation::bench::<CoreEngine, LweCiphertext32, LweCiphertext32>(&mut criterion);
     lwe_ciphertext_discarding_negation::bench::<CoreEngine, LweCiphertext64, LweCiphertext64>(&mut criterion);
+    lwe_ciphertext_discarding_storing::bench::<CoreEngine, LweCiphertext32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_discarding_storing::bench::<CoreEngine, LweCiphertext64, LweCiphertextVector64>(&mut criterion);
+    lwe_ciphertext_loading::bench::<CoreEngine, LweCiphertextVector32, LweCiphertext32>(&mut criterion);
+    lwe_ciphertext_loading::bench::<CoreEngine, LweCiphertextVector64, LweCiphertext64>(&mut criterion);
     lwe_ciphertext_plaintext_fusing_addition::bench::<CoreEngine, LweCiphertext32, Plaintext32>(&mut criterion);
     lwe_ciphertext_plaintext_fusing_addition::bench::<CoreEngine, LweCiphertext64, Plaintext64>(&mut criterion);
     lwe_ciphertext_plaintext_discarding_addition::bench::<CoreEngine, LweCiphertext32, Plaintext32, LweCiphertext32>(&mut criterion);
@@ -76,6 +88,10 @@ pub fn bench() {
     lwe_ciphertext_vector_discarding_decryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64, PlaintextVector64>(&mut criterion);
     lwe_ciphertext_vector_discarding_encryption::bench::<CoreEngine, LweSecretKey32, PlaintextVector32, LweCiphertextVector32>(&mut criterion);
     lwe_ciphertext_vector_discarding_encryption::bench::<CoreEngine, LweSecretKey64, PlaintextVector64, LweCiphertextVector64>(&mut criterion);
+    lwe_ciphertext_vector_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey32, LweCiphertextVector32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_discarding_keyswitch::bench::<CoreEngine, LweKeyswitchKey64, LweCiphertextVector64, LweCiphertextVector64>(&mut criterion);
+    lwe_ciphertext_vector_loading::bench::<CoreEngine, LweCiphertextVector32, LweCiphertextVector32>(&mut criterion);
+    lwe_ciphertext_vector_loading::bench::<CoreEngine, LweCiphertextVector64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_vector_zero_encryption::bench::<CoreEngine, LweSecretKey32, LweCiphertextVector32>(&mut criterion);
     lwe_ciphertext_vector_zero_encryption::bench::<CoreEngine, LweSecretKey64, LweCiphertextVector64>(&mut criterion);
     lwe_ciphertext_zero_encryption::bench::<CoreEngine, LweSecretKey32, LweCiphertext32>(&mut criterion);
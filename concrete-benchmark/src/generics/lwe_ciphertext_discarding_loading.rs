@@ -0,0 +1,64 @@
+use crate::synthesizer::{
+    SynthesizableLweCiphertextEntity, SynthesizableLweCiphertextVectorEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+use concrete_core::specification::engines::LweCiphertextDiscardingLoadingEngine;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the discarding lwe ciphertext loading operation.
+pub fn bench<Engine, CiphertextVector, Ciphertext>(c: &mut Criterion)
+where
+    Engine: LweCiphertextDiscardingLoadingEngine<CiphertextVector, Ciphertext>,
+    Ciphertext: SynthesizableLweCiphertextEntity,
+    CiphertextVector: SynthesizableLweCiphertextVectorEntity<KeyFlavor = Ciphertext::KeyFlavor>,
+{
+    let mut group = c.benchmark_group(benchmark_name!(impl LweCiphertextDiscardingLoadingEngine<
+            CiphertextVector,
+            Ciphertext
+            > for Engine));
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (lwe_dim, ciphertext_count) = param.to_owned();
+                let mut ciphertext = Ciphertext::synthesize(&mut synthesizer, lwe_dim, VARIANCE);
+                let ciphertext_vector = CiphertextVector::synthesize(
+                    &mut synthesizer,
+                    lwe_dim,
+                    ciphertext_count,
+                    VARIANCE,
+                );
+                b.iter(|| {
+                    engine
+                        .discard_load_lwe_ciphertext(
+                            black_box(&mut ciphertext),
+                            black_box(&ciphertext_vector),
+                            black_box(LweCiphertextIndex(ciphertext_count.0 / 2)),
+                        )
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(LweDimension, LweCiphertextCount); 6] = [
+    (LweDimension(100), LweCiphertextCount(100)),
+    (LweDimension(300), LweCiphertextCount(100)),
+    (LweDimension(600), LweCiphertextCount(100)),
+    (LweDimension(1000), LweCiphertextCount(100)),
+    (LweDimension(3000), LweCiphertextCount(100)),
+    (LweDimension(6000), LweCiphertextCount(100)),
+];
@@ -0,0 +1,118 @@
+use crate::synthesizer::{
+    SynthesizableLweCiphertextVectorEntity, SynthesizableLweKeyswitchKeyEntity, Synthesizer,
+};
+use crate::utils::benchmark_name;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+};
+use concrete_core::specification::engines::LweCiphertextVectorDiscardingKeyswitchEngine;
+use criterion::{black_box, BenchmarkId, Criterion};
+
+/// A generic function benchmarking the discarding lwe vector keyswitch operation.
+pub fn bench<Engine, KeyswitchKey, InputCiphertextVector, OutputCiphertextVector>(c: &mut Criterion)
+where
+    Engine: LweCiphertextVectorDiscardingKeyswitchEngine<
+        KeyswitchKey,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >,
+    KeyswitchKey: SynthesizableLweKeyswitchKeyEntity,
+    InputCiphertextVector:
+        SynthesizableLweCiphertextVectorEntity<KeyFlavor = KeyswitchKey::InputKeyFlavor>,
+    OutputCiphertextVector:
+        SynthesizableLweCiphertextVectorEntity<KeyFlavor = KeyswitchKey::OutputKeyFlavor>,
+{
+    let mut group = c.benchmark_group(
+        benchmark_name!(impl LweCiphertextVectorDiscardingKeyswitchEngine<
+            KeyswitchKey, 
+            InputCiphertextVector, 
+            OutputCiphertextVector
+            > for Engine),
+    );
+
+    let mut engine = Engine::new().unwrap();
+    let mut synthesizer = Synthesizer::default();
+
+    for param in PARAMETERS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", param)),
+            &param,
+            |b, param| {
+                let (lwe_dim, lwe_count, base_log, level) = param.to_owned();
+                let ksk = KeyswitchKey::synthesize(
+                    &mut synthesizer,
+                    lwe_dim,
+                    lwe_dim,
+                    base_log,
+                    level,
+                    VARIANCE,
+                );
+                let mut output_lwe_vector = OutputCiphertextVector::synthesize(
+                    &mut synthesizer,
+                    lwe_dim,
+                    lwe_count,
+                    VARIANCE,
+                );
+                let input_lwe_vector = InputCiphertextVector::synthesize(
+                    &mut synthesizer,
+                    lwe_dim,
+                    lwe_count,
+                    VARIANCE,
+                );
+                b.iter(|| {
+                    engine
+                        .discard_keyswitch_lwe_ciphertext_vector(
+                            black_box(&mut output_lwe_vector),
+                            black_box(&input_lwe_vector),
+                            black_box(&ksk),
+                        )
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+/// The variance used to encrypt everything in the benchmark.
+const VARIANCE: Variance = Variance(0.00000001);
+
+/// The parameters the benchmark is executed against.
+const PARAMETERS: [(
+    LweDimension,
+    LweCiphertextCount,
+    DecompositionBaseLog,
+    DecompositionLevelCount,
+); 5] = [
+    (
+        LweDimension(100),
+        LweCiphertextCount(100),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(200),
+        LweCiphertextCount(100),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(300),
+        LweCiphertextCount(100),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(400),
+        LweCiphertextCount(100),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+    (
+        LweDimension(500),
+        LweCiphertextCount(100),
+        DecompositionBaseLog(2),
+        DecompositionLevelCount(3),
+    ),
+];
@@ -25,9 +25,18 @@ pub struct LweCiphertextCount(pub usize);
 pub struct LweCiphertextIndex(pub usize);
 
 /// The range of indices of multiple contiguous ciphertexts in an lwe ciphertext list.
+///
+/// The first index is included in the range, while the second one is excluded.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct LweCiphertextRange(pub usize, pub usize);
 
+impl LweCiphertextRange {
+    /// Returns whether the lower bound of the range is smaller than its upper bound.
+    pub fn is_ordered(&self) -> bool {
+        self.0 <= self.1
+    }
+}
+
 /// The number of ciphertexts in a glwe ciphertext list.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct GlweCiphertextCount(pub usize);
@@ -40,6 +49,14 @@ pub struct GswCiphertextCount(pub usize);
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
 pub struct GgswCiphertextCount(pub usize);
 
+/// The number of private functional packing keyswitch keys in a list.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct FunctionalPackingKeyswitchKeyCount(pub usize);
+
+/// The number of encryptions of zero contained in an LWE public key.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Serialize, Deserialize)]
+pub struct LwePublicKeyZeroEncryptionCount(pub usize);
+
 /// The number of scalars in an LWE ciphertext, i.e. the number of scalar in an LWE mask plus one.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct LweSize(pub usize);
@@ -114,3 +131,10 @@ pub struct DecompositionBaseLog(pub usize);
 /// the $l$ value.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Deserialize, Serialize)]
 pub struct DecompositionLevelCount(pub usize);
+
+/// The logarithm of the modulus of a ciphertext.
+///
+/// When the coefficients of a ciphertext are switched to the $2^m$ modulus, this type represents
+/// the $m$ value.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Deserialize, Serialize)]
+pub struct CiphertextModulusLog(pub usize);
@@ -0,0 +1,247 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError as CreationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// # Description:
+/// Implementation of [`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+impl
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(8);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// // The input key is the output key of the bootstrap key used in the circuit bootstrap
+    /// let input_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let cbs_pfpksk: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     cbs_pfpksk.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), LweDimension(8));
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_polynomial_size(), output_polynomial_size);
+    /// assert_eq!(
+    ///     cbs_pfpksk.key_count(),
+    ///     FunctionalPackingKeyswitchKeyCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_key: &CleartextGlweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        CreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(CreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(CreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(CreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_key: &CleartextGlweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+            input_lwe_dimension: LweDimension(
+                input_key.glwe_dimension().0 * input_key.polynomial_size().0,
+            ),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            output_polynomial_size: output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+impl
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(8);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// // The input key is the output key of the bootstrap key used in the circuit bootstrap
+    /// let input_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let cbs_pfpksk: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     cbs_pfpksk.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), LweDimension(8));
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_polynomial_size(), output_polynomial_size);
+    /// assert_eq!(
+    ///     cbs_pfpksk.key_count(),
+    ///     FunctionalPackingKeyswitchKeyCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_key: &CleartextGlweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        CreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(CreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(CreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(CreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_key: &CleartextGlweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+            input_lwe_dimension: LweDimension(
+                input_key.glwe_dimension().0 * input_key.polynomial_size().0,
+            ),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            output_polynomial_size: output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
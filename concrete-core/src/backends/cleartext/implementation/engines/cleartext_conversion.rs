@@ -0,0 +1,85 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::backends::core::private::crypto::encoding::Cleartext as ImplCleartext;
+use crate::specification::engines::{
+    CleartextConversionEngine, CleartextConversionError, CleartextDiscardingConversionEngine,
+};
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CleartextEngine`] that converts a 32 bits
+/// cleartext to a 64 bits cleartext. The values are cast to the output precision.
+///
+/// The cleartexts are the ones of the `core` backend, which this implementation shares with it.
+impl CleartextConversionEngine<Cleartext32, Cleartext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&input)?;
+    ///
+    /// let output: Cleartext64 = engine.convert_cleartext(&cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext32,
+    ) -> Result<Cleartext64, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext32) -> Cleartext64 {
+        let mut output = Cleartext64(ImplCleartext(0_u64));
+        self.discard_convert_cleartext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CleartextEngine`] that converts a 64 bits
+/// cleartext to a 32 bits cleartext. The values are cast to the output precision, discarding the
+/// most significant bits.
+///
+/// The cleartexts are the ones of the `core` backend, which this implementation shares with it.
+impl CleartextConversionEngine<Cleartext64, Cleartext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    ///
+    /// let output: Cleartext32 = engine.convert_cleartext(&cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext64,
+    ) -> Result<Cleartext32, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext64) -> Cleartext32 {
+        let mut output = Cleartext32(ImplCleartext(0_u32));
+        self.discard_convert_cleartext_unchecked(&mut output, input);
+        output
+    }
+}
@@ -0,0 +1,116 @@
+use crate::backends::cleartext::implementation::engines::{CleartextEngine, CleartextError};
+use crate::backends::core::entities::{CleartextF64, FloatEncoder, Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Encoder;
+use crate::specification::engines::{CleartextEncodingEngine, CleartextEncodingError};
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CleartextEngine`] that encodes 64 bits
+/// floating point numbers to 32 bits integers.
+///
+/// The encoders, cleartexts and plaintexts are the ones of the `core` backend, which this
+/// implementation shares with it.
+impl CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext32, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(CleartextEncodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        if !encoder.0.is_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::Engine(
+                CleartextError::FloatEncoderValueOutOfInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Plaintext32 {
+        Plaintext32(encoder.0.encode(cleartext.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextEncodingEngine`] for [`CleartextEngine`] that encodes 64 bits
+/// floating point numbers to 64 bits integers.
+///
+/// The encoders, cleartexts and plaintexts are the ones of the `core` backend, which this
+/// implementation shares with it.
+impl CleartextEncodingEngine<FloatEncoder, CleartextF64, Plaintext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Result<Plaintext64, CleartextEncodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(CleartextEncodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        if !encoder.0.is_in_interval(cleartext.0 .0) {
+            return Err(CleartextEncodingError::Engine(
+                CleartextError::FloatEncoderValueOutOfInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_unchecked(encoder, cleartext) })
+    }
+
+    unsafe fn encode_cleartext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        cleartext: &CleartextF64,
+    ) -> Plaintext64 {
+        Plaintext64(encoder.0.encode(cleartext.0))
+    }
+}
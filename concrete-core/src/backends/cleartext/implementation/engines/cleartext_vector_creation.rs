@@ -0,0 +1,115 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::core::entities::{CleartextVector32, CleartextVector64, CleartextVectorF64};
+use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
+use crate::specification::engines::{CleartextVectorCreationEngine, CleartextVectorCreationError};
+
+/// # Description:
+/// Implementation of [`CleartextVectorCreationEngine`] for [`CleartextEngine`] that operates on 32
+/// bits integers.
+impl CleartextVectorCreationEngine<u32, CleartextVector32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&input)?;
+    /// #
+    /// assert_eq!(cleartext_vector.cleartext_count(), CleartextCount(100));
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext_vector(
+        &mut self,
+        input: &[u32],
+    ) -> Result<CleartextVector32, CleartextVectorCreationError<Self::EngineError>> {
+        if input.is_empty() {
+            return Err(CleartextVectorCreationError::EmptyInput);
+        }
+        Ok(unsafe { self.create_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_vector_unchecked(&mut self, input: &[u32]) -> CleartextVector32 {
+        CleartextVector32(ImplCleartextList::from_container(input.to_vec()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorCreationEngine`] for [`CleartextEngine`] that operates on 64
+/// bits integers.
+impl CleartextVectorCreationEngine<u64, CleartextVector64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector(&input)?;
+    /// #
+    /// assert_eq!(cleartext_vector.cleartext_count(), CleartextCount(100));
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext_vector(
+        &mut self,
+        input: &[u64],
+    ) -> Result<CleartextVector64, CleartextVectorCreationError<Self::EngineError>> {
+        if input.is_empty() {
+            return Err(CleartextVectorCreationError::EmptyInput);
+        }
+        Ok(unsafe { self.create_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_vector_unchecked(&mut self, input: &[u64]) -> CleartextVector64 {
+        CleartextVector64(ImplCleartextList::from_container(input.to_vec()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorCreationEngine`] for [`CleartextEngine`] that operates on 64
+/// bits floating point numbers.
+impl CleartextVectorCreationEngine<f64, CleartextVectorF64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_f64; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&input)?;
+    /// #
+    /// assert_eq!(cleartext_vector.cleartext_count(), CleartextCount(100));
+    /// engine.destroy(cleartext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext_vector(
+        &mut self,
+        input: &[f64],
+    ) -> Result<CleartextVectorF64, CleartextVectorCreationError<Self::EngineError>> {
+        if input.is_empty() {
+            return Err(CleartextVectorCreationError::EmptyInput);
+        }
+        Ok(unsafe { self.create_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn create_cleartext_vector_unchecked(&mut self, input: &[f64]) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(input.to_vec()))
+    }
+}
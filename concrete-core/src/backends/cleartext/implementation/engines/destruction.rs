@@ -0,0 +1,1301 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64,
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32, CleartextFourierLweGaussianBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32, CleartextFourierLweTernaryBootstrapKey64,
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextGgswCiphertextVector32,
+    CleartextGgswCiphertextVector64, CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+    CleartextGlweCiphertextMutView32, CleartextGlweCiphertextMutView64,
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweCiphertextView32, CleartextGlweCiphertextView64,
+    CleartextGlweGaussianCiphertext32, CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector32, CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64,
+    CleartextGlweRelinearizationKey32, CleartextGlweRelinearizationKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweSeededCiphertext32, CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector32, CleartextGlweSeededCiphertextVector64,
+    CleartextGlweTernaryCiphertext32, CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector32, CleartextGlweTernaryCiphertextVector64,
+    CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64, CleartextLweCiphertext32,
+    CleartextLweCiphertext64, CleartextLweCiphertextMutView32, CleartextLweCiphertextMutView64,
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView32, CleartextLweCiphertextVectorMutView64,
+    CleartextLweCiphertextVectorView32, CleartextLweCiphertextVectorView64,
+    CleartextLweCiphertextView32, CleartextLweCiphertextView64, CleartextLweCompressedCiphertext64,
+    CleartextLweCompressedCiphertextVector64, CleartextLweGaussianBootstrapKey32,
+    CleartextLweGaussianBootstrapKey64, CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertext64, CleartextLweGaussianCiphertextVector32,
+    CleartextLweGaussianCiphertextVector64, CleartextLweGaussianSecretKey32,
+    CleartextLweGaussianSecretKey64, CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64,
+    CleartextLwePublicKey32, CleartextLwePublicKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextLweSeededCiphertext32, CleartextLweSeededCiphertext64,
+    CleartextLweSeededCiphertextVector32, CleartextLweSeededCiphertextVector64,
+    CleartextLweTernaryBootstrapKey32, CleartextLweTernaryBootstrapKey64,
+    CleartextLweTernaryCiphertext32, CleartextLweTernaryCiphertext64,
+    CleartextLweTernaryCiphertextVector32, CleartextLweTernaryCiphertextVector64,
+    CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64, CleartextPackingKeyswitchKey32,
+    CleartextPackingKeyswitchKey64, CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::backends::core::entities::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, FloatEncoder, FloatEncoderVector, Plaintext32, Plaintext64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+impl DestructionEngine<Cleartext32> for CleartextEngine {
+    fn destroy(&mut self, entity: Cleartext32) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: Cleartext32) {}
+}
+
+impl DestructionEngine<Cleartext64> for CleartextEngine {
+    fn destroy(&mut self, entity: Cleartext64) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: Cleartext64) {}
+}
+
+impl DestructionEngine<CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32>
+    for CleartextEngine
+{
+    fn destroy(
+        &mut self,
+        entity: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+    }
+}
+
+impl DestructionEngine<CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64>
+    for CleartextEngine
+{
+    fn destroy(
+        &mut self,
+        entity: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+    }
+}
+
+impl DestructionEngine<CleartextF64> for CleartextEngine {
+    fn destroy(&mut self, entity: CleartextF64) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextF64) {}
+}
+
+impl DestructionEngine<CleartextFourierGgswCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierGgswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierGgswCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextFourierGgswCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierGgswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierGgswCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextFourierLweBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextFourierLweBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextFourierLweGaussianBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweGaussianBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweGaussianBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextFourierLweGaussianBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweGaussianBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweGaussianBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextFourierLweTernaryBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweTernaryBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweTernaryBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextFourierLweTernaryBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextFourierLweTernaryBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextFourierLweTernaryBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextGgswCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGgswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGgswCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextGgswCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGgswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGgswCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextGgswCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGgswCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGgswCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextGgswCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGgswCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGgswCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextGlweCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextGlweCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertext64) {}
+}
+
+impl<'a> DestructionEngine<CleartextGlweCiphertextMutView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextGlweCiphertextMutView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextMutView64<'a>) {}
+}
+
+impl DestructionEngine<CleartextGlweCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextGlweCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextVector64) {}
+}
+
+impl<'a> DestructionEngine<CleartextGlweCiphertextView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextGlweCiphertextView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweCiphertextView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweCiphertextView64<'a>) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianSecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianSecretKey32) {}
+}
+
+impl DestructionEngine<CleartextGlweGaussianSecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweGaussianSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweGaussianSecretKey64) {}
+}
+
+impl DestructionEngine<CleartextGlweRelinearizationKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweRelinearizationKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweRelinearizationKey32) {}
+}
+
+impl DestructionEngine<CleartextGlweRelinearizationKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweRelinearizationKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweRelinearizationKey64) {}
+}
+
+impl DestructionEngine<CleartextGlweSecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSecretKey32) {}
+}
+
+impl DestructionEngine<CleartextGlweSecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSecretKey64) {}
+}
+
+impl DestructionEngine<CleartextGlweSeededCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSeededCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSeededCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextGlweSeededCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSeededCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSeededCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextGlweSeededCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSeededCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSeededCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextGlweSeededCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweSeededCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweSeededCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextGlweTernaryCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernaryCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernaryCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextGlweTernaryCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernaryCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernaryCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextGlweTernaryCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernaryCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernaryCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextGlweTernaryCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernaryCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernaryCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextGlweTernarySecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernarySecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernarySecretKey32) {}
+}
+
+impl DestructionEngine<CleartextGlweTernarySecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextGlweTernarySecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextGlweTernarySecretKey64) {}
+}
+
+impl DestructionEngine<CleartextLweBinaryToGaussianKeyswitchKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBinaryToGaussianKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBinaryToGaussianKeyswitchKey32) {}
+}
+
+impl DestructionEngine<CleartextLweBinaryToGaussianKeyswitchKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBinaryToGaussianKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBinaryToGaussianKeyswitchKey64) {}
+}
+
+impl DestructionEngine<CleartextLweBinaryToTernaryKeyswitchKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBinaryToTernaryKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBinaryToTernaryKeyswitchKey32) {}
+}
+
+impl DestructionEngine<CleartextLweBinaryToTernaryKeyswitchKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBinaryToTernaryKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBinaryToTernaryKeyswitchKey64) {}
+}
+
+impl DestructionEngine<CleartextLweBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextLweBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextLweCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextLweCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertext64) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextMutView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextMutView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextMutView64<'a>) {}
+}
+
+impl DestructionEngine<CleartextLweCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextLweCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVector64) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextVectorMutView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVectorMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVectorMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextVectorMutView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVectorMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVectorMutView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextVectorView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVectorView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVectorView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextVectorView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextVectorView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextVectorView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextView32<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<CleartextLweCiphertextView64<'a>> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCiphertextView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCiphertextView64<'a>) {}
+}
+
+impl DestructionEngine<CleartextLweCompressedCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCompressedCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCompressedCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextLweCompressedCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweCompressedCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweCompressedCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianSecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianSecretKey32) {}
+}
+
+impl DestructionEngine<CleartextLweGaussianSecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweGaussianSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweGaussianSecretKey64) {}
+}
+
+impl DestructionEngine<CleartextLweKeyswitchKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweKeyswitchKey32) {}
+}
+
+impl DestructionEngine<CleartextLweKeyswitchKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweKeyswitchKey64) {}
+}
+
+impl DestructionEngine<CleartextLwePublicKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLwePublicKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLwePublicKey32) {}
+}
+
+impl DestructionEngine<CleartextLwePublicKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLwePublicKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLwePublicKey64) {}
+}
+
+impl DestructionEngine<CleartextLweSecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSecretKey32) {}
+}
+
+impl DestructionEngine<CleartextLweSecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSecretKey64) {}
+}
+
+impl DestructionEngine<CleartextLweSeededCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSeededCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSeededCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextLweSeededCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSeededCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSeededCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextLweSeededCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSeededCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSeededCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextLweSeededCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweSeededCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweSeededCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryBootstrapKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryBootstrapKey32) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryBootstrapKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryBootstrapKey64) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryCiphertext32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryCiphertext32) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryCiphertext64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryCiphertext64) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryCiphertextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryCiphertextVector32) {}
+}
+
+impl DestructionEngine<CleartextLweTernaryCiphertextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernaryCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernaryCiphertextVector64) {}
+}
+
+impl DestructionEngine<CleartextLweTernarySecretKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernarySecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernarySecretKey32) {}
+}
+
+impl DestructionEngine<CleartextLweTernarySecretKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextLweTernarySecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextLweTernarySecretKey64) {}
+}
+
+impl DestructionEngine<CleartextPackingKeyswitchKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextPackingKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextPackingKeyswitchKey32) {}
+}
+
+impl DestructionEngine<CleartextPackingKeyswitchKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextPackingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextPackingKeyswitchKey64) {}
+}
+
+impl DestructionEngine<CleartextPrivateFunctionalPackingKeyswitchKey32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CleartextPrivateFunctionalPackingKeyswitchKey32,
+    ) {
+    }
+}
+
+impl DestructionEngine<CleartextPrivateFunctionalPackingKeyswitchKey64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CleartextPrivateFunctionalPackingKeyswitchKey64,
+    ) {
+    }
+}
+
+impl DestructionEngine<CleartextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector32) {}
+}
+
+impl DestructionEngine<CleartextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector64) {}
+}
+
+impl DestructionEngine<CleartextVectorF64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextVectorF64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVectorF64) {}
+}
+
+impl DestructionEngine<FloatEncoder> for CleartextEngine {
+    fn destroy(&mut self, entity: FloatEncoder) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoder) {}
+}
+
+impl DestructionEngine<FloatEncoderVector> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: FloatEncoderVector,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoderVector) {}
+}
+
+impl DestructionEngine<Plaintext32> for CleartextEngine {
+    fn destroy(&mut self, entity: Plaintext32) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: Plaintext32) {}
+}
+
+impl DestructionEngine<Plaintext64> for CleartextEngine {
+    fn destroy(&mut self, entity: Plaintext64) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: Plaintext64) {}
+}
+
+impl DestructionEngine<PlaintextVector32> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: PlaintextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: PlaintextVector32) {}
+}
+
+impl DestructionEngine<PlaintextVector64> for CleartextEngine {
+    fn destroy(
+        &mut self,
+        entity: PlaintextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: PlaintextVector64) {}
+}
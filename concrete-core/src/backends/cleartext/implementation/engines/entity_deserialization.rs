@@ -0,0 +1,233 @@
+use super::entity_serialization::deserialize_entity;
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64,
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32, CleartextFourierLweGaussianBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32, CleartextFourierLweTernaryBootstrapKey64,
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextGgswCiphertextVector32,
+    CleartextGgswCiphertextVector64, CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweGaussianCiphertext32, CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector32, CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64,
+    CleartextGlweRelinearizationKey32, CleartextGlweRelinearizationKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweSeededCiphertext32, CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector32, CleartextGlweSeededCiphertextVector64,
+    CleartextGlweTernaryCiphertext32, CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector32, CleartextGlweTernaryCiphertextVector64,
+    CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64, CleartextLweCiphertext32,
+    CleartextLweCiphertext64, CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCompressedCiphertext64, CleartextLweCompressedCiphertextVector64,
+    CleartextLweGaussianBootstrapKey32, CleartextLweGaussianBootstrapKey64,
+    CleartextLweGaussianCiphertext32, CleartextLweGaussianCiphertext64,
+    CleartextLweGaussianCiphertextVector32, CleartextLweGaussianCiphertextVector64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64, CleartextLwePublicKey32, CleartextLwePublicKey64,
+    CleartextLweSecretKey32, CleartextLweSecretKey64, CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64, CleartextLweSeededCiphertextVector32,
+    CleartextLweSeededCiphertextVector64, CleartextLweTernaryBootstrapKey32,
+    CleartextLweTernaryBootstrapKey64, CleartextLweTernaryCiphertext32,
+    CleartextLweTernaryCiphertext64, CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64, CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64, CleartextPackingKeyswitchKey32, CleartextPackingKeyswitchKey64,
+    CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::backends::core::entities::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, FloatEncoder, FloatEncoderVector, Plaintext32, Plaintext64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{EntityDeserializationEngine, EntityDeserializationError};
+
+macro_rules! implement_entity_deserialization {
+    ($($(#[$meta: meta])* $entity: ident),* $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntityDeserializationEngine`] for [`CleartextEngine`] that \
+                deserializes [`",
+                stringify!($entity),
+                "`] entities from byte buffers."
+            )]
+            ///
+            /// The buffer must have been produced by the
+            /// [`EntitySerializationEngine`](crate::specification::engines::EntitySerializationEngine)
+            /// implementation of the same entity type for the [`CleartextEngine`]. Buffers written
+            /// by the `core` backend, or with another version of the format, or containing another
+            /// kind of entity, are rejected.
+            $(#[$meta])*
+            impl EntityDeserializationEngine<&[u8], $entity> for CleartextEngine {
+                fn deserialize(
+                    &mut self,
+                    serialized: &[u8],
+                ) -> Result<$entity, EntityDeserializationError<Self::EngineError>> {
+                    deserialize_entity(serialized)
+                }
+
+                /// # Safety:
+                /// The `serialized` buffer is still parsed in full, and this method panics if
+                /// it is rejected for any of the reasons listed in
+                /// [`EntityDeserializationError`]. Untrusted buffers should be deserialized with
+                /// the checked method instead.
+                unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> $entity {
+                    deserialize_entity(serialized)
+                        .expect("Failed to deserialize an entity from a byte buffer.")
+                }
+            }
+        )*
+    };
+}
+implement_entity_deserialization!(
+    Plaintext32,
+    Plaintext64,
+    PlaintextVector32,
+    PlaintextVector64,
+    Cleartext32,
+    Cleartext64,
+    CleartextF64,
+    CleartextVector32,
+    CleartextVector64,
+    CleartextVectorF64,
+    FloatEncoder,
+    FloatEncoderVector,
+    CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+    CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64,
+    CleartextLweGaussianSecretKey32,
+    CleartextLweGaussianSecretKey64,
+    CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+    CleartextGlweTernarySecretKey32,
+    CleartextGlweTernarySecretKey64,
+    CleartextGlweGaussianSecretKey32,
+    CleartextGlweGaussianSecretKey64,
+    CleartextLweCiphertext32,
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let serialized: Vec<u8> = engine.serialize(&ciphertext)?;
+    ///
+    /// let deserialized: CleartextLweCiphertext64 = engine.deserialize(serialized.as_slice())?;
+    /// #
+    /// assert_eq!(deserialized, ciphertext);
+    ///
+    /// // Deserializing the buffer as another kind of entity fails.
+    /// let result: Result<CleartextGlweCiphertext64, _> = engine.deserialize(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::KindMismatch)
+    /// ));
+    ///
+    /// // The buffers of the `core` backend are rejected, and conversely.
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let result: Result<CleartextLweCiphertext64, _> =
+    ///     engine.deserialize(core_engine.serialize(&plaintext)?.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::UnsupportedVersion)
+    /// ));
+    /// let result: Result<LweCiphertext64, _> = core_engine.deserialize(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::UnsupportedVersion)
+    /// ));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(deserialized)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    CleartextLweCiphertext64,
+    CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64,
+    CleartextLweTernaryCiphertext32,
+    CleartextLweTernaryCiphertext64,
+    CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64,
+    CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertext64,
+    CleartextLweGaussianCiphertextVector32,
+    CleartextLweGaussianCiphertextVector64,
+    CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64,
+    CleartextLweSeededCiphertextVector32,
+    CleartextLweSeededCiphertextVector64,
+    CleartextGlweCiphertext32,
+    CleartextGlweCiphertext64,
+    CleartextGlweCiphertextVector32,
+    CleartextGlweCiphertextVector64,
+    CleartextGlweTernaryCiphertext32,
+    CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector32,
+    CleartextGlweTernaryCiphertextVector64,
+    CleartextGlweGaussianCiphertext32,
+    CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector32,
+    CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweSeededCiphertext32,
+    CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector32,
+    CleartextGlweSeededCiphertextVector64,
+    CleartextGgswCiphertext32,
+    CleartextGgswCiphertext64,
+    CleartextGgswCiphertextVector32,
+    CleartextGgswCiphertextVector64,
+    CleartextFourierGgswCiphertext32,
+    CleartextFourierGgswCiphertext64,
+    CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32,
+    CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweBinaryToGaussianKeyswitchKey32,
+    CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBootstrapKey32,
+    CleartextLweBootstrapKey64,
+    CleartextLweTernaryBootstrapKey32,
+    CleartextLweTernaryBootstrapKey64,
+    CleartextLweGaussianBootstrapKey32,
+    CleartextLweGaussianBootstrapKey64,
+    CleartextFourierLweBootstrapKey32,
+    CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32,
+    CleartextFourierLweTernaryBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32,
+    CleartextFourierLweGaussianBootstrapKey64,
+    CleartextLwePublicKey32,
+    CleartextLwePublicKey64,
+    CleartextPackingKeyswitchKey32,
+    CleartextPackingKeyswitchKey64,
+    CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextGlweRelinearizationKey32,
+    CleartextGlweRelinearizationKey64,
+    CleartextLweCompressedCiphertext64,
+    CleartextLweCompressedCiphertextVector64,
+);
@@ -0,0 +1,1136 @@
+use std::io::{Read, Write};
+
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LwePublicKeyZeroEncryptionCount, PolynomialSize};
+
+use crate::backends::cleartext::implementation::engines::{CleartextEngine, CleartextError};
+use crate::backends::cleartext::implementation::entities::{
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64,
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32, CleartextFourierLweGaussianBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32, CleartextFourierLweTernaryBootstrapKey64,
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextGgswCiphertextVector32,
+    CleartextGgswCiphertextVector64, CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweGaussianCiphertext32, CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector32, CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64,
+    CleartextGlweRelinearizationKey32, CleartextGlweRelinearizationKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweSeededCiphertext32, CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector32, CleartextGlweSeededCiphertextVector64,
+    CleartextGlweTernaryCiphertext32, CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector32, CleartextGlweTernaryCiphertextVector64,
+    CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64, CleartextLweCiphertext32,
+    CleartextLweCiphertext64, CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCompressedCiphertext64, CleartextLweCompressedCiphertextVector64,
+    CleartextLweGaussianBootstrapKey32, CleartextLweGaussianBootstrapKey64,
+    CleartextLweGaussianCiphertext32, CleartextLweGaussianCiphertext64,
+    CleartextLweGaussianCiphertextVector32, CleartextLweGaussianCiphertextVector64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64, CleartextLwePublicKey32, CleartextLwePublicKey64,
+    CleartextLweSecretKey32, CleartextLweSecretKey64, CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64, CleartextLweSeededCiphertextVector32,
+    CleartextLweSeededCiphertextVector64, CleartextLweTernaryBootstrapKey32,
+    CleartextLweTernaryBootstrapKey64, CleartextLweTernaryCiphertext32,
+    CleartextLweTernaryCiphertext64, CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64, CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64, CleartextPackingKeyswitchKey32, CleartextPackingKeyswitchKey64,
+    CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::backends::core::engines::entity_serialization::{
+    checked_product, read_modulus_log, read_vec, write_slice, EntityHeader, EntityParameters,
+    SerializableEntity, SerializedEntityKind, SerializedKeyFlavor, SerializedPrecision,
+};
+use crate::backends::core::entities::{
+    Cleartext32, Cleartext64, CleartextF64, CleartextVector32, CleartextVector64,
+    CleartextVectorF64, FloatEncoder, FloatEncoderVector, Plaintext32, Plaintext64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    EntityDeserializationError, EntitySerializationEngine, EntitySerializationError,
+};
+
+/// The marker written at the beginning of the entities serialized by the cleartext backend.
+///
+/// The entities of the `core` backend start with the version of their format instead, encoded as
+/// a little endian `u16`. The marker reads as an unsupported version for the `core` backend, and
+/// the entities of the `core` backend are rejected by the cleartext backend, which can not
+/// recover their phases without the secret keys.
+pub(crate) const FORMAT_MARKER: [u8; 4] = *b"CLTX";
+
+/// The version of the format used to serialize the entities of the cleartext backend.
+///
+/// The header and the payloads of the plaintexts, cleartexts and encoders are the ones of the
+/// `core` backend. The payloads of the other entities hold their phases, followed by the variance
+/// of the noise for the entities which simulate it. This version must be incremented every time
+/// one of these layouts is modified.
+pub(crate) const FORMAT_VERSION: u16 = 1;
+
+/// Serializes an entity, header included.
+pub(crate) fn serialize_entity<Entity: SerializableEntity>(entity: &Entity) -> Vec<u8> {
+    let header = EntityHeader {
+        kind: Entity::KIND,
+        key_flavor: Entity::KEY_FLAVOR,
+        output_key_flavor: Entity::OUTPUT_KEY_FLAVOR,
+        precision: Entity::PRECISION,
+        parameters: entity.parameters(),
+    };
+    let mut output = Vec::new();
+    bincode::serialize_into(&mut output, &FORMAT_MARKER)
+        .and_then(|_| bincode::serialize_into(&mut output, &FORMAT_VERSION))
+        .and_then(|_| bincode::serialize_into(&mut output, &header))
+        .and_then(|_| entity.write_payload(&mut output))
+        .expect("Failed to serialize an entity into a byte buffer.");
+    output
+}
+
+/// Deserializes an entity, after checking its header.
+pub(crate) fn deserialize_entity<Entity: SerializableEntity>(
+    mut serialized: &[u8],
+) -> Result<Entity, EntityDeserializationError<CleartextError>> {
+    let marker: [u8; 4] = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::MalformedHeader)?;
+    let version: u16 = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::MalformedHeader)?;
+    if marker != FORMAT_MARKER || version != FORMAT_VERSION {
+        return Err(EntityDeserializationError::UnsupportedVersion);
+    }
+    let header: EntityHeader = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::MalformedHeader)?;
+    if header.kind != Entity::KIND {
+        return Err(EntityDeserializationError::KindMismatch);
+    }
+    if header.key_flavor != Entity::KEY_FLAVOR
+        || header.output_key_flavor != Entity::OUTPUT_KEY_FLAVOR
+    {
+        return Err(EntityDeserializationError::KeyFlavorMismatch);
+    }
+    if header.precision != Entity::PRECISION {
+        return Err(EntityDeserializationError::PrecisionMismatch);
+    }
+    let entity = Entity::read_payload(&header.parameters, &mut serialized)
+        .ok_or(EntityDeserializationError::MalformedPayload)?;
+    if !serialized.is_empty() {
+        return Err(EntityDeserializationError::MalformedPayload);
+    }
+    Ok(entity)
+}
+
+// The variance of the noise is written at the end of the payload of the entities which simulate
+// it.
+fn write_noise<W: Write>(writer: W, noise: Variance) -> bincode::Result<()> {
+    bincode::serialize_into(writer, &noise.0)
+}
+
+fn read_noise<R: Read>(reader: R) -> Option<Variance> {
+    let noise: f64 = bincode::deserialize_from(reader).ok()?;
+    if noise.is_finite() && noise >= 0. {
+        Some(Variance(noise))
+    } else {
+        None
+    }
+}
+
+// Returns the polynomial size of the parameters, if it is set and not null.
+fn polynomial_size(parameters: &EntityParameters) -> Option<PolynomialSize> {
+    parameters.polynomial_size.filter(|size| size.0 != 0)
+}
+
+macro_rules! implement_secret_keys {
+    ($lwe: ident, $glwe: ident, $flavor: ident, $precision: ident) => {
+        // The secret keys of the cleartext backend hold no data: their payload is empty.
+        impl SerializableEntity for $lwe {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweSecretKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, _writer: W) -> bincode::Result<()> {
+                Ok(())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, _reader: R) -> Option<Self> {
+                Some($lwe {
+                    lwe_dimension: parameters.lwe_dimension?,
+                })
+            }
+        }
+
+        impl SerializableEntity for $glwe {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweSecretKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, _writer: W) -> bincode::Result<()> {
+                Ok(())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, _reader: R) -> Option<Self> {
+                Some($glwe {
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: polynomial_size(parameters)?,
+                })
+            }
+        }
+    };
+}
+implement_secret_keys!(
+    CleartextLweSecretKey32,
+    CleartextGlweSecretKey32,
+    Binary,
+    U32
+);
+implement_secret_keys!(
+    CleartextLweSecretKey64,
+    CleartextGlweSecretKey64,
+    Binary,
+    U64
+);
+implement_secret_keys!(
+    CleartextLweTernarySecretKey32,
+    CleartextGlweTernarySecretKey32,
+    Ternary,
+    U32
+);
+implement_secret_keys!(
+    CleartextLweTernarySecretKey64,
+    CleartextGlweTernarySecretKey64,
+    Ternary,
+    U64
+);
+implement_secret_keys!(
+    CleartextLweGaussianSecretKey32,
+    CleartextGlweGaussianSecretKey32,
+    Gaussian,
+    U32
+);
+implement_secret_keys!(
+    CleartextLweGaussianSecretKey64,
+    CleartextGlweGaussianSecretKey64,
+    Gaussian,
+    U64
+);
+
+macro_rules! implement_lwe_ciphertexts {
+    (
+        $ciphertext: ident,
+        $vector: ident,
+        $kind: ident,
+        $vector_kind: ident,
+        $flavor: ident,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $ciphertext {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &[self.phase])
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, 1)?;
+                Some($ciphertext {
+                    phase: vec[0],
+                    lwe_dimension: parameters.lwe_dimension?,
+                })
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$vector_kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension),
+                    count: Some(self.phases.len()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &self.phases)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($vector {
+                    phases: read_vec(reader, parameters.count?)?,
+                    lwe_dimension: parameters.lwe_dimension?,
+                })
+            }
+        }
+    };
+}
+implement_lwe_ciphertexts!(
+    CleartextLweCiphertext32,
+    CleartextLweCiphertextVector32,
+    LweCiphertext,
+    LweCiphertextVector,
+    Binary,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(
+    CleartextLweCiphertext64,
+    CleartextLweCiphertextVector64,
+    LweCiphertext,
+    LweCiphertextVector,
+    Binary,
+    u64,
+    U64
+);
+implement_lwe_ciphertexts!(
+    CleartextLweTernaryCiphertext32,
+    CleartextLweTernaryCiphertextVector32,
+    LweCiphertext,
+    LweCiphertextVector,
+    Ternary,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(
+    CleartextLweTernaryCiphertext64,
+    CleartextLweTernaryCiphertextVector64,
+    LweCiphertext,
+    LweCiphertextVector,
+    Ternary,
+    u64,
+    U64
+);
+implement_lwe_ciphertexts!(
+    CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertextVector32,
+    LweCiphertext,
+    LweCiphertextVector,
+    Gaussian,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(
+    CleartextLweGaussianCiphertext64,
+    CleartextLweGaussianCiphertextVector64,
+    LweCiphertext,
+    LweCiphertextVector,
+    Gaussian,
+    u64,
+    U64
+);
+implement_lwe_ciphertexts!(
+    CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertextVector32,
+    LweSeededCiphertext,
+    LweSeededCiphertextVector,
+    Binary,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(
+    CleartextLweSeededCiphertext64,
+    CleartextLweSeededCiphertextVector64,
+    LweSeededCiphertext,
+    LweSeededCiphertextVector,
+    Binary,
+    u64,
+    U64
+);
+
+impl SerializableEntity for CleartextLweCompressedCiphertext64 {
+    const KIND: SerializedEntityKind = SerializedEntityKind::LweCompressedCiphertext;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+    const PRECISION: SerializedPrecision = SerializedPrecision::U64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters {
+            lwe_dimension: Some(self.lwe_dimension),
+            ..Default::default()
+        }
+    }
+
+    fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(&mut writer, &self.modulus_log)?;
+        write_slice(writer, &[self.compressed_phase])
+    }
+
+    fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+        let modulus_log = read_modulus_log(&mut reader)?;
+        let vec: Vec<u32> = read_vec(reader, 1)?;
+        Some(CleartextLweCompressedCiphertext64 {
+            compressed_phase: vec[0],
+            lwe_dimension: parameters.lwe_dimension?,
+            modulus_log,
+        })
+    }
+}
+
+impl SerializableEntity for CleartextLweCompressedCiphertextVector64 {
+    const KIND: SerializedEntityKind = SerializedEntityKind::LweCompressedCiphertextVector;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+    const PRECISION: SerializedPrecision = SerializedPrecision::U64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters {
+            lwe_dimension: Some(self.lwe_dimension),
+            count: Some(self.compressed_phases.len()),
+            ..Default::default()
+        }
+    }
+
+    fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(&mut writer, &self.modulus_log)?;
+        write_slice(writer, &self.compressed_phases)
+    }
+
+    fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+        let modulus_log = read_modulus_log(&mut reader)?;
+        Some(CleartextLweCompressedCiphertextVector64 {
+            compressed_phases: read_vec(reader, parameters.count?)?,
+            lwe_dimension: parameters.lwe_dimension?,
+            modulus_log,
+        })
+    }
+}
+
+macro_rules! implement_glwe_ciphertexts {
+    (
+        $ciphertext: ident,
+        $vector: ident,
+        $kind: ident,
+        $vector_kind: ident,
+        $flavor: ident,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $ciphertext {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(PolynomialSize(self.phases.len())),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &self.phases)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($ciphertext {
+                    phases: read_vec(reader, polynomial_size(parameters)?.0)?,
+                    glwe_dimension: parameters.glwe_dimension?,
+                })
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$vector_kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    count: Some(self.phases.len() / self.polynomial_size.0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &self.phases)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let poly_size = polynomial_size(parameters)?;
+                let len = checked_product(&[parameters.count?, poly_size.0])?;
+                Some($vector {
+                    phases: read_vec(reader, len)?,
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: poly_size,
+                })
+            }
+        }
+    };
+}
+implement_glwe_ciphertexts!(
+    CleartextGlweCiphertext32,
+    CleartextGlweCiphertextVector32,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Binary,
+    U32
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweCiphertext64,
+    CleartextGlweCiphertextVector64,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Binary,
+    U64
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweTernaryCiphertext32,
+    CleartextGlweTernaryCiphertextVector32,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Ternary,
+    U32
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector64,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Ternary,
+    U64
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweGaussianCiphertext32,
+    CleartextGlweGaussianCiphertextVector32,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Gaussian,
+    U32
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector64,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    Gaussian,
+    U64
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweSeededCiphertext32,
+    CleartextGlweSeededCiphertextVector32,
+    GlweSeededCiphertext,
+    GlweSeededCiphertextVector,
+    Binary,
+    U32
+);
+implement_glwe_ciphertexts!(
+    CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector64,
+    GlweSeededCiphertext,
+    GlweSeededCiphertextVector,
+    Binary,
+    U64
+);
+
+macro_rules! implement_ggsw_ciphertexts {
+    ($ggsw: ident, $fourier: ident, $vector: ident, $scalar: ty, $precision: ident) => {
+        implement_ggsw_ciphertexts!(@single $ggsw, GgswCiphertext, $scalar, $precision);
+        implement_ggsw_ciphertexts!(@single $fourier, FourierGgswCiphertext, $scalar, $precision);
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GgswCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    count: Some(self.plaintexts.len()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+                write_slice(&mut writer, &self.plaintexts)?;
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+                Some($vector {
+                    plaintexts: read_vec(&mut reader, parameters.count?)?,
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: polynomial_size(parameters)?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+    (@single $ggsw: ident, $kind: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $ggsw {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+                write_slice(&mut writer, &[self.plaintext])?;
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(&mut reader, 1)?;
+                Some($ggsw {
+                    plaintext: vec[0],
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: polynomial_size(parameters)?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_ggsw_ciphertexts!(
+    CleartextGgswCiphertext32,
+    CleartextFourierGgswCiphertext32,
+    CleartextGgswCiphertextVector32,
+    u32,
+    U32
+);
+implement_ggsw_ciphertexts!(
+    CleartextGgswCiphertext64,
+    CleartextFourierGgswCiphertext64,
+    CleartextGgswCiphertextVector64,
+    u64,
+    U64
+);
+
+// The evaluation keys of the cleartext backend only hold their parameters, and the variance of
+// the noise they would have been encrypted with.
+
+macro_rules! implement_keyswitch_key {
+    ($ksk: ident, $output_flavor: ident, $precision: ident) => {
+        impl SerializableEntity for $ksk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweKeyswitchKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::$output_flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension),
+                    output_lwe_dimension: Some(self.output_lwe_dimension),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($ksk {
+                    input_lwe_dimension: parameters.lwe_dimension?,
+                    output_lwe_dimension: parameters.output_lwe_dimension?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_keyswitch_key!(CleartextLweKeyswitchKey32, Binary, U32);
+implement_keyswitch_key!(CleartextLweKeyswitchKey64, Binary, U64);
+implement_keyswitch_key!(CleartextLweBinaryToTernaryKeyswitchKey32, Ternary, U32);
+implement_keyswitch_key!(CleartextLweBinaryToTernaryKeyswitchKey64, Ternary, U64);
+implement_keyswitch_key!(CleartextLweBinaryToGaussianKeyswitchKey32, Gaussian, U32);
+implement_keyswitch_key!(CleartextLweBinaryToGaussianKeyswitchKey64, Gaussian, U64);
+
+macro_rules! implement_bootstrap_key {
+    ($bsk: ident, $kind: ident, $input_flavor: ident, $precision: ident) => {
+        impl SerializableEntity for $bsk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::$input_flavor);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension),
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($bsk {
+                    input_lwe_dimension: parameters.lwe_dimension?,
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: polynomial_size(parameters)?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_bootstrap_key!(CleartextLweBootstrapKey32, LweBootstrapKey, Binary, U32);
+implement_bootstrap_key!(CleartextLweBootstrapKey64, LweBootstrapKey, Binary, U64);
+implement_bootstrap_key!(
+    CleartextLweTernaryBootstrapKey32,
+    LweBootstrapKey,
+    Ternary,
+    U32
+);
+implement_bootstrap_key!(
+    CleartextLweTernaryBootstrapKey64,
+    LweBootstrapKey,
+    Ternary,
+    U64
+);
+implement_bootstrap_key!(
+    CleartextLweGaussianBootstrapKey32,
+    LweBootstrapKey,
+    Gaussian,
+    U32
+);
+implement_bootstrap_key!(
+    CleartextLweGaussianBootstrapKey64,
+    LweBootstrapKey,
+    Gaussian,
+    U64
+);
+implement_bootstrap_key!(
+    CleartextFourierLweBootstrapKey32,
+    FourierLweBootstrapKey,
+    Binary,
+    U32
+);
+implement_bootstrap_key!(
+    CleartextFourierLweBootstrapKey64,
+    FourierLweBootstrapKey,
+    Binary,
+    U64
+);
+implement_bootstrap_key!(
+    CleartextFourierLweTernaryBootstrapKey32,
+    FourierLweBootstrapKey,
+    Ternary,
+    U32
+);
+implement_bootstrap_key!(
+    CleartextFourierLweTernaryBootstrapKey64,
+    FourierLweBootstrapKey,
+    Ternary,
+    U64
+);
+implement_bootstrap_key!(
+    CleartextFourierLweGaussianBootstrapKey32,
+    FourierLweBootstrapKey,
+    Gaussian,
+    U32
+);
+implement_bootstrap_key!(
+    CleartextFourierLweGaussianBootstrapKey64,
+    FourierLweBootstrapKey,
+    Gaussian,
+    U64
+);
+
+macro_rules! implement_public_key {
+    ($public_key: ident, $precision: ident) => {
+        impl SerializableEntity for $public_key {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LwePublicKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension),
+                    count: Some(self.lwe_zero_encryption_count.0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($public_key {
+                    lwe_dimension: parameters.lwe_dimension?,
+                    lwe_zero_encryption_count: LwePublicKeyZeroEncryptionCount(parameters.count?),
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_public_key!(CleartextLwePublicKey32, U32);
+implement_public_key!(CleartextLwePublicKey64, U64);
+
+macro_rules! implement_packing_keyswitch_keys {
+    ($pksk: ident, $pfpksk: ident, $cbs_keys: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $pksk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::PackingKeyswitchKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension),
+                    glwe_dimension: Some(self.output_glwe_dimension),
+                    polynomial_size: Some(self.output_polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($pksk {
+                    input_lwe_dimension: parameters.lwe_dimension?,
+                    output_glwe_dimension: parameters.glwe_dimension?,
+                    output_polynomial_size: polynomial_size(parameters)?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+
+        // The polynomial of the linear map is written before the variance of the noise.
+        impl SerializableEntity for $pfpksk {
+            const KIND: SerializedEntityKind =
+                SerializedEntityKind::PrivateFunctionalPackingKeyswitchKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension),
+                    glwe_dimension: Some(self.output_glwe_dimension),
+                    polynomial_size: Some(PolynomialSize(self.polynomial.len())),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+                write_slice(&mut writer, &self.polynomial)?;
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+                let polynomial: Vec<$scalar> =
+                    read_vec(&mut reader, polynomial_size(parameters)?.0)?;
+                Some($pfpksk {
+                    polynomial,
+                    input_lwe_dimension: parameters.lwe_dimension?,
+                    output_glwe_dimension: parameters.glwe_dimension?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+
+        impl SerializableEntity for $cbs_keys {
+            const KIND: SerializedEntityKind =
+                SerializedEntityKind::CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension),
+                    glwe_dimension: Some(self.output_glwe_dimension),
+                    polynomial_size: Some(self.output_polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($cbs_keys {
+                    input_lwe_dimension: parameters.lwe_dimension?,
+                    output_glwe_dimension: parameters.glwe_dimension?,
+                    output_polynomial_size: polynomial_size(parameters)?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_packing_keyswitch_keys!(
+    CleartextPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    u32,
+    U32
+);
+implement_packing_keyswitch_keys!(
+    CleartextPackingKeyswitchKey64,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    u64,
+    U64
+);
+
+macro_rules! implement_glwe_relinearization_key {
+    ($rlk: ident, $precision: ident) => {
+        impl SerializableEntity for $rlk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweRelinearizationKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension),
+                    polynomial_size: Some(self.polynomial_size),
+                    decomposition_base_log: Some(self.decomposition_base_log),
+                    decomposition_level_count: Some(self.decomposition_level_count),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_noise(writer, self.noise)
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                Some($rlk {
+                    glwe_dimension: parameters.glwe_dimension?,
+                    polynomial_size: polynomial_size(parameters)?,
+                    decomposition_level_count: parameters.decomposition_level_count?,
+                    decomposition_base_log: parameters.decomposition_base_log?,
+                    noise: read_noise(reader)?,
+                })
+            }
+        }
+    };
+}
+implement_glwe_relinearization_key!(CleartextGlweRelinearizationKey32, U32);
+implement_glwe_relinearization_key!(CleartextGlweRelinearizationKey64, U64);
+
+macro_rules! implement_entity_serialization {
+    ($($(#[$meta: meta])* $entity: ident),* $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntitySerializationEngine`] for [`CleartextEngine`] that \
+                serializes [`",
+                stringify!($entity),
+                "`] entities into byte buffers."
+            )]
+            ///
+            /// The buffer contains a marker identifying the cleartext backend and the version of
+            /// the format, followed by the header used by the `core` backend, and by the phases
+            /// of the entity along with the variance of its simulated noise, if any.
+            $(#[$meta])*
+            impl EntitySerializationEngine<$entity, Vec<u8>> for CleartextEngine {
+                fn serialize(
+                    &mut self,
+                    entity: &$entity,
+                ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+                    Ok(unsafe { self.serialize_unchecked(entity) })
+                }
+
+                unsafe fn serialize_unchecked(&mut self, entity: &$entity) -> Vec<u8> {
+                    serialize_entity(entity)
+                }
+            }
+        )*
+    };
+}
+implement_entity_serialization!(
+    Plaintext32,
+    Plaintext64,
+    PlaintextVector32,
+    PlaintextVector64,
+    Cleartext32,
+    Cleartext64,
+    CleartextF64,
+    CleartextVector32,
+    CleartextVector64,
+    CleartextVectorF64,
+    FloatEncoder,
+    FloatEncoderVector,
+    CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+    CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64,
+    CleartextLweGaussianSecretKey32,
+    CleartextLweGaussianSecretKey64,
+    CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+    CleartextGlweTernarySecretKey32,
+    CleartextGlweTernarySecretKey64,
+    CleartextGlweGaussianSecretKey32,
+    CleartextGlweGaussianSecretKey64,
+    CleartextLweCiphertext32,
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let serialized: Vec<u8> = engine.serialize(&ciphertext)?;
+    /// let deserialized: CleartextLweCiphertext64 = engine.deserialize(serialized.as_slice())?;
+    /// #
+    /// assert_eq!(deserialized, ciphertext);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(deserialized)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    CleartextLweCiphertext64,
+    CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64,
+    CleartextLweTernaryCiphertext32,
+    CleartextLweTernaryCiphertext64,
+    CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64,
+    CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertext64,
+    CleartextLweGaussianCiphertextVector32,
+    CleartextLweGaussianCiphertextVector64,
+    CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64,
+    CleartextLweSeededCiphertextVector32,
+    CleartextLweSeededCiphertextVector64,
+    CleartextGlweCiphertext32,
+    CleartextGlweCiphertext64,
+    CleartextGlweCiphertextVector32,
+    CleartextGlweCiphertextVector64,
+    CleartextGlweTernaryCiphertext32,
+    CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernaryCiphertextVector32,
+    CleartextGlweTernaryCiphertextVector64,
+    CleartextGlweGaussianCiphertext32,
+    CleartextGlweGaussianCiphertext64,
+    CleartextGlweGaussianCiphertextVector32,
+    CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweSeededCiphertext32,
+    CleartextGlweSeededCiphertext64,
+    CleartextGlweSeededCiphertextVector32,
+    CleartextGlweSeededCiphertextVector64,
+    CleartextGgswCiphertext32,
+    CleartextGgswCiphertext64,
+    CleartextGgswCiphertextVector32,
+    CleartextGgswCiphertextVector64,
+    CleartextFourierGgswCiphertext32,
+    CleartextFourierGgswCiphertext64,
+    CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32,
+    CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweBinaryToGaussianKeyswitchKey32,
+    CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBootstrapKey32,
+    CleartextLweBootstrapKey64,
+    CleartextLweTernaryBootstrapKey32,
+    CleartextLweTernaryBootstrapKey64,
+    CleartextLweGaussianBootstrapKey32,
+    CleartextLweGaussianBootstrapKey64,
+    CleartextFourierLweBootstrapKey32,
+    CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32,
+    CleartextFourierLweTernaryBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32,
+    CleartextFourierLweGaussianBootstrapKey64,
+    CleartextLwePublicKey32,
+    CleartextLwePublicKey64,
+    CleartextPackingKeyswitchKey32,
+    CleartextPackingKeyswitchKey64,
+    CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextGlweRelinearizationKey32,
+    CleartextGlweRelinearizationKey64,
+    CleartextLweCompressedCiphertext64,
+    CleartextLweCompressedCiphertextVector64,
+);
@@ -0,0 +1,152 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64, CleartextGgswCiphertext32,
+    CleartextGgswCiphertext64,
+};
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextConversionError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CleartextEngine`] that operates on 32
+/// bits integers. It converts a GGSW ciphertext from the standard to the Fourier domain.
+///
+/// The ciphertext holds the same plaintext, parameters and noise in both domains, which are copied.
+impl GgswCiphertextConversionEngine<CleartextGgswCiphertext32, CleartextFourierGgswCiphertext32>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let fourier_ciphertext: CleartextFourierGgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &CleartextGgswCiphertext32,
+    ) -> Result<CleartextFourierGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGgswCiphertext32,
+    ) -> CleartextFourierGgswCiphertext32 {
+        CleartextFourierGgswCiphertext32 {
+            plaintext: input.plaintext,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_level_count: input.decomposition_level_count,
+            decomposition_base_log: input.decomposition_base_log,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CleartextEngine`] that operates on 64
+/// bits integers. It converts a GGSW ciphertext from the standard to the Fourier domain.
+///
+/// The ciphertext holds the same plaintext, parameters and noise in both domains, which are copied.
+impl GgswCiphertextConversionEngine<CleartextGgswCiphertext64, CleartextFourierGgswCiphertext64>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let fourier_ciphertext: CleartextFourierGgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &CleartextGgswCiphertext64,
+    ) -> Result<CleartextFourierGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGgswCiphertext64,
+    ) -> CleartextFourierGgswCiphertext64 {
+        CleartextFourierGgswCiphertext64 {
+            plaintext: input.plaintext,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_level_count: input.decomposition_level_count,
+            decomposition_base_log: input.decomposition_base_log,
+            noise: input.noise,
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    GgswCiphertextScalarDiscardingEncryptionEngine, GgswCiphertextScalarDiscardingEncryptionError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarDiscardingEncryptionEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+///
+/// The ciphertext holds the plaintext along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextScalarDiscardingEncryptionEngine<
+        CleartextGlweSecretKey32,
+        Plaintext32,
+        CleartextGgswCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGgswCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextScalarDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_encrypt_scalar_ggsw_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGgswCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        output.plaintext = input.0 .0;
+        output.noise = noise;
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarDiscardingEncryptionEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+///
+/// The ciphertext holds the plaintext along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextScalarDiscardingEncryptionEngine<
+        CleartextGlweSecretKey64,
+        Plaintext64,
+        CleartextGgswCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGgswCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextScalarDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_encrypt_scalar_ggsw_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGgswCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        output.plaintext = input.0 .0;
+        output.noise = noise;
+    }
+}
@@ -0,0 +1,210 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    GgswCiphertextScalarEncryptionEngine, GgswCiphertextScalarEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The ciphertext holds the plaintext along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextScalarEncryptionEngine<
+        CleartextGlweSecretKey32,
+        Plaintext32,
+        CleartextGgswCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CleartextGgswCiphertext32, GgswCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GgswCiphertextScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CleartextGgswCiphertext32 {
+        CleartextGgswCiphertext32 {
+            plaintext: input.0 .0,
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The ciphertext holds the plaintext along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextScalarEncryptionEngine<
+        CleartextGlweSecretKey64,
+        Plaintext64,
+        CleartextGgswCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CleartextGgswCiphertext64, GgswCiphertextScalarEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GgswCiphertextScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CleartextGgswCiphertext64 {
+        CleartextGgswCiphertext64 {
+            plaintext: input.0 .0,
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
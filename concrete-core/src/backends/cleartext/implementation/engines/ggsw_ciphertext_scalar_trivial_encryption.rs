@@ -0,0 +1,204 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    GgswCiphertextScalarTrivialEncryptionEngine, GgswCiphertextScalarTrivialEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarTrivialEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The ciphertext holds the plaintext along with a null variance.
+impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext32, CleartextGgswCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: CleartextGgswCiphertext32 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_size,
+    ///     level,
+    ///     base_log,
+    ///     &plaintext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext32,
+    ) -> Result<
+        CleartextGgswCiphertext32,
+        GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext32,
+    ) -> CleartextGgswCiphertext32 {
+        CleartextGgswCiphertext32 {
+            plaintext: input.0 .0,
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+            noise: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarTrivialEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The ciphertext holds the plaintext along with a null variance.
+impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext64, CleartextGgswCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: CleartextGgswCiphertext64 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_size,
+    ///     level,
+    ///     base_log,
+    ///     &plaintext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext64,
+    ) -> Result<
+        CleartextGgswCiphertext64,
+        GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext64,
+    ) -> CleartextGgswCiphertext64 {
+        CleartextGgswCiphertext64 {
+            plaintext: input.0 .0,
+            glwe_dimension: glwe_size.to_glwe_dimension(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+            noise: Variance(0.),
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGgswCiphertextVector32, CleartextGgswCiphertextVector64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GgswCiphertextVectorScalarEncryptionEngine, GgswCiphertextVectorScalarEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The ciphertexts hold the plaintexts along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGgswCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = vec![3_u32; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(
+    ///     ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        CleartextGgswCiphertextVector32,
+        GgswCiphertextVectorScalarEncryptionError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CleartextGgswCiphertextVector32 {
+        CleartextGgswCiphertextVector32 {
+            plaintexts: input.0.as_tensor().iter().copied().collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The ciphertexts hold the plaintexts along with the given variance, which is used to predict the
+/// noise of the external products.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGgswCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = vec![3_u64; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(
+    ///     ciphertext_vector.ggsw_ciphertext_count(),
+    ///     GgswCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<
+        CleartextGgswCiphertextVector64,
+        GgswCiphertextVectorScalarEncryptionError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CleartextGgswCiphertextVector64 {
+        CleartextGgswCiphertextVector64 {
+            plaintexts: input.0.as_tensor().iter().copied().collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextConsumingRetrievalEngine, GlweCiphertextConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 32 bits integers of a GLWE ciphertext.
+///
+/// The container follows the layout of the `core` backend, with null mask polynomials and the phase
+/// polynomial of the ciphertext as body, i.e. it holds a trivial encryption of the phases.
+impl GlweCiphertextConsumingRetrievalEngine<CleartextGlweCiphertext32, Vec<u32>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: CleartextGlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     glwe_dimension.to_glwe_size().0 * polynomial_size.0
+    /// );
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: CleartextGlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext(
+        &mut self,
+        ciphertext: CleartextGlweCiphertext32,
+    ) -> Result<Vec<u32>, GlweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: CleartextGlweCiphertext32,
+    ) -> Vec<u32> {
+        let mut container = vec![0; ciphertext.glwe_dimension.0 * ciphertext.phases.len()];
+        container.extend(ciphertext.phases);
+        container
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 64 bits integers of a GLWE ciphertext.
+///
+/// The container follows the layout of the `core` backend, with null mask polynomials and the phase
+/// polynomial of the ciphertext as body, i.e. it holds a trivial encryption of the phases.
+impl GlweCiphertextConsumingRetrievalEngine<CleartextGlweCiphertext64, Vec<u64>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = vec![3_u64 << 52; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: CleartextGlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     glwe_dimension.to_glwe_size().0 * polynomial_size.0
+    /// );
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: CleartextGlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext(
+        &mut self,
+        ciphertext: CleartextGlweCiphertext64,
+    ) -> Result<Vec<u64>, GlweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: CleartextGlweCiphertext64,
+    ) -> Vec<u64> {
+        let mut container = vec![0; ciphertext.glwe_dimension.0 * ciphertext.phases.len()];
+        container.extend(ciphertext.phases);
+        container
+    }
+}
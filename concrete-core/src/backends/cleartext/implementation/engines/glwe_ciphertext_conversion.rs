@@ -0,0 +1,136 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits GLWE ciphertext to a 64 bits GLWE ciphertext. The torus elements are exactly rescaled to
+/// the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl GlweCiphertextConversionEngine<CleartextGlweCiphertext32, CleartextGlweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextGlweCiphertext64 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &CleartextGlweCiphertext32,
+    ) -> Result<CleartextGlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGlweCiphertext32,
+    ) -> CleartextGlweCiphertext64 {
+        CleartextGlweCiphertext64 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            glwe_dimension: input.glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits GLWE ciphertext to a 32 bits GLWE ciphertext. The torus elements are rounded to the closest
+/// element of the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl GlweCiphertextConversionEngine<CleartextGlweCiphertext64, CleartextGlweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextGlweCiphertext32 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &CleartextGlweCiphertext64,
+    ) -> Result<CleartextGlweCiphertext32, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGlweCiphertext64,
+    ) -> CleartextGlweCiphertext32 {
+        CleartextGlweCiphertext32 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            glwe_dimension: input.glwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweCiphertextMutView32,
+    CleartextGlweCiphertextMutView64, CleartextGlweCiphertextView32, CleartextGlweCiphertextView64,
+};
+use crate::specification::engines::{GlweCiphertextCreationEngine, GlweCiphertextCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns a GLWE
+/// ciphertext, taking ownership of a vector of 32 bits integers.
+///
+/// The mask polynomials of the container are ignored, and its body polynomial is taken as the phase
+/// polynomial of the ciphertext.
+impl GlweCiphertextCreationEngine<Vec<u32>, CleartextGlweCiphertext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext: CleartextGlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertext32, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertext32 {
+        let mut container = container;
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertext32 {
+            phases: container.split_off(glwe_dimension.0 * polynomial_size.0),
+            glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns a GLWE
+/// ciphertext, taking ownership of a vector of 64 bits integers.
+///
+/// The mask polynomials of the container are ignored, and its body polynomial is taken as the phase
+/// polynomial of the ciphertext.
+impl GlweCiphertextCreationEngine<Vec<u64>, CleartextGlweCiphertext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext: CleartextGlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertext64, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertext64 {
+        let mut container = container;
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertext64 {
+            phases: container.split_off(glwe_dimension.0 * polynomial_size.0),
+            glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the
+/// view of a GLWE ciphertext, borrowing an immutable slice of 32 bits integers.
+///
+/// Only the body polynomial of the slice, which holds the phase polynomial of the ciphertext, is
+/// viewed.
+impl<'a> GlweCiphertextCreationEngine<&'a [u32], CleartextGlweCiphertextView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextGlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a [u32],
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertextView32<'a>, GlweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u32],
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertextView32<'a> {
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertextView32 {
+            phases: &container[glwe_dimension.0 * polynomial_size.0..],
+            glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the
+/// view of a GLWE ciphertext, borrowing an immutable slice of 64 bits integers.
+///
+/// Only the body polynomial of the slice, which holds the phase polynomial of the ciphertext, is
+/// viewed.
+impl<'a> GlweCiphertextCreationEngine<&'a [u64], CleartextGlweCiphertextView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextGlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a [u64],
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertextView64<'a>, GlweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u64],
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertextView64<'a> {
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertextView64 {
+            phases: &container[glwe_dimension.0 * polynomial_size.0..],
+            glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the
+/// view of a GLWE ciphertext, borrowing a mutable slice of 32 bits integers.
+///
+/// Only the body polynomial of the slice, which holds the phase polynomial of the ciphertext, is
+/// viewed.
+impl<'a> GlweCiphertextCreationEngine<&'a mut [u32], CleartextGlweCiphertextMutView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let mut container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextGlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a mut [u32],
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertextMutView32<'a>, GlweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertextMutView32<'a> {
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertextMutView32 {
+            phases: &mut container[glwe_dimension.0 * polynomial_size.0..],
+            glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the
+/// view of a GLWE ciphertext, borrowing a mutable slice of 64 bits integers.
+///
+/// Only the body polynomial of the slice, which holds the phase polynomial of the ciphertext, is
+/// viewed.
+impl<'a> GlweCiphertextCreationEngine<&'a mut [u64], CleartextGlweCiphertextMutView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let mut container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextGlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a mut [u64],
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweCiphertextMutView64<'a>, GlweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweCiphertextMutView64<'a> {
+        let glwe_dimension = GlweDimension(container.len() / polynomial_size.0 - 1);
+        CleartextGlweCiphertextMutView64 {
+            phases: &mut container[glwe_dimension.0 * polynomial_size.0..],
+            glwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweGaussianCiphertext32,
+    CleartextGlweGaussianCiphertext64, CleartextGlweGaussianSecretKey32,
+    CleartextGlweGaussianSecretKey64, CleartextGlweSecretKey32, CleartextGlweSecretKey64,
+    CleartextGlweTernaryCiphertext32, CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+use crate::specification::entities::{GlweCiphertextEntity, GlweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweCiphertext32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector, plaintext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &CleartextGlweCiphertext32,
+    ) -> Result<PlaintextVector32, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        input: &CleartextGlweCiphertext32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweCiphertext64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector, plaintext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &CleartextGlweCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        input: &CleartextGlweCiphertext64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweTernarySecretKey32,
+        CleartextGlweTernaryCiphertext32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &CleartextGlweTernaryCiphertext32,
+    ) -> Result<PlaintextVector32, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweTernarySecretKey32,
+        input: &CleartextGlweTernaryCiphertext32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweTernarySecretKey64,
+        CleartextGlweTernaryCiphertext64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &CleartextGlweTernaryCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweTernarySecretKey64,
+        input: &CleartextGlweTernaryCiphertext64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweGaussianSecretKey32,
+        CleartextGlweGaussianCiphertext32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &CleartextGlweGaussianCiphertext32,
+    ) -> Result<PlaintextVector32, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweGaussianSecretKey32,
+        input: &CleartextGlweGaussianCiphertext32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    GlweCiphertextDecryptionEngine<
+        CleartextGlweGaussianSecretKey64,
+        CleartextGlweGaussianCiphertext64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     plaintext_vector.plaintext_count()
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &CleartextGlweGaussianCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweGaussianSecretKey64,
+        input: &CleartextGlweGaussianCiphertext64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
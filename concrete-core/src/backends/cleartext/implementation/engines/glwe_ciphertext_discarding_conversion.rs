@@ -0,0 +1,153 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingConversionEngine, GlweCiphertextDiscardingConversionError,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits GLWE ciphertext to a 64 bits GLWE ciphertext. The torus elements are exactly
+/// rescaled to the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl GlweCiphertextDiscardingConversionEngine<CleartextGlweCiphertext32, CleartextGlweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_convert_glwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextGlweCiphertext32,
+    ) -> Result<(), GlweCiphertextDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextGlweCiphertext32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = input_phase.cast_torus_into();
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits GLWE ciphertext to a 32 bits GLWE ciphertext. The torus elements are rounded
+/// to the closest element of the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl GlweCiphertextDiscardingConversionEngine<CleartextGlweCiphertext64, CleartextGlweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_convert_glwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextGlweCiphertext64,
+    ) -> Result<(), GlweCiphertextDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextGlweCiphertext64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = input_phase.cast_torus_into();
+        }
+    }
+}
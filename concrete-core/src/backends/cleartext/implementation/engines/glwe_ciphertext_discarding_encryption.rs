@@ -0,0 +1,344 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweCiphertextMutView32,
+    CleartextGlweCiphertextMutView64, CleartextGlweSecretKey32, CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextDiscardingEncryptionError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of each coefficient of the output ciphertext is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl
+    GlweCiphertextDiscardingEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key_1: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
+    /// // We're going to re-encrypt the input with another secret key
+    /// // For this, it is required that the second secret key uses the same GLWE dimension
+    /// // and polynomial size as the first one.
+    /// let key_2: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(&key_2, &mut ciphertext, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertext32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of each coefficient of the output ciphertext is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl
+    GlweCiphertextDiscardingEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 4];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key_1: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext = engine.encrypt_glwe_ciphertext(&key_1, &plaintext_vector, noise)?;
+    /// // We're going to re-encrypt the input with another secret key
+    /// // For this, it is required that the second secret key uses the same GLWE dimension
+    /// // and polynomial size as the first one.
+    /// let key_2: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(&key_2, &mut ciphertext, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertext64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of each coefficient of the output ciphertext is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl<'a>
+    GlweCiphertextDiscardingEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweCiphertextMutView32<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_view: CleartextGlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(&key, &mut ciphertext_view, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertextMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertextMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of each coefficient of the output ciphertext is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl<'a>
+    GlweCiphertextDiscardingEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweCiphertextMutView64<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_view: CleartextGlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(&key, &mut ciphertext_view, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertextMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertextMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweRelinearizationKey32,
+    CleartextGlweRelinearizationKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    GlweCiphertextDiscardingMultiplicationEngine, GlweCiphertextDiscardingMultiplicationError,
+};
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The product of the phase polynomials of the inputs is divided by the scaling factor, as in the
+/// tensor product of the `core` backend, and a noise is added with the variance predicted by
+/// `concrete-npe` for the roundings of the tensor product and the relinearization.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        CleartextGlweCiphertext32,
+        CleartextGlweCiphertext32,
+        CleartextGlweRelinearizationKey32,
+        Plaintext32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(4);
+    /// let decomposition_base_log = DecompositionBaseLog(6);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input_1 = vec![3_u32 << 28; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 28; polynomial_size.0];
+    /// let delta = 1_u32 << 28;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: CleartextGlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     &delta,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(rlk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input_1: &CleartextGlweCiphertext32,
+        input_2: &CleartextGlweCiphertext32,
+        rlk: &CleartextGlweRelinearizationKey32,
+        delta: &Plaintext32,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != output.glwe_dimension()
+            || input_1.glwe_dimension() != rlk.glwe_dimension()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != output.polynomial_size()
+            || input_1.polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::PolynomialSizeMismatch);
+        }
+        if !delta.0 .0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingMultiplicationError::InvalidScalingFactor);
+        }
+        unsafe { self.discard_mul_glwe_ciphertext_unchecked(output, input_1, input_2, rlk, delta) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input_1: &CleartextGlweCiphertext32,
+        input_2: &CleartextGlweCiphertext32,
+        rlk: &CleartextGlweRelinearizationKey32,
+        delta: &Plaintext32,
+    ) {
+        output.phases =
+            self.multiply_phases(&input_1.phases, &input_2.phases, delta.0 .0, rlk, rlk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The product of the phase polynomials of the inputs is divided by the scaling factor, as in the
+/// tensor product of the `core` backend, and a noise is added with the variance predicted by
+/// `concrete-npe` for the roundings of the tensor product and the relinearization.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        CleartextGlweCiphertext64,
+        CleartextGlweCiphertext64,
+        CleartextGlweRelinearizationKey64,
+        Plaintext64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(4);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// // Here a hard-set encoding is applied (shift by 56 bits)
+    /// let input_1 = vec![3_u64 << 56; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 56; polynomial_size.0];
+    /// let delta = 1_u64 << 56;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: CleartextGlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     &delta,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(rlk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input_1: &CleartextGlweCiphertext64,
+        input_2: &CleartextGlweCiphertext64,
+        rlk: &CleartextGlweRelinearizationKey64,
+        delta: &Plaintext64,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != output.glwe_dimension()
+            || input_1.glwe_dimension() != rlk.glwe_dimension()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != output.polynomial_size()
+            || input_1.polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::PolynomialSizeMismatch);
+        }
+        if !delta.0 .0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingMultiplicationError::InvalidScalingFactor);
+        }
+        unsafe { self.discard_mul_glwe_ciphertext_unchecked(output, input_1, input_2, rlk, delta) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input_1: &CleartextGlweCiphertext64,
+        input_2: &CleartextGlweCiphertext64,
+        rlk: &CleartextGlweRelinearizationKey64,
+        delta: &Plaintext64,
+    ) {
+        output.phases =
+            self.multiply_phases(&input_1.phases, &input_2.phases, delta.0 .0, rlk, rlk.noise);
+    }
+}
@@ -0,0 +1,467 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweGaussianCiphertext32,
+    CleartextGlweGaussianCiphertext64, CleartextGlweGaussianSecretKey32,
+    CleartextGlweGaussianSecretKey64, CleartextGlweSecretKey32, CleartextGlweSecretKey64,
+    CleartextGlweTernaryCiphertext32, CleartextGlweTernaryCiphertext64,
+    CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<CleartextGlweCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweCiphertext32 {
+        CleartextGlweCiphertext32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<CleartextGlweCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweCiphertext64 {
+        CleartextGlweCiphertext64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweTernarySecretKey32,
+        PlaintextVector32,
+        CleartextGlweTernaryCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<CleartextGlweTernaryCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>>
+    {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweTernaryCiphertext32 {
+        CleartextGlweTernaryCiphertext32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweTernarySecretKey64,
+        PlaintextVector64,
+        CleartextGlweTernaryCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<CleartextGlweTernaryCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>>
+    {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweTernaryCiphertext64 {
+        CleartextGlweTernaryCiphertext64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweGaussianSecretKey32,
+        PlaintextVector32,
+        CleartextGlweGaussianCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<CleartextGlweGaussianCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>>
+    {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweGaussianCiphertext32 {
+        CleartextGlweGaussianCiphertext32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertext is the corresponding plaintext, plus a
+/// noise sampled with the given variance.
+impl
+    GlweCiphertextEncryptionEngine<
+        CleartextGlweGaussianSecretKey64,
+        PlaintextVector64,
+        CleartextGlweGaussianCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<CleartextGlweGaussianCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>>
+    {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweGaussianCiphertext64 {
+        CleartextGlweGaussianCiphertext64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
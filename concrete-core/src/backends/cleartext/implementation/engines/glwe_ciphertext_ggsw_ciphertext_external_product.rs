@@ -0,0 +1,206 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64, CleartextGlweCiphertext32,
+    CleartextGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextGgswCiphertextExternalProductEngine,
+    GlweCiphertextGgswCiphertextExternalProductError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextExternalProductEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+///
+/// The phases of the input are multiplied by the plaintext of the GGSW ciphertext, and a noise is
+/// added with the variance predicted by `concrete-npe` for the external product.
+impl
+    GlweCiphertextGgswCiphertextExternalProductEngine<
+        CleartextGlweCiphertext32,
+        CleartextFourierGgswCiphertext32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u32;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: CleartextFourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product: CleartextGlweCiphertext32 =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &CleartextGlweCiphertext32,
+        ggsw_input: &CleartextFourierGgswCiphertext32,
+    ) -> Result<
+        CleartextGlweCiphertext32,
+        GlweCiphertextGgswCiphertextExternalProductError<Self::EngineError>,
+    > {
+        if glwe_input.polynomial_size() != ggsw_input.polynomial_size() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension() != ggsw_input.glwe_dimension() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &CleartextGlweCiphertext32,
+        ggsw_input: &CleartextFourierGgswCiphertext32,
+    ) -> CleartextGlweCiphertext32 {
+        CleartextGlweCiphertext32 {
+            phases: self.external_product_phases(
+                &glwe_input.phases,
+                ggsw_input.plaintext,
+                ggsw_input,
+                ggsw_input.noise,
+            ),
+            glwe_dimension: glwe_input.glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextExternalProductEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+///
+/// The phases of the input are multiplied by the plaintext of the GGSW ciphertext, and a noise is
+/// added with the variance predicted by `concrete-npe` for the external product.
+impl
+    GlweCiphertextGgswCiphertextExternalProductEngine<
+        CleartextGlweCiphertext64,
+        CleartextFourierGgswCiphertext64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u64;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: CleartextFourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product: CleartextGlweCiphertext64 =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &CleartextGlweCiphertext64,
+        ggsw_input: &CleartextFourierGgswCiphertext64,
+    ) -> Result<
+        CleartextGlweCiphertext64,
+        GlweCiphertextGgswCiphertextExternalProductError<Self::EngineError>,
+    > {
+        if glwe_input.polynomial_size() != ggsw_input.polynomial_size() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension() != ggsw_input.glwe_dimension() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &CleartextGlweCiphertext64,
+        ggsw_input: &CleartextFourierGgswCiphertext64,
+    ) -> CleartextGlweCiphertext64 {
+        CleartextGlweCiphertext64 {
+            phases: self.external_product_phases(
+                &glwe_input.phases,
+                ggsw_input.plaintext,
+                ggsw_input,
+                ggsw_input.noise,
+            ),
+            glwe_dimension: glwe_input.glwe_dimension,
+        }
+    }
+}
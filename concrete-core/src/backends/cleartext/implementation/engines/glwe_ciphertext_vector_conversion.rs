@@ -0,0 +1,156 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    GlweCiphertextVectorConversionEngine, GlweCiphertextVectorConversionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorConversionEngine`] for [`CleartextEngine`] that converts
+/// a 32 bits GLWE ciphertext vector to a 64 bits GLWE ciphertext vector. The torus elements are
+/// exactly rescaled to the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    GlweCiphertextVectorConversionEngine<
+        CleartextGlweCiphertextVector32,
+        CleartextGlweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 2 * polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextGlweCiphertextVector64 =
+    ///     engine.convert_glwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextGlweCiphertextVector32,
+    ) -> Result<
+        CleartextGlweCiphertextVector64,
+        GlweCiphertextVectorConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextGlweCiphertextVector32,
+    ) -> CleartextGlweCiphertextVector64 {
+        CleartextGlweCiphertextVector64 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorConversionEngine`] for [`CleartextEngine`] that converts
+/// a 64 bits GLWE ciphertext vector to a 32 bits GLWE ciphertext vector. The torus elements are
+/// rounded to the closest element of the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    GlweCiphertextVectorConversionEngine<
+        CleartextGlweCiphertextVector64,
+        CleartextGlweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 2 * polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextGlweCiphertextVector32 =
+    ///     engine.convert_glwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextGlweCiphertextVector64,
+    ) -> Result<
+        CleartextGlweCiphertextVector32,
+        GlweCiphertextVectorConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextGlweCiphertextVector64,
+    ) -> CleartextGlweCiphertextVector32 {
+        CleartextGlweCiphertextVector32 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
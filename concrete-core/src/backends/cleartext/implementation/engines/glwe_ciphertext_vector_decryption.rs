@@ -0,0 +1,445 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweGaussianCiphertextVector32, CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweTernaryCiphertextVector32,
+    CleartextGlweTernaryCiphertextVector64, CleartextGlweTernarySecretKey32,
+    CleartextGlweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    GlweCiphertextVectorDecryptionEngine, GlweCiphertextVectorDecryptionError,
+};
+use crate::specification::entities::{GlweCiphertextVectorEntity, GlweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &CleartextGlweCiphertextVector32,
+    ) -> Result<PlaintextVector32, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        input: &CleartextGlweCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &CleartextGlweCiphertextVector64,
+    ) -> Result<PlaintextVector64, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        input: &CleartextGlweCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweTernarySecretKey32,
+        CleartextGlweTernaryCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &CleartextGlweTernaryCiphertextVector32,
+    ) -> Result<PlaintextVector32, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweTernarySecretKey32,
+        input: &CleartextGlweTernaryCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweTernarySecretKey64,
+        CleartextGlweTernaryCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &CleartextGlweTernaryCiphertextVector64,
+    ) -> Result<PlaintextVector64, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweTernarySecretKey64,
+        input: &CleartextGlweTernaryCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweGaussianSecretKey32,
+        CleartextGlweGaussianCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &CleartextGlweGaussianCiphertextVector32,
+    ) -> Result<PlaintextVector32, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweGaussianSecretKey32,
+        input: &CleartextGlweGaussianCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDecryptionEngine<
+        CleartextGlweGaussianSecretKey64,
+        CleartextGlweGaussianCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_glwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    /// #     decrypted_plaintext_vector.plaintext_count(),
+    /// #     PlaintextCount(8)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &CleartextGlweGaussianCiphertextVector64,
+    ) -> Result<PlaintextVector64, GlweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDecryptionError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe { self.decrypt_glwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweGaussianSecretKey64,
+        input: &CleartextGlweGaussianCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
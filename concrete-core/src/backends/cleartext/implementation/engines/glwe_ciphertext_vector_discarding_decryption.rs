@@ -0,0 +1,177 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::specification::engines::{
+    GlweCiphertextVectorDiscardingDecryptionEngine, GlweCiphertextVectorDiscardingDecryptionError,
+};
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingDecryptionEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDiscardingDecryptionEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_decrypt_glwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut plaintext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(8));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        output: &mut PlaintextVector32,
+        input: &CleartextGlweCiphertextVector32,
+    ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::PolynomialSizeMismatch);
+        }
+        if input.polynomial_size().0 * input.glwe_ciphertext_count().0 != output.plaintext_count().0
+        {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_decrypt_glwe_ciphertext_vector_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        output: &mut PlaintextVector32,
+        input: &CleartextGlweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(&input.phases);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingDecryptionEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    GlweCiphertextVectorDiscardingDecryptionEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_decrypt_glwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut plaintext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(8));
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &CleartextGlweCiphertextVector64,
+    ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::PolynomialSizeMismatch);
+        }
+        if input.polynomial_size().0 * input.glwe_ciphertext_count().0 != output.plaintext_count().0
+        {
+            return Err(GlweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_decrypt_glwe_ciphertext_vector_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &CleartextGlweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(&input.phases);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweCiphertextVectorDiscardingEncryptionEngine, GlweCiphertextVectorDiscardingEncryptionError,
+};
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+///
+/// The phase of each coefficient of the output ciphertexts is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl
+    GlweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key_1: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let key_2: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key_1, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_vector(
+    ///     &key_2,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if output.polynomial_size().0 * output.glwe_ciphertext_count().0
+            != input.plaintext_count().0
+        {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey32,
+        output: &mut CleartextGlweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+///
+/// The phase of each coefficient of the output ciphertexts is replaced by the corresponding
+/// plaintext, plus a noise sampled with the given variance.
+impl
+    GlweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key_1: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let key_2: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key_1, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext_vector(
+    ///     &key_2,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key_1)?;
+    /// engine.destroy(key_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if output.polynomial_size().0 * output.glwe_ciphertext_count().0
+            != input.plaintext_count().0
+        {
+            return Err(GlweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextGlweSecretKey64,
+        output: &mut CleartextGlweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweGaussianCiphertextVector32, CleartextGlweGaussianCiphertextVector64,
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweTernaryCiphertextVector32,
+    CleartextGlweTernaryCiphertextVector64, CleartextGlweTernarySecretKey32,
+    CleartextGlweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweCiphertextVectorEncryptionEngine, GlweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweCiphertextVector32,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweCiphertextVector32 {
+        CleartextGlweCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweCiphertextVector64,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweCiphertextVector64 {
+        CleartextGlweCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweTernarySecretKey32,
+        PlaintextVector32,
+        CleartextGlweTernaryCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweTernaryCiphertextVector32,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweTernaryCiphertextVector32 {
+        CleartextGlweTernaryCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweTernarySecretKey64,
+        PlaintextVector64,
+        CleartextGlweTernaryCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweTernaryCiphertextVector64,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweTernaryCiphertextVector64 {
+        CleartextGlweTernaryCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweGaussianSecretKey32,
+        PlaintextVector32,
+        CleartextGlweGaussianCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweGaussianCiphertextVector32,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweGaussianCiphertextVector32 {
+        CleartextGlweGaussianCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phase of each coefficient of the ciphertexts is the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        CleartextGlweGaussianSecretKey64,
+        PlaintextVector64,
+        CleartextGlweGaussianCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweGaussianCiphertextVector64,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if input.plaintext_count().0 % key.polynomial_size().0 != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweGaussianCiphertextVector64 {
+        CleartextGlweGaussianCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
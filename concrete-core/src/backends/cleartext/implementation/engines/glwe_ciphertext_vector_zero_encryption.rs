@@ -0,0 +1,151 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextVectorZeroEncryptionEngine, GlweCiphertextVectorZeroEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::GlweCiphertextCount;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextVectorZeroEncryptionEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> Result<
+        CleartextGlweCiphertextVector32,
+        GlweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        if count.0 == 0 {
+            return Err(GlweCiphertextVectorZeroEncryptionError::NullCiphertextCount);
+        }
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> CleartextGlweCiphertextVector32 {
+        CleartextGlweCiphertextVector32 {
+            phases: (0..count.0 * key.polynomial_size().0)
+                .map(|_| self.add_noise(0, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorZeroEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextVectorZeroEncryptionEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let ciphertext_count = GlweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.zero_encrypt_glwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), ciphertext_count);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> Result<
+        CleartextGlweCiphertextVector64,
+        GlweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        if count.0 == 0 {
+            return Err(GlweCiphertextVectorZeroEncryptionError::NullCiphertextCount);
+        }
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> CleartextGlweCiphertextVector64 {
+        CleartextGlweCiphertextVector64 {
+            phases: (0..count.0 * key.polynomial_size().0)
+                .map(|_| self.add_noise(0, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{
+    GlweCiphertextZeroEncryptionEngine, GlweCiphertextZeroEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextZeroEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl GlweCiphertextZeroEncryptionEngine<CleartextGlweSecretKey32, CleartextGlweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        noise: Variance,
+    ) -> Result<CleartextGlweCiphertext32, GlweCiphertextZeroEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        noise: Variance,
+    ) -> CleartextGlweCiphertext32 {
+        CleartextGlweCiphertext32 {
+            phases: (0..key.polynomial_size().0)
+                .map(|_| self.add_noise(0, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextZeroEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl GlweCiphertextZeroEncryptionEngine<CleartextGlweSecretKey64, CleartextGlweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        noise: Variance,
+    ) -> Result<CleartextGlweCiphertext64, GlweCiphertextZeroEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.zero_encrypt_glwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        noise: Variance,
+    ) -> CleartextGlweCiphertext64 {
+        CleartextGlweCiphertext64 {
+            phases: (0..key.polynomial_size().0)
+                .map(|_| self.add_noise(0, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierGgswCiphertext32, CleartextFourierGgswCiphertext64, CleartextGlweCiphertext32,
+    CleartextGlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextsGgswCiphertextCmuxEngine, GlweCiphertextsGgswCiphertextCmuxError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextCmuxEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The difference of the phases of the inputs is multiplied by the plaintext of the GGSW ciphertext
+/// and added to the phases of the first input, and a noise is added with the variance predicted by
+/// `concrete-npe` for the external product.
+impl
+    GlweCiphertextsGgswCiphertextCmuxEngine<
+        CleartextGlweCiphertext32,
+        CleartextFourierGgswCiphertext32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u32;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 20 bits)
+    /// let input_0 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_1 = vec![5_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: CleartextFourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// // Select one of the two GLWE ciphertexts with the GGSW ciphertext.
+    /// let output: CleartextGlweCiphertext32 =
+    ///     engine.cmux_glwe_ciphertexts_ggsw_ciphertext(&glwe_0, &glwe_1, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_input_0: &CleartextGlweCiphertext32,
+        glwe_input_1: &CleartextGlweCiphertext32,
+        ggsw_input: &CleartextFourierGgswCiphertext32,
+    ) -> Result<CleartextGlweCiphertext32, GlweCiphertextsGgswCiphertextCmuxError<Self::EngineError>>
+    {
+        if glwe_input_0.polynomial_size() != ggsw_input.polynomial_size()
+            || glwe_input_1.polynomial_size() != ggsw_input.polynomial_size()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::PolynomialSizeMismatch);
+        }
+        if glwe_input_0.glwe_dimension() != ggsw_input.glwe_dimension()
+            || glwe_input_1.glwe_dimension() != ggsw_input.glwe_dimension()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input_0: &CleartextGlweCiphertext32,
+        glwe_input_1: &CleartextGlweCiphertext32,
+        ggsw_input: &CleartextFourierGgswCiphertext32,
+    ) -> CleartextGlweCiphertext32 {
+        // The cmux is computed as an external product of the difference of the inputs, added
+        // to the first input.
+        let differences: Vec<u32> = glwe_input_1
+            .phases
+            .iter()
+            .zip(glwe_input_0.phases.iter())
+            .map(|(phase_1, phase_0)| phase_1.wrapping_sub(*phase_0))
+            .collect();
+        let products = self.external_product_phases(
+            &differences,
+            ggsw_input.plaintext,
+            ggsw_input,
+            ggsw_input.noise,
+        );
+        CleartextGlweCiphertext32 {
+            phases: glwe_input_0
+                .phases
+                .iter()
+                .zip(products.iter())
+                .map(|(phase_0, product)| phase_0.wrapping_add(*product))
+                .collect(),
+            glwe_dimension: glwe_input_0.glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextCmuxEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The difference of the phases of the inputs is multiplied by the plaintext of the GGSW ciphertext
+/// and added to the phases of the first input, and a noise is added with the variance predicted by
+/// `concrete-npe` for the external product.
+impl
+    GlweCiphertextsGgswCiphertextCmuxEngine<
+        CleartextGlweCiphertext64,
+        CleartextFourierGgswCiphertext64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u64;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 50 bits)
+    /// let input_0 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_1 = vec![5_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: CleartextFourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// // Select one of the two GLWE ciphertexts with the GGSW ciphertext.
+    /// let output: CleartextGlweCiphertext64 =
+    ///     engine.cmux_glwe_ciphertexts_ggsw_ciphertext(&glwe_0, &glwe_1, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_input_0: &CleartextGlweCiphertext64,
+        glwe_input_1: &CleartextGlweCiphertext64,
+        ggsw_input: &CleartextFourierGgswCiphertext64,
+    ) -> Result<CleartextGlweCiphertext64, GlweCiphertextsGgswCiphertextCmuxError<Self::EngineError>>
+    {
+        if glwe_input_0.polynomial_size() != ggsw_input.polynomial_size()
+            || glwe_input_1.polynomial_size() != ggsw_input.polynomial_size()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::PolynomialSizeMismatch);
+        }
+        if glwe_input_0.glwe_dimension() != ggsw_input.glwe_dimension()
+            || glwe_input_1.glwe_dimension() != ggsw_input.glwe_dimension()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input_0: &CleartextGlweCiphertext64,
+        glwe_input_1: &CleartextGlweCiphertext64,
+        ggsw_input: &CleartextFourierGgswCiphertext64,
+    ) -> CleartextGlweCiphertext64 {
+        // The cmux is computed as an external product of the difference of the inputs, added
+        // to the first input.
+        let differences: Vec<u64> = glwe_input_1
+            .phases
+            .iter()
+            .zip(glwe_input_0.phases.iter())
+            .map(|(phase_1, phase_0)| phase_1.wrapping_sub(*phase_0))
+            .collect();
+        let products = self.external_product_phases(
+            &differences,
+            ggsw_input.plaintext,
+            ggsw_input,
+            ggsw_input.noise,
+        );
+        CleartextGlweCiphertext64 {
+            phases: glwe_input_0
+                .phases
+                .iter()
+                .zip(products.iter())
+                .map(|(phase_0, product)| phase_0.wrapping_add(*product))
+                .collect(),
+            glwe_dimension: glwe_input_0.glwe_dimension,
+        }
+    }
+}
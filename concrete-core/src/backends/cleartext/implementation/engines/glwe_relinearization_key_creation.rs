@@ -0,0 +1,211 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweRelinearizationKey32, CleartextGlweRelinearizationKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{
+    GlweRelinearizationKeyCreationEngine, GlweRelinearizationKeyCreationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyCreationEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    GlweRelinearizationKeyCreationEngine<
+        CleartextGlweSecretKey32,
+        CleartextGlweRelinearizationKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let relinearization_key: CleartextGlweRelinearizationKey32 = engine
+    ///     .create_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_relinearization_key(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweRelinearizationKey32,
+        GlweRelinearizationKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GlweRelinearizationKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_glwe_relinearization_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextGlweRelinearizationKey32 {
+        CleartextGlweRelinearizationKey32 {
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyCreationEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    GlweRelinearizationKeyCreationEngine<
+        CleartextGlweSecretKey64,
+        CleartextGlweRelinearizationKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let relinearization_key: CleartextGlweRelinearizationKey64 = engine
+    ///     .create_glwe_relinearization_key(
+    ///         &key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     relinearization_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_relinearization_key(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweRelinearizationKey64,
+        GlweRelinearizationKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GlweRelinearizationKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_glwe_relinearization_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextGlweRelinearizationKey64 {
+        CleartextGlweRelinearizationKey64 {
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{GlweSecretKeyConversionEngine, GlweSecretKeyConversionError};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits GLWE secret key to a 64 bits GLWE secret key. The key bits are copied to the output
+/// precision.
+///
+/// The key only carries its parameters, which are copied to the output.
+impl GlweSecretKeyConversionEngine<CleartextGlweSecretKey32, CleartextGlweSecretKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: CleartextGlweSecretKey64 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &CleartextGlweSecretKey32,
+    ) -> Result<CleartextGlweSecretKey64, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &CleartextGlweSecretKey32,
+    ) -> CleartextGlweSecretKey64 {
+        CleartextGlweSecretKey64 {
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits GLWE secret key to a 32 bits GLWE secret key. The key bits are copied to the output
+/// precision.
+///
+/// The key only carries its parameters, which are copied to the output.
+impl GlweSecretKeyConversionEngine<CleartextGlweSecretKey64, CleartextGlweSecretKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: CleartextGlweSecretKey32 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &CleartextGlweSecretKey64,
+    ) -> Result<CleartextGlweSecretKey32, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &CleartextGlweSecretKey64,
+    ) -> CleartextGlweSecretKey32 {
+        CleartextGlweSecretKey32 {
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
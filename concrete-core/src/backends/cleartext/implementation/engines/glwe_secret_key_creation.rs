@@ -0,0 +1,339 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweGaussianSecretKey32, CleartextGlweGaussianSecretKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextGlweTernarySecretKey32, CleartextGlweTernarySecretKey64,
+};
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweSecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweSecretKey32, GlweSecretKeyCreationError<Self::EngineError>> {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweSecretKey32 {
+        CleartextGlweSecretKey32 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweSecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweSecretKey64, GlweSecretKeyCreationError<Self::EngineError>> {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweSecretKey64 {
+        CleartextGlweSecretKey64 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweTernarySecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweTernarySecretKey32, GlweSecretKeyCreationError<Self::EngineError>>
+    {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweTernarySecretKey32 {
+        CleartextGlweTernarySecretKey32 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweTernarySecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweTernarySecretKey64, GlweSecretKeyCreationError<Self::EngineError>>
+    {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweTernarySecretKey64 {
+        CleartextGlweTernarySecretKey64 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweGaussianSecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweGaussianSecretKey32, GlweSecretKeyCreationError<Self::EngineError>>
+    {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweGaussianSecretKey32 {
+        CleartextGlweGaussianSecretKey32 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyCreationEngine<CleartextGlweGaussianSecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_secret_key: CleartextGlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<CleartextGlweGaussianSecretKey64, GlweSecretKeyCreationError<Self::EngineError>>
+    {
+        if glwe_dimension.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullGlweDimension);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweSecretKeyCreationError::NullPolynomialSize);
+        }
+        if polynomial_size.0 == 1 {
+            return Err(GlweSecretKeyCreationError::SizeOnePolynomial);
+        }
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> CleartextGlweGaussianSecretKey64 {
+        CleartextGlweGaussianSecretKey64 {
+            glwe_dimension,
+            polynomial_size,
+        }
+    }
+}
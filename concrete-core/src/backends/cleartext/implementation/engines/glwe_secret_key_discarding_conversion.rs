@@ -0,0 +1,128 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64,
+};
+use crate::specification::engines::{
+    GlweSecretKeyDiscardingConversionEngine, GlweSecretKeyDiscardingConversionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits GLWE secret key to a 64 bits GLWE secret key. The key bits are copied to the
+/// output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, hence
+/// the output is left untouched.
+impl GlweSecretKeyDiscardingConversionEngine<CleartextGlweSecretKey32, CleartextGlweSecretKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_convert_glwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_secret_key(
+        &mut self,
+        output: &mut CleartextGlweSecretKey64,
+        input: &CleartextGlweSecretKey32,
+    ) -> Result<(), GlweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweSecretKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweSecretKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_secret_key_unchecked(
+        &mut self,
+        _output: &mut CleartextGlweSecretKey64,
+        _input: &CleartextGlweSecretKey32,
+    ) {
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits GLWE secret key to a 32 bits GLWE secret key. The key bits are copied to the
+/// output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, hence
+/// the output is left untouched.
+impl GlweSecretKeyDiscardingConversionEngine<CleartextGlweSecretKey64, CleartextGlweSecretKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_convert_glwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_secret_key(
+        &mut self,
+        output: &mut CleartextGlweSecretKey32,
+        input: &CleartextGlweSecretKey64,
+    ) -> Result<(), GlweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweSecretKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweSecretKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_secret_key_unchecked(
+        &mut self,
+        _output: &mut CleartextGlweSecretKey32,
+        _input: &CleartextGlweSecretKey64,
+    ) {
+    }
+}
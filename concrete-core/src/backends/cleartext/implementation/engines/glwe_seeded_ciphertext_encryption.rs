@@ -0,0 +1,168 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64, CleartextGlweSeededCiphertext32,
+    CleartextGlweSeededCiphertext64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweSeededCiphertextEncryptionEngine, GlweSeededCiphertextEncryptionError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phases of the ciphertext are the plaintexts, plus a noise sampled with the given variance.
+impl
+    GlweSeededCiphertextEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweSeededCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext: CleartextGlweSeededCiphertext32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_seeded_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweSeededCiphertext32,
+        GlweSeededCiphertextEncryptionError<Self::EngineError>,
+    > {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweSeededCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweSeededCiphertext32 {
+        CleartextGlweSeededCiphertext32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phases of the ciphertext are the plaintexts, plus a noise sampled with the given variance.
+impl
+    GlweSeededCiphertextEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweSeededCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext: CleartextGlweSeededCiphertext64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_seeded_ciphertext(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweSeededCiphertext64,
+        GlweSeededCiphertextEncryptionError<Self::EngineError>,
+    > {
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweSeededCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweSeededCiphertext64 {
+        CleartextGlweSeededCiphertext64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+        }
+    }
+}
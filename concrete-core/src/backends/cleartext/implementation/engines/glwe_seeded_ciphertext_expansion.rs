@@ -0,0 +1,134 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweSeededCiphertext32,
+    CleartextGlweSeededCiphertext64,
+};
+use crate::specification::engines::{
+    GlweSeededCiphertextExpansionEngine, GlweSeededCiphertextExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextExpansionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl GlweSeededCiphertextExpansionEngine<CleartextGlweSeededCiphertext32, CleartextGlweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext: CleartextGlweSeededCiphertext32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: CleartextGlweCiphertext32 =
+    ///     engine.expand_glwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext(
+        &mut self,
+        input: &CleartextGlweSeededCiphertext32,
+    ) -> Result<CleartextGlweCiphertext32, GlweSeededCiphertextExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGlweSeededCiphertext32,
+    ) -> CleartextGlweCiphertext32 {
+        CleartextGlweCiphertext32 {
+            phases: input.phases.clone(),
+            glwe_dimension: input.glwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextExpansionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl GlweSeededCiphertextExpansionEngine<CleartextGlweSeededCiphertext64, CleartextGlweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext: CleartextGlweSeededCiphertext64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: CleartextGlweCiphertext64 =
+    ///     engine.expand_glwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext(
+        &mut self,
+        input: &CleartextGlweSeededCiphertext64,
+    ) -> Result<CleartextGlweCiphertext64, GlweSeededCiphertextExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextGlweSeededCiphertext64,
+    ) -> CleartextGlweCiphertext64 {
+        CleartextGlweCiphertext64 {
+            phases: input.phases.clone(),
+            glwe_dimension: input.glwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64, CleartextGlweSeededCiphertextVector32,
+    CleartextGlweSeededCiphertextVector64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorEncryptionEngine, GlweSeededCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, PlaintextVectorEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the ciphertexts are the plaintexts, plus a noise sampled with the given variance.
+impl
+    GlweSeededCiphertextVectorEncryptionEngine<
+        CleartextGlweSecretKey32,
+        PlaintextVector32,
+        CleartextGlweSeededCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: CleartextGlweSeededCiphertextVector32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_seeded_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweSeededCiphertextVector32,
+        GlweSeededCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweSeededCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_seeded_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextGlweSeededCiphertextVector32 {
+        CleartextGlweSeededCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the ciphertexts are the plaintexts, plus a noise sampled with the given variance.
+impl
+    GlweSeededCiphertextVectorEncryptionEngine<
+        CleartextGlweSecretKey64,
+        PlaintextVector64,
+        CleartextGlweSeededCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: CleartextGlweSeededCiphertextVector64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_seeded_ciphertext_vector(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextGlweSeededCiphertextVector64,
+        GlweSeededCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweSeededCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_seeded_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextGlweSeededCiphertextVector64 {
+        CleartextGlweSeededCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            glwe_dimension: key.glwe_dimension(),
+            polynomial_size: key.polynomial_size(),
+        }
+    }
+}
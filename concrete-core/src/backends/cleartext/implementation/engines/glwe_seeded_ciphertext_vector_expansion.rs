@@ -0,0 +1,152 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64,
+    CleartextGlweSeededCiphertextVector32, CleartextGlweSeededCiphertextVector64,
+};
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorExpansionEngine, GlweSeededCiphertextVectorExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorExpansionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl
+    GlweSeededCiphertextVectorExpansionEngine<
+        CleartextGlweSeededCiphertextVector32,
+        CleartextGlweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: CleartextGlweSeededCiphertextVector32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: CleartextGlweCiphertextVector32 =
+    ///     engine.expand_glwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &CleartextGlweSeededCiphertextVector32,
+    ) -> Result<
+        CleartextGlweCiphertextVector32,
+        GlweSeededCiphertextVectorExpansionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextGlweSeededCiphertextVector32,
+    ) -> CleartextGlweCiphertextVector32 {
+        CleartextGlweCiphertextVector32 {
+            phases: input.phases.clone(),
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorExpansionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl
+    GlweSeededCiphertextVectorExpansionEngine<
+        CleartextGlweSeededCiphertextVector64,
+        CleartextGlweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: CleartextGlweSeededCiphertextVector64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: CleartextGlweCiphertextVector64 =
+    ///     engine.expand_glwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.glwe_ciphertext_count(),
+    ///     GlweCiphertextCount(2)
+    /// );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &CleartextGlweSeededCiphertextVector64,
+    ) -> Result<
+        CleartextGlweCiphertextVector64,
+        GlweSeededCiphertextVectorExpansionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextGlweSeededCiphertextVector64,
+    ) -> CleartextGlweCiphertextVector64 {
+        CleartextGlweCiphertextVector64 {
+            phases: input.phases.clone(),
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+        }
+    }
+}
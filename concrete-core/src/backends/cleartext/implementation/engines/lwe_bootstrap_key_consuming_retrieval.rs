@@ -0,0 +1,314 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::specification::engines::{
+    LweBootstrapKeyConsumingRetrievalEngine, LweBootstrapKeyConsumingRetrievalError,
+};
+use concrete_fftw::array::AlignedVec;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 32 bits integers of an LWE bootstrap key.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl LweBootstrapKeyConsumingRetrievalEngine<CleartextLweBootstrapKey32, Vec<u32>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_bootstrap_key(bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let bsk: CleartextLweBootstrapKey32 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: CleartextLweBootstrapKey32,
+    ) -> Result<Vec<u32>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: CleartextLweBootstrapKey32,
+    ) -> Vec<u32> {
+        let glwe_size = bootstrap_key.glwe_dimension.to_glwe_size();
+        vec![
+            0;
+            bootstrap_key.input_lwe_dimension.0
+                * bootstrap_key.decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+                * bootstrap_key.polynomial_size.0
+        ]
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 64 bits integers of an LWE bootstrap key.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl LweBootstrapKeyConsumingRetrievalEngine<CleartextLweBootstrapKey64, Vec<u64>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_bootstrap_key(bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let bsk: CleartextLweBootstrapKey64 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: CleartextLweBootstrapKey64,
+    ) -> Result<Vec<u64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: CleartextLweBootstrapKey64,
+    ) -> Vec<u64> {
+        let glwe_size = bootstrap_key.glwe_dimension.to_glwe_size();
+        vec![
+            0;
+            bootstrap_key.input_lwe_dimension.0
+                * bootstrap_key.decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+                * bootstrap_key.polynomial_size.0
+        ]
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the aligned vector of complex numbers of a Fourier LWE bootstrap key of 32 bits
+/// integers.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl
+    LweBootstrapKeyConsumingRetrievalEngine<
+        CleartextFourierLweBootstrapKey32,
+        AlignedVec<Complex64>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let container: AlignedVec<c64> = engine.consume_retrieve_lwe_bootstrap_key(fourier_bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey32 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: CleartextFourierLweBootstrapKey32,
+    ) -> Result<AlignedVec<Complex64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: CleartextFourierLweBootstrapKey32,
+    ) -> AlignedVec<Complex64> {
+        let glwe_size = bootstrap_key.glwe_dimension.to_glwe_size();
+        AlignedVec::new(
+            bootstrap_key.input_lwe_dimension.0
+                * bootstrap_key.decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+                * bootstrap_key.polynomial_size.0,
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the aligned vector of complex numbers of a Fourier LWE bootstrap key of 64 bits
+/// integers.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl
+    LweBootstrapKeyConsumingRetrievalEngine<
+        CleartextFourierLweBootstrapKey64,
+        AlignedVec<Complex64>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let container: AlignedVec<c64> = engine.consume_retrieve_lwe_bootstrap_key(fourier_bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey64 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: CleartextFourierLweBootstrapKey64,
+    ) -> Result<AlignedVec<Complex64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: CleartextFourierLweBootstrapKey64,
+    ) -> AlignedVec<Complex64> {
+        let glwe_size = bootstrap_key.glwe_dimension.to_glwe_size();
+        AlignedVec::new(
+            bootstrap_key.input_lwe_dimension.0
+                * bootstrap_key.decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+                * bootstrap_key.polynomial_size.0,
+        )
+    }
+}
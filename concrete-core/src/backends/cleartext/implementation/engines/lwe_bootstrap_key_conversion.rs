@@ -0,0 +1,579 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32, CleartextFourierLweGaussianBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32, CleartextFourierLweTernaryBootstrapKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64, CleartextLweGaussianBootstrapKey32,
+    CleartextLweGaussianBootstrapKey64, CleartextLweTernaryBootstrapKey32,
+    CleartextLweTernaryBootstrapKey64,
+};
+use crate::specification::engines::{
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl LweBootstrapKeyConversionEngine<CleartextLweBootstrapKey32, CleartextFourierLweBootstrapKey32>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweBootstrapKey32,
+    ) -> Result<CleartextFourierLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweBootstrapKey32,
+    ) -> CleartextFourierLweBootstrapKey32 {
+        CleartextFourierLweBootstrapKey32 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl LweBootstrapKeyConversionEngine<CleartextLweBootstrapKey64, CleartextFourierLweBootstrapKey64>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweBootstrapKey64,
+    ) -> Result<CleartextFourierLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweBootstrapKey64,
+    ) -> CleartextFourierLweBootstrapKey64 {
+        CleartextFourierLweBootstrapKey64 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key created from a ternary key from the standard to
+/// the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl
+    LweBootstrapKeyConversionEngine<
+        CleartextLweTernaryBootstrapKey32,
+        CleartextFourierLweTernaryBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweTernaryBootstrapKey32 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweTernaryBootstrapKey32,
+    ) -> Result<
+        CleartextFourierLweTernaryBootstrapKey32,
+        LweBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweTernaryBootstrapKey32,
+    ) -> CleartextFourierLweTernaryBootstrapKey32 {
+        CleartextFourierLweTernaryBootstrapKey32 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key created from a ternary key from the standard to
+/// the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl
+    LweBootstrapKeyConversionEngine<
+        CleartextLweTernaryBootstrapKey64,
+        CleartextFourierLweTernaryBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweTernaryBootstrapKey64 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweTernaryBootstrapKey64,
+    ) -> Result<
+        CleartextFourierLweTernaryBootstrapKey64,
+        LweBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweTernaryBootstrapKey64,
+    ) -> CleartextFourierLweTernaryBootstrapKey64 {
+        CleartextFourierLweTernaryBootstrapKey64 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key created from a gaussian key from the standard to
+/// the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl
+    LweBootstrapKeyConversionEngine<
+        CleartextLweGaussianBootstrapKey32,
+        CleartextFourierLweGaussianBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweGaussianBootstrapKey32 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweGaussianBootstrapKey32,
+    ) -> Result<
+        CleartextFourierLweGaussianBootstrapKey32,
+        LweBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweGaussianBootstrapKey32,
+    ) -> CleartextFourierLweGaussianBootstrapKey32 {
+        CleartextFourierLweGaussianBootstrapKey32 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key created from a gaussian key from the standard to
+/// the Fourier domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl
+    LweBootstrapKeyConversionEngine<
+        CleartextLweGaussianBootstrapKey64,
+        CleartextFourierLweGaussianBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: CleartextFourierLweGaussianBootstrapKey64 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextLweGaussianBootstrapKey64,
+    ) -> Result<
+        CleartextFourierLweGaussianBootstrapKey64,
+        LweBootstrapKeyConversionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextLweGaussianBootstrapKey64,
+    ) -> CleartextFourierLweGaussianBootstrapKey64 {
+        CleartextFourierLweGaussianBootstrapKey64 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl LweBootstrapKeyConversionEngine<CleartextFourierLweBootstrapKey32, CleartextLweBootstrapKey32>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: CleartextLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&fourier_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<CleartextLweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextFourierLweBootstrapKey32,
+    ) -> CleartextLweBootstrapKey32 {
+        CleartextLweBootstrapKey32 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+///
+/// # Note:
+/// The Fourier domain stores the coefficients as double precision floats, which cannot hold 64
+/// bits torus elements exactly. The output coefficients are thus only close to the ones of the
+/// standard key the Fourier key was computed from, and this conversion is not exact.
+///
+/// The bootstrap key holds the same parameters and noise in both domains, which are copied.
+impl LweBootstrapKeyConversionEngine<CleartextFourierLweBootstrapKey64, CleartextLweBootstrapKey64>
+    for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let fourier_bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: CleartextLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&fourier_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<CleartextLweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &CleartextFourierLweBootstrapKey64,
+    ) -> CleartextLweBootstrapKey64 {
+        CleartextLweBootstrapKey64 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            glwe_dimension: input.glwe_dimension,
+            polynomial_size: input.polynomial_size,
+            decomposition_base_log: input.decomposition_base_log,
+            decomposition_level_count: input.decomposition_level_count,
+            noise: input.noise,
+        }
+    }
+}
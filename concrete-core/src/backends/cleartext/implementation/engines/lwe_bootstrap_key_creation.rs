@@ -0,0 +1,751 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64, CleartextGlweSecretKey32,
+    CleartextGlweSecretKey64, CleartextLweBootstrapKey32, CleartextLweBootstrapKey64,
+    CleartextLweGaussianBootstrapKey32, CleartextLweGaussianBootstrapKey64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextLweTernaryBootstrapKey32, CleartextLweTernaryBootstrapKey64,
+    CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextLweBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweBootstrapKey32 {
+        CleartextLweBootstrapKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextLweBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweBootstrapKey64 {
+        CleartextLweBootstrapKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweTernarySecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextLweTernaryBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweTernarySecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweTernaryBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweTernarySecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweTernaryBootstrapKey32 {
+        CleartextLweTernaryBootstrapKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweTernarySecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextLweTernaryBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweTernarySecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweTernaryBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweTernarySecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweTernaryBootstrapKey64 {
+        CleartextLweTernaryBootstrapKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweGaussianSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextLweGaussianBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweGaussianSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweGaussianBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweGaussianSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweGaussianBootstrapKey32 {
+        CleartextLweGaussianBootstrapKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweGaussianSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextLweGaussianBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextLweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweGaussianSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextLweGaussianBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweGaussianSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextLweGaussianBootstrapKey64 {
+        CleartextLweGaussianBootstrapKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// It outputs a bootstrap key in the Fourier domain.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextFourierLweBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextFourierLweBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextFourierLweBootstrapKey32 {
+        CleartextFourierLweBootstrapKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// It outputs a bootstrap key in the Fourier domain.
+impl
+    LweBootstrapKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextFourierLweBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk);
+    /// engine.destroy(glwe_sk);
+    /// engine.destroy(bsk);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<CleartextFourierLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> CleartextFourierLweBootstrapKey64 {
+        CleartextFourierLweBootstrapKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            glwe_dimension: output_key.glwe_dimension(),
+            polynomial_size: output_key.polynomial_size(),
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+        }
+    }
+}
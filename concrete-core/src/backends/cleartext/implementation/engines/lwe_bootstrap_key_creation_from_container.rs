@@ -0,0 +1,460 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::specification::engines::{
+    LweBootstrapKeyCreationFromContainerEngine, LweBootstrapKeyCreationFromContainerError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns an LWE bootstrap key, taking ownership of a vector of 32 bits integers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl LweBootstrapKeyCreationFromContainerEngine<Vec<u32>, CleartextLweBootstrapKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container = vec![0_u32; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let bsk: CleartextLweBootstrapKey32 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextLweBootstrapKey32,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextLweBootstrapKey32 {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        CleartextLweBootstrapKey32 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0),
+            ),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns an LWE bootstrap key, taking ownership of a vector of 64 bits integers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl LweBootstrapKeyCreationFromContainerEngine<Vec<u64>, CleartextLweBootstrapKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container = vec![0_u64; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let bsk: CleartextLweBootstrapKey64 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextLweBootstrapKey64,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextLweBootstrapKey64 {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        CleartextLweBootstrapKey64 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0),
+            ),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns a Fourier LWE bootstrap key of 32 bits integers, taking ownership of an aligned vector
+/// of complex numbers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl
+    LweBootstrapKeyCreationFromContainerEngine<
+        AlignedVec<Complex64>,
+        CleartextFourierLweBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container: AlignedVec<c64> =
+    ///     AlignedVec::new(lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextFourierLweBootstrapKey32,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextFourierLweBootstrapKey32 {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        CleartextFourierLweBootstrapKey32 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0),
+            ),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns a Fourier LWE bootstrap key of 64 bits integers, taking ownership of an aligned vector
+/// of complex numbers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl
+    LweBootstrapKeyCreationFromContainerEngine<
+        AlignedVec<Complex64>,
+        CleartextFourierLweBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container: AlignedVec<c64> =
+    ///     AlignedVec::new(lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 = engine
+    ///     .create_lwe_bootstrap_key_from_container(container, glwe_dim, poly_size, dec_bl, dec_lc)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextFourierLweBootstrapKey64,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextFourierLweBootstrapKey64 {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        CleartextFourierLweBootstrapKey64 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0),
+            ),
+            glwe_dimension,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise: Variance(0.),
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64,
+};
+use crate::specification::engines::{
+    LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers. It converts a bootstrap key from the standard to the Fourier
+/// domain.
+///
+/// The bootstrap keys hold the same parameters in both domains, so only the noise of the input is
+/// copied to the output.
+impl
+    LweBootstrapKeyDiscardingConversionEngine<
+        CleartextLweBootstrapKey32,
+        CleartextFourierLweBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut CleartextFourierLweBootstrapKey32,
+        input: &CleartextLweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut CleartextFourierLweBootstrapKey32,
+        input: &CleartextLweBootstrapKey32,
+    ) {
+        output.noise = input.noise;
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers. It converts a bootstrap key from the standard to the Fourier
+/// domain.
+///
+/// The bootstrap keys hold the same parameters in both domains, so only the noise of the input is
+/// copied to the output.
+impl
+    LweBootstrapKeyDiscardingConversionEngine<
+        CleartextLweBootstrapKey64,
+        CleartextFourierLweBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut CleartextFourierLweBootstrapKey64,
+        input: &CleartextLweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut CleartextFourierLweBootstrapKey64,
+        input: &CleartextLweBootstrapKey64,
+    ) {
+        output.noise = input.noise;
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers. It converts a bootstrap key from the Fourier to the standard
+/// domain.
+///
+/// The bootstrap keys hold the same parameters in both domains, so only the noise of the input is
+/// copied to the output.
+impl
+    LweBootstrapKeyDiscardingConversionEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextLweBootstrapKey32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut CleartextLweBootstrapKey32,
+        input: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut CleartextLweBootstrapKey32,
+        input: &CleartextFourierLweBootstrapKey32,
+    ) {
+        output.noise = input.noise;
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers. It converts a bootstrap key from the Fourier to the standard
+/// domain.
+///
+/// # Note:
+/// The Fourier domain stores the coefficients as double precision floats, which cannot hold 64
+/// bits torus elements exactly. The output coefficients are thus only close to the ones of the
+/// standard key the Fourier key was computed from, and this conversion is not exact.
+///
+/// The bootstrap keys hold the same parameters in both domains, so only the noise of the input is
+/// copied to the output.
+impl
+    LweBootstrapKeyDiscardingConversionEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextLweBootstrapKey64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut CleartextLweBootstrapKey64,
+        input: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut CleartextLweBootstrapKey64,
+        input: &CleartextFourierLweBootstrapKey64,
+    ) {
+        output.noise = input.noise;
+    }
+}
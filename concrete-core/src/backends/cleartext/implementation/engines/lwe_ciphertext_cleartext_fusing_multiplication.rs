@@ -0,0 +1,246 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64,
+};
+use crate::backends::core::entities::{Cleartext32, Cleartext64};
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<CleartextLweCiphertext32, Cleartext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let cleartext_input = 12_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&cleartext_input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &Cleartext32,
+    ) {
+        output.phase = output.phase.wrapping_mul(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<CleartextLweCiphertext64, Cleartext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let cleartext_input = 12_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&cleartext_input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        output.phase = output.phase.wrapping_mul(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextCleartextFusingMultiplicationEngine<
+        CleartextLweCiphertextMutView32<'a>,
+        Cleartext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// let cleartext = engine.create_cleartext(&2_u32)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext_view, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Cleartext32,
+    ) {
+        *output.phase = output.phase.wrapping_mul(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextCleartextFusingMultiplicationEngine<
+        CleartextLweCiphertextMutView64<'a>,
+        Cleartext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// let cleartext = engine.create_cleartext(&2_u64)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext_view, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Cleartext64,
+    ) {
+        *output.phase = output.phase.wrapping_mul(input.0 .0);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext64, CleartextLweCompressedCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextCompressionEngine, LweCiphertextCompressionError,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+/// # Description:
+/// Implementation of [`LweCiphertextCompressionEngine`] for [`CleartextEngine`] that operates on 64
+/// bits integers, and stores the compressed coefficients on 32 bits.
+///
+/// The phase of the input is switched to the modulus of the compression, with a rounding error
+/// similar to the one of the `core` backend.
+impl LweCiphertextCompressionEngine<CleartextLweCiphertext64, CleartextLweCompressedCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertext is switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let compressed: CleartextLweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, modulus_log)?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.modulus_log(), modulus_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(compressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &CleartextLweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<CleartextLweCompressedCiphertext64, LweCiphertextCompressionError<Self::EngineError>>
+    {
+        if modulus_log.0 == 0 {
+            return Err(LweCiphertextCompressionError::NullModulusLog);
+        }
+        if modulus_log.0 > 32 {
+            return Err(LweCiphertextCompressionError::ModulusLogTooLarge);
+        }
+        Ok(unsafe { self.compress_lwe_ciphertext_unchecked(input, modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> CleartextLweCompressedCiphertext64 {
+        CleartextLweCompressedCiphertext64 {
+            compressed_phase: self.modulus_switch(
+                input.phase,
+                input.lwe_dimension,
+                1 << modulus_log.0,
+            ) as u32,
+            lwe_dimension: input.lwe_dimension,
+            modulus_log,
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextConsumingRetrievalEngine, LweCiphertextConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextConsumingRetrievalEngine`] for [`CleartextEngine`] that returns
+/// the vector of 32 bits integers of an LWE ciphertext.
+///
+/// The container follows the layout of the `core` backend, with a null mask and the phase of the
+/// ciphertext as body, i.e. it holds a trivial encryption of the phase.
+impl LweCiphertextConsumingRetrievalEngine<CleartextLweCiphertext32, Vec<u32>> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: CleartextLweCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0);
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: CleartextLweCiphertext32 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext(
+        &mut self,
+        ciphertext: CleartextLweCiphertext32,
+    ) -> Result<Vec<u32>, LweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: CleartextLweCiphertext32,
+    ) -> Vec<u32> {
+        let mut container = vec![0; ciphertext.lwe_dimension.0];
+        container.push(ciphertext.phase);
+        container
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConsumingRetrievalEngine`] for [`CleartextEngine`] that returns
+/// the vector of 64 bits integers of an LWE ciphertext.
+///
+/// The container follows the layout of the `core` backend, with a null mask and the phase of the
+/// ciphertext as body, i.e. it holds a trivial encryption of the phase.
+impl LweCiphertextConsumingRetrievalEngine<CleartextLweCiphertext64, Vec<u64>> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: CleartextLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0);
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: CleartextLweCiphertext64 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext(
+        &mut self,
+        ciphertext: CleartextLweCiphertext64,
+    ) -> Result<Vec<u64>, LweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: CleartextLweCiphertext64,
+    ) -> Vec<u64> {
+        let mut container = vec![0; ciphertext.lwe_dimension.0];
+        container.push(ciphertext.phase);
+        container
+    }
+}
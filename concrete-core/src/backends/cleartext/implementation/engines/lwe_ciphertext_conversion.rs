@@ -0,0 +1,120 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{LweCiphertextConversionEngine, LweCiphertextConversionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits LWE ciphertext to a 64 bits LWE ciphertext. The torus elements are exactly rescaled to the
+/// output precision.
+///
+/// The phase of the input is exactly rescaled to the output precision.
+impl LweCiphertextConversionEngine<CleartextLweCiphertext32, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: CleartextLweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &CleartextLweCiphertext32,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertext32,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: input.phase.cast_torus_into(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits LWE ciphertext to a 32 bits LWE ciphertext. The torus elements are rounded to the closest
+/// element of the output precision.
+///
+/// The phase of the input is exactly rescaled to the output precision.
+impl LweCiphertextConversionEngine<CleartextLweCiphertext64, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: CleartextLweCiphertext32 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &CleartextLweCiphertext64,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertext64,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: input.phase.cast_torus_into(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,313 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweCiphertextView32, CleartextLweCiphertextView64,
+};
+use crate::specification::engines::{LweCiphertextCreationEngine, LweCiphertextCreationError};
+use concrete_commons::parameters::LweDimension;
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns an LWE
+/// ciphertext, taking ownership of a vector of 32 bits integers.
+///
+/// The mask of the container is ignored, and its body is taken as the phase of the ciphertext.
+impl LweCiphertextCreationEngine<Vec<u32>, CleartextLweCiphertext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext: CleartextLweCiphertext32 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: Vec<u32>,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u32>,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: *container.last().unwrap(),
+            lwe_dimension: LweDimension(container.len() - 1),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns an LWE
+/// ciphertext, taking ownership of a vector of 64 bits integers.
+///
+/// The mask of the container is ignored, and its body is taken as the phase of the ciphertext.
+impl LweCiphertextCreationEngine<Vec<u64>, CleartextLweCiphertext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext: CleartextLweCiphertext64 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: Vec<u64>,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u64>,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: *container.last().unwrap(),
+            lwe_dimension: LweDimension(container.len() - 1),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the view
+/// of an LWE ciphertext, borrowing an immutable slice of 32 bits integers.
+///
+/// Only the body of the slice, which holds the phase of the ciphertext, is viewed.
+impl<'a> LweCiphertextCreationEngine<&'a [u32], CleartextLweCiphertextView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextView32 =
+    ///     engine.create_lwe_ciphertext(&container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a [u32],
+    ) -> Result<CleartextLweCiphertextView32<'a>, LweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u32],
+    ) -> CleartextLweCiphertextView32<'a> {
+        CleartextLweCiphertextView32 {
+            phase: container.last().unwrap(),
+            lwe_dimension: LweDimension(container.len() - 1),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the view
+/// of an LWE ciphertext, borrowing an immutable slice of 64 bits integers.
+///
+/// Only the body of the slice, which holds the phase of the ciphertext, is viewed.
+impl<'a> LweCiphertextCreationEngine<&'a [u64], CleartextLweCiphertextView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextView64 =
+    ///     engine.create_lwe_ciphertext(&container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a [u64],
+    ) -> Result<CleartextLweCiphertextView64<'a>, LweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u64],
+    ) -> CleartextLweCiphertextView64<'a> {
+        CleartextLweCiphertextView64 {
+            phase: container.last().unwrap(),
+            lwe_dimension: LweDimension(container.len() - 1),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the view
+/// of an LWE ciphertext, borrowing a mutable slice of 32 bits integers.
+///
+/// Only the body of the slice, which holds the phase of the ciphertext, is viewed.
+impl<'a> LweCiphertextCreationEngine<&'a mut [u32], CleartextLweCiphertextMutView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a mut [u32],
+    ) -> Result<CleartextLweCiphertextMutView32<'a>, LweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+    ) -> CleartextLweCiphertextMutView32<'a> {
+        let lwe_dimension = LweDimension(container.len() - 1);
+        CleartextLweCiphertextMutView32 {
+            phase: container.last_mut().unwrap(),
+            lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CleartextEngine`] which returns the view
+/// of an LWE ciphertext, borrowing a mutable slice of 64 bits integers.
+///
+/// Only the body of the slice, which holds the phase of the ciphertext, is viewed.
+impl<'a> LweCiphertextCreationEngine<&'a mut [u64], CleartextLweCiphertextMutView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a mut [u64],
+    ) -> Result<CleartextLweCiphertextMutView64<'a>, LweCiphertextCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+    ) -> CleartextLweCiphertextMutView64<'a> {
+        let lwe_dimension = LweDimension(container.len() - 1);
+        CleartextLweCiphertextMutView64 {
+            phase: container.last_mut().unwrap(),
+            lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext64, CleartextLweCompressedCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDecompressionEngine, LweCiphertextDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecompressionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The compressed phase is lifted back to the modulus of the output.
+impl LweCiphertextDecompressionEngine<CleartextLweCompressedCiphertext64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertext is switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed: CleartextLweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, modulus_log)?;
+    ///
+    /// let decompressed: CleartextLweCiphertext64 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(compressed)?;
+    /// engine.destroy(decompressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &CleartextLweCompressedCiphertext64,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweCompressedCiphertext64,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: (input.compressed_phase as u64) << (u64::BITS as usize - input.modulus_log.0),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,482 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextView32,
+    CleartextLweCiphertextView64, CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertext64, CleartextLweGaussianSecretKey32,
+    CleartextLweGaussianSecretKey64, CleartextLweSecretKey32, CleartextLweSecretKey64,
+    CleartextLweTernaryCiphertext32, CleartextLweTernaryCiphertext64,
+    CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl LweCiphertextDecryptionEngine<CleartextLweSecretKey32, CleartextLweCiphertext32, Plaintext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext, plaintext);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertext32,
+    ) -> Plaintext32 {
+        Plaintext32(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl LweCiphertextDecryptionEngine<CleartextLweSecretKey64, CleartextLweCiphertext64, Plaintext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext, plaintext);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertext64,
+    ) -> Plaintext64 {
+        Plaintext64(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl
+    LweCiphertextDecryptionEngine<
+        CleartextLweTernarySecretKey32,
+        CleartextLweTernaryCiphertext32,
+        Plaintext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &CleartextLweTernaryCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweTernarySecretKey32,
+        input: &CleartextLweTernaryCiphertext32,
+    ) -> Plaintext32 {
+        Plaintext32(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl
+    LweCiphertextDecryptionEngine<
+        CleartextLweTernarySecretKey64,
+        CleartextLweTernaryCiphertext64,
+        Plaintext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &CleartextLweTernaryCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweTernarySecretKey64,
+        input: &CleartextLweTernaryCiphertext64,
+    ) -> Plaintext64 {
+        Plaintext64(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl
+    LweCiphertextDecryptionEngine<
+        CleartextLweGaussianSecretKey32,
+        CleartextLweGaussianCiphertext32,
+        Plaintext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &CleartextLweGaussianCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweGaussianSecretKey32,
+        input: &CleartextLweGaussianCiphertext32,
+    ) -> Plaintext32 {
+        Plaintext32(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl
+    LweCiphertextDecryptionEngine<
+        CleartextLweGaussianSecretKey64,
+        CleartextLweGaussianCiphertext64,
+        Plaintext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &CleartextLweGaussianCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweGaussianSecretKey64,
+        input: &CleartextLweGaussianCiphertext64,
+    ) -> Plaintext64 {
+        Plaintext64(ImplPlaintext(input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on 32
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl<'a>
+    LweCiphertextDecryptionEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertextView32<'a>,
+        Plaintext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// let ciphertext_view: CleartextLweCiphertextView32 =
+    ///     engine.create_lwe_ciphertext(&container[..])?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_view)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextView32<'a>,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextView32<'a>,
+    ) -> Plaintext32 {
+        Plaintext32(ImplPlaintext(*input.phase))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CleartextEngine`] that operates on 64
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The decrypted plaintext is the phase of the ciphertext, noise included.
+impl<'a>
+    LweCiphertextDecryptionEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertextView64<'a>,
+        Plaintext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// let ciphertext_view: CleartextLweCiphertextView64 =
+    ///     engine.create_lwe_ciphertext(&container[..])?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_view)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextView64<'a>,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextView64<'a>,
+    ) -> Plaintext64 {
+        Plaintext64(ImplPlaintext(*input.phase))
+    }
+}
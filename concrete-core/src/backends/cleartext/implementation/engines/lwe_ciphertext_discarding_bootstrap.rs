@@ -0,0 +1,1541 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextFourierLweGaussianBootstrapKey32, CleartextFourierLweGaussianBootstrapKey64,
+    CleartextFourierLweTernaryBootstrapKey32, CleartextFourierLweTernaryBootstrapKey64,
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweCiphertextView32,
+    CleartextGlweCiphertextView64, CleartextLweBootstrapKey32, CleartextLweBootstrapKey64,
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweCiphertextView32, CleartextLweCiphertextView64,
+    CleartextLweGaussianBootstrapKey32, CleartextLweGaussianBootstrapKey64,
+    CleartextLweGaussianCiphertext32, CleartextLweGaussianCiphertext64,
+    CleartextLweTernaryBootstrapKey32, CleartextLweTernaryBootstrapKey64,
+    CleartextLweTernaryCiphertext32, CleartextLweTernaryCiphertext64,
+};
+use crate::backends::core::entities::GAUSSIAN_KEY_EXPANSION_WIDTH;
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 1);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 1);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a ciphertext encrypted under a ternary key, and simulates the blind rotation
+/// over the binary expansion of the key, which holds twice as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweTernaryBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweTernaryCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweTernaryCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweTernaryBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweTernaryCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweTernaryBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 2);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a ciphertext encrypted under a ternary key, and simulates the blind rotation
+/// over the binary expansion of the key, which holds twice as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweTernaryBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweTernaryCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweTernaryCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweTernaryBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweTernaryCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweTernaryBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 2);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a ciphertext encrypted under a gaussian key, and simulates the blind rotation
+/// over the signed binary expansion of the key, which holds ten times as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweGaussianBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweGaussianCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweGaussianCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweGaussianBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweGaussianCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextLweGaussianBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(
+            input.phase,
+            &acc.phases,
+            bsk,
+            bsk.noise,
+            2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a ciphertext encrypted under a gaussian key, and simulates the blind rotation
+/// over the signed binary expansion of the key, which holds ten times as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextLweGaussianBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweGaussianCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweGaussianCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweGaussianBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweGaussianCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextLweGaussianBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(
+            input.phase,
+            &acc.phases,
+            bsk,
+            bsk.noise,
+            2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 1);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.encrypt_lwe_ciphertext(&lwe_sk_output, &plaintext, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 1);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain, and a ciphertext encrypted under a
+/// ternary key, and simulates the blind rotation over the binary expansion of the key, which holds
+/// twice as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweTernaryBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweTernaryCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweTernaryBootstrapKey32 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweTernaryCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweTernaryBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweTernaryCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweTernaryBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 2);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain, and a ciphertext encrypted under a
+/// ternary key, and simulates the blind rotation over the binary expansion of the key, which holds
+/// twice as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweTernaryBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweTernaryCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweTernaryBootstrapKey64 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweTernaryCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweTernaryBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweTernaryCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweTernaryBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(input.phase, &acc.phases, bsk, bsk.noise, 2);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain, and a ciphertext encrypted under a
+/// gaussian key, and simulates the blind rotation over the signed binary expansion of the key,
+/// which holds ten times as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweGaussianBootstrapKey32,
+        CleartextGlweCiphertext32,
+        CleartextLweGaussianCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweGaussianBootstrapKey32 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweGaussianCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweGaussianBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweGaussianCiphertext32,
+        acc: &CleartextGlweCiphertext32,
+        bsk: &CleartextFourierLweGaussianBootstrapKey32,
+    ) {
+        output.phase = self.bootstrap_phase(
+            input.phase,
+            &acc.phases,
+            bsk,
+            bsk.noise,
+            2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the
+/// one of the `core` backend, and used to look up the phase of the accumulator in the negacyclic
+/// way of the blind rotation. A noise is then added with the variance predicted by `concrete-npe`
+/// for the bootstrap.
+///
+/// It takes as input a bootstrap key in the Fourier domain, and a ciphertext encrypted under a
+/// gaussian key, and simulates the blind rotation over the signed binary expansion of the key,
+/// which holds ten times as many coefficients.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweGaussianBootstrapKey64,
+        CleartextGlweCiphertext64,
+        CleartextLweGaussianCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: CleartextFourierLweGaussianBootstrapKey64 =
+    ///     engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweGaussianCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweGaussianBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweGaussianCiphertext64,
+        acc: &CleartextGlweCiphertext64,
+        bsk: &CleartextFourierLweGaussianBootstrapKey64,
+    ) {
+        output.phase = self.bootstrap_phase(
+            input.phase,
+            &acc.phases,
+            bsk,
+            bsk.noise,
+            2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the one
+/// of the `core` backend, and used to look up the phase of the accumulator in the negacyclic way of
+/// the blind rotation. A noise is then added with the variance predicted by `concrete-npe` for the
+/// bootstrap.
+impl<'a, 'b, 'c>
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextGlweCiphertextView32<'a>,
+        CleartextLweCiphertextView32<'b>,
+        CleartextLweCiphertextMutView32<'c>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut acc_container = vec![0_u32; glwe_dim.to_glwe_size().0 * poly_size.0];
+    /// let mut input_container = vec![0_u32; lwe_dim.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; lwe_dim_output.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let mut acc: CleartextGlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut acc_container[..], poly_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_sk, &mut acc, &plaintext_vector, noise)?;
+    /// engine.destroy(acc)?;
+    /// let acc: CleartextGlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&acc_container[..], poly_size)?;
+    /// let mut input: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&lwe_sk, &mut input, &plaintext, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: CleartextLweCiphertextView32 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'c>,
+        input: &CleartextLweCiphertextView32<'b>,
+        acc: &CleartextGlweCiphertextView32<'a>,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'c>,
+        input: &CleartextLweCiphertextView32<'b>,
+        acc: &CleartextGlweCiphertextView32<'a>,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) {
+        *output.phase = self.bootstrap_phase(*input.phase, acc.phases, bsk, bsk.noise, 1);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of the input is switched to the modulus $2N$, with a rounding error similar to the one
+/// of the `core` backend, and used to look up the phase of the accumulator in the negacyclic way of
+/// the blind rotation. A noise is then added with the variance predicted by `concrete-npe` for the
+/// bootstrap.
+impl<'a, 'b, 'c>
+    LweCiphertextDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextGlweCiphertextView64<'a>,
+        CleartextLweCiphertextView64<'b>,
+        CleartextLweCiphertextMutView64<'c>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut acc_container = vec![0_u64; glwe_dim.to_glwe_size().0 * poly_size.0];
+    /// let mut input_container = vec![0_u64; lwe_dim.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; lwe_dim_output.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let mut acc: CleartextGlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut acc_container[..], poly_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_sk, &mut acc, &plaintext_vector, noise)?;
+    /// engine.destroy(acc)?;
+    /// let acc: CleartextGlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&acc_container[..], poly_size)?;
+    /// let mut input: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&lwe_sk, &mut input, &plaintext, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: CleartextLweCiphertextView64 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'c>,
+        input: &CleartextLweCiphertextView64<'b>,
+        acc: &CleartextGlweCiphertextView64<'a>,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'c>,
+        input: &CleartextLweCiphertextView64<'b>,
+        acc: &CleartextGlweCiphertextView64<'a>,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) {
+        *output.phase = self.bootstrap_phase(*input.phase, acc.phases, bsk, bsk.noise, 1);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweCiphertextDiscardingConversionEngine, LweCiphertextDiscardingConversionError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits LWE ciphertext to a 64 bits LWE ciphertext. The torus elements are exactly
+/// rescaled to the output precision.
+///
+/// The phase of the input is exactly rescaled to the output precision.
+impl LweCiphertextDiscardingConversionEngine<CleartextLweCiphertext32, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext32,
+    ) {
+        output.phase = input.phase.cast_torus_into();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits LWE ciphertext to a 32 bits LWE ciphertext. The torus elements are rounded to
+/// the closest element of the output precision.
+///
+/// The phase of the input is exactly rescaled to the output precision.
+impl LweCiphertextDiscardingConversionEngine<CleartextLweCiphertext64, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext(&mut output, &ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext64,
+    ) {
+        output.phase = input.phase.cast_torus_into();
+    }
+}
@@ -0,0 +1,137 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{
+    LweCiphertextDiscardingDecryptionEngine, LweCiphertextDiscardingDecryptionError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingDecryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingDecryptionEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertext32,
+        Plaintext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut output = engine.create_plaintext(&0_u32)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_decrypt_lwe_ciphertext(&key, &mut output, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut Plaintext32,
+        input: &CleartextLweCiphertext32,
+    ) -> Result<(), LweCiphertextDiscardingDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextDiscardingDecryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_decrypt_lwe_ciphertext_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut Plaintext32,
+        input: &CleartextLweCiphertext32,
+    ) {
+        output.0 = ImplPlaintext(input.phase);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingDecryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingDecryptionEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertext64,
+        Plaintext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut output = engine.create_plaintext(&0_u64)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_decrypt_lwe_ciphertext(&key, &mut output, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut Plaintext64,
+        input: &CleartextLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextDiscardingDecryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_decrypt_lwe_ciphertext_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut Plaintext64,
+        input: &CleartextLweCiphertext64,
+    ) {
+        output.0 = ImplPlaintext(input.phase);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweSecretKey32, CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextDiscardingEncryptionEngine, LweCiphertextDiscardingEncryptionError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingEncryptionEngine<
+        CleartextLweSecretKey32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        output.phase = self.add_noise(input.0 .0, noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingEncryptionEngine<
+        CleartextLweSecretKey64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        output.phase = self.add_noise(input.0 .0, noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextDiscardingEncryptionEngine<
+        CleartextLweSecretKey32,
+        Plaintext32,
+        CleartextLweCiphertextMutView32<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        *output.phase = self.add_noise(input.0 .0, noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextDiscardingEncryptionEngine<
+        CleartextLweSecretKey64,
+        Plaintext64,
+        CleartextLweCiphertextMutView64<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        *output.phase = self.add_noise(input.0 .0, noise);
+    }
+}
@@ -0,0 +1,327 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextGlweCiphertextView32,
+    CleartextGlweCiphertextView64, CleartextLweCiphertext32, CleartextLweCiphertext64,
+    CleartextLweCiphertextMutView32, CleartextLweCiphertextMutView64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingExtractionEngine, LweCiphertextDiscardingExtractionError,
+};
+use crate::specification::entities::{GlweCiphertextEntity, LweCiphertextEntity};
+use concrete_commons::parameters::MonomialDegree;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextDiscardingExtractionEngine<CleartextGlweCiphertext32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let lwe_dimension = LweDimension(8);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(
+    ///     &mut lwe_ciphertext,
+    ///     &glwe_ciphertext,
+    ///     MonomialDegree(0),
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_ciphertext)?;
+    /// engine.destroy(lwe_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextGlweCiphertext32,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.lwe_dimension().0 != input.polynomial_size().0 * input.glwe_dimension().0 {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 >= input.polynomial_size().0 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextGlweCiphertext32,
+        nth: MonomialDegree,
+    ) {
+        output.phase = input.phases[nth.0];
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextDiscardingExtractionEngine<CleartextGlweCiphertext64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let lwe_dimension = LweDimension(8);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let lwe_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let glwe_ciphertext = engine.encrypt_glwe_ciphertext(&glwe_key, &plaintext_vector, noise)?;
+    /// let mut lwe_ciphertext = engine.zero_encrypt_lwe_ciphertext(&lwe_key, noise)?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(
+    ///     &mut lwe_ciphertext,
+    ///     &glwe_ciphertext,
+    ///     MonomialDegree(0),
+    /// )?;
+    /// #
+    /// assert_eq!(lwe_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(lwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_ciphertext)?;
+    /// engine.destroy(lwe_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextGlweCiphertext64,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.lwe_dimension().0 != input.polynomial_size().0 * input.glwe_dimension().0 {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 >= input.polynomial_size().0 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextGlweCiphertext64,
+        nth: MonomialDegree,
+    ) {
+        output.phase = input.phases[nth.0];
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextDiscardingExtractionEngine<
+        CleartextGlweCiphertextView32<'a>,
+        CleartextLweCiphertextMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The target LWE dimension should be equal to the polynomial size + 1
+    /// // since we're going to extract one sample from the GLWE ciphertext
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // We're going to extract the first one
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut glwe_container = vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    /// let mut lwe_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut glwe_view: CleartextGlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut glwe_container[..], polynomial_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_key, &mut glwe_view, &plaintext_vector, noise)?;
+    /// engine.destroy(glwe_view)?;
+    /// let glwe_view: CleartextGlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&glwe_container[..], polynomial_size)?;
+    /// let mut lwe_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut lwe_container[..])?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(&mut lwe_view, &glwe_view, MonomialDegree(0))?;
+    /// #
+    /// assert_eq!(lwe_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_view)?;
+    /// engine.destroy(lwe_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextGlweCiphertextView32<'a>,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.lwe_dimension().0 != input.polynomial_size().0 * input.glwe_dimension().0 {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 >= input.polynomial_size().0 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextGlweCiphertextView32<'a>,
+        nth: MonomialDegree,
+    ) {
+        *output.phase = input.phases[nth.0];
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextDiscardingExtractionEngine<
+        CleartextGlweCiphertextView64<'a>,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The target LWE dimension should be equal to the polynomial size + 1
+    /// // since we're going to extract one sample from the GLWE ciphertext
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // We're going to extract the first one
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut glwe_container = vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    /// let mut lwe_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let glwe_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut glwe_view: CleartextGlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut glwe_container[..], polynomial_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_key, &mut glwe_view, &plaintext_vector, noise)?;
+    /// engine.destroy(glwe_view)?;
+    /// let glwe_view: CleartextGlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&glwe_container[..], polynomial_size)?;
+    /// let mut lwe_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut lwe_container[..])?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(&mut lwe_view, &glwe_view, MonomialDegree(0))?;
+    /// #
+    /// assert_eq!(lwe_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_view)?;
+    /// engine.destroy(lwe_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextGlweCiphertextView64<'a>,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.lwe_dimension().0 != input.polynomial_size().0 * input.glwe_dimension().0 {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 >= input.polynomial_size().0 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextGlweCiphertextView64<'a>,
+        nth: MonomialDegree,
+    ) {
+        *output.phase = input.phases[nth.0];
+    }
+}
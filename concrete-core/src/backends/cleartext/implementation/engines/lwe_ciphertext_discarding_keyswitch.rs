@@ -0,0 +1,727 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweCiphertextView32, CleartextLweCiphertextView64,
+    CleartextLweGaussianCiphertext32, CleartextLweGaussianCiphertext64, CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64, CleartextLweTernaryCiphertext32, CleartextLweTernaryCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweKeyswitchKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey32,
+        CleartextLweCiphertext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey64,
+        CleartextLweCiphertext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweBinaryToTernaryKeyswitchKey32,
+        CleartextLweCiphertext32,
+        CleartextLweTernaryCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut ciphertext_2: CleartextLweTernaryCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&output_key, &zero, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey32,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweBinaryToTernaryKeyswitchKey64,
+        CleartextLweCiphertext64,
+        CleartextLweTernaryCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut ciphertext_2: CleartextLweTernaryCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&output_key, &zero, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey64,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweBinaryToGaussianKeyswitchKey32,
+        CleartextLweCiphertext32,
+        CleartextLweGaussianCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut ciphertext_2: CleartextLweGaussianCiphertext32 =
+    ///     engine.encrypt_lwe_ciphertext(&output_key, &zero, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertext32,
+        input: &CleartextLweCiphertext32,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey32,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweBinaryToGaussianKeyswitchKey64,
+        CleartextLweCiphertext64,
+        CleartextLweGaussianCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut ciphertext_2: CleartextLweGaussianCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&output_key, &zero, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertext64,
+        input: &CleartextLweCiphertext64,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey64,
+    ) {
+        output.phase = self.keyswitch_phase(input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl<'a, 'b>
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey32,
+        CleartextLweCiphertextView32<'a>,
+        CleartextLweCiphertextMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; input_lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; output_lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&input_key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView32 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut output_view, &input_view, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextLweCiphertextView32<'a>,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextLweCiphertextView32<'a>,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) {
+        *output.phase = self.keyswitch_phase(*input.phase, ksk, ksk.noise);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of the input is kept, and a noise is added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl<'a, 'b>
+    LweCiphertextDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey64,
+        CleartextLweCiphertextView64<'a>,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; input_lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; output_lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&input_key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView64 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut output_view, &input_view, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextLweCiphertextView64<'a>,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextLweCiphertextView64<'a>,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) {
+        *output.phase = self.keyswitch_phase(*input.phase, ksk, ksk.noise);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingStoringEngine, LweCiphertextDiscardingStoringError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextDiscardingStoringEngine<CleartextLweCiphertext32, CleartextLweCiphertextVector32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(0),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut CleartextLweCiphertextVector32,
+        ciphertext: &CleartextLweCiphertext32,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        if ciphertext.lwe_dimension() != vector.lwe_dimension() {
+            return Err(LweCiphertextDiscardingStoringError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingStoringError::IndexTooLarge);
+        }
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut CleartextLweCiphertextVector32,
+        ciphertext: &CleartextLweCiphertext32,
+        i: LweCiphertextIndex,
+    ) {
+        vector.phases[i.0] = ciphertext.phase;
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextDiscardingStoringEngine<CleartextLweCiphertext64, CleartextLweCiphertextVector64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(0),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut CleartextLweCiphertextVector64,
+        ciphertext: &CleartextLweCiphertext64,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        if ciphertext.lwe_dimension() != vector.lwe_dimension() {
+            return Err(LweCiphertextDiscardingStoringError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingStoringError::IndexTooLarge);
+        }
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut CleartextLweCiphertextVector64,
+        ciphertext: &CleartextLweCiphertext64,
+        i: LweCiphertextIndex,
+    ) {
+        vector.phases[i.0] = ciphertext.phase;
+    }
+}
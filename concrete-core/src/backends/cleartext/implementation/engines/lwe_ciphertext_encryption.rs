@@ -0,0 +1,380 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweGaussianCiphertext32,
+    CleartextLweGaussianCiphertext64, CleartextLweGaussianSecretKey32,
+    CleartextLweGaussianSecretKey64, CleartextLweSecretKey32, CleartextLweSecretKey64,
+    CleartextLweTernaryCiphertext32, CleartextLweTernaryCiphertext64,
+    CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl LweCiphertextEncryptionEngine<CleartextLweSecretKey32, Plaintext32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl LweCiphertextEncryptionEngine<CleartextLweSecretKey64, Plaintext64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweCiphertextEncryptionEngine<
+        CleartextLweTernarySecretKey32,
+        Plaintext32,
+        CleartextLweTernaryCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<CleartextLweTernaryCiphertext32, LweCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> CleartextLweTernaryCiphertext32 {
+        CleartextLweTernaryCiphertext32 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweCiphertextEncryptionEngine<
+        CleartextLweTernarySecretKey64,
+        Plaintext64,
+        CleartextLweTernaryCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<CleartextLweTernaryCiphertext64, LweCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> CleartextLweTernaryCiphertext64 {
+        CleartextLweTernaryCiphertext64 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweCiphertextEncryptionEngine<
+        CleartextLweGaussianSecretKey32,
+        Plaintext32,
+        CleartextLweGaussianCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<CleartextLweGaussianCiphertext32, LweCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> CleartextLweGaussianCiphertext32 {
+        CleartextLweGaussianCiphertext32 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweCiphertextEncryptionEngine<
+        CleartextLweGaussianSecretKey64,
+        Plaintext64,
+        CleartextLweGaussianCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<CleartextLweGaussianCiphertext64, LweCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> CleartextLweGaussianCiphertext64 {
+        CleartextLweGaussianCiphertext64 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweCiphertextView32, CleartextLweCiphertextView64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextFusingAdditionEngine<CleartextLweCiphertext32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_1 = 3_u32 << 20;
+    /// let input_2 = 7_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let mut ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextFusingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &CleartextLweCiphertext32,
+    ) {
+        output.phase = output.phase.wrapping_add(input.phase);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextFusingAdditionEngine<CleartextLweCiphertext64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let mut ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextFusingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &CleartextLweCiphertext64,
+    ) {
+        output.phase = output.phase.wrapping_add(input.phase);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextFusingAdditionEngine<
+        CleartextLweCiphertextView32<'a>,
+        CleartextLweCiphertextMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView32 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut output_view, &input_view)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextLweCiphertextView32<'a>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextFusingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input: &CleartextLweCiphertextView32<'a>,
+    ) {
+        *output.phase = output.phase.wrapping_add(*input.phase);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextFusingAdditionEngine<
+        CleartextLweCiphertextView64<'a>,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView64 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut output_view, &input_view)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextLweCiphertextView64<'a>,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextFusingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input: &CleartextLweCiphertextView64<'a>,
+    ) {
+        *output.phase = output.phase.wrapping_add(*input.phase);
+    }
+}
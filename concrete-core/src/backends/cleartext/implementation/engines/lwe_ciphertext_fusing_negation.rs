@@ -0,0 +1,210 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64,
+};
+use crate::specification::engines::{
+    LweCiphertextFusingNegationEngine, LweCiphertextFusingNegationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextFusingNegationEngine<CleartextLweCiphertext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut CleartextLweCiphertext32,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(&mut self, input: &mut CleartextLweCiphertext32) {
+        input.phase = input.phase.wrapping_neg();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextFusingNegationEngine<CleartextLweCiphertext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut CleartextLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(&mut self, input: &mut CleartextLweCiphertext64) {
+        input.phase = input.phase.wrapping_neg();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextFusingNegationEngine<CleartextLweCiphertextMutView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext_view)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut CleartextLweCiphertextMutView32<'a>,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &mut CleartextLweCiphertextMutView32<'a>,
+    ) {
+        *input.phase = input.phase.wrapping_neg();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextFusingNegationEngine<CleartextLweCiphertextMutView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext_view)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut CleartextLweCiphertextMutView64<'a>,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &mut CleartextLweCiphertextMutView64<'a>,
+    ) {
+        *input.phase = input.phase.wrapping_neg();
+    }
+}
@@ -0,0 +1,285 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextGgswCiphertext32, CleartextGgswCiphertext64, CleartextLweCiphertext32,
+    CleartextLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine,
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError as CircuitBootstrapError,
+};
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, GgswCiphertextEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+///
+/// The bit encoded in the most significant bit of the input phase is recovered with a simulated
+/// bootstrap, and becomes the scalar of the output. The variance of the output is the one predicted
+/// by `concrete-npe` for the bootstrap followed by the private functional packing keyswitch.
+impl
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        CleartextLweCiphertext32,
+        CleartextGgswCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (pfpksk_lc, pfpksk_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let (ggsw_lc, ggsw_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(4));
+    /// // The input bit is encoded in the most significant bit
+    /// let input = 1_u32 << (32 - 1);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let ggsw_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let cbs_pfpksk: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &glwe_sk, &ggsw_sk, pfpksk_lc, pfpksk_bl, noise,
+    ///     )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut output: CleartextGgswCiphertext32 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     ggsw_lc,
+    ///     ggsw_bl,
+    ///     &zero,
+    /// )?;
+    ///
+    /// engine.discard_circuit_bootstrap_lwe_ciphertext(&mut output, &input, &bsk, &cbs_pfpksk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(ggsw_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGgswCiphertext32,
+        input: &CleartextLweCiphertext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+        cbs_pfpksk: &CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<(), CircuitBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::InputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::KeysInputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(CircuitBootstrapError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(CircuitBootstrapError::OutputPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != output.glwe_dimension().to_glwe_size().0 {
+            return Err(CircuitBootstrapError::KeyCountMismatch);
+        }
+        if output.decomposition_base_log().0 * output.decomposition_level_count().0 >= 32 {
+            return Err(CircuitBootstrapError::DecompositionTooLarge);
+        }
+        unsafe {
+            self.discard_circuit_bootstrap_lwe_ciphertext_unchecked(output, input, bsk, cbs_pfpksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGgswCiphertext32,
+        input: &CleartextLweCiphertext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+        cbs_pfpksk: &CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+        // The input phase is shifted by q/4, and bootstrapped with an accumulator which maps the
+        // bit m to (2m - 1)q/2B, shifted in turn by q/2B to get mq/B, as in the first level
+        // of the core backend.
+        let base_log = output.decomposition_base_log.0;
+        let half_scale = 1_u32 << (u32::BITS as usize - 1 - base_log);
+        let shifted = input.phase.wrapping_add(1 << (u32::BITS - 2));
+        let accumulator = vec![half_scale.wrapping_neg(); bsk.polynomial_size.0];
+        let extracted = self
+            .bootstrap_phase(shifted, &accumulator, bsk, bsk.noise, 1)
+            .wrapping_add(half_scale);
+        let bootstrap_variance =
+            super::bootstrap_variance::<u32, _>(bsk, bsk.noise, bsk.input_lwe_dimension);
+        let keyswitch_variance = super::keyswitch_variance::<u32>(
+            cbs_pfpksk.input_lwe_dimension,
+            cbs_pfpksk.noise,
+            cbs_pfpksk.decomposition_base_log,
+            cbs_pfpksk.decomposition_level_count,
+        );
+        output.plaintext =
+            (extracted.wrapping_add(half_scale) >> (u32::BITS as usize - base_log)) & 1;
+        output.noise = Variance(bootstrap_variance.0 + keyswitch_variance.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+///
+/// The bit encoded in the most significant bit of the input phase is recovered with a simulated
+/// bootstrap, and becomes the scalar of the output. The variance of the output is the one predicted
+/// by `concrete-npe` for the bootstrap followed by the private functional packing keyswitch.
+impl
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        CleartextLweCiphertext64,
+        CleartextGgswCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (pfpksk_lc, pfpksk_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let (ggsw_lc, ggsw_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(4));
+    /// // The input bit is encoded in the most significant bit
+    /// let input = 1_u64 << (64 - 1);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let ggsw_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let cbs_pfpksk: CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &glwe_sk, &ggsw_sk, pfpksk_lc, pfpksk_bl, noise,
+    ///     )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut output: CleartextGgswCiphertext64 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     ggsw_lc,
+    ///     ggsw_bl,
+    ///     &zero,
+    /// )?;
+    ///
+    /// engine.discard_circuit_bootstrap_lwe_ciphertext(&mut output, &input, &bsk, &cbs_pfpksk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(ggsw_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextGgswCiphertext64,
+        input: &CleartextLweCiphertext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+        cbs_pfpksk: &CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), CircuitBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::InputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::KeysInputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(CircuitBootstrapError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(CircuitBootstrapError::OutputPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != output.glwe_dimension().to_glwe_size().0 {
+            return Err(CircuitBootstrapError::KeyCountMismatch);
+        }
+        if output.decomposition_base_log().0 * output.decomposition_level_count().0 >= 64 {
+            return Err(CircuitBootstrapError::DecompositionTooLarge);
+        }
+        unsafe {
+            self.discard_circuit_bootstrap_lwe_ciphertext_unchecked(output, input, bsk, cbs_pfpksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextGgswCiphertext64,
+        input: &CleartextLweCiphertext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+        cbs_pfpksk: &CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+        // The input phase is shifted by q/4, and bootstrapped with an accumulator which maps the
+        // bit m to (2m - 1)q/2B, shifted in turn by q/2B to get mq/B, as in the first level
+        // of the core backend.
+        let base_log = output.decomposition_base_log.0;
+        let half_scale = 1_u64 << (u64::BITS as usize - 1 - base_log);
+        let shifted = input.phase.wrapping_add(1 << (u64::BITS - 2));
+        let accumulator = vec![half_scale.wrapping_neg(); bsk.polynomial_size.0];
+        let extracted = self
+            .bootstrap_phase(shifted, &accumulator, bsk, bsk.noise, 1)
+            .wrapping_add(half_scale);
+        let bootstrap_variance =
+            super::bootstrap_variance::<u64, _>(bsk, bsk.noise, bsk.input_lwe_dimension);
+        let keyswitch_variance = super::keyswitch_variance::<u64>(
+            cbs_pfpksk.input_lwe_dimension,
+            cbs_pfpksk.noise,
+            cbs_pfpksk.decomposition_base_log,
+            cbs_pfpksk.decomposition_level_count,
+        );
+        output.plaintext =
+            (extracted.wrapping_add(half_scale) >> (u64::BITS as usize - base_log)) & 1;
+        output.noise = Variance(bootstrap_variance.0 + keyswitch_variance.0);
+    }
+}
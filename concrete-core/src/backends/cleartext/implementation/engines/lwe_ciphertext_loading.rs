@@ -0,0 +1,126 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64,
+};
+use crate::specification::engines::{LweCiphertextLoadingEngine, LweCiphertextLoadingError};
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextLoadingEngine<CleartextLweCiphertextVector32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext = engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(1))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &CleartextLweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextLoadingError<Self::EngineError>> {
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextLoadingError::IndexTooLarge);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &CleartextLweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: vector.phases[i.0],
+            lwe_dimension: vector.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextLoadingEngine<CleartextLweCiphertextVector64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext = engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(1))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &CleartextLweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextLoadingError<Self::EngineError>> {
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextLoadingError::IndexTooLarge);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &CleartextLweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: vector.phases[i.0],
+            lwe_dimension: vector.lwe_dimension(),
+        }
+    }
+}
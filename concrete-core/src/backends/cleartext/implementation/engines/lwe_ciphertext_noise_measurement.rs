@@ -0,0 +1,140 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextNoiseMeasurementEngine, LweCiphertextNoiseMeasurementError, NoiseMeasurement,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The error is the difference between the phase of the ciphertext and the expected plaintext.
+impl
+    LweCiphertextNoiseMeasurementEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertext32,
+        Plaintext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measurement = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(measurement.precision, 32);
+    /// assert!(measurement.bit_size < 25);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertext32,
+        expected: &Plaintext32,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextNoiseMeasurementError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertext32,
+        expected: &Plaintext32,
+    ) -> NoiseMeasurement {
+        let error = input.phase.wrapping_sub(expected.0 .0);
+        NoiseMeasurement::from_error(error as i32 as i64, 32)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The error is the difference between the phase of the ciphertext and the expected plaintext.
+impl
+    LweCiphertextNoiseMeasurementEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertext64,
+        Plaintext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measurement = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(measurement.precision, 64);
+    /// assert!(measurement.bit_size < 45);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertext64,
+        expected: &Plaintext64,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextNoiseMeasurementError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertext64,
+        expected: &Plaintext64,
+    ) -> NoiseMeasurement {
+        let error = input.phase.wrapping_sub(expected.0 .0);
+        NoiseMeasurement::from_error(error as i64, 64)
+    }
+}
@@ -0,0 +1,284 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64, CleartextLweCiphertextView32, CleartextLweCiphertextView64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextPlaintextDiscardingAdditionEngine, LweCiphertextPlaintextDiscardingAdditionError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextPlaintextDiscardingAdditionEngine<
+        CleartextLweCiphertext32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut output_ciphertext, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input_1: &CleartextLweCiphertext32,
+        input_2: &Plaintext32,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input_1: &CleartextLweCiphertext32,
+        input_2: &Plaintext32,
+    ) {
+        output.phase = input_1.phase.wrapping_add(input_2.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextPlaintextDiscardingAdditionEngine<
+        CleartextLweCiphertext64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut output_ciphertext, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input_1: &CleartextLweCiphertext64,
+        input_2: &Plaintext64,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input_1: &CleartextLweCiphertext64,
+        input_2: &Plaintext64,
+    ) {
+        output.phase = input_1.phase.wrapping_add(input_2.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextPlaintextDiscardingAdditionEngine<
+        CleartextLweCiphertextView32<'a>,
+        Plaintext32,
+        CleartextLweCiphertextMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView32 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut output_view, &input_view, &plaintext)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input_1: &CleartextLweCiphertextView32<'a>,
+        input_2: &Plaintext32,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        if input_1.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'b>,
+        input_1: &CleartextLweCiphertextView32<'a>,
+        input_2: &Plaintext32,
+    ) {
+        *output.phase = input_1.phase.wrapping_add(input_2.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextDiscardingAdditionEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextPlaintextDiscardingAdditionEngine<
+        CleartextLweCiphertextView64<'a>,
+        Plaintext64,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextView64 =
+    ///     engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_add_lwe_ciphertext_plaintext(&mut output_view, &input_view, &plaintext)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input_1: &CleartextLweCiphertextView64<'a>,
+        input_2: &Plaintext64,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError<Self::EngineError>> {
+        if input_1.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextPlaintextDiscardingAdditionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_add_lwe_ciphertext_plaintext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        input_1: &CleartextLweCiphertextView64<'a>,
+        input_2: &Plaintext64,
+    ) {
+        *output.phase = input_1.phase.wrapping_add(input_2.0 .0);
+    }
+}
@@ -0,0 +1,231 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView32,
+    CleartextLweCiphertextMutView64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextPlaintextFusingAdditionEngine, LweCiphertextPlaintextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl LweCiphertextPlaintextFusingAdditionEngine<CleartextLweCiphertext32, Plaintext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &Plaintext32,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        input: &Plaintext32,
+    ) {
+        output.phase = output.phase.wrapping_add(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextPlaintextFusingAdditionEngine<CleartextLweCiphertext64, Plaintext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &Plaintext64,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        input: &Plaintext64,
+    ) {
+        output.phase = output.phase.wrapping_add(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextPlaintextFusingAdditionEngine<CleartextLweCiphertextMutView32<'a>, Plaintext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext_view, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Plaintext32,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView32<'a>,
+        input: &Plaintext32,
+    ) {
+        *output.phase = output.phase.wrapping_add(input.0 .0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPlaintextFusingAdditionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextPlaintextFusingAdditionEngine<CleartextLweCiphertextMutView64<'a>, Plaintext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext_plaintext(&mut ciphertext_view, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext_plaintext(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Plaintext64,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError<Self::EngineError>> {
+        unsafe { self.fuse_add_lwe_ciphertext_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_plaintext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'a>,
+        input: &Plaintext64,
+    ) {
+        *output.phase = output.phase.wrapping_add(input.0 .0);
+    }
+}
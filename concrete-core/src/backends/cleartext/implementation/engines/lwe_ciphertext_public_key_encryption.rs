@@ -0,0 +1,149 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLwePublicKey32,
+    CleartextLwePublicKey64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweCiphertextPublicKeyEncryptionEngine, LweCiphertextPublicKeyEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the variance predicted
+/// by `concrete-npe` for the sum of the encryptions of zero of the public key.
+impl
+    LweCiphertextPublicKeyEncryptionEngine<
+        CleartextLwePublicKey32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let secret_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: CleartextLwePublicKey32 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &CleartextLwePublicKey32,
+        input: &Plaintext32,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextPublicKeyEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &CleartextLwePublicKey32,
+        input: &Plaintext32,
+    ) -> CleartextLweCiphertext32 {
+        let variance = concrete_npe::estimate_lwe_public_key_encryption_noise::<u32, _>(
+            key.noise,
+            key.lwe_zero_encryption_count,
+        );
+        CleartextLweCiphertext32 {
+            phase: self.add_noise(input.0 .0, variance),
+            lwe_dimension: key.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the variance predicted
+/// by `concrete-npe` for the sum of the encryptions of zero of the public key.
+impl
+    LweCiphertextPublicKeyEncryptionEngine<
+        CleartextLwePublicKey64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let secret_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: CleartextLwePublicKey64 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &CleartextLwePublicKey64,
+        input: &Plaintext64,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextPublicKeyEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &CleartextLwePublicKey64,
+        input: &Plaintext64,
+    ) -> CleartextLweCiphertext64 {
+        let variance = concrete_npe::estimate_lwe_public_key_encryption_noise::<u64, _>(
+            key.noise,
+            key.lwe_zero_encryption_count,
+        );
+        CleartextLweCiphertext64 {
+            phase: self.add_noise(input.0 .0, variance),
+            lwe_dimension: key.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector64, CleartextLweCompressedCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorCompressionEngine, LweCiphertextVectorCompressionError,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCompressionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers, and stores the compressed coefficients on 32 bits.
+///
+/// The phases of the inputs are switched to the modulus of the compression, with a rounding error
+/// similar to the one of the `core` backend.
+impl
+    LweCiphertextVectorCompressionEngine<
+        CleartextLweCiphertextVector64,
+        CleartextLweCompressedCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertexts are switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let compressed: CleartextLweCompressedCiphertextVector64 =
+    ///     engine.compress_lwe_ciphertext_vector(&ciphertext_vector, modulus_log)?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    /// assert_eq!(compressed.modulus_log(), modulus_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(compressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweCiphertextVector64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        CleartextLweCompressedCiphertextVector64,
+        LweCiphertextVectorCompressionError<Self::EngineError>,
+    > {
+        if modulus_log.0 == 0 {
+            return Err(LweCiphertextVectorCompressionError::NullModulusLog);
+        }
+        if modulus_log.0 > 32 {
+            return Err(LweCiphertextVectorCompressionError::ModulusLogTooLarge);
+        }
+        Ok(unsafe { self.compress_lwe_ciphertext_vector_unchecked(input, modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertextVector64,
+        modulus_log: CiphertextModulusLog,
+    ) -> CleartextLweCompressedCiphertextVector64 {
+        CleartextLweCompressedCiphertextVector64 {
+            compressed_phases: input
+                .phases
+                .iter()
+                .map(|phase| {
+                    self.modulus_switch(*phase, input.lwe_dimension, 1 << modulus_log.0) as u32
+                })
+                .collect(),
+            lwe_dimension: input.lwe_dimension,
+            modulus_log,
+        }
+    }
+}
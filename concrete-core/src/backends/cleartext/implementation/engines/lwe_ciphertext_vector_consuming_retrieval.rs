@@ -0,0 +1,149 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorConsumingRetrievalEngine, LweCiphertextVectorConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 32 bits integers of an LWE ciphertext vector.
+///
+/// The container follows the layout of the `core` backend, with null masks and the phases of the
+/// ciphertexts as bodies, i.e. it holds trivial encryptions of the phases.
+impl LweCiphertextVectorConsumingRetrievalEngine<CleartextLweCiphertextVector32, Vec<u32>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0 * input.len());
+    ///
+    /// // The container can be used to create the ciphertext vector again
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_dimension.to_lwe_size())?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: CleartextLweCiphertextVector32,
+    ) -> Result<Vec<u32>, LweCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: CleartextLweCiphertextVector32,
+    ) -> Vec<u32> {
+        let lwe_size = ciphertext_vector.lwe_dimension.to_lwe_size();
+        let mut container = vec![0; ciphertext_vector.phases.len() * lwe_size.0];
+        for (ciphertext, phase) in container
+            .chunks_mut(lwe_size.0)
+            .zip(ciphertext_vector.phases.iter())
+        {
+            *ciphertext.last_mut().unwrap() = *phase;
+        }
+        container
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 64 bits integers of an LWE ciphertext vector.
+///
+/// The container follows the layout of the `core` backend, with null masks and the phases of the
+/// ciphertexts as bodies, i.e. it holds trivial encryptions of the phases.
+impl LweCiphertextVectorConsumingRetrievalEngine<CleartextLweCiphertextVector64, Vec<u64>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = vec![3_u64 << 52; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_ciphertext_vector(ciphertext_vector)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0 * input.len());
+    ///
+    /// // The container can be used to create the ciphertext vector again
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_dimension.to_lwe_size())?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext_vector(
+        &mut self,
+        ciphertext_vector: CleartextLweCiphertextVector64,
+    ) -> Result<Vec<u64>, LweCiphertextVectorConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_vector_unchecked(ciphertext_vector) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        ciphertext_vector: CleartextLweCiphertextVector64,
+    ) -> Vec<u64> {
+        let lwe_size = ciphertext_vector.lwe_dimension.to_lwe_size();
+        let mut container = vec![0; ciphertext_vector.phases.len() * lwe_size.0];
+        for (ciphertext, phase) in container
+            .chunks_mut(lwe_size.0)
+            .zip(ciphertext_vector.phases.iter())
+        {
+            *ciphertext.last_mut().unwrap() = *phase;
+        }
+        container
+    }
+}
@@ -0,0 +1,142 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweCiphertextVectorConversionEngine, LweCiphertextVectorConversionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CleartextEngine`] that converts
+/// a 32 bits LWE ciphertext vector to a 64 bits LWE ciphertext vector. The torus elements are
+/// exactly rescaled to the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    LweCiphertextVectorConversionEngine<
+        CleartextLweCiphertextVector32,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextLweCiphertextVector64 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweCiphertextVector32,
+    ) -> Result<CleartextLweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertextVector32,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CleartextEngine`] that converts
+/// a 64 bits LWE ciphertext vector to a 32 bits LWE ciphertext vector. The torus elements are
+/// rounded to the closest element of the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    LweCiphertextVectorConversionEngine<
+        CleartextLweCiphertextVector64,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: CleartextLweCiphertextVector32 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweCiphertextVector64,
+    ) -> Result<CleartextLweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweCiphertextVector64,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: input
+                .phases
+                .iter()
+                .map(|phase| phase.cast_torus_into())
+                .collect(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,416 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView32, CleartextLweCiphertextVectorMutView64,
+    CleartextLweCiphertextVectorView32, CleartextLweCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorCreationEngine, LweCiphertextVectorCreationError,
+};
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns an
+/// LWE ciphertext vector, taking ownership of a vector of 32 bits integers.
+///
+/// The masks of the ciphertexts of the container are ignored, and their bodies are taken as the
+/// phases of the ciphertexts.
+impl LweCiphertextVectorCreationEngine<Vec<u32>, CleartextLweCiphertextVector32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_dimension(),
+    ///     lwe_size.to_lwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> Result<CleartextLweCiphertextVector32, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: container
+                .chunks(lwe_size.0)
+                .map(|ciphertext| *ciphertext.last().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns an
+/// LWE ciphertext vector, taking ownership of a vector of 64 bits integers.
+///
+/// The masks of the ciphertexts of the container are ignored, and their bodies are taken as the
+/// phases of the ciphertexts.
+impl LweCiphertextVectorCreationEngine<Vec<u64>, CleartextLweCiphertextVector64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_dimension(),
+    ///     lwe_size.to_lwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> Result<CleartextLweCiphertextVector64, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: container
+                .chunks(lwe_size.0)
+                .map(|ciphertext| *ciphertext.last().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns
+/// the view of an LWE ciphertext vector, borrowing an immutable slice of 32 bits integers.
+///
+/// Only the bodies of the ciphertexts of the slice, which hold their phases, are viewed.
+impl<'a> LweCiphertextVectorCreationEngine<&'a [u32], CleartextLweCiphertextVectorView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a [u32],
+        lwe_size: LweSize,
+    ) -> Result<
+        CleartextLweCiphertextVectorView32<'a>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a [u32],
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVectorView32<'a> {
+        CleartextLweCiphertextVectorView32 {
+            phases: container
+                .chunks(lwe_size.0)
+                .map(|ciphertext| ciphertext.last().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns
+/// the view of an LWE ciphertext vector, borrowing an immutable slice of 64 bits integers.
+///
+/// Only the bodies of the ciphertexts of the slice, which hold their phases, are viewed.
+impl<'a> LweCiphertextVectorCreationEngine<&'a [u64], CleartextLweCiphertextVectorView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a [u64],
+        lwe_size: LweSize,
+    ) -> Result<
+        CleartextLweCiphertextVectorView64<'a>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a [u64],
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVectorView64<'a> {
+        CleartextLweCiphertextVectorView64 {
+            phases: container
+                .chunks(lwe_size.0)
+                .map(|ciphertext| ciphertext.last().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns
+/// the view of an LWE ciphertext vector, borrowing a mutable slice of 32 bits integers.
+///
+/// Only the bodies of the ciphertexts of the slice, which hold their phases, are viewed.
+impl<'a> LweCiphertextVectorCreationEngine<&'a mut [u32], CleartextLweCiphertextVectorMutView32<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let mut container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a mut [u32],
+        lwe_size: LweSize,
+    ) -> Result<
+        CleartextLweCiphertextVectorMutView32<'a>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVectorMutView32<'a> {
+        CleartextLweCiphertextVectorMutView32 {
+            phases: container
+                .chunks_mut(lwe_size.0)
+                .map(|ciphertext| ciphertext.last_mut().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CleartextEngine`] which returns
+/// the view of an LWE ciphertext vector, borrowing a mutable slice of 64 bits integers.
+///
+/// Only the bodies of the ciphertexts of the slice, which hold their phases, are viewed.
+impl<'a> LweCiphertextVectorCreationEngine<&'a mut [u64], CleartextLweCiphertextVectorMutView64<'a>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let mut container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let ciphertext_view: CleartextLweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a mut [u64],
+        lwe_size: LweSize,
+    ) -> Result<
+        CleartextLweCiphertextVectorMutView64<'a>,
+        LweCiphertextVectorCreationError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+        lwe_size: LweSize,
+    ) -> CleartextLweCiphertextVectorMutView64<'a> {
+        CleartextLweCiphertextVectorMutView64 {
+            phases: container
+                .chunks_mut(lwe_size.0)
+                .map(|ciphertext| ciphertext.last_mut().unwrap())
+                .collect(),
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector64, CleartextLweCompressedCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDecompressionEngine, LweCiphertextVectorDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecompressionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The compressed phases are lifted back to the modulus of the output.
+impl
+    LweCiphertextVectorDecompressionEngine<
+        CleartextLweCompressedCiphertextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertexts are switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed: CleartextLweCompressedCiphertextVector64 =
+    ///     engine.compress_lwe_ciphertext_vector(&ciphertext_vector, modulus_log)?;
+    ///
+    /// let decompressed: CleartextLweCiphertextVector64 =
+    ///     engine.decompress_lwe_ciphertext_vector(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(decompressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(compressed)?;
+    /// engine.destroy(decompressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweCompressedCiphertextVector64,
+    ) -> Result<
+        CleartextLweCiphertextVector64,
+        LweCiphertextVectorDecompressionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.decompress_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweCompressedCiphertextVector64,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: input
+                .compressed_phases
+                .iter()
+                .map(|phase| (*phase as u64) << (u64::BITS as usize - input.modulus_log.0))
+                .collect(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
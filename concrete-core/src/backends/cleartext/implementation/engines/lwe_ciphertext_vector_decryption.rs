@@ -0,0 +1,548 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorView32, CleartextLweCiphertextVectorView64,
+    CleartextLweGaussianCiphertextVector32, CleartextLweGaussianCiphertextVector64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64, CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDecryptionError,
+};
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector, plaintext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector, plaintext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweTernarySecretKey32,
+        CleartextLweTernaryCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &CleartextLweTernaryCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweTernarySecretKey32,
+        input: &CleartextLweTernaryCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweTernarySecretKey64,
+        CleartextLweTernaryCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &CleartextLweTernaryCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweTernarySecretKey64,
+        input: &CleartextLweTernaryCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweGaussianSecretKey32,
+        CleartextLweGaussianCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &CleartextLweGaussianCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweGaussianSecretKey32,
+        input: &CleartextLweGaussianCiphertextVector32,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweGaussianSecretKey64,
+        CleartextLweGaussianCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &CleartextLweGaussianCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDecryptionError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweGaussianSecretKey64,
+        input: &CleartextLweGaussianCiphertextVector64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(input.phases.clone()))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertextVectorView32<'a>,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: CleartextLweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// let ciphertext_vector_view: CleartextLweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector_view)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+    ) -> PlaintextVector32 {
+        let phases: Vec<u32> = input.phases.iter().map(|phase| **phase).collect();
+        PlaintextVector32(ImplPlaintextList::from_container(phases))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDecryptionEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertextVectorView64<'a>,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: CleartextLweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// let ciphertext_vector_view: CleartextLweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector_view)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+    ) -> PlaintextVector64 {
+        let phases: Vec<u64> = input.phases.iter().map(|phase| **phase).collect();
+        PlaintextVector64(ImplPlaintextList::from_container(phases))
+    }
+}
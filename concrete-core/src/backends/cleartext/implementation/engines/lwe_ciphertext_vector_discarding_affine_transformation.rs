@@ -0,0 +1,424 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweCiphertextMutView64,
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView64, CleartextLweCiphertextVectorView64,
+};
+use crate::backends::core::entities::{
+    CleartextVector32, CleartextVector64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingAffineTransformationEngine,
+    LweCiphertextVectorDiscardingAffineTransformationError,
+};
+use crate::specification::entities::{
+    CleartextVectorEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        CleartextLweCiphertextVector32,
+        CleartextVector32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 8];
+    /// let weights_input = vec![2_u32; 8];
+    /// let bias_input = 8_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector32 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext32 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        inputs: &CleartextLweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext32,
+        inputs: &CleartextLweCiphertextVector32,
+        weights: &CleartextVector32,
+        bias: &Plaintext32,
+    ) {
+        output.phase = inputs
+            .phases
+            .iter()
+            .zip(weights.0.as_tensor().iter())
+            .fold(bias.0 .0, |acc, (phase, weight)| {
+                acc.wrapping_add(phase.wrapping_mul(*weight))
+            });
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        CleartextLweCiphertextVector64,
+        CleartextVector64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        inputs: &CleartextLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertext64,
+        inputs: &CleartextLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        output.phase = inputs
+            .phases
+            .iter()
+            .zip(weights.0.as_tensor().iter())
+            .fold(bias.0 .0, |acc, (phase, weight)| {
+                acc.wrapping_add(phase.wrapping_mul(*weight))
+            });
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers, on views of ciphertexts stored in
+/// borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        CleartextLweCiphertextVectorView64<'a>,
+        CleartextVector64,
+        Plaintext64,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let mut input_view: CleartextLweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(&mut input_container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dimension.to_lwe_size())?;
+    /// let mut output_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        inputs: &CleartextLweCiphertextVectorView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        inputs: &CleartextLweCiphertextVectorView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        *output.phase = inputs
+            .phases
+            .iter()
+            .zip(weights.0.as_tensor().iter())
+            .fold(bias.0 .0, |acc, (phase, weight)| {
+                acc.wrapping_add(phase.wrapping_mul(*weight))
+            });
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers, on mutable views of ciphertexts stored in
+/// borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        CleartextLweCiphertextVectorMutView64<'a>,
+        CleartextVector64,
+        Plaintext64,
+        CleartextLweCiphertextMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let mut input_view: CleartextLweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(&mut input_container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// let mut output_view: CleartextLweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        inputs: &CleartextLweCiphertextVectorMutView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextMutView64<'b>,
+        inputs: &CleartextLweCiphertextVectorMutView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        *output.phase = inputs
+            .phases
+            .iter()
+            .zip(weights.0.as_tensor().iter())
+            .fold(bias.0 .0, |acc, (phase, weight)| {
+                acc.wrapping_add(phase.wrapping_mul(*weight))
+            });
+    }
+}
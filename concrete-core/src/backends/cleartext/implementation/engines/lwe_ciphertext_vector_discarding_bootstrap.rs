@@ -0,0 +1,774 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextGlweCiphertextVector32, CleartextGlweCiphertextVector64, CleartextLweBootstrapKey32,
+    CleartextLweBootstrapKey64, CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView32, CleartextLweCiphertextVectorMutView64,
+    CleartextLweCiphertextVectorView32, CleartextLweCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextLweBootstrapKey32,
+        CleartextGlweCiphertextVector32,
+        CleartextLweCiphertextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            *output = self.bootstrap_phase(*input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextLweBootstrapKey64,
+        CleartextGlweCiphertextVector64,
+        CleartextLweCiphertextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            *output = self.bootstrap_phase(*input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextGlweCiphertextVector32,
+        CleartextLweCiphertextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            *output = self.bootstrap_phase(*input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextGlweCiphertextVector64,
+        CleartextLweCiphertextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            *output = self.bootstrap_phase(*input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextGlweCiphertextVector32,
+        CleartextLweCiphertextVectorView32<'a>,
+        CleartextLweCiphertextVectorMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; lwe_dim.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u32; lwe_dim_output.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let mut input: CleartextLweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut input_container[..], lwe_dim.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(&lwe_sk, &mut input, &plaintext_vector, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: CleartextLweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dim.to_lwe_size())?;
+    /// let mut output: CleartextLweCiphertextVectorMutView32 = engine
+    ///     .create_lwe_ciphertext_vector(&mut output_container[..], lwe_dim_output.to_lwe_size())?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView32<'b>,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView32<'b>,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+        acc: &CleartextGlweCiphertextVector32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            **output = self.bootstrap_phase(**input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// Each input is bootstrapped as a single ciphertext would be, with the accumulator of the same
+/// index, or the single accumulator of the vector if it only contains one.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextGlweCiphertextVector64,
+        CleartextLweCiphertextVectorView64<'a>,
+        CleartextLweCiphertextVectorMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dim.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u64; lwe_dim_output.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let mut input: CleartextLweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut input_container[..], lwe_dim.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(&lwe_sk, &mut input, &plaintext_vector, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: CleartextLweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dim.to_lwe_size())?;
+    /// let mut output: CleartextLweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(&mut output_container[..], lwe_dim_output.to_lwe_size())?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView64<'b>,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView64<'b>,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+        acc: &CleartextGlweCiphertextVector64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) {
+        let accumulators: Vec<_> = acc.phases.chunks(acc.polynomial_size().0).collect();
+        for (i, (output, input)) in output
+            .phases
+            .iter_mut()
+            .zip(input.phases.iter())
+            .enumerate()
+        {
+            let accumulator = accumulators[i % accumulators.len()];
+            **output = self.bootstrap_phase(**input, accumulator, bsk, bsk.noise, 1);
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingConversionEngine, LweCiphertextVectorDiscardingConversionError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits LWE ciphertext vector to a 64 bits LWE ciphertext vector. The torus elements
+/// are exactly rescaled to the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    LweCiphertextVectorDiscardingConversionEngine<
+        CleartextLweCiphertextVector32,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingConversionError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = input_phase.cast_torus_into();
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits LWE ciphertext vector to a 32 bits LWE ciphertext vector. The torus elements
+/// are rounded to the closest element of the output precision.
+///
+/// The phases of the input are exactly rescaled to the output precision.
+impl
+    LweCiphertextVectorDiscardingConversionEngine<
+        CleartextLweCiphertextVector64,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingConversionError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = input_phase.cast_torus_into();
+        }
+    }
+}
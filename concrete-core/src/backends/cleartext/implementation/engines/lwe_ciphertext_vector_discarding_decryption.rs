@@ -0,0 +1,165 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::{AsMutSlice, AsMutTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingDecryptionEngine, LweCiphertextVectorDiscardingDecryptionError,
+};
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingDecryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    LweCiphertextVectorDiscardingDecryptionEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_decrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut plaintext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(18));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut PlaintextVector32,
+        input: &CleartextLweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingDecryptionError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != output.plaintext_count().0 {
+            return Err(LweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_decrypt_lwe_ciphertext_vector_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut PlaintextVector32,
+        input: &CleartextLweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(&input.phases);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingDecryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The decrypted plaintexts are the phases of the ciphertexts, noise included.
+impl
+    LweCiphertextVectorDiscardingDecryptionEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// engine.discard_decrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut plaintext_vector,
+    ///     &ciphertext_vector,
+    /// )?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(18));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &CleartextLweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingDecryptionError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != output.plaintext_count().0 {
+            return Err(LweCiphertextVectorDiscardingDecryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_decrypt_lwe_ciphertext_vector_unchecked(key, output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut PlaintextVector64,
+        input: &CleartextLweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(&input.phases);
+    }
+}
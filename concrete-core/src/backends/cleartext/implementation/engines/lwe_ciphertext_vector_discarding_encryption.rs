@@ -0,0 +1,347 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView32, CleartextLweCiphertextVectorMutView64,
+    CleartextLweSecretKey32, CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
+};
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phase of each output ciphertext is replaced by the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextLweSecretKey32,
+        PlaintextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phase of each output ciphertext is replaced by the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextLweSecretKey64,
+        PlaintextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// );
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            *phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of each output ciphertext is replaced by the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl<'a>
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextLweSecretKey32,
+        PlaintextVector32,
+        CleartextLweCiphertextVectorMutView32<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: CleartextLweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector_view.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextVectorMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        output: &mut CleartextLweCiphertextVectorMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            **phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phase of each output ciphertext is replaced by the corresponding plaintext, plus a noise
+/// sampled with the given variance.
+impl<'a>
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        CleartextLweSecretKey64,
+        PlaintextVector64,
+        CleartextLweCiphertextVectorMutView64<'a>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: CleartextLweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector_view.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextVectorMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        output: &mut CleartextLweCiphertextVectorMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        for (phase, plaintext) in output.phases.iter_mut().zip(input.0.as_tensor().iter()) {
+            **phase = self.add_noise(*plaintext, noise);
+        }
+    }
+}
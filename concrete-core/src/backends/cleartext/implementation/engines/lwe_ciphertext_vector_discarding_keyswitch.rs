@@ -0,0 +1,867 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweCiphertextVectorMutView32, CleartextLweCiphertextVectorMutView64,
+    CleartextLweCiphertextVectorView32, CleartextLweCiphertextVectorView64,
+    CleartextLweGaussianCiphertextVector32, CleartextLweGaussianCiphertextVector64,
+    CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64, CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingKeyswitchEngine, LweCiphertextVectorDiscardingKeyswitchError,
+};
+use crate::specification::entities::{LweCiphertextVectorEntity, LweKeyswitchKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey32,
+        CleartextLweCiphertextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input_vector = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey64,
+        CleartextLweCiphertextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweBinaryToTernaryKeyswitchKey32,
+        CleartextLweCiphertextVector32,
+        CleartextLweTernaryCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; 3])?;
+    /// let mut output_ciphertext_vector: CleartextLweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweBinaryToTernaryKeyswitchKey64,
+        CleartextLweCiphertextVector64,
+        CleartextLweTernaryCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; 3])?;
+    /// let mut output_ciphertext_vector: CleartextLweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweTernaryCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweBinaryToTernaryKeyswitchKey64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweBinaryToGaussianKeyswitchKey32,
+        CleartextLweCiphertextVector32,
+        CleartextLweGaussianCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; 3])?;
+    /// let mut output_ciphertext_vector: CleartextLweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertextVector32,
+        input: &CleartextLweCiphertextVector32,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweBinaryToGaussianKeyswitchKey64,
+        CleartextLweCiphertextVector64,
+        CleartextLweGaussianCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; 3])?;
+    /// let mut output_ciphertext_vector: CleartextLweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_dimension(),
+    ///     output_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count() != output.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweGaussianCiphertextVector64,
+        input: &CleartextLweCiphertextVector64,
+        ksk: &CleartextLweBinaryToGaussianKeyswitchKey64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            *output_phase = self.keyswitch_phase(*input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey32,
+        CleartextLweCiphertextVectorView32<'a>,
+        CleartextLweCiphertextVectorMutView32<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; input_lwe_dimension.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u32; output_lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut input_view: CleartextLweCiphertextVectorMutView32 = engine
+    ///     .create_lwe_ciphertext_vector(
+    ///         &mut input_container[..],
+    ///         input_lwe_dimension.to_lwe_size(),
+    ///     )?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &input_key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextVectorView32 = engine
+    ///     .create_lwe_ciphertext_vector(&input_container[..], input_lwe_dimension.to_lwe_size())?;
+    /// let mut output_view: CleartextLweCiphertextVectorMutView32 = engine
+    ///     .create_lwe_ciphertext_vector(
+    ///         &mut output_container[..],
+    ///         output_lwe_dimension.to_lwe_size(),
+    ///     )?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output_view.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView32<'b>,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView32<'b>,
+        input: &CleartextLweCiphertextVectorView32<'a>,
+        ksk: &CleartextLweKeyswitchKey32,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            **output_phase = self.keyswitch_phase(**input_phase, ksk, ksk.noise);
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+///
+/// The phases of the inputs are kept, and noises are added with the variance predicted by
+/// `concrete-npe` for the keyswitch.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        CleartextLweKeyswitchKey64,
+        CleartextLweCiphertextVectorView64<'a>,
+        CleartextLweCiphertextVectorMutView64<'b>,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; input_lwe_dimension.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u64; output_lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut input_view: CleartextLweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(
+    ///         &mut input_container[..],
+    ///         input_lwe_dimension.to_lwe_size(),
+    ///     )?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &input_key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: CleartextLweCiphertextVectorView64 = engine
+    ///     .create_lwe_ciphertext_vector(&input_container[..], input_lwe_dimension.to_lwe_size())?;
+    /// let mut output_view: CleartextLweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(
+    ///         &mut output_container[..],
+    ///         output_lwe_dimension.to_lwe_size(),
+    ///     )?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output_view.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView64<'b>,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVectorMutView64<'b>,
+        input: &CleartextLweCiphertextVectorView64<'a>,
+        ksk: &CleartextLweKeyswitchKey64,
+    ) {
+        for (output_phase, input_phase) in output.phases.iter_mut().zip(input.phases.iter()) {
+            **output_phase = self.keyswitch_phase(**input_phase, ksk, ksk.noise);
+        }
+    }
+}
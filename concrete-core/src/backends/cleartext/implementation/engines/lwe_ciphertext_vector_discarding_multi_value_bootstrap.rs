@@ -0,0 +1,529 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextFourierLweBootstrapKey32, CleartextFourierLweBootstrapKey64,
+    CleartextLweBootstrapKey32, CleartextLweBootstrapKey64, CleartextLweCiphertext32,
+    CleartextLweCiphertext64, CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+};
+use crate::backends::core::entities::{
+    CleartextVector32, CleartextVector64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine,
+    LweCiphertextVectorDiscardingMultiValueBootstrapError as MultiValueBootstrapError,
+};
+use crate::specification::entities::{
+    CleartextVectorEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$ once, and used to look up each of the
+/// lookup tables in the negacyclic way of the blind rotation. Each output phase is the looked up
+/// value multiplied by delta, plus a noise whose variance is the one of the bootstrap, scaled by
+/// the norm of the polynomial the common accumulator gets multiplied by.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        CleartextLweBootstrapKey32,
+        CleartextVector32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 20 bits
+    /// let luts: Vec<u32> = [1_u32, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u32 << 20;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &CleartextLweBootstrapKey32,
+    ) {
+        output.phases = self.multi_value_bootstrap_phases(
+            input.phase,
+            luts.0.as_tensor().as_slice(),
+            (delta.0).0,
+            bsk,
+            bsk.noise,
+            1,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$ once, and used to look up each of the
+/// lookup tables in the negacyclic way of the blind rotation. Each output phase is the looked up
+/// value multiplied by delta, plus a noise whose variance is the one of the bootstrap, scaled by
+/// the norm of the polynomial the common accumulator gets multiplied by.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        CleartextLweBootstrapKey64,
+        CleartextVector64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 52 bits
+    /// let luts: Vec<u64> = [1_u64, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u64 << 52;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &CleartextLweBootstrapKey64,
+    ) {
+        output.phases = self.multi_value_bootstrap_phases(
+            input.phase,
+            luts.0.as_tensor().as_slice(),
+            (delta.0).0,
+            bsk,
+            bsk.noise,
+            1,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$ once, and used to look up each of the
+/// lookup tables in the negacyclic way of the blind rotation. Each output phase is the looked up
+/// value multiplied by delta, plus a noise whose variance is the one of the bootstrap, scaled by
+/// the norm of the polynomial the common accumulator gets multiplied by.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        CleartextFourierLweBootstrapKey32,
+        CleartextVector32,
+        Plaintext32,
+        CleartextLweCiphertext32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 20 bits
+    /// let luts: Vec<u32> = [1_u32, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u32 << 20;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector32,
+        input: &CleartextLweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &CleartextFourierLweBootstrapKey32,
+    ) {
+        output.phases = self.multi_value_bootstrap_phases(
+            input.phase,
+            luts.0.as_tensor().as_slice(),
+            (delta.0).0,
+            bsk,
+            bsk.noise,
+            1,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+///
+/// The phase of the input is switched to the modulus $2N$ once, and used to look up each of the
+/// lookup tables in the negacyclic way of the blind rotation. Each output phase is the looked up
+/// value multiplied by delta, plus a noise whose variance is the one of the bootstrap, scaled by
+/// the norm of the polynomial the common accumulator gets multiplied by.
+///
+/// It takes as input a bootstrap key in the Fourier domain.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        CleartextFourierLweBootstrapKey64,
+        CleartextVector64,
+        Plaintext64,
+        CleartextLweCiphertext64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 52 bits
+    /// let luts: Vec<u64> = [1_u64, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u64 << 52;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_sk: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: CleartextGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: CleartextFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut CleartextLweCiphertextVector64,
+        input: &CleartextLweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &CleartextFourierLweBootstrapKey64,
+    ) {
+        output.phases = self.multi_value_bootstrap_phases(
+            input.phase,
+            luts.0.as_tensor().as_slice(),
+            (delta.0).0,
+            bsk,
+            bsk.noise,
+            1,
+        );
+    }
+}
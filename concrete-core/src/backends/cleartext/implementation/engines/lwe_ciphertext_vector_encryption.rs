@@ -0,0 +1,448 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweGaussianCiphertextVector32, CleartextLweGaussianCiphertextVector64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextLweTernaryCiphertextVector32,
+    CleartextLweTernaryCiphertextVector64, CleartextLweTernarySecretKey32,
+    CleartextLweTernarySecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweSecretKey32,
+        PlaintextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertextVector32, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweSecretKey64,
+        PlaintextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertextVector64, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweTernarySecretKey32,
+        PlaintextVector32,
+        CleartextLweTernaryCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: CleartextLweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweTernaryCiphertextVector32,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextLweTernaryCiphertextVector32 {
+        CleartextLweTernaryCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweTernarySecretKey64,
+        PlaintextVector64,
+        CleartextLweTernaryCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: CleartextLweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweTernaryCiphertextVector64,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextLweTernaryCiphertextVector64 {
+        CleartextLweTernaryCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweGaussianSecretKey32,
+        PlaintextVector32,
+        CleartextLweGaussianCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: CleartextLweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweGaussianCiphertextVector32,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextLweGaussianCiphertextVector32 {
+        CleartextLweGaussianCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        CleartextLweGaussianSecretKey64,
+        PlaintextVector64,
+        CleartextLweGaussianCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: CleartextLweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweGaussianCiphertextVector64,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextLweGaussianCiphertextVector64 {
+        CleartextLweGaussianCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64, CleartextPackingKeyswitchKey32, CleartextPackingKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError as PackingKeyswitchError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity, PackingKeyswitchKeyEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`] for
+/// [`CleartextEngine`] that operates on 32 bits integers.
+///
+/// The phases of the inputs are copied to the first coefficients of the phase polynomial of the
+/// output, and a noise is added to every coefficient with the variance predicted by `concrete-npe`
+/// for as many keyswitches as there are inputs.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+        CleartextPackingKeyswitchKey32,
+        CleartextLweCiphertextVector32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let pksk = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(pksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextLweCiphertextVector32,
+        pksk: &CleartextPackingKeyswitchKey32,
+    ) -> Result<(), PackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pksk.input_lwe_dimension() {
+            return Err(PackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pksk.output_glwe_dimension() {
+            return Err(PackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pksk.output_polynomial_size() {
+            return Err(PackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(output, input, pksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextLweCiphertextVector32,
+        pksk: &CleartextPackingKeyswitchKey32,
+    ) {
+        let mut identity = vec![0; pksk.output_polynomial_size.0];
+        identity[0] = 1;
+        output.phases = self.packing_keyswitch_phases(
+            &input.phases,
+            &identity,
+            pksk.input_lwe_dimension,
+            pksk.decomposition_base_log,
+            pksk.decomposition_level_count,
+            pksk.noise,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine`] for
+/// [`CleartextEngine`] that operates on 64 bits integers.
+///
+/// The phases of the inputs are copied to the first coefficients of the phase polynomial of the
+/// output, and a noise is added to every coefficient with the variance predicted by `concrete-npe`
+/// for as many keyswitches as there are inputs.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+        CleartextPackingKeyswitchKey64,
+        CleartextLweCiphertextVector64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let pksk = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(pksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextLweCiphertextVector64,
+        pksk: &CleartextPackingKeyswitchKey64,
+    ) -> Result<(), PackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pksk.input_lwe_dimension() {
+            return Err(PackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pksk.output_glwe_dimension() {
+            return Err(PackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pksk.output_polynomial_size() {
+            return Err(PackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(output, input, pksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextLweCiphertextVector64,
+        pksk: &CleartextPackingKeyswitchKey64,
+    ) {
+        let mut identity = vec![0; pksk.output_polynomial_size.0];
+        identity[0] = 1;
+        output.phases = self.packing_keyswitch_phases(
+            &input.phases,
+            &identity,
+            pksk.input_lwe_dimension,
+            pksk.decomposition_base_log,
+            pksk.decomposition_level_count,
+            pksk.noise,
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweCiphertext32, CleartextGlweCiphertext64, CleartextLweCiphertextVector32,
+    CleartextLweCiphertextVector64, CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError as PrivateFunctionalPackingKeyswitchError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity, PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine`]
+/// for [`CleartextEngine`] that operates on 32 bits integers.
+///
+/// The polynomial whose coefficients are the phases of the inputs is multiplied by the polynomial
+/// of the linear map, and a noise is added to every coefficient with the variance predicted by
+/// `concrete-npe` for as many keyswitches as there are inputs.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+        CleartextPrivateFunctionalPackingKeyswitchKey32,
+        CleartextLweCiphertextVector32,
+        CleartextGlweCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u32, 1, 0, 0];
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector32 = engine.create_cleartext_vector(&polynomial_coefficients)?;
+    /// let pfpksk = engine.create_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(pfpksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextLweCiphertextVector32,
+        pfpksk: &CleartextPrivateFunctionalPackingKeyswitchKey32,
+    ) -> Result<(), PrivateFunctionalPackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pfpksk.input_lwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pfpksk.output_glwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pfpksk.output_polynomial_size() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext32,
+        input: &CleartextLweCiphertextVector32,
+        pfpksk: &CleartextPrivateFunctionalPackingKeyswitchKey32,
+    ) {
+        output.phases = self.packing_keyswitch_phases(
+            &input.phases,
+            &pfpksk.polynomial,
+            pfpksk.input_lwe_dimension,
+            pfpksk.decomposition_base_log,
+            pfpksk.decomposition_level_count,
+            pfpksk.noise,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine`]
+/// for [`CleartextEngine`] that operates on 64 bits integers.
+///
+/// The polynomial whose coefficients are the phases of the inputs is multiplied by the polynomial
+/// of the linear map, and a noise is added to every coefficient with the variance predicted by
+/// `concrete-npe` for as many keyswitches as there are inputs.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+        CleartextPrivateFunctionalPackingKeyswitchKey64,
+        CleartextLweCiphertextVector64,
+        CleartextGlweCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u64, 1, 0, 0];
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector64 = engine.create_cleartext_vector(&polynomial_coefficients)?;
+    /// let pfpksk = engine.create_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(pfpksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextLweCiphertextVector64,
+        pfpksk: &CleartextPrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<(), PrivateFunctionalPackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pfpksk.input_lwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pfpksk.output_glwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pfpksk.output_polynomial_size() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextGlweCiphertext64,
+        input: &CleartextLweCiphertextVector64,
+        pfpksk: &CleartextPrivateFunctionalPackingKeyswitchKey64,
+    ) {
+        output.phases = self.packing_keyswitch_phases(
+            &input.phases,
+            &pfpksk.polynomial,
+            pfpksk.input_lwe_dimension,
+            pfpksk.decomposition_base_log,
+            pfpksk.decomposition_level_count,
+            pfpksk.noise,
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorLoadingEngine, LweCiphertextVectorLoadingError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextRange;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLoadingEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweCiphertextVectorLoadingEngine<CleartextLweCiphertextVector32, CleartextLweCiphertextVector32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let sub_ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.load_lwe_ciphertext_vector(&ciphertext_vector, LweCiphertextRange(5, 12))?;
+    /// #
+    /// assert_eq!(sub_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     sub_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(7)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(sub_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext_vector(
+        &mut self,
+        vector: &CleartextLweCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> Result<CleartextLweCiphertextVector32, LweCiphertextVectorLoadingError<Self::EngineError>>
+    {
+        if !range.is_ordered() {
+            return Err(LweCiphertextVectorLoadingError::UnorderedInputRange);
+        }
+        if range.1 > vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorLoadingError::OutOfVectorInputRange);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_vector_unchecked(vector, range) })
+    }
+
+    unsafe fn load_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        vector: &CleartextLweCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: vector.phases[range.0..range.1].to_vec(),
+            lwe_dimension: vector.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLoadingEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextVectorLoadingEngine<CleartextLweCiphertextVector64, CleartextLweCiphertextVector64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let sub_ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.load_lwe_ciphertext_vector(&ciphertext_vector, LweCiphertextRange(5, 12))?;
+    /// #
+    /// assert_eq!(sub_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     sub_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(7)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(sub_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext_vector(
+        &mut self,
+        vector: &CleartextLweCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> Result<CleartextLweCiphertextVector64, LweCiphertextVectorLoadingError<Self::EngineError>>
+    {
+        if !range.is_ordered() {
+            return Err(LweCiphertextVectorLoadingError::UnorderedInputRange);
+        }
+        if range.1 > vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorLoadingError::OutOfVectorInputRange);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_vector_unchecked(vector, range) })
+    }
+
+    unsafe fn load_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        vector: &CleartextLweCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: vector.phases[range.0..range.1].to_vec(),
+            lwe_dimension: vector.lwe_dimension(),
+        }
+    }
+}
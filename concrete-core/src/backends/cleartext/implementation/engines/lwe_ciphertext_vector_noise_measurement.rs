@@ -0,0 +1,172 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorNoiseMeasurementEngine, LweCiphertextVectorNoiseMeasurementError,
+    NoiseMeasurement,
+};
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The errors are the differences between the phases of the ciphertexts and the expected
+/// plaintexts.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        CleartextLweSecretKey32,
+        CleartextLweCiphertextVector32,
+        PlaintextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), 3);
+    /// assert!(measurements
+    ///     .iter()
+    ///     .all(|measurement| measurement.bit_size < 25));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorNoiseMeasurementError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(LweCiphertextVectorNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey32,
+        input: &CleartextLweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Vec<NoiseMeasurement> {
+        input
+            .phases
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(phase, expected)| {
+                NoiseMeasurement::from_error(phase.wrapping_sub(*expected) as i32 as i64, 32)
+            })
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The errors are the differences between the phases of the ciphertexts and the expected
+/// plaintexts.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        CleartextLweSecretKey64,
+        CleartextLweCiphertextVector64,
+        PlaintextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), 3);
+    /// assert!(measurements
+    ///     .iter()
+    ///     .all(|measurement| measurement.bit_size < 45));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorNoiseMeasurementError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(LweCiphertextVectorNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        _key: &CleartextLweSecretKey64,
+        input: &CleartextLweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Vec<NoiseMeasurement> {
+        input
+            .phases
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(phase, expected)| {
+                NoiseMeasurement::from_error(phase.wrapping_sub(*expected) as i64, 64)
+            })
+            .collect()
+    }
+}
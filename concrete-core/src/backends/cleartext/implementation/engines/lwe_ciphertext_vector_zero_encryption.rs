@@ -0,0 +1,135 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorZeroEncryptionEngine, LweCiphertextVectorZeroEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweCiphertextCount;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorZeroEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorZeroEncryptionEngine<CleartextLweSecretKey32, CleartextLweCiphertextVector32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// let ciphertext_count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), ciphertext_count);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        noise: Variance,
+        count: LweCiphertextCount,
+    ) -> Result<
+        CleartextLweCiphertextVector32,
+        LweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        if count.0 == 0 {
+            return Err(LweCiphertextVectorZeroEncryptionError::NullCiphertextCount);
+        }
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        noise: Variance,
+        count: LweCiphertextCount,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: (0..count.0).map(|_| self.add_noise(0, noise)).collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorZeroEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorZeroEncryptionEngine<CleartextLweSecretKey64, CleartextLweCiphertextVector64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// let ciphertext_count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, ciphertext_count)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), ciphertext_count);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        noise: Variance,
+        count: LweCiphertextCount,
+    ) -> Result<
+        CleartextLweCiphertextVector64,
+        LweCiphertextVectorZeroEncryptionError<Self::EngineError>,
+    > {
+        if count.0 == 0 {
+            return Err(LweCiphertextVectorZeroEncryptionError::NullCiphertextCount);
+        }
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_vector_unchecked(key, noise, count) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        noise: Variance,
+        count: LweCiphertextCount,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: (0..count.0).map(|_| self.add_noise(0, noise)).collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
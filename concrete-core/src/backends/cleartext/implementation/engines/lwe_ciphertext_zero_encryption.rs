@@ -0,0 +1,110 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextZeroEncryptionEngine<CleartextLweSecretKey32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertext32, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        noise: Variance,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: self.add_noise(0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextZeroEncryptionEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextZeroEncryptionEngine<CleartextLweSecretKey64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let ciphertext = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn zero_encrypt_lwe_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        noise: Variance,
+    ) -> Result<CleartextLweCiphertext64, LweCiphertextZeroEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.zero_encrypt_lwe_ciphertext_unchecked(key, noise) })
+    }
+
+    unsafe fn zero_encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        noise: Variance,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: self.add_noise(0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyConsumingRetrievalEngine, LweKeyswitchKeyConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 32 bits integers of an LWE keyswitch key.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl LweKeyswitchKeyConsumingRetrievalEngine<CleartextLweKeyswitchKey32, Vec<u32>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: CleartextLweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     input_lwe_dimension.0 * decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0
+    /// );
+    ///
+    /// // The container can be used to create the keyswitch key again
+    /// let keyswitch_key: CleartextLweKeyswitchKey32 = engine
+    ///     .create_lwe_keyswitch_key_from_container(
+    ///         container,
+    ///         output_lwe_dimension,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///     )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: CleartextLweKeyswitchKey32,
+    ) -> Result<Vec<u32>, LweKeyswitchKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_keyswitch_key_unchecked(keyswitch_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: CleartextLweKeyswitchKey32,
+    ) -> Vec<u32> {
+        vec![
+            0;
+            keyswitch_key.input_lwe_dimension.0
+                * keyswitch_key.decomposition_level_count.0
+                * keyswitch_key.output_lwe_dimension.to_lwe_size().0
+        ]
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConsumingRetrievalEngine`] for [`CleartextEngine`] that
+/// returns the vector of 64 bits integers of an LWE keyswitch key.
+///
+/// The keys of the cleartext backend hold no key material: the returned container has the size of
+/// the one of the `core` backend, and is filled with zeros.
+impl LweKeyswitchKeyConsumingRetrievalEngine<CleartextLweKeyswitchKey64, Vec<u64>>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: CleartextLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     input_lwe_dimension.0 * decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0
+    /// );
+    ///
+    /// // The container can be used to create the keyswitch key again
+    /// let keyswitch_key: CleartextLweKeyswitchKey64 = engine
+    ///     .create_lwe_keyswitch_key_from_container(
+    ///         container,
+    ///         output_lwe_dimension,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///     )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: CleartextLweKeyswitchKey64,
+    ) -> Result<Vec<u64>, LweKeyswitchKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_keyswitch_key_unchecked(keyswitch_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: CleartextLweKeyswitchKey64,
+    ) -> Vec<u64> {
+        vec![
+            0;
+            keyswitch_key.input_lwe_dimension.0
+                * keyswitch_key.decomposition_level_count.0
+                * keyswitch_key.output_lwe_dimension.to_lwe_size().0
+        ]
+    }
+}
@@ -0,0 +1,163 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits LWE keyswitch key to a 64 bits LWE keyswitch key. The torus elements are exactly rescaled
+/// to the output precision.
+///
+/// The key only carries its parameters and the variance of its noise, which are copied to the
+/// output.
+impl LweKeyswitchKeyConversionEngine<CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: CleartextLweKeyswitchKey64 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &CleartextLweKeyswitchKey32,
+    ) -> Result<CleartextLweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &CleartextLweKeyswitchKey32,
+    ) -> CleartextLweKeyswitchKey64 {
+        CleartextLweKeyswitchKey64 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            output_lwe_dimension: input.output_lwe_dimension,
+            decomposition_level_count: input.decomposition_level_count,
+            decomposition_base_log: input.decomposition_base_log,
+            noise: input.noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits LWE keyswitch key to a 32 bits LWE keyswitch key. The torus elements are rounded to the
+/// closest element of the output precision.
+///
+/// The key only carries its parameters and the variance of its noise, which are copied to the
+/// output.
+impl LweKeyswitchKeyConversionEngine<CleartextLweKeyswitchKey64, CleartextLweKeyswitchKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: CleartextLweKeyswitchKey32 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &CleartextLweKeyswitchKey64,
+    ) -> Result<CleartextLweKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweKeyswitchKeyConversionError::DecompositionTooLarge);
+        }
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &CleartextLweKeyswitchKey64,
+    ) -> CleartextLweKeyswitchKey32 {
+        CleartextLweKeyswitchKey32 {
+            input_lwe_dimension: input.input_lwe_dimension,
+            output_lwe_dimension: input.output_lwe_dimension,
+            decomposition_level_count: input.decomposition_level_count,
+            decomposition_base_log: input.decomposition_base_log,
+            noise: input.noise,
+        }
+    }
+}
@@ -0,0 +1,638 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweBinaryToGaussianKeyswitchKey32, CleartextLweBinaryToGaussianKeyswitchKey64,
+    CleartextLweBinaryToTernaryKeyswitchKey32, CleartextLweBinaryToTernaryKeyswitchKey64,
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweKeyswitchKey32,
+    CleartextLweKeyswitchKey64, CleartextLweSecretKey32, CleartextLweSecretKey64,
+    CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64,
+};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextLweSecretKey32,
+        CleartextLweKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: CleartextLweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<CleartextLweKeyswitchKey32, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweKeyswitchKey32 {
+        CleartextLweKeyswitchKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextLweSecretKey64,
+        CleartextLweKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: CleartextLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<CleartextLweKeyswitchKey64, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweKeyswitchKey64 {
+        CleartextLweKeyswitchKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextLweTernarySecretKey32,
+        CleartextLweBinaryToTernaryKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweBinaryToTernaryKeyswitchKey32,
+        LweKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweBinaryToTernaryKeyswitchKey32 {
+        CleartextLweBinaryToTernaryKeyswitchKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextLweTernarySecretKey64,
+        CleartextLweBinaryToTernaryKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweTernarySecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweBinaryToTernaryKeyswitchKey64,
+        LweKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweBinaryToTernaryKeyswitchKey64 {
+        CleartextLweBinaryToTernaryKeyswitchKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextLweGaussianSecretKey32,
+        CleartextLweBinaryToGaussianKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweBinaryToGaussianKeyswitchKey32,
+        LweKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextLweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweBinaryToGaussianKeyswitchKey32 {
+        CleartextLweBinaryToGaussianKeyswitchKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextLweGaussianSecretKey64,
+        CleartextLweBinaryToGaussianKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweBinaryToGaussianKeyswitchKey64,
+        LweKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextLweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextLweBinaryToGaussianKeyswitchKey64 {
+        CleartextLweBinaryToGaussianKeyswitchKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_lwe_dimension: output_key.lwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
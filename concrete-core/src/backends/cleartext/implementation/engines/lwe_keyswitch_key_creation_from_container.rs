@@ -0,0 +1,237 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyCreationFromContainerEngine, LweKeyswitchKeyCreationFromContainerError,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns an LWE keyswitch key, taking ownership of a vector of 32 bits integers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl LweKeyswitchKeyCreationFromContainerEngine<Vec<u32>, CleartextLweKeyswitchKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// // Here we create a container outside of the engine
+    /// let container = vec![
+    ///     0_u32;
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// ];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let keyswitch_key: CleartextLweKeyswitchKey32 = engine
+    ///     .create_lwe_keyswitch_key_from_container(
+    ///         container,
+    ///         output_lwe_dimension,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextLweKeyswitchKey32,
+        LweKeyswitchKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweKeyswitchKeyCreationFromContainerError::EmptyContainer);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        if container.len() % (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0)
+            != 0
+        {
+            return Err(LweKeyswitchKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_from_container_unchecked(
+                container,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextLweKeyswitchKey32 {
+        CleartextLweKeyswitchKey32 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0),
+            ),
+            output_lwe_dimension,
+            decomposition_level_count,
+            decomposition_base_log,
+            noise: Variance(0.),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationFromContainerEngine`] for [`CleartextEngine`] which
+/// returns an LWE keyswitch key, taking ownership of a vector of 64 bits integers.
+///
+/// The keys of the cleartext backend hold no key material: only the parameters of the key are
+/// deduced from the container, whose values are ignored. As the noise of the encryptions forming
+/// the key can not be recovered, the key is considered noiseless.
+impl LweKeyswitchKeyCreationFromContainerEngine<Vec<u64>, CleartextLweKeyswitchKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// // Here we create a container outside of the engine
+    /// let container = vec![
+    ///     0_u64;
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// ];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let keyswitch_key: CleartextLweKeyswitchKey64 = engine
+    ///     .create_lwe_keyswitch_key_from_container(
+    ///         container,
+    ///         output_lwe_dimension,
+    ///         decomposition_base_log,
+    ///         decomposition_level_count,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        CleartextLweKeyswitchKey64,
+        LweKeyswitchKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweKeyswitchKeyCreationFromContainerError::EmptyContainer);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        if container.len() % (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0)
+            != 0
+        {
+            return Err(LweKeyswitchKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_from_container_unchecked(
+                container,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> CleartextLweKeyswitchKey64 {
+        CleartextLweKeyswitchKey64 {
+            input_lwe_dimension: LweDimension(
+                container.len()
+                    / (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0),
+            ),
+            output_lwe_dimension,
+            decomposition_level_count,
+            decomposition_base_log,
+            noise: Variance(0.),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweKeyswitchKey32, CleartextLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweKeyswitchKeyDiscardingConversionEngine, LweKeyswitchKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits LWE keyswitch key to a 64 bits LWE keyswitch key. The torus elements are
+/// exactly rescaled to the output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, and
+/// the variance of its noise, which is copied to the output.
+impl
+    LweKeyswitchKeyDiscardingConversionEngine<
+        CleartextLweKeyswitchKey32,
+        CleartextLweKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let converted_input_key: CleartextLweSecretKey64 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: CleartextLweSecretKey64 =
+    ///     engine.convert_lwe_secret_key(&output_key)?;
+    /// let mut output = engine.create_lwe_keyswitch_key(
+    ///     &converted_input_key,
+    ///     &converted_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut CleartextLweKeyswitchKey64,
+        input: &CleartextLweKeyswitchKey32,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::InputLweDimensionMismatch);
+        }
+        if output.output_lwe_dimension() != input.output_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::OutputLweDimensionMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut CleartextLweKeyswitchKey64,
+        input: &CleartextLweKeyswitchKey32,
+    ) {
+        output.noise = input.noise;
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits LWE keyswitch key to a 32 bits LWE keyswitch key. The torus elements are
+/// rounded to the closest element of the output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, and
+/// the variance of its noise, which is copied to the output.
+impl
+    LweKeyswitchKeyDiscardingConversionEngine<
+        CleartextLweKeyswitchKey64,
+        CleartextLweKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let converted_input_key: CleartextLweSecretKey32 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: CleartextLweSecretKey32 =
+    ///     engine.convert_lwe_secret_key(&output_key)?;
+    /// let mut output = engine.create_lwe_keyswitch_key(
+    ///     &converted_input_key,
+    ///     &converted_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut CleartextLweKeyswitchKey32,
+        input: &CleartextLweKeyswitchKey64,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::InputLweDimensionMismatch);
+        }
+        if output.output_lwe_dimension() != input.output_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::OutputLweDimensionMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionTooLarge);
+        }
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut CleartextLweKeyswitchKey32,
+        input: &CleartextLweKeyswitchKey64,
+    ) {
+        output.noise = input.noise;
+    }
+}
@@ -0,0 +1,145 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLwePublicKey32, CleartextLwePublicKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64,
+};
+use crate::specification::engines::{LwePublicKeyCreationEngine, LwePublicKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LwePublicKeyCreationEngine<CleartextLweSecretKey32, CleartextLwePublicKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let secret_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: CleartextLwePublicKey32 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        secret_key: &CleartextLweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<CleartextLwePublicKey32, LwePublicKeyCreationError<Self::EngineError>> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(LwePublicKeyCreationError::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        secret_key: &CleartextLweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> CleartextLwePublicKey32 {
+        CleartextLwePublicKey32 {
+            lwe_dimension: secret_key.lwe_dimension(),
+            lwe_zero_encryption_count: lwe_public_key_zero_encryption_count,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LwePublicKeyCreationEngine<CleartextLweSecretKey64, CleartextLwePublicKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let secret_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: CleartextLwePublicKey64 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        secret_key: &CleartextLweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<CleartextLwePublicKey64, LwePublicKeyCreationError<Self::EngineError>> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(LwePublicKeyCreationError::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        secret_key: &CleartextLweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> CleartextLwePublicKey64 {
+        CleartextLwePublicKey64 {
+            lwe_dimension: secret_key.lwe_dimension(),
+            lwe_zero_encryption_count: lwe_public_key_zero_encryption_count,
+            noise,
+        }
+    }
+}
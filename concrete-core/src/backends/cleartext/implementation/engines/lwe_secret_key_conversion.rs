@@ -0,0 +1,101 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweSecretKey32, CleartextLweSecretKey64,
+};
+use crate::specification::engines::{LweSecretKeyConversionEngine, LweSecretKeyConversionError};
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits LWE secret key to a 64 bits LWE secret key. The key bits are copied to the output
+/// precision.
+///
+/// The key only carries its parameters, which are copied to the output.
+impl LweSecretKeyConversionEngine<CleartextLweSecretKey32, CleartextLweSecretKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let output: CleartextLweSecretKey64 = engine.convert_lwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &CleartextLweSecretKey32,
+    ) -> Result<CleartextLweSecretKey64, LweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_secret_key_unchecked(
+        &mut self,
+        input: &CleartextLweSecretKey32,
+    ) -> CleartextLweSecretKey64 {
+        CleartextLweSecretKey64 {
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits LWE secret key to a 32 bits LWE secret key. The key bits are copied to the output
+/// precision.
+///
+/// The key only carries its parameters, which are copied to the output.
+impl LweSecretKeyConversionEngine<CleartextLweSecretKey64, CleartextLweSecretKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let output: CleartextLweSecretKey32 = engine.convert_lwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &CleartextLweSecretKey64,
+    ) -> Result<CleartextLweSecretKey32, LweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_secret_key_unchecked(
+        &mut self,
+        input: &CleartextLweSecretKey64,
+    ) -> CleartextLweSecretKey32 {
+        CleartextLweSecretKey32 {
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
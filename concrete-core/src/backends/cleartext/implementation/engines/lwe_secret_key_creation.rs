@@ -0,0 +1,251 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweGaussianSecretKey32, CleartextLweGaussianSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextLweTernarySecretKey32, CleartextLweTernarySecretKey64,
+};
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+use concrete_commons::parameters::LweDimension;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweSecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweSecretKey32, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweSecretKey32 {
+        CleartextLweSecretKey32 { lwe_dimension }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweSecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweSecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweSecretKey64 {
+        CleartextLweSecretKey64 { lwe_dimension }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweTernarySecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweTernarySecretKey32 =
+    ///     engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweTernarySecretKey32, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweTernarySecretKey32 {
+        CleartextLweTernarySecretKey32 { lwe_dimension }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweTernarySecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweTernarySecretKey64 =
+    ///     engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweTernarySecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweTernarySecretKey64 {
+        CleartextLweTernarySecretKey64 { lwe_dimension }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweGaussianSecretKey32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweGaussianSecretKey32, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweGaussianSecretKey32 {
+        CleartextLweGaussianSecretKey32 { lwe_dimension }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyCreationEngine<CleartextLweGaussianSecretKey64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let lwe_secret_key: CleartextLweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<CleartextLweGaussianSecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        if lwe_dimension.0 == 0 {
+            return Err(LweSecretKeyCreationError::NullLweDimension);
+        }
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> CleartextLweGaussianSecretKey64 {
+        CleartextLweGaussianSecretKey64 { lwe_dimension }
+    }
+}
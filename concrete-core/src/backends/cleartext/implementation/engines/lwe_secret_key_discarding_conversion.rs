@@ -0,0 +1,114 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweSecretKey32, CleartextLweSecretKey64,
+};
+use crate::specification::engines::{
+    LweSecretKeyDiscardingConversionEngine, LweSecretKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits LWE secret key to a 64 bits LWE secret key. The key bits are copied to the
+/// output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, hence
+/// the output is left untouched.
+impl LweSecretKeyDiscardingConversionEngine<CleartextLweSecretKey32, CleartextLweSecretKey64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut CleartextLweSecretKey64,
+        input: &CleartextLweSecretKey32,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweSecretKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        _output: &mut CleartextLweSecretKey64,
+        _input: &CleartextLweSecretKey32,
+    ) {
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits LWE secret key to a 32 bits LWE secret key. The key bits are copied to the
+/// output precision.
+///
+/// The key only carries its parameters, which are checked to match the ones of the output, hence
+/// the output is left untouched.
+impl LweSecretKeyDiscardingConversionEngine<CleartextLweSecretKey64, CleartextLweSecretKey32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut CleartextLweSecretKey32,
+        input: &CleartextLweSecretKey64,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweSecretKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        _output: &mut CleartextLweSecretKey32,
+        _input: &CleartextLweSecretKey64,
+    ) {
+    }
+}
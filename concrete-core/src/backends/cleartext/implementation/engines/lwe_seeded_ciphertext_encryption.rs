@@ -0,0 +1,139 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweSecretKey32, CleartextLweSecretKey64, CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64,
+};
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::specification::engines::{
+    LweSeededCiphertextEncryptionEngine, LweSeededCiphertextEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweSeededCiphertextEncryptionEngine<
+        CleartextLweSecretKey32,
+        Plaintext32,
+        CleartextLweSeededCiphertext32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: CleartextLweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<CleartextLweSeededCiphertext32, LweSeededCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> CleartextLweSeededCiphertext32 {
+        CleartextLweSeededCiphertext32 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextEncryptionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phase of the ciphertext is the plaintext, plus a noise sampled with the given variance.
+impl
+    LweSeededCiphertextEncryptionEngine<
+        CleartextLweSecretKey64,
+        Plaintext64,
+        CleartextLweSeededCiphertext64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: CleartextLweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<CleartextLweSeededCiphertext64, LweSeededCiphertextEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> CleartextLweSeededCiphertext64 {
+        CleartextLweSeededCiphertext64 {
+            phase: self.add_noise(input.0 .0, noise),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertext32, CleartextLweCiphertext64, CleartextLweSeededCiphertext32,
+    CleartextLweSeededCiphertext64,
+};
+use crate::specification::engines::{
+    LweSeededCiphertextExpansionEngine, LweSeededCiphertextExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextExpansionEngine`] for [`CleartextEngine`] that operates
+/// on 32 bits integers.
+///
+/// The phase of the seeded input is copied to the output.
+impl LweSeededCiphertextExpansionEngine<CleartextLweSeededCiphertext32, CleartextLweCiphertext32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let seeded_ciphertext: CleartextLweSeededCiphertext32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let ciphertext: CleartextLweCiphertext32 =
+    ///     engine.expand_lwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext(
+        &mut self,
+        input: &CleartextLweSeededCiphertext32,
+    ) -> Result<CleartextLweCiphertext32, LweSeededCiphertextExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweSeededCiphertext32,
+    ) -> CleartextLweCiphertext32 {
+        CleartextLweCiphertext32 {
+            phase: input.phase,
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextExpansionEngine`] for [`CleartextEngine`] that operates
+/// on 64 bits integers.
+///
+/// The phase of the seeded input is copied to the output.
+impl LweSeededCiphertextExpansionEngine<CleartextLweSeededCiphertext64, CleartextLweCiphertext64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let seeded_ciphertext: CleartextLweSeededCiphertext64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let ciphertext: CleartextLweCiphertext64 =
+    ///     engine.expand_lwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext(
+        &mut self,
+        input: &CleartextLweSeededCiphertext64,
+    ) -> Result<CleartextLweCiphertext64, LweSeededCiphertextExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &CleartextLweSeededCiphertext64,
+    ) -> CleartextLweCiphertext64 {
+        CleartextLweCiphertext64 {
+            phase: input.phase,
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweSecretKey32, CleartextLweSecretKey64, CleartextLweSeededCiphertextVector32,
+    CleartextLweSeededCiphertextVector64,
+};
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorEncryptionEngine, LweSeededCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the ciphertexts are the plaintexts, plus a noise sampled with the given variance.
+impl
+    LweSeededCiphertextVectorEncryptionEngine<
+        CleartextLweSecretKey32,
+        PlaintextVector32,
+        CleartextLweSeededCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: CleartextLweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweSeededCiphertextVector32,
+        LweSeededCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> CleartextLweSeededCiphertextVector32 {
+        CleartextLweSeededCiphertextVector32 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorEncryptionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the ciphertexts are the plaintexts, plus a noise sampled with the given variance.
+impl
+    LweSeededCiphertextVectorEncryptionEngine<
+        CleartextLweSecretKey64,
+        PlaintextVector64,
+        CleartextLweSeededCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: CleartextLweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_seeded_ciphertext_vector(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        CleartextLweSeededCiphertextVector64,
+        LweSeededCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.encrypt_lwe_seeded_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        key: &CleartextLweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> CleartextLweSeededCiphertextVector64 {
+        CleartextLweSeededCiphertextVector64 {
+            phases: input
+                .0
+                .as_tensor()
+                .iter()
+                .map(|plaintext| self.add_noise(*plaintext, noise))
+                .collect(),
+            lwe_dimension: key.lwe_dimension(),
+        }
+    }
+}
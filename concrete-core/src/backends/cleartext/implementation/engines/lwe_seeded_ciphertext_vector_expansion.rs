@@ -0,0 +1,144 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextLweCiphertextVector32, CleartextLweCiphertextVector64,
+    CleartextLweSeededCiphertextVector32, CleartextLweSeededCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweSeededCiphertextVectorExpansionEngine, LweSeededCiphertextVectorExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorExpansionEngine`] for [`CleartextEngine`] that
+/// operates on 32 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl
+    LweSeededCiphertextVectorExpansionEngine<
+        CleartextLweSeededCiphertextVector32,
+        CleartextLweCiphertextVector32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: CleartextLweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: CleartextLweCiphertextVector32 =
+    ///     engine.expand_lwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweSeededCiphertextVector32,
+    ) -> Result<
+        CleartextLweCiphertextVector32,
+        LweSeededCiphertextVectorExpansionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweSeededCiphertextVector32,
+    ) -> CleartextLweCiphertextVector32 {
+        CleartextLweCiphertextVector32 {
+            phases: input.phases.clone(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorExpansionEngine`] for [`CleartextEngine`] that
+/// operates on 64 bits integers.
+///
+/// The phases of the seeded input are copied to the output.
+impl
+    LweSeededCiphertextVectorExpansionEngine<
+        CleartextLweSeededCiphertextVector64,
+        CleartextLweCiphertextVector64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: CleartextLweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: CleartextLweCiphertextVector64 =
+    ///     engine.expand_lwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &CleartextLweSeededCiphertextVector64,
+    ) -> Result<
+        CleartextLweCiphertextVector64,
+        LweSeededCiphertextVectorExpansionError<Self::EngineError>,
+    > {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CleartextLweSeededCiphertextVector64,
+    ) -> CleartextLweCiphertextVector64 {
+        CleartextLweCiphertextVector64 {
+            phases: input.phases.clone(),
+            lwe_dimension: input.lwe_dimension,
+        }
+    }
+}
@@ -0,0 +1,589 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the cleartext
+//! backend.
+
+use crate::backends::core::engines::{EntropySeeder, Seeder};
+use crate::backends::core::private::math::random::{Gaussian, RandomGenerable, RandomGenerator};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweRelinearizationKeyEntity, LweBootstrapKeyEntity,
+    LweKeyswitchKeyEntity,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::{CastFrom, CastInto};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur in the execution of FHE operations, due to the cleartext
+/// implementation.
+///
+/// # Note:
+///
+/// The only operations of the cleartext backend which can fail for reasons specific to the
+/// backend are the ones of the float encoders, which it shares with the `core` backend.
+#[derive(Debug)]
+pub enum CleartextError {
+    FloatEncoderMinMaxOrder,
+    FloatEncoderNullPrecision,
+    FloatEncoderPrecisionOverflow,
+    FloatEncoderValueOutOfInterval,
+}
+impl Display for CleartextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleartextError::FloatEncoderMinMaxOrder => {
+                write!(
+                    f,
+                    "The min value of the float encoder must be smaller than its max value."
+                )
+            }
+            CleartextError::FloatEncoderNullPrecision => {
+                write!(
+                    f,
+                    "The precision of the float encoder must be strictly positive."
+                )
+            }
+            CleartextError::FloatEncoderPrecisionOverflow => {
+                write!(
+                    f,
+                    "The precision and padding of the float encoder must fit in the plaintext."
+                )
+            }
+            CleartextError::FloatEncoderValueOutOfInterval => {
+                write!(
+                    f,
+                    "The cleartext value must be contained in the float encoder interval."
+                )
+            }
+        }
+    }
+}
+impl Error for CleartextError {}
+
+/// The main engine exposed by the cleartext backend.
+///
+/// This engine does not perform any actual cryptography: its ciphertexts hold their phase in
+/// clear, and the noise of the operations is simulated.
+pub struct CleartextEngine {
+    generator: RandomGenerator,
+}
+
+impl CleartextEngine {
+    /// Creates a new engine, whose noise generator is seeded by the given seeder.
+    ///
+    /// A single seed is drawn from the seeder. Two engines created from seeders returning the same
+    /// seed simulate the same noises, provided they execute the same operations in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut first_engine = CleartextEngine::with_seeder(&mut DeterministicSeeder::new(42));
+    /// let key: CleartextLweSecretKey64 = first_engine.create_lwe_secret_key(LweDimension(2))?;
+    /// let plaintext = first_engine.create_plaintext(&input)?;
+    /// let first_ciphertext = first_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let mut second_engine = CleartextEngine::with_seeder(&mut DeterministicSeeder::new(42));
+    /// let second_ciphertext = second_engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(first_ciphertext, second_ciphertext);
+    ///
+    /// first_engine.destroy(key)?;
+    /// first_engine.destroy(plaintext)?;
+    /// first_engine.destroy(first_ciphertext)?;
+    /// second_engine.destroy(second_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_seeder<S: Seeder>(seeder: &mut S) -> CleartextEngine {
+        CleartextEngine {
+            generator: RandomGenerator::new(Some(seeder.seed())),
+        }
+    }
+}
+
+impl AbstractEngineSeal for CleartextEngine {}
+impl AbstractEngine for CleartextEngine {
+    type EngineError = CleartextError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(CleartextEngine::with_seeder(&mut EntropySeeder))
+    }
+}
+
+impl CleartextEngine {
+    // Adds a noise sampled with the given dispersion to a phase.
+    pub(crate) fn add_noise<T: UnsignedTorus>(
+        &mut self,
+        phase: T,
+        noise: impl DispersionParameter,
+    ) -> T {
+        let sample = <T as RandomGenerable<Gaussian<f64>>>::generate_one(
+            &mut self.generator,
+            Gaussian {
+                std: noise.get_standard_dev(),
+                mean: 0.,
+            },
+        );
+        phase.wrapping_add(sample)
+    }
+
+    // Simulates the keyswitch of a phase, by adding the noise predicted for the keyswitch.
+    pub(crate) fn keyswitch_phase<T, K>(&mut self, phase: T, ksk: &K, ksk_noise: Variance) -> T
+    where
+        T: UnsignedTorus,
+        K: LweKeyswitchKeyEntity,
+    {
+        let variance = keyswitch_variance::<T>(
+            ksk.input_lwe_dimension(),
+            ksk_noise,
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+        );
+        self.add_noise(phase, variance)
+    }
+
+    // Simulates the private functional packing keyswitch of phases into the phase polynomial of a
+    // GLWE ciphertext, by multiplying the polynomial whose coefficients are the input phases with
+    // the polynomial of the linear map, and adding the noise predicted for the keyswitches.
+    //
+    // Each input ciphertext is keyswitched on its own before the results are summed, hence every
+    // coefficient of the output receives the noise of as many keyswitches as there are inputs.
+    pub(crate) fn packing_keyswitch_phases<T>(
+        &mut self,
+        phases: &[T],
+        polynomial: &[T],
+        input_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        pksk_noise: Variance,
+    ) -> Vec<T>
+    where
+        T: UnsignedTorus + CastFrom<u128>,
+        u128: CastFrom<T>,
+    {
+        let variance = keyswitch_variance::<T>(
+            input_lwe_dimension,
+            pksk_noise,
+            decomposition_base_log,
+            decomposition_level_count,
+        );
+        let variance = Variance(variance.get_variance() * phases.len() as f64);
+        let mut packed = vec![T::ZERO; polynomial.len()];
+        packed[..phases.len()].copy_from_slice(phases);
+        scaled_negacyclic_product(&packed, polynomial, T::ONE)
+            .into_iter()
+            .map(|phase| self.add_noise(phase, variance))
+            .collect()
+    }
+
+    // Simulates the bootstrap of a phase, by evaluating the lookup table contained in the phase of
+    // the accumulator, and adding the noise predicted for the bootstrap.
+    //
+    // The bootstrap keys created from ternary or gaussian keys encrypt the binary expansion of
+    // the input key, which holds `key_expansion` times as many coefficients as the key itself. The
+    // blind rotation iterates over the expanded key, which increases both the noise and the
+    // rounding errors of the modulus switching.
+    pub(crate) fn bootstrap_phase<T, K>(
+        &mut self,
+        phase: T,
+        accumulator: &[T],
+        bsk: &K,
+        bsk_noise: Variance,
+        key_expansion: usize,
+    ) -> T
+    where
+        T: UnsignedTorus,
+        K: LweBootstrapKeyEntity,
+    {
+        let dimension = LweDimension(bsk.input_lwe_dimension().0 * key_expansion);
+        let switched = self.modulus_switch(phase, dimension, 2 * accumulator.len());
+        let output = negacyclic_lookup(accumulator, switched);
+        let variance = bootstrap_variance::<T, K>(bsk, bsk_noise, dimension);
+        self.add_noise(output, variance)
+    }
+
+    // Simulates the multi-value bootstrap of a phase, by evaluating each lookup table, scaled by
+    // delta, on the same switched phase.
+    pub(crate) fn multi_value_bootstrap_phases<T, K>(
+        &mut self,
+        phase: T,
+        luts: &[T],
+        delta: T,
+        bsk: &K,
+        bsk_noise: Variance,
+        key_expansion: usize,
+    ) -> Vec<T>
+    where
+        T: UnsignedTorus,
+        K: LweBootstrapKeyEntity,
+    {
+        let polynomial_size = bsk.polynomial_size().0;
+        let dimension = LweDimension(bsk.input_lwe_dimension().0 * key_expansion);
+        let switched = self.modulus_switch(phase, dimension, 2 * polynomial_size);
+        let variance = bootstrap_variance::<T, K>(bsk, bsk_noise, dimension);
+        // The common accumulator holds delta/2 in each coefficient, which gets doubled when the
+        // rotated accumulator is multiplied by (1 - X) * T(X).
+        let scale = (delta >> 1).wrapping_add(delta >> 1);
+        luts.chunks(polynomial_size)
+            .map(|lut| {
+                // The noise of the rotated accumulator gets multiplied by the coefficients of
+                // (1 - X) * T(X), which scales its variance by their squared norm.
+                let squared_norm: f64 = (0..polynomial_size)
+                    .map(|j| {
+                        let previous = if j == 0 {
+                            lut[polynomial_size - 1].wrapping_neg()
+                        } else {
+                            lut[j - 1]
+                        };
+                        let coefficient: f64 =
+                            lut[j].wrapping_sub(previous).into_signed().cast_into();
+                        coefficient * coefficient
+                    })
+                    .sum();
+                let output = negacyclic_lookup(lut, switched).wrapping_mul(scale);
+                self.add_noise(output, Variance(variance.get_variance() * squared_norm))
+            })
+            .collect()
+    }
+
+    // Simulates the external product of the phases of a GLWE ciphertext with a GGSW ciphertext, by
+    // multiplying the phases by the scalar encrypted in the GGSW ciphertext, and adding the noise
+    // predicted for the external product.
+    pub(crate) fn external_product_phases<T, G>(
+        &mut self,
+        phases: &[T],
+        scalar: T,
+        ggsw: &G,
+        ggsw_noise: Variance,
+    ) -> Vec<T>
+    where
+        T: UnsignedTorus,
+        G: GgswCiphertextEntity,
+    {
+        // The input noise is already contained in the phases, and gets multiplied along with
+        // them, so only the noise added by the external product is predicted. The estimator
+        // returns a variance in the integer representation of the torus.
+        let modular_variance = concrete_npe::estimate_external_product_noise_with_binary_ggsw::<
+            T,
+            _,
+            _,
+            BinaryKeyKind,
+        >(
+            ggsw.polynomial_size(),
+            ggsw.glwe_dimension(),
+            Variance(0.),
+            ggsw_noise,
+            ggsw.decomposition_base_log(),
+            ggsw.decomposition_level_count(),
+        );
+        let variance = Variance::from_modular_variance::<T>(modular_variance.get_variance());
+        phases
+            .iter()
+            .map(|phase| self.add_noise(phase.wrapping_mul(scalar), variance))
+            .collect()
+    }
+
+    // Simulates the multiplication of two GLWE ciphertexts, by computing the product of their phase
+    // polynomials divided by delta, as the tensor product of the core backend does, and adding the
+    // noise predicted for the tensor product and the relinearization.
+    //
+    // The product of the phases already contains the products of the input noises with the
+    // messages and with each other. The noise which depends on the variance of the inputs
+    // through their masks can not be predicted, since the ciphertexts do not hold their
+    // variance, and only the noise added by the roundings and the relinearization is simulated.
+    pub(crate) fn multiply_phases<T, R>(
+        &mut self,
+        lhs: &[T],
+        rhs: &[T],
+        delta: T,
+        rlk: &R,
+        rlk_noise: Variance,
+    ) -> Vec<T>
+    where
+        T: UnsignedTorus + CastFrom<u128>,
+        u128: CastFrom<T>,
+        R: GlweRelinearizationKeyEntity,
+    {
+        let delta_f64: f64 = delta.cast_into();
+        // The estimators return variances in the integer representation of the torus. They are
+        // summed here rather than with `estimate_multiplication_noise`, which converts them once
+        // more.
+        let tensor_product_variance =
+            concrete_npe::estimate_tensor_product_noise::<T, _, _, BinaryKeyKind>(
+                rlk.polynomial_size(),
+                rlk.glwe_dimension(),
+                Variance(0.),
+                Variance(0.),
+                delta_f64,
+                delta_f64,
+                0.,
+                0.,
+            );
+        let relinearization_variance =
+            concrete_npe::estimate_relinearization_noise::<T, _, BinaryKeyKind>(
+                rlk.polynomial_size(),
+                rlk.glwe_dimension(),
+                rlk_noise,
+                rlk.decomposition_base_log(),
+                rlk.decomposition_level_count(),
+            );
+        let variance = Variance::from_modular_variance::<T>(
+            tensor_product_variance.get_variance() + relinearization_variance.get_variance(),
+        );
+        scaled_negacyclic_product(lhs, rhs, delta)
+            .into_iter()
+            .map(|phase| self.add_noise(phase, variance))
+            .collect()
+    }
+
+    // Switches a phase to a smaller power of two modulus, such as the modulus 2N used by the blind
+    // rotation.
+    //
+    // In the core backend, both the body and the mask of the ciphertext are rounded. The rounding
+    // of the body is performed as is, while the rounding errors of the mask, multiplied by the
+    // binary key coefficients, are simulated by a gaussian drift of variance n/24.
+    pub(crate) fn modulus_switch<T: UnsignedTorus>(
+        &mut self,
+        phase: T,
+        lwe_dimension: LweDimension,
+        modulus: usize,
+    ) -> usize {
+        let (drift, _): (f64, f64) = self
+            .generator
+            .random_gaussian(0., (lwe_dimension.0 as f64 / 24.).sqrt());
+        let switched = (phase.into_torus() * modulus as f64 + drift).round() as i64;
+        switched.rem_euclid(modulus as i64) as usize
+    }
+}
+
+// Returns the constant coefficient of a table multiplied by X^{-index}, in the negacyclic way of
+// the blind rotation: the coefficients wrap around with a negation.
+fn negacyclic_lookup<T: UnsignedTorus>(table: &[T], index: usize) -> T {
+    if index < table.len() {
+        table[index]
+    } else {
+        table[index - table.len()].wrapping_neg()
+    }
+}
+
+// Returns the negacyclic product of two polynomials, whose coefficients are lifted to signed
+// integers, divided by delta with rounding. The products are computed modulo 2^128, which is
+// enough to compute the output modulo delta * 2^BITS.
+fn scaled_negacyclic_product<T>(lhs: &[T], rhs: &[T], delta: T) -> Vec<T>
+where
+    T: UnsignedTorus + CastFrom<u128>,
+    u128: CastFrom<T>,
+{
+    let lift = |coef: T| {
+        let mut value = u128::cast_from(coef) as i128;
+        if value >= 1 << (T::BITS - 1) {
+            value -= 1 << T::BITS;
+        }
+        value as u128
+    };
+    let size = lhs.len();
+    let mut product = vec![0u128; size];
+    for (i, lhs_coef) in lhs.iter().map(|coef| lift(*coef)).enumerate() {
+        for (j, rhs_coef) in rhs.iter().map(|coef| lift(*coef)).enumerate() {
+            let term = lhs_coef.wrapping_mul(rhs_coef);
+            if i + j < size {
+                product[i + j] = product[i + j].wrapping_add(term);
+            } else {
+                product[i + j - size] = product[i + j - size].wrapping_sub(term);
+            }
+        }
+    }
+    let delta = u128::cast_from(delta);
+    let delta_log = delta.trailing_zeros();
+    product
+        .into_iter()
+        .map(|coef| T::cast_from(coef.wrapping_add(delta >> 1) >> delta_log))
+        .collect()
+}
+
+// Returns the variance predicted for the noise added by a keyswitch from a key of the given
+// dimension.
+fn keyswitch_variance<T: UnsignedTorus>(
+    input_lwe_dimension: LweDimension,
+    ksk_noise: Variance,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+) -> Variance {
+    // The input noise is already contained in the phase, so only the noise added by the keyswitch
+    // is predicted. The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+        T,
+        _,
+        _,
+        BinaryKeyKind,
+    >(
+        input_lwe_dimension,
+        Variance(0.),
+        ksk_noise,
+        decomposition_base_log,
+        decomposition_level_count,
+    );
+    Variance::from_modular_variance::<T>(modular_variance.get_variance())
+}
+
+// Returns the variance predicted for the noise added by a bootstrap, whose blind rotation iterates
+// over a key of the given dimension.
+fn bootstrap_variance<T, K>(bsk: &K, bsk_noise: Variance, dimension: LweDimension) -> Variance
+where
+    T: UnsignedTorus,
+    K: LweBootstrapKeyEntity,
+{
+    // The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_pbs_noise::<T, _, BinaryKeyKind>(
+        dimension,
+        bsk.polynomial_size(),
+        bsk.glwe_dimension(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk_noise,
+    );
+    Variance::from_modular_variance::<T>(modular_variance.get_variance())
+}
+
+mod circuit_bootstrap_private_functional_packing_keyswitch_keys_creation;
+mod cleartext_conversion;
+mod cleartext_creation;
+mod cleartext_discarding_conversion;
+mod cleartext_encoding;
+mod cleartext_retrieval;
+mod cleartext_vector_conversion;
+mod cleartext_vector_creation;
+mod cleartext_vector_discarding_conversion;
+mod cleartext_vector_encoding;
+mod cleartext_vector_retrieval;
+mod destruction;
+mod encoder_creation;
+mod encoder_vector_creation;
+mod entity_deserialization;
+mod entity_serialization;
+mod ggsw_ciphertext_conversion;
+mod ggsw_ciphertext_scalar_discarding_encryption;
+mod ggsw_ciphertext_scalar_encryption;
+mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_scalar_encryption;
+mod glwe_ciphertext_consuming_retrieval;
+mod glwe_ciphertext_conversion;
+mod glwe_ciphertext_creation;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_conversion;
+mod glwe_ciphertext_discarding_decryption;
+mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_multiplication;
+mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_ggsw_ciphertext_external_product;
+#[cfg(feature = "noise_measurement")]
+mod glwe_ciphertext_noise_measurement;
+mod glwe_ciphertext_vector_conversion;
+mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_discarding_conversion;
+mod glwe_ciphertext_vector_discarding_decryption;
+mod glwe_ciphertext_vector_discarding_encryption;
+mod glwe_ciphertext_vector_encryption;
+mod glwe_ciphertext_vector_zero_encryption;
+mod glwe_ciphertext_zero_encryption;
+mod glwe_ciphertexts_ggsw_ciphertext_cmux;
+mod glwe_relinearization_key_creation;
+mod glwe_secret_key_conversion;
+mod glwe_secret_key_creation;
+mod glwe_secret_key_discarding_conversion;
+mod glwe_seeded_ciphertext_encryption;
+mod glwe_seeded_ciphertext_expansion;
+mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_ciphertext_vector_expansion;
+mod lwe_bootstrap_key_consuming_retrieval;
+mod lwe_bootstrap_key_conversion;
+mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_creation_from_container;
+mod lwe_bootstrap_key_discarding_conversion;
+mod lwe_ciphertext_cleartext_discarding_multiplication;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_compression;
+mod lwe_ciphertext_consuming_retrieval;
+mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_creation;
+mod lwe_ciphertext_decompression;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_conversion;
+mod lwe_ciphertext_discarding_decryption;
+mod lwe_ciphertext_discarding_encryption;
+mod lwe_ciphertext_discarding_extraction;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_loading;
+mod lwe_ciphertext_discarding_negation;
+mod lwe_ciphertext_discarding_storing;
+mod lwe_ciphertext_discarding_vertical_packing;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_negation;
+mod lwe_ciphertext_ggsw_ciphertext_discarding_circuit_bootstrap;
+mod lwe_ciphertext_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_noise_measurement;
+mod lwe_ciphertext_plaintext_discarding_addition;
+mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_public_key_encryption;
+mod lwe_ciphertext_vector_compression;
+mod lwe_ciphertext_vector_consuming_retrieval;
+mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
+mod lwe_ciphertext_vector_decompression;
+mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_conversion;
+mod lwe_ciphertext_vector_discarding_decryption;
+mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_multi_value_bootstrap;
+mod lwe_ciphertext_vector_encryption;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_vector_noise_measurement;
+mod lwe_ciphertext_vector_zero_encryption;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_consuming_retrieval;
+mod lwe_keyswitch_key_conversion;
+mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_creation_from_container;
+mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_public_key_creation;
+mod lwe_secret_key_conversion;
+mod lwe_secret_key_creation;
+mod lwe_secret_key_discarding_conversion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_expansion;
+mod packing_keyswitch_key_creation;
+mod plaintext_conversion;
+mod plaintext_creation;
+mod plaintext_decoding;
+mod plaintext_discarding_conversion;
+mod plaintext_vector_conversion;
+mod plaintext_vector_creation;
+mod plaintext_vector_decoding;
+mod plaintext_vector_discarding_conversion;
+mod private_functional_packing_keyswitch_key_creation;
@@ -0,0 +1,227 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextPackingKeyswitchKey32, CleartextPackingKeyswitchKey64,
+};
+use crate::specification::engines::{
+    PackingKeyswitchKeyCreationEngine, PackingKeyswitchKeyCreationError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`PackingKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 32 bits integers.
+impl
+    PackingKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextPackingKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let packing_keyswitch_key = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_packing_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<CleartextPackingKeyswitchKey32, PackingKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(PackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextPackingKeyswitchKey32 {
+        CleartextPackingKeyswitchKey32 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            output_polynomial_size: output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`PackingKeyswitchKeyCreationEngine`] for [`CleartextEngine`] that operates on
+/// 64 bits integers.
+impl
+    PackingKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextPackingKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let packing_keyswitch_key = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_packing_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<CleartextPackingKeyswitchKey64, PackingKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(PackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextPackingKeyswitchKey64 {
+        CleartextPackingKeyswitchKey64 {
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            output_polynomial_size: output_key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::backends::cleartext::implementation::engines::{CleartextEngine, CleartextError};
+use crate::backends::core::entities::{CleartextF64, FloatEncoder, Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Encoder;
+use crate::specification::engines::{PlaintextDecodingEngine, PlaintextDecodingError};
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CleartextEngine`] that decodes 32 bits
+/// integers to 64 bits floating point numbers.
+///
+/// The encoders, plaintexts and cleartexts are the ones of the `core` backend, which this
+/// implementation shares with it.
+impl PlaintextDecodingEngine<FloatEncoder, Plaintext32, CleartextF64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext32 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let decoded: CleartextF64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let value: f64 = engine.retrieve_cleartext(&decoded)?;
+    /// assert!((value - 5.).abs() < 0.1);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(decoded)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext32,
+    ) -> Result<CleartextF64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u32>() {
+            return Err(PlaintextDecodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext32,
+    ) -> CleartextF64 {
+        CleartextF64(encoder.0.decode(input.0))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDecodingEngine`] for [`CleartextEngine`] that decodes 64 bits
+/// integers to 64 bits floating point numbers.
+///
+/// The encoders, plaintexts and cleartexts are the ones of the `core` backend, which this
+/// implementation shares with it.
+impl PlaintextDecodingEngine<FloatEncoder, Plaintext64, CleartextF64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// let cleartext: CleartextF64 = engine.create_cleartext(&5.)?;
+    /// let plaintext: Plaintext64 = engine.encode_cleartext(&encoder, &cleartext)?;
+    /// let decoded: CleartextF64 = engine.decode_plaintext(&encoder, &plaintext)?;
+    /// #
+    /// let value: f64 = engine.retrieve_cleartext(&decoded)?;
+    /// assert!((value - 5.).abs() < 0.1);
+    /// engine.destroy(encoder)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(decoded)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext64,
+    ) -> Result<CleartextF64, PlaintextDecodingError<Self::EngineError>> {
+        if !encoder.0.fits_in::<u64>() {
+            return Err(PlaintextDecodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_unchecked(
+        &mut self,
+        encoder: &FloatEncoder,
+        input: &Plaintext64,
+    ) -> CleartextF64 {
+        CleartextF64(encoder.0.decode(input.0))
+    }
+}
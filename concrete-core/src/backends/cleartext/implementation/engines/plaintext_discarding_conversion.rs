@@ -0,0 +1,98 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::core::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    PlaintextDiscardingConversionEngine, PlaintextDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextDiscardingConversionEngine`] for [`CleartextEngine`] that converts
+/// a 32 bits plaintext to a 64 bits plaintext. The torus elements are exactly rescaled to the
+/// output precision.
+///
+/// The plaintexts are the ones of the `core` backend, which this implementation shares with it.
+impl PlaintextDiscardingConversionEngine<Plaintext32, Plaintext64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext(&input)?;
+    /// let mut output: Plaintext64 = engine.create_plaintext(&0_u64)?;
+    ///
+    /// engine.discard_convert_plaintext(&mut output, &plaintext)?;
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext(
+        &mut self,
+        output: &mut Plaintext64,
+        input: &Plaintext32,
+    ) -> Result<(), PlaintextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_unchecked(
+        &mut self,
+        output: &mut Plaintext64,
+        input: &Plaintext32,
+    ) {
+        output.0 .0 = input.0 .0.cast_torus_into();
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextDiscardingConversionEngine`] for [`CleartextEngine`] that converts
+/// a 64 bits plaintext to a 32 bits plaintext. The torus elements are rounded to the closest
+/// element of the output precision.
+///
+/// The plaintexts are the ones of the `core` backend, which this implementation shares with it.
+impl PlaintextDiscardingConversionEngine<Plaintext64, Plaintext32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext(&input)?;
+    /// let mut output: Plaintext32 = engine.create_plaintext(&0_u32)?;
+    ///
+    /// engine.discard_convert_plaintext(&mut output, &plaintext)?;
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext(
+        &mut self,
+        output: &mut Plaintext32,
+        input: &Plaintext64,
+    ) -> Result<(), PlaintextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_plaintext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_unchecked(
+        &mut self,
+        output: &mut Plaintext32,
+        input: &Plaintext64,
+    ) {
+        output.0 .0 = input.0 .0.cast_torus_into();
+    }
+}
@@ -0,0 +1,106 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::specification::engines::{
+    PlaintextVectorConversionEngine, PlaintextVectorConversionError,
+    PlaintextVectorDiscardingConversionEngine,
+};
+use crate::specification::entities::PlaintextVectorEntity;
+
+/// # Description:
+/// Implementation of [`PlaintextVectorConversionEngine`] for [`CleartextEngine`] that converts a 32
+/// bits plaintext vector to a 64 bits plaintext vector. The torus elements are exactly rescaled to
+/// the output precision.
+///
+/// The plaintext vectors are the ones of the `core` backend, which this implementation shares with
+/// it.
+impl PlaintextVectorConversionEngine<PlaintextVector32, PlaintextVector64> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let output: PlaintextVector64 = engine.convert_plaintext_vector(&plaintext_vector)?;
+    /// #
+    /// assert_eq!(output.plaintext_count(), PlaintextCount(100));
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext_vector(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> Result<PlaintextVector64, PlaintextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_vector_unchecked(
+        &mut self,
+        input: &PlaintextVector32,
+    ) -> PlaintextVector64 {
+        let mut output =
+            PlaintextVector64(ImplPlaintextList::allocate(0_u64, input.plaintext_count()));
+        self.discard_convert_plaintext_vector_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorConversionEngine`] for [`CleartextEngine`] that converts a 64
+/// bits plaintext vector to a 32 bits plaintext vector. The torus elements are rounded to the
+/// closest element of the output precision.
+///
+/// The plaintext vectors are the ones of the `core` backend, which this implementation shares with
+/// it.
+impl PlaintextVectorConversionEngine<PlaintextVector64, PlaintextVector32> for CleartextEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let output: PlaintextVector32 = engine.convert_plaintext_vector(&plaintext_vector)?;
+    /// #
+    /// assert_eq!(output.plaintext_count(), PlaintextCount(100));
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext_vector(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> Result<PlaintextVector32, PlaintextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_vector_unchecked(
+        &mut self,
+        input: &PlaintextVector64,
+    ) -> PlaintextVector32 {
+        let mut output =
+            PlaintextVector32(ImplPlaintextList::allocate(0_u32, input.plaintext_count()));
+        self.discard_convert_plaintext_vector_unchecked(&mut output, input);
+        output
+    }
+}
@@ -0,0 +1,155 @@
+use crate::backends::cleartext::implementation::engines::{CleartextEngine, CleartextError};
+use crate::backends::core::entities::{
+    CleartextVectorF64, FloatEncoderVector, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::{
+    CleartextList as ImplCleartextList, Encoder,
+};
+use crate::specification::engines::{PlaintextVectorDecodingEngine, PlaintextVectorDecodingError};
+use crate::specification::entities::{EncoderVectorEntity, PlaintextVectorEntity};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CleartextEngine`] that decodes 32 bits
+/// integers to 64 bits floating point numbers.
+///
+/// The encoder vectors, plaintext vectors and cleartext vectors are the ones of the `core` backend,
+/// which this implementation shares with it.
+impl PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector32, CleartextVectorF64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let decoded_vector: CleartextVectorF64 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// let values: Vec<f64> = engine.retrieve_cleartext_vector(&decoded_vector)?;
+    /// assert!(values.iter().all(|v| (v - 5.).abs() < 0.1));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decoded_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector32,
+    ) -> Result<CleartextVectorF64, PlaintextVectorDecodingError<Self::EngineError>> {
+        if encoder.encoder_count().0 != input.plaintext_count().0 {
+            return Err(PlaintextVectorDecodingError::EncoderCountMismatch);
+        }
+        if encoder.0.iter().any(|e| !e.fits_in::<u32>()) {
+            return Err(PlaintextVectorDecodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector32,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(e, p)| e.decode(*p).0)
+                .collect::<Vec<f64>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDecodingEngine`] for [`CleartextEngine`] that decodes 64 bits
+/// integers to 64 bits floating point numbers.
+///
+/// The encoder vectors, plaintext vectors and cleartext vectors are the ones of the `core` backend,
+/// which this implementation shares with it.
+impl PlaintextVectorDecodingEngine<FloatEncoderVector, PlaintextVector64, CleartextVectorF64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CleartextEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// let decoded_vector: CleartextVectorF64 =
+    ///     engine.decode_plaintext_vector(&encoder_vector, &plaintext_vector)?;
+    /// #
+    /// let values: Vec<f64> = engine.retrieve_cleartext_vector(&decoded_vector)?;
+    /// assert!(values.iter().all(|v| (v - 5.).abs() < 0.1));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(decoded_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decode_plaintext_vector(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector64,
+    ) -> Result<CleartextVectorF64, PlaintextVectorDecodingError<Self::EngineError>> {
+        if encoder.encoder_count().0 != input.plaintext_count().0 {
+            return Err(PlaintextVectorDecodingError::EncoderCountMismatch);
+        }
+        if encoder.0.iter().any(|e| !e.fits_in::<u64>()) {
+            return Err(PlaintextVectorDecodingError::Engine(
+                CleartextError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        Ok(unsafe { self.decode_plaintext_vector_unchecked(encoder, input) })
+    }
+
+    unsafe fn decode_plaintext_vector_unchecked(
+        &mut self,
+        encoder: &FloatEncoderVector,
+        input: &PlaintextVector64,
+    ) -> CleartextVectorF64 {
+        CleartextVectorF64(ImplCleartextList::from_container(
+            encoder
+                .0
+                .iter()
+                .zip(input.0.plaintext_iter())
+                .map(|(e, p)| e.decode(*p).0)
+                .collect::<Vec<f64>>(),
+        ))
+    }
+}
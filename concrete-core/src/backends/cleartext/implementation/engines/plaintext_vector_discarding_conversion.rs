@@ -0,0 +1,124 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::core::entities::{PlaintextVector32, PlaintextVector64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    PlaintextVectorDiscardingConversionEngine, PlaintextVectorDiscardingConversionError,
+};
+use crate::specification::entities::PlaintextVectorEntity;
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 32 bits plaintext vector to a 64 bits plaintext vector. The torus elements are
+/// exactly rescaled to the output precision.
+///
+/// The plaintext vectors are the ones of the `core` backend, which this implementation shares with
+/// it.
+impl PlaintextVectorDiscardingConversionEngine<PlaintextVector32, PlaintextVector64>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let mut output: PlaintextVector64 = engine.create_plaintext_vector(&vec![0_u64; 100])?;
+    ///
+    /// engine.discard_convert_plaintext_vector(&mut output, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(output.plaintext_count(), PlaintextCount(100));
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext_vector(
+        &mut self,
+        output: &mut PlaintextVector64,
+        input: &PlaintextVector32,
+    ) -> Result<(), PlaintextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.plaintext_count() != input.plaintext_count() {
+            return Err(PlaintextVectorDiscardingConversionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_convert_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut PlaintextVector64,
+        input: &PlaintextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextVectorDiscardingConversionEngine`] for [`CleartextEngine`] that
+/// converts a 64 bits plaintext vector to a 32 bits plaintext vector. The torus elements are
+/// rounded to the closest element of the output precision.
+///
+/// The plaintext vectors are the ones of the `core` backend, which this implementation shares with
+/// it.
+impl PlaintextVectorDiscardingConversionEngine<PlaintextVector64, PlaintextVector32>
+    for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 100];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let mut output: PlaintextVector32 = engine.create_plaintext_vector(&vec![0_u32; 100])?;
+    ///
+    /// engine.discard_convert_plaintext_vector(&mut output, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(output.plaintext_count(), PlaintextCount(100));
+    ///
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_plaintext_vector(
+        &mut self,
+        output: &mut PlaintextVector32,
+        input: &PlaintextVector64,
+    ) -> Result<(), PlaintextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.plaintext_count() != input.plaintext_count() {
+            return Err(PlaintextVectorDiscardingConversionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_convert_plaintext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_plaintext_vector_unchecked(
+        &mut self,
+        output: &mut PlaintextVector32,
+        input: &PlaintextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
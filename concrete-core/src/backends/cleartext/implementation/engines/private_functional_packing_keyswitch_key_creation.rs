@@ -0,0 +1,291 @@
+use crate::backends::cleartext::implementation::engines::CleartextEngine;
+use crate::backends::cleartext::implementation::entities::{
+    CleartextGlweSecretKey32, CleartextGlweSecretKey64, CleartextLweSecretKey32,
+    CleartextLweSecretKey64, CleartextPrivateFunctionalPackingKeyswitchKey32,
+    CleartextPrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::backends::core::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    PrivateFunctionalPackingKeyswitchKeyCreationEngine,
+    PrivateFunctionalPackingKeyswitchKeyCreationError,
+};
+use crate::specification::entities::{
+    CleartextVectorEntity, GlweSecretKeyEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`PrivateFunctionalPackingKeyswitchKeyCreationEngine`] for [`CleartextEngine`]
+/// that operates on 32 bits integers.
+impl
+    PrivateFunctionalPackingKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey32,
+        CleartextGlweSecretKey32,
+        CleartextVector32,
+        CleartextPrivateFunctionalPackingKeyswitchKey32,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u32, 1, 0, 0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector32 =
+    ///     engine.create_cleartext_vector(&polynomial_coefficients)?;
+    ///
+    /// let private_functional_packing_keyswitch_key = engine
+    ///     .create_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         &polynomial,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     private_functional_packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     private_functional_packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.input_lwe_dimension(),
+    ///     input_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.output_glwe_dimension(),
+    ///     output_glwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.output_polynomial_size(),
+    ///     polynomial_size
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(private_functional_packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        polynomial: &CleartextVector32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextPrivateFunctionalPackingKeyswitchKey32,
+        PrivateFunctionalPackingKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(
+                PrivateFunctionalPackingKeyswitchKeyCreationError::NullDecompositionBaseLog,
+            );
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(
+                PrivateFunctionalPackingKeyswitchKeyCreationError::NullDecompositionLevelCount,
+            );
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(PrivateFunctionalPackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        if polynomial.cleartext_count().0 != output_key.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchKeyCreationError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe {
+            self.create_private_functional_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                polynomial,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey32,
+        output_key: &CleartextGlweSecretKey32,
+        polynomial: &CleartextVector32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextPrivateFunctionalPackingKeyswitchKey32 {
+        CleartextPrivateFunctionalPackingKeyswitchKey32 {
+            polynomial: polynomial.0.as_tensor().iter().copied().collect(),
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`PrivateFunctionalPackingKeyswitchKeyCreationEngine`] for [`CleartextEngine`]
+/// that operates on 64 bits integers.
+impl
+    PrivateFunctionalPackingKeyswitchKeyCreationEngine<
+        CleartextLweSecretKey64,
+        CleartextGlweSecretKey64,
+        CleartextVector64,
+        CleartextPrivateFunctionalPackingKeyswitchKey64,
+    > for CleartextEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u64, 1, 0, 0];
+    ///
+    /// let mut engine = CleartextEngine::new()?;
+    /// let input_key: CleartextLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: CleartextGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector64 =
+    ///     engine.create_cleartext_vector(&polynomial_coefficients)?;
+    ///
+    /// let private_functional_packing_keyswitch_key = engine
+    ///     .create_private_functional_packing_keyswitch_key(
+    ///         &input_key,
+    ///         &output_key,
+    ///         &polynomial,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(
+    /// #     private_functional_packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     private_functional_packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.input_lwe_dimension(),
+    ///     input_lwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.output_glwe_dimension(),
+    ///     output_glwe_dimension
+    /// );
+    /// assert_eq!(
+    ///     private_functional_packing_keyswitch_key.output_polynomial_size(),
+    ///     polynomial_size
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(private_functional_packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        polynomial: &CleartextVector64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CleartextPrivateFunctionalPackingKeyswitchKey64,
+        PrivateFunctionalPackingKeyswitchKeyCreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(
+                PrivateFunctionalPackingKeyswitchKeyCreationError::NullDecompositionBaseLog,
+            );
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(
+                PrivateFunctionalPackingKeyswitchKeyCreationError::NullDecompositionLevelCount,
+            );
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(PrivateFunctionalPackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        if polynomial.cleartext_count().0 != output_key.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchKeyCreationError::PolynomialSizeMismatch);
+        }
+        Ok(unsafe {
+            self.create_private_functional_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                polynomial,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &CleartextLweSecretKey64,
+        output_key: &CleartextGlweSecretKey64,
+        polynomial: &CleartextVector64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CleartextPrivateFunctionalPackingKeyswitchKey64 {
+        CleartextPrivateFunctionalPackingKeyswitchKey64 {
+            polynomial: polynomial.0.as_tensor().iter().copied().collect(),
+            input_lwe_dimension: input_key.lwe_dimension(),
+            output_glwe_dimension: output_key.glwe_dimension(),
+            decomposition_level_count,
+            decomposition_base_log,
+            noise,
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind,
+};
+use crate::specification::entities::{
+    AbstractEntity, CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing the private functional packing keyswitch keys used in a circuit
+/// bootstrap, with 32 bits of precision, in the cleartext backend.
+///
+/// The keys only carry their parameters, and the variance of the noise they would have been
+/// encrypted with, which are used to simulate the noise of the circuit bootstraps. There is one
+/// key per row of the output GGSW ciphertexts, that is the output GLWE dimension plus one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) output_polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+    type Kind = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        FunctionalPackingKeyswitchKeyCount(self.output_glwe_dimension.to_glwe_size().0)
+    }
+}
+
+/// A structure representing the private functional packing keyswitch keys used in a circuit
+/// bootstrap, with 64 bits of precision, in the cleartext backend.
+///
+/// The keys only carry their parameters, and the variance of the noise they would have been
+/// encrypted with, which are used to simulate the noise of the circuit bootstraps. There is one
+/// key per row of the output GGSW ciphertexts, that is the output GLWE dimension plus one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) output_polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+    type Kind = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for CleartextCircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        FunctionalPackingKeyswitchKeyCount(self.output_glwe_dimension.to_glwe_size().0)
+    }
+}
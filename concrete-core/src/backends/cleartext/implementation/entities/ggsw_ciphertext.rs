@@ -0,0 +1,154 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, GgswCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The ciphertext holds the encrypted scalar along with its parameters, and the variance of the
+/// noise it was encrypted with, which is used to simulate the noise of the external products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGgswCiphertext32 {
+    pub(crate) plaintext: u32,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for CleartextGgswCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The ciphertext holds the encrypted scalar along with its parameters, and the variance of the
+/// noise it was encrypted with, which is used to simulate the noise of the external products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGgswCiphertext64 {
+    pub(crate) plaintext: u64,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for CleartextGgswCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision in the Fourier domain, in
+/// the cleartext backend.
+///
+/// The ciphertext holds the encrypted scalar along with its parameters, and the variance of the
+/// noise it was encrypted with, which is used to simulate the noise of the external products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierGgswCiphertext32 {
+    pub(crate) plaintext: u32,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for CleartextFourierGgswCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision in the Fourier domain, in
+/// the cleartext backend.
+///
+/// The ciphertext holds the encrypted scalar along with its parameters, and the variance of the
+/// noise it was encrypted with, which is used to simulate the noise of the external products.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierGgswCiphertext64 {
+    pub(crate) plaintext: u64,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for CleartextFourierGgswCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
@@ -0,0 +1,87 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, GgswCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, GgswCiphertextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    PolynomialSize,
+};
+
+/// A structure representing a vector of GGSW ciphertexts with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the encrypted scalars, which share the same parameters and noise variance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGgswCiphertextVector32 {
+    pub(crate) plaintexts: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGgswCiphertextVector32 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for CleartextGgswCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.plaintexts.len())
+    }
+}
+
+/// A structure representing a vector of GGSW ciphertexts with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the encrypted scalars, which share the same parameters and noise variance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGgswCiphertextVector64 {
+    pub(crate) plaintexts: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGgswCiphertextVector64 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for CleartextGgswCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        GgswCiphertextCount(self.plaintexts.len())
+    }
+}
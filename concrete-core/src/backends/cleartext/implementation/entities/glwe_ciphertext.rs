@@ -0,0 +1,249 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweCiphertextKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertext32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertext64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a ternary key, with 32 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernaryCiphertext32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweTernaryCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweTernaryCiphertext32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a ternary key, with 64 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernaryCiphertext64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweTernaryCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweTernaryCiphertext64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a gaussian key, with 32 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianCiphertext32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweGaussianCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweGaussianCiphertext32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a gaussian key, with 64 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds the coefficients of its phase polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianCiphertext64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweGaussianCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweGaussianCiphertext64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, viewing an immutable
+/// slice of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body polynomial of the slice, which holds the coefficients of its phase
+/// polynomial. The mask polynomials of the slice are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertextView32<'a> {
+    pub(crate) phases: &'a [u32],
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertextView32<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertextView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, viewing a mutable
+/// slice of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body polynomial of the slice, which holds the coefficients of its phase
+/// polynomial. The mask polynomials of the slice are ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextGlweCiphertextMutView32<'a> {
+    pub(crate) phases: &'a mut [u32],
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertextMutView32<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertextMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, viewing an immutable
+/// slice of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body polynomial of the slice, which holds the coefficients of its phase
+/// polynomial. The mask polynomials of the slice are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertextView64<'a> {
+    pub(crate) phases: &'a [u64],
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertextView64<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertextView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, viewing a mutable
+/// slice of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body polynomial of the slice, which holds the coefficients of its phase
+/// polynomial. The mask polynomials of the slice are ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextGlweCiphertextMutView64<'a> {
+    pub(crate) phases: &'a mut [u64],
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweCiphertextMutView64<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for CleartextGlweCiphertextMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweCiphertextVectorKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextVectorEntity};
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+/// A structure representing a vector of GLWE ciphertexts with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a ternary key, with 32
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernaryCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweTernaryCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweTernaryCiphertextVector32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a ternary key, with 64
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernaryCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweTernaryCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweTernaryCiphertextVector64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a gaussian key, with 32
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweGaussianCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweGaussianCiphertextVector32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a gaussian key, with 64
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the coefficients of the phase polynomials of the ciphertexts, one after the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweGaussianCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for CleartextGlweGaussianCiphertextVector64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweRelinearizationKeyKind};
+use crate::specification::entities::{AbstractEntity, GlweRelinearizationKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A structure representing a GLWE relinearization key with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the relinearizations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweRelinearizationKey32 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGlweRelinearizationKey32 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+impl GlweRelinearizationKeyEntity for CleartextGlweRelinearizationKey32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a GLWE relinearization key with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the relinearizations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweRelinearizationKey64 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextGlweRelinearizationKey64 {
+    type Kind = GlweRelinearizationKeyKind;
+}
+impl GlweRelinearizationKeyEntity for CleartextGlweRelinearizationKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
@@ -0,0 +1,153 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweSecretKeyKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE secret key with 32 bits of precision, in the cleartext backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSecretKey32 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweSecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweSecretKey32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A structure representing a GLWE secret key with 64 bits of precision, in the cleartext backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSecretKey64 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweSecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweSecretKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A structure representing a ternary GLWE secret key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernarySecretKey32 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweTernarySecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweTernarySecretKey32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A structure representing a ternary GLWE secret key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweTernarySecretKey64 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweTernarySecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweTernarySecretKey64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A structure representing a gaussian GLWE secret key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianSecretKey32 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweGaussianSecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweGaussianSecretKey32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
+
+/// A structure representing a gaussian GLWE secret key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimensions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweGaussianSecretKey64 {
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweGaussianSecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for CleartextGlweGaussianSecretKey64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+}
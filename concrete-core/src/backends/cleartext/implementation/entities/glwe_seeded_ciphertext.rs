@@ -0,0 +1,53 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSeededCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GlweSeededCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a seeded GLWE ciphertext with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The cleartext backend does not generate any mask, hence the ciphertext holds the coefficients
+/// of its phase polynomial like the regular GLWE ciphertexts do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSeededCiphertext32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweSeededCiphertext32 {
+    type Kind = GlweSeededCiphertextKind;
+}
+impl GlweSeededCiphertextEntity for CleartextGlweSeededCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
+
+/// A structure representing a seeded GLWE ciphertext with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The cleartext backend does not generate any mask, hence the ciphertext holds the coefficients
+/// of its phase polynomial like the regular GLWE ciphertexts do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSeededCiphertext64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+}
+impl AbstractEntity for CleartextGlweSeededCiphertext64 {
+    type Kind = GlweSeededCiphertextKind;
+}
+impl GlweSeededCiphertextEntity for CleartextGlweSeededCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.phases.len())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSeededCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, GlweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+/// A structure representing a vector of seeded GLWE ciphertexts with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The cleartext backend does not generate any mask, hence the vector holds the coefficients of
+/// the phase polynomials of the ciphertexts, one after the other, like the regular GLWE
+/// ciphertext vectors do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSeededCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweSeededCiphertextVector32 {
+    type Kind = GlweSeededCiphertextVectorKind;
+}
+impl GlweSeededCiphertextVectorEntity for CleartextGlweSeededCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
+
+/// A structure representing a vector of seeded GLWE ciphertexts with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The cleartext backend does not generate any mask, hence the vector holds the coefficients of
+/// the phase polynomials of the ciphertexts, one after the other, like the regular GLWE
+/// ciphertext vectors do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextGlweSeededCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+}
+impl AbstractEntity for CleartextGlweSeededCiphertextVector64 {
+    type Kind = GlweSeededCiphertextVectorKind;
+}
+impl GlweSeededCiphertextVectorEntity for CleartextGlweSeededCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.phases.len() / self.polynomial_size.0)
+    }
+}
@@ -0,0 +1,512 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweBootstrapKeyKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweBootstrapKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweBootstrapKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 32 bits of precision, in
+/// the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweTernaryBootstrapKey32 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 64 bits of precision, in
+/// the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweTernaryBootstrapKey64 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 32 bits of precision, in
+/// the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweGaussianBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweGaussianBootstrapKey32 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 64 bits of precision, in
+/// the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the bootstraps.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweGaussianBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextLweGaussianBootstrapKey64 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 32 bits of precision in the Fourier domain,
+/// in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweBootstrapKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision in the Fourier domain,
+/// in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweBootstrapKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 32 bits of precision in
+/// the Fourier domain, in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweTernaryBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweTernaryBootstrapKey32 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 64 bits of precision in
+/// the Fourier domain, in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweTernaryBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweTernaryBootstrapKey64 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 32 bits of precision in
+/// the Fourier domain, in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweGaussianBootstrapKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweGaussianBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweGaussianBootstrapKey32 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 64 bits of precision in
+/// the Fourier domain, in the cleartext backend.
+///
+/// The cleartext backend does not perform any polynomial multiplication, hence the key carries the
+/// same parameters and noise variance as the standard key it is converted from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextFourierLweGaussianBootstrapKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) polynomial_size: PolynomialSize,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextFourierLweGaussianBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for CleartextFourierLweGaussianBootstrapKey64 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+}
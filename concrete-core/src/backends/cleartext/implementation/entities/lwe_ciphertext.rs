@@ -0,0 +1,207 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweCiphertextKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertext32 {
+    pub(crate) phase: u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertext64 {
+    pub(crate) phase: u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a ternary key, with 32 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryCiphertext32 {
+    pub(crate) phase: u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernaryCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweTernaryCiphertext32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a ternary key, with 64 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryCiphertext64 {
+    pub(crate) phase: u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernaryCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweTernaryCiphertext64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a gaussian key, with 32 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianCiphertext32 {
+    pub(crate) phase: u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweGaussianCiphertext32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a gaussian key, with 64 bits of
+/// precision, in the cleartext backend.
+///
+/// The ciphertext holds its phase, i.e. the encrypted plaintext plus the simulated noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianCiphertext64 {
+    pub(crate) phase: u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweGaussianCiphertext64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, viewing an immutable slice
+/// of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body of the slice, which holds its phase. The mask of the slice is
+/// ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextView32<'a> {
+    pub(crate) phase: &'a u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextView32<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertextView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, viewing a mutable slice
+/// of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body of the slice, which holds its phase. The mask of the slice is
+/// ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextLweCiphertextMutView32<'a> {
+    pub(crate) phase: &'a mut u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextMutView32<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertextMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, viewing an immutable slice
+/// of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body of the slice, which holds its phase. The mask of the slice is
+/// ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextView64<'a> {
+    pub(crate) phase: &'a u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextView64<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertextView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, viewing a mutable slice
+/// of scalars it does not own, in the cleartext backend.
+///
+/// The ciphertext views the body of the slice, which holds its phase. The mask of the slice is
+/// ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextLweCiphertextMutView64<'a> {
+    pub(crate) phase: &'a mut u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextMutView64<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for CleartextLweCiphertextMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
@@ -0,0 +1,249 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweCiphertextVectorKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a ternary key, with 32 bits
+/// of precision, in the cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernaryCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweTernaryCiphertextVector32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a ternary key, with 64 bits
+/// of precision, in the cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernaryCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernaryCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweTernaryCiphertextVector64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a gaussian key, with 32
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweGaussianCiphertextVector32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a gaussian key, with 64
+/// bits of precision, in the cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweGaussianCiphertextVector64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing an
+/// immutable slice of scalars it does not own, in the cleartext backend.
+///
+/// The vector views the bodies of the ciphertexts of the slice, which hold their phases. The masks
+/// of the slice are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextVectorView32<'a> {
+    pub(crate) phases: Vec<&'a u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVectorView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVectorView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing a
+/// mutable slice of scalars it does not own, in the cleartext backend.
+///
+/// The vector views the bodies of the ciphertexts of the slice, which hold their phases. The masks
+/// of the slice are ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextLweCiphertextVectorMutView32<'a> {
+    pub(crate) phases: Vec<&'a mut u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVectorMutView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVectorMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing an
+/// immutable slice of scalars it does not own, in the cleartext backend.
+///
+/// The vector views the bodies of the ciphertexts of the slice, which hold their phases. The masks
+/// of the slice are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCiphertextVectorView64<'a> {
+    pub(crate) phases: Vec<&'a u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVectorView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVectorView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing a
+/// mutable slice of scalars it does not own, in the cleartext backend.
+///
+/// The vector views the bodies of the ciphertexts of the slice, which hold their phases. The masks
+/// of the slice are ignored.
+#[derive(Debug, PartialEq)]
+pub struct CleartextLweCiphertextVectorMutView64<'a> {
+    pub(crate) phases: Vec<&'a mut u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweCiphertextVectorMutView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for CleartextLweCiphertextVectorMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
@@ -0,0 +1,28 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCompressedCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextEntity};
+use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+
+/// A structure representing a compressed LWE ciphertext with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The ciphertext holds its phase switched to the modulus of the compression, stored on 32 bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCompressedCiphertext64 {
+    pub(crate) compressed_phase: u32,
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) modulus_log: CiphertextModulusLog,
+}
+impl AbstractEntity for CleartextLweCompressedCiphertext64 {
+    type Kind = LweCompressedCiphertextKind;
+}
+impl LweCompressedCiphertextEntity for CleartextLweCompressedCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn modulus_log(&self) -> CiphertextModulusLog {
+        self.modulus_log
+    }
+}
@@ -0,0 +1,33 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCompressedCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextVectorEntity};
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of compressed LWE ciphertexts with 64 bits of precision, in
+/// the cleartext backend.
+///
+/// The vector holds the phases of the ciphertexts switched to the modulus of the compression,
+/// stored on 32 bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweCompressedCiphertextVector64 {
+    pub(crate) compressed_phases: Vec<u32>,
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) modulus_log: CiphertextModulusLog,
+}
+impl AbstractEntity for CleartextLweCompressedCiphertextVector64 {
+    type Kind = LweCompressedCiphertextVectorKind;
+}
+impl LweCompressedCiphertextVectorEntity for CleartextLweCompressedCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.compressed_phases.len())
+    }
+
+    fn modulus_log(&self) -> CiphertextModulusLog {
+        self.modulus_log
+    }
+}
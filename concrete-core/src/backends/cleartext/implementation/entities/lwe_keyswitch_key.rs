@@ -0,0 +1,228 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweKeyswitchKeyKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweKeyswitchKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweKeyswitchKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a ternary key, with 32 bits
+/// of precision, in the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBinaryToTernaryKeyswitchKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBinaryToTernaryKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweBinaryToTernaryKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = TernaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a ternary key, with 64 bits
+/// of precision, in the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBinaryToTernaryKeyswitchKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBinaryToTernaryKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweBinaryToTernaryKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = TernaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a gaussian key, with 32 bits
+/// of precision, in the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBinaryToGaussianKeyswitchKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBinaryToGaussianKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweBinaryToGaussianKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = GaussianKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a gaussian key, with 64 bits
+/// of precision, in the cleartext backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweBinaryToGaussianKeyswitchKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_lwe_dimension: LweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLweBinaryToGaussianKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for CleartextLweBinaryToGaussianKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = GaussianKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.output_lwe_dimension
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
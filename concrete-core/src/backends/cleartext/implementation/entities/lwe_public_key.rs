@@ -0,0 +1,56 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, LwePublicKeyKind};
+use crate::specification::entities::{AbstractEntity, LwePublicKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// A structure representing an LWE public key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise of its encryptions of zero,
+/// which are used to simulate the noise of the public key encryptions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLwePublicKey32 {
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) lwe_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLwePublicKey32 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for CleartextLwePublicKey32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        self.lwe_zero_encryption_count
+    }
+}
+
+/// A structure representing an LWE public key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise of its encryptions of zero,
+/// which are used to simulate the noise of the public key encryptions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLwePublicKey64 {
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) lwe_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextLwePublicKey64 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for CleartextLwePublicKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        self.lwe_zero_encryption_count
+    }
+}
@@ -0,0 +1,123 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweSecretKeyKind, TernaryKeyFlavor,
+};
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE secret key with 32 bits of precision, in the cleartext backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSecretKey32 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweSecretKey32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing an LWE secret key with 64 bits of precision, in the cleartext backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSecretKey64 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweSecretKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing a ternary LWE secret key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernarySecretKey32 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernarySecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweTernarySecretKey32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing a ternary LWE secret key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweTernarySecretKey64 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweTernarySecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweTernarySecretKey64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing a gaussian LWE secret key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianSecretKey32 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianSecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweGaussianSecretKey32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing a gaussian LWE secret key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// Since the ciphertexts of this backend hold their phase in clear, the key only carries its
+/// dimension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweGaussianSecretKey64 {
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweGaussianSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for CleartextLweGaussianSecretKey64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
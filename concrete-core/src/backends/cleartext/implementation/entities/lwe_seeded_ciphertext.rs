@@ -0,0 +1,45 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweSeededCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweSeededCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a seeded LWE ciphertext with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The cleartext backend does not generate any mask, hence the ciphertext holds its phase like
+/// the regular LWE ciphertexts do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSeededCiphertext32 {
+    pub(crate) phase: u32,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSeededCiphertext32 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for CleartextLweSeededCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
+
+/// A structure representing a seeded LWE ciphertext with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The cleartext backend does not generate any mask, hence the ciphertext holds its phase like
+/// the regular LWE ciphertexts do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSeededCiphertext64 {
+    pub(crate) phase: u64,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSeededCiphertext64 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for CleartextLweSeededCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+}
@@ -0,0 +1,53 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweSeededCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of seeded LWE ciphertexts with 32 bits of precision, in the
+/// cleartext backend.
+///
+/// The cleartext backend does not generate any mask, hence the vector holds the phases of the
+/// ciphertexts like the regular LWE ciphertext vectors do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSeededCiphertextVector32 {
+    pub(crate) phases: Vec<u32>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSeededCiphertextVector32 {
+    type Kind = LweSeededCiphertextVectorKind;
+}
+impl LweSeededCiphertextVectorEntity for CleartextLweSeededCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
+
+/// A structure representing a vector of seeded LWE ciphertexts with 64 bits of precision, in the
+/// cleartext backend.
+///
+/// The cleartext backend does not generate any mask, hence the vector holds the phases of the
+/// ciphertexts like the regular LWE ciphertext vectors do.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextLweSeededCiphertextVector64 {
+    pub(crate) phases: Vec<u64>,
+    pub(crate) lwe_dimension: LweDimension,
+}
+impl AbstractEntity for CleartextLweSeededCiphertextVector64 {
+    type Kind = LweSeededCiphertextVectorKind;
+}
+impl LweSeededCiphertextVectorEntity for CleartextLweSeededCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.phases.len())
+    }
+}
@@ -0,0 +1,46 @@
+//! A module containing all the [entities](crate::specification::entities) exposed by the
+//! cleartext backend.
+
+mod circuit_bootstrap_private_functional_packing_keyswitch_keys;
+mod ggsw_ciphertext;
+mod ggsw_ciphertext_vector;
+mod glwe_ciphertext;
+mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
+mod glwe_secret_key;
+mod glwe_seeded_ciphertext;
+mod glwe_seeded_ciphertext_vector;
+mod lwe_bootstrap_key;
+mod lwe_ciphertext;
+mod lwe_ciphertext_vector;
+mod lwe_compressed_ciphertext;
+mod lwe_compressed_ciphertext_vector;
+mod lwe_keyswitch_key;
+mod lwe_public_key;
+mod lwe_secret_key;
+mod lwe_seeded_ciphertext;
+mod lwe_seeded_ciphertext_vector;
+mod packing_keyswitch_key;
+mod private_functional_packing_keyswitch_key;
+
+pub use circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
+pub use ggsw_ciphertext::*;
+pub use ggsw_ciphertext_vector::*;
+pub use glwe_ciphertext::*;
+pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
+pub use glwe_secret_key::*;
+pub use glwe_seeded_ciphertext::*;
+pub use glwe_seeded_ciphertext_vector::*;
+pub use lwe_bootstrap_key::*;
+pub use lwe_ciphertext::*;
+pub use lwe_ciphertext_vector::*;
+pub use lwe_compressed_ciphertext::*;
+pub use lwe_compressed_ciphertext_vector::*;
+pub use lwe_keyswitch_key::*;
+pub use lwe_public_key::*;
+pub use lwe_secret_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_seeded_ciphertext_vector::*;
+pub use packing_keyswitch_key::*;
+pub use private_functional_packing_keyswitch_key::*;
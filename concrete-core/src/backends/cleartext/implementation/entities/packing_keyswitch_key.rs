@@ -0,0 +1,90 @@
+use crate::specification::entities::markers::{BinaryKeyFlavor, PackingKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, PackingKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing a packing keyswitch key with 32 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the packing keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextPackingKeyswitchKey32 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) output_polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextPackingKeyswitchKey32 {
+    type Kind = PackingKeyswitchKeyKind;
+}
+impl PackingKeyswitchKeyEntity for CleartextPackingKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a packing keyswitch key with 64 bits of precision, in the cleartext
+/// backend.
+///
+/// The key only carries its parameters, and the variance of the noise it would have been
+/// encrypted with, which are used to simulate the noise of the packing keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextPackingKeyswitchKey64 {
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) output_polynomial_size: PolynomialSize,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextPackingKeyswitchKey64 {
+    type Kind = PackingKeyswitchKeyKind;
+}
+impl PackingKeyswitchKeyEntity for CleartextPackingKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
@@ -0,0 +1,98 @@
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, PrivateFunctionalPackingKeyswitchKeyKind,
+};
+use crate::specification::entities::{AbstractEntity, PrivateFunctionalPackingKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing a private functional packing keyswitch key with 32 bits of
+/// precision, in the cleartext backend.
+///
+/// The key carries the polynomial of the linear map it evaluates along with its parameters, and
+/// the variance of the noise it would have been encrypted with, which is used to simulate the
+/// noise of the packing keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextPrivateFunctionalPackingKeyswitchKey32 {
+    pub(crate) polynomial: Vec<u32>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextPrivateFunctionalPackingKeyswitchKey32 {
+    type Kind = PrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl PrivateFunctionalPackingKeyswitchKeyEntity
+    for CleartextPrivateFunctionalPackingKeyswitchKey32
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.polynomial.len())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
+
+/// A structure representing a private functional packing keyswitch key with 64 bits of
+/// precision, in the cleartext backend.
+///
+/// The key carries the polynomial of the linear map it evaluates along with its parameters, and
+/// the variance of the noise it would have been encrypted with, which is used to simulate the
+/// noise of the packing keyswitches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleartextPrivateFunctionalPackingKeyswitchKey64 {
+    pub(crate) polynomial: Vec<u64>,
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub(crate) output_glwe_dimension: GlweDimension,
+    pub(crate) decomposition_level_count: DecompositionLevelCount,
+    pub(crate) decomposition_base_log: DecompositionBaseLog,
+    pub(crate) noise: Variance,
+}
+impl AbstractEntity for CleartextPrivateFunctionalPackingKeyswitchKey64 {
+    type Kind = PrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl PrivateFunctionalPackingKeyswitchKeyEntity
+    for CleartextPrivateFunctionalPackingKeyswitchKey64
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_dimension
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        PolynomialSize(self.polynomial.len())
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+}
@@ -0,0 +1,18 @@
+//! A module containing the cleartext backend implementation.
+//!
+//! This module contains a mock backend, whose ciphertexts hold their phase in clear, i.e. the
+//! plaintext they encrypt plus a simulated noise. Linear operations are performed directly on the
+//! phases, while keyswitches and bootstraps add a noise sampled with the variance predicted by the
+//! `concrete-npe` crate. The bootstraps evaluate the lookup table of the accumulator on the
+//! rounded phase of the input, which means that padding and overflow bugs still corrupt the
+//! results, as they would with the `core` backend.
+//!
+//! Since no actual cryptography is performed, this backend is orders of magnitude faster than the
+//! `core` one, and is meant to test the logic of applications written generically over the
+//! engines. It must never be used to protect actual data.
+//!
+//! The plaintexts and cleartexts used along with this backend are the ones of the `core` backend.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
@@ -0,0 +1,258 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, LweDimension,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList as ImplPrivateFunctionalPackingKeyswitchKeyList;
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError as CreationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+        GlweSecretKey32,
+        GlweSecretKey32,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(8);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The input key is the output key of the bootstrap key used in the circuit bootstrap
+    /// let input_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let cbs_pfpksk: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(cbs_pfpksk.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), LweDimension(8));
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_polynomial_size(), output_polynomial_size);
+    /// assert_eq!(cbs_pfpksk.key_count(), FunctionalPackingKeyswitchKeyCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        CreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(CreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(CreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(CreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+        let mut cbs_pfpksk = ImplPrivateFunctionalPackingKeyswitchKeyList::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            LweDimension(input_key.glwe_dimension().0 * input_key.polynomial_size().0),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+            FunctionalPackingKeyswitchKeyCount(output_key.glwe_dimension().to_glwe_size().0),
+        );
+        let input_lwe_key =
+            ImplLweSecretKey::binary_from_container(input_key.0.as_tensor().as_slice());
+        cbs_pfpksk.fill_with_circuit_bootstrap_keys(
+            &input_lwe_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(cbs_pfpksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+        GlweSecretKey64,
+        GlweSecretKey64,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_glwe_dimension = GlweDimension(1);
+    /// let input_polynomial_size = PolynomialSize(8);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let output_polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// // The input key is the output key of the bootstrap key used in the circuit bootstrap
+    /// let input_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(input_glwe_dimension, input_polynomial_size)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, output_polynomial_size)?;
+    ///
+    /// let cbs_pfpksk: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &input_key,
+    ///         &output_key,
+    ///         decomposition_level_count,
+    ///         decomposition_base_log,
+    ///         noise,
+    ///     )?;
+    /// #
+    /// assert_eq!(cbs_pfpksk.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(cbs_pfpksk.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(cbs_pfpksk.input_lwe_dimension(), LweDimension(8));
+    /// assert_eq!(cbs_pfpksk.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(cbs_pfpksk.output_polynomial_size(), output_polynomial_size);
+    /// assert_eq!(cbs_pfpksk.key_count(), FunctionalPackingKeyswitchKeyCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        CreationError<Self::EngineError>,
+    > {
+        if decomposition_base_log.0 == 0 {
+            return Err(CreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(CreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(CreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_key: &GlweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+        let mut cbs_pfpksk = ImplPrivateFunctionalPackingKeyswitchKeyList::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            LweDimension(input_key.glwe_dimension().0 * input_key.polynomial_size().0),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+            FunctionalPackingKeyswitchKeyCount(output_key.glwe_dimension().to_glwe_size().0),
+        );
+        let input_lwe_key =
+            ImplLweSecretKey::binary_from_container(input_key.0.as_tensor().as_slice());
+        cbs_pfpksk.fill_with_circuit_bootstrap_keys(
+            &input_lwe_key,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(cbs_pfpksk)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::backends::core::private::crypto::encoding::Cleartext as ImplCleartext;
+use crate::specification::engines::{
+    CleartextConversionEngine, CleartextConversionError, CleartextDiscardingConversionEngine,
+};
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// cleartext to a 64 bits cleartext. The values are cast to the output precision.
+impl CleartextConversionEngine<Cleartext32, Cleartext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&input)?;
+    ///
+    /// let output: Cleartext64 = engine.convert_cleartext(&cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext32,
+    ) -> Result<Cleartext64, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext32) -> Cleartext64 {
+        let mut output = Cleartext64(ImplCleartext(0_u64));
+        self.discard_convert_cleartext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// cleartext to a 32 bits cleartext. The values are cast to the output precision, discarding the
+/// most significant bits.
+impl CleartextConversionEngine<Cleartext64, Cleartext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    ///
+    /// let output: Cleartext32 = engine.convert_cleartext(&cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext(
+        &mut self,
+        input: &Cleartext64,
+    ) -> Result<Cleartext32, CleartextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_unchecked(&mut self, input: &Cleartext64) -> Cleartext32 {
+        let mut output = Cleartext32(ImplCleartext(0_u32));
+        self.discard_convert_cleartext_unchecked(&mut output, input);
+        output
+    }
+}
@@ -0,0 +1,90 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Cleartext32, Cleartext64};
+use crate::specification::engines::{
+    CleartextDiscardingConversionEngine, CleartextDiscardingConversionError,
+};
+
+/// # Description:
+/// Implementation of [`CleartextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits cleartext to a 64 bits cleartext. The values are cast to the output precision.
+impl CleartextDiscardingConversionEngine<Cleartext32, Cleartext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u32 = 3;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext32 = engine.create_cleartext(&input)?;
+    /// let mut output: Cleartext64 = engine.create_cleartext(&0_u64)?;
+    ///
+    /// engine.discard_convert_cleartext(&mut output, &cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext(
+        &mut self,
+        output: &mut Cleartext64,
+        input: &Cleartext32,
+    ) -> Result<(), CleartextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_unchecked(
+        &mut self,
+        output: &mut Cleartext64,
+        input: &Cleartext32,
+    ) {
+        output.0 .0 = input.0 .0 as u64;
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextDiscardingConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits cleartext to a 32 bits cleartext. The values are cast to the output precision, discarding
+/// the most significant bits.
+impl CleartextDiscardingConversionEngine<Cleartext64, Cleartext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    /// let mut output: Cleartext32 = engine.create_cleartext(&0_u32)?;
+    ///
+    /// engine.discard_convert_cleartext(&mut output, &cleartext)?;
+    ///
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext(
+        &mut self,
+        output: &mut Cleartext32,
+        input: &Cleartext64,
+    ) -> Result<(), CleartextDiscardingConversionError<Self::EngineError>> {
+        unsafe { self.discard_convert_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_unchecked(
+        &mut self,
+        output: &mut Cleartext32,
+        input: &Cleartext64,
+    ) {
+        output.0 .0 = input.0 .0 as u32;
+    }
+}
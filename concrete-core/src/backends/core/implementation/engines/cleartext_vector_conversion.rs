@@ -0,0 +1,97 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::crypto::encoding::CleartextList as ImplCleartextList;
+use crate::specification::engines::{
+    CleartextVectorConversionEngine, CleartextVectorConversionError,
+    CleartextVectorDiscardingConversionEngine,
+};
+use crate::specification::entities::CleartextVectorEntity;
+
+/// # Description:
+/// Implementation of [`CleartextVectorConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// cleartext vector to a 64 bits cleartext vector. The values are cast to the output precision.
+impl CleartextVectorConversionEngine<CleartextVector32, CleartextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&input)?;
+    ///
+    /// let output: CleartextVector64 = engine.convert_cleartext_vector(&cleartext_vector)?;
+    /// #
+    /// assert_eq!(output.cleartext_count(), CleartextCount(100));
+    ///
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext_vector(
+        &mut self,
+        input: &CleartextVector32,
+    ) -> Result<CleartextVector64, CleartextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_vector_unchecked(
+        &mut self,
+        input: &CleartextVector32,
+    ) -> CleartextVector64 {
+        let mut output =
+            CleartextVector64(ImplCleartextList::allocate(0_u64, input.cleartext_count()));
+        self.discard_convert_cleartext_vector_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// cleartext vector to a 32 bits cleartext vector. The values are cast to the output precision,
+/// discarding the most significant bits.
+impl CleartextVectorConversionEngine<CleartextVector64, CleartextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector(&input)?;
+    ///
+    /// let output: CleartextVector32 = engine.convert_cleartext_vector(&cleartext_vector)?;
+    /// #
+    /// assert_eq!(output.cleartext_count(), CleartextCount(100));
+    ///
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_cleartext_vector(
+        &mut self,
+        input: &CleartextVector64,
+    ) -> Result<CleartextVector32, CleartextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_cleartext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_cleartext_vector_unchecked(
+        &mut self,
+        input: &CleartextVector64,
+    ) -> CleartextVector32 {
+        let mut output =
+            CleartextVector32(ImplCleartextList::allocate(0_u32, input.cleartext_count()));
+        self.discard_convert_cleartext_vector_unchecked(&mut output, input);
+        output
+    }
+}
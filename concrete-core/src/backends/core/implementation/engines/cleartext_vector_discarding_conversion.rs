@@ -0,0 +1,115 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{CleartextVector32, CleartextVector64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    CleartextVectorDiscardingConversionEngine, CleartextVectorDiscardingConversionError,
+};
+use crate::specification::entities::CleartextVectorEntity;
+
+/// # Description:
+/// Implementation of [`CleartextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits cleartext vector to a 64 bits cleartext vector. The values are cast to the output
+/// precision.
+impl CleartextVectorDiscardingConversionEngine<CleartextVector32, CleartextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u32; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVector32 = engine.create_cleartext_vector(&input)?;
+    /// let mut output: CleartextVector64 = engine.create_cleartext_vector(&vec![0_u64; 100])?;
+    ///
+    /// engine.discard_convert_cleartext_vector(&mut output, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(output.cleartext_count(), CleartextCount(100));
+    ///
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext_vector(
+        &mut self,
+        output: &mut CleartextVector64,
+        input: &CleartextVector32,
+    ) -> Result<(), CleartextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.cleartext_count() != input.cleartext_count() {
+            return Err(CleartextVectorDiscardingConversionError::CleartextCountMismatch);
+        }
+        unsafe { self.discard_convert_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextVector64,
+        input: &CleartextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u64);
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits cleartext vector to a 32 bits cleartext vector. The values are cast to the output
+/// precision, discarding the most significant bits.
+impl CleartextVectorDiscardingConversionEngine<CleartextVector64, CleartextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::CleartextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input = vec![3_u64; 100];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let cleartext_vector: CleartextVector64 = engine.create_cleartext_vector(&input)?;
+    /// let mut output: CleartextVector32 = engine.create_cleartext_vector(&vec![0_u32; 100])?;
+    ///
+    /// engine.discard_convert_cleartext_vector(&mut output, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(output.cleartext_count(), CleartextCount(100));
+    ///
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_cleartext_vector(
+        &mut self,
+        output: &mut CleartextVector32,
+        input: &CleartextVector64,
+    ) -> Result<(), CleartextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.cleartext_count() != input.cleartext_count() {
+            return Err(CleartextVectorDiscardingConversionError::CleartextCountMismatch);
+        }
+        unsafe { self.discard_convert_cleartext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_cleartext_vector_unchecked(
+        &mut self,
+        output: &mut CleartextVector32,
+        input: &CleartextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u32);
+    }
+}
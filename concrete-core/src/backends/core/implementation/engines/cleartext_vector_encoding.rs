@@ -0,0 +1,163 @@
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    CleartextVectorF64, FloatEncoderVector, PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::{
+    Encoder, PlaintextList as ImplPlaintextList,
+};
+use crate::specification::engines::{CleartextVectorEncodingEngine, CleartextVectorEncodingError};
+use crate::specification::entities::{CleartextVectorEntity, EncoderVectorEntity};
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 64 bits
+/// floating point numbers to 32 bits integers.
+impl CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector32 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector32, CleartextVectorEncodingError<Self::EngineError>> {
+        if encoder_vector.encoder_count().0 != cleartext_vector.cleartext_count().0 {
+            return Err(CleartextVectorEncodingError::EncoderCountMismatch);
+        }
+        if encoder_vector.0.iter().any(|e| !e.fits_in::<u32>()) {
+            return Err(CleartextVectorEncodingError::Engine(
+                CoreError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(e, c)| !e.is_in_interval(c.0))
+        {
+            return Err(CleartextVectorEncodingError::Engine(
+                CoreError::FloatEncoderValueOutOfInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PlaintextVector32 {
+        PlaintextVector32(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(e, c)| e.encode(*c).0)
+                .collect::<Vec<u32>>(),
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`CleartextVectorEncodingEngine`] for [`CoreEngine`] that encodes 64 bits
+/// floating point numbers to 64 bits integers.
+impl CleartextVectorEncodingEngine<FloatEncoderVector, CleartextVectorF64, PlaintextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     100
+    /// ])?;
+    /// let cleartext_vector: CleartextVectorF64 = engine.create_cleartext_vector(&vec![5.; 100])?;
+    /// let plaintext_vector: PlaintextVector64 =
+    ///     engine.encode_cleartext_vector(&encoder_vector, &cleartext_vector)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(100));
+    /// engine.destroy(encoder_vector)?;
+    /// engine.destroy(cleartext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encode_cleartext_vector(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> Result<PlaintextVector64, CleartextVectorEncodingError<Self::EngineError>> {
+        if encoder_vector.encoder_count().0 != cleartext_vector.cleartext_count().0 {
+            return Err(CleartextVectorEncodingError::EncoderCountMismatch);
+        }
+        if encoder_vector.0.iter().any(|e| !e.fits_in::<u64>()) {
+            return Err(CleartextVectorEncodingError::Engine(
+                CoreError::FloatEncoderPrecisionOverflow,
+            ));
+        }
+        if encoder_vector
+            .0
+            .iter()
+            .zip(cleartext_vector.0.cleartext_iter())
+            .any(|(e, c)| !e.is_in_interval(c.0))
+        {
+            return Err(CleartextVectorEncodingError::Engine(
+                CoreError::FloatEncoderValueOutOfInterval,
+            ));
+        }
+        Ok(unsafe { self.encode_cleartext_vector_unchecked(encoder_vector, cleartext_vector) })
+    }
+
+    unsafe fn encode_cleartext_vector_unchecked(
+        &mut self,
+        encoder_vector: &FloatEncoderVector,
+        cleartext_vector: &CleartextVectorF64,
+    ) -> PlaintextVector64 {
+        PlaintextVector64(ImplPlaintextList::from_container(
+            encoder_vector
+                .0
+                .iter()
+                .zip(cleartext_vector.0.cleartext_iter())
+                .map(|(e, c)| e.encode(*c).0)
+                .collect::<Vec<u64>>(),
+        ))
+    }
+}
@@ -1,11 +1,37 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, CleartextVector32, CleartextVector64, FourierLweBootstrapKey32,
-    FourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64, GlweCiphertextVector32,
-    GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64, LweBootstrapKey32, LweBootstrapKey64,
-    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
-    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
-    PlaintextVector32, PlaintextVector64,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, Cleartext32, Cleartext64,
+    CleartextF64, CleartextVector32, CleartextVector64, CleartextVectorF64, FloatEncoder,
+    FloatEncoderVector, FourierGgswCiphertext32, FourierGgswCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, FourierLweGaussianBootstrapKey32, FourierLweGaussianBootstrapKey64,
+    FourierLweTernaryBootstrapKey32, FourierLweTernaryBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GgswCiphertextVector32, GgswCiphertextVector64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextMutView32, GlweCiphertextMutView64, GlweCiphertextVector32,
+    GlweCiphertextVector64, GlweCiphertextView32, GlweCiphertextView64, GlweGaussianCiphertext32,
+    GlweGaussianCiphertext64, GlweGaussianCiphertextVector32, GlweGaussianCiphertextVector64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweRelinearizationKey32,
+    GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64, GlweSeededCiphertext32,
+    GlweSeededCiphertext64, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    GlweTernaryCiphertext32, GlweTernaryCiphertext64, GlweTernaryCiphertextVector32,
+    GlweTernaryCiphertextVector64, GlweTernarySecretKey32, GlweTernarySecretKey64,
+    LweBinaryToGaussianKeyswitchKey32, LweBinaryToGaussianKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32, LweBinaryToTernaryKeyswitchKey64, LweBootstrapKey32,
+    LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64, LweCiphertextView32, LweCiphertextView64, LweCompressedCiphertext64,
+    LweCompressedCiphertextVector64, LweGaussianBootstrapKey32, LweGaussianBootstrapKey64,
+    LweGaussianCiphertext32, LweGaussianCiphertext64, LweGaussianCiphertextVector32,
+    LweGaussianCiphertextVector64, LweGaussianSecretKey32, LweGaussianSecretKey64,
+    LweKeyswitchKey32, LweKeyswitchKey64, LwePublicKey32, LwePublicKey64, LweSecretKey32,
+    LweSecretKey64, LweSeededCiphertext32, LweSeededCiphertext64, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64, LweTernaryBootstrapKey32, LweTernaryBootstrapKey64,
+    LweTernaryCiphertext32, LweTernaryCiphertext64, LweTernaryCiphertextVector32,
+    LweTernaryCiphertextVector64, LweTernarySecretKey32, LweTernarySecretKey64,
+    PackingKeyswitchKey32, PackingKeyswitchKey64, Plaintext32, Plaintext64, PlaintextVector32,
+    PlaintextVector64, PrivateFunctionalPackingKeyswitchKey32,
+    PrivateFunctionalPackingKeyswitchKey64,
 };
 use crate::backends::core::private::math::tensor::AsMutTensor;
 use crate::specification::engines::{DestructionEngine, DestructionError};
@@ -52,6 +78,48 @@ impl DestructionEngine<CleartextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: CleartextVector64) {}
 }
 
+impl DestructionEngine<CleartextF64> for CoreEngine {
+    fn destroy(&mut self, entity: CleartextF64) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextF64) {}
+}
+
+impl DestructionEngine<CleartextVectorF64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: CleartextVectorF64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: CleartextVectorF64) {}
+}
+
+impl DestructionEngine<FloatEncoder> for CoreEngine {
+    fn destroy(&mut self, entity: FloatEncoder) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoder) {}
+}
+
+impl DestructionEngine<FloatEncoderVector> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FloatEncoderVector,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FloatEncoderVector) {}
+}
+
 impl DestructionEngine<Plaintext32> for CoreEngine {
     fn destroy(&mut self, entity: Plaintext32) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
@@ -142,6 +210,150 @@ impl DestructionEngine<LweCiphertextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVector64) {}
 }
 
+impl DestructionEngine<LweSeededCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertext32) {}
+}
+
+impl DestructionEngine<LweSeededCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertext64) {}
+}
+
+impl DestructionEngine<LweSeededCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertextVector32) {}
+}
+
+impl DestructionEngine<LweSeededCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSeededCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweSeededCiphertextVector64) {}
+}
+
+impl DestructionEngine<LweCompressedCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCompressedCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCompressedCiphertext64) {}
+}
+
+impl DestructionEngine<LweCompressedCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCompressedCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCompressedCiphertextVector64) {}
+}
+
+impl DestructionEngine<FourierGgswCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierGgswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierGgswCiphertext32) {}
+}
+
+impl DestructionEngine<FourierGgswCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierGgswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierGgswCiphertext64) {}
+}
+
+impl DestructionEngine<GgswCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GgswCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertext32) {}
+}
+
+impl DestructionEngine<GgswCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GgswCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertext64) {}
+}
+
+impl DestructionEngine<GgswCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GgswCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertextVector32) {}
+}
+
+impl DestructionEngine<GgswCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GgswCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GgswCiphertextVector64) {}
+}
+
 impl DestructionEngine<GlweCiphertext32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -190,6 +402,54 @@ impl DestructionEngine<GlweCiphertextVector64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextVector64) {}
 }
 
+impl DestructionEngine<GlweSeededCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSeededCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweSeededCiphertext32) {}
+}
+
+impl DestructionEngine<GlweSeededCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSeededCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweSeededCiphertext64) {}
+}
+
+impl DestructionEngine<GlweSeededCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSeededCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweSeededCiphertextVector32) {}
+}
+
+impl DestructionEngine<GlweSeededCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSeededCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweSeededCiphertextVector64) {}
+}
+
 impl DestructionEngine<LweBootstrapKey32> for CoreEngine {
     fn destroy(
         &mut self,
@@ -262,62 +522,790 @@ impl DestructionEngine<LweKeyswitchKey64> for CoreEngine {
     unsafe fn destroy_unchecked(&mut self, _entity: LweKeyswitchKey64) {}
 }
 
-impl DestructionEngine<LweSecretKey32> for CoreEngine {
+impl DestructionEngine<LwePublicKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweSecretKey32,
+        entity: LwePublicKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey32) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+    unsafe fn destroy_unchecked(&mut self, _entity: LwePublicKey32) {}
+}
+
+impl DestructionEngine<LwePublicKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LwePublicKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
     }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LwePublicKey64) {}
 }
 
-impl DestructionEngine<LweSecretKey64> for CoreEngine {
+impl DestructionEngine<PackingKeyswitchKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: LweSecretKey64,
+        entity: PackingKeyswitchKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey64) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+    unsafe fn destroy_unchecked(&mut self, _entity: PackingKeyswitchKey32) {}
+}
+
+impl DestructionEngine<PackingKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PackingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
     }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: PackingKeyswitchKey64) {}
 }
 
-impl DestructionEngine<GlweSecretKey32> for CoreEngine {
+impl DestructionEngine<PrivateFunctionalPackingKeyswitchKey32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: GlweSecretKey32,
+        entity: PrivateFunctionalPackingKeyswitchKey32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey32) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u32);
+    unsafe fn destroy_unchecked(&mut self, _entity: PrivateFunctionalPackingKeyswitchKey32) {}
+}
+
+impl DestructionEngine<PrivateFunctionalPackingKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: PrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
     }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: PrivateFunctionalPackingKeyswitchKey64) {}
 }
 
-impl DestructionEngine<GlweSecretKey64> for CoreEngine {
+impl DestructionEngine<CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32> for CoreEngine {
     fn destroy(
         &mut self,
-        entity: GlweSecretKey64,
+        entity: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
     ) -> Result<(), DestructionError<Self::EngineError>> {
         unsafe { self.destroy_unchecked(entity) };
         Ok(())
     }
 
-    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey64) {
-        let mut entity = entity;
-        entity.0.as_mut_tensor().fill_with_element(0u64);
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+    }
+}
+
+impl DestructionEngine<CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(
+        &mut self,
+        _entity: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+    }
+}
+
+impl DestructionEngine<GlweRelinearizationKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweRelinearizationKey32) {}
+}
+
+impl DestructionEngine<GlweRelinearizationKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweRelinearizationKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
     }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweRelinearizationKey64) {}
+}
+
+impl DestructionEngine<LweSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<LweSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweSecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<GlweSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweSecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<LweTernarySecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernarySecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweTernarySecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<GlweTernarySecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernarySecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweTernarySecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<LweTernaryCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryCiphertext32) {}
+}
+
+impl DestructionEngine<LweTernaryCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryCiphertextVector32) {}
+}
+
+impl DestructionEngine<GlweTernaryCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernaryCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweTernaryCiphertext32) {}
+}
+
+impl DestructionEngine<GlweTernaryCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernaryCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweTernaryCiphertextVector32) {}
+}
+
+impl DestructionEngine<LweBinaryToTernaryKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweBinaryToTernaryKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweBinaryToTernaryKeyswitchKey32) {}
+}
+
+impl DestructionEngine<LweTernarySecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernarySecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweTernarySecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<GlweTernarySecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernarySecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweTernarySecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<LweTernaryCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryCiphertext64) {}
+}
+
+impl DestructionEngine<LweTernaryCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryCiphertextVector64) {}
+}
+
+impl DestructionEngine<GlweTernaryCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernaryCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweTernaryCiphertext64) {}
+}
+
+impl DestructionEngine<GlweTernaryCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweTernaryCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweTernaryCiphertextVector64) {}
+}
+
+impl DestructionEngine<LweBinaryToTernaryKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweBinaryToTernaryKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweBinaryToTernaryKeyswitchKey64) {}
+}
+
+impl DestructionEngine<LweGaussianSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweGaussianSecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<GlweGaussianSecretKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianSecretKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweGaussianSecretKey32) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u32);
+    }
+}
+
+impl DestructionEngine<LweGaussianCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianCiphertext32) {}
+}
+
+impl DestructionEngine<LweGaussianCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianCiphertextVector32) {}
+}
+
+impl DestructionEngine<GlweGaussianCiphertext32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianCiphertext32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweGaussianCiphertext32) {}
+}
+
+impl DestructionEngine<GlweGaussianCiphertextVector32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianCiphertextVector32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweGaussianCiphertextVector32) {}
+}
+
+impl DestructionEngine<LweBinaryToGaussianKeyswitchKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweBinaryToGaussianKeyswitchKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweBinaryToGaussianKeyswitchKey32) {}
+}
+
+impl DestructionEngine<LweGaussianSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: LweGaussianSecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<GlweGaussianSecretKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: GlweGaussianSecretKey64) {
+        let mut entity = entity;
+        entity.0.as_mut_tensor().fill_with_element(0u64);
+    }
+}
+
+impl DestructionEngine<LweGaussianCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianCiphertext64) {}
+}
+
+impl DestructionEngine<LweGaussianCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianCiphertextVector64) {}
+}
+
+impl DestructionEngine<GlweGaussianCiphertext64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweGaussianCiphertext64) {}
+}
+
+impl DestructionEngine<GlweGaussianCiphertextVector64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweGaussianCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweGaussianCiphertextVector64) {}
+}
+
+impl DestructionEngine<LweBinaryToGaussianKeyswitchKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweBinaryToGaussianKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweBinaryToGaussianKeyswitchKey64) {}
+}
+
+impl DestructionEngine<LweTernaryBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryBootstrapKey32) {}
+}
+
+impl DestructionEngine<LweTernaryBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweTernaryBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweTernaryBootstrapKey64) {}
+}
+
+impl DestructionEngine<LweGaussianBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianBootstrapKey32) {}
+}
+
+impl DestructionEngine<LweGaussianBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweGaussianBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweGaussianBootstrapKey64) {}
+}
+
+impl DestructionEngine<FourierLweTernaryBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierLweTernaryBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweTernaryBootstrapKey32) {}
+}
+
+impl DestructionEngine<FourierLweTernaryBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierLweTernaryBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweTernaryBootstrapKey64) {}
+}
+
+impl DestructionEngine<FourierLweGaussianBootstrapKey32> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierLweGaussianBootstrapKey32,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweGaussianBootstrapKey32) {}
+}
+
+impl DestructionEngine<FourierLweGaussianBootstrapKey64> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: FourierLweGaussianBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: FourierLweGaussianBootstrapKey64) {}
+}
+
+impl<'a> DestructionEngine<GlweCiphertextView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertextView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<GlweCiphertextView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertextView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<GlweCiphertextMutView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertextMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<GlweCiphertextMutView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: GlweCiphertextMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: GlweCiphertextMutView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextMutView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextMutView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextMutView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVectorView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVectorView64<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorMutView32<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorMutView32<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVectorMutView32<'a>) {}
+}
+
+impl<'a> DestructionEngine<LweCiphertextVectorMutView64<'a>> for CoreEngine {
+    fn destroy(
+        &mut self,
+        entity: LweCiphertextVectorMutView64<'a>,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, _entity: LweCiphertextVectorMutView64<'a>) {}
 }
@@ -0,0 +1,95 @@
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    FloatEncoder, FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig,
+};
+use crate::backends::core::private::crypto::encoding::FloatEncoder as ImplFloatEncoder;
+use crate::specification::engines::{EncoderCreationEngine, EncoderCreationError};
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`CoreEngine`] that creates an encoder to
+/// encode 64 bits floating point numbers.
+impl EncoderCreationEngine<FloatEncoderMinMaxConfig, FloatEncoder> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderMinMaxConfig {
+    ///     min: 0.,
+    ///     max: 10.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// engine.destroy(encoder)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder(
+        &mut self,
+        config: &FloatEncoderMinMaxConfig,
+    ) -> Result<FloatEncoder, EncoderCreationError<Self::EngineError>> {
+        if config.min >= config.max {
+            return Err(EncoderCreationError::Engine(
+                CoreError::FloatEncoderMinMaxOrder,
+            ));
+        }
+        if config.nb_bit_precision == 0 {
+            return Err(EncoderCreationError::Engine(
+                CoreError::FloatEncoderNullPrecision,
+            ));
+        }
+        Ok(unsafe { self.create_encoder_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_unchecked(
+        &mut self,
+        config: &FloatEncoderMinMaxConfig,
+    ) -> FloatEncoder {
+        FloatEncoder(ImplFloatEncoder::new(
+            config.min,
+            config.max,
+            config.nb_bit_precision,
+            config.nb_bit_padding,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderCreationEngine`] for [`CoreEngine`] that creates an encoder to
+/// encode 64 bits floating point numbers.
+impl EncoderCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoder> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder = engine.create_encoder(&FloatEncoderCenterRadiusConfig {
+    ///     center: 10.,
+    ///     radius: 5.,
+    ///     nb_bit_precision: 8,
+    ///     nb_bit_padding: 1,
+    /// })?;
+    /// engine.destroy(encoder)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder(
+        &mut self,
+        config: &FloatEncoderCenterRadiusConfig,
+    ) -> Result<FloatEncoder, EncoderCreationError<Self::EngineError>> {
+        self.create_encoder(&FloatEncoderMinMaxConfig::from(*config))
+    }
+
+    unsafe fn create_encoder_unchecked(
+        &mut self,
+        config: &FloatEncoderCenterRadiusConfig,
+    ) -> FloatEncoder {
+        self.create_encoder_unchecked(&FloatEncoderMinMaxConfig::from(*config))
+    }
+}
@@ -0,0 +1,124 @@
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    FloatEncoderCenterRadiusConfig, FloatEncoderMinMaxConfig, FloatEncoderVector,
+};
+use crate::backends::core::private::crypto::encoding::FloatEncoder as ImplFloatEncoder;
+use crate::specification::engines::{EncoderVectorCreationEngine, EncoderVectorCreationError};
+
+/// # Description:
+/// Implementation of [`EncoderVectorCreationEngine`] for [`CoreEngine`] that creates an encoder
+/// vector to encode vectors of 64 bits floating point numbers.
+impl EncoderVectorCreationEngine<FloatEncoderMinMaxConfig, FloatEncoderVector> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::EncoderCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderMinMaxConfig {
+    ///         min: 0.,
+    ///         max: 10.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     10
+    /// ])?;
+    /// #
+    /// assert_eq!(encoder_vector.encoder_count(), EncoderCount(10));
+    /// engine.destroy(encoder_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_vector(
+        &mut self,
+        config: &[FloatEncoderMinMaxConfig],
+    ) -> Result<FloatEncoderVector, EncoderVectorCreationError<Self::EngineError>> {
+        if config.is_empty() {
+            return Err(EncoderVectorCreationError::EmptyInput);
+        }
+        for conf in config.iter() {
+            if conf.min >= conf.max {
+                return Err(EncoderVectorCreationError::Engine(
+                    CoreError::FloatEncoderMinMaxOrder,
+                ));
+            }
+            if conf.nb_bit_precision == 0 {
+                return Err(EncoderVectorCreationError::Engine(
+                    CoreError::FloatEncoderNullPrecision,
+                ));
+            }
+        }
+        Ok(unsafe { self.create_encoder_vector_unchecked(config) })
+    }
+
+    unsafe fn create_encoder_vector_unchecked(
+        &mut self,
+        config: &[FloatEncoderMinMaxConfig],
+    ) -> FloatEncoderVector {
+        FloatEncoderVector(
+            config
+                .iter()
+                .map(|c| ImplFloatEncoder::new(c.min, c.max, c.nb_bit_precision, c.nb_bit_padding))
+                .collect(),
+        )
+    }
+}
+
+/// # Description:
+/// Implementation of [`EncoderVectorCreationEngine`] for [`CoreEngine`] that creates an encoder
+/// vector to encode vectors of 64 bits floating point numbers.
+impl EncoderVectorCreationEngine<FloatEncoderCenterRadiusConfig, FloatEncoderVector>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::EncoderCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = CoreEngine::new()?;
+    /// let encoder_vector = engine.create_encoder_vector(&vec![
+    ///     FloatEncoderCenterRadiusConfig {
+    ///         center: 10.,
+    ///         radius: 5.,
+    ///         nb_bit_precision: 8,
+    ///         nb_bit_padding: 1,
+    ///     };
+    ///     10
+    /// ])?;
+    /// #
+    /// assert_eq!(encoder_vector.encoder_count(), EncoderCount(10));
+    /// engine.destroy(encoder_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_encoder_vector(
+        &mut self,
+        config: &[FloatEncoderCenterRadiusConfig],
+    ) -> Result<FloatEncoderVector, EncoderVectorCreationError<Self::EngineError>> {
+        let config: Vec<FloatEncoderMinMaxConfig> = config
+            .iter()
+            .copied()
+            .map(FloatEncoderMinMaxConfig::from)
+            .collect();
+        self.create_encoder_vector(config.as_slice())
+    }
+
+    unsafe fn create_encoder_vector_unchecked(
+        &mut self,
+        config: &[FloatEncoderCenterRadiusConfig],
+    ) -> FloatEncoderVector {
+        let config: Vec<FloatEncoderMinMaxConfig> = config
+            .iter()
+            .copied()
+            .map(FloatEncoderMinMaxConfig::from)
+            .collect();
+        self.create_encoder_vector_unchecked(config.as_slice())
+    }
+}
@@ -0,0 +1,206 @@
+use super::entity_serialization::deserialize_entity;
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, Cleartext32, Cleartext64,
+    CleartextF64, CleartextVector32, CleartextVector64, CleartextVectorF64, FloatEncoder,
+    FloatEncoderVector, FourierGgswCiphertext32, FourierGgswCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, FourierLweGaussianBootstrapKey32, FourierLweGaussianBootstrapKey64,
+    FourierLweTernaryBootstrapKey32, FourierLweTernaryBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GgswCiphertextVector32, GgswCiphertextVector64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64, GlweGaussianCiphertext32,
+    GlweGaussianCiphertext64, GlweGaussianCiphertextVector32, GlweGaussianCiphertextVector64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweRelinearizationKey32,
+    GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64, GlweSeededCiphertext32,
+    GlweSeededCiphertext64, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    GlweTernaryCiphertext32, GlweTernaryCiphertext64, GlweTernaryCiphertextVector32,
+    GlweTernaryCiphertextVector64, GlweTernarySecretKey32, GlweTernarySecretKey64, GswCiphertext32,
+    GswCiphertext64, LweBinaryToGaussianKeyswitchKey32, LweBinaryToGaussianKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32, LweBinaryToTernaryKeyswitchKey64, LweBootstrapKey32,
+    LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCompressedCiphertext64, LweCompressedCiphertextVector64,
+    LweGaussianBootstrapKey32, LweGaussianBootstrapKey64, LweGaussianCiphertext32,
+    LweGaussianCiphertext64, LweGaussianCiphertextVector32, LweGaussianCiphertextVector64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweSeededCiphertextVector32, LweSeededCiphertextVector64,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey64, LweTernaryCiphertext32,
+    LweTernaryCiphertext64, LweTernaryCiphertextVector32, LweTernaryCiphertextVector64,
+    LweTernarySecretKey32, LweTernarySecretKey64, PackingKeyswitchKey32, PackingKeyswitchKey64,
+    Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+    PrivateFunctionalPackingKeyswitchKey32, PrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::specification::engines::{EntityDeserializationEngine, EntityDeserializationError};
+
+macro_rules! implement_entity_deserialization {
+    ($($(#[$meta: meta])* $entity: ident),* $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntityDeserializationEngine`] for [`CoreEngine`] that \
+                deserializes [`",
+                stringify!($entity),
+                "`] entities from byte buffers."
+            )]
+            ///
+            /// The buffer must have been produced by the
+            /// [`EntitySerializationEngine`](crate::specification::engines::EntitySerializationEngine)
+            /// implementation of the same entity type. Buffers written with another version of
+            /// the format, or containing another kind of entity, are rejected.
+            $(#[$meta])*
+            impl EntityDeserializationEngine<&[u8], $entity> for CoreEngine {
+                fn deserialize(
+                    &mut self,
+                    serialized: &[u8],
+                ) -> Result<$entity, EntityDeserializationError<Self::EngineError>> {
+                    deserialize_entity(serialized)
+                }
+
+                /// # Safety:
+                /// The `serialized` buffer is still parsed in full, and this method panics if
+                /// it is rejected for any of the reasons listed in
+                /// [`EntityDeserializationError`]. Untrusted buffers should be deserialized with
+                /// the checked method instead.
+                unsafe fn deserialize_unchecked(&mut self, serialized: &[u8]) -> $entity {
+                    deserialize_entity(serialized)
+                        .expect("Failed to deserialize an entity from a byte buffer.")
+                }
+            }
+        )*
+    };
+}
+implement_entity_deserialization!(
+    Plaintext32,
+    Plaintext64,
+    PlaintextVector32,
+    PlaintextVector64,
+    Cleartext32,
+    Cleartext64,
+    CleartextF64,
+    CleartextVector32,
+    CleartextVector64,
+    CleartextVectorF64,
+    FloatEncoder,
+    FloatEncoderVector,
+    LweSecretKey32,
+    LweSecretKey64,
+    LweTernarySecretKey32,
+    LweTernarySecretKey64,
+    LweGaussianSecretKey32,
+    LweGaussianSecretKey64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    GlweTernarySecretKey32,
+    GlweTernarySecretKey64,
+    GlweGaussianSecretKey32,
+    GlweGaussianSecretKey64,
+    LweCiphertext32,
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let serialized: Vec<u8> = engine.serialize(&ciphertext)?;
+    ///
+    /// let deserialized: LweCiphertext64 = engine.deserialize(serialized.as_slice())?;
+    /// #
+    /// assert_eq!(deserialized, ciphertext);
+    ///
+    /// // Deserializing the buffer as another kind of entity fails.
+    /// let result: Result<GlweCiphertext64, _> = engine.deserialize(serialized.as_slice());
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(EntityDeserializationError::KindMismatch)
+    /// ));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(deserialized)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    LweCiphertext64,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    LweTernaryCiphertext32,
+    LweTernaryCiphertext64,
+    LweTernaryCiphertextVector32,
+    LweTernaryCiphertextVector64,
+    LweGaussianCiphertext32,
+    LweGaussianCiphertext64,
+    LweGaussianCiphertextVector32,
+    LweGaussianCiphertextVector64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GlweTernaryCiphertext32,
+    GlweTernaryCiphertext64,
+    GlweTernaryCiphertextVector32,
+    GlweTernaryCiphertextVector64,
+    GlweGaussianCiphertext32,
+    GlweGaussianCiphertext64,
+    GlweGaussianCiphertextVector32,
+    GlweGaussianCiphertextVector64,
+    GlweSeededCiphertext32,
+    GlweSeededCiphertext64,
+    GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64,
+    GswCiphertext32,
+    GswCiphertext64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    GgswCiphertextVector32,
+    GgswCiphertextVector64,
+    FourierGgswCiphertext32,
+    FourierGgswCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32,
+    LweBinaryToTernaryKeyswitchKey64,
+    LweBinaryToGaussianKeyswitchKey32,
+    LweBinaryToGaussianKeyswitchKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
+    LweGaussianBootstrapKey32,
+    LweGaussianBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    FourierLweTernaryBootstrapKey32,
+    FourierLweTernaryBootstrapKey64,
+    FourierLweGaussianBootstrapKey32,
+    FourierLweGaussianBootstrapKey64,
+    LwePublicKey32,
+    LwePublicKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    PrivateFunctionalPackingKeyswitchKey32,
+    PrivateFunctionalPackingKeyswitchKey64,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    GlweRelinearizationKey32,
+    GlweRelinearizationKey64,
+    LweCompressedCiphertext64,
+    LweCompressedCiphertextVector64,
+);
@@ -0,0 +1,1698 @@
+use std::io::{Read, Write};
+
+use concrete_commons::parameters::{
+    CiphertextModulusLog, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    LweDimension, LweSize, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
+use crate::backends::core::implementation::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, Cleartext32, Cleartext64,
+    CleartextF64, CleartextVector32, CleartextVector64, CleartextVectorF64, FloatEncoder,
+    FloatEncoderVector, FourierGgswCiphertext32, FourierGgswCiphertext64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, FourierLweGaussianBootstrapKey32, FourierLweGaussianBootstrapKey64,
+    FourierLweTernaryBootstrapKey32, FourierLweTernaryBootstrapKey64, GgswCiphertext32,
+    GgswCiphertext64, GgswCiphertextVector32, GgswCiphertextVector64, GlweCiphertext32,
+    GlweCiphertext64, GlweCiphertextVector32, GlweCiphertextVector64, GlweGaussianCiphertext32,
+    GlweGaussianCiphertext64, GlweGaussianCiphertextVector32, GlweGaussianCiphertextVector64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweRelinearizationKey32,
+    GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64, GlweSeededCiphertext32,
+    GlweSeededCiphertext64, GlweSeededCiphertextVector32, GlweSeededCiphertextVector64,
+    GlweTernaryCiphertext32, GlweTernaryCiphertext64, GlweTernaryCiphertextVector32,
+    GlweTernaryCiphertextVector64, GlweTernarySecretKey32, GlweTernarySecretKey64, GswCiphertext32,
+    GswCiphertext64, LweBinaryToGaussianKeyswitchKey32, LweBinaryToGaussianKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32, LweBinaryToTernaryKeyswitchKey64, LweBootstrapKey32,
+    LweBootstrapKey64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCompressedCiphertext64, LweCompressedCiphertextVector64,
+    LweGaussianBootstrapKey32, LweGaussianBootstrapKey64, LweGaussianCiphertext32,
+    LweGaussianCiphertext64, LweGaussianCiphertextVector32, LweGaussianCiphertextVector64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64, LweSeededCiphertext32,
+    LweSeededCiphertext64, LweSeededCiphertextVector32, LweSeededCiphertextVector64,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey64, LweTernaryCiphertext32,
+    LweTernaryCiphertext64, LweTernaryCiphertextVector32, LweTernaryCiphertextVector64,
+    LweTernarySecretKey32, LweTernarySecretKey64, PackingKeyswitchKey32, PackingKeyswitchKey64,
+    Plaintext32, Plaintext64, PlaintextVector32, PlaintextVector64,
+    PrivateFunctionalPackingKeyswitchKey32, PrivateFunctionalPackingKeyswitchKey64,
+    GAUSSIAN_KEY_EXPANSION_WIDTH,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+};
+use crate::backends::core::private::crypto::encoding::{
+    Cleartext as ImplCleartext, CleartextList as ImplCleartextList,
+    FloatEncoder as ImplFloatEncoder, Plaintext as ImplPlaintext,
+    PlaintextList as ImplPlaintextList,
+};
+use crate::backends::core::private::crypto::ggsw::{
+    FourierGgswCiphertext as ImplFourierGgswCiphertext, GgswCiphertext as ImplGgswCiphertext,
+    GgswList as ImplGgswList,
+};
+use crate::backends::core::private::crypto::glwe::{
+    GlweCiphertext as ImplGlweCiphertext, GlweList as ImplGlweList,
+    GlweRelinearizationKey as ImplGlweRelinearizationKey,
+    GlweSeededCiphertext as ImplGlweSeededCiphertext, GlweSeededList as ImplGlweSeededList,
+    PackingKeyswitchKey as ImplPackingKeyswitchKey,
+    PrivateFunctionalPackingKeyswitchKey as ImplPrivateFunctionalPackingKeyswitchKey,
+    PrivateFunctionalPackingKeyswitchKeyList as ImplPrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::backends::core::private::crypto::gsw::GswCiphertext as ImplGswCiphertext;
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext as ImplLweCiphertext, LweCompressedCiphertext as ImplLweCompressedCiphertext,
+    LweCompressedList as ImplLweCompressedList, LweKeyswitchKey as ImplLweKeyswitchKey,
+    LweList as ImplLweList, LwePublicKey as ImplLwePublicKey,
+    LweSeededCiphertext as ImplLweSeededCiphertext, LweSeededList as ImplLweSeededList,
+};
+use crate::backends::core::private::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    EntityDeserializationError, EntitySerializationEngine, EntitySerializationError,
+};
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, CleartextVectorEntity,
+    EncoderVectorEntity, GgswCiphertextEntity, GgswCiphertextVectorEntity, GlweCiphertextEntity,
+    GlweCiphertextVectorEntity, GlweRelinearizationKeyEntity, GlweSecretKeyEntity,
+    GlweSeededCiphertextEntity, GlweSeededCiphertextVectorEntity, GswCiphertextEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+    LweCompressedCiphertextEntity, LweCompressedCiphertextVectorEntity, LweKeyswitchKeyEntity,
+    LwePublicKeyEntity, LweSecretKeyEntity, LweSeededCiphertextEntity,
+    LweSeededCiphertextVectorEntity, PackingKeyswitchKeyEntity, PlaintextVectorEntity,
+    PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+
+/// The version of the format used to serialize the entities of the core backend.
+///
+/// This version must be incremented every time the layout of the header or the layout of one of
+/// the payloads is modified.
+pub(crate) const FORMAT_VERSION: u16 = 1;
+
+// The enums below are serialized as the index of their variants: existing variants must never be
+// reordered nor removed, and new variants must be appended at the end.
+
+/// The kind of a serialized entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum SerializedEntityKind {
+    Plaintext,
+    PlaintextVector,
+    Cleartext,
+    CleartextVector,
+    Encoder,
+    EncoderVector,
+    LweSecretKey,
+    GlweSecretKey,
+    LweCiphertext,
+    LweCiphertextVector,
+    GlweCiphertext,
+    GlweCiphertextVector,
+    LweSeededCiphertext,
+    LweSeededCiphertextVector,
+    GlweSeededCiphertext,
+    GlweSeededCiphertextVector,
+    GswCiphertext,
+    GgswCiphertext,
+    GgswCiphertextVector,
+    FourierGgswCiphertext,
+    LweKeyswitchKey,
+    LweBootstrapKey,
+    FourierLweBootstrapKey,
+    LwePublicKey,
+    PackingKeyswitchKey,
+    PrivateFunctionalPackingKeyswitchKey,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    GlweRelinearizationKey,
+    LweCompressedCiphertext,
+    LweCompressedCiphertextVector,
+}
+
+/// The key flavor of a serialized entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum SerializedKeyFlavor {
+    Binary,
+    Ternary,
+    Gaussian,
+}
+
+/// The scalar type of a serialized entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum SerializedPrecision {
+    U32,
+    U64,
+    F64,
+}
+
+/// The parameters of a serialized entity.
+///
+/// Only the parameters which make sense for the kind of the entity are set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub(crate) struct EntityParameters {
+    pub lwe_dimension: Option<LweDimension>,
+    pub output_lwe_dimension: Option<LweDimension>,
+    pub glwe_dimension: Option<GlweDimension>,
+    pub polynomial_size: Option<PolynomialSize>,
+    pub decomposition_base_log: Option<DecompositionBaseLog>,
+    pub decomposition_level_count: Option<DecompositionLevelCount>,
+    pub count: Option<usize>,
+    pub seed: Option<u128>,
+}
+
+/// The header written before the payload of a serialized entity.
+///
+/// The header itself is preceded by the format version, encoded as a little endian `u16`, which
+/// allows to reject serialized entities written with an unsupported layout before reading the
+/// rest of the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct EntityHeader {
+    pub kind: SerializedEntityKind,
+    /// The flavor of the key of the entity, or of the input key for evaluation keys.
+    pub key_flavor: Option<SerializedKeyFlavor>,
+    /// The flavor of the output key, for evaluation keys only.
+    pub output_key_flavor: Option<SerializedKeyFlavor>,
+    pub precision: SerializedPrecision,
+    pub parameters: EntityParameters,
+}
+
+/// A trait implemented by the entities of the core backend which can be serialized.
+pub(crate) trait SerializableEntity: Sized {
+    const KIND: SerializedEntityKind;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor>;
+    const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+    const PRECISION: SerializedPrecision;
+
+    /// Returns the parameters written in the header.
+    fn parameters(&self) -> EntityParameters;
+
+    /// Writes the payload of the entity.
+    fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()>;
+
+    /// Reads the payload of an entity, returning `None` if it is not consistent with the
+    /// parameters.
+    fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self>;
+}
+
+/// Serializes an entity, header included.
+pub(crate) fn serialize_entity<Entity: SerializableEntity>(entity: &Entity) -> Vec<u8> {
+    let header = EntityHeader {
+        kind: Entity::KIND,
+        key_flavor: Entity::KEY_FLAVOR,
+        output_key_flavor: Entity::OUTPUT_KEY_FLAVOR,
+        precision: Entity::PRECISION,
+        parameters: entity.parameters(),
+    };
+    let mut output = Vec::new();
+    bincode::serialize_into(&mut output, &FORMAT_VERSION)
+        .and_then(|_| bincode::serialize_into(&mut output, &header))
+        .and_then(|_| entity.write_payload(&mut output))
+        .expect("Failed to serialize an entity into a byte buffer.");
+    output
+}
+
+/// Deserializes an entity, after checking its header.
+pub(crate) fn deserialize_entity<Entity: SerializableEntity>(
+    mut serialized: &[u8],
+) -> Result<Entity, EntityDeserializationError<CoreError>> {
+    let version: u16 = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::MalformedHeader)?;
+    if version != FORMAT_VERSION {
+        return Err(EntityDeserializationError::UnsupportedVersion);
+    }
+    let header: EntityHeader = bincode::deserialize_from(&mut serialized)
+        .map_err(|_| EntityDeserializationError::MalformedHeader)?;
+    if header.kind != Entity::KIND {
+        return Err(EntityDeserializationError::KindMismatch);
+    }
+    if header.key_flavor != Entity::KEY_FLAVOR
+        || header.output_key_flavor != Entity::OUTPUT_KEY_FLAVOR
+    {
+        return Err(EntityDeserializationError::KeyFlavorMismatch);
+    }
+    if header.precision != Entity::PRECISION {
+        return Err(EntityDeserializationError::PrecisionMismatch);
+    }
+    let entity = Entity::read_payload(&header.parameters, &mut serialized)
+        .ok_or(EntityDeserializationError::MalformedPayload)?;
+    if !serialized.is_empty() {
+        return Err(EntityDeserializationError::MalformedPayload);
+    }
+    Ok(entity)
+}
+
+// Returns the product of the factors, or `None` if it overflows.
+pub(crate) fn checked_product(factors: &[usize]) -> Option<usize> {
+    factors
+        .iter()
+        .try_fold(1usize, |acc, factor| acc.checked_mul(*factor))
+}
+
+// The payloads are written as bincode sequences, e.g. a `u64` length followed by the elements.
+pub(crate) fn write_slice<Scalar: Serialize, W: Write>(
+    writer: W,
+    slice: &[Scalar],
+) -> bincode::Result<()> {
+    bincode::serialize_into(writer, slice)
+}
+
+pub(crate) fn read_vec<Scalar: DeserializeOwned, R: Read>(
+    reader: R,
+    len: usize,
+) -> Option<Vec<Scalar>> {
+    let vec: Vec<Scalar> = bincode::deserialize_from(reader).ok()?;
+    if vec.len() == len {
+        Some(vec)
+    } else {
+        None
+    }
+}
+
+fn read_aligned_vec<R: Read>(reader: R, len: usize) -> Option<AlignedVec<Complex64>> {
+    let vec: Vec<Complex64> = read_vec(reader, len)?;
+    let mut aligned = AlignedVec::new(len);
+    aligned.as_slice_mut().copy_from_slice(&vec);
+    Some(aligned)
+}
+
+macro_rules! implement_plaintext {
+    ($entity: ident, $vector: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $entity {
+            const KIND: SerializedEntityKind = SerializedEntityKind::Plaintext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters::default()
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &[(self.0).0])
+            }
+
+            fn read_payload<R: Read>(_parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, 1)?;
+                Some($entity(ImplPlaintext(vec[0])))
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::PlaintextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    count: Some(self.plaintext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, parameters.count?)?;
+                Some($vector(ImplPlaintextList::from_container(vec)))
+            }
+        }
+    };
+}
+implement_plaintext!(Plaintext32, PlaintextVector32, u32, U32);
+implement_plaintext!(Plaintext64, PlaintextVector64, u64, U64);
+
+macro_rules! implement_cleartext {
+    ($entity: ident, $vector: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $entity {
+            const KIND: SerializedEntityKind = SerializedEntityKind::Cleartext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters::default()
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &[(self.0).0])
+            }
+
+            fn read_payload<R: Read>(_parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, 1)?;
+                Some($entity(ImplCleartext(vec[0])))
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::CleartextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    count: Some(self.cleartext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, parameters.count?)?;
+                Some($vector(ImplCleartextList::from_container(vec)))
+            }
+        }
+    };
+}
+implement_cleartext!(Cleartext32, CleartextVector32, u32, U32);
+implement_cleartext!(Cleartext64, CleartextVector64, u64, U64);
+implement_cleartext!(CleartextF64, CleartextVectorF64, f64, F64);
+
+impl SerializableEntity for FloatEncoder {
+    const KIND: SerializedEntityKind = SerializedEntityKind::Encoder;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+    const PRECISION: SerializedPrecision = SerializedPrecision::F64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters::default()
+    }
+
+    fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+        write_slice(writer, &[self.0])
+    }
+
+    fn read_payload<R: Read>(_parameters: &EntityParameters, reader: R) -> Option<Self> {
+        let vec: Vec<ImplFloatEncoder> = read_vec(reader, 1)?;
+        Some(FloatEncoder(vec[0]))
+    }
+}
+
+impl SerializableEntity for FloatEncoderVector {
+    const KIND: SerializedEntityKind = SerializedEntityKind::EncoderVector;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+    const PRECISION: SerializedPrecision = SerializedPrecision::F64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters {
+            count: Some(self.encoder_count().0),
+            ..Default::default()
+        }
+    }
+
+    fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+        write_slice(writer, self.0.as_slice())
+    }
+
+    fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+        Some(FloatEncoderVector(read_vec(reader, parameters.count?)?))
+    }
+}
+
+macro_rules! implement_secret_keys {
+    (
+        $lwe: ident,
+        $glwe: ident,
+        $flavor: ident,
+        $from_container: ident,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $lwe {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweSecretKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, parameters.lwe_dimension?.0)?;
+                Some($lwe(ImplLweSecretKey::$from_container(vec)))
+            }
+        }
+
+        impl SerializableEntity for $glwe {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweSecretKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let len = checked_product(&[parameters.glwe_dimension?.0, poly_size.0])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($glwe(ImplGlweSecretKey::$from_container(vec, poly_size)))
+            }
+        }
+    };
+}
+implement_secret_keys!(
+    LweSecretKey32,
+    GlweSecretKey32,
+    Binary,
+    binary_from_container,
+    u32,
+    U32
+);
+implement_secret_keys!(
+    LweSecretKey64,
+    GlweSecretKey64,
+    Binary,
+    binary_from_container,
+    u64,
+    U64
+);
+implement_secret_keys!(
+    LweTernarySecretKey32,
+    GlweTernarySecretKey32,
+    Ternary,
+    ternary_from_container,
+    u32,
+    U32
+);
+implement_secret_keys!(
+    LweTernarySecretKey64,
+    GlweTernarySecretKey64,
+    Ternary,
+    ternary_from_container,
+    u64,
+    U64
+);
+implement_secret_keys!(
+    LweGaussianSecretKey32,
+    GlweGaussianSecretKey32,
+    Gaussian,
+    gaussian_from_container,
+    u32,
+    U32
+);
+implement_secret_keys!(
+    LweGaussianSecretKey64,
+    GlweGaussianSecretKey64,
+    Gaussian,
+    gaussian_from_container,
+    u64,
+    U64
+);
+
+macro_rules! implement_lwe_ciphertexts {
+    (
+        $ciphertext: ident,
+        $vector: ident,
+        $flavor: ident,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $ciphertext {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let lwe_size = parameters.lwe_dimension?.0.checked_add(1)?;
+                let vec: Vec<$scalar> = read_vec(reader, lwe_size)?;
+                Some($ciphertext(ImplLweCiphertext::from_container(vec)))
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    count: Some(self.lwe_ciphertext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let lwe_size = parameters.lwe_dimension?.0.checked_add(1)?;
+                let len = checked_product(&[lwe_size, parameters.count?])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($vector(ImplLweList::from_container(vec, LweSize(lwe_size))))
+            }
+        }
+    };
+}
+implement_lwe_ciphertexts!(LweCiphertext32, LweCiphertextVector32, Binary, u32, U32);
+implement_lwe_ciphertexts!(
+    LweTernaryCiphertext32,
+    LweTernaryCiphertextVector32,
+    Ternary,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(
+    LweGaussianCiphertext32,
+    LweGaussianCiphertextVector32,
+    Gaussian,
+    u32,
+    U32
+);
+implement_lwe_ciphertexts!(LweCiphertext64, LweCiphertextVector64, Binary, u64, U64);
+implement_lwe_ciphertexts!(
+    LweTernaryCiphertext64,
+    LweTernaryCiphertextVector64,
+    Ternary,
+    u64,
+    U64
+);
+implement_lwe_ciphertexts!(
+    LweGaussianCiphertext64,
+    LweGaussianCiphertextVector64,
+    Gaussian,
+    u64,
+    U64
+);
+
+macro_rules! implement_lwe_seeded_ciphertexts {
+    ($seeded: ident, $seeded_vector: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $seeded {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweSeededCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    seed: Some(self.0.seed()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, &[self.0.get_body().0])
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, 1)?;
+                Some($seeded(ImplLweSeededCiphertext::allocate(
+                    vec[0],
+                    parameters.lwe_dimension?,
+                    parameters.seed?,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $seeded_vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweSeededCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    count: Some(self.lwe_ciphertext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            // The seeds of the ciphertexts are written after their bodies.
+            fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+                write_slice(&mut writer, self.0.as_tensor().as_slice())?;
+                write_slice(writer, self.0.seeds())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+                let count = parameters.count?;
+                let vec: Vec<$scalar> = read_vec(&mut reader, count)?;
+                let seeds: Vec<u128> = read_vec(reader, count)?;
+                Some($seeded_vector(ImplLweSeededList::from_container(
+                    vec,
+                    parameters.lwe_dimension?,
+                    seeds,
+                )))
+            }
+        }
+    };
+}
+implement_lwe_seeded_ciphertexts!(LweSeededCiphertext32, LweSeededCiphertextVector32, u32, U32);
+implement_lwe_seeded_ciphertexts!(LweSeededCiphertext64, LweSeededCiphertextVector64, u64, U64);
+
+// The modulus log of the compressed ciphertexts is written at the beginning of their payload.
+pub(crate) fn read_modulus_log<R: Read>(reader: R) -> Option<CiphertextModulusLog> {
+    let modulus_log: CiphertextModulusLog = bincode::deserialize_from(reader).ok()?;
+    if modulus_log.0 != 0 && modulus_log.0 <= 32 {
+        Some(modulus_log)
+    } else {
+        None
+    }
+}
+
+impl SerializableEntity for LweCompressedCiphertext64 {
+    const KIND: SerializedEntityKind = SerializedEntityKind::LweCompressedCiphertext;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+    const PRECISION: SerializedPrecision = SerializedPrecision::U64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters {
+            lwe_dimension: Some(self.lwe_dimension()),
+            ..Default::default()
+        }
+    }
+
+    fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(&mut writer, &self.modulus_log())?;
+        write_slice(writer, self.0.as_tensor().as_slice())
+    }
+
+    fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+        let lwe_size = parameters.lwe_dimension?.0.checked_add(1)?;
+        let modulus_log = read_modulus_log(&mut reader)?;
+        let vec: Vec<u32> = read_vec(reader, lwe_size)?;
+        Some(LweCompressedCiphertext64(
+            ImplLweCompressedCiphertext::from_container(vec, modulus_log),
+        ))
+    }
+}
+
+impl SerializableEntity for LweCompressedCiphertextVector64 {
+    const KIND: SerializedEntityKind = SerializedEntityKind::LweCompressedCiphertextVector;
+    const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+    const PRECISION: SerializedPrecision = SerializedPrecision::U64;
+
+    fn parameters(&self) -> EntityParameters {
+        EntityParameters {
+            lwe_dimension: Some(self.lwe_dimension()),
+            count: Some(self.lwe_ciphertext_count().0),
+            ..Default::default()
+        }
+    }
+
+    fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+        bincode::serialize_into(&mut writer, &self.modulus_log())?;
+        write_slice(writer, self.0.as_tensor().as_slice())
+    }
+
+    fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+        let lwe_size = parameters.lwe_dimension?.0.checked_add(1)?;
+        let len = checked_product(&[lwe_size, parameters.count?])?;
+        let modulus_log = read_modulus_log(&mut reader)?;
+        let vec: Vec<u32> = read_vec(reader, len)?;
+        Some(LweCompressedCiphertextVector64(
+            ImplLweCompressedList::from_container(vec, LweSize(lwe_size), modulus_log),
+        ))
+    }
+}
+
+macro_rules! implement_glwe_ciphertexts {
+    (
+        $ciphertext: ident,
+        $vector: ident,
+        $flavor: ident,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $ciphertext {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.0.checked_add(1)?;
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let len = checked_product(&[glwe_size, poly_size.0])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($ciphertext(ImplGlweCiphertext::from_container(
+                    vec, poly_size,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::$flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    count: Some(self.glwe_ciphertext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_dimension = parameters.glwe_dimension?;
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let len = checked_product(&[
+                    glwe_dimension.0.checked_add(1)?,
+                    poly_size.0,
+                    parameters.count?,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($vector(ImplGlweList::from_container(
+                    vec,
+                    glwe_dimension,
+                    poly_size,
+                )))
+            }
+        }
+    };
+}
+implement_glwe_ciphertexts!(GlweCiphertext32, GlweCiphertextVector32, Binary, u32, U32);
+implement_glwe_ciphertexts!(
+    GlweTernaryCiphertext32,
+    GlweTernaryCiphertextVector32,
+    Ternary,
+    u32,
+    U32
+);
+implement_glwe_ciphertexts!(
+    GlweGaussianCiphertext32,
+    GlweGaussianCiphertextVector32,
+    Gaussian,
+    u32,
+    U32
+);
+implement_glwe_ciphertexts!(GlweCiphertext64, GlweCiphertextVector64, Binary, u64, U64);
+implement_glwe_ciphertexts!(
+    GlweTernaryCiphertext64,
+    GlweTernaryCiphertextVector64,
+    Ternary,
+    u64,
+    U64
+);
+implement_glwe_ciphertexts!(
+    GlweGaussianCiphertext64,
+    GlweGaussianCiphertextVector64,
+    Gaussian,
+    u64,
+    U64
+);
+
+macro_rules! implement_glwe_seeded_ciphertexts {
+    ($seeded: ident, $seeded_vector: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $seeded {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweSeededCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    seed: Some(self.0.seed()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let vec: Vec<$scalar> = read_vec(reader, parameters.polynomial_size?.0)?;
+                Some($seeded(ImplGlweSeededCiphertext::from_container(
+                    vec,
+                    parameters.glwe_dimension?,
+                    parameters.seed?,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $seeded_vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweSeededCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    count: Some(self.glwe_ciphertext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            // The seeds of the ciphertexts are written after their bodies.
+            fn write_payload<W: Write>(&self, mut writer: W) -> bincode::Result<()> {
+                write_slice(&mut writer, self.0.as_tensor().as_slice())?;
+                write_slice(writer, self.0.seeds())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, mut reader: R) -> Option<Self> {
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let count = parameters.count?;
+                let len = checked_product(&[poly_size.0, count])?;
+                let vec: Vec<$scalar> = read_vec(&mut reader, len)?;
+                let seeds: Vec<u128> = read_vec(reader, count)?;
+                Some($seeded_vector(ImplGlweSeededList::from_container(
+                    vec,
+                    poly_size,
+                    parameters.glwe_dimension?,
+                    seeds,
+                )))
+            }
+        }
+    };
+}
+implement_glwe_seeded_ciphertexts!(
+    GlweSeededCiphertext32,
+    GlweSeededCiphertextVector32,
+    u32,
+    U32
+);
+implement_glwe_seeded_ciphertexts!(
+    GlweSeededCiphertext64,
+    GlweSeededCiphertextVector64,
+    u64,
+    U64
+);
+
+macro_rules! implement_gsw_ciphertexts {
+    (
+        $gsw: ident,
+        $ggsw: ident,
+        $ggsw_vector: ident,
+        $fourier_ggsw: ident,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $gsw {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GswCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let lwe_size = parameters.lwe_dimension?.0.checked_add(1)?;
+                let len = checked_product(&[
+                    lwe_size,
+                    lwe_size,
+                    parameters.decomposition_level_count?.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($gsw(ImplGswCiphertext::from_container(
+                    vec,
+                    LweSize(lwe_size),
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $ggsw {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GgswCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.to_glwe_size();
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let len = checked_product(&[
+                    glwe_size.0,
+                    glwe_size.0,
+                    poly_size.0,
+                    parameters.decomposition_level_count?.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($ggsw(ImplGgswCiphertext::from_container(
+                    vec,
+                    glwe_size,
+                    poly_size,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $ggsw_vector {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GgswCiphertextVector;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    count: Some(self.ggsw_ciphertext_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.to_glwe_size();
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    glwe_size.0,
+                    glwe_size.0,
+                    poly_size.0,
+                    level_count.0,
+                    parameters.count?,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($ggsw_vector(ImplGgswList::from_container(
+                    vec,
+                    poly_size,
+                    glwe_size,
+                    level_count,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+
+        impl SerializableEntity for $fourier_ggsw {
+            const KIND: SerializedEntityKind = SerializedEntityKind::FourierGgswCiphertext;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.to_glwe_size();
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let len = checked_product(&[
+                    glwe_size.0,
+                    glwe_size.0,
+                    poly_size.0,
+                    parameters.decomposition_level_count?.0,
+                ])?;
+                let vec = read_aligned_vec(reader, len)?;
+                Some($fourier_ggsw(ImplFourierGgswCiphertext::from_container(
+                    vec,
+                    glwe_size,
+                    poly_size,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+    };
+}
+implement_gsw_ciphertexts!(
+    GswCiphertext32,
+    GgswCiphertext32,
+    GgswCiphertextVector32,
+    FourierGgswCiphertext32,
+    u32,
+    U32
+);
+implement_gsw_ciphertexts!(
+    GswCiphertext64,
+    GgswCiphertext64,
+    GgswCiphertextVector64,
+    FourierGgswCiphertext64,
+    u64,
+    U64
+);
+
+macro_rules! implement_keyswitch_key {
+    ($ksk: ident, $output_flavor: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $ksk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweKeyswitchKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::$output_flavor);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension()),
+                    output_lwe_dimension: Some(self.output_lwe_dimension()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let output_lwe_dimension = parameters.output_lwe_dimension?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    parameters.lwe_dimension?.0,
+                    level_count.0,
+                    output_lwe_dimension.0.checked_add(1)?,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($ksk(ImplLweKeyswitchKey::from_container(
+                    vec,
+                    parameters.decomposition_base_log?,
+                    level_count,
+                    output_lwe_dimension,
+                )))
+            }
+        }
+    };
+}
+implement_keyswitch_key!(LweKeyswitchKey32, Binary, u32, U32);
+implement_keyswitch_key!(LweBinaryToTernaryKeyswitchKey32, Ternary, u32, U32);
+implement_keyswitch_key!(LweBinaryToGaussianKeyswitchKey32, Gaussian, u32, U32);
+implement_keyswitch_key!(LweKeyswitchKey64, Binary, u64, U64);
+implement_keyswitch_key!(LweBinaryToTernaryKeyswitchKey64, Ternary, u64, U64);
+implement_keyswitch_key!(LweBinaryToGaussianKeyswitchKey64, Gaussian, u64, U64);
+
+// The bootstrap keys created from ternary and gaussian keys hold several GGSW ciphertexts per
+// coefficient of their input key, see `LweTernaryBootstrapKey32` and `LweGaussianBootstrapKey32`.
+macro_rules! implement_bootstrap_key {
+    (
+        $bsk: ident,
+        $input_flavor: ident,
+        $ggsw_per_coefficient: expr,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $bsk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LweBootstrapKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::$input_flavor);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension()),
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.to_glwe_size();
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    parameters.lwe_dimension?.0,
+                    $ggsw_per_coefficient,
+                    level_count.0,
+                    glwe_size.0,
+                    glwe_size.0,
+                    poly_size.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($bsk(ImplStandardBootstrapKey::from_container(
+                    vec,
+                    glwe_size,
+                    poly_size,
+                    level_count,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+    };
+}
+implement_bootstrap_key!(LweBootstrapKey32, Binary, 1, u32, U32);
+implement_bootstrap_key!(LweTernaryBootstrapKey32, Ternary, 2, u32, U32);
+implement_bootstrap_key!(
+    LweGaussianBootstrapKey32,
+    Gaussian,
+    2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+    u32,
+    U32
+);
+implement_bootstrap_key!(LweBootstrapKey64, Binary, 1, u64, U64);
+implement_bootstrap_key!(LweTernaryBootstrapKey64, Ternary, 2, u64, U64);
+implement_bootstrap_key!(
+    LweGaussianBootstrapKey64,
+    Gaussian,
+    2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+    u64,
+    U64
+);
+
+macro_rules! implement_fourier_bootstrap_key {
+    ($fourier_bsk: ident, $input_flavor: ident, $ggsw_per_coefficient: expr, $precision: ident) => {
+        impl SerializableEntity for $fourier_bsk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::FourierLweBootstrapKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::$input_flavor);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension()),
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_size = parameters.glwe_dimension?.to_glwe_size();
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    parameters.lwe_dimension?.0,
+                    $ggsw_per_coefficient,
+                    level_count.0,
+                    glwe_size.0,
+                    glwe_size.0,
+                    poly_size.0,
+                ])?;
+                let vec = read_aligned_vec(reader, len)?;
+                Some($fourier_bsk(ImplFourierBootstrapKey::from_container(
+                    vec,
+                    glwe_size,
+                    poly_size,
+                    level_count,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+    };
+}
+implement_fourier_bootstrap_key!(FourierLweBootstrapKey32, Binary, 1, U32);
+implement_fourier_bootstrap_key!(FourierLweTernaryBootstrapKey32, Ternary, 2, U32);
+implement_fourier_bootstrap_key!(
+    FourierLweGaussianBootstrapKey32,
+    Gaussian,
+    2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+    U32
+);
+implement_fourier_bootstrap_key!(FourierLweBootstrapKey64, Binary, 1, U64);
+implement_fourier_bootstrap_key!(FourierLweTernaryBootstrapKey64, Ternary, 2, U64);
+implement_fourier_bootstrap_key!(
+    FourierLweGaussianBootstrapKey64,
+    Gaussian,
+    2 * GAUSSIAN_KEY_EXPANSION_WIDTH,
+    U64
+);
+
+macro_rules! implement_public_key {
+    ($pk: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $pk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::LwePublicKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.lwe_dimension()),
+                    count: Some(self.lwe_zero_encryption_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let lwe_size = parameters.lwe_dimension?.to_lwe_size();
+                let len = checked_product(&[lwe_size.0, parameters.count?])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($pk(ImplLwePublicKey::from_container(vec, lwe_size)))
+            }
+        }
+    };
+}
+implement_public_key!(LwePublicKey32, u32, U32);
+implement_public_key!(LwePublicKey64, u64, U64);
+
+// The private functional packing keyswitch keys hold one more block of GLWE ciphertexts than
+// the packing keyswitch keys, associated with the body of the input ciphertexts.
+macro_rules! implement_packing_keyswitch_key {
+    (
+        $pksk: ident,
+        $impl_pksk: ident,
+        $kind: ident,
+        $extra_blocks: literal,
+        $scalar: ty,
+        $precision: ident
+    ) => {
+        impl SerializableEntity for $pksk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::$kind;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension()),
+                    glwe_dimension: Some(self.output_glwe_dimension()),
+                    polynomial_size: Some(self.output_polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_dimension = parameters.glwe_dimension?;
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    parameters.lwe_dimension?.0.checked_add($extra_blocks)?,
+                    level_count.0,
+                    glwe_dimension.to_glwe_size().0,
+                    poly_size.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($pksk($impl_pksk::from_container(
+                    vec,
+                    parameters.decomposition_base_log?,
+                    level_count,
+                    glwe_dimension,
+                    poly_size,
+                )))
+            }
+        }
+    };
+}
+implement_packing_keyswitch_key!(
+    PackingKeyswitchKey32,
+    ImplPackingKeyswitchKey,
+    PackingKeyswitchKey,
+    0,
+    u32,
+    U32
+);
+implement_packing_keyswitch_key!(
+    PackingKeyswitchKey64,
+    ImplPackingKeyswitchKey,
+    PackingKeyswitchKey,
+    0,
+    u64,
+    U64
+);
+implement_packing_keyswitch_key!(
+    PrivateFunctionalPackingKeyswitchKey32,
+    ImplPrivateFunctionalPackingKeyswitchKey,
+    PrivateFunctionalPackingKeyswitchKey,
+    1,
+    u32,
+    U32
+);
+implement_packing_keyswitch_key!(
+    PrivateFunctionalPackingKeyswitchKey64,
+    ImplPrivateFunctionalPackingKeyswitchKey,
+    PrivateFunctionalPackingKeyswitchKey,
+    1,
+    u64,
+    U64
+);
+
+macro_rules! implement_circuit_bootstrap_keys {
+    ($keys: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $keys {
+            const KIND: SerializedEntityKind =
+                SerializedEntityKind::CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> =
+                Some(SerializedKeyFlavor::Binary);
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    lwe_dimension: Some(self.input_lwe_dimension()),
+                    glwe_dimension: Some(self.output_glwe_dimension()),
+                    polynomial_size: Some(self.output_polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    count: Some(self.key_count().0),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let lwe_dimension = parameters.lwe_dimension?;
+                let glwe_dimension = parameters.glwe_dimension?;
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                let len = checked_product(&[
+                    parameters.count?,
+                    lwe_dimension.to_lwe_size().0,
+                    level_count.0,
+                    glwe_dimension.to_glwe_size().0,
+                    poly_size.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($keys(
+                    ImplPrivateFunctionalPackingKeyswitchKeyList::from_container(
+                        vec,
+                        parameters.decomposition_base_log?,
+                        level_count,
+                        lwe_dimension,
+                        glwe_dimension,
+                        poly_size,
+                    ),
+                ))
+            }
+        }
+    };
+}
+implement_circuit_bootstrap_keys!(
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    u32,
+    U32
+);
+implement_circuit_bootstrap_keys!(
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    u64,
+    U64
+);
+
+macro_rules! implement_glwe_relinearization_key {
+    ($rlk: ident, $scalar: ty, $precision: ident) => {
+        impl SerializableEntity for $rlk {
+            const KIND: SerializedEntityKind = SerializedEntityKind::GlweRelinearizationKey;
+            const KEY_FLAVOR: Option<SerializedKeyFlavor> = Some(SerializedKeyFlavor::Binary);
+            const OUTPUT_KEY_FLAVOR: Option<SerializedKeyFlavor> = None;
+            const PRECISION: SerializedPrecision = SerializedPrecision::$precision;
+
+            fn parameters(&self) -> EntityParameters {
+                EntityParameters {
+                    glwe_dimension: Some(self.glwe_dimension()),
+                    polynomial_size: Some(self.polynomial_size()),
+                    decomposition_base_log: Some(self.decomposition_base_log()),
+                    decomposition_level_count: Some(self.decomposition_level_count()),
+                    ..Default::default()
+                }
+            }
+
+            fn write_payload<W: Write>(&self, writer: W) -> bincode::Result<()> {
+                write_slice(writer, self.0.as_tensor().as_slice())
+            }
+
+            fn read_payload<R: Read>(parameters: &EntityParameters, reader: R) -> Option<Self> {
+                let glwe_dimension = parameters.glwe_dimension?;
+                let poly_size = parameters.polynomial_size.filter(|size| size.0 != 0)?;
+                let level_count = parameters
+                    .decomposition_level_count
+                    .filter(|count| count.0 != 0)?;
+                // The key holds one block of GLWE ciphertexts per pair of key polynomials.
+                let block_count = glwe_dimension
+                    .0
+                    .checked_mul(glwe_dimension.0.checked_add(1)?)?
+                    / 2;
+                let len = checked_product(&[
+                    block_count,
+                    level_count.0,
+                    glwe_dimension.to_glwe_size().0,
+                    poly_size.0,
+                ])?;
+                let vec: Vec<$scalar> = read_vec(reader, len)?;
+                Some($rlk(ImplGlweRelinearizationKey::from_container(
+                    vec,
+                    glwe_dimension,
+                    poly_size,
+                    level_count,
+                    parameters.decomposition_base_log?,
+                )))
+            }
+        }
+    };
+}
+implement_glwe_relinearization_key!(GlweRelinearizationKey32, u32, U32);
+implement_glwe_relinearization_key!(GlweRelinearizationKey64, u64, U64);
+
+macro_rules! implement_entity_serialization {
+    ($($(#[$meta: meta])* $entity: ident),* $(,)?) => {
+        $(
+            /// # Description:
+            #[doc = concat!(
+                "Implementation of [`EntitySerializationEngine`] for [`CoreEngine`] that \
+                serializes [`",
+                stringify!($entity),
+                "`] entities into byte buffers."
+            )]
+            ///
+            /// The buffer contains the version of the format, followed by a header describing the
+            /// kind, the key flavor, the scalar type and the parameters of the entity, and by the
+            /// raw data of the entity.
+            $(#[$meta])*
+            impl EntitySerializationEngine<$entity, Vec<u8>> for CoreEngine {
+                fn serialize(
+                    &mut self,
+                    entity: &$entity,
+                ) -> Result<Vec<u8>, EntitySerializationError<Self::EngineError>> {
+                    Ok(unsafe { self.serialize_unchecked(entity) })
+                }
+
+                unsafe fn serialize_unchecked(&mut self, entity: &$entity) -> Vec<u8> {
+                    serialize_entity(entity)
+                }
+            }
+        )*
+    };
+}
+implement_entity_serialization!(
+    Plaintext32,
+    Plaintext64,
+    PlaintextVector32,
+    PlaintextVector64,
+    Cleartext32,
+    Cleartext64,
+    CleartextF64,
+    CleartextVector32,
+    CleartextVector64,
+    CleartextVectorF64,
+    FloatEncoder,
+    FloatEncoderVector,
+    LweSecretKey32,
+    LweSecretKey64,
+    LweTernarySecretKey32,
+    LweTernarySecretKey64,
+    LweGaussianSecretKey32,
+    LweGaussianSecretKey64,
+    GlweSecretKey32,
+    GlweSecretKey64,
+    GlweTernarySecretKey32,
+    GlweTernarySecretKey64,
+    GlweGaussianSecretKey32,
+    GlweGaussianSecretKey64,
+    LweCiphertext32,
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let serialized: Vec<u8> = engine.serialize(&ciphertext)?;
+    /// let deserialized: LweCiphertext64 = engine.deserialize(serialized.as_slice())?;
+    /// #
+    /// assert_eq!(deserialized, ciphertext);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(deserialized)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    LweCiphertext64,
+    LweCiphertextVector32,
+    LweCiphertextVector64,
+    LweTernaryCiphertext32,
+    LweTernaryCiphertext64,
+    LweTernaryCiphertextVector32,
+    LweTernaryCiphertextVector64,
+    LweGaussianCiphertext32,
+    LweGaussianCiphertext64,
+    LweGaussianCiphertextVector32,
+    LweGaussianCiphertextVector64,
+    LweSeededCiphertext32,
+    LweSeededCiphertext64,
+    LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+    GlweCiphertext32,
+    GlweCiphertext64,
+    GlweCiphertextVector32,
+    GlweCiphertextVector64,
+    GlweTernaryCiphertext32,
+    GlweTernaryCiphertext64,
+    GlweTernaryCiphertextVector32,
+    GlweTernaryCiphertextVector64,
+    GlweGaussianCiphertext32,
+    GlweGaussianCiphertext64,
+    GlweGaussianCiphertextVector32,
+    GlweGaussianCiphertextVector64,
+    GlweSeededCiphertext32,
+    GlweSeededCiphertext64,
+    GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64,
+    GswCiphertext32,
+    GswCiphertext64,
+    GgswCiphertext32,
+    GgswCiphertext64,
+    GgswCiphertextVector32,
+    GgswCiphertextVector64,
+    FourierGgswCiphertext32,
+    FourierGgswCiphertext64,
+    LweKeyswitchKey32,
+    LweKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32,
+    LweBinaryToTernaryKeyswitchKey64,
+    LweBinaryToGaussianKeyswitchKey32,
+    LweBinaryToGaussianKeyswitchKey64,
+    LweBootstrapKey32,
+    LweBootstrapKey64,
+    LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
+    LweGaussianBootstrapKey32,
+    LweGaussianBootstrapKey64,
+    FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64,
+    FourierLweTernaryBootstrapKey32,
+    FourierLweTernaryBootstrapKey64,
+    FourierLweGaussianBootstrapKey32,
+    FourierLweGaussianBootstrapKey64,
+    LwePublicKey32,
+    LwePublicKey64,
+    PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+    PrivateFunctionalPackingKeyswitchKey32,
+    PrivateFunctionalPackingKeyswitchKey64,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    GlweRelinearizationKey32,
+    GlweRelinearizationKey64,
+    LweCompressedCiphertext64,
+    LweCompressedCiphertextVector64,
+);
@@ -0,0 +1,146 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, GgswCiphertext32, GgswCiphertext64,
+};
+use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext as ImplFourierGgswCiphertext;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextConversionError,
+};
+use crate::specification::entities::GgswCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers. It converts a GGSW ciphertext from the standard to the Fourier domain.
+impl GgswCiphertextConversionEngine<GgswCiphertext32, FourierGgswCiphertext32> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let fourier_ciphertext: FourierGgswCiphertext32 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> Result<FourierGgswCiphertext32, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext32,
+    ) -> FourierGgswCiphertext32 {
+        let mut output = ImplFourierGgswCiphertext::allocate(
+            Complex64::new(0., 0.),
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierGgswCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextConversionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers. It converts a GGSW ciphertext from the standard to the Fourier domain.
+impl GgswCiphertextConversionEngine<GgswCiphertext64, FourierGgswCiphertext64> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    ///
+    /// let fourier_ciphertext: FourierGgswCiphertext64 =
+    ///     engine.convert_ggsw_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(fourier_ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(fourier_ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(fourier_ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(fourier_ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(fourier_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_ggsw_ciphertext(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> Result<FourierGgswCiphertext64, GgswCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_ggsw_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_ggsw_ciphertext_unchecked(
+        &mut self,
+        input: &GgswCiphertext64,
+    ) -> FourierGgswCiphertext64 {
+        let mut output = ImplFourierGgswCiphertext::allocate(
+            Complex64::new(0., 0.),
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierGgswCiphertext64(output)
+    }
+}
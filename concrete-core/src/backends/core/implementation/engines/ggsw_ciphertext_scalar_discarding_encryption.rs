@@ -0,0 +1,188 @@
+use concrete_commons::dispersion::Variance;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, GlweSecretKey32, GlweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::specification::engines::{
+    GgswCiphertextScalarDiscardingEncryptionEngine, GgswCiphertextScalarDiscardingEncryptionError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarDiscardingEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// When the `multithread` feature is activated, the levels of the ciphertext are encrypted in
+/// parallel.
+impl GgswCiphertextScalarDiscardingEncryptionEngine<GlweSecretKey32, Plaintext32, GgswCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GgswCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextScalarDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_encrypt_scalar_ggsw_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GgswCiphertext32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) {
+        #[cfg(feature = "multithread")]
+        key.0.par_encrypt_constant_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        #[cfg(not(feature = "multithread"))]
+        key.0.encrypt_constant_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarDiscardingEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// When the `multithread` feature is activated, the levels of the ciphertext are encrypted in
+/// parallel.
+impl GgswCiphertextScalarDiscardingEncryptionEngine<GlweSecretKey64, Plaintext64, GgswCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let mut ciphertext =
+    ///     engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// engine.discard_encrypt_scalar_ggsw_ciphertext(&key, &mut ciphertext, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GgswCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextScalarDiscardingEncryptionError<Self::EngineError>> {
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GgswCiphertextScalarDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_encrypt_scalar_ggsw_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GgswCiphertext64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) {
+        #[cfg(feature = "multithread")]
+        key.0.par_encrypt_constant_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        #[cfg(not(feature = "multithread"))]
+        key.0.encrypt_constant_ggsw(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
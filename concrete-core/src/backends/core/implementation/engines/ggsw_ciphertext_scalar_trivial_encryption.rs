@@ -0,0 +1,196 @@
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertext32, GgswCiphertext64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::crypto::ggsw::GgswCiphertext as ImplGgswCiphertext;
+use crate::specification::engines::{
+    GgswCiphertextScalarTrivialEncryptionEngine, GgswCiphertextScalarTrivialEncryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarTrivialEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext32, GgswCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u32;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: GgswCiphertext32 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_size,
+    ///     level,
+    ///     base_log,
+    ///     &plaintext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext32,
+    ) -> Result<GgswCiphertext32, GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext32,
+    ) -> GgswCiphertext32 {
+        let mut ciphertext = ImplGgswCiphertext::allocate(
+            0u32,
+            polynomial_size,
+            glwe_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ciphertext.fill_with_trivial_encryption(&input.0);
+        GgswCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextScalarTrivialEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl GgswCiphertextScalarTrivialEncryptionEngine<Plaintext64, GgswCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = 3_u64;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: GgswCiphertext64 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     polynomial_size,
+    ///     glwe_size,
+    ///     level,
+    ///     base_log,
+    ///     &plaintext,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext.decomposition_base_log(), base_log);
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn trivially_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext64,
+    ) -> Result<GgswCiphertext64, GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GgswCiphertextScalarTrivialEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+                polynomial_size,
+                glwe_size,
+                decomposition_level_count,
+                decomposition_base_log,
+                input,
+            )
+        })
+    }
+
+    unsafe fn trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext64,
+    ) -> GgswCiphertext64 {
+        let mut ciphertext = ImplGgswCiphertext::allocate(
+            0u64,
+            polynomial_size,
+            glwe_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        ciphertext.fill_with_trivial_encryption(&input.0);
+        GgswCiphertext64(ciphertext)
+    }
+}
@@ -0,0 +1,267 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount,
+};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GgswCiphertextVector32, GgswCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::ggsw::GgswList as ImplGgswList;
+use crate::specification::engines::{
+    GgswCiphertextVectorScalarEncryptionEngine, GgswCiphertextVectorScalarEncryptionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// When the `multithread` feature is activated, the levels of each ciphertext are encrypted in
+/// parallel.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        GgswCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = vec![3_u32; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), GgswCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector32, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector32 {
+        let mut ciphertexts = ImplGgswList::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            GgswCiphertextCount(input.0.count().0),
+        );
+        for (mut ciphertext, plaintext) in ciphertexts
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+        {
+            #[cfg(feature = "multithread")]
+            key.0.par_encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+            #[cfg(not(feature = "multithread"))]
+            key.0.encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+        }
+        GgswCiphertextVector32(ciphertexts)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GgswCiphertextVectorScalarEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// When the `multithread` feature is activated, the levels of each ciphertext are encrypted in
+/// parallel.
+impl
+    GgswCiphertextVectorScalarEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        GgswCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let input = vec![3_u64; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector = engine.encrypt_scalar_ggsw_ciphertext_vector(
+    ///     &key,
+    ///     &plaintext_vector,
+    ///     noise,
+    ///     level,
+    ///     base_log,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.decomposition_level_count(), level);
+    /// assert_eq!(ciphertext_vector.decomposition_base_log(), base_log);
+    /// assert_eq!(ciphertext_vector.ggsw_ciphertext_count(), GgswCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<GgswCiphertextVector64, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GgswCiphertextVectorScalarEncryptionError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+                key,
+                input,
+                noise,
+                decomposition_level_count,
+                decomposition_base_log,
+            )
+        })
+    }
+
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> GgswCiphertextVector64 {
+        let mut ciphertexts = ImplGgswList::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+            GgswCiphertextCount(input.0.count().0),
+        );
+        for (mut ciphertext, plaintext) in ciphertexts
+            .ciphertext_iter_mut()
+            .zip(input.0.plaintext_iter())
+        {
+            #[cfg(feature = "multithread")]
+            key.0.par_encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+            #[cfg(not(feature = "multithread"))]
+            key.0.encrypt_constant_ggsw(
+                &mut ciphertext,
+                plaintext,
+                noise,
+                &mut self.encryption_generator,
+            );
+        }
+        GgswCiphertextVector64(ciphertexts)
+    }
+}
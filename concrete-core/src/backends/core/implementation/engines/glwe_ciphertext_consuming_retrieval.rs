@@ -0,0 +1,128 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    GlweCiphertextConsumingRetrievalEngine, GlweCiphertextConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// vector of 32 bits integers of a GLWE ciphertext.
+impl GlweCiphertextConsumingRetrievalEngine<GlweCiphertext32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     glwe_dimension.to_glwe_size().0 * polynomial_size.0
+    /// );
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext(
+        &mut self,
+        ciphertext: GlweCiphertext32,
+    ) -> Result<Vec<u32>, GlweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: GlweCiphertext32,
+    ) -> Vec<u32> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// vector of 64 bits integers of a GLWE ciphertext.
+impl GlweCiphertextConsumingRetrievalEngine<GlweCiphertext64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = vec![3_u64 << 52; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_glwe_ciphertext(ciphertext)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     glwe_dimension.to_glwe_size().0 * polynomial_size.0
+    /// );
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_glwe_ciphertext(
+        &mut self,
+        ciphertext: GlweCiphertext64,
+    ) -> Result<Vec<u64>, GlweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_glwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_glwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: GlweCiphertext64,
+    ) -> Vec<u64> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
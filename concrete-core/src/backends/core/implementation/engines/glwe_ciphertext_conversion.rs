@@ -0,0 +1,126 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweCiphertext32, GlweCiphertext64};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError,
+    GlweCiphertextDiscardingConversionEngine,
+};
+use crate::specification::entities::GlweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// GLWE ciphertext to a 64 bits GLWE ciphertext. The torus elements are exactly rescaled to the
+/// output precision.
+impl GlweCiphertextConversionEngine<GlweCiphertext32, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertext64 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> Result<GlweCiphertext64, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext32,
+    ) -> GlweCiphertext64 {
+        let mut output = GlweCiphertext64(ImplGlweCiphertext::allocate(
+            0_u64,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        ));
+        self.discard_convert_glwe_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// GLWE ciphertext to a 32 bits GLWE ciphertext. The torus elements are rounded to the closest
+/// element of the output precision.
+impl GlweCiphertextConversionEngine<GlweCiphertext64, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertext32 = engine.convert_glwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> Result<GlweCiphertext32, GlweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_unchecked(
+        &mut self,
+        input: &GlweCiphertext64,
+    ) -> GlweCiphertext32 {
+        let mut output = GlweCiphertext32(ImplGlweCiphertext::allocate(
+            0_u32,
+            input.polynomial_size(),
+            input.glwe_dimension().to_glwe_size(),
+        ));
+        self.discard_convert_glwe_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
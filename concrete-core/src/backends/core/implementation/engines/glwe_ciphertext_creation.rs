@@ -0,0 +1,362 @@
+use super::super::super::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextView32, GlweCiphertextView64,
+};
+use crate::specification::engines::{GlweCiphertextCreationEngine, GlweCiphertextCreationError};
+use concrete_commons::parameters::PolynomialSize;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// a GLWE ciphertext, borrowing an immutable slice of 32 bits integers.
+impl<'a> GlweCiphertextCreationEngine<&'a [u32], GlweCiphertextView32<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: GlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a [u32],
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextView32<'a>, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u32],
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextView32<'a> {
+        GlweCiphertextView32(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// a GLWE ciphertext, borrowing a mutable slice of 32 bits integers.
+impl<'a> GlweCiphertextCreationEngine<&'a mut [u32], GlweCiphertextMutView32<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let mut container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: GlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a mut [u32],
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextMutView32<'a>, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextMutView32<'a> {
+        GlweCiphertextMutView32(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// a GLWE ciphertext, borrowing an immutable slice of 64 bits integers.
+impl<'a> GlweCiphertextCreationEngine<&'a [u64], GlweCiphertextView64<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: GlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a [u64],
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextView64<'a>, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u64],
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextView64<'a> {
+        GlweCiphertextView64(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// a GLWE ciphertext, borrowing a mutable slice of 64 bits integers.
+impl<'a> GlweCiphertextCreationEngine<&'a mut [u64], GlweCiphertextMutView64<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let mut container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: GlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_view.glwe_dimension(),
+    ///     glwe_size.to_glwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: &'a mut [u64],
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertextMutView64<'a>, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertextMutView64<'a> {
+        GlweCiphertextMutView64(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns a GLWE
+/// ciphertext, taking ownership of a vector of 32 bits integers.
+impl GlweCiphertextCreationEngine<Vec<u32>, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u32; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertext32, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext32 {
+        GlweCiphertext32(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextCreationEngine`] for [`CoreEngine`] which returns a GLWE
+/// ciphertext, taking ownership of a vector of 64 bits integers.
+impl GlweCiphertextCreationEngine<Vec<u64>, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = GlweSize(3);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let container = vec![0_u64; glwe_size.0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.create_glwe_ciphertext(container, polynomial_size)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_size.to_glwe_dimension());
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweCiphertext64, GlweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(GlweCiphertextCreationError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(GlweCiphertextCreationError::NullPolynomialSize);
+        }
+        if container.len() % polynomial_size.0 != 0 {
+            return Err(GlweCiphertextCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_glwe_ciphertext_unchecked(container, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        polynomial_size: PolynomialSize,
+    ) -> GlweCiphertext64 {
+        GlweCiphertext64(ImplGlweCiphertext::from_container(
+            container,
+            polynomial_size,
+        ))
+    }
+}
@@ -2,8 +2,8 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweSecretKey32, GlweSecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::specification::engines::{
     GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextDiscardingEncryptionError,
@@ -169,3 +169,177 @@ impl GlweCiphertextDiscardingEncryptionEngine<GlweSecretKey64, PlaintextVector64
         );
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    GlweCiphertextDiscardingEncryptionEngine<
+        GlweSecretKey32,
+        PlaintextVector32,
+        GlweCiphertextMutView32<'a>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_view: GlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(
+    ///     &key,
+    ///     &mut ciphertext_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GlweCiphertextMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        output: &mut GlweCiphertextMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.encrypt_glwe(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingEncryptionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    GlweCiphertextDiscardingEncryptionEngine<
+        GlweSecretKey64,
+        PlaintextVector64,
+        GlweCiphertextMutView64<'a>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_view: GlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut container[..], polynomial_size)?;
+    ///
+    /// engine.discard_encrypt_glwe_ciphertext(
+    ///     &key,
+    ///     &mut ciphertext_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_view.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_view.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GlweCiphertextMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError<Self::EngineError>> {
+        if key.polynomial_size() != output.polynomial_size() {
+            return Err(GlweCiphertextDiscardingEncryptionError::PolynomialSizeMismatch);
+        }
+        if key.glwe_dimension() != output.glwe_dimension() {
+            return Err(GlweCiphertextDiscardingEncryptionError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size().0 != input.plaintext_count().0 {
+            return Err(GlweCiphertextDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_glwe_ciphertext_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        output: &mut GlweCiphertextMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.encrypt_glwe(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
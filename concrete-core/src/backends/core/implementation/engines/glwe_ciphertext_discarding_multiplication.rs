@@ -0,0 +1,247 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweRelinearizationKey32, GlweRelinearizationKey64,
+    Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::crypto::glwe::tensor_product_polynomial_count;
+use crate::backends::core::private::math::polynomial::PolynomialList as ImplPolynomialList;
+use crate::specification::engines::{
+    GlweCiphertextDiscardingMultiplicationEngine, GlweCiphertextDiscardingMultiplicationError,
+};
+use crate::specification::entities::{GlweCiphertextEntity, GlweRelinearizationKeyEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext32,
+        GlweCiphertext32,
+        GlweRelinearizationKey32,
+        Plaintext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(4);
+    /// let decomposition_base_log = DecompositionBaseLog(6);
+    /// // Here a hard-set encoding is applied (shift by 28 bits)
+    /// let input_1 = vec![3_u32 << 28; polynomial_size.0];
+    /// let input_2 = vec![2_u32 << 28; polynomial_size.0];
+    /// let delta = 1_u32 << 28;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     &delta,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(rlk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+        delta: &Plaintext32,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != output.glwe_dimension()
+            || input_1.glwe_dimension() != rlk.glwe_dimension()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != output.polynomial_size()
+            || input_1.polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::PolynomialSizeMismatch);
+        }
+        if !delta.0 .0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingMultiplicationError::InvalidScalingFactor);
+        }
+        unsafe { self.discard_mul_glwe_ciphertext_unchecked(output, input_1, input_2, rlk, delta) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input_1: &GlweCiphertext32,
+        input_2: &GlweCiphertext32,
+        rlk: &GlweRelinearizationKey32,
+        delta: &Plaintext32,
+    ) {
+        let mut tensor_product = ImplPolynomialList::allocate(
+            0u32,
+            tensor_product_polynomial_count(input_1.0.size()),
+            input_1.polynomial_size(),
+        );
+        input_1
+            .0
+            .tensor_product(&input_2.0, &mut tensor_product, delta.0 .0);
+        rlk.0.relinearize(&mut output.0, &tensor_product);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDiscardingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextDiscardingMultiplicationEngine<
+        GlweCiphertext64,
+        GlweCiphertext64,
+        GlweRelinearizationKey64,
+        Plaintext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(4);
+    /// let decomposition_base_log = DecompositionBaseLog(10);
+    /// // Here a hard-set encoding is applied (shift by 56 bits)
+    /// let input_1 = vec![3_u64 << 56; polynomial_size.0];
+    /// let input_2 = vec![2_u64 << 56; polynomial_size.0];
+    /// let delta = 1_u64 << 56;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let rlk: GlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let plaintext_vector_2 = engine.create_plaintext_vector(&input_2)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let ciphertext_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_2, noise)?;
+    /// let mut ciphertext_3 = engine.zero_encrypt_glwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_mul_glwe_ciphertext(
+    ///     &mut ciphertext_3,
+    ///     &ciphertext_1,
+    ///     &ciphertext_2,
+    ///     &rlk,
+    ///     &delta,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_3.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext_3.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(rlk)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(plaintext_vector_2)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+        delta: &Plaintext64,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>> {
+        if input_1.glwe_dimension() != input_2.glwe_dimension()
+            || input_1.glwe_dimension() != output.glwe_dimension()
+            || input_1.glwe_dimension() != rlk.glwe_dimension()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::GlweDimensionMismatch);
+        }
+        if input_1.polynomial_size() != input_2.polynomial_size()
+            || input_1.polynomial_size() != output.polynomial_size()
+            || input_1.polynomial_size() != rlk.polynomial_size()
+        {
+            return Err(GlweCiphertextDiscardingMultiplicationError::PolynomialSizeMismatch);
+        }
+        if !delta.0 .0.is_power_of_two() {
+            return Err(GlweCiphertextDiscardingMultiplicationError::InvalidScalingFactor);
+        }
+        unsafe { self.discard_mul_glwe_ciphertext_unchecked(output, input_1, input_2, rlk, delta) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input_1: &GlweCiphertext64,
+        input_2: &GlweCiphertext64,
+        rlk: &GlweRelinearizationKey64,
+        delta: &Plaintext64,
+    ) {
+        let mut tensor_product = ImplPolynomialList::allocate(
+            0u64,
+            tensor_product_polynomial_count(input_1.0.size()),
+            input_1.polynomial_size(),
+        );
+        input_1
+            .0
+            .tensor_product(&input_2.0, &mut tensor_product, delta.0 .0);
+        rlk.0.relinearize(&mut output.0, &tensor_product);
+    }
+}
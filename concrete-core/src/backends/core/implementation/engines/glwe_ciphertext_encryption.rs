@@ -2,8 +2,10 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
-    PlaintextVector64,
+    GlweCiphertext32, GlweCiphertext64, GlweGaussianCiphertext32, GlweGaussianCiphertext64,
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweTernaryCiphertext32, GlweTernaryCiphertext64, GlweTernarySecretKey32,
+    GlweTernarySecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
 use crate::specification::engines::{
@@ -152,3 +154,307 @@ impl GlweCiphertextEncryptionEngine<GlweSecretKey64, PlaintextVector64, GlweCiph
         GlweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and ternary keys.
+impl
+    GlweCiphertextEncryptionEngine<
+        GlweTernarySecretKey32,
+        PlaintextVector32,
+        GlweTernaryCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<GlweTernaryCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.0.polynomial_size().0 != input.0.count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> GlweTernaryCiphertext32 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweTernaryCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and ternary keys.
+impl
+    GlweCiphertextEncryptionEngine<
+        GlweTernarySecretKey64,
+        PlaintextVector64,
+        GlweTernaryCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<GlweTernaryCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.0.polynomial_size().0 != input.0.count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> GlweTernaryCiphertext64 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweTernaryCiphertext64(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and gaussian keys.
+impl
+    GlweCiphertextEncryptionEngine<
+        GlweGaussianSecretKey32,
+        PlaintextVector32,
+        GlweGaussianCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<GlweGaussianCiphertext32, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.0.polynomial_size().0 != input.0.count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> GlweGaussianCiphertext32 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweGaussianCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and gaussian keys.
+impl
+    GlweCiphertextEncryptionEngine<
+        GlweGaussianSecretKey64,
+        PlaintextVector64,
+        GlweGaussianCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &GlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<GlweGaussianCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        if key.0.polynomial_size().0 != input.0.count().0 {
+            return Err(GlweCiphertextEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &GlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> GlweGaussianCiphertext64 {
+        let mut ciphertext = ImplGlweCiphertext::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension().to_glwe_size(),
+        );
+        key.0.encrypt_glwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweGaussianCiphertext64(ciphertext)
+    }
+}
@@ -0,0 +1,192 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweCiphertextGgswCiphertextExternalProductEngine,
+    GlweCiphertextGgswCiphertextExternalProductError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextExternalProductEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    GlweCiphertextGgswCiphertextExternalProductEngine<
+        GlweCiphertext32,
+        FourierGgswCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u32;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product: GlweCiphertext32 =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) -> Result<GlweCiphertext32, GlweCiphertextGgswCiphertextExternalProductError<Self::EngineError>>
+    {
+        if glwe_input.polynomial_size() != ggsw_input.polynomial_size() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension() != ggsw_input.glwe_dimension() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) -> GlweCiphertext32 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u32,
+            glwe_input.polynomial_size(),
+            glwe_input.glwe_dimension().to_glwe_size(),
+        );
+        ggsw_input.0.external_product(&mut output, &glwe_input.0);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextGgswCiphertextExternalProductEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    GlweCiphertextGgswCiphertextExternalProductEngine<
+        GlweCiphertext64,
+        FourierGgswCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u64;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// // Compute the external product.
+    /// let product: GlweCiphertext64 =
+    ///     engine.compute_external_product_glwe_ciphertext_ggsw_ciphertext(&glwe, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(product.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(product.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe)?;
+    /// engine.destroy(product)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextGgswCiphertextExternalProductError<Self::EngineError>>
+    {
+        if glwe_input.polynomial_size() != ggsw_input.polynomial_size() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::PolynomialSizeMismatch);
+        }
+        if glwe_input.glwe_dimension() != ggsw_input.glwe_dimension() {
+            return Err(GlweCiphertextGgswCiphertextExternalProductError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+                glwe_input, ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) -> GlweCiphertext64 {
+        let mut output = ImplGlweCiphertext::allocate(
+            0u64,
+            glwe_input.polynomial_size(),
+            glwe_input.glwe_dimension().to_glwe_size(),
+        );
+        ggsw_input.0.external_product(&mut output, &glwe_input.0);
+        GlweCiphertext64(output)
+    }
+}
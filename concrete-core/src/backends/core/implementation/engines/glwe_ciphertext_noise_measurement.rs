@@ -0,0 +1,166 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSecretKey32, GlweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    GlweCiphertextNoiseMeasurementEngine, GlweCiphertextNoiseMeasurementError, NoiseMeasurement,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::parameters::PlaintextCount;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl GlweCiphertextNoiseMeasurementEngine<GlweSecretKey32, GlweCiphertext32, PlaintextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements = engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), polynomial_size.0);
+    /// assert!(measurements.iter().all(|measurement| measurement.bit_size < 25));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<NoiseMeasurement>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextNoiseMeasurementError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextNoiseMeasurementError::PolynomialSizeMismatch);
+        }
+        if input.polynomial_size().0 != expected.plaintext_count().0 {
+            return Err(GlweCiphertextNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        input: &GlweCiphertext32,
+        expected: &PlaintextVector32,
+    ) -> Vec<NoiseMeasurement> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintexts, &input.0);
+        plaintexts
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| {
+                NoiseMeasurement::from_error(decrypted.wrapping_sub(*expected) as i32 as i64, 32)
+            })
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextNoiseMeasurementEngine<GlweSecretKey64, GlweCiphertext64, PlaintextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements = engine.measure_glwe_ciphertext_noise(&key, &ciphertext, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), polynomial_size.0);
+    /// assert!(measurements.iter().all(|measurement| measurement.bit_size < 45));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<NoiseMeasurement>, GlweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweCiphertextNoiseMeasurementError::GlweDimensionMismatch);
+        }
+        if key.polynomial_size() != input.polynomial_size() {
+            return Err(GlweCiphertextNoiseMeasurementError::PolynomialSizeMismatch);
+        }
+        if input.polynomial_size().0 != expected.plaintext_count().0 {
+            return Err(GlweCiphertextNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_glwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        input: &GlweCiphertext64,
+        expected: &PlaintextVector64,
+    ) -> Vec<NoiseMeasurement> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.polynomial_size().0));
+        key.0.decrypt_glwe(&mut plaintexts, &input.0);
+        plaintexts
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| {
+                NoiseMeasurement::from_error(decrypted.wrapping_sub(*expected) as i64, 64)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+use concrete_commons::parameters::CiphertextCount;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::glwe::GlweList as ImplGlweList;
+use crate::specification::engines::{
+    GlweCiphertextVectorConversionEngine, GlweCiphertextVectorConversionError,
+    GlweCiphertextVectorDiscardingConversionEngine,
+};
+use crate::specification::entities::GlweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits GLWE ciphertext vector to a 64 bits GLWE ciphertext vector. The torus elements are exactly
+/// rescaled to the output precision.
+impl GlweCiphertextVectorConversionEngine<GlweCiphertextVector32, GlweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 2 * polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertextVector64 =
+    ///     engine.convert_glwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector(
+        &mut self,
+        input: &GlweCiphertextVector32,
+    ) -> Result<GlweCiphertextVector64, GlweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GlweCiphertextVector32,
+    ) -> GlweCiphertextVector64 {
+        let mut output = GlweCiphertextVector64(ImplGlweList::allocate(
+            0_u64,
+            input.polynomial_size(),
+            input.glwe_dimension(),
+            CiphertextCount(input.glwe_ciphertext_count().0),
+        ));
+        self.discard_convert_glwe_ciphertext_vector_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits GLWE ciphertext vector to a 32 bits GLWE ciphertext vector. The torus elements are rounded
+/// to the closest element of the output precision.
+impl GlweCiphertextVectorConversionEngine<GlweCiphertextVector64, GlweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 2 * polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: GlweCiphertextVector32 =
+    ///     engine.convert_glwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    /// assert_eq!(output.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_ciphertext_vector(
+        &mut self,
+        input: &GlweCiphertextVector64,
+    ) -> Result<GlweCiphertextVector32, GlweCiphertextVectorConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_glwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GlweCiphertextVector64,
+    ) -> GlweCiphertextVector32 {
+        let mut output = GlweCiphertextVector32(ImplGlweList::allocate(
+            0_u32,
+            input.polynomial_size(),
+            input.glwe_dimension(),
+            CiphertextCount(input.glwe_ciphertext_count().0),
+        ));
+        self.discard_convert_glwe_ciphertext_vector_unchecked(&mut output, input);
+        output
+    }
+}
@@ -3,8 +3,10 @@ use concrete_commons::parameters::CiphertextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertextVector32, GlweCiphertextVector64, GlweSecretKey32, GlweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweGaussianCiphertextVector32,
+    GlweGaussianCiphertextVector64, GlweGaussianSecretKey32, GlweGaussianSecretKey64,
+    GlweSecretKey32, GlweSecretKey64, GlweTernaryCiphertextVector32, GlweTernaryCiphertextVector64,
+    GlweTernarySecretKey32, GlweTernarySecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::glwe::GlweList as ImplGlweList;
 use crate::specification::engines::{
@@ -167,3 +169,335 @@ impl
         GlweCiphertextVector64(ciphertext_vector)
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers and ternary keys.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweTernarySecretKey32,
+        PlaintextVector32,
+        GlweTernaryCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<GlweTernaryCiphertextVector32, GlweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> GlweTernaryCiphertextVector32 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension(),
+            CiphertextCount(input.plaintext_count().0 / key.polynomial_size().0),
+        );
+        key.0.encrypt_glwe_list(
+            &mut ciphertext_vector,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweTernaryCiphertextVector32(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers and ternary keys.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweTernarySecretKey64,
+        PlaintextVector64,
+        GlweTernaryCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<GlweTernaryCiphertextVector64, GlweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> GlweTernaryCiphertextVector64 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension(),
+            CiphertextCount(input.plaintext_count().0 / key.polynomial_size().0),
+        );
+        key.0.encrypt_glwe_list(
+            &mut ciphertext_vector,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweTernaryCiphertextVector64(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers and gaussian keys.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweGaussianSecretKey32,
+        PlaintextVector32,
+        GlweGaussianCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<
+        GlweGaussianCiphertextVector32,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> GlweGaussianCiphertextVector32 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u32,
+            key.polynomial_size(),
+            key.glwe_dimension(),
+            CiphertextCount(input.plaintext_count().0 / key.polynomial_size().0),
+        );
+        key.0.encrypt_glwe_list(
+            &mut ciphertext_vector,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweGaussianCiphertextVector32(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers and gaussian keys.
+impl
+    GlweCiphertextVectorEncryptionEngine<
+        GlweGaussianSecretKey64,
+        PlaintextVector64,
+        GlweGaussianCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector =
+    ///     engine.encrypt_glwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(
+    /// #     ciphertext_vector.glwe_ciphertext_count(),
+    /// #     GlweCiphertextCount(2)
+    /// # );
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext_vector(
+        &mut self,
+        key: &GlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        GlweGaussianCiphertextVector64,
+        GlweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        if (input.plaintext_count().0 % key.polynomial_size().0) != 0 {
+            return Err(GlweCiphertextVectorEncryptionError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.encrypt_glwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_glwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &GlweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> GlweGaussianCiphertextVector64 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u64,
+            key.polynomial_size(),
+            key.glwe_dimension(),
+            CiphertextCount(input.plaintext_count().0 / key.polynomial_size().0),
+        );
+        key.0.encrypt_glwe_list(
+            &mut ciphertext_vector,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        GlweGaussianCiphertextVector64(ciphertext_vector)
+    }
+}
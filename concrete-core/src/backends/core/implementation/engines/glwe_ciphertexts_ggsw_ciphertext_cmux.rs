@@ -0,0 +1,208 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, GlweCiphertext32, GlweCiphertext64,
+};
+use crate::specification::engines::{
+    GlweCiphertextsGgswCiphertextCmuxEngine, GlweCiphertextsGgswCiphertextCmuxError,
+};
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextCmuxEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl
+    GlweCiphertextsGgswCiphertextCmuxEngine<
+        GlweCiphertext32,
+        FourierGgswCiphertext32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u32;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 20 bits)
+    /// let input_0 = vec![3_u32 << 20; polynomial_size.0];
+    /// let input_1 = vec![5_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: FourierGgswCiphertext32 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// // Select one of the two GLWE ciphertexts with the GGSW ciphertext.
+    /// let output: GlweCiphertext32 =
+    ///     engine.cmux_glwe_ciphertexts_ggsw_ciphertext(&glwe_0, &glwe_1, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_input_0: &GlweCiphertext32,
+        glwe_input_1: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) -> Result<GlweCiphertext32, GlweCiphertextsGgswCiphertextCmuxError<Self::EngineError>> {
+        if glwe_input_0.polynomial_size() != ggsw_input.polynomial_size()
+            || glwe_input_1.polynomial_size() != ggsw_input.polynomial_size()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::PolynomialSizeMismatch);
+        }
+        if glwe_input_0.glwe_dimension() != ggsw_input.glwe_dimension()
+            || glwe_input_1.glwe_dimension() != ggsw_input.glwe_dimension()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input_0: &GlweCiphertext32,
+        glwe_input_1: &GlweCiphertext32,
+        ggsw_input: &FourierGgswCiphertext32,
+    ) -> GlweCiphertext32 {
+        let mut output = glwe_input_0.0.clone();
+        let mut buffer = glwe_input_1.0.clone();
+        ggsw_input.0.cmux(&mut output, &mut buffer);
+        GlweCiphertext32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweCiphertextsGgswCiphertextCmuxEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl
+    GlweCiphertextsGgswCiphertextCmuxEngine<
+        GlweCiphertext64,
+        FourierGgswCiphertext64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let level = DecompositionLevelCount(1);
+    /// let base_log = DecompositionBaseLog(4);
+    /// // A GGSW ciphertext encrypts a small integer, which is not shifted to the msb.
+    /// let selector = 1_u64;
+    /// // Here a hard-set encoding is applied on the GLWE messages (shift by 50 bits)
+    /// let input_0 = vec![3_u64 << 50; polynomial_size.0];
+    /// let input_1 = vec![5_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext = engine.create_plaintext(&selector)?;
+    /// let plaintext_vector_0 = engine.create_plaintext_vector(&input_0)?;
+    /// let plaintext_vector_1 = engine.create_plaintext_vector(&input_1)?;
+    /// let ggsw = engine.encrypt_scalar_ggsw_ciphertext(&key, &plaintext, noise, level, base_log)?;
+    /// let fourier_ggsw: FourierGgswCiphertext64 = engine.convert_ggsw_ciphertext(&ggsw)?;
+    /// let glwe_0 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_0, noise)?;
+    /// let glwe_1 = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector_1, noise)?;
+    ///
+    /// // Select one of the two GLWE ciphertexts with the GGSW ciphertext.
+    /// let output: GlweCiphertext64 =
+    ///     engine.cmux_glwe_ciphertexts_ggsw_ciphertext(&glwe_0, &glwe_1, &fourier_ggsw)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector_0)?;
+    /// engine.destroy(plaintext_vector_1)?;
+    /// engine.destroy(ggsw)?;
+    /// engine.destroy(fourier_ggsw)?;
+    /// engine.destroy(glwe_0)?;
+    /// engine.destroy(glwe_1)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_input_0: &GlweCiphertext64,
+        glwe_input_1: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweCiphertextsGgswCiphertextCmuxError<Self::EngineError>> {
+        if glwe_input_0.polynomial_size() != ggsw_input.polynomial_size()
+            || glwe_input_1.polynomial_size() != ggsw_input.polynomial_size()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::PolynomialSizeMismatch);
+        }
+        if glwe_input_0.glwe_dimension() != ggsw_input.glwe_dimension()
+            || glwe_input_1.glwe_dimension() != ggsw_input.glwe_dimension()
+        {
+            return Err(GlweCiphertextsGgswCiphertextCmuxError::GlweDimensionMismatch);
+        }
+        Ok(unsafe {
+            self.cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+                glwe_input_0,
+                glwe_input_1,
+                ggsw_input,
+            )
+        })
+    }
+
+    unsafe fn cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input_0: &GlweCiphertext64,
+        glwe_input_1: &GlweCiphertext64,
+        ggsw_input: &FourierGgswCiphertext64,
+    ) -> GlweCiphertext64 {
+        let mut output = glwe_input_0.0.clone();
+        let mut buffer = glwe_input_1.0.clone();
+        ggsw_input.0.cmux(&mut output, &mut buffer);
+        GlweCiphertext64(output)
+    }
+}
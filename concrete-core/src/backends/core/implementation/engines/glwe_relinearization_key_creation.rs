@@ -0,0 +1,192 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweRelinearizationKey32, GlweRelinearizationKey64, GlweSecretKey32, GlweSecretKey64,
+};
+use crate::backends::core::private::crypto::glwe::GlweRelinearizationKey as ImplGlweRelinearizationKey;
+use crate::specification::engines::{
+    GlweRelinearizationKeyCreationEngine, GlweRelinearizationKeyCreationError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl GlweRelinearizationKeyCreationEngine<GlweSecretKey32, GlweRelinearizationKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let relinearization_key: GlweRelinearizationKey32 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relinearization_key.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(relinearization_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_relinearization_key(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey32, GlweRelinearizationKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(GlweRelinearizationKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_glwe_relinearization_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRelinearizationKey32 {
+        let mut rlk = ImplGlweRelinearizationKey::allocate(
+            0,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        rlk.fill_with_relinearization_key(&key.0, noise, &mut self.encryption_generator);
+        GlweRelinearizationKey32(rlk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweRelinearizationKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl GlweRelinearizationKeyCreationEngine<GlweSecretKey64, GlweRelinearizationKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let decomposition_level_count = DecompositionLevelCount(3);
+    /// let decomposition_base_log = DecompositionBaseLog(7);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let relinearization_key: GlweRelinearizationKey64 = engine.create_glwe_relinearization_key(
+    ///     &key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(relinearization_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(relinearization_key.polynomial_size(), polynomial_size);
+    /// assert_eq!(relinearization_key.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(relinearization_key.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(relinearization_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_relinearization_key(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<GlweRelinearizationKey64, GlweRelinearizationKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(GlweRelinearizationKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(GlweRelinearizationKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_glwe_relinearization_key_unchecked(
+                key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> GlweRelinearizationKey64 {
+        let mut rlk = ImplGlweRelinearizationKey::allocate(
+            0,
+            key.glwe_dimension(),
+            key.polynomial_size(),
+            decomposition_level_count,
+            decomposition_base_log,
+        );
+        rlk.fill_with_relinearization_key(&key.0, noise, &mut self.encryption_generator);
+        GlweRelinearizationKey64(rlk)
+    }
+}
@@ -0,0 +1,106 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
+use crate::specification::engines::{
+    GlweSecretKeyConversionEngine, GlweSecretKeyConversionError,
+    GlweSecretKeyDiscardingConversionEngine,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// GLWE secret key to a 64 bits GLWE secret key. The key bits are copied to the output precision.
+impl GlweSecretKeyConversionEngine<GlweSecretKey32, GlweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: GlweSecretKey64 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> Result<GlweSecretKey64, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey32,
+    ) -> GlweSecretKey64 {
+        let mut output = GlweSecretKey64(ImplGlweSecretKey::binary_from_container(
+            vec![0_u64; input.glwe_dimension().0 * input.polynomial_size().0],
+            input.polynomial_size(),
+        ));
+        self.discard_convert_glwe_secret_key_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// GLWE secret key to a 32 bits GLWE secret key. The key bits are copied to the output precision.
+impl GlweSecretKeyConversionEngine<GlweSecretKey64, GlweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// let output: GlweSecretKey32 = engine.convert_glwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_glwe_secret_key(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> Result<GlweSecretKey32, GlweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_glwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_glwe_secret_key_unchecked(
+        &mut self,
+        input: &GlweSecretKey64,
+    ) -> GlweSecretKey32 {
+        let mut output = GlweSecretKey32(ImplGlweSecretKey::binary_from_container(
+            vec![0_u32; input.glwe_dimension().0 * input.polynomial_size().0],
+            input.polynomial_size(),
+        ));
+        self.discard_convert_glwe_secret_key_unchecked(&mut output, input);
+        output
+    }
+}
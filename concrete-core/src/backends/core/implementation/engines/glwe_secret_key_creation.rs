@@ -1,7 +1,10 @@
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::implementation::entities::{
+    GlweGaussianSecretKey32, GlweGaussianSecretKey64, GlweSecretKey32, GlweSecretKey64,
+    GlweTernarySecretKey32, GlweTernarySecretKey64,
+};
 use crate::backends::core::private::crypto::secret::GlweSecretKey as ImplGlweSecretKey;
 use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
 
@@ -100,3 +103,195 @@ impl GlweSecretKeyCreationEngine<GlweSecretKey64> for CoreEngine {
         ))
     }
 }
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and ternary keys.
+impl GlweSecretKeyCreationEngine<GlweTernarySecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_secret_key: GlweTernarySecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweTernarySecretKey32, GlweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweTernarySecretKey32 {
+        GlweTernarySecretKey32(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and ternary keys.
+impl GlweSecretKeyCreationEngine<GlweTernarySecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_secret_key: GlweTernarySecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweTernarySecretKey64, GlweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweTernarySecretKey64 {
+        GlweTernarySecretKey64(ImplGlweSecretKey::generate_ternary(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and gaussian keys.
+impl GlweSecretKeyCreationEngine<GlweGaussianSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_secret_key: GlweGaussianSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweGaussianSecretKey32, GlweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweGaussianSecretKey32 {
+        GlweGaussianSecretKey32(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and gaussian keys.
+impl GlweSecretKeyCreationEngine<GlweGaussianSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_secret_key: GlweGaussianSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<GlweGaussianSecretKey64, GlweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size) })
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> GlweGaussianSecretKey64 {
+        GlweGaussianSecretKey64(ImplGlweSecretKey::generate_gaussian(
+            glwe_dimension,
+            polynomial_size,
+            &mut self.secret_generator,
+        ))
+    }
+}
@@ -0,0 +1,125 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{GlweSecretKey32, GlweSecretKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    GlweSecretKeyDiscardingConversionEngine, GlweSecretKeyDiscardingConversionError,
+};
+use crate::specification::entities::GlweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 32 bits GLWE secret key to a 64 bits GLWE secret key. The key bits are copied to the output
+/// precision.
+impl GlweSecretKeyDiscardingConversionEngine<GlweSecretKey32, GlweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_convert_glwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_secret_key(
+        &mut self,
+        output: &mut GlweSecretKey64,
+        input: &GlweSecretKey32,
+    ) -> Result<(), GlweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweSecretKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweSecretKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_secret_key_unchecked(
+        &mut self,
+        output: &mut GlweSecretKey64,
+        input: &GlweSecretKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u64);
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 64 bits GLWE secret key to a 32 bits GLWE secret key. The key bits are copied to the output
+/// precision.
+impl GlweSecretKeyDiscardingConversionEngine<GlweSecretKey64, GlweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let mut output: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    ///
+    /// engine.discard_convert_glwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(output.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_glwe_secret_key(
+        &mut self,
+        output: &mut GlweSecretKey32,
+        input: &GlweSecretKey64,
+    ) -> Result<(), GlweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(GlweSecretKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(GlweSecretKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        unsafe { self.discard_convert_glwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_glwe_secret_key_unchecked(
+        &mut self,
+        output: &mut GlweSecretKey32,
+        input: &GlweSecretKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u32);
+    }
+}
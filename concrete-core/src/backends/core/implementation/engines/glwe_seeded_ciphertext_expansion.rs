@@ -0,0 +1,124 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweSeededCiphertext32, GlweSeededCiphertext64,
+};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
+use crate::specification::engines::{
+    GlweSeededCiphertextExpansionEngine, GlweSeededCiphertextExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers.
+impl GlweSeededCiphertextExpansionEngine<GlweSeededCiphertext32, GlweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext: GlweSeededCiphertext32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: GlweCiphertext32 =
+    ///     engine.expand_glwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext(
+        &mut self,
+        input: &GlweSeededCiphertext32,
+    ) -> Result<GlweCiphertext32, GlweSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GlweSeededCiphertext32,
+    ) -> GlweCiphertext32 {
+        let mut ciphertext =
+            ImplGlweCiphertext::allocate(0u32, input.0.polynomial_size(), input.0.size());
+        input.0.expand_into(&mut ciphertext);
+        GlweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextExpansionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers.
+impl GlweSeededCiphertextExpansionEngine<GlweSeededCiphertext64, GlweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext: GlweSeededCiphertext64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: GlweCiphertext64 =
+    ///     engine.expand_glwe_seeded_ciphertext(&seeded_ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext(
+        &mut self,
+        input: &GlweSeededCiphertext64,
+    ) -> Result<GlweCiphertext64, GlweSeededCiphertextExpansionError<Self::EngineError>> {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        input: &GlweSeededCiphertext64,
+    ) -> GlweCiphertext64 {
+        let mut ciphertext =
+            ImplGlweCiphertext::allocate(0u64, input.0.polynomial_size(), input.0.size());
+        input.0.expand_into(&mut ciphertext);
+        GlweCiphertext64(ciphertext)
+    }
+}
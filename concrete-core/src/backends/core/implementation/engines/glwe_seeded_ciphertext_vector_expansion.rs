@@ -0,0 +1,139 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertextVector32, GlweCiphertextVector64, GlweSeededCiphertextVector32,
+    GlweSeededCiphertextVector64,
+};
+use crate::backends::core::private::crypto::glwe::GlweList as ImplGlweList;
+use crate::specification::engines::{
+    GlweSeededCiphertextVectorExpansionEngine, GlweSeededCiphertextVectorExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorExpansionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl GlweSeededCiphertextVectorExpansionEngine<GlweSeededCiphertextVector32, GlweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector32 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector32 =
+    ///     engine.expand_glwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &GlweSeededCiphertextVector32,
+    ) -> Result<GlweCiphertextVector32, GlweSeededCiphertextVectorExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GlweSeededCiphertextVector32,
+    ) -> GlweCiphertextVector32 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u32,
+            input.0.polynomial_size(),
+            input.0.glwe_dimension(),
+            input.0.ciphertext_count(),
+        );
+        input.0.expand_into(&mut ciphertext_vector);
+        GlweCiphertextVector32(ciphertext_vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`GlweSeededCiphertextVectorExpansionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl GlweSeededCiphertextVectorExpansionEngine<GlweSeededCiphertextVector64, GlweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 8];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: GlweSeededCiphertextVector64 =
+    ///     engine.encrypt_glwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: GlweCiphertextVector64 =
+    ///     engine.expand_glwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.glwe_ciphertext_count(), GlweCiphertextCount(2));
+    /// assert_eq!(ciphertext_vector.polynomial_size(), polynomial_size);
+    /// assert_eq!(ciphertext_vector.glwe_dimension(), glwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_glwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &GlweSeededCiphertextVector64,
+    ) -> Result<GlweCiphertextVector64, GlweSeededCiphertextVectorExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_glwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &GlweSeededCiphertextVector64,
+    ) -> GlweCiphertextVector64 {
+        let mut ciphertext_vector = ImplGlweList::allocate(
+            0u64,
+            input.0.polynomial_size(),
+            input.0.glwe_dimension(),
+            input.0.ciphertext_count(),
+        );
+        input.0.expand_into(&mut ciphertext_vector);
+        GlweCiphertextVector64(ciphertext_vector)
+    }
+}
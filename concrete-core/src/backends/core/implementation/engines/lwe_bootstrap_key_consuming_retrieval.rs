@@ -0,0 +1,278 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweBootstrapKeyConsumingRetrievalEngine, LweBootstrapKeyConsumingRetrievalError,
+};
+use concrete_fftw::array::AlignedVec;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the vector of 32 bits integers of an LWE bootstrap key.
+impl LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKey32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_bootstrap_key(bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let bsk: LweBootstrapKey32 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: LweBootstrapKey32,
+    ) -> Result<Vec<u32>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: LweBootstrapKey32,
+    ) -> Vec<u32> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the vector of 64 bits integers of an LWE bootstrap key.
+impl LweBootstrapKeyConsumingRetrievalEngine<LweBootstrapKey64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_bootstrap_key(bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let bsk: LweBootstrapKey64 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: LweBootstrapKey64,
+    ) -> Result<Vec<u64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: LweBootstrapKey64,
+    ) -> Vec<u64> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the aligned vector of complex numbers of a Fourier LWE bootstrap key of 32 bits integers.
+impl LweBootstrapKeyConsumingRetrievalEngine<FourierLweBootstrapKey32, AlignedVec<Complex64>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let container: AlignedVec<c64> = engine.consume_retrieve_lwe_bootstrap_key(fourier_bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let fourier_bsk: FourierLweBootstrapKey32 = engine
+    ///     .create_lwe_bootstrap_key_from_container(
+    ///         container, glwe_dim, poly_size, dec_bl, dec_lc,
+    ///     )?;
+    /// #
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: FourierLweBootstrapKey32,
+    ) -> Result<AlignedVec<Complex64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: FourierLweBootstrapKey32,
+    ) -> AlignedVec<Complex64> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the aligned vector of complex numbers of a Fourier LWE bootstrap key of 64 bits integers.
+impl LweBootstrapKeyConsumingRetrievalEngine<FourierLweBootstrapKey64, AlignedVec<Complex64>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    ///
+    /// let container: AlignedVec<c64> = engine.consume_retrieve_lwe_bootstrap_key(fourier_bsk)?;
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// assert_eq!(
+    ///     container.len(),
+    ///     lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0
+    /// );
+    ///
+    /// // The container can be used to create the bootstrap key again
+    /// let fourier_bsk: FourierLweBootstrapKey64 = engine
+    ///     .create_lwe_bootstrap_key_from_container(
+    ///         container, glwe_dim, poly_size, dec_bl, dec_lc,
+    ///     )?;
+    /// #
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_bootstrap_key(
+        &mut self,
+        bootstrap_key: FourierLweBootstrapKey64,
+    ) -> Result<AlignedVec<Complex64>, LweBootstrapKeyConsumingRetrievalError<Self::EngineError>>
+    {
+        Ok(unsafe { self.consume_retrieve_lwe_bootstrap_key_unchecked(bootstrap_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_bootstrap_key_unchecked(
+        &mut self,
+        bootstrap_key: FourierLweBootstrapKey64,
+    ) -> AlignedVec<Complex64> {
+        bootstrap_key.0.into_tensor().into_container()
+    }
+}
@@ -1,11 +1,19 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, FourierLweGaussianBootstrapKey32,
+    FourierLweGaussianBootstrapKey64, FourierLweTernaryBootstrapKey32,
+    FourierLweTernaryBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+    LweGaussianBootstrapKey32, LweGaussianBootstrapKey64, LweTernaryBootstrapKey32,
+    LweTernaryBootstrapKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
 };
-use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey as ImplFourierBootstrapKey;
 use crate::backends::core::private::math::fft::Complex64;
 use crate::specification::engines::{
     LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+    LweBootstrapKeyDiscardingConversionEngine,
 };
 use crate::specification::entities::LweBootstrapKeyEntity;
 
@@ -24,7 +32,8 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, FourierLweBootstrapKey32
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
     /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
@@ -89,7 +98,8 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
     ///
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
-    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
     /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
     /// let noise = Variance(2_f64.powf(-25.));
     ///
@@ -139,6 +149,560 @@ impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64
     }
 }
 
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key created from a ternary key from the standard to
+/// the Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweTernaryBootstrapKey32, FourierLweTernaryBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FourierLweTernaryBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweTernaryBootstrapKey32,
+    ) -> Result<FourierLweTernaryBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweTernaryBootstrapKey32,
+    ) -> FourierLweTernaryBootstrapKey32 {
+        let mut output = ImplFourierBootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.0.key_size(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierLweTernaryBootstrapKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key created from a ternary key from the standard to
+/// the Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweTernaryBootstrapKey64, FourierLweTernaryBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FourierLweTernaryBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweTernaryBootstrapKey64,
+    ) -> Result<FourierLweTernaryBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweTernaryBootstrapKey64,
+    ) -> FourierLweTernaryBootstrapKey64 {
+        let mut output = ImplFourierBootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.0.key_size(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierLweTernaryBootstrapKey64(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key created from a gaussian key from the standard to
+/// the Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweGaussianBootstrapKey32, FourierLweGaussianBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FourierLweGaussianBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweGaussianBootstrapKey32,
+    ) -> Result<FourierLweGaussianBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweGaussianBootstrapKey32,
+    ) -> FourierLweGaussianBootstrapKey32 {
+        let mut output = ImplFourierBootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.0.key_size(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierLweGaussianBootstrapKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key created from a gaussian key from the standard to
+/// the Fourier domain.
+impl LweBootstrapKeyConversionEngine<LweGaussianBootstrapKey64, FourierLweGaussianBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let fourier_bsk: FourierLweGaussianBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(fourier_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(fourier_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(fourier_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(fourier_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(fourier_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweGaussianBootstrapKey64,
+    ) -> Result<FourierLweGaussianBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweGaussianBootstrapKey64,
+    ) -> FourierLweGaussianBootstrapKey64 {
+        let mut output = ImplFourierBootstrapKey::allocate(
+            Complex64::new(0., 0.),
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.0.key_size(),
+        );
+        output.fill_with_forward_fourier(&input.0);
+        FourierLweGaussianBootstrapKey64(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+impl LweBootstrapKeyConversionEngine<FourierLweBootstrapKey32, LweBootstrapKey32> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let fourier_bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: LweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&fourier_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &FourierLweBootstrapKey32,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &FourierLweBootstrapKey32,
+    ) -> LweBootstrapKey32 {
+        let mut output = ImplStandardBootstrapKey::allocate(
+            0_u32,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        output.fill_with_backward_fourier(&input.0);
+        LweBootstrapKey32(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+///
+/// # Note:
+/// The Fourier domain stores the coefficients as double precision floats, which cannot hold 64
+/// bits torus elements exactly. The output coefficients are thus only close to the ones of the
+/// standard key the Fourier key was computed from, and this conversion is not exact.
+impl LweBootstrapKeyConversionEngine<FourierLweBootstrapKey64, LweBootstrapKey64> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let fourier_bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let bsk: LweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&fourier_bsk)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &FourierLweBootstrapKey64,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &FourierLweBootstrapKey64,
+    ) -> LweBootstrapKey64 {
+        let mut output = ImplStandardBootstrapKey::allocate(
+            0_u64,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        );
+        output.fill_with_backward_fourier(&input.0);
+        LweBootstrapKey64(output)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits bootstrap key to a 64 bits bootstrap key, in the standard domain. The torus elements are
+/// exactly rescaled to the output precision.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey32, LweBootstrapKey64> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let converted_bsk: LweBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(converted_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(converted_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(converted_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(converted_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(converted_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(converted_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey32,
+    ) -> LweBootstrapKey64 {
+        let mut output = LweBootstrapKey64(ImplStandardBootstrapKey::allocate(
+            0_u64,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        ));
+        self.discard_convert_lwe_bootstrap_key_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits bootstrap key to a 32 bits bootstrap key, in the standard domain. The torus elements are
+/// rounded to the closest element of the output precision.
+impl LweBootstrapKeyConversionEngine<LweBootstrapKey64, LweBootstrapKey32> for CoreEngine {
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// let converted_bsk: LweBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// #
+    /// assert_eq!(converted_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(converted_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(converted_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(converted_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(converted_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(converted_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_bootstrap_key(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyConversionError<Self::EngineError>> {
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweBootstrapKeyConversionError::DecompositionTooLarge);
+        }
+        Ok(unsafe { self.convert_lwe_bootstrap_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input: &LweBootstrapKey64,
+    ) -> LweBootstrapKey32 {
+        let mut output = LweBootstrapKey32(ImplStandardBootstrapKey::allocate(
+            0_u32,
+            input.glwe_dimension().to_glwe_size(),
+            input.polynomial_size(),
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+        ));
+        self.discard_convert_lwe_bootstrap_key_unchecked(&mut output, input);
+        output
+    }
+}
+
 impl<Key> LweBootstrapKeyConversionEngine<Key, Key> for CoreEngine
 where
     Key: LweBootstrapKeyEntity + Clone,
@@ -1,16 +1,25 @@
 use concrete_commons::dispersion::Variance;
+use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
 
-use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::engines::{CoreEngine, CoreError};
 use crate::backends::core::implementation::entities::{
     FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweSecretKey32, GlweSecretKey64,
-    LweBootstrapKey32, LweBootstrapKey64, LweSecretKey32, LweSecretKey64,
+    LweBootstrapKey32, LweBootstrapKey64, LweGaussianBootstrapKey32, LweGaussianBootstrapKey64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernaryBootstrapKey32, LweTernaryBootstrapKey64, LweTernarySecretKey32,
+    LweTernarySecretKey64, GAUSSIAN_KEY_EXPANSION_WIDTH,
 };
 use crate::backends::core::private::crypto::bootstrap::{
     FourierBootstrapKey as ImplFourierBootstrapKey,
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator as ImplEncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::{
+    GlweSecretKey as ImplGlweSecretKey, LweSecretKey as ImplLweSecretKey,
+};
 use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
 
 /// # Description:
@@ -203,6 +212,416 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, LweBootstrap
     }
 }
 
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers. It takes a ternary LWE secret key as input, and outputs a bootstrap key in the
+/// standard domain, built from the binary expansion of the ternary key.
+///
+/// # Note:
+/// Every coefficient of the ternary key is expanded over two binary coefficients, which makes the
+/// bootstrap key twice as large as a bootstrap key of the same dimension created from a binary
+/// key.
+impl LweBootstrapKeyCreationEngine<LweTernarySecretKey32, GlweSecretKey32, LweTernaryBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey32 {
+        let expanded_key = input_key.0.binary_expansion();
+        LweTernaryBootstrapKey32(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers. It takes a ternary LWE secret key as input, and outputs a bootstrap key in the
+/// standard domain, built from the binary expansion of the ternary key.
+///
+/// # Note:
+/// Every coefficient of the ternary key is expanded over two binary coefficients, which makes the
+/// bootstrap key twice as large as a bootstrap key of the same dimension created from a binary
+/// key.
+impl LweBootstrapKeyCreationEngine<LweTernarySecretKey64, GlweSecretKey64, LweTernaryBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweTernaryBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweTernarySecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweTernaryBootstrapKey64 {
+        let expanded_key = input_key.0.binary_expansion();
+        LweTernaryBootstrapKey64(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers. It takes a gaussian LWE secret key as input, and outputs a bootstrap key in the
+/// standard domain, built from the signed binary expansion of the gaussian key.
+///
+/// # Note:
+/// The coefficients of the gaussian key are expanded over five bits, which makes the bootstrap key
+/// ten times larger than a bootstrap key of the same dimension created from a binary key. The
+/// creation fails with a [`CoreError::GaussianKeyCoefficientOutOfRange`] error in the unlikely
+/// case where a coefficient of the gaussian key does not fit in five bits.
+impl
+    LweBootstrapKeyCreationEngine<
+        LweGaussianSecretKey32,
+        GlweSecretKey32,
+        LweGaussianBootstrapKey32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweGaussianBootstrapKey32, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        let expanded_key = input_key
+            .0
+            .binary_expansion(GAUSSIAN_KEY_EXPANSION_WIDTH)
+            .ok_or(LweBootstrapKeyCreationError::Engine(
+                CoreError::GaussianKeyCoefficientOutOfRange,
+            ))?;
+        Ok(LweGaussianBootstrapKey32(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        )))
+    }
+
+    /// # Safety:
+    /// This method panics if a coefficient of the gaussian key does not fit in five bits.
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweGaussianBootstrapKey32 {
+        let expanded_key = input_key
+            .0
+            .binary_expansion(GAUSSIAN_KEY_EXPANSION_WIDTH)
+            .expect("The coefficients of the gaussian key are too large to be expanded.");
+        LweGaussianBootstrapKey32(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers. It takes a gaussian LWE secret key as input, and outputs a bootstrap key in the
+/// standard domain, built from the signed binary expansion of the gaussian key.
+///
+/// # Note:
+/// The coefficients of the gaussian key are expanded over five bits, which makes the bootstrap key
+/// ten times larger than a bootstrap key of the same dimension created from a binary key. The
+/// creation fails with a [`CoreError::GaussianKeyCoefficientOutOfRange`] error in the unlikely
+/// case where a coefficient of the gaussian key does not fit in five bits.
+impl
+    LweBootstrapKeyCreationEngine<
+        LweGaussianSecretKey64,
+        GlweSecretKey64,
+        LweGaussianBootstrapKey64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: LweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<LweGaussianBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        let expanded_key = input_key
+            .0
+            .binary_expansion(GAUSSIAN_KEY_EXPANSION_WIDTH)
+            .ok_or(LweBootstrapKeyCreationError::Engine(
+                CoreError::GaussianKeyCoefficientOutOfRange,
+            ))?;
+        Ok(LweGaussianBootstrapKey64(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        )))
+    }
+
+    /// # Safety:
+    /// This method panics if a coefficient of the gaussian key does not fit in five bits.
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweGaussianSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> LweGaussianBootstrapKey64 {
+        let expanded_key = input_key
+            .0
+            .binary_expansion(GAUSSIAN_KEY_EXPANSION_WIDTH)
+            .expect("The coefficients of the gaussian key are too large to be expanded.");
+        LweGaussianBootstrapKey64(create_expanded_bootstrap_key(
+            &expanded_key,
+            &output_key.0,
+            decomposition_base_log,
+            decomposition_level_count,
+            noise,
+            &mut self.encryption_generator,
+        ))
+    }
+}
+
 /// # Description:
 /// Implementation of [`LweBootstrapKeyCreationEngine`] for [`CoreEngine`] that operates on
 /// 32 bits integers. It outputs a bootstrap key in the Fourier domain.
@@ -410,3 +829,28 @@ impl LweBootstrapKeyCreationEngine<LweSecretKey64, GlweSecretKey64, FourierLweBo
         FourierLweBootstrapKey64(fourier_key)
     }
 }
+
+// Creates a bootstrap key in the standard domain from the binary expansion of a ternary or
+// gaussian lwe secret key. The size of the output key is the one of the expanded key.
+fn create_expanded_bootstrap_key<Scalar>(
+    expanded_key: &ImplLweSecretKey<BinaryKeyKind, Vec<Scalar>>,
+    output_key: &ImplGlweSecretKey<BinaryKeyKind, Vec<Scalar>>,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    noise: Variance,
+    generator: &mut ImplEncryptionRandomGenerator,
+) -> ImplStandardBootstrapKey<Vec<Scalar>>
+where
+    Scalar: UnsignedTorus,
+{
+    let mut key = ImplStandardBootstrapKey::allocate(
+        Scalar::ZERO,
+        output_key.key_size().to_glwe_size(),
+        output_key.polynomial_size(),
+        decomposition_level_count,
+        decomposition_base_log,
+        expanded_key.key_size(),
+    );
+    key.fill_with_new_key(expanded_key, output_key, noise, generator);
+    key
+}
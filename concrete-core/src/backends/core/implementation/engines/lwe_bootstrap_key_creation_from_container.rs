@@ -0,0 +1,428 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey as ImplFourierBootstrapKey,
+    StandardBootstrapKey as ImplStandardBootstrapKey,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::specification::engines::{
+    LweBootstrapKeyCreationFromContainerEngine, LweBootstrapKeyCreationFromContainerError,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns an LWE bootstrap key, taking ownership of a vector of 32 bits integers.
+impl LweBootstrapKeyCreationFromContainerEngine<Vec<u32>, LweBootstrapKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container = vec![0_u32; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: LweBootstrapKey32 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweBootstrapKey32, LweBootstrapKeyCreationFromContainerError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweBootstrapKey32 {
+        LweBootstrapKey32(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns an LWE bootstrap key, taking ownership of a vector of 64 bits integers.
+impl LweBootstrapKeyCreationFromContainerEngine<Vec<u64>, LweBootstrapKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container = vec![0_u64; lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: LweBootstrapKey64 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweBootstrapKey64, LweBootstrapKeyCreationFromContainerError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweBootstrapKey64 {
+        LweBootstrapKey64(ImplStandardBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns a Fourier LWE bootstrap key of 32 bits integers, taking ownership of an aligned vector
+/// of complex numbers.
+impl LweBootstrapKeyCreationFromContainerEngine<AlignedVec<Complex64>, FourierLweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container: AlignedVec<c64> =
+    ///     AlignedVec::new(lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: FourierLweBootstrapKey32 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        FourierLweBootstrapKey32,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> FourierLweBootstrapKey32 {
+        FourierLweBootstrapKey32(ImplFourierBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns a Fourier LWE bootstrap key of 64 bits integers, taking ownership of an aligned vector
+/// of complex numbers.
+impl LweBootstrapKeyCreationFromContainerEngine<AlignedVec<Complex64>, FourierLweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// use concrete_fftw::array::AlignedVec;
+    /// use concrete_fftw::types::c64;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    ///
+    /// // Here we create a container outside of the engine
+    /// let glwe_size = glwe_dim.to_glwe_size();
+    /// let container: AlignedVec<c64> =
+    ///     AlignedVec::new(lwe_dim.0 * dec_lc.0 * glwe_size.0 * glwe_size.0 * poly_size.0);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let bsk: FourierLweBootstrapKey64 = engine.create_lwe_bootstrap_key_from_container(
+    ///     container, glwe_dim, poly_size, dec_bl, dec_lc,
+    /// )?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<
+        FourierLweBootstrapKey64,
+        LweBootstrapKeyCreationFromContainerError<Self::EngineError>,
+    > {
+        if container.is_empty() {
+            return Err(LweBootstrapKeyCreationFromContainerError::EmptyContainer);
+        }
+        if polynomial_size.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullPolynomialSize);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        let glwe_size = glwe_dimension.to_glwe_size();
+        if container.len()
+            % (decomposition_level_count.0 * glwe_size.0 * glwe_size.0 * polynomial_size.0)
+            != 0
+        {
+            return Err(LweBootstrapKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_from_container_unchecked(
+                container,
+                glwe_dimension,
+                polynomial_size,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: AlignedVec<Complex64>,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> FourierLweBootstrapKey64 {
+        FourierLweBootstrapKey64(ImplFourierBootstrapKey::from_container(
+            container,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomposition_level_count,
+            decomposition_base_log,
+        ))
+    }
+}
@@ -0,0 +1,518 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweBootstrapKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey32, FourierLweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut FourierLweBootstrapKey32,
+        input: &LweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut FourierLweBootstrapKey32,
+        input: &LweBootstrapKey32,
+    ) {
+        output.0.fill_with_forward_fourier(&input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers. It converts a bootstrap key from the standard to the Fourier domain.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey64, FourierLweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut FourierLweBootstrapKey64,
+        input: &LweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut FourierLweBootstrapKey64,
+        input: &LweBootstrapKey64,
+    ) {
+        output.0.fill_with_forward_fourier(&input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+impl LweBootstrapKeyDiscardingConversionEngine<FourierLweBootstrapKey32, LweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &FourierLweBootstrapKey32,
+    ) {
+        output.0.fill_with_backward_fourier(&input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers. It converts a bootstrap key from the Fourier to the standard domain.
+///
+/// # Note:
+/// The Fourier domain stores the coefficients as double precision floats, which cannot hold 64
+/// bits torus elements exactly. The output coefficients are thus only close to the ones of the
+/// standard key the Fourier key was computed from, and this conversion is not exact.
+impl LweBootstrapKeyDiscardingConversionEngine<FourierLweBootstrapKey64, LweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let mut output_bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &FourierLweBootstrapKey64,
+    ) {
+        output.0.fill_with_backward_fourier(&input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits bootstrap key to a 64 bits bootstrap key, in the standard domain. The torus elements
+/// are exactly rescaled to the output precision.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey32, LweBootstrapKey64>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: LweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let output_lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let output_glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let mut output_bsk: LweBootstrapKey64 = engine.create_lwe_bootstrap_key(
+    ///     &output_lwe_sk,
+    ///     &output_glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(output_lwe_sk)?;
+    /// engine.destroy(output_glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweBootstrapKey32,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey64,
+        input: &LweBootstrapKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits bootstrap key to a 32 bits bootstrap key, in the standard domain. The torus elements
+/// are rounded to the closest element of the output precision.
+impl LweBootstrapKeyDiscardingConversionEngine<LweBootstrapKey64, LweBootstrapKey32>
+    for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) =
+    ///     (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let input_bsk: LweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let output_lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let output_glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let mut output_bsk: LweBootstrapKey32 = engine.create_lwe_bootstrap_key(
+    ///     &output_lwe_sk,
+    ///     &output_glwe_sk,
+    ///     dec_bl,
+    ///     dec_lc,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_bootstrap_key(&mut output_bsk, &input_bsk)?;
+    /// #
+    /// assert_eq!(output_bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output_bsk.polynomial_size(), poly_size);
+    /// assert_eq!(output_bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(output_bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(output_bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(output_lwe_sk)?;
+    /// engine.destroy(output_glwe_sk)?;
+    /// engine.destroy(input_bsk)?;
+    /// engine.destroy(output_bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_bootstrap_key(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweBootstrapKey64,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.glwe_dimension() != input.glwe_dimension() {
+            return Err(LweBootstrapKeyDiscardingConversionError::GlweDimensionMismatch);
+        }
+        if output.polynomial_size() != input.polynomial_size() {
+            return Err(LweBootstrapKeyDiscardingConversionError::PolynomialSizeMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweBootstrapKeyDiscardingConversionError::DecompositionTooLarge);
+        }
+        unsafe { self.discard_convert_lwe_bootstrap_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_bootstrap_key_unchecked(
+        &mut self,
+        output: &mut LweBootstrapKey32,
+        input: &LweBootstrapKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64,
 };
 use crate::specification::engines::{
     LweCiphertextCleartextDiscardingMultiplicationEngine,
@@ -143,3 +144,153 @@ impl
         output.0.fill_with_scalar_mul(&input_1.0, &input_2.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`CoreEngine`]
+/// that operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        LweCiphertextView32<'a>,
+        Cleartext32,
+        LweCiphertextMutView32<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: LweCiphertextView32 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    /// let cleartext = engine.create_cleartext(&2_u32)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut output_view, &input_view, &cleartext)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'b>,
+        input_1: &LweCiphertextView32<'a>,
+        input_2: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(LweCiphertextCleartextDiscardingMultiplicationError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'b>,
+        input_1: &LweCiphertextView32<'a>,
+        input_2: &Cleartext32,
+    ) {
+        output.0.fill_with_scalar_mul(&input_1.0, &input_2.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextDiscardingMultiplicationEngine`] for [`CoreEngine`]
+/// that operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        LweCiphertextView64<'a>,
+        Cleartext64,
+        LweCiphertextMutView64<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut input_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut input_view, &plaintext, noise)?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: LweCiphertextView64 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    /// let cleartext = engine.create_cleartext(&2_u64)?;
+    ///
+    /// engine.discard_mul_lwe_ciphertext_cleartext(&mut output_view, &input_view, &cleartext)?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        input_1: &LweCiphertextView64<'a>,
+        input_2: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError<Self::EngineError>> {
+        if output.lwe_dimension() != input_1.lwe_dimension() {
+            return Err(LweCiphertextCleartextDiscardingMultiplicationError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_mul_lwe_ciphertext_cleartext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        input_1: &LweCiphertextView64<'a>,
+        input_2: &Cleartext64,
+    ) {
+        output.0.fill_with_scalar_mul(&input_1.0, &input_2.0);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64,
+    Cleartext32, Cleartext64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64,
 };
 use crate::specification::engines::{
     LweCiphertextCleartextFusingMultiplicationEngine,
@@ -116,3 +117,123 @@ impl LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertext64, Cleartext
         output.0.update_with_scalar_mul(input.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertextMutView32<'a>, Cleartext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// let cleartext = engine.create_cleartext(&2_u32)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext_view, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'a>,
+        input: &Cleartext32,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'a>,
+        input: &Cleartext32,
+    ) {
+        output.0.update_with_scalar_mul(input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextCleartextFusingMultiplicationEngine<LweCiphertextMutView64<'a>, Cleartext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// let cleartext = engine.create_cleartext(&2_u64)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext_view, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(cleartext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'a>,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'a>,
+        input: &Cleartext64,
+    ) {
+        output.0.update_with_scalar_mul(input.0);
+    }
+}
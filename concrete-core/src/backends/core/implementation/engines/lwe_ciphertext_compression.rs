@@ -0,0 +1,73 @@
+use concrete_commons::parameters::CiphertextModulusLog;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext64, LweCompressedCiphertext64};
+use crate::backends::core::private::crypto::lwe::LweCompressedCiphertext as ImplLweCompressedCiphertext;
+use crate::specification::engines::{
+    LweCiphertextCompressionEngine, LweCiphertextCompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCompressionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers, and stores the compressed coefficients on 32 bits.
+impl LweCiphertextCompressionEngine<LweCiphertext64, LweCompressedCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertext is switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let compressed: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, modulus_log)?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.modulus_log(), modulus_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(compressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<LweCompressedCiphertext64, LweCiphertextCompressionError<Self::EngineError>> {
+        if modulus_log.0 == 0 {
+            return Err(LweCiphertextCompressionError::NullModulusLog);
+        }
+        if modulus_log.0 > 32 {
+            return Err(LweCiphertextCompressionError::ModulusLogTooLarge);
+        }
+        Ok(unsafe { self.compress_lwe_ciphertext_unchecked(input, modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+        modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertext64 {
+        let mut compressed =
+            ImplLweCompressedCiphertext::allocate(0u32, input.0.lwe_size(), modulus_log);
+        compressed.fill_with_modulus_switch(&input.0);
+        LweCompressedCiphertext64(compressed)
+    }
+}
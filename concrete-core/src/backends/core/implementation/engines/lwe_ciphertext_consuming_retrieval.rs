@@ -0,0 +1,112 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweCiphertextConsumingRetrievalEngine, LweCiphertextConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// vector of 32 bits integers of an LWE ciphertext.
+impl LweCiphertextConsumingRetrievalEngine<LweCiphertext32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0);
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: LweCiphertext32 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext(
+        &mut self,
+        ciphertext: LweCiphertext32,
+    ) -> Result<Vec<u32>, LweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: LweCiphertext32,
+    ) -> Vec<u32> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConsumingRetrievalEngine`] for [`CoreEngine`] that returns the
+/// vector of 64 bits integers of an LWE ciphertext.
+impl LweCiphertextConsumingRetrievalEngine<LweCiphertext64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_ciphertext(ciphertext)?;
+    /// assert_eq!(container.len(), lwe_dimension.to_lwe_size().0);
+    ///
+    /// // The container can be used to create the ciphertext again
+    /// let ciphertext: LweCiphertext64 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_ciphertext(
+        &mut self,
+        ciphertext: LweCiphertext64,
+    ) -> Result<Vec<u64>, LweCiphertextConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_ciphertext_unchecked(ciphertext) })
+    }
+
+    unsafe fn consume_retrieve_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: LweCiphertext64,
+    ) -> Vec<u64> {
+        ciphertext.0.into_tensor().into_container()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextConversionEngine, LweCiphertextConversionError,
+    LweCiphertextDiscardingConversionEngine,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE ciphertext to a 64 bits LWE ciphertext. The torus elements are exactly rescaled to the
+/// output precision.
+impl LweCiphertextConversionEngine<LweCiphertext32, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertext64 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext32,
+    ) -> Result<LweCiphertext64, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext32,
+    ) -> LweCiphertext64 {
+        let mut output = LweCiphertext64(ImplLweCiphertext::allocate(
+            0_u64,
+            input.lwe_dimension().to_lwe_size(),
+        ));
+        self.discard_convert_lwe_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE ciphertext to a 32 bits LWE ciphertext. The torus elements are rounded to the closest
+/// element of the output precision.
+impl LweCiphertextConversionEngine<LweCiphertext64, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let output: LweCiphertext32 = engine.convert_lwe_ciphertext(&ciphertext)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> Result<LweCiphertext32, LweCiphertextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCiphertext64,
+    ) -> LweCiphertext32 {
+        let mut output = LweCiphertext32(ImplLweCiphertext::allocate(
+            0_u32,
+            input.lwe_dimension().to_lwe_size(),
+        ));
+        self.discard_convert_lwe_ciphertext_unchecked(&mut output, input);
+        output
+    }
+}
@@ -0,0 +1,261 @@
+use super::super::super::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
+    LweCiphertextView32, LweCiphertextView64,
+};
+use crate::specification::engines::{LweCiphertextCreationEngine, LweCiphertextCreationError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// an LWE ciphertext, borrowing an immutable slice of 32 bits integers.
+impl<'a> LweCiphertextCreationEngine<&'a [u32], LweCiphertextView32<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextView32 = engine.create_lwe_ciphertext(&container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a [u32],
+    ) -> Result<LweCiphertextView32<'a>, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u32],
+    ) -> LweCiphertextView32<'a> {
+        LweCiphertextView32(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// an LWE ciphertext, borrowing a mutable slice of 32 bits integers.
+impl<'a> LweCiphertextCreationEngine<&'a mut [u32], LweCiphertextMutView32<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a mut [u32],
+    ) -> Result<LweCiphertextMutView32<'a>, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+    ) -> LweCiphertextMutView32<'a> {
+        LweCiphertextMutView32(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// an LWE ciphertext, borrowing an immutable slice of 64 bits integers.
+impl<'a> LweCiphertextCreationEngine<&'a [u64], LweCiphertextView64<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextView64 = engine.create_lwe_ciphertext(&container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a [u64],
+    ) -> Result<LweCiphertextView64<'a>, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a [u64],
+    ) -> LweCiphertextView64<'a> {
+        LweCiphertextView64(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns the view of
+/// an LWE ciphertext, borrowing a mutable slice of 64 bits integers.
+impl<'a> LweCiphertextCreationEngine<&'a mut [u64], LweCiphertextMutView64<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let mut container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: &'a mut [u64],
+    ) -> Result<LweCiphertextMutView64<'a>, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+    ) -> LweCiphertextMutView64<'a> {
+        LweCiphertextMutView64(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns an LWE
+/// ciphertext, taking ownership of a vector of 32 bits integers.
+impl LweCiphertextCreationEngine<Vec<u32>, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u32; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: LweCiphertext32 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: Vec<u32>,
+    ) -> Result<LweCiphertext32, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(&mut self, container: Vec<u32>) -> LweCiphertext32 {
+        LweCiphertext32(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextCreationEngine`] for [`CoreEngine`] which returns an LWE
+/// ciphertext, taking ownership of a vector of 64 bits integers.
+impl LweCiphertextCreationEngine<Vec<u64>, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let container = vec![0_u64; lwe_size.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext: LweCiphertext64 = engine.create_lwe_ciphertext(container)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_size.to_lwe_dimension());
+    ///
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: Vec<u64>,
+    ) -> Result<LweCiphertext64, LweCiphertextCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextCreationError::EmptyContainer);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_unchecked(container) })
+    }
+
+    unsafe fn create_lwe_ciphertext_unchecked(&mut self, container: Vec<u64>) -> LweCiphertext64 {
+        LweCiphertext64(ImplLweCiphertext::from_container(container))
+    }
+}
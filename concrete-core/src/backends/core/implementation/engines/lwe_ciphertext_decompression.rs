@@ -0,0 +1,63 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweCiphertext64, LweCompressedCiphertext64};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextDecompressionEngine, LweCiphertextDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecompressionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers.
+impl LweCiphertextDecompressionEngine<LweCompressedCiphertext64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertext is switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let compressed: LweCompressedCiphertext64 =
+    ///     engine.compress_lwe_ciphertext(&ciphertext, modulus_log)?;
+    ///
+    /// let decompressed: LweCiphertext64 = engine.decompress_lwe_ciphertext(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(compressed)?;
+    /// engine.destroy(decompressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &LweCompressedCiphertext64,
+    ) -> Result<LweCiphertext64, LweCiphertextDecompressionError<Self::EngineError>> {
+        Ok(unsafe { self.decompress_lwe_ciphertext_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &LweCompressedCiphertext64,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, input.0.lwe_size());
+        input.0.lift_into(&mut ciphertext);
+        LweCiphertext64(ciphertext)
+    }
+}
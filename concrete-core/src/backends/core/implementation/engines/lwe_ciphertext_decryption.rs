@@ -1,6 +1,9 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext32, LweCiphertext64, LweCiphertextView32, LweCiphertextView64,
+    LweGaussianCiphertext32, LweGaussianCiphertext64, LweGaussianSecretKey32,
+    LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64, LweTernaryCiphertext32,
+    LweTernaryCiphertext64, LweTernarySecretKey32, LweTernarySecretKey64, Plaintext32, Plaintext64,
 };
 use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
 use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
@@ -108,3 +111,339 @@ impl LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
         Plaintext64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and ternary keys.
+impl LweCiphertextDecryptionEngine<LweTernarySecretKey32, LweTernaryCiphertext32, Plaintext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweTernaryCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweTernaryCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and ternary keys.
+impl LweCiphertextDecryptionEngine<LweTernarySecretKey64, LweTernaryCiphertext64, Plaintext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweTernaryCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweTernaryCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and gaussian keys.
+impl LweCiphertextDecryptionEngine<LweGaussianSecretKey32, LweGaussianCiphertext32, Plaintext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweGaussianCiphertext32,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweGaussianCiphertext32,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and gaussian keys.
+impl LweCiphertextDecryptionEngine<LweGaussianSecretKey64, LweGaussianCiphertext64, Plaintext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweGaussianCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweGaussianCiphertext64,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextDecryptionEngine<LweSecretKey32, LweCiphertextView32<'a>, Plaintext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// let ciphertext_view: LweCiphertextView32 = engine.create_lwe_ciphertext(&container[..])?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_view)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextView32<'a>,
+    ) -> Result<Plaintext32, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextView32<'a>,
+    ) -> Plaintext32 {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextDecryptionEngine<LweSecretKey64, LweCiphertextView64<'a>, Plaintext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// let ciphertext_view: LweCiphertextView64 = engine.create_lwe_ciphertext(&container[..])?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext_view)?;
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextView64<'a>,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextView64<'a>,
+    ) -> Plaintext64 {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        Plaintext64(plaintext)
+    }
+}
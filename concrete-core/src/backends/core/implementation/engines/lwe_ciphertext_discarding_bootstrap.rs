@@ -1,9 +1,15 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertext32, GlweCiphertext64,
-    LweCiphertext32, LweCiphertext64,
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, FourierLweGaussianBootstrapKey32,
+    FourierLweGaussianBootstrapKey64, FourierLweTernaryBootstrapKey32,
+    FourierLweTernaryBootstrapKey64, GlweCiphertext32, GlweCiphertext64, GlweCiphertextView32,
+    GlweCiphertextView64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView32,
+    LweCiphertextMutView64, LweCiphertextView32, LweCiphertextView64, LweGaussianCiphertext32,
+    LweGaussianCiphertext64, LweTernaryCiphertext32, LweTernaryCiphertext64,
+    GAUSSIAN_KEY_EXPANSION_WIDTH,
 };
 use crate::backends::core::private::crypto::bootstrap::Bootstrap;
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{
     LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
 };
@@ -206,3 +212,637 @@ impl
         bsk.0.bootstrap(&mut output.0, &input.0, &acc.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b, 'c>
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweBootstrapKey32,
+        GlweCiphertextView32<'a>,
+        LweCiphertextView32<'b>,
+        LweCiphertextMutView32<'c>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut acc_container = vec![0_u32; glwe_dim.to_glwe_size().0 * poly_size.0];
+    /// let mut input_container = vec![0_u32; lwe_dim.to_lwe_size().0];
+    /// let mut output_container = vec![0_u32; lwe_dim_output.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let mut acc: GlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut acc_container[..], poly_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_sk, &mut acc, &plaintext_vector, noise)?;
+    /// engine.destroy(acc)?;
+    /// let acc: GlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&acc_container[..], poly_size)?;
+    /// let mut input: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&lwe_sk, &mut input, &plaintext, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: LweCiphertextView32 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'c>,
+        input: &LweCiphertextView32<'b>,
+        acc: &GlweCiphertextView32<'a>,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'c>,
+        input: &LweCiphertextView32<'b>,
+        acc: &GlweCiphertextView32<'a>,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b, 'c>
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweBootstrapKey64,
+        GlweCiphertextView64<'a>,
+        LweCiphertextView64<'b>,
+        LweCiphertextMutView64<'c>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut acc_container = vec![0_u64; glwe_dim.to_glwe_size().0 * poly_size.0];
+    /// let mut input_container = vec![0_u64; lwe_dim.to_lwe_size().0];
+    /// let mut output_container = vec![0_u64; lwe_dim_output.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let mut acc: GlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut acc_container[..], poly_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(&glwe_sk, &mut acc, &plaintext_vector, noise)?;
+    /// engine.destroy(acc)?;
+    /// let acc: GlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&acc_container[..], poly_size)?;
+    /// let mut input: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut input_container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&lwe_sk, &mut input, &plaintext, noise)?;
+    /// engine.destroy(input)?;
+    /// let input: LweCiphertextView64 = engine.create_lwe_ciphertext(&input_container[..])?;
+    /// let mut output: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'c>,
+        input: &LweCiphertextView64<'b>,
+        acc: &GlweCiphertextView64<'a>,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'c>,
+        input: &LweCiphertextView64<'b>,
+        acc: &GlweCiphertextView64<'a>,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        bsk.0.bootstrap(&mut output.0, &input.0, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It takes as input a ciphertext encrypted under a ternary key, whose mask is
+/// expanded to match the binary expansion of the key the bootstrap key was created from.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweTernaryBootstrapKey32,
+        GlweCiphertext32,
+        LweTernaryCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweTernaryBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweTernaryCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweTernaryBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweTernaryCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweTernaryBootstrapKey32,
+    ) {
+        let mut expanded = ImplLweCiphertext::allocate(0_u32, bsk.0.key_size().to_lwe_size());
+        expanded.fill_with_mask_expansion(&input.0, 1);
+        bsk.0.bootstrap(&mut output.0, &expanded, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It takes as input a ciphertext encrypted under a ternary key, whose mask is
+/// expanded to match the binary expansion of the key the bootstrap key was created from.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweTernaryBootstrapKey64,
+        GlweCiphertext64,
+        LweTernaryCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweTernaryBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweTernaryBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweTernaryCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweTernaryBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweTernaryCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweTernaryBootstrapKey64,
+    ) {
+        let mut expanded = ImplLweCiphertext::allocate(0_u64, bsk.0.key_size().to_lwe_size());
+        expanded.fill_with_mask_expansion(&input.0, 1);
+        bsk.0.bootstrap(&mut output.0, &expanded, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers. It takes as input a ciphertext encrypted under a gaussian key, whose mask is
+/// expanded to match the signed binary expansion of the key the bootstrap key was created from.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweGaussianBootstrapKey32,
+        GlweCiphertext32,
+        LweGaussianCiphertext32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweGaussianBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweGaussianBootstrapKey32 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweGaussianCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweGaussianBootstrapKey32,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        input: &LweGaussianCiphertext32,
+        acc: &GlweCiphertext32,
+        bsk: &FourierLweGaussianBootstrapKey32,
+    ) {
+        let mut expanded = ImplLweCiphertext::allocate(0_u32, bsk.0.key_size().to_lwe_size());
+        expanded.fill_with_mask_expansion(&input.0, GAUSSIAN_KEY_EXPANSION_WIDTH);
+        bsk.0.bootstrap(&mut output.0, &expanded, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers. It takes as input a ciphertext encrypted under a gaussian key, whose mask is
+/// expanded to match the signed binary expansion of the key the bootstrap key was created from.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        FourierLweGaussianBootstrapKey64,
+        GlweCiphertext64,
+        LweGaussianCiphertext64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: LweGaussianBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let fourier_bsk: FourierLweGaussianBootstrapKey64 = engine.convert_lwe_bootstrap_key(&bsk)?;
+    /// let lwe_sk_output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &fourier_bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(fourier_bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweGaussianCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweGaussianBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        input: &LweGaussianCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &FourierLweGaussianBootstrapKey64,
+    ) {
+        let mut expanded = ImplLweCiphertext::allocate(0_u64, bsk.0.key_size().to_lwe_size());
+        expanded.fill_with_mask_expansion(&input.0, GAUSSIAN_KEY_EXPANSION_WIDTH);
+        bsk.0.bootstrap(&mut output.0, &expanded, &acc.0);
+    }
+}
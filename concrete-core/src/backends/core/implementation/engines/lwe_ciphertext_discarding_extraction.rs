@@ -2,7 +2,8 @@ use concrete_commons::parameters::MonomialDegree;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    GlweCiphertext32, GlweCiphertext64, LweCiphertext32, LweCiphertext64,
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextView32, GlweCiphertextView64,
+    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
 };
 use crate::specification::engines::{
     LweCiphertextDiscardingExtractionEngine, LweCiphertextDiscardingExtractionError,
@@ -170,3 +171,183 @@ impl LweCiphertextDiscardingExtractionEngine<GlweCiphertext64, LweCiphertext64>
         output.0.fill_with_glwe_sample_extraction(&input.0, nth);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextDiscardingExtractionEngine<GlweCiphertextView32<'a>, LweCiphertextMutView32<'b>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// // The target LWE dimension should be equal to the polynomial size + 1
+    /// // since we're going to extract one sample from the GLWE ciphertext
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // We're going to extract the first one
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut glwe_container = vec![0_u32; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    /// let mut lwe_container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut glwe_view: GlweCiphertextMutView32 =
+    ///     engine.create_glwe_ciphertext(&mut glwe_container[..], polynomial_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(
+    ///     &glwe_key,
+    ///     &mut glwe_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(glwe_view)?;
+    /// let glwe_view: GlweCiphertextView32 =
+    ///     engine.create_glwe_ciphertext(&glwe_container[..], polynomial_size)?;
+    /// let mut lwe_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut lwe_container[..])?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(&mut lwe_view, &glwe_view, MonomialDegree(0))?;
+    /// #
+    /// assert_eq!(lwe_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_view)?;
+    /// engine.destroy(lwe_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'b>,
+        input: &GlweCiphertextView32<'a>,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.0.lwe_size().to_lwe_dimension().0
+            != input.0.polynomial_size().0 * input.0.size().to_glwe_dimension().0
+        {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 > input.glwe_dimension().0 - 1 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView32<'b>,
+        input: &GlweCiphertextView32<'a>,
+        nth: MonomialDegree,
+    ) {
+        output.0.fill_with_glwe_sample_extraction(&input.0, nth);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingExtractionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextDiscardingExtractionEngine<GlweCiphertextView64<'a>, LweCiphertextMutView64<'b>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     GlweDimension, LweDimension, MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// // The target LWE dimension should be equal to the polynomial size + 1
+    /// // since we're going to extract one sample from the GLWE ciphertext
+    /// let lwe_dimension = LweDimension(8);
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // There are always polynomial_size messages encrypted in the GLWE ciphertext
+    /// // We're going to extract the first one
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut glwe_container = vec![0_u64; glwe_dimension.to_glwe_size().0 * polynomial_size.0];
+    /// let mut lwe_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut glwe_view: GlweCiphertextMutView64 =
+    ///     engine.create_glwe_ciphertext(&mut glwe_container[..], polynomial_size)?;
+    /// engine.discard_encrypt_glwe_ciphertext(
+    ///     &glwe_key,
+    ///     &mut glwe_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(glwe_view)?;
+    /// let glwe_view: GlweCiphertextView64 =
+    ///     engine.create_glwe_ciphertext(&glwe_container[..], polynomial_size)?;
+    /// let mut lwe_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut lwe_container[..])?;
+    ///
+    /// engine.discard_extract_lwe_ciphertext(&mut lwe_view, &glwe_view, MonomialDegree(0))?;
+    /// #
+    /// assert_eq!(lwe_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(glwe_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(glwe_view)?;
+    /// engine.destroy(lwe_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_extract_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        input: &GlweCiphertextView64<'a>,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError<Self::EngineError>> {
+        if output.0.lwe_size().to_lwe_dimension().0
+            != input.0.polynomial_size().0 * input.0.size().to_glwe_dimension().0
+        {
+            return Err(LweCiphertextDiscardingExtractionError::SizeMismatch);
+        }
+        if nth.0 > input.glwe_dimension().0 - 1 {
+            return Err(LweCiphertextDiscardingExtractionError::MonomialDegreeTooLarge);
+        }
+        unsafe { self.discard_extract_lwe_ciphertext_unchecked(output, input, nth) };
+        Ok(())
+    }
+
+    unsafe fn discard_extract_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        input: &GlweCiphertextView64<'a>,
+        nth: MonomialDegree,
+    ) {
+        output.0.fill_with_glwe_sample_extraction(&input.0, nth);
+    }
+}
@@ -0,0 +1,156 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextDiscardingLoadingEngine, LweCiphertextDiscardingLoadingError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLoadingEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingLoadingEngine<LweCiphertextVector32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext: LweCiphertext32 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_load_lwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_vector,
+    ///     LweCiphertextIndex(5),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_load_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingLoadingError<Self::EngineError>> {
+        if ciphertext.lwe_dimension() != vector.lwe_dimension() {
+            return Err(LweCiphertextDiscardingLoadingError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingLoadingError::IndexTooLarge);
+        }
+        unsafe { self.discard_load_lwe_ciphertext_unchecked(ciphertext, vector, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_load_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext32,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) {
+        let lwe_size = vector.0.lwe_size().0;
+        ciphertext.0.as_mut_tensor().fill_with_copy(
+            &vector
+                .0
+                .as_tensor()
+                .get_sub(i.0 * lwe_size..(i.0 + 1) * lwe_size),
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingLoadingEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingLoadingEngine<LweCiphertextVector64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut ciphertext: LweCiphertext64 = engine.zero_encrypt_lwe_ciphertext(&key, noise)?;
+    ///
+    /// engine.discard_load_lwe_ciphertext(
+    ///     &mut ciphertext,
+    ///     &ciphertext_vector,
+    ///     LweCiphertextIndex(5),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_load_lwe_ciphertext(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingLoadingError<Self::EngineError>> {
+        if ciphertext.lwe_dimension() != vector.lwe_dimension() {
+            return Err(LweCiphertextDiscardingLoadingError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingLoadingError::IndexTooLarge);
+        }
+        unsafe { self.discard_load_lwe_ciphertext_unchecked(ciphertext, vector, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_load_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: &mut LweCiphertext64,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) {
+        let lwe_size = vector.0.lwe_size().0;
+        ciphertext.0.as_mut_tensor().fill_with_copy(
+            &vector
+                .0
+                .as_tensor()
+                .get_sub(i.0 * lwe_size..(i.0 + 1) * lwe_size),
+        );
+    }
+}
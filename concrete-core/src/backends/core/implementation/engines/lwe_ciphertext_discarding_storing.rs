@@ -0,0 +1,156 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextDiscardingStoringEngine, LweCiphertextDiscardingStoringError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweCiphertextVectorEntity};
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextDiscardingStoringEngine<LweCiphertext32, LweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext32 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(18))?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(5),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(18));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut LweCiphertextVector32,
+        ciphertext: &LweCiphertext32,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        if vector.lwe_dimension() != ciphertext.lwe_dimension() {
+            return Err(LweCiphertextDiscardingStoringError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingStoringError::IndexTooLarge);
+        }
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut LweCiphertextVector32,
+        ciphertext: &LweCiphertext32,
+        i: LweCiphertextIndex,
+    ) {
+        let lwe_size = vector.0.lwe_size().0;
+        vector
+            .0
+            .as_mut_tensor()
+            .get_sub_mut(i.0 * lwe_size..(i.0 + 1) * lwe_size)
+            .fill_with_copy(ciphertext.0.as_tensor());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingStoringEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDiscardingStoringEngine<LweCiphertext64, LweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext(&input)?;
+    /// let ciphertext: LweCiphertext64 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// let mut ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&key, noise, LweCiphertextCount(18))?;
+    ///
+    /// engine.discard_store_lwe_ciphertext(
+    ///     &mut ciphertext_vector,
+    ///     &ciphertext,
+    ///     LweCiphertextIndex(5),
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(18));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_store_lwe_ciphertext(
+        &mut self,
+        vector: &mut LweCiphertextVector64,
+        ciphertext: &LweCiphertext64,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError<Self::EngineError>> {
+        if vector.lwe_dimension() != ciphertext.lwe_dimension() {
+            return Err(LweCiphertextDiscardingStoringError::LweDimensionMismatch);
+        }
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextDiscardingStoringError::IndexTooLarge);
+        }
+        unsafe { self.discard_store_lwe_ciphertext_unchecked(vector, ciphertext, i) };
+        Ok(())
+    }
+
+    unsafe fn discard_store_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &mut LweCiphertextVector64,
+        ciphertext: &LweCiphertext64,
+        i: LweCiphertextIndex,
+    ) {
+        let lwe_size = vector.0.lwe_size().0;
+        vector
+            .0
+            .as_mut_tensor()
+            .get_sub_mut(i.0 * lwe_size..(i.0 + 1) * lwe_size)
+            .fill_with_copy(ciphertext.0.as_tensor());
+    }
+}
@@ -0,0 +1,218 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierGgswCiphertext32, FourierGgswCiphertext64, LweCiphertext32, LweCiphertext64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingVerticalPackingEngine,
+    LweCiphertextDiscardingVerticalPackingError as VerticalPackingError,
+};
+use crate::specification::entities::{
+    GgswCiphertextEntity, LweCiphertextEntity, PlaintextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingVerticalPackingEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextDiscardingVerticalPackingEngine<
+        FourierGgswCiphertext32,
+        PlaintextVector32,
+        LweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (level, base_log) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // The index is encrypted bitwise, with more bits than log2(poly_size)
+    /// let (bit_count, index) = (9, 300_usize);
+    /// let lut_values: Vec<u32> = (0..1_u32 << bit_count).map(|i| i << 20).collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(LweDimension(poly_size.0))?;
+    /// let mut ggsw_inputs: Vec<FourierGgswCiphertext32> = Vec::new();
+    /// for i in (0..bit_count).rev() {
+    ///     let bit = engine.create_plaintext(&(((index >> i) & 1) as u32))?;
+    ///     let ggsw =
+    ///         engine.encrypt_scalar_ggsw_ciphertext(&glwe_sk, &bit, noise, level, base_log)?;
+    ///     ggsw_inputs.push(engine.convert_ggsw_ciphertext(&ggsw)?);
+    ///     engine.destroy(bit)?;
+    ///     engine.destroy(ggsw)?;
+    /// }
+    /// let lut = engine.create_plaintext_vector(&lut_values)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// engine.discard_vertical_packing_lwe_ciphertext(&mut output, &ggsw_inputs, &lut)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    ///
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(lwe_sk)?;
+    /// for ggsw in ggsw_inputs {
+    ///     engine.destroy(ggsw)?;
+    /// }
+    /// engine.destroy(lut)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext32,
+        ggsw_inputs: &[FourierGgswCiphertext32],
+        lut: &PlaintextVector32,
+    ) -> Result<(), VerticalPackingError<Self::EngineError>> {
+        let first_ggsw = ggsw_inputs
+            .first()
+            .ok_or(VerticalPackingError::EmptyInput)?;
+        if ggsw_inputs
+            .iter()
+            .any(|ggsw| ggsw.glwe_dimension() != first_ggsw.glwe_dimension())
+        {
+            return Err(VerticalPackingError::GlweDimensionMismatch);
+        }
+        if ggsw_inputs
+            .iter()
+            .any(|ggsw| ggsw.polynomial_size() != first_ggsw.polynomial_size())
+        {
+            return Err(VerticalPackingError::PolynomialSizeMismatch);
+        }
+        if output.lwe_dimension().0
+            != first_ggsw.glwe_dimension().0 * first_ggsw.polynomial_size().0
+        {
+            return Err(VerticalPackingError::OutputLweDimensionMismatch);
+        }
+        if Some(lut.plaintext_count().0) != 1usize.checked_shl(ggsw_inputs.len() as u32) {
+            return Err(VerticalPackingError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_vertical_packing_lwe_ciphertext_unchecked(output, ggsw_inputs, lut) };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext32,
+        ggsw_inputs: &[FourierGgswCiphertext32],
+        lut: &PlaintextVector32,
+    ) {
+        let ggsw_list: Vec<_> = ggsw_inputs.iter().map(|ggsw| &ggsw.0).collect();
+        output.0.fill_with_vertical_packing(&ggsw_list, &lut.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingVerticalPackingEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingVerticalPackingEngine<
+        FourierGgswCiphertext64,
+        PlaintextVector64,
+        LweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (glwe_dim, poly_size) = (GlweDimension(1), PolynomialSize(256));
+    /// let (level, base_log) = (DecompositionLevelCount(3), DecompositionBaseLog(7));
+    /// // The index is encrypted bitwise, with more bits than log2(poly_size)
+    /// let (bit_count, index) = (9, 300_usize);
+    /// let lut_values: Vec<u64> = (0..1_u64 << bit_count).map(|i| i << 52).collect();
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(poly_size.0))?;
+    /// let mut ggsw_inputs: Vec<FourierGgswCiphertext64> = Vec::new();
+    /// for i in (0..bit_count).rev() {
+    ///     let bit = engine.create_plaintext(&(((index >> i) & 1) as u64))?;
+    ///     let ggsw =
+    ///         engine.encrypt_scalar_ggsw_ciphertext(&glwe_sk, &bit, noise, level, base_log)?;
+    ///     ggsw_inputs.push(engine.convert_ggsw_ciphertext(&ggsw)?);
+    ///     engine.destroy(bit)?;
+    ///     engine.destroy(ggsw)?;
+    /// }
+    /// let lut = engine.create_plaintext_vector(&lut_values)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk, noise)?;
+    ///
+    /// engine.discard_vertical_packing_lwe_ciphertext(&mut output, &ggsw_inputs, &lut)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    ///
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(lwe_sk)?;
+    /// for ggsw in ggsw_inputs {
+    ///     engine.destroy(ggsw)?;
+    /// }
+    /// engine.destroy(lut)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertext64,
+        ggsw_inputs: &[FourierGgswCiphertext64],
+        lut: &PlaintextVector64,
+    ) -> Result<(), VerticalPackingError<Self::EngineError>> {
+        let first_ggsw = ggsw_inputs
+            .first()
+            .ok_or(VerticalPackingError::EmptyInput)?;
+        if ggsw_inputs
+            .iter()
+            .any(|ggsw| ggsw.glwe_dimension() != first_ggsw.glwe_dimension())
+        {
+            return Err(VerticalPackingError::GlweDimensionMismatch);
+        }
+        if ggsw_inputs
+            .iter()
+            .any(|ggsw| ggsw.polynomial_size() != first_ggsw.polynomial_size())
+        {
+            return Err(VerticalPackingError::PolynomialSizeMismatch);
+        }
+        if output.lwe_dimension().0
+            != first_ggsw.glwe_dimension().0 * first_ggsw.polynomial_size().0
+        {
+            return Err(VerticalPackingError::OutputLweDimensionMismatch);
+        }
+        if Some(lut.plaintext_count().0) != 1usize.checked_shl(ggsw_inputs.len() as u32) {
+            return Err(VerticalPackingError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_vertical_packing_lwe_ciphertext_unchecked(output, ggsw_inputs, lut) };
+        Ok(())
+    }
+
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertext64,
+        ggsw_inputs: &[FourierGgswCiphertext64],
+        lut: &PlaintextVector64,
+    ) {
+        let ggsw_list: Vec<_> = ggsw_inputs.iter().map(|ggsw| &ggsw.0).collect();
+        output.0.fill_with_vertical_packing(&ggsw_list, &lut.0);
+    }
+}
@@ -2,7 +2,10 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+    LweCiphertext32, LweCiphertext64, LweGaussianCiphertext32, LweGaussianCiphertext64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernaryCiphertext32, LweTernaryCiphertext64, LweTernarySecretKey32, LweTernarySecretKey64,
+    Plaintext32, Plaintext64,
 };
 use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
 use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
@@ -125,3 +128,247 @@ impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, LweCiphertext64>
         LweCiphertext64(ciphertext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and ternary keys.
+impl LweCiphertextEncryptionEngine<LweTernarySecretKey32, Plaintext32, LweTernaryCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<LweTernaryCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> LweTernaryCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and ternary keys.
+impl LweCiphertextEncryptionEngine<LweTernarySecretKey64, Plaintext64, LweTernaryCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<LweTernaryCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> LweTernaryCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweTernaryCiphertext64(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and gaussian keys.
+impl LweCiphertextEncryptionEngine<LweGaussianSecretKey32, Plaintext32, LweGaussianCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> Result<LweGaussianCiphertext32, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &Plaintext32,
+        noise: Variance,
+    ) -> LweGaussianCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweGaussianCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and gaussian keys.
+impl LweCiphertextEncryptionEngine<LweGaussianSecretKey64, Plaintext64, LweGaussianCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<LweGaussianCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> LweGaussianCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0.encrypt_lwe(
+            &mut ciphertext,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweGaussianCiphertext64(ciphertext)
+    }
+}
@@ -1,5 +1,7 @@
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{LweCiphertext32, LweCiphertext64};
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextMutView32, LweCiphertextMutView64,
+};
 use crate::specification::engines::{
     LweCiphertextFusingNegationEngine, LweCiphertextFusingNegationError,
 };
@@ -97,3 +99,105 @@ impl LweCiphertextFusingNegationEngine<LweCiphertext64> for CoreEngine {
         input.0.update_with_neg();
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextFusingNegationEngine<LweCiphertextMutView32<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView32 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext_view)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut LweCiphertextMutView32<'a>,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(&mut self, input: &mut LweCiphertextMutView32<'a>) {
+        input.0.update_with_neg();
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a> LweCiphertextFusingNegationEngine<LweCiphertextMutView64<'a>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertext is stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut container[..])?;
+    /// engine.discard_encrypt_lwe_ciphertext(&key, &mut ciphertext_view, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext_view)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut LweCiphertextMutView64<'a>,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        unsafe { self.fuse_neg_lwe_ciphertext_unchecked(input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(&mut self, input: &mut LweCiphertextMutView64<'a>) {
+        input.0.update_with_neg();
+    }
+}
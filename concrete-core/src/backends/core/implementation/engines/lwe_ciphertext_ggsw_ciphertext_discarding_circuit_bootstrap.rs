@@ -0,0 +1,240 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64, FourierLweBootstrapKey32,
+    FourierLweBootstrapKey64, GgswCiphertext32, GgswCiphertext64, LweCiphertext32, LweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine,
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError as CircuitBootstrapError,
+};
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, GgswCiphertextEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine`] for
+/// [`CoreEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+        FourierLweBootstrapKey32,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+        LweCiphertext32,
+        GgswCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (pfpksk_lc, pfpksk_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let (ggsw_lc, ggsw_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(4));
+    /// // The input bit is encoded in the most significant bit
+    /// let input = 1_u32 << (32 - 1);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let ggsw_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let cbs_pfpksk: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &glwe_sk, &ggsw_sk, pfpksk_lc, pfpksk_bl, noise,
+    ///     )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u32)?;
+    /// let mut output: GgswCiphertext32 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     ggsw_lc,
+    ///     ggsw_bl,
+    ///     &zero,
+    /// )?;
+    ///
+    /// engine.discard_circuit_bootstrap_lwe_ciphertext(&mut output, &input, &bsk, &cbs_pfpksk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(ggsw_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut GgswCiphertext32,
+        input: &LweCiphertext32,
+        bsk: &FourierLweBootstrapKey32,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) -> Result<(), CircuitBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::InputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::KeysInputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(CircuitBootstrapError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(CircuitBootstrapError::OutputPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != output.glwe_dimension().to_glwe_size().0 {
+            return Err(CircuitBootstrapError::KeyCountMismatch);
+        }
+        if output.decomposition_base_log().0 * output.decomposition_level_count().0 >= 32 {
+            return Err(CircuitBootstrapError::DecompositionTooLarge);
+        }
+        unsafe {
+            self.discard_circuit_bootstrap_lwe_ciphertext_unchecked(output, input, bsk, cbs_pfpksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GgswCiphertext32,
+        input: &LweCiphertext32,
+        bsk: &FourierLweBootstrapKey32,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32,
+    ) {
+        bsk.0
+            .circuit_bootstrap(&mut output.0, &input.0, &cbs_pfpksk.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+        FourierLweBootstrapKey64,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+        LweCiphertext64,
+        GgswCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(1), PolynomialSize(256));
+    /// let (bsk_lc, bsk_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let (pfpksk_lc, pfpksk_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(8));
+    /// let (ggsw_lc, ggsw_bl) = (DecompositionLevelCount(2), DecompositionBaseLog(4));
+    /// // The input bit is encoded in the most significant bit
+    /// let input = 1_u64 << (64 - 1);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let ggsw_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, bsk_bl, bsk_lc, noise)?;
+    /// let cbs_pfpksk: CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 = engine
+    ///     .create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+    ///         &glwe_sk, &ggsw_sk, pfpksk_lc, pfpksk_bl, noise,
+    ///     )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let zero = engine.create_plaintext(&0_u64)?;
+    /// let mut output: GgswCiphertext64 = engine.trivially_encrypt_scalar_ggsw_ciphertext(
+    ///     poly_size,
+    ///     glwe_dim.to_glwe_size(),
+    ///     ggsw_lc,
+    ///     ggsw_bl,
+    ///     &zero,
+    /// )?;
+    ///
+    /// engine.discard_circuit_bootstrap_lwe_ciphertext(&mut output, &input, &bsk, &cbs_pfpksk)?;
+    /// #
+    /// assert_eq!(output.glwe_dimension(), glwe_dim);
+    /// assert_eq!(output.polynomial_size(), poly_size);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(ggsw_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(cbs_pfpksk)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(zero)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_circuit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut GgswCiphertext64,
+        input: &LweCiphertext64,
+        bsk: &FourierLweBootstrapKey64,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) -> Result<(), CircuitBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::InputLweDimensionMismatch);
+        }
+        if bsk.output_lwe_dimension() != cbs_pfpksk.input_lwe_dimension() {
+            return Err(CircuitBootstrapError::KeysInputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != cbs_pfpksk.output_glwe_dimension() {
+            return Err(CircuitBootstrapError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != cbs_pfpksk.output_polynomial_size() {
+            return Err(CircuitBootstrapError::OutputPolynomialSizeMismatch);
+        }
+        if cbs_pfpksk.key_count().0 != output.glwe_dimension().to_glwe_size().0 {
+            return Err(CircuitBootstrapError::KeyCountMismatch);
+        }
+        if output.decomposition_base_log().0 * output.decomposition_level_count().0 >= 64 {
+            return Err(CircuitBootstrapError::DecompositionTooLarge);
+        }
+        unsafe {
+            self.discard_circuit_bootstrap_lwe_ciphertext_unchecked(output, input, bsk, cbs_pfpksk)
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut GgswCiphertext64,
+        input: &LweCiphertext64,
+        bsk: &FourierLweBootstrapKey64,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64,
+    ) {
+        bsk.0
+            .circuit_bootstrap(&mut output.0, &input.0, &cbs_pfpksk.0);
+    }
+}
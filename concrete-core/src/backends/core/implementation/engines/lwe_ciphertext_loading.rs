@@ -0,0 +1,137 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{LweCiphertextLoadingEngine, LweCiphertextLoadingError};
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextIndex;
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers.
+impl LweCiphertextLoadingEngine<LweCiphertextVector32, LweCiphertext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext32 =
+    ///     engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(5))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext32, LweCiphertextLoadingError<Self::EngineError>> {
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextLoadingError::IndexTooLarge);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext32 {
+        let lwe_size = vector.0.lwe_size().0;
+        let container = vector
+            .0
+            .as_tensor()
+            .get_sub(i.0 * lwe_size..(i.0 + 1) * lwe_size)
+            .into_container()
+            .to_vec();
+        LweCiphertext32(ImplLweCiphertext::from_container(container))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextLoadingEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers.
+impl LweCiphertextLoadingEngine<LweCiphertextVector64, LweCiphertext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextIndex, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext: LweCiphertext64 =
+    ///     engine.load_lwe_ciphertext(&ciphertext_vector, LweCiphertextIndex(5))?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> Result<LweCiphertext64, LweCiphertextLoadingError<Self::EngineError>> {
+        if i.0 >= vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextLoadingError::IndexTooLarge);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_unchecked(vector, i) })
+    }
+
+    unsafe fn load_lwe_ciphertext_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        i: LweCiphertextIndex,
+    ) -> LweCiphertext64 {
+        let lwe_size = vector.0.lwe_size().0;
+        let container = vector
+            .0
+            .as_tensor()
+            .get_sub(i.0 * lwe_size..(i.0 + 1) * lwe_size)
+            .into_container()
+            .to_vec();
+        LweCiphertext64(ImplLweCiphertext::from_container(container))
+    }
+}
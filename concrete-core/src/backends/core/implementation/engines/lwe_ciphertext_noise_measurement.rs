@@ -0,0 +1,133 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LweSecretKey32, LweSecretKey64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{
+    LweCiphertextNoiseMeasurementEngine, LweCiphertextNoiseMeasurementError, NoiseMeasurement,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey32, LweCiphertext32, Plaintext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measurement = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(measurement.precision, 32);
+    /// assert!(measurement.bit_size < 25);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextNoiseMeasurementError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertext32,
+        expected: &Plaintext32,
+    ) -> NoiseMeasurement {
+        let mut plaintext = ImplPlaintext(0u32);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        let error = plaintext.0.wrapping_sub(expected.0 .0);
+        NoiseMeasurement::from_error(error as i32 as i64, 32)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextNoiseMeasurementEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextNoiseMeasurementEngine<LweSecretKey64, LweCiphertext64, Plaintext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let measurement = engine.measure_lwe_ciphertext_noise(&key, &ciphertext, &plaintext)?;
+    /// #
+    /// assert_eq!(measurement.precision, 64);
+    /// assert!(measurement.bit_size < 45);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>> {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextNoiseMeasurementError::LweDimensionMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertext64,
+        expected: &Plaintext64,
+    ) -> NoiseMeasurement {
+        let mut plaintext = ImplPlaintext(0u64);
+        key.0.decrypt_lwe(&mut plaintext, &input.0);
+        let error = plaintext.0.wrapping_sub(expected.0 .0);
+        NoiseMeasurement::from_error(error as i64, 64)
+    }
+}
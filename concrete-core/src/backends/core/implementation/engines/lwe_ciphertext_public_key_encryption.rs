@@ -0,0 +1,127 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertext32, LweCiphertext64, LwePublicKey32, LwePublicKey64, Plaintext32, Plaintext64,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
+use crate::specification::engines::{
+    LweCiphertextPublicKeyEncryptionEngine, LweCiphertextPublicKeyEncryptionError,
+};
+use crate::specification::entities::LwePublicKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl LweCiphertextPublicKeyEncryptionEngine<LwePublicKey32, Plaintext32, LweCiphertext32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey32 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &Plaintext32,
+    ) -> Result<LweCiphertext32, LweCiphertextPublicKeyEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey32,
+        input: &Plaintext32,
+    ) -> LweCiphertext32 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u32, key.lwe_dimension().to_lwe_size());
+        key.0
+            .encrypt_lwe(&mut ciphertext, &input.0, &mut self.secret_generator);
+        LweCiphertext32(ciphertext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextPublicKeyEncryptionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextPublicKeyEncryptionEngine<LwePublicKey64, Plaintext64, LweCiphertext64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let public_key: LwePublicKey64 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext_with_public_key(&public_key, &plaintext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &Plaintext64,
+    ) -> Result<LweCiphertext64, LweCiphertextPublicKeyEncryptionError<Self::EngineError>> {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_with_public_key_unchecked(key, input) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &LwePublicKey64,
+        input: &Plaintext64,
+    ) -> LweCiphertext64 {
+        let mut ciphertext = ImplLweCiphertext::allocate(0u64, key.lwe_dimension().to_lwe_size());
+        key.0
+            .encrypt_lwe(&mut ciphertext, &input.0, &mut self.secret_generator);
+        LweCiphertext64(ciphertext)
+    }
+}
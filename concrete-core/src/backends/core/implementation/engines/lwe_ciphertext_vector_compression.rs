@@ -0,0 +1,85 @@
+use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector64, LweCompressedCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweCompressedList as ImplLweCompressedList;
+use crate::specification::engines::{
+    LweCiphertextVectorCompressionEngine, LweCiphertextVectorCompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCompressionEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers, and stores the compressed coefficients on 32 bits.
+impl LweCiphertextVectorCompressionEngine<LweCiphertextVector64, LweCompressedCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertexts are switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let compressed: LweCompressedCiphertextVector64 =
+    ///     engine.compress_lwe_ciphertext_vector(&ciphertext_vector, modulus_log)?;
+    /// #
+    /// assert_eq!(compressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(compressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    /// assert_eq!(compressed.modulus_log(), modulus_log);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(compressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn compress_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector64,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<
+        LweCompressedCiphertextVector64,
+        LweCiphertextVectorCompressionError<Self::EngineError>,
+    > {
+        if modulus_log.0 == 0 {
+            return Err(LweCiphertextVectorCompressionError::NullModulusLog);
+        }
+        if modulus_log.0 > 32 {
+            return Err(LweCiphertextVectorCompressionError::ModulusLogTooLarge);
+        }
+        Ok(unsafe { self.compress_lwe_ciphertext_vector_unchecked(input, modulus_log) })
+    }
+
+    unsafe fn compress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector64,
+        modulus_log: CiphertextModulusLog,
+    ) -> LweCompressedCiphertextVector64 {
+        let mut compressed = ImplLweCompressedList::allocate(
+            0u32,
+            input.0.lwe_size(),
+            CiphertextCount(input.0.count().0),
+            modulus_log,
+        );
+        compressed.fill_with_modulus_switch(&input.0);
+        LweCompressedCiphertextVector64(compressed)
+    }
+}
@@ -0,0 +1,136 @@
+use concrete_commons::parameters::CiphertextCount;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorConversionEngine, LweCiphertextVectorConversionError,
+    LweCiphertextVectorDiscardingConversionEngine,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 32
+/// bits LWE ciphertext vector to a 64 bits LWE ciphertext vector. The torus elements are exactly
+/// rescaled to the output precision.
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector32, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: LweCiphertextVector64 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector32,
+    ) -> LweCiphertextVector64 {
+        let mut output = LweCiphertextVector64(ImplLweList::allocate(
+            0_u64,
+            input.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.lwe_ciphertext_count().0),
+        ));
+        self.discard_convert_lwe_ciphertext_vector_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorConversionEngine`] for [`CoreEngine`] that converts a 64
+/// bits LWE ciphertext vector to a 32 bits LWE ciphertext vector. The torus elements are rounded to
+/// the closest element of the output precision.
+impl LweCiphertextVectorConversionEngine<LweCiphertextVector64, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let output: LweCiphertextVector32 =
+    ///     engine.convert_lwe_ciphertext_vector(&ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCiphertextVector64,
+    ) -> LweCiphertextVector32 {
+        let mut output = LweCiphertextVector32(ImplLweList::allocate(
+            0_u32,
+            input.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.lwe_ciphertext_count().0),
+        ));
+        self.discard_convert_lwe_ciphertext_vector_unchecked(&mut output, input);
+        output
+    }
+}
@@ -0,0 +1,352 @@
+use super::super::super::private::crypto::lwe::LweList as ImplLweList;
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweCiphertextVectorView32, LweCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorCreationEngine, LweCiphertextVectorCreationError,
+};
+use concrete_commons::parameters::LweSize;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns the
+/// view of an LWE ciphertext vector, borrowing an immutable slice of 32 bits integers.
+impl<'a> LweCiphertextVectorCreationEngine<&'a [u32], LweCiphertextVectorView32<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a [u32],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView32<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a [u32],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView32<'a> {
+        LweCiphertextVectorView32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns the
+/// view of an LWE ciphertext vector, borrowing a mutable slice of 32 bits integers.
+impl<'a> LweCiphertextVectorCreationEngine<&'a mut [u32], LweCiphertextVectorMutView32<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let mut container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a mut [u32],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorMutView32<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a mut [u32],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorMutView32<'a> {
+        LweCiphertextVectorMutView32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns the
+/// view of an LWE ciphertext vector, borrowing an immutable slice of 64 bits integers.
+impl<'a> LweCiphertextVectorCreationEngine<&'a [u64], LweCiphertextVectorView64<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a [u64],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorView64<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a [u64],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorView64<'a> {
+        LweCiphertextVectorView64(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns the
+/// view of an LWE ciphertext vector, borrowing a mutable slice of 64 bits integers.
+impl<'a> LweCiphertextVectorCreationEngine<&'a mut [u64], LweCiphertextVectorMutView64<'a>>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // The scalars are stored in a buffer which is not owned by the entity
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let mut container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_view: LweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_size)?;
+    /// #
+    /// assert_eq!(ciphertext_view.lwe_dimension(), lwe_size.to_lwe_dimension());
+    /// assert_eq!(ciphertext_view.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: &'a mut [u64],
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVectorMutView64<'a>, LweCiphertextVectorCreationError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: &'a mut [u64],
+        lwe_size: LweSize,
+    ) -> LweCiphertextVectorMutView64<'a> {
+        LweCiphertextVectorMutView64(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an LWE
+/// ciphertext vector, taking ownership of a vector of 32 bits integers.
+impl LweCiphertextVectorCreationEngine<Vec<u32>, LweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u32; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_dimension(),
+    ///     lwe_size.to_lwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVector32 {
+        LweCiphertextVector32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorCreationEngine`] for [`CoreEngine`] which returns an LWE
+/// ciphertext vector, taking ownership of a vector of 64 bits integers.
+impl LweCiphertextVectorCreationEngine<Vec<u64>, LweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here we create a container outside of the engine
+    /// let lwe_size = LweSize(16);
+    /// let lwe_count = LweCiphertextCount(3);
+    /// let container = vec![0_u64; lwe_size.0 * lwe_count.0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.create_lwe_ciphertext_vector(container, lwe_size)?;
+    /// #
+    /// assert_eq!(
+    ///     ciphertext_vector.lwe_dimension(),
+    ///     lwe_size.to_lwe_dimension()
+    /// );
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), lwe_count);
+    ///
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorCreationError<Self::EngineError>> {
+        if container.is_empty() {
+            return Err(LweCiphertextVectorCreationError::EmptyContainer);
+        }
+        if lwe_size.0 == 0 {
+            return Err(LweCiphertextVectorCreationError::NullLweSize);
+        }
+        if container.len() % lwe_size.0 != 0 {
+            return Err(LweCiphertextVectorCreationError::InvalidContainerSize);
+        }
+        Ok(unsafe { self.create_lwe_ciphertext_vector_unchecked(container, lwe_size) })
+    }
+
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        lwe_size: LweSize,
+    ) -> LweCiphertextVector64 {
+        LweCiphertextVector64(ImplLweList::from_container(container, lwe_size))
+    }
+}
@@ -0,0 +1,70 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector64, LweCompressedCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweCiphertextVectorDecompressionEngine, LweCiphertextVectorDecompressionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecompressionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl LweCiphertextVectorDecompressionEngine<LweCompressedCiphertextVector64, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    /// // The ciphertexts are switched to the 2^20 modulus
+    /// let modulus_log = CiphertextModulusLog(20);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let compressed: LweCompressedCiphertextVector64 =
+    ///     engine.compress_lwe_ciphertext_vector(&ciphertext_vector, modulus_log)?;
+    ///
+    /// let decompressed: LweCiphertextVector64 =
+    ///     engine.decompress_lwe_ciphertext_vector(&compressed)?;
+    /// #
+    /// assert_eq!(decompressed.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(decompressed.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(compressed)?;
+    /// engine.destroy(decompressed)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decompress_lwe_ciphertext_vector(
+        &mut self,
+        input: &LweCompressedCiphertextVector64,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorDecompressionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.decompress_lwe_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn decompress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweCompressedCiphertextVector64,
+    ) -> LweCiphertextVector64 {
+        let mut ciphertexts = ImplLweList::allocate(0u64, input.0.lwe_size(), input.0.count());
+        input.0.lift_into(&mut ciphertexts);
+        LweCiphertextVector64(ciphertexts)
+    }
+}
@@ -2,8 +2,11 @@ use concrete_commons::parameters::PlaintextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64, LweGaussianCiphertextVector32, LweGaussianCiphertextVector64,
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernaryCiphertextVector32, LweTernaryCiphertextVector64, LweTernarySecretKey32,
+    LweTernarySecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
 use crate::specification::engines::{
@@ -134,3 +137,421 @@ impl LweCiphertextVectorDecryptionEngine<LweSecretKey64, LweCiphertextVector64,
         PlaintextVector64(plaintext)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers and ternary keys.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        LweTernarySecretKey32,
+        LweTernaryCiphertextVector32,
+        PlaintextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweTernaryCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &LweTernaryCiphertextVector32,
+    ) -> PlaintextVector32 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers and ternary keys.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        LweTernarySecretKey64,
+        LweTernaryCiphertextVector64,
+        PlaintextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweTernaryCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &LweTernaryCiphertextVector64,
+    ) -> PlaintextVector64 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers and gaussian keys.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        LweGaussianSecretKey32,
+        LweGaussianCiphertextVector32,
+        PlaintextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweGaussianCiphertextVector32,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &LweGaussianCiphertextVector32,
+    ) -> PlaintextVector32 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers and gaussian keys.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        LweGaussianSecretKey64,
+        LweGaussianCiphertextVector64,
+        PlaintextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    ///
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(18)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweGaussianCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &LweGaussianCiphertextVector64,
+    ) -> PlaintextVector64 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector64(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDecryptionEngine<
+        LweSecretKey32,
+        LweCiphertextVectorView32<'a>,
+        PlaintextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: LweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// let ciphertext_vector_view: LweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector_view)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVectorView32<'a>,
+    ) -> Result<PlaintextVector32, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVectorView32<'a>,
+    ) -> PlaintextVector32 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector32(plaintext)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDecryptionEngine<
+        LweSecretKey64,
+        LweCiphertextVectorView64<'a>,
+        PlaintextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: LweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// let ciphertext_vector_view: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector_view)?;
+    /// #
+    /// assert_eq!(
+    ///     decrypted_plaintext_vector.plaintext_count(),
+    ///     PlaintextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVectorView64<'a>,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVectorView64<'a>,
+    ) -> PlaintextVector64 {
+        let mut plaintext =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintext, &input.0);
+        PlaintextVector64(plaintext)
+    }
+}
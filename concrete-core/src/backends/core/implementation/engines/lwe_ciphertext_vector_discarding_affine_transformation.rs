@@ -1,7 +1,8 @@
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    CleartextVector32, CleartextVector64, LweCiphertext32, LweCiphertext64, LweCiphertextVector32,
-    LweCiphertextVector64, Plaintext32, Plaintext64,
+    CleartextVector32, CleartextVector64, LweCiphertext32, LweCiphertext64, LweCiphertextMutView64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView64, Plaintext32, Plaintext64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingAffineTransformationEngine,
@@ -194,3 +195,214 @@ impl
             .fill_with_multisum_with_bias(&inputs.0, &weights.0, &bias.0);
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers, on views of ciphertexts stored in
+/// borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        LweCiphertextVectorView64<'a>,
+        CleartextVector64,
+        Plaintext64,
+        LweCiphertextMutView64<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let mut input_view: LweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(&mut input_container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dimension.to_lwe_size())?;
+    /// let mut output_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        inputs: &LweCiphertextVectorView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        inputs: &LweCiphertextVectorView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        output
+            .0
+            .fill_with_multisum_with_bias(&inputs.0, &weights.0, &bias.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`CoreEngine`] that operates on 64 bits integers, on mutable views of ciphertexts stored in
+/// borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        LweCiphertextVectorMutView64<'a>,
+        CleartextVector64,
+        Plaintext64,
+        LweCiphertextMutView64<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_vector = vec![3_u64 << 50; 8];
+    /// let weights_input = vec![2_u64; 8];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input_vector.len()];
+    /// let mut output_container = vec![0_u64; lwe_dimension.to_lwe_size().0];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input_vector)?;
+    /// let mut input_view: LweCiphertextVectorMutView64 = engine
+    ///     .create_lwe_ciphertext_vector(&mut input_container[..], lwe_dimension.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// let mut output_view: LweCiphertextMutView64 =
+    ///     engine.create_lwe_ciphertext(&mut output_container[..])?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(weights)?;
+    /// engine.destroy(bias)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        inputs: &LweCiphertextVectorMutView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextMutView64<'b>,
+        inputs: &LweCiphertextVectorMutView64<'a>,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        output
+            .0
+            .fill_with_multisum_with_bias(&inputs.0, &weights.0, &bias.0);
+    }
+}
@@ -0,0 +1,507 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, GlweCiphertextVector32,
+    GlweCiphertextVector64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, LweBootstrapKeyEntity, LweCiphertextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// When the `multithread` feature is activated, the ciphertexts are bootstrapped in parallel.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FourierLweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        bsk.0.par_bootstrap_list(&mut output.0, &input.0, &acc.0);
+        #[cfg(not(feature = "multithread"))]
+        bsk.0.bootstrap_list(&mut output.0, &input.0, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// When the `multithread` feature is activated, the ciphertexts are bootstrapped in parallel.
+impl
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FourierLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext_vector(&lwe_sk, &plaintext_vector, noise)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        bsk.0.par_bootstrap_list(&mut output.0, &input.0, &acc.0);
+        #[cfg(not(feature = "multithread"))]
+        bsk.0.bootstrap_list(&mut output.0, &input.0, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FourierLweBootstrapKey32,
+        GlweCiphertextVector32,
+        LweCiphertextVectorView32<'a>,
+        LweCiphertextVectorMutView32<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u32 << 20; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; lwe_dim.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u32; lwe_dim_output.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let mut input: LweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut input_container[..], lwe_dim.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk,
+    ///     &mut input,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input)?;
+    /// let input: LweCiphertextVectorView32 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dim.to_lwe_size())?;
+    /// let mut output: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector(
+    ///     &mut output_container[..],
+    ///     lwe_dim_output.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView32<'b>,
+        input: &LweCiphertextVectorView32<'a>,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView32<'b>,
+        input: &LweCiphertextVectorView32<'a>,
+        acc: &GlweCiphertextVector32,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        bsk.0.par_bootstrap_list(&mut output.0, &input.0, &acc.0);
+        #[cfg(not(feature = "multithread"))]
+        bsk.0.bootstrap_list(&mut output.0, &input.0, &acc.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingBootstrapEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        FourierLweBootstrapKey64,
+        GlweCiphertextVector64,
+        LweCiphertextVectorView64<'a>,
+        LweCiphertextVectorMutView64<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap, with a single shared accumulator
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; lwe_dim.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u64; lwe_dim_output.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let lut_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext_vector(&glwe_sk, &lut_vector, noise)?;
+    /// let mut input: LweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut input_container[..], lwe_dim.to_lwe_size())?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &lwe_sk,
+    ///     &mut input,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input)?;
+    /// let input: LweCiphertextVectorView64 =
+    ///     engine.create_lwe_ciphertext_vector(&input_container[..], lwe_dim.to_lwe_size())?;
+    /// let mut output: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector(
+    ///     &mut output_container[..],
+    ///     lwe_dim_output.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext_vector(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(lut_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64<'b>,
+        input: &LweCiphertextVectorView64<'a>,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch,
+            );
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingBootstrapError::AccumulatorGlweDimensionMismatch,
+            );
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        if acc.glwe_ciphertext_count().0 != 1
+            && acc.glwe_ciphertext_count().0 != input.lwe_ciphertext_count().0
+        {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::AccumulatorCountMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingBootstrapError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_bootstrap_lwe_ciphertext_vector_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64<'b>,
+        input: &LweCiphertextVectorView64<'a>,
+        acc: &GlweCiphertextVector64,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        bsk.0.par_bootstrap_list(&mut output.0, &input.0, &acc.0);
+        #[cfg(not(feature = "multithread"))]
+        bsk.0.bootstrap_list(&mut output.0, &input.0, &acc.0);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingConversionEngine, LweCiphertextVectorDiscardingConversionError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingConversionEngine`] for [`CoreEngine`] that
+/// converts a 32 bits LWE ciphertext vector to a 64 bits LWE ciphertext vector. The torus elements
+/// are exactly rescaled to the output precision.
+impl LweCiphertextVectorDiscardingConversionEngine<LweCiphertextVector32, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector32,
+    ) -> Result<(), LweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingConversionError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingConversionEngine`] for [`CoreEngine`] that
+/// converts a 64 bits LWE ciphertext vector to a 32 bits LWE ciphertext vector. The torus elements
+/// are rounded to the closest element of the output precision.
+impl LweCiphertextVectorDiscardingConversionEngine<LweCiphertextVector64, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_convert_lwe_ciphertext_vector(&mut output, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(output.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector64,
+    ) -> Result<(), LweCiphertextVectorDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingConversionError::LweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingConversionError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_ciphertext_vector_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
@@ -2,8 +2,9 @@ use concrete_commons::dispersion::Variance;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweCiphertextVectorMutView32,
+    LweCiphertextVectorMutView64, LweSecretKey32, LweSecretKey64, PlaintextVector32,
+    PlaintextVector64,
 };
 use crate::specification::engines::{
     LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
@@ -177,3 +178,173 @@ impl
         );
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey32,
+        PlaintextVector32,
+        LweCiphertextVectorMutView32<'a>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u32; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: LweCiphertextVectorMutView32 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector_view.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVectorMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        output: &mut LweCiphertextVectorMutView32<'a>,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) {
+        key.0.encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingEncryptionEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a>
+    LweCiphertextVectorDiscardingEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        LweCiphertextVectorMutView64<'a>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The ciphertexts are stored in a buffer which is not owned by the entity
+    /// let mut container = vec![0_u64; lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut ciphertext_vector_view: LweCiphertextVectorMutView64 =
+    ///     engine.create_lwe_ciphertext_vector(&mut container[..], lwe_dimension.to_lwe_size())?;
+    ///
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &key,
+    ///     &mut ciphertext_vector_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(ciphertext_vector_view.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     ciphertext_vector_view.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVectorMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError<Self::EngineError>> {
+        if key.lwe_dimension() != output.lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::LweDimensionMismatch);
+        }
+        if input.plaintext_count().0 != output.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorDiscardingEncryptionError::PlaintextCountMismatch);
+        }
+        unsafe { self.discard_encrypt_lwe_ciphertext_vector_unchecked(key, output, input, noise) };
+        Ok(())
+    }
+
+    unsafe fn discard_encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        output: &mut LweCiphertextVectorMutView64<'a>,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) {
+        key.0.encrypt_lwe_list(
+            &mut output.0,
+            &input.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+    }
+}
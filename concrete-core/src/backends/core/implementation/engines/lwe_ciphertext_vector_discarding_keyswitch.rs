@@ -0,0 +1,861 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweBinaryToGaussianKeyswitchKey32, LweBinaryToGaussianKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32, LweBinaryToTernaryKeyswitchKey64, LweCiphertextVector32,
+    LweCiphertextVector64, LweCiphertextVectorMutView32, LweCiphertextVectorMutView64,
+    LweCiphertextVectorView32, LweCiphertextVectorView64, LweGaussianCiphertextVector32,
+    LweGaussianCiphertextVector64, LweKeyswitchKey32, LweKeyswitchKey64,
+    LweTernaryCiphertextVector32, LweTernaryCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingKeyswitchEngine, LweCiphertextVectorDiscardingKeyswitchError,
+};
+use crate::specification::entities::{LweCiphertextVectorEntity, LweKeyswitchKeyEntity};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey32,
+        LweCiphertextVector32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey64,
+        LweCiphertextVector64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext_vector =
+    ///     engine.zero_encrypt_lwe_ciphertext_vector(&output_key, noise, LweCiphertextCount(3))?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers. It switches ciphertexts encrypted under a binary key to
+/// ciphertexts encrypted under a ternary key.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweBinaryToTernaryKeyswitchKey32,
+        LweCiphertextVector32,
+        LweTernaryCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; 3])?;
+    /// let mut output_ciphertext_vector: LweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweTernaryCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweBinaryToTernaryKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweTernaryCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweBinaryToTernaryKeyswitchKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers. It switches ciphertexts encrypted under a binary key to
+/// ciphertexts encrypted under a ternary key.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweBinaryToTernaryKeyswitchKey64,
+        LweCiphertextVector64,
+        LweTernaryCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; 3])?;
+    /// let mut output_ciphertext_vector: LweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweTernaryCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweBinaryToTernaryKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweTernaryCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweBinaryToTernaryKeyswitchKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers. It switches ciphertexts encrypted under a binary key to
+/// ciphertexts encrypted under a gaussian key.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweBinaryToGaussianKeyswitchKey32,
+        LweCiphertextVector32,
+        LweGaussianCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u32; 3])?;
+    /// let mut output_ciphertext_vector: LweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweGaussianCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweBinaryToGaussianKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweGaussianCiphertextVector32,
+        input: &LweCiphertextVector32,
+        ksk: &LweBinaryToGaussianKeyswitchKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers. It switches ciphertexts encrypted under a binary key to
+/// ciphertexts encrypted under a gaussian key.
+///
+/// When the `multithread` feature is activated, the ciphertexts are keyswitched in parallel.
+impl
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweBinaryToGaussianKeyswitchKey64,
+        LweCiphertextVector64,
+        LweGaussianCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let zeros = engine.create_plaintext_vector(&vec![0_u64; 3])?;
+    /// let mut output_ciphertext_vector: LweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&output_key, &zeros, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext_vector,
+    ///     &ciphertext_vector,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext_vector.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(
+    ///     output_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(3)
+    /// );
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(zeros)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweGaussianCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweBinaryToGaussianKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweGaussianCiphertextVector64,
+        input: &LweCiphertextVector64,
+        ksk: &LweBinaryToGaussianKeyswitchKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey32,
+        LweCiphertextVectorView32<'a>,
+        LweCiphertextVectorMutView32<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u32; input_lwe_dimension.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u32; output_lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut input_view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector(
+    ///     &mut input_container[..],
+    ///     input_lwe_dimension.to_lwe_size(),
+    /// )?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &input_key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: LweCiphertextVectorView32 = engine.create_lwe_ciphertext_vector(
+    ///     &input_container[..],
+    ///     input_lwe_dimension.to_lwe_size(),
+    /// )?;
+    /// let mut output_view: LweCiphertextVectorMutView32 = engine.create_lwe_ciphertext_vector(
+    ///     &mut output_container[..],
+    ///     output_lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output_view.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView32<'b>,
+        input: &LweCiphertextVectorView32<'a>,
+        ksk: &LweKeyswitchKey32,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView32<'b>,
+        input: &LweCiphertextVectorView32<'a>,
+        ksk: &LweKeyswitchKey32,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingKeyswitchEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers, on views of ciphertexts stored in borrowed slices.
+impl<'a, 'b>
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        LweKeyswitchKey64,
+        LweCiphertextVectorView64<'a>,
+        LweCiphertextVectorMutView64<'b>,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweCiphertextCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// // The ciphertexts are stored in buffers which are not owned by the entities
+    /// let mut input_container = vec![0_u64; input_lwe_dimension.to_lwe_size().0 * input.len()];
+    /// let mut output_container = vec![0_u64; output_lwe_dimension.to_lwe_size().0 * input.len()];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let mut input_view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector(
+    ///     &mut input_container[..],
+    ///     input_lwe_dimension.to_lwe_size(),
+    /// )?;
+    /// engine.discard_encrypt_lwe_ciphertext_vector(
+    ///     &input_key,
+    ///     &mut input_view,
+    ///     &plaintext_vector,
+    ///     noise,
+    /// )?;
+    /// engine.destroy(input_view)?;
+    /// let input_view: LweCiphertextVectorView64 = engine.create_lwe_ciphertext_vector(
+    ///     &input_container[..],
+    ///     input_lwe_dimension.to_lwe_size(),
+    /// )?;
+    /// let mut output_view: LweCiphertextVectorMutView64 = engine.create_lwe_ciphertext_vector(
+    ///     &mut output_container[..],
+    ///     output_lwe_dimension.to_lwe_size(),
+    /// )?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_view,
+    ///     &input_view,
+    ///     &keyswitch_key,
+    /// )?;
+    /// #
+    /// assert_eq!(output_view.lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output_view.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(input_view)?;
+    /// engine.destroy(output_view)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64<'b>,
+        input: &LweCiphertextVectorView64<'a>,
+        ksk: &LweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        if output.lwe_ciphertext_count() != input.lwe_ciphertext_count() {
+            return Err(LweCiphertextVectorDiscardingKeyswitchError::CiphertextCountMismatch);
+        }
+        unsafe { self.discard_keyswitch_lwe_ciphertext_vector_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVectorMutView64<'b>,
+        input: &LweCiphertextVectorView64<'a>,
+        ksk: &LweKeyswitchKey64,
+    ) {
+        #[cfg(feature = "multithread")]
+        ksk.0.par_keyswitch_list(&mut output.0, &input.0);
+        #[cfg(not(feature = "multithread"))]
+        ksk.0.keyswitch_list(&mut output.0, &input.0);
+    }
+}
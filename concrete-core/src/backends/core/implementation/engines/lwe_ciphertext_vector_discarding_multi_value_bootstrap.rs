@@ -0,0 +1,253 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    CleartextVector32, CleartextVector64, FourierLweBootstrapKey32, FourierLweBootstrapKey64,
+    LweCiphertext32, LweCiphertext64, LweCiphertextVector32, LweCiphertextVector64, Plaintext32,
+    Plaintext64,
+};
+use crate::backends::core::private::math::polynomial::PolynomialList;
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine,
+    LweCiphertextVectorDiscardingMultiValueBootstrapError as MultiValueBootstrapError,
+};
+use crate::specification::entities::{
+    CleartextVectorEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for [`CoreEngine`]
+/// that operates on 32 bits integers.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        FourierLweBootstrapKey32,
+        CleartextVector32,
+        Plaintext32,
+        LweCiphertext32,
+        LweCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 20 bits
+    /// let luts: Vec<u32> = [1_u32, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u32 << 20;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey32 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey32 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &FourierLweBootstrapKey32,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector32,
+        input: &LweCiphertext32,
+        luts: &CleartextVector32,
+        delta: &Plaintext32,
+        bsk: &FourierLweBootstrapKey32,
+    ) {
+        let luts =
+            PolynomialList::from_container(luts.0.as_tensor().as_slice(), bsk.polynomial_size());
+        bsk.0
+            .multi_value_bootstrap(&mut output.0, &input.0, &luts, (delta.0).0);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingMultiValueBootstrapEngine`] for [`CoreEngine`]
+/// that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        FourierLweBootstrapKey64,
+        CleartextVector64,
+        Plaintext64,
+        LweCiphertext64,
+        LweCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweCiphertextCount,
+    ///     LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 52 bits)
+    /// let input = 3_u64 << 52;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // Three constant functions are applied during the bootstrap, and their values are encoded
+    /// // with the same shift of 52 bits
+    /// let luts: Vec<u64> = [1_u64, 2, 3]
+    ///     .iter()
+    ///     .flat_map(|value| vec![*value; poly_size.0])
+    ///     .collect();
+    /// let delta = 1_u64 << 52;
+    /// let count = LweCiphertextCount(3);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: FourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let luts = engine.create_cleartext_vector(&luts)?;
+    /// let delta = engine.create_plaintext(&delta)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext_vector(&lwe_sk_output, noise, count)?;
+    ///
+    /// engine.discard_multi_value_bootstrap_lwe_ciphertext(
+    ///     &mut output,
+    ///     &input,
+    ///     &luts,
+    ///     &delta,
+    ///     &bsk,
+    /// )?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// assert_eq!(output.lwe_ciphertext_count(), count);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(luts)?;
+    /// engine.destroy(delta)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &FourierLweBootstrapKey64,
+    ) -> Result<(), MultiValueBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(MultiValueBootstrapError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(MultiValueBootstrapError::OutputLweDimensionMismatch);
+        }
+        if luts.cleartext_count().0 != output.lwe_ciphertext_count().0 * bsk.polynomial_size().0 {
+            return Err(MultiValueBootstrapError::CleartextCountMismatch);
+        }
+        unsafe {
+            self.discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+                output, input, luts, delta, bsk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut LweCiphertextVector64,
+        input: &LweCiphertext64,
+        luts: &CleartextVector64,
+        delta: &Plaintext64,
+        bsk: &FourierLweBootstrapKey64,
+    ) {
+        let luts =
+            PolynomialList::from_container(luts.0.as_tensor().as_slice(), bsk.polynomial_size());
+        bsk.0
+            .multi_value_bootstrap(&mut output.0, &input.0, &luts, (delta.0).0);
+    }
+}
@@ -3,8 +3,10 @@ use concrete_commons::parameters::CiphertextCount;
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
-    PlaintextVector32, PlaintextVector64,
+    LweCiphertextVector32, LweCiphertextVector64, LweGaussianCiphertextVector32,
+    LweGaussianCiphertextVector64, LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32,
+    LweSecretKey64, LweTernaryCiphertextVector32, LweTernaryCiphertextVector64,
+    LweTernarySecretKey32, LweTernarySecretKey64, PlaintextVector32, PlaintextVector64,
 };
 use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
 use crate::specification::engines::{
@@ -143,3 +145,287 @@ impl LweCiphertextVectorEncryptionEngine<LweSecretKey64, PlaintextVector64, LweC
         LweCiphertextVector64(vector)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers and ternary keys.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        LweTernarySecretKey32,
+        PlaintextVector32,
+        LweTernaryCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweTernaryCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<LweTernaryCiphertextVector32, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> LweTernaryCiphertextVector32 {
+        let mut vector = ImplLweList::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweTernaryCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers and ternary keys.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        LweTernarySecretKey64,
+        PlaintextVector64,
+        LweTernaryCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweTernaryCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<LweTernaryCiphertextVector64, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweTernarySecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> LweTernaryCiphertextVector64 {
+        let mut vector = ImplLweList::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweTernaryCiphertextVector64(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers and gaussian keys.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        LweGaussianSecretKey32,
+        PlaintextVector32,
+        LweGaussianCiphertextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweGaussianCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> Result<LweGaussianCiphertextVector32, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey32,
+        input: &PlaintextVector32,
+        noise: Variance,
+    ) -> LweGaussianCiphertextVector32 {
+        let mut vector = ImplLweList::allocate(
+            0u32,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweGaussianCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`CoreEngine`] that operates on 64
+/// bits integers and gaussian keys.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        LweGaussianSecretKey64,
+        PlaintextVector64,
+        LweGaussianCiphertextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let mut ciphertext_vector: LweGaussianCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #     ciphertext_vector.lwe_ciphertext_count(),
+    /// #     LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<LweGaussianCiphertextVector64, LweCiphertextVectorEncryptionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweGaussianSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> LweGaussianCiphertextVector64 {
+        let mut vector = ImplLweList::allocate(
+            0u64,
+            key.lwe_dimension().to_lwe_size(),
+            CiphertextCount(input.plaintext_count().0),
+        );
+        key.0
+            .encrypt_lwe_list(&mut vector, &input.0, noise, &mut self.encryption_generator);
+        LweGaussianCiphertextVector64(vector)
+    }
+}
@@ -0,0 +1,234 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, LweCiphertextVector32, LweCiphertextVector64,
+    PrivateFunctionalPackingKeyswitchKey32, PrivateFunctionalPackingKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError as PrivateFunctionalPackingKeyswitchError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity, PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine`]
+/// for [`CoreEngine`] that operates on 32 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+        PrivateFunctionalPackingKeyswitchKey32,
+        LweCiphertextVector32,
+        GlweCiphertext32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u32, 1, 0, 0];
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector32 =
+    ///     engine.create_cleartext_vector(&polynomial_coefficients)?;
+    /// let pfpksk = engine.create_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(pfpksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey32,
+    ) -> Result<(), PrivateFunctionalPackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pfpksk.input_lwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pfpksk.output_glwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pfpksk.output_polynomial_size() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext32,
+        input: &LweCiphertextVector32,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey32,
+    ) {
+        pfpksk
+            .0
+            .private_functional_packing_keyswitch(&mut output.0, &input.0);
+    }
+}
+
+/// # Description:
+/// Implementation of
+/// [`LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine`]
+/// for [`CoreEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+        PrivateFunctionalPackingKeyswitchKey64,
+        LweCiphertextVector64,
+        GlweCiphertext64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // The linear map multiplies the inputs by X
+    /// let polynomial_coefficients = vec![0_u64, 1, 0, 0];
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    /// let polynomial: CleartextVector64 =
+    ///     engine.create_cleartext_vector(&polynomial_coefficients)?;
+    /// let pfpksk = engine.create_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector =
+    ///     engine.encrypt_lwe_ciphertext_vector(&input_key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.zero_encrypt_glwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &pfpksk,
+    /// )?;
+    /// #
+    /// assert_eq!(output_ciphertext.glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(output_ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(polynomial)?;
+    /// engine.destroy(pfpksk)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey64,
+    ) -> Result<(), PrivateFunctionalPackingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != pfpksk.input_lwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.glwe_dimension() != pfpksk.output_glwe_dimension() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputGlweDimensionMismatch);
+        }
+        if output.polynomial_size() != pfpksk.output_polynomial_size() {
+            return Err(PrivateFunctionalPackingKeyswitchError::OutputPolynomialSizeMismatch);
+        }
+        if input.lwe_ciphertext_count().0 > output.polynomial_size().0 {
+            return Err(PrivateFunctionalPackingKeyswitchError::CiphertextCountTooLarge);
+        }
+        unsafe {
+            self.discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+                output, input, pfpksk,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut GlweCiphertext64,
+        input: &LweCiphertextVector64,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey64,
+    ) {
+        pfpksk
+            .0
+            .private_functional_packing_keyswitch(&mut output.0, &input.0);
+    }
+}
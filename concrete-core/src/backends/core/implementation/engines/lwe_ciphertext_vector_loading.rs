@@ -0,0 +1,153 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorLoadingEngine, LweCiphertextVectorLoadingError,
+};
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweCiphertextRange;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLoadingEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers.
+impl LweCiphertextVectorLoadingEngine<LweCiphertextVector32, LweCiphertextVector32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let sub_ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.load_lwe_ciphertext_vector(&ciphertext_vector, LweCiphertextRange(5, 12))?;
+    /// #
+    /// assert_eq!(sub_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     sub_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(7)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(sub_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext_vector(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> Result<LweCiphertextVector32, LweCiphertextVectorLoadingError<Self::EngineError>> {
+        if !range.is_ordered() {
+            return Err(LweCiphertextVectorLoadingError::UnorderedInputRange);
+        }
+        if range.1 > vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorLoadingError::OutOfVectorInputRange);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_vector_unchecked(vector, range) })
+    }
+
+    unsafe fn load_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector32,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector32 {
+        let lwe_size = vector.0.lwe_size();
+        let container = vector
+            .0
+            .as_tensor()
+            .get_sub(range.0 * lwe_size.0..range.1 * lwe_size.0)
+            .into_container()
+            .to_vec();
+        LweCiphertextVector32(ImplLweList::from_container(container, lwe_size))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorLoadingEngine`] for [`CoreEngine`] that operates on 32
+/// bits integers.
+impl LweCiphertextVectorLoadingEngine<LweCiphertextVector64, LweCiphertextVector64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweCiphertextRange, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let sub_ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.load_lwe_ciphertext_vector(&ciphertext_vector, LweCiphertextRange(5, 12))?;
+    /// #
+    /// assert_eq!(sub_ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     sub_ciphertext_vector.lwe_ciphertext_count(),
+    ///     LweCiphertextCount(7)
+    /// );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(sub_ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn load_lwe_ciphertext_vector(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> Result<LweCiphertextVector64, LweCiphertextVectorLoadingError<Self::EngineError>> {
+        if !range.is_ordered() {
+            return Err(LweCiphertextVectorLoadingError::UnorderedInputRange);
+        }
+        if range.1 > vector.lwe_ciphertext_count().0 {
+            return Err(LweCiphertextVectorLoadingError::OutOfVectorInputRange);
+        }
+        Ok(unsafe { self.load_lwe_ciphertext_vector_unchecked(vector, range) })
+    }
+
+    unsafe fn load_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        vector: &LweCiphertextVector64,
+        range: LweCiphertextRange,
+    ) -> LweCiphertextVector64 {
+        let lwe_size = vector.0.lwe_size();
+        let container = vector
+            .0
+            .as_tensor()
+            .get_sub(range.0 * lwe_size.0..range.1 * lwe_size.0)
+            .into_container()
+            .to_vec();
+        LweCiphertextVector64(ImplLweList::from_container(container, lwe_size))
+    }
+}
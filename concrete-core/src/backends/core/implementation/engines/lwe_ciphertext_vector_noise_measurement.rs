@@ -0,0 +1,171 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSecretKey32, LweSecretKey64,
+    PlaintextVector32, PlaintextVector64,
+};
+use crate::backends::core::private::crypto::encoding::PlaintextList as ImplPlaintextList;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::specification::engines::{
+    LweCiphertextVectorNoiseMeasurementEngine, LweCiphertextVectorNoiseMeasurementError,
+    NoiseMeasurement,
+};
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::parameters::PlaintextCount;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that
+/// operates on 32 bits integers.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey32,
+        LweCiphertextVector32,
+        PlaintextVector32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), 3);
+    /// assert!(measurements.iter().all(|measurement| measurement.bit_size < 25));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorNoiseMeasurementError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(LweCiphertextVectorNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey32,
+        input: &LweCiphertextVector32,
+        expected: &PlaintextVector32,
+    ) -> Vec<NoiseMeasurement> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u32, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintexts, &input.0);
+        plaintexts
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| {
+                NoiseMeasurement::from_error(decrypted.wrapping_sub(*expected) as i32 as i64, 32)
+            })
+            .collect()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorNoiseMeasurementEngine`] for [`CoreEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorNoiseMeasurementEngine<
+        LweSecretKey64,
+        LweCiphertextVector64,
+        PlaintextVector64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector = engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let measurements =
+    ///     engine.measure_lwe_ciphertext_vector_noise(&key, &ciphertext_vector, &plaintext_vector)?;
+    /// #
+    /// assert_eq!(measurements.len(), 3);
+    /// assert!(measurements.iter().all(|measurement| measurement.bit_size < 45));
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>
+    {
+        if key.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextVectorNoiseMeasurementError::LweDimensionMismatch);
+        }
+        if input.lwe_ciphertext_count().0 != expected.plaintext_count().0 {
+            return Err(LweCiphertextVectorNoiseMeasurementError::PlaintextCountMismatch);
+        }
+        Ok(unsafe { self.measure_lwe_ciphertext_vector_noise_unchecked(key, input, expected) })
+    }
+
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &LweCiphertextVector64,
+        expected: &PlaintextVector64,
+    ) -> Vec<NoiseMeasurement> {
+        let mut plaintexts =
+            ImplPlaintextList::allocate(0u64, PlaintextCount(input.lwe_ciphertext_count().0));
+        key.0.decrypt_lwe_list(&mut plaintexts, &input.0);
+        plaintexts
+            .as_tensor()
+            .iter()
+            .zip(expected.0.as_tensor().iter())
+            .map(|(decrypted, expected)| {
+                NoiseMeasurement::from_error(decrypted.wrapping_sub(*expected) as i64, 64)
+            })
+            .collect()
+    }
+}
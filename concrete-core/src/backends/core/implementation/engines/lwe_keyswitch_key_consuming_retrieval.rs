@@ -0,0 +1,152 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::math::tensor::IntoTensor;
+use crate::specification::engines::{
+    LweKeyswitchKeyConsumingRetrievalEngine, LweKeyswitchKeyConsumingRetrievalError,
+};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the vector of 32 bits integers of an LWE keyswitch key.
+impl LweKeyswitchKeyConsumingRetrievalEngine<LweKeyswitchKey32, Vec<u32>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let container: Vec<u32> = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// );
+    ///
+    /// // The container can be used to create the keyswitch key again
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.create_lwe_keyswitch_key_from_container(
+    ///     container,
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: LweKeyswitchKey32,
+    ) -> Result<Vec<u32>, LweKeyswitchKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_keyswitch_key_unchecked(keyswitch_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: LweKeyswitchKey32,
+    ) -> Vec<u32> {
+        keyswitch_key.0.into_tensor().into_container()
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConsumingRetrievalEngine`] for [`CoreEngine`] that returns
+/// the vector of 64 bits integers of an LWE keyswitch key.
+impl LweKeyswitchKeyConsumingRetrievalEngine<LweKeyswitchKey64, Vec<u64>> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let container: Vec<u64> = engine.consume_retrieve_lwe_keyswitch_key(keyswitch_key)?;
+    /// assert_eq!(
+    ///     container.len(),
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// );
+    ///
+    /// // The container can be used to create the keyswitch key again
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key_from_container(
+    ///     container,
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    /// #
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn consume_retrieve_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: LweKeyswitchKey64,
+    ) -> Result<Vec<u64>, LweKeyswitchKeyConsumingRetrievalError<Self::EngineError>> {
+        Ok(unsafe { self.consume_retrieve_lwe_keyswitch_key_unchecked(keyswitch_key) })
+    }
+
+    unsafe fn consume_retrieve_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: LweKeyswitchKey64,
+    ) -> Vec<u64> {
+        keyswitch_key.0.into_tensor().into_container()
+    }
+}
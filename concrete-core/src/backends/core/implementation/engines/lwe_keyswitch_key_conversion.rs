@@ -0,0 +1,153 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::engines::{
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+    LweKeyswitchKeyDiscardingConversionEngine,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE keyswitch key to a 64 bits LWE keyswitch key. The torus elements are exactly rescaled to the
+/// output precision.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey32, LweKeyswitchKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: LweKeyswitchKey64 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey32,
+    ) -> LweKeyswitchKey64 {
+        let mut output = LweKeyswitchKey64(ImplLweKeyswitchKey::allocate(
+            0_u64,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        ));
+        self.discard_convert_lwe_keyswitch_key_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE keyswitch key to a 32 bits LWE keyswitch key. The torus elements are rounded to the closest
+/// element of the output precision.
+impl LweKeyswitchKeyConversionEngine<LweKeyswitchKey64, LweKeyswitchKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// let output: LweKeyswitchKey32 = engine.convert_lwe_keyswitch_key(&keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_keyswitch_key(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyConversionError<Self::EngineError>> {
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweKeyswitchKeyConversionError::DecompositionTooLarge);
+        }
+        Ok(unsafe { self.convert_lwe_keyswitch_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input: &LweKeyswitchKey64,
+    ) -> LweKeyswitchKey32 {
+        let mut output = LweKeyswitchKey32(ImplLweKeyswitchKey::allocate(
+            0_u32,
+            input.decomposition_level_count(),
+            input.decomposition_base_log(),
+            input.input_lwe_dimension(),
+            input.output_lwe_dimension(),
+        ));
+        self.discard_convert_lwe_keyswitch_key_unchecked(&mut output, input);
+        output
+    }
+}
@@ -3,7 +3,10 @@ use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount
 
 use crate::backends::core::implementation::engines::CoreEngine;
 use crate::backends::core::implementation::entities::{
-    LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweBinaryToGaussianKeyswitchKey32, LweBinaryToGaussianKeyswitchKey64,
+    LweBinaryToTernaryKeyswitchKey32, LweBinaryToTernaryKeyswitchKey64, LweGaussianSecretKey32,
+    LweGaussianSecretKey64, LweKeyswitchKey32, LweKeyswitchKey64, LweSecretKey32, LweSecretKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64,
 };
 use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
 use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
@@ -202,3 +205,413 @@ impl LweKeyswitchKeyCreationEngine<LweSecretKey64, LweSecretKey64, LweKeyswitchK
         LweKeyswitchKey64(ksk)
     }
 }
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers. It creates keyswitch keys from binary LWE secret keys to ternary LWE secret keys.
+impl
+    LweKeyswitchKeyCreationEngine<
+        LweSecretKey32,
+        LweTernarySecretKey32,
+        LweBinaryToTernaryKeyswitchKey32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweBinaryToTernaryKeyswitchKey32, LweKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweTernarySecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweBinaryToTernaryKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBinaryToTernaryKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers. It creates keyswitch keys from binary LWE secret keys to ternary LWE secret keys.
+impl
+    LweKeyswitchKeyCreationEngine<
+        LweSecretKey64,
+        LweTernarySecretKey64,
+        LweBinaryToTernaryKeyswitchKey64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweTernarySecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweBinaryToTernaryKeyswitchKey64, LweKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweTernarySecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweBinaryToTernaryKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBinaryToTernaryKeyswitchKey64(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers. It creates keyswitch keys from binary LWE secret keys to gaussian LWE secret keys.
+impl
+    LweKeyswitchKeyCreationEngine<
+        LweSecretKey32,
+        LweGaussianSecretKey32,
+        LweBinaryToGaussianKeyswitchKey32,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey32 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweBinaryToGaussianKeyswitchKey32, LweKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &LweGaussianSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweBinaryToGaussianKeyswitchKey32 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBinaryToGaussianKeyswitchKey32(ksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers. It creates keyswitch keys from binary LWE secret keys to gaussian LWE secret keys.
+impl
+    LweKeyswitchKeyCreationEngine<
+        LweSecretKey64,
+        LweGaussianSecretKey64,
+        LweBinaryToGaussianKeyswitchKey64,
+    > for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweGaussianSecretKey64 =
+    ///     engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<LweBinaryToGaussianKeyswitchKey64, LweKeyswitchKeyCreationError<Self::EngineError>>
+    {
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &LweGaussianSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> LweBinaryToGaussianKeyswitchKey64 {
+        let mut ksk = ImplLweKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.lwe_dimension(),
+        );
+        ksk.fill_with_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        LweBinaryToGaussianKeyswitchKey64(ksk)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::engines::{
+    LweKeyswitchKeyCreationFromContainerEngine, LweKeyswitchKeyCreationFromContainerError,
+};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns an LWE keyswitch key, taking ownership of a vector of 32 bits integers.
+impl LweKeyswitchKeyCreationFromContainerEngine<Vec<u32>, LweKeyswitchKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// // Here we create a container outside of the engine
+    /// let container = vec![
+    ///     0_u32;
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// ];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let keyswitch_key: LweKeyswitchKey32 = engine.create_lwe_keyswitch_key_from_container(
+    ///     container,
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_from_container(
+        &mut self,
+        container: Vec<u32>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweKeyswitchKey32, LweKeyswitchKeyCreationFromContainerError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweKeyswitchKeyCreationFromContainerError::EmptyContainer);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 32 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        if container.len() % (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0)
+            != 0
+        {
+            return Err(LweKeyswitchKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_from_container_unchecked(
+                container,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u32>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweKeyswitchKey32 {
+        LweKeyswitchKey32(ImplLweKeyswitchKey::from_container(
+            container,
+            decomposition_base_log,
+            decomposition_level_count,
+            output_lwe_dimension,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationFromContainerEngine`] for [`CoreEngine`] which
+/// returns an LWE keyswitch key, taking ownership of a vector of 64 bits integers.
+impl LweKeyswitchKeyCreationFromContainerEngine<Vec<u64>, LweKeyswitchKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    ///
+    /// // Here we create a container outside of the engine
+    /// let container = vec![
+    ///     0_u64;
+    ///     input_lwe_dimension.0
+    ///         * decomposition_level_count.0
+    ///         * output_lwe_dimension.to_lwe_size().0
+    /// ];
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let keyswitch_key: LweKeyswitchKey64 = engine.create_lwe_keyswitch_key_from_container(
+    ///     container,
+    ///     output_lwe_dimension,
+    ///     decomposition_base_log,
+    ///     decomposition_level_count,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_base_log(),
+    ///     decomposition_base_log
+    /// );
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key_from_container(
+        &mut self,
+        container: Vec<u64>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<LweKeyswitchKey64, LweKeyswitchKeyCreationFromContainerError<Self::EngineError>>
+    {
+        if container.is_empty() {
+            return Err(LweKeyswitchKeyCreationFromContainerError::EmptyContainer);
+        }
+        if decomposition_base_log.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 >= 64 {
+            return Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge);
+        }
+        if container.len() % (decomposition_level_count.0 * output_lwe_dimension.to_lwe_size().0)
+            != 0
+        {
+            return Err(LweKeyswitchKeyCreationFromContainerError::InvalidContainerSize);
+        }
+        Ok(unsafe {
+            self.create_lwe_keyswitch_key_from_container_unchecked(
+                container,
+                output_lwe_dimension,
+                decomposition_base_log,
+                decomposition_level_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_keyswitch_key_from_container_unchecked(
+        &mut self,
+        container: Vec<u64>,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> LweKeyswitchKey64 {
+        LweKeyswitchKey64(ImplLweKeyswitchKey::from_container(
+            container,
+            decomposition_base_log,
+            decomposition_level_count,
+            output_lwe_dimension,
+        ))
+    }
+}
@@ -0,0 +1,199 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweKeyswitchKey32, LweKeyswitchKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::CastTorusInto;
+use crate::specification::engines::{
+    LweKeyswitchKeyDiscardingConversionEngine, LweKeyswitchKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 32 bits LWE keyswitch key to a 64 bits LWE keyswitch key. The torus elements are exactly
+/// rescaled to the output precision.
+impl LweKeyswitchKeyDiscardingConversionEngine<LweKeyswitchKey32, LweKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey32 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let converted_input_key: LweSecretKey64 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: LweSecretKey64 = engine.convert_lwe_secret_key(&output_key)?;
+    /// let mut output = engine.create_lwe_keyswitch_key(
+    ///     &converted_input_key,
+    ///     &converted_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweKeyswitchKey32,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::InputLweDimensionMismatch);
+        }
+        if output.output_lwe_dimension() != input.output_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::OutputLweDimensionMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut LweKeyswitchKey64,
+        input: &LweKeyswitchKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts
+/// a 64 bits LWE keyswitch key to a 32 bits LWE keyswitch key. The torus elements are rounded to
+/// the closest element of the output precision.
+impl LweKeyswitchKeyDiscardingConversionEngine<LweKeyswitchKey64, LweKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let converted_input_key: LweSecretKey32 = engine.convert_lwe_secret_key(&input_key)?;
+    /// let converted_output_key: LweSecretKey32 = engine.convert_lwe_secret_key(&output_key)?;
+    /// let mut output = engine.create_lwe_keyswitch_key(
+    ///     &converted_input_key,
+    ///     &converted_output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    ///
+    /// engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(output.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(output.output_lwe_dimension(), output_lwe_dimension);
+    /// assert_eq!(output.decomposition_level_count(), decomposition_level_count);
+    /// assert_eq!(output.decomposition_base_log(), decomposition_base_log);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(converted_input_key)?;
+    /// engine.destroy(converted_output_key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_keyswitch_key(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweKeyswitchKey64,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError<Self::EngineError>> {
+        if output.input_lwe_dimension() != input.input_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::InputLweDimensionMismatch);
+        }
+        if output.output_lwe_dimension() != input.output_lwe_dimension() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::OutputLweDimensionMismatch);
+        }
+        if output.decomposition_base_log() != input.decomposition_base_log() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionBaseLogMismatch);
+        }
+        if output.decomposition_level_count() != input.decomposition_level_count() {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionLevelCountMismatch);
+        }
+        if input.decomposition_base_log().0 * input.decomposition_level_count().0 >= 32 {
+            return Err(LweKeyswitchKeyDiscardingConversionError::DecompositionTooLarge);
+        }
+        unsafe { self.discard_convert_lwe_keyswitch_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_keyswitch_key_unchecked(
+        &mut self,
+        output: &mut LweKeyswitchKey32,
+        input: &LweKeyswitchKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| a.cast_torus_into());
+    }
+}
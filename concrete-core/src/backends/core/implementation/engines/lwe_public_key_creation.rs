@@ -0,0 +1,148 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LwePublicKey32, LwePublicKey64, LweSecretKey32, LweSecretKey64,
+};
+use crate::backends::core::private::crypto::lwe::LwePublicKey as ImplLwePublicKey;
+use crate::specification::engines::{LwePublicKeyCreationEngine, LwePublicKeyCreationError};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl LwePublicKeyCreationEngine<LweSecretKey32, LwePublicKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey32 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LwePublicKey32, LwePublicKeyCreationError<Self::EngineError>> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(LwePublicKeyCreationError::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        secret_key: &LweSecretKey32,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LwePublicKey32 {
+        let mut public_key = ImplLwePublicKey::allocate(
+            0u32,
+            secret_key.lwe_dimension().to_lwe_size(),
+            lwe_public_key_zero_encryption_count,
+        );
+        public_key.fill_with_new_key(&secret_key.0, noise, &mut self.encryption_generator);
+        LwePublicKey32(public_key)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LwePublicKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl LwePublicKeyCreationEngine<LweSecretKey64, LwePublicKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(10);
+    /// let noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let secret_key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let public_key: LwePublicKey64 =
+    ///     engine.create_lwe_public_key(&secret_key, noise, zero_encryption_count)?;
+    /// #
+    /// assert_eq!(public_key.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    ///     public_key.lwe_zero_encryption_count(),
+    ///     zero_encryption_count
+    /// );
+    ///
+    /// engine.destroy(secret_key)?;
+    /// engine.destroy(public_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_public_key(
+        &mut self,
+        secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<LwePublicKey64, LwePublicKeyCreationError<Self::EngineError>> {
+        if lwe_public_key_zero_encryption_count.0 == 0 {
+            return Err(LwePublicKeyCreationError::NullPublicKeyZeroEncryptionCount);
+        }
+        Ok(unsafe {
+            self.create_lwe_public_key_unchecked(
+                secret_key,
+                noise,
+                lwe_public_key_zero_encryption_count,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        secret_key: &LweSecretKey64,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> LwePublicKey64 {
+        let mut public_key = ImplLwePublicKey::allocate(
+            0u64,
+            secret_key.lwe_dimension().to_lwe_size(),
+            lwe_public_key_zero_encryption_count,
+        );
+        public_key.fill_with_new_key(&secret_key.0, noise, &mut self.encryption_generator);
+        LwePublicKey64(public_key)
+    }
+}
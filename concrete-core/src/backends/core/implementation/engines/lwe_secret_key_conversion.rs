@@ -0,0 +1,106 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
+use crate::specification::engines::{
+    LweSecretKeyConversionEngine, LweSecretKeyConversionError,
+    LweSecretKeyDiscardingConversionEngine,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// LWE secret key to a 64 bits LWE secret key. The key bits are copied to the output precision.
+impl LweSecretKeyConversionEngine<LweSecretKey32, LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let output: LweSecretKey64 = engine.convert_lwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &LweSecretKey32,
+    ) -> Result<LweSecretKey64, LweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_secret_key_unchecked(
+        &mut self,
+        input: &LweSecretKey32,
+    ) -> LweSecretKey64 {
+        let mut output = LweSecretKey64(ImplLweSecretKey::binary_from_container(vec![
+            0_u64;
+            input
+                .lwe_dimension()
+                .0
+        ]));
+        self.discard_convert_lwe_secret_key_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// LWE secret key to a 32 bits LWE secret key. The key bits are copied to the output precision.
+impl LweSecretKeyConversionEngine<LweSecretKey64, LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// let output: LweSecretKey32 = engine.convert_lwe_secret_key(&key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_lwe_secret_key(
+        &mut self,
+        input: &LweSecretKey64,
+    ) -> Result<LweSecretKey32, LweSecretKeyConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_lwe_secret_key_unchecked(input) })
+    }
+
+    unsafe fn convert_lwe_secret_key_unchecked(
+        &mut self,
+        input: &LweSecretKey64,
+    ) -> LweSecretKey32 {
+        let mut output = LweSecretKey32(ImplLweSecretKey::binary_from_container(vec![
+            0_u32;
+            input
+                .lwe_dimension()
+                .0
+        ]));
+        self.discard_convert_lwe_secret_key_unchecked(&mut output, input);
+        output
+    }
+}
@@ -1,7 +1,10 @@
 use concrete_commons::parameters::LweDimension;
 
 use crate::backends::core::implementation::engines::CoreEngine;
-use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::implementation::entities::{
+    LweGaussianSecretKey32, LweGaussianSecretKey64, LweSecretKey32, LweSecretKey64,
+    LweTernarySecretKey32, LweTernarySecretKey64,
+};
 use crate::backends::core::private::crypto::secret::LweSecretKey as ImplLweSecretKey;
 use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
 
@@ -86,3 +89,167 @@ impl LweSecretKeyCreationEngine<LweSecretKey64> for CoreEngine {
         ))
     }
 }
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and ternary keys.
+impl LweSecretKeyCreationEngine<LweTernarySecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_secret_key: LweTernarySecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweTernarySecretKey32, LweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweTernarySecretKey32 {
+        LweTernarySecretKey32(ImplLweSecretKey::generate_ternary(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and ternary keys.
+impl LweSecretKeyCreationEngine<LweTernarySecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_secret_key: LweTernarySecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweTernarySecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweTernarySecretKey64 {
+        LweTernarySecretKey64(ImplLweSecretKey::generate_ternary(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 32 bits
+/// integers and gaussian keys.
+impl LweSecretKeyCreationEngine<LweGaussianSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_secret_key: LweGaussianSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweGaussianSecretKey32, LweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweGaussianSecretKey32 {
+        LweGaussianSecretKey32(ImplLweSecretKey::generate_gaussian(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`CoreEngine`] that operates on 64 bits
+/// integers and gaussian keys.
+impl LweSecretKeyCreationEngine<LweGaussianSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let lwe_secret_key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<LweGaussianSecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        Ok(unsafe { self.create_lwe_secret_key_unchecked(lwe_dimension) })
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> LweGaussianSecretKey64 {
+        LweGaussianSecretKey64(ImplLweSecretKey::generate_gaussian(
+            lwe_dimension,
+            &mut self.secret_generator,
+        ))
+    }
+}
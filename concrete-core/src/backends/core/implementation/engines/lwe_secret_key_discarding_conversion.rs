@@ -0,0 +1,113 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{LweSecretKey32, LweSecretKey64};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+use crate::specification::engines::{
+    LweSecretKeyDiscardingConversionEngine, LweSecretKeyDiscardingConversionError,
+};
+use crate::specification::entities::LweSecretKeyEntity;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 32 bits LWE secret key to a 64 bits LWE secret key. The key bits are copied to the output
+/// precision.
+impl LweSecretKeyDiscardingConversionEngine<LweSecretKey32, LweSecretKey64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut LweSecretKey64,
+        input: &LweSecretKey32,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweSecretKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        output: &mut LweSecretKey64,
+        input: &LweSecretKey32,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u64);
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSecretKeyDiscardingConversionEngine`] for [`CoreEngine`] that converts a
+/// 64 bits LWE secret key to a 32 bits LWE secret key. The key bits are copied to the output
+/// precision.
+impl LweSecretKeyDiscardingConversionEngine<LweSecretKey64, LweSecretKey32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let mut output: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    ///
+    /// engine.discard_convert_lwe_secret_key(&mut output, &key)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_convert_lwe_secret_key(
+        &mut self,
+        output: &mut LweSecretKey32,
+        input: &LweSecretKey64,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweSecretKeyDiscardingConversionError::LweDimensionMismatch);
+        }
+        unsafe { self.discard_convert_lwe_secret_key_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn discard_convert_lwe_secret_key_unchecked(
+        &mut self,
+        output: &mut LweSecretKey32,
+        input: &LweSecretKey64,
+    ) {
+        output
+            .0
+            .as_mut_tensor()
+            .fill_with_one(input.0.as_tensor(), |a| *a as u32);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweCiphertextVector32, LweCiphertextVector64, LweSeededCiphertextVector32,
+    LweSeededCiphertextVector64,
+};
+use crate::backends::core::private::crypto::lwe::LweList as ImplLweList;
+use crate::specification::engines::{
+    LweSeededCiphertextVectorExpansionEngine, LweSeededCiphertextVectorExpansionError,
+};
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorExpansionEngine`] for [`CoreEngine`] that operates
+/// on 32 bits integers.
+impl LweSeededCiphertextVectorExpansionEngine<LweSeededCiphertextVector32, LweCiphertextVector32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = vec![3_u32 << 20; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey32 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector32 = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector32 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector32 =
+    ///     engine.expand_lwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &LweSeededCiphertextVector32,
+    ) -> Result<LweCiphertextVector32, LweSeededCiphertextVectorExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweSeededCiphertextVector32,
+    ) -> LweCiphertextVector32 {
+        let mut vector = ImplLweList::allocate(0u32, input.0.lwe_size(), input.0.count());
+        input.0.expand_into(&mut vector);
+        LweCiphertextVector32(vector)
+    }
+}
+
+/// # Description:
+/// Implementation of [`LweSeededCiphertextVectorExpansionEngine`] for [`CoreEngine`] that operates
+/// on 64 bits integers.
+impl LweSeededCiphertextVectorExpansionEngine<LweSeededCiphertextVector64, LweCiphertextVector64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// let seeded_ciphertext_vector: LweSeededCiphertextVector64 =
+    ///     engine.encrypt_lwe_seeded_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let ciphertext_vector: LweCiphertextVector64 =
+    ///     engine.expand_lwe_seeded_ciphertext_vector(&seeded_ciphertext_vector)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(
+    /// #    ciphertext_vector.lwe_ciphertext_count(),
+    /// #    LweCiphertextCount(3)
+    /// # );
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(seeded_ciphertext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn expand_lwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &LweSeededCiphertextVector64,
+    ) -> Result<LweCiphertextVector64, LweSeededCiphertextVectorExpansionError<Self::EngineError>>
+    {
+        Ok(unsafe { self.expand_lwe_seeded_ciphertext_vector_unchecked(input) })
+    }
+
+    unsafe fn expand_lwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        input: &LweSeededCiphertextVector64,
+    ) -> LweCiphertextVector64 {
+        let mut vector = ImplLweList::allocate(0u64, input.0.lwe_size(), input.0.count());
+        input.0.expand_into(&mut vector);
+        LweCiphertextVector64(vector)
+    }
+}
@@ -4,6 +4,7 @@ use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator as ImplEncryptionRandomGenerator,
     SecretRandomGenerator as ImplSecretRandomGenerator,
 };
+use crate::backends::core::private::math::random::RandomGenerator;
 use crate::specification::engines::sealed::AbstractEngineSeal;
 use crate::specification::engines::AbstractEngine;
 use std::error::Error;
@@ -13,11 +14,17 @@ use std::fmt::{Display, Formatter};
 ///
 /// # Note:
 ///
-/// There is currently no such case, as the core implementation is not expected to undergo some
-/// major issues unrelated to FHE.
+/// Apart from the borrowing errors, those errors are raised by the encoding operations, when the
+/// configuration of a float encoder can not be honored, and by the creation of the bootstrap keys
+/// from gaussian keys, when a coefficient of the key is too large to be expanded.
 #[derive(Debug)]
 pub enum CoreError {
     Borrow,
+    FloatEncoderMinMaxOrder,
+    FloatEncoderNullPrecision,
+    FloatEncoderPrecisionOverflow,
+    FloatEncoderValueOutOfInterval,
+    GaussianKeyCoefficientOutOfRange,
 }
 impl Display for CoreError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -25,68 +32,280 @@ impl Display for CoreError {
             CoreError::Borrow => {
                 write!(f, "The borrowing rules were broken during execution.")
             }
+            CoreError::FloatEncoderMinMaxOrder => {
+                write!(
+                    f,
+                    "The min value of the float encoder must be smaller than its max value."
+                )
+            }
+            CoreError::FloatEncoderNullPrecision => {
+                write!(
+                    f,
+                    "The precision of the float encoder must be strictly positive."
+                )
+            }
+            CoreError::FloatEncoderPrecisionOverflow => {
+                write!(
+                    f,
+                    "The precision and padding of the float encoder must fit in the plaintext."
+                )
+            }
+            CoreError::FloatEncoderValueOutOfInterval => {
+                write!(
+                    f,
+                    "The cleartext value must be contained in the float encoder interval."
+                )
+            }
+            CoreError::GaussianKeyCoefficientOutOfRange => {
+                write!(
+                    f,
+                    "The coefficients of the gaussian key are too large to create a bootstrap key."
+                )
+            }
         }
     }
 }
 impl Error for CoreError {}
 
+/// A source of seeds for the random generators of the engines, such as the [`CoreEngine`].
+///
+/// Implementing this trait makes it possible to plug a custom entropy source in the engine.
+pub trait Seeder {
+    /// Returns a new seed.
+    fn seed(&mut self) -> u128;
+}
+
+/// A seeder drawing its seeds from the entropy source of the machine.
+///
+/// This is the seeder used by [`CoreEngine::new`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EntropySeeder;
+
+impl Seeder for EntropySeeder {
+    fn seed(&mut self) -> u128 {
+        RandomGenerator::new(None).random_uniform()
+    }
+}
+
+/// A seeder deterministically deriving its seeds from a master seed.
+///
+/// # Note:
+///
+/// The engines built from such a seeder generate reproducible keys and ciphertexts, which are
+/// only as secret as the master seed. This is meant for regression and known-answer tests.
+pub struct DeterministicSeeder(RandomGenerator);
+
+impl DeterministicSeeder {
+    /// Creates a new seeder from a master seed.
+    pub fn new(seed: u128) -> DeterministicSeeder {
+        DeterministicSeeder(RandomGenerator::new(Some(seed)))
+    }
+}
+
+impl Seeder for DeterministicSeeder {
+    fn seed(&mut self) -> u128 {
+        self.0.random_uniform()
+    }
+}
+
 /// The main engine exposed by the core backend.
 pub struct CoreEngine {
     secret_generator: ImplSecretRandomGenerator,
     encryption_generator: ImplEncryptionRandomGenerator,
 }
 
+impl CoreEngine {
+    /// Creates a new engine, whose random generators are seeded by the given seeder.
+    ///
+    /// The seeds of the secret generator, of the mask generator and of the noise generator are
+    /// drawn from the seeder, in that order. Two engines created from seeders returning the same
+    /// seeds generate the same keys and ciphertexts, provided they execute the same operations in
+    /// the same order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut first_engine = CoreEngine::with_seeder(&mut DeterministicSeeder::new(42));
+    /// let first_key: LweSecretKey64 = first_engine.create_lwe_secret_key(LweDimension(2))?;
+    /// let plaintext = first_engine.create_plaintext(&input)?;
+    /// let first_ciphertext = first_engine.encrypt_lwe_ciphertext(&first_key, &plaintext, noise)?;
+    ///
+    /// let mut second_engine = CoreEngine::with_seeder(&mut DeterministicSeeder::new(42));
+    /// let second_key: LweSecretKey64 = second_engine.create_lwe_secret_key(LweDimension(2))?;
+    /// let second_ciphertext = second_engine.encrypt_lwe_ciphertext(&second_key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(first_key, second_key);
+    /// assert_eq!(first_ciphertext, second_ciphertext);
+    ///
+    /// first_engine.destroy(first_key)?;
+    /// first_engine.destroy(plaintext)?;
+    /// first_engine.destroy(first_ciphertext)?;
+    /// second_engine.destroy(second_key)?;
+    /// second_engine.destroy(second_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_seeder<S: Seeder>(seeder: &mut S) -> CoreEngine {
+        let secret_seed = seeder.seed();
+        let mask_seed = seeder.seed();
+        let noise_seed = seeder.seed();
+        CoreEngine {
+            secret_generator: ImplSecretRandomGenerator::new(Some(secret_seed)),
+            encryption_generator: ImplEncryptionRandomGenerator::new_with_seeds(
+                mask_seed, noise_seed,
+            ),
+        }
+    }
+}
+
 impl AbstractEngineSeal for CoreEngine {}
 impl AbstractEngine for CoreEngine {
     type EngineError = CoreError;
 
     fn new() -> Result<Self, Self::EngineError> {
-        Ok(CoreEngine {
-            secret_generator: ImplSecretRandomGenerator::new(None),
-            encryption_generator: ImplEncryptionRandomGenerator::new(None),
-        })
+        Ok(CoreEngine::with_seeder(&mut EntropySeeder))
     }
 }
 
+mod circuit_bootstrap_private_functional_packing_keyswitch_keys_creation;
+mod cleartext_conversion;
 mod cleartext_creation;
+mod cleartext_discarding_conversion;
+mod cleartext_encoding;
+mod cleartext_retrieval;
+mod cleartext_vector_conversion;
 mod cleartext_vector_creation;
+mod cleartext_vector_discarding_conversion;
+mod cleartext_vector_encoding;
+mod cleartext_vector_retrieval;
 mod destruction;
+mod encoder_creation;
+mod encoder_vector_creation;
+mod entity_deserialization;
+pub(crate) mod entity_serialization;
+mod ggsw_ciphertext_conversion;
+mod ggsw_ciphertext_scalar_discarding_encryption;
+mod ggsw_ciphertext_scalar_encryption;
+mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_scalar_encryption;
+mod glwe_ciphertext_consuming_retrieval;
+mod glwe_ciphertext_conversion;
+mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_multiplication;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_ggsw_ciphertext_external_product;
+#[cfg(feature = "noise_measurement")]
+mod glwe_ciphertext_noise_measurement;
+mod glwe_ciphertext_vector_conversion;
 mod glwe_ciphertext_vector_decryption;
+mod glwe_ciphertext_vector_discarding_conversion;
 mod glwe_ciphertext_vector_discarding_decryption;
 mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_ciphertexts_ggsw_ciphertext_cmux;
+mod glwe_relinearization_key_creation;
+mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
+mod glwe_secret_key_discarding_conversion;
+mod glwe_seeded_ciphertext_encryption;
+mod glwe_seeded_ciphertext_expansion;
+mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_ciphertext_vector_expansion;
+mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_creation_from_container;
+mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_compression;
+mod lwe_ciphertext_consuming_retrieval;
+mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_creation;
+mod lwe_ciphertext_decompression;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_conversion;
 mod lwe_ciphertext_discarding_decryption;
 mod lwe_ciphertext_discarding_encryption;
 mod lwe_ciphertext_discarding_extraction;
 mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_discarding_loading;
 mod lwe_ciphertext_discarding_negation;
+mod lwe_ciphertext_discarding_storing;
+mod lwe_ciphertext_discarding_vertical_packing;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_negation;
+mod lwe_ciphertext_ggsw_ciphertext_discarding_circuit_bootstrap;
+mod lwe_ciphertext_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_public_key_encryption;
+mod lwe_ciphertext_vector_compression;
+mod lwe_ciphertext_vector_consuming_retrieval;
+mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
+mod lwe_ciphertext_vector_decompression;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_discarding_bootstrap;
+mod lwe_ciphertext_vector_discarding_conversion;
 mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
+mod lwe_ciphertext_vector_discarding_keyswitch;
+mod lwe_ciphertext_vector_discarding_multi_value_bootstrap;
 mod lwe_ciphertext_vector_encryption;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
+mod lwe_ciphertext_vector_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_consuming_retrieval;
+mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_creation_from_container;
+mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_public_key_creation;
+mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
+mod lwe_secret_key_discarding_conversion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_expansion;
+mod packing_keyswitch_key_creation;
+mod plaintext_conversion;
 mod plaintext_creation;
+mod plaintext_decoding;
+mod plaintext_discarding_conversion;
+mod plaintext_vector_conversion;
 mod plaintext_vector_creation;
+mod plaintext_vector_decoding;
+mod plaintext_vector_discarding_conversion;
+mod private_functional_packing_keyswitch_key_creation;
+
+#[cfg(test)]
+mod tests;
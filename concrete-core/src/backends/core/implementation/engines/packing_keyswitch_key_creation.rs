@@ -0,0 +1,235 @@
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweSecretKey32, GlweSecretKey64, LweSecretKey32, LweSecretKey64, PackingKeyswitchKey32,
+    PackingKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::glwe::PackingKeyswitchKey as ImplPackingKeyswitchKey;
+use crate::specification::engines::{
+    PackingKeyswitchKeyCreationEngine, PackingKeyswitchKeyCreationError,
+};
+use crate::specification::entities::{GlweSecretKeyEntity, LweSecretKeyEntity};
+
+/// # Description:
+/// Implementation of [`PackingKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 32 bits integers.
+impl PackingKeyswitchKeyCreationEngine<LweSecretKey32, GlweSecretKey32, PackingKeyswitchKey32>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey32 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey32 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let packing_keyswitch_key = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_packing_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<PackingKeyswitchKey32, PackingKeyswitchKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 32 {
+            return Err(PackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey32,
+        output_key: &GlweSecretKey32,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> PackingKeyswitchKey32 {
+        let mut pksk = ImplPackingKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        pksk.fill_with_packing_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        PackingKeyswitchKey32(pksk)
+    }
+}
+
+/// # Description:
+/// Implementation of [`PackingKeyswitchKeyCreationEngine`] for [`CoreEngine`] that operates on
+/// 64 bits integers.
+impl PackingKeyswitchKeyCreationEngine<LweSecretKey64, GlweSecretKey64, PackingKeyswitchKey64>
+    for CoreEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let input_key: LweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: GlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(output_glwe_dimension, polynomial_size)?;
+    ///
+    /// let packing_keyswitch_key = engine.create_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_level_count(),
+    /// #     decomposition_level_count
+    /// # );
+    /// assert_eq!(
+    /// #     packing_keyswitch_key.decomposition_base_log(),
+    /// #     decomposition_base_log
+    /// # );
+    /// assert_eq!(packing_keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(packing_keyswitch_key.output_polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(packing_keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_packing_keyswitch_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<PackingKeyswitchKey64, PackingKeyswitchKeyCreationError<Self::EngineError>> {
+        if decomposition_base_log.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(PackingKeyswitchKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(PackingKeyswitchKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_packing_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> PackingKeyswitchKey64 {
+        let mut pksk = ImplPackingKeyswitchKey::allocate(
+            0,
+            decomposition_level_count,
+            decomposition_base_log,
+            input_key.lwe_dimension(),
+            output_key.glwe_dimension(),
+            output_key.polynomial_size(),
+        );
+        pksk.fill_with_packing_keyswitch_key(
+            &input_key.0,
+            &output_key.0,
+            noise,
+            &mut self.encryption_generator,
+        );
+        PackingKeyswitchKey64(pksk)
+    }
+}
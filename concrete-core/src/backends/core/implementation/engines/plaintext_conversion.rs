@@ -0,0 +1,84 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{Plaintext32, Plaintext64};
+use crate::backends::core::private::crypto::encoding::Plaintext as ImplPlaintext;
+use crate::specification::engines::{
+    PlaintextConversionEngine, PlaintextConversionError, PlaintextDiscardingConversionEngine,
+};
+
+/// # Description:
+/// Implementation of [`PlaintextConversionEngine`] for [`CoreEngine`] that converts a 32 bits
+/// plaintext to a 64 bits plaintext. The torus elements are exactly rescaled to the output
+/// precision.
+impl PlaintextConversionEngine<Plaintext32, Plaintext64> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 20 bits)
+    /// let input = 3_u32 << 20;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext32 = engine.create_plaintext(&input)?;
+    ///
+    /// let output: Plaintext64 = engine.convert_plaintext(&plaintext)?;
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext(
+        &mut self,
+        input: &Plaintext32,
+    ) -> Result<Plaintext64, PlaintextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_unchecked(&mut self, input: &Plaintext32) -> Plaintext64 {
+        let mut output = Plaintext64(ImplPlaintext(0_u64));
+        self.discard_convert_plaintext_unchecked(&mut output, input);
+        output
+    }
+}
+
+/// # Description:
+/// Implementation of [`PlaintextConversionEngine`] for [`CoreEngine`] that converts a 64 bits
+/// plaintext to a 32 bits plaintext. The torus elements are rounded to the closest element of the
+/// output precision.
+impl PlaintextConversionEngine<Plaintext64, Plaintext32> for CoreEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = CoreEngine::new()?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext(&input)?;
+    ///
+    /// let output: Plaintext32 = engine.convert_plaintext(&plaintext)?;
+    ///
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn convert_plaintext(
+        &mut self,
+        input: &Plaintext64,
+    ) -> Result<Plaintext32, PlaintextConversionError<Self::EngineError>> {
+        Ok(unsafe { self.convert_plaintext_unchecked(input) })
+    }
+
+    unsafe fn convert_plaintext_unchecked(&mut self, input: &Plaintext64) -> Plaintext32 {
+        let mut output = Plaintext32(ImplPlaintext(0_u32));
+        self.discard_convert_plaintext_unchecked(&mut output, input);
+        output
+    }
+}
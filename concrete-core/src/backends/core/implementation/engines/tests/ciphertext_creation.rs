@@ -0,0 +1,102 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    GlweCiphertext32, GlweCiphertext64, GlweCiphertextMutView32, GlweCiphertextMutView64,
+    GlweCiphertextView32, GlweCiphertextView64, LweCiphertextVector32, LweCiphertextVector64,
+    LweCiphertextVectorMutView32, LweCiphertextVectorMutView64, LweCiphertextVectorView32,
+    LweCiphertextVectorView64,
+};
+use crate::specification::engines::{
+    AbstractEngine, GlweCiphertextCreationEngine, GlweCiphertextCreationError,
+    LweCiphertextVectorCreationEngine, LweCiphertextVectorCreationError,
+};
+use concrete_commons::parameters::{LweSize, PolynomialSize};
+
+#[test]
+fn test_lwe_ciphertext_vector_null_lwe_size() {
+    let mut engine = CoreEngine::new().unwrap();
+    let mut container_32 = vec![0_u32; 8];
+    let mut container_64 = vec![0_u64; 8];
+
+    let result: Result<LweCiphertextVectorView32, _> =
+        engine.create_lwe_ciphertext_vector(&container_32[..], LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+    let result: Result<LweCiphertextVectorMutView32, _> =
+        engine.create_lwe_ciphertext_vector(&mut container_32[..], LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+    let result: Result<LweCiphertextVector32, _> =
+        engine.create_lwe_ciphertext_vector(container_32, LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+
+    let result: Result<LweCiphertextVectorView64, _> =
+        engine.create_lwe_ciphertext_vector(&container_64[..], LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+    let result: Result<LweCiphertextVectorMutView64, _> =
+        engine.create_lwe_ciphertext_vector(&mut container_64[..], LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+    let result: Result<LweCiphertextVector64, _> =
+        engine.create_lwe_ciphertext_vector(container_64, LweSize(0));
+    assert!(matches!(
+        result,
+        Err(LweCiphertextVectorCreationError::NullLweSize)
+    ));
+}
+
+#[test]
+fn test_glwe_ciphertext_null_polynomial_size() {
+    let mut engine = CoreEngine::new().unwrap();
+    let mut container_32 = vec![0_u32; 8];
+    let mut container_64 = vec![0_u64; 8];
+
+    let result: Result<GlweCiphertextView32, _> =
+        engine.create_glwe_ciphertext(&container_32[..], PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+    let result: Result<GlweCiphertextMutView32, _> =
+        engine.create_glwe_ciphertext(&mut container_32[..], PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+    let result: Result<GlweCiphertext32, _> =
+        engine.create_glwe_ciphertext(container_32, PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+
+    let result: Result<GlweCiphertextView64, _> =
+        engine.create_glwe_ciphertext(&container_64[..], PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+    let result: Result<GlweCiphertextMutView64, _> =
+        engine.create_glwe_ciphertext(&mut container_64[..], PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+    let result: Result<GlweCiphertext64, _> =
+        engine.create_glwe_ciphertext(container_64, PolynomialSize(0));
+    assert!(matches!(
+        result,
+        Err(GlweCiphertextCreationError::NullPolynomialSize)
+    ));
+}
@@ -0,0 +1,183 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweBootstrapKey32, FourierLweBootstrapKey64, LweBootstrapKey32, LweBootstrapKey64,
+    LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::math::fft::Complex64;
+use crate::specification::engines::{
+    AbstractEngine, LweBootstrapKeyCreationFromContainerEngine,
+    LweBootstrapKeyCreationFromContainerError, LweKeyswitchKeyCreationFromContainerEngine,
+    LweKeyswitchKeyCreationFromContainerError,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+// The decomposition parameters, whose precision lies just below and at the precision of the
+// scalars.
+const VALID_32: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(31), DecompositionLevelCount(1));
+const TOO_LARGE_32: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(8), DecompositionLevelCount(4));
+const VALID_64: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(21), DecompositionLevelCount(3));
+const TOO_LARGE_64: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(16), DecompositionLevelCount(4));
+
+const KSK_OUTPUT_DIMENSION: LweDimension = LweDimension(3);
+const BSK_GLWE_DIMENSION: GlweDimension = GlweDimension(1);
+const BSK_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(128);
+
+// Returns the length of a keyswitch key with an input dimension of 2.
+fn keyswitch_key_len(level_count: DecompositionLevelCount) -> usize {
+    2 * level_count.0 * KSK_OUTPUT_DIMENSION.to_lwe_size().0
+}
+
+// Returns the length of a bootstrap key with an input dimension of 2.
+fn bootstrap_key_len(level_count: DecompositionLevelCount) -> usize {
+    let glwe_size = BSK_GLWE_DIMENSION.to_glwe_size().0;
+    2 * level_count.0 * glwe_size * glwe_size * BSK_POLYNOMIAL_SIZE.0
+}
+
+#[test]
+fn test_keyswitch_key_decomposition_precision_32() {
+    let mut engine = CoreEngine::new().unwrap();
+    let (base_log, level_count) = VALID_32;
+    let result: Result<LweKeyswitchKey32, _> = engine.create_lwe_keyswitch_key_from_container(
+        vec![0_u32; keyswitch_key_len(level_count)],
+        KSK_OUTPUT_DIMENSION,
+        base_log,
+        level_count,
+    );
+    assert!(result.is_ok());
+    let (base_log, level_count) = TOO_LARGE_32;
+    let result: Result<LweKeyswitchKey32, _> = engine.create_lwe_keyswitch_key_from_container(
+        vec![0_u32; keyswitch_key_len(level_count)],
+        KSK_OUTPUT_DIMENSION,
+        base_log,
+        level_count,
+    );
+    assert!(matches!(
+        result,
+        Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_keyswitch_key_decomposition_precision_64() {
+    let mut engine = CoreEngine::new().unwrap();
+    let (base_log, level_count) = VALID_64;
+    let result: Result<LweKeyswitchKey64, _> = engine.create_lwe_keyswitch_key_from_container(
+        vec![0_u64; keyswitch_key_len(level_count)],
+        KSK_OUTPUT_DIMENSION,
+        base_log,
+        level_count,
+    );
+    assert!(result.is_ok());
+    let (base_log, level_count) = TOO_LARGE_64;
+    let result: Result<LweKeyswitchKey64, _> = engine.create_lwe_keyswitch_key_from_container(
+        vec![0_u64; keyswitch_key_len(level_count)],
+        KSK_OUTPUT_DIMENSION,
+        base_log,
+        level_count,
+    );
+    assert!(matches!(
+        result,
+        Err(LweKeyswitchKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_bootstrap_key_decomposition_precision_32() {
+    let mut engine = CoreEngine::new().unwrap();
+    let (base_log, level_count) = VALID_32;
+    let result: Result<LweBootstrapKey32, _> = engine.create_lwe_bootstrap_key_from_container(
+        vec![0_u32; bootstrap_key_len(level_count)],
+        BSK_GLWE_DIMENSION,
+        BSK_POLYNOMIAL_SIZE,
+        base_log,
+        level_count,
+    );
+    assert!(result.is_ok());
+    let result: Result<FourierLweBootstrapKey32, _> = engine
+        .create_lwe_bootstrap_key_from_container(
+            AlignedVec::<Complex64>::new(bootstrap_key_len(level_count)),
+            BSK_GLWE_DIMENSION,
+            BSK_POLYNOMIAL_SIZE,
+            base_log,
+            level_count,
+        );
+    assert!(result.is_ok());
+    let (base_log, level_count) = TOO_LARGE_32;
+    let result: Result<LweBootstrapKey32, _> = engine.create_lwe_bootstrap_key_from_container(
+        vec![0_u32; bootstrap_key_len(level_count)],
+        BSK_GLWE_DIMENSION,
+        BSK_POLYNOMIAL_SIZE,
+        base_log,
+        level_count,
+    );
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+    let result: Result<FourierLweBootstrapKey32, _> = engine
+        .create_lwe_bootstrap_key_from_container(
+            AlignedVec::<Complex64>::new(bootstrap_key_len(level_count)),
+            BSK_GLWE_DIMENSION,
+            BSK_POLYNOMIAL_SIZE,
+            base_log,
+            level_count,
+        );
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_bootstrap_key_decomposition_precision_64() {
+    let mut engine = CoreEngine::new().unwrap();
+    let (base_log, level_count) = VALID_64;
+    let result: Result<LweBootstrapKey64, _> = engine.create_lwe_bootstrap_key_from_container(
+        vec![0_u64; bootstrap_key_len(level_count)],
+        BSK_GLWE_DIMENSION,
+        BSK_POLYNOMIAL_SIZE,
+        base_log,
+        level_count,
+    );
+    assert!(result.is_ok());
+    let result: Result<FourierLweBootstrapKey64, _> = engine
+        .create_lwe_bootstrap_key_from_container(
+            AlignedVec::<Complex64>::new(bootstrap_key_len(level_count)),
+            BSK_GLWE_DIMENSION,
+            BSK_POLYNOMIAL_SIZE,
+            base_log,
+            level_count,
+        );
+    assert!(result.is_ok());
+    let (base_log, level_count) = TOO_LARGE_64;
+    let result: Result<LweBootstrapKey64, _> = engine.create_lwe_bootstrap_key_from_container(
+        vec![0_u64; bootstrap_key_len(level_count)],
+        BSK_GLWE_DIMENSION,
+        BSK_POLYNOMIAL_SIZE,
+        base_log,
+        level_count,
+    );
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+    let result: Result<FourierLweBootstrapKey64, _> = engine
+        .create_lwe_bootstrap_key_from_container(
+            AlignedVec::<Complex64>::new(bootstrap_key_len(level_count)),
+            BSK_GLWE_DIMENSION,
+            BSK_POLYNOMIAL_SIZE,
+            base_log,
+            level_count,
+        );
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyCreationFromContainerError::DecompositionTooLarge)
+    ));
+}
@@ -0,0 +1,94 @@
+use crate::backends::core::implementation::engines::entity_serialization::FORMAT_VERSION;
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweSecretKey32, LweSecretKey64, LweTernarySecretKey64,
+};
+use crate::specification::engines::{
+    AbstractEngine, EntityDeserializationEngine, EntityDeserializationError,
+    EntitySerializationEngine, LweSecretKeyCreationEngine,
+};
+use concrete_commons::parameters::LweDimension;
+
+// Returns an engine along with a serialized 64 bits binary LWE secret key.
+fn serialized_key() -> (CoreEngine, Vec<u8>) {
+    let mut engine = CoreEngine::new().unwrap();
+    let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(4)).unwrap();
+    let serialized = engine.serialize(&key).unwrap();
+    (engine, serialized)
+}
+
+#[test]
+fn test_deserialization_round_trip() {
+    let (mut engine, serialized) = serialized_key();
+    let result: Result<LweSecretKey64, _> = engine.deserialize(serialized.as_slice());
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_deserialization_unsupported_version() {
+    let (mut engine, mut serialized) = serialized_key();
+    // The format version is written first, as a little endian `u16`.
+    serialized[..2].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+    let result: Result<LweSecretKey64, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::UnsupportedVersion)
+    ));
+}
+
+#[test]
+fn test_deserialization_malformed_header() {
+    let (mut engine, serialized) = serialized_key();
+    let result: Result<LweSecretKey64, _> = engine.deserialize(&serialized[..1]);
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::MalformedHeader)
+    ));
+    let result: Result<LweSecretKey64, _> = engine.deserialize(&serialized[..3]);
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::MalformedHeader)
+    ));
+}
+
+#[test]
+fn test_deserialization_precision_mismatch() {
+    let (mut engine, serialized) = serialized_key();
+    let result: Result<LweSecretKey32, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::PrecisionMismatch)
+    ));
+}
+
+#[test]
+fn test_deserialization_key_flavor_mismatch() {
+    let (mut engine, serialized) = serialized_key();
+    let result: Result<LweTernarySecretKey64, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::KeyFlavorMismatch)
+    ));
+}
+
+#[test]
+fn test_deserialization_truncated_payload() {
+    let (mut engine, mut serialized) = serialized_key();
+    serialized.pop();
+    let result: Result<LweSecretKey64, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::MalformedPayload)
+    ));
+}
+
+#[test]
+fn test_deserialization_trailing_payload() {
+    let (mut engine, mut serialized) = serialized_key();
+    serialized.push(0);
+    let result: Result<LweSecretKey64, _> = engine.deserialize(serialized.as_slice());
+    assert!(matches!(
+        result,
+        Err(EntityDeserializationError::MalformedPayload)
+    ));
+}
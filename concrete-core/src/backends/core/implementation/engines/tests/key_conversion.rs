@@ -0,0 +1,135 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    LweBootstrapKey32, LweBootstrapKey64, LweKeyswitchKey32, LweKeyswitchKey64,
+};
+use crate::backends::core::private::crypto::bootstrap::StandardBootstrapKey as ImplStandardBootstrapKey;
+use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
+use crate::specification::engines::{
+    AbstractEngine, LweBootstrapKeyConversionEngine, LweBootstrapKeyConversionError,
+    LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyDiscardingConversionError,
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError,
+    LweKeyswitchKeyDiscardingConversionEngine, LweKeyswitchKeyDiscardingConversionError,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+// The decomposition parameters, whose precision lies just below and at the precision of the
+// 32 bits keys.
+const VALID: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(31), DecompositionLevelCount(1));
+const TOO_LARGE: (DecompositionBaseLog, DecompositionLevelCount) =
+    (DecompositionBaseLog(8), DecompositionLevelCount(4));
+
+const INPUT_DIMENSION: LweDimension = LweDimension(2);
+const OUTPUT_DIMENSION: LweDimension = LweDimension(3);
+const GLWE_DIMENSION: GlweDimension = GlweDimension(1);
+const POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(128);
+
+fn keyswitch_key_64(
+    (base_log, level_count): (DecompositionBaseLog, DecompositionLevelCount),
+) -> LweKeyswitchKey64 {
+    LweKeyswitchKey64(ImplLweKeyswitchKey::allocate(
+        0_u64,
+        level_count,
+        base_log,
+        INPUT_DIMENSION,
+        OUTPUT_DIMENSION,
+    ))
+}
+
+fn keyswitch_key_32(
+    (base_log, level_count): (DecompositionBaseLog, DecompositionLevelCount),
+) -> LweKeyswitchKey32 {
+    LweKeyswitchKey32(ImplLweKeyswitchKey::allocate(
+        0_u32,
+        level_count,
+        base_log,
+        INPUT_DIMENSION,
+        OUTPUT_DIMENSION,
+    ))
+}
+
+fn bootstrap_key_64(
+    (base_log, level_count): (DecompositionBaseLog, DecompositionLevelCount),
+) -> LweBootstrapKey64 {
+    LweBootstrapKey64(ImplStandardBootstrapKey::allocate(
+        0_u64,
+        GLWE_DIMENSION.to_glwe_size(),
+        POLYNOMIAL_SIZE,
+        level_count,
+        base_log,
+        INPUT_DIMENSION,
+    ))
+}
+
+fn bootstrap_key_32(
+    (base_log, level_count): (DecompositionBaseLog, DecompositionLevelCount),
+) -> LweBootstrapKey32 {
+    LweBootstrapKey32(ImplStandardBootstrapKey::allocate(
+        0_u32,
+        GLWE_DIMENSION.to_glwe_size(),
+        POLYNOMIAL_SIZE,
+        level_count,
+        base_log,
+        INPUT_DIMENSION,
+    ))
+}
+
+#[test]
+fn test_keyswitch_key_conversion_decomposition_precision() {
+    let mut engine = CoreEngine::new().unwrap();
+    let result: Result<LweKeyswitchKey32, _> =
+        engine.convert_lwe_keyswitch_key(&keyswitch_key_64(VALID));
+    assert!(result.is_ok());
+    let result: Result<LweKeyswitchKey32, _> =
+        engine.convert_lwe_keyswitch_key(&keyswitch_key_64(TOO_LARGE));
+    assert!(matches!(
+        result,
+        Err(LweKeyswitchKeyConversionError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_keyswitch_key_discarding_conversion_decomposition_precision() {
+    let mut engine = CoreEngine::new().unwrap();
+    let mut output = keyswitch_key_32(VALID);
+    let result = engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key_64(VALID));
+    assert!(result.is_ok());
+    let mut output = keyswitch_key_32(TOO_LARGE);
+    let result =
+        engine.discard_convert_lwe_keyswitch_key(&mut output, &keyswitch_key_64(TOO_LARGE));
+    assert!(matches!(
+        result,
+        Err(LweKeyswitchKeyDiscardingConversionError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_bootstrap_key_conversion_decomposition_precision() {
+    let mut engine = CoreEngine::new().unwrap();
+    let result: Result<LweBootstrapKey32, _> =
+        engine.convert_lwe_bootstrap_key(&bootstrap_key_64(VALID));
+    assert!(result.is_ok());
+    let result: Result<LweBootstrapKey32, _> =
+        engine.convert_lwe_bootstrap_key(&bootstrap_key_64(TOO_LARGE));
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyConversionError::DecompositionTooLarge)
+    ));
+}
+
+#[test]
+fn test_bootstrap_key_discarding_conversion_decomposition_precision() {
+    let mut engine = CoreEngine::new().unwrap();
+    let mut output = bootstrap_key_32(VALID);
+    let result = engine.discard_convert_lwe_bootstrap_key(&mut output, &bootstrap_key_64(VALID));
+    assert!(result.is_ok());
+    let mut output = bootstrap_key_32(TOO_LARGE);
+    let result =
+        engine.discard_convert_lwe_bootstrap_key(&mut output, &bootstrap_key_64(TOO_LARGE));
+    assert!(matches!(
+        result,
+        Err(LweBootstrapKeyDiscardingConversionError::DecompositionTooLarge)
+    ));
+}
@@ -0,0 +1,214 @@
+use crate::backends::core::implementation::engines::CoreEngine;
+use crate::backends::core::implementation::entities::{
+    FourierLweGaussianBootstrapKey64, FourierLweTernaryBootstrapKey64, GlweCiphertext64,
+    GlweSecretKey64, LweBinaryToGaussianKeyswitchKey64, LweBinaryToTernaryKeyswitchKey64,
+    LweCiphertext64, LweGaussianBootstrapKey64, LweGaussianCiphertext64, LweGaussianSecretKey64,
+    LweSecretKey64, LweTernaryBootstrapKey64, LweTernaryCiphertext64, LweTernarySecretKey64,
+    Plaintext64,
+};
+use crate::specification::engines::{
+    AbstractEngine, GlweCiphertextEncryptionEngine, GlweSecretKeyCreationEngine,
+    LweBootstrapKeyConversionEngine, LweBootstrapKeyCreationEngine, LweCiphertextDecryptionEngine,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingKeyswitchEngine,
+    LweCiphertextEncryptionEngine, LweCiphertextZeroEncryptionEngine,
+    LweKeyswitchKeyCreationEngine, LweSecretKeyCreationEngine, PlaintextCreationEngine,
+    PlaintextVectorCreationEngine,
+};
+use crate::specification::entities::markers::BinaryKeyFlavor;
+use crate::specification::entities::{
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+// The noise used for all the encryptions, small enough for the decryptions to be checked with a
+// tight bound.
+const NOISE: Variance = Variance(2.0e-30);
+
+const INPUT_DIMENSION: LweDimension = LweDimension(32);
+const OUTPUT_DIMENSION: LweDimension = LweDimension(16);
+const GLWE_DIMENSION: GlweDimension = GlweDimension(1);
+const POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(512);
+
+// Returns the distance between two torus elements.
+fn torus_distance(first: u64, second: u64) -> u64 {
+    std::cmp::min(first.wrapping_sub(second), second.wrapping_sub(first))
+}
+
+fn decrypt<Key, Ciphertext>(engine: &mut CoreEngine, key: &Key, ciphertext: &Ciphertext) -> u64
+where
+    CoreEngine: LweCiphertextDecryptionEngine<Key, Ciphertext, Plaintext64>,
+    Key: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyFlavor = Key::KeyFlavor>,
+{
+    let plaintext: Plaintext64 = engine.decrypt_lwe_ciphertext(key, ciphertext).unwrap();
+    (plaintext.0).0
+}
+
+#[test]
+fn test_keyswitch_to_ternary_key() {
+    let mut engine = CoreEngine::new().unwrap();
+    let input_key: LweSecretKey64 = engine.create_lwe_secret_key(INPUT_DIMENSION).unwrap();
+    let output_key: LweTernarySecretKey64 = engine.create_lwe_secret_key(OUTPUT_DIMENSION).unwrap();
+    let keyswitch_key: LweBinaryToTernaryKeyswitchKey64 = engine
+        .create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(5),
+            DecompositionBaseLog(6),
+            NOISE,
+        )
+        .unwrap();
+    let zero = engine.create_plaintext(&0_u64).unwrap();
+    for &message in &[0_u64, 3 << 60, 13 << 60] {
+        let plaintext = engine.create_plaintext(&message).unwrap();
+        let input: LweCiphertext64 = engine
+            .encrypt_lwe_ciphertext(&input_key, &plaintext, NOISE)
+            .unwrap();
+        let mut output: LweTernaryCiphertext64 = engine
+            .encrypt_lwe_ciphertext(&output_key, &zero, NOISE)
+            .unwrap();
+        engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)
+            .unwrap();
+        let decrypted = decrypt(&mut engine, &output_key, &output);
+        assert!(torus_distance(decrypted, message) < 1 << 40);
+    }
+}
+
+#[test]
+fn test_keyswitch_to_gaussian_key() {
+    let mut engine = CoreEngine::new().unwrap();
+    let input_key: LweSecretKey64 = engine.create_lwe_secret_key(INPUT_DIMENSION).unwrap();
+    let output_key: LweGaussianSecretKey64 =
+        engine.create_lwe_secret_key(OUTPUT_DIMENSION).unwrap();
+    let keyswitch_key: LweBinaryToGaussianKeyswitchKey64 = engine
+        .create_lwe_keyswitch_key(
+            &input_key,
+            &output_key,
+            DecompositionLevelCount(5),
+            DecompositionBaseLog(6),
+            NOISE,
+        )
+        .unwrap();
+    let zero = engine.create_plaintext(&0_u64).unwrap();
+    for &message in &[0_u64, 3 << 60, 13 << 60] {
+        let plaintext = engine.create_plaintext(&message).unwrap();
+        let input: LweCiphertext64 = engine
+            .encrypt_lwe_ciphertext(&input_key, &plaintext, NOISE)
+            .unwrap();
+        let mut output: LweGaussianCiphertext64 = engine
+            .encrypt_lwe_ciphertext(&output_key, &zero, NOISE)
+            .unwrap();
+        engine
+            .discard_keyswitch_lwe_ciphertext(&mut output, &input, &keyswitch_key)
+            .unwrap();
+        let decrypted = decrypt(&mut engine, &output_key, &output);
+        assert!(torus_distance(decrypted, message) < 1 << 40);
+    }
+}
+
+// Bootstraps encryptions of odd multiples of an eighth of the torus, with an accumulator which
+// outputs the sign of the input phase, and checks the decryption of the outputs.
+fn check_sign_bootstrap<InputKey, Ciphertext, BootstrapKey>(
+    engine: &mut CoreEngine,
+    input_key: &InputKey,
+    glwe_key: &GlweSecretKey64,
+    bootstrap_key: &BootstrapKey,
+) where
+    CoreEngine: LweCiphertextEncryptionEngine<InputKey, Plaintext64, Ciphertext>
+        + LweCiphertextDiscardingBootstrapEngine<
+            BootstrapKey,
+            GlweCiphertext64,
+            Ciphertext,
+            LweCiphertext64,
+        >,
+    InputKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyFlavor = InputKey::KeyFlavor>,
+    BootstrapKey: LweBootstrapKeyEntity<
+        InputKeyFlavor = InputKey::KeyFlavor,
+        OutputKeyFlavor = BinaryKeyFlavor,
+    >,
+{
+    let output_key = LweSecretKey64(glwe_key.0.clone().into_lwe_secret_key());
+    let sign = 1_u64 << 60;
+    let lut = engine
+        .create_plaintext_vector(&vec![sign; POLYNOMIAL_SIZE.0])
+        .unwrap();
+    let acc = engine
+        .encrypt_glwe_ciphertext(glwe_key, &lut, NOISE)
+        .unwrap();
+    for &(message, expected) in &[
+        (1_u64 << 61, sign),
+        (3 << 61, sign),
+        (5 << 61, sign.wrapping_neg()),
+        (7 << 61, sign.wrapping_neg()),
+    ] {
+        let plaintext = engine.create_plaintext(&message).unwrap();
+        let input = engine
+            .encrypt_lwe_ciphertext(input_key, &plaintext, NOISE)
+            .unwrap();
+        let mut output = engine
+            .zero_encrypt_lwe_ciphertext(&output_key, NOISE)
+            .unwrap();
+        engine
+            .discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, bootstrap_key)
+            .unwrap();
+        let decrypted = decrypt(engine, &output_key, &output);
+        assert!(torus_distance(decrypted, expected) < 1 << 56);
+    }
+}
+
+#[test]
+fn test_bootstrap_from_ternary_key() {
+    let mut engine = CoreEngine::new().unwrap();
+    let input_key: LweTernarySecretKey64 = engine.create_lwe_secret_key(INPUT_DIMENSION).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(GLWE_DIMENSION, POLYNOMIAL_SIZE)
+        .unwrap();
+    let bootstrap_key: LweTernaryBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &input_key,
+            &glwe_key,
+            DecompositionBaseLog(15),
+            DecompositionLevelCount(2),
+            NOISE,
+        )
+        .unwrap();
+    let fourier_key: FourierLweTernaryBootstrapKey64 =
+        engine.convert_lwe_bootstrap_key(&bootstrap_key).unwrap();
+    check_sign_bootstrap::<_, LweTernaryCiphertext64, _>(
+        &mut engine,
+        &input_key,
+        &glwe_key,
+        &fourier_key,
+    );
+}
+
+#[test]
+fn test_bootstrap_from_gaussian_key() {
+    let mut engine = CoreEngine::new().unwrap();
+    let input_key: LweGaussianSecretKey64 = engine.create_lwe_secret_key(INPUT_DIMENSION).unwrap();
+    let glwe_key: GlweSecretKey64 = engine
+        .create_glwe_secret_key(GLWE_DIMENSION, POLYNOMIAL_SIZE)
+        .unwrap();
+    let bootstrap_key: LweGaussianBootstrapKey64 = engine
+        .create_lwe_bootstrap_key(
+            &input_key,
+            &glwe_key,
+            DecompositionBaseLog(15),
+            DecompositionLevelCount(2),
+            NOISE,
+        )
+        .unwrap();
+    let fourier_key: FourierLweGaussianBootstrapKey64 =
+        engine.convert_lwe_bootstrap_key(&bootstrap_key).unwrap();
+    check_sign_bootstrap::<_, LweGaussianCiphertext64, _>(
+        &mut engine,
+        &input_key,
+        &glwe_key,
+        &fourier_key,
+    );
+}
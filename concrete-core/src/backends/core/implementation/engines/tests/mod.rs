@@ -0,0 +1,5 @@
+mod ciphertext_creation;
+mod container_creation;
+mod entity_deserialization;
+mod key_conversion;
+mod key_flavors;
@@ -0,0 +1,91 @@
+use crate::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList as ImplPrivateFunctionalPackingKeyswitchKeyList;
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind,
+};
+use crate::specification::entities::{
+    AbstractEntity, CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity,
+};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing the private functional packing keyswitch keys used in a circuit
+/// bootstrap, with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32(
+    pub(crate) ImplPrivateFunctionalPackingKeyswitchKeyList<Vec<u32>>,
+);
+impl AbstractEntity for CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32 {
+    type Kind = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys32
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        self.0.key_count()
+    }
+}
+
+/// A structure representing the private functional packing keyswitch keys used in a circuit
+/// bootstrap, with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64(
+    pub(crate) ImplPrivateFunctionalPackingKeyswitchKeyList<Vec<u64>>,
+);
+impl AbstractEntity for CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64 {
+    type Kind = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind;
+}
+impl CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity
+    for CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys64
+{
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount {
+        self.0.key_count()
+    }
+}
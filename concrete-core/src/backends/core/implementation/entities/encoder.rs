@@ -0,0 +1,56 @@
+use crate::backends::core::private::crypto::encoding::FloatEncoder as ImplFloatEncoder;
+use crate::specification::entities::markers::EncoderKind;
+use crate::specification::entities::{AbstractEntity, EncoderEntity};
+
+/// A structure representing a floating point encoder, encoding the values of an interval with a
+/// given precision and number of padding bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatEncoder(pub(crate) ImplFloatEncoder);
+impl AbstractEntity for FloatEncoder {
+    type Kind = EncoderKind;
+}
+impl EncoderEntity for FloatEncoder {}
+
+/// A configuration of a [`FloatEncoder`] defined by the bounds of its interval.
+///
+/// The `[min, max]` interval is discretized in `2^nb_bit_precision` evenly spaced values, both
+/// bounds included, and the `nb_bit_padding` most significant bits of the encodings are left to
+/// zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatEncoderMinMaxConfig {
+    /// The lower bound of the interval.
+    pub min: f64,
+    /// The upper bound of the interval.
+    pub max: f64,
+    /// The number of bits of precision of the encodings.
+    pub nb_bit_precision: usize,
+    /// The number of bits of padding of the encodings.
+    pub nb_bit_padding: usize,
+}
+
+/// A configuration of a [`FloatEncoder`] defined by the center and the radius of its interval.
+///
+/// This configuration is equivalent to a [`FloatEncoderMinMaxConfig`] with bounds
+/// `center - radius` and `center + radius`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatEncoderCenterRadiusConfig {
+    /// The center of the interval.
+    pub center: f64,
+    /// The radius of the interval.
+    pub radius: f64,
+    /// The number of bits of precision of the encodings.
+    pub nb_bit_precision: usize,
+    /// The number of bits of padding of the encodings.
+    pub nb_bit_padding: usize,
+}
+
+impl From<FloatEncoderCenterRadiusConfig> for FloatEncoderMinMaxConfig {
+    fn from(config: FloatEncoderCenterRadiusConfig) -> Self {
+        FloatEncoderMinMaxConfig {
+            min: config.center - config.radius,
+            max: config.center + config.radius,
+            nb_bit_precision: config.nb_bit_precision,
+            nb_bit_padding: config.nb_bit_padding,
+        }
+    }
+}
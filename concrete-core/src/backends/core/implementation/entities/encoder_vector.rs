@@ -0,0 +1,16 @@
+use crate::backends::core::private::crypto::encoding::FloatEncoder as ImplFloatEncoder;
+use crate::specification::entities::markers::EncoderVectorKind;
+use crate::specification::entities::{AbstractEntity, EncoderVectorEntity};
+use concrete_commons::parameters::EncoderCount;
+
+/// A structure representing a vector of floating point encoders.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FloatEncoderVector(pub(crate) Vec<ImplFloatEncoder>);
+impl AbstractEntity for FloatEncoderVector {
+    type Kind = EncoderVectorKind;
+}
+impl EncoderVectorEntity for FloatEncoderVector {
+    fn encoder_count(&self) -> EncoderCount {
+        EncoderCount(self.0.len())
+    }
+}
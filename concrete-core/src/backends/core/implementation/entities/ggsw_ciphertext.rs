@@ -1,13 +1,17 @@
-use crate::backends::core::private::crypto::ggsw::GgswCiphertext as ImplGgswCiphertext;
+use crate::backends::core::private::crypto::ggsw::{
+    FourierGgswCiphertext as ImplFourierGgswCiphertext, GgswCiphertext as ImplGgswCiphertext,
+};
+use crate::backends::core::private::math::fft::Complex64;
 use crate::specification::entities::markers::{BinaryKeyFlavor, GgswCiphertextKind};
 use crate::specification::entities::{AbstractEntity, GgswCiphertextEntity};
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
 };
+use concrete_fftw::array::AlignedVec;
 
 /// A structure representing a GGSW ciphertext with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq)]
-pub struct GgswCiphertext32(ImplGgswCiphertext<Vec<u32>>);
+pub struct GgswCiphertext32(pub(crate) ImplGgswCiphertext<Vec<u32>>);
 impl AbstractEntity for GgswCiphertext32 {
     type Kind = GgswCiphertextKind;
 }
@@ -33,7 +37,7 @@ impl GgswCiphertextEntity for GgswCiphertext32 {
 
 /// A structure representing a GGSW ciphertext with 64 bits of precision.
 #[derive(Debug, Clone, PartialEq)]
-pub struct GgswCiphertext64(ImplGgswCiphertext<Vec<u64>>);
+pub struct GgswCiphertext64(pub(crate) ImplGgswCiphertext<Vec<u64>>);
 impl AbstractEntity for GgswCiphertext64 {
     type Kind = GgswCiphertextKind;
 }
@@ -56,3 +60,59 @@ impl GgswCiphertextEntity for GgswCiphertext64 {
         self.0.decomposition_base_log()
     }
 }
+
+/// A structure representing a GGSW ciphertext with 32 bits of precision in the fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierGgswCiphertext32(
+    pub(crate) ImplFourierGgswCiphertext<AlignedVec<Complex64>, u32>,
+);
+impl AbstractEntity for FourierGgswCiphertext32 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for FourierGgswCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a GGSW ciphertext with 64 bits of precision in the fourier domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierGgswCiphertext64(
+    pub(crate) ImplFourierGgswCiphertext<AlignedVec<Complex64>, u64>,
+);
+impl AbstractEntity for FourierGgswCiphertext64 {
+    type Kind = GgswCiphertextKind;
+}
+impl GgswCiphertextEntity for FourierGgswCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
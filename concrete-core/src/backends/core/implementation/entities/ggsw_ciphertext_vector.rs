@@ -0,0 +1,67 @@
+use crate::backends::core::private::crypto::ggsw::GgswList as ImplGgswList;
+use crate::specification::entities::markers::{BinaryKeyFlavor, GgswCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, GgswCiphertextVectorEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    PolynomialSize,
+};
+
+/// A structure representing a vector of GGSW ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgswCiphertextVector32(pub(crate) ImplGgswList<Vec<u32>>);
+impl AbstractEntity for GgswCiphertextVector32 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for GgswCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        self.0.ciphertext_count()
+    }
+}
+
+/// A structure representing a vector of GGSW ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgswCiphertextVector64(pub(crate) ImplGgswList<Vec<u64>>);
+impl AbstractEntity for GgswCiphertextVector64 {
+    type Kind = GgswCiphertextVectorKind;
+}
+impl GgswCiphertextVectorEntity for GgswCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+
+    fn ggsw_ciphertext_count(&self) -> GgswCiphertextCount {
+        self.0.ciphertext_count()
+    }
+}
@@ -1,5 +1,7 @@
 use super::super::super::private::crypto::glwe::GlweCiphertext as ImplGlweCiphertext;
-use crate::specification::entities::markers::{BinaryKeyFlavor, GlweCiphertextKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweCiphertextKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
@@ -38,3 +40,155 @@ impl GlweCiphertextEntity for GlweCiphertext64 {
         self.0.polynomial_size()
     }
 }
+
+/// A structure representing a GLWE ciphertext encrypted under a ternary key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernaryCiphertext32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+impl AbstractEntity for GlweTernaryCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweTernaryCiphertext32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a ternary key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernaryCiphertext64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+impl AbstractEntity for GlweTernaryCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweTernaryCiphertext64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a gaussian key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianCiphertext32(pub(crate) ImplGlweCiphertext<Vec<u32>>);
+impl AbstractEntity for GlweGaussianCiphertext32 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweGaussianCiphertext32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext encrypted under a gaussian key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianCiphertext64(pub(crate) ImplGlweCiphertext<Vec<u64>>);
+impl AbstractEntity for GlweGaussianCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweGaussianCiphertext64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, viewing an immutable slice
+/// of scalars it does not own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertextView32<'a>(pub(crate) ImplGlweCiphertext<&'a [u32]>);
+impl AbstractEntity for GlweCiphertextView32<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweCiphertextView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 32 bits of precision, viewing a mutable slice of
+/// scalars it does not own.
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextMutView32<'a>(pub(crate) ImplGlweCiphertext<&'a mut [u32]>);
+impl AbstractEntity for GlweCiphertextMutView32<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweCiphertextMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, viewing an immutable slice
+/// of scalars it does not own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweCiphertextView64<'a>(pub(crate) ImplGlweCiphertext<&'a [u64]>);
+impl AbstractEntity for GlweCiphertextView64<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweCiphertextView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, viewing a mutable slice of
+/// scalars it does not own.
+#[derive(Debug, PartialEq)]
+pub struct GlweCiphertextMutView64<'a>(pub(crate) ImplGlweCiphertext<&'a mut [u64]>);
+impl AbstractEntity for GlweCiphertextMutView64<'_> {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for GlweCiphertextMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
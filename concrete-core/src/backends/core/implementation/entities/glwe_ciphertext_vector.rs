@@ -1,5 +1,7 @@
 use super::super::super::private::crypto::glwe::GlweList as ImplGlweList;
-use crate::specification::entities::markers::{BinaryKeyFlavor, GlweCiphertextVectorKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweCiphertextVectorKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, GlweCiphertextVectorEntity};
 use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
 
@@ -46,3 +48,95 @@ impl GlweCiphertextVectorEntity for GlweCiphertextVector64 {
         GlweCiphertextCount(self.0.ciphertext_count().0)
     }
 }
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a ternary key,
+/// with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernaryCiphertextVector32(pub(crate) ImplGlweList<Vec<u32>>);
+impl AbstractEntity for GlweTernaryCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweTernaryCiphertextVector32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a ternary key,
+/// with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernaryCiphertextVector64(pub(crate) ImplGlweList<Vec<u64>>);
+impl AbstractEntity for GlweTernaryCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweTernaryCiphertextVector64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a gaussian key,
+/// with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianCiphertextVector32(pub(crate) ImplGlweList<Vec<u32>>);
+impl AbstractEntity for GlweGaussianCiphertextVector32 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweGaussianCiphertextVector32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of GLWE ciphertexts encrypted under a gaussian key,
+/// with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianCiphertextVector64(pub(crate) ImplGlweList<Vec<u64>>);
+impl AbstractEntity for GlweGaussianCiphertextVector64 {
+    type Kind = GlweCiphertextVectorKind;
+}
+impl GlweCiphertextVectorEntity for GlweGaussianCiphertextVector64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
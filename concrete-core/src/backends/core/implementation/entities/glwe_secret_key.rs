@@ -1,7 +1,9 @@
 use crate::backends::core::private::crypto::secret::GlweSecretKey as ImpGlweSecretKey;
-use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSecretKeyKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, GlweSecretKeyKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
 
 /// A structure representing a GLWE secret key with 32 bits of precision.
@@ -39,3 +41,75 @@ impl GlweSecretKeyEntity for GlweSecretKey64 {
         self.0.polynomial_size()
     }
 }
+
+/// A structure representing a ternary GLWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernarySecretKey32(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweTernarySecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a ternary GLWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweTernarySecretKey64(pub(crate) ImpGlweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweTernarySecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweTernarySecretKey64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a gaussian GLWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianSecretKey32(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for GlweGaussianSecretKey32 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweGaussianSecretKey32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a gaussian GLWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweGaussianSecretKey64(pub(crate) ImpGlweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for GlweGaussianSecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for GlweGaussianSecretKey64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.key_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
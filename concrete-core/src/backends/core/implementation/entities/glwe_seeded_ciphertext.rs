@@ -0,0 +1,40 @@
+use super::super::super::private::crypto::glwe::GlweSeededCiphertext as ImplGlweSeededCiphertext;
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSeededCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GlweSeededCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a seeded GLWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSeededCiphertext32(pub(crate) ImplGlweSeededCiphertext<Vec<u32>>);
+impl AbstractEntity for GlweSeededCiphertext32 {
+    type Kind = GlweSeededCiphertextKind;
+}
+impl GlweSeededCiphertextEntity for GlweSeededCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.mask_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
+
+/// A structure representing a seeded GLWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSeededCiphertext64(pub(crate) ImplGlweSeededCiphertext<Vec<u64>>);
+impl AbstractEntity for GlweSeededCiphertext64 {
+    type Kind = GlweSeededCiphertextKind;
+}
+impl GlweSeededCiphertextEntity for GlweSeededCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.mask_size()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+}
@@ -0,0 +1,48 @@
+use super::super::super::private::crypto::glwe::GlweSeededList as ImplGlweSeededList;
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSeededCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, GlweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+/// A structure representing a vector of seeded GLWE ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSeededCiphertextVector32(pub(crate) ImplGlweSeededList<Vec<u32>>);
+impl AbstractEntity for GlweSeededCiphertextVector32 {
+    type Kind = GlweSeededCiphertextVectorKind;
+}
+impl GlweSeededCiphertextVectorEntity for GlweSeededCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
+
+/// A structure representing a vector of seeded GLWE ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlweSeededCiphertextVector64(pub(crate) ImplGlweSeededList<Vec<u64>>);
+impl AbstractEntity for GlweSeededCiphertextVector64 {
+    type Kind = GlweSeededCiphertextVectorKind;
+}
+impl GlweSeededCiphertextVectorEntity for GlweSeededCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount {
+        GlweCiphertextCount(self.0.ciphertext_count().0)
+    }
+}
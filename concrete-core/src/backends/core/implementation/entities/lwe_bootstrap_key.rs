@@ -3,13 +3,22 @@ use crate::backends::core::private::crypto::bootstrap::{
     StandardBootstrapKey as ImplStandardBootstrapKey,
 };
 use crate::backends::core::private::math::fft::Complex64;
-use crate::specification::entities::markers::{BinaryKeyFlavor, LweBootstrapKeyKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweBootstrapKeyKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
 use concrete_fftw::array::AlignedVec;
 
+/// The number of bits of the signed binary expansion of the gaussian keys, used by the bootstrap
+/// keys created from gaussian keys.
+///
+/// The coefficients of the gaussian keys have a standard deviation close to 3.2, and lie in
+/// $]-2^5, 2^5[$ with overwhelming probability.
+pub(crate) const GAUSSIAN_KEY_EXPANSION_WIDTH: usize = 5;
+
 /// A structure representing an LWE bootstrap key with 32 bits of precision.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LweBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
@@ -72,6 +81,146 @@ impl LweBootstrapKeyEntity for LweBootstrapKey64 {
     }
 }
 
+/// A structure representing an LWE bootstrap key from a ternary key, with 32 bits of precision.
+///
+/// The key is stored as a standard bootstrap key for the binary expansion of the ternary input
+/// key, which holds twice as many coefficients as the ternary key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweTernaryBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey32 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 64 bits of precision.
+///
+/// The key is stored as a standard bootstrap key for the binary expansion of the ternary input
+/// key, which holds twice as many coefficients as the ternary key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweTernaryBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweTernaryBootstrapKey64 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 32 bits of precision.
+///
+/// The key is stored as a standard bootstrap key for the signed binary expansion of the gaussian
+/// input key over five bits, which holds ten times as many coefficients as the gaussian key. The
+/// creation of the key fails in the unlikely case where a coefficient of the gaussian key does not
+/// fit in five bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweGaussianBootstrapKey32(pub(crate) ImplStandardBootstrapKey<Vec<u32>>);
+impl AbstractEntity for LweGaussianBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweGaussianBootstrapKey32 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / (2 * GAUSSIAN_KEY_EXPANSION_WIDTH))
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 64 bits of precision.
+///
+/// The key is stored as a standard bootstrap key for the signed binary expansion of the gaussian
+/// input key over five bits, which holds ten times as many coefficients as the gaussian key. The
+/// creation of the key fails in the unlikely case where a coefficient of the gaussian key does not
+/// fit in five bits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweGaussianBootstrapKey64(pub(crate) ImplStandardBootstrapKey<Vec<u64>>);
+impl AbstractEntity for LweGaussianBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for LweGaussianBootstrapKey64 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / (2 * GAUSSIAN_KEY_EXPANSION_WIDTH))
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
 /// A structure representing an LWE bootstrap key with 32 bits of precision, in the fourier domain.
 #[derive(Debug, Clone, PartialEq)]
 pub struct FourierLweBootstrapKey32(pub(crate) ImplFourierBootstrapKey<AlignedVec<Complex64>, u32>);
@@ -133,3 +282,151 @@ impl LweBootstrapKeyEntity for FourierLweBootstrapKey64 {
         self.0.level_count()
     }
 }
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 32 bits of precision,
+/// in the fourier domain.
+///
+/// As for [`LweTernaryBootstrapKey32`], the key is built from the binary expansion of the ternary
+/// input key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierLweTernaryBootstrapKey32(
+    pub(crate) ImplFourierBootstrapKey<AlignedVec<Complex64>, u32>,
+);
+impl AbstractEntity for FourierLweTernaryBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FourierLweTernaryBootstrapKey32 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a ternary key, with 64 bits of precision,
+/// in the fourier domain.
+///
+/// As for [`LweTernaryBootstrapKey64`], the key is built from the binary expansion of the ternary
+/// input key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierLweTernaryBootstrapKey64(
+    pub(crate) ImplFourierBootstrapKey<AlignedVec<Complex64>, u64>,
+);
+impl AbstractEntity for FourierLweTernaryBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FourierLweTernaryBootstrapKey64 {
+    type InputKeyFlavor = TernaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / 2)
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 32 bits of precision,
+/// in the fourier domain.
+///
+/// As for [`LweGaussianBootstrapKey32`], the key is built from the signed binary expansion of the
+/// gaussian input key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierLweGaussianBootstrapKey32(
+    pub(crate) ImplFourierBootstrapKey<AlignedVec<Complex64>, u32>,
+);
+impl AbstractEntity for FourierLweGaussianBootstrapKey32 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FourierLweGaussianBootstrapKey32 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / (2 * GAUSSIAN_KEY_EXPANSION_WIDTH))
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
+
+/// A structure representing an LWE bootstrap key from a gaussian key, with 64 bits of precision,
+/// in the fourier domain.
+///
+/// As for [`LweGaussianBootstrapKey64`], the key is built from the signed binary expansion of the
+/// gaussian input key.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FourierLweGaussianBootstrapKey64(
+    pub(crate) ImplFourierBootstrapKey<AlignedVec<Complex64>, u64>,
+);
+impl AbstractEntity for FourierLweGaussianBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for FourierLweGaussianBootstrapKey64 {
+    type InputKeyFlavor = GaussianKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.0.glwe_size().to_glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.0.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.0.key_size().0 / (2 * GAUSSIAN_KEY_EXPANSION_WIDTH))
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.level_count()
+    }
+}
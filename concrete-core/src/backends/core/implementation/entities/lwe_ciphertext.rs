@@ -1,5 +1,7 @@
 use super::super::super::private::crypto::lwe::LweCiphertext as ImplLweCiphertext;
-use crate::specification::entities::markers::{BinaryKeyFlavor, LweCiphertextKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweCiphertextKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
 use concrete_commons::parameters::LweDimension;
 
@@ -30,3 +32,123 @@ impl LweCiphertextEntity for LweCiphertext64 {
         self.0.lwe_size().to_lwe_dimension()
     }
 }
+
+/// A structure representing an LWE ciphertext encrypted under a ternary key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryCiphertext32(pub(crate) ImplLweCiphertext<Vec<u32>>);
+impl AbstractEntity for LweTernaryCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweTernaryCiphertext32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a ternary key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryCiphertext64(pub(crate) ImplLweCiphertext<Vec<u64>>);
+impl AbstractEntity for LweTernaryCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweTernaryCiphertext64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a gaussian key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianCiphertext32(pub(crate) ImplLweCiphertext<Vec<u32>>);
+impl AbstractEntity for LweGaussianCiphertext32 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweGaussianCiphertext32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext encrypted under a gaussian key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianCiphertext64(pub(crate) ImplLweCiphertext<Vec<u64>>);
+impl AbstractEntity for LweGaussianCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweGaussianCiphertext64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, viewing an immutable slice
+/// of scalars it does not own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextView32<'a>(pub(crate) ImplLweCiphertext<&'a [u32]>);
+impl AbstractEntity for LweCiphertextView32<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertextView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 32 bits of precision, viewing a mutable slice of
+/// scalars it does not own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextMutView32<'a>(pub(crate) ImplLweCiphertext<&'a mut [u32]>);
+impl AbstractEntity for LweCiphertextMutView32<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertextMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, viewing an immutable slice
+/// of scalars it does not own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextView64<'a>(pub(crate) ImplLweCiphertext<&'a [u64]>);
+impl AbstractEntity for LweCiphertextView64<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertextView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, viewing a mutable slice of
+/// scalars it does not own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextMutView64<'a>(pub(crate) ImplLweCiphertext<&'a mut [u64]>);
+impl AbstractEntity for LweCiphertextMutView64<'_> {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for LweCiphertextMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+}
@@ -1,5 +1,7 @@
 use super::super::super::private::crypto::lwe::LweList as ImplLweList;
-use crate::specification::entities::markers::{BinaryKeyFlavor, LweCiphertextVectorKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweCiphertextVectorKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
 use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
 
@@ -38,3 +40,155 @@ impl LweCiphertextVectorEntity for LweCiphertextVector64 {
         LweCiphertextCount(self.0.count().0)
     }
 }
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a ternary key,
+/// with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryCiphertextVector32(pub(crate) ImplLweList<Vec<u32>>);
+impl AbstractEntity for LweTernaryCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweTernaryCiphertextVector32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a ternary key,
+/// with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweTernaryCiphertextVector64(pub(crate) ImplLweList<Vec<u64>>);
+impl AbstractEntity for LweTernaryCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweTernaryCiphertextVector64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a gaussian key,
+/// with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianCiphertextVector32(pub(crate) ImplLweList<Vec<u32>>);
+impl AbstractEntity for LweGaussianCiphertextVector32 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweGaussianCiphertextVector32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts encrypted under a gaussian key,
+/// with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweGaussianCiphertextVector64(pub(crate) ImplLweList<Vec<u64>>);
+impl AbstractEntity for LweGaussianCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweGaussianCiphertextVector64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing an
+/// immutable slice of scalars it does not own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextVectorView32<'a>(pub(crate) ImplLweList<&'a [u32]>);
+impl AbstractEntity for LweCiphertextVectorView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweCiphertextVectorView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 32 bits of precision, viewing a
+/// mutable slice of scalars it does not own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextVectorMutView32<'a>(pub(crate) ImplLweList<&'a mut [u32]>);
+impl AbstractEntity for LweCiphertextVectorMutView32<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweCiphertextVectorMutView32<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing an
+/// immutable slice of scalars it does not own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCiphertextVectorView64<'a>(pub(crate) ImplLweList<&'a [u64]>);
+impl AbstractEntity for LweCiphertextVectorView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweCiphertextVectorView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, viewing a
+/// mutable slice of scalars it does not own.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LweCiphertextVectorMutView64<'a>(pub(crate) ImplLweList<&'a mut [u64]>);
+impl AbstractEntity for LweCiphertextVectorMutView64<'_> {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for LweCiphertextVectorMutView64<'_> {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
@@ -0,0 +1,24 @@
+use super::super::super::private::crypto::lwe::LweCompressedCiphertext as ImplLweCompressedCiphertext;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCompressedCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextEntity};
+use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+
+/// A structure representing a compressed LWE ciphertext with 64 bits of precision.
+///
+/// The coefficients of the ciphertext are stored on 32 bits, hence its modulus log is at most 32.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertext64(pub(crate) ImplLweCompressedCiphertext<Vec<u32>>);
+impl AbstractEntity for LweCompressedCiphertext64 {
+    type Kind = LweCompressedCiphertextKind;
+}
+impl LweCompressedCiphertextEntity for LweCompressedCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+
+    fn modulus_log(&self) -> CiphertextModulusLog {
+        self.0.modulus_log()
+    }
+}
@@ -0,0 +1,29 @@
+use super::super::super::private::crypto::lwe::LweCompressedList as ImplLweCompressedList;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCompressedCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCompressedCiphertextVectorEntity};
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of compressed LWE ciphertexts with 64 bits of precision.
+///
+/// The coefficients of the ciphertexts are stored on 32 bits, hence their modulus log is at most
+/// 32.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweCompressedCiphertextVector64(pub(crate) ImplLweCompressedList<Vec<u32>>);
+impl AbstractEntity for LweCompressedCiphertextVector64 {
+    type Kind = LweCompressedCiphertextVectorKind;
+}
+impl LweCompressedCiphertextVectorEntity for LweCompressedCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+
+    fn modulus_log(&self) -> CiphertextModulusLog {
+        self.0.modulus_log()
+    }
+}
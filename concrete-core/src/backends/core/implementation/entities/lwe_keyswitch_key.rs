@@ -1,5 +1,7 @@
 use crate::backends::core::private::crypto::lwe::LweKeyswitchKey as ImplLweKeyswitchKey;
-use crate::specification::entities::markers::{BinaryKeyFlavor, LweKeyswitchKeyKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweKeyswitchKeyKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
 
@@ -56,3 +58,115 @@ impl LweKeyswitchKeyEntity for LweKeyswitchKey64 {
         self.0.decomposition_base_log()
     }
 }
+
+/// A structure representing an LWE keyswitch key from a binary key to a ternary key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweBinaryToTernaryKeyswitchKey32(pub(crate) ImplLweKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweBinaryToTernaryKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweBinaryToTernaryKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = TernaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a ternary key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweBinaryToTernaryKeyswitchKey64(pub(crate) ImplLweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweBinaryToTernaryKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweBinaryToTernaryKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = TernaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a gaussian key, with 32 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweBinaryToGaussianKeyswitchKey32(pub(crate) ImplLweKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for LweBinaryToGaussianKeyswitchKey32 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweBinaryToGaussianKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = GaussianKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing an LWE keyswitch key from a binary key to a gaussian key, with 64 bits
+/// of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweBinaryToGaussianKeyswitchKey64(pub(crate) ImplLweKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for LweBinaryToGaussianKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for LweBinaryToGaussianKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = GaussianKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.before_key_size()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.0.after_key_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
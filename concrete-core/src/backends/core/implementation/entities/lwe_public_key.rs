@@ -0,0 +1,40 @@
+use crate::backends::core::private::crypto::lwe::LwePublicKey as ImplLwePublicKey;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LwePublicKeyKind};
+use crate::specification::entities::{AbstractEntity, LwePublicKeyEntity};
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// A structure representing an LWE public key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LwePublicKey32(pub(crate) ImplLwePublicKey<Vec<u32>>);
+impl AbstractEntity for LwePublicKey32 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for LwePublicKey32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        self.0.zero_encryption_count()
+    }
+}
+
+/// A structure representing an LWE public key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LwePublicKey64(pub(crate) ImplLwePublicKey<Vec<u64>>);
+impl AbstractEntity for LwePublicKey64 {
+    type Kind = LwePublicKeyKind;
+}
+impl LwePublicKeyEntity for LwePublicKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_size().to_lwe_dimension()
+    }
+
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount {
+        self.0.zero_encryption_count()
+    }
+}
@@ -1,7 +1,9 @@
 use crate::backends::core::private::crypto::secret::LweSecretKey as ImpLweSecretKey;
-use crate::specification::entities::markers::{BinaryKeyFlavor, LweSecretKeyKind};
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, GaussianKeyFlavor, LweSecretKeyKind, TernaryKeyFlavor,
+};
 use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::{BinaryKeyKind, GaussianKeyKind, TernaryKeyKind};
 use concrete_commons::parameters::LweDimension;
 
 /// A structure representing an LWE secret key with 32 bits of precision.
@@ -31,3 +33,59 @@ impl LweSecretKeyEntity for LweSecretKey64 {
         self.0.key_size()
     }
 }
+
+/// A structure representing a ternary LWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweTernarySecretKey32(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u32>>);
+impl AbstractEntity for LweTernarySecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey32 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+/// A structure representing a ternary LWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweTernarySecretKey64(pub(crate) ImpLweSecretKey<TernaryKeyKind, Vec<u64>>);
+impl AbstractEntity for LweTernarySecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweTernarySecretKey64 {
+    type KeyFlavor = TernaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+/// A structure representing a gaussian LWE secret key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweGaussianSecretKey32(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u32>>);
+impl AbstractEntity for LweGaussianSecretKey32 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweGaussianSecretKey32 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
+
+/// A structure representing a gaussian LWE secret key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LweGaussianSecretKey64(pub(crate) ImpLweSecretKey<GaussianKeyKind, Vec<u64>>);
+impl AbstractEntity for LweGaussianSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for LweGaussianSecretKey64 {
+    type KeyFlavor = GaussianKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.key_size()
+    }
+}
@@ -0,0 +1,32 @@
+use super::super::super::private::crypto::lwe::LweSeededCiphertext as ImplLweSeededCiphertext;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweSeededCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweSeededCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing a seeded LWE ciphertext with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertext32(pub(crate) ImplLweSeededCiphertext<u32>);
+impl AbstractEntity for LweSeededCiphertext32 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for LweSeededCiphertext32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+}
+
+/// A structure representing a seeded LWE ciphertext with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertext64(pub(crate) ImplLweSeededCiphertext<u64>);
+impl AbstractEntity for LweSeededCiphertext64 {
+    type Kind = LweSeededCiphertextKind;
+}
+impl LweSeededCiphertextEntity for LweSeededCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+}
@@ -0,0 +1,40 @@
+use super::super::super::private::crypto::lwe::LweSeededList as ImplLweSeededList;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweSeededCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweSeededCiphertextVectorEntity};
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of seeded LWE ciphertexts with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertextVector32(pub(crate) ImplLweSeededList<Vec<u32>>);
+impl AbstractEntity for LweSeededCiphertextVector32 {
+    type Kind = LweSeededCiphertextVectorKind;
+}
+impl LweSeededCiphertextVectorEntity for LweSeededCiphertextVector32 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
+
+/// A structure representing a vector of seeded LWE ciphertexts with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LweSeededCiphertextVector64(pub(crate) ImplLweSeededList<Vec<u64>>);
+impl AbstractEntity for LweSeededCiphertextVector64 {
+    type Kind = LweSeededCiphertextVectorKind;
+}
+impl LweSeededCiphertextVectorEntity for LweSeededCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.0.lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.0.count().0)
+    }
+}
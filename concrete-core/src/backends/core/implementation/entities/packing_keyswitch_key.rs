@@ -0,0 +1,68 @@
+use crate::backends::core::private::crypto::glwe::PackingKeyswitchKey as ImplPackingKeyswitchKey;
+use crate::specification::entities::markers::{BinaryKeyFlavor, PackingKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, PackingKeyswitchKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing a packing keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackingKeyswitchKey32(pub(crate) ImplPackingKeyswitchKey<Vec<u32>>);
+impl AbstractEntity for PackingKeyswitchKey32 {
+    type Kind = PackingKeyswitchKeyKind;
+}
+impl PackingKeyswitchKeyEntity for PackingKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a packing keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackingKeyswitchKey64(pub(crate) ImplPackingKeyswitchKey<Vec<u64>>);
+impl AbstractEntity for PackingKeyswitchKey64 {
+    type Kind = PackingKeyswitchKeyKind;
+}
+impl PackingKeyswitchKeyEntity for PackingKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
@@ -0,0 +1,74 @@
+use crate::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKey as ImplPrivateFunctionalPackingKeyswitchKey;
+use crate::specification::entities::markers::{
+    BinaryKeyFlavor, PrivateFunctionalPackingKeyswitchKeyKind,
+};
+use crate::specification::entities::{AbstractEntity, PrivateFunctionalPackingKeyswitchKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing a private functional packing keyswitch key with 32 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrivateFunctionalPackingKeyswitchKey32(
+    pub(crate) ImplPrivateFunctionalPackingKeyswitchKey<Vec<u32>>,
+);
+impl AbstractEntity for PrivateFunctionalPackingKeyswitchKey32 {
+    type Kind = PrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl PrivateFunctionalPackingKeyswitchKeyEntity for PrivateFunctionalPackingKeyswitchKey32 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
+
+/// A structure representing a private functional packing keyswitch key with 64 bits of precision.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrivateFunctionalPackingKeyswitchKey64(
+    pub(crate) ImplPrivateFunctionalPackingKeyswitchKey<Vec<u64>>,
+);
+impl AbstractEntity for PrivateFunctionalPackingKeyswitchKey64 {
+    type Kind = PrivateFunctionalPackingKeyswitchKeyKind;
+}
+impl PrivateFunctionalPackingKeyswitchKeyEntity for PrivateFunctionalPackingKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.0.input_lwe_key_dimension()
+    }
+
+    fn output_glwe_dimension(&self) -> GlweDimension {
+        self.0.output_glwe_key_dimension()
+    }
+
+    fn output_polynomial_size(&self) -> PolynomialSize {
+        self.0.output_polynomial_size()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.0.decomposition_levels_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.0.decomposition_base_log()
+    }
+}
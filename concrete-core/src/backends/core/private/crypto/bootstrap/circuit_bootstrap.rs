@@ -0,0 +1,128 @@
+use crate::backends::core::private::crypto::bootstrap::{Bootstrap, FourierBootstrapKey};
+use crate::backends::core::private::crypto::ggsw::GgswCiphertext;
+use crate::backends::core::private::crypto::glwe::{
+    GlweCiphertext, PrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+impl<Cont, Scalar> FourierBootstrapKey<Cont, Scalar>
+where
+    Self: AsRefTensor<Element = Complex64>,
+    Scalar: UnsignedTorus,
+{
+    /// Performs a circuit bootstrap of an LWE ciphertext encrypting a bit, and writes the
+    /// resulting GGSW ciphertext in `output`.
+    ///
+    /// The input ciphertext must encrypt a bit $m$ encoded as $m\cdot\frac{q}{2}$. For each level
+    /// $j$ of the output GGSW, a programmable bootstrap produces an LWE encryption of
+    /// $m\cdot\frac{q}{B^j}$ under the output key of the bootstrap key. Each row of the $j$-th
+    /// level matrix is then obtained with a private functional packing keyswitch, the list
+    /// containing one key per row (see
+    /// [`PrivateFunctionalPackingKeyswitchKeyList::fill_with_circuit_bootstrap_keys`]).
+    ///
+    /// The product of the decomposition base log and level count of the output must be smaller
+    /// than the number of bits of `Scalar`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, GlweSize, LweDimension, LweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswCiphertext;
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let bsk: FourierBootstrapKey<_, u32> = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     GlweSize(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let pfpksk_list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0u32,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(256),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(2),
+    /// );
+    /// let lwe_in = LweCiphertext::allocate(0u32, LweSize(5));
+    /// let mut ggsw_out = GgswCiphertext::allocate(
+    ///     0u32,
+    ///     PolynomialSize(256),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// bsk.circuit_bootstrap(&mut ggsw_out, &lwe_in, &pfpksk_list);
+    /// ```
+    pub fn circuit_bootstrap<OutCont, InCont, KeyCont>(
+        &self,
+        output: &mut GgswCiphertext<OutCont>,
+        input: &LweCiphertext<InCont>,
+        pfpksk_list: &PrivateFunctionalPackingKeyswitchKeyList<KeyCont>,
+    ) where
+        GgswCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        PrivateFunctionalPackingKeyswitchKeyList<KeyCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.key_size().0 => input.lwe_size().to_lwe_dimension().0);
+        ck_dim_eq!(self.output_lwe_dimension().0 => pfpksk_list.input_lwe_key_dimension().0);
+        ck_dim_eq!(output.glwe_size().0 => pfpksk_list.key_count().0);
+        ck_dim_eq!(
+            output.glwe_size().to_glwe_dimension().0 =>
+            pfpksk_list.output_glwe_key_dimension().0
+        );
+        ck_dim_eq!(output.polynomial_size().0 => pfpksk_list.output_polynomial_size().0);
+        let base_log = output.decomposition_base_log();
+        let level_count = output.decomposition_level_count();
+        assert!(
+            base_log.0 * level_count.0 < Scalar::BITS,
+            "The output decomposition must fit in the scalar type."
+        );
+
+        // We shift the input phase by q/4, so that the bit lands on either half of the torus.
+        let mut shifted = LweCiphertext::allocate(Scalar::ZERO, input.lwe_size());
+        shifted.as_mut_tensor().fill_with_copy(input.as_tensor());
+        let body = shifted.get_mut_body();
+        body.0 = body.0.wrapping_add(Scalar::ONE << (Scalar::BITS - 2));
+
+        let mut accumulator =
+            GlweCiphertext::allocate(Scalar::ZERO, self.polynomial_size(), self.glwe_size());
+        let mut extracted =
+            LweCiphertext::allocate(Scalar::ZERO, self.output_lwe_dimension().to_lwe_size());
+
+        for mut level_matrix in output.level_matrix_iter_mut() {
+            // The bootstrap maps the bit m to (2m - 1)q/(2B^j), which we then shift by q/(2B^j)
+            // to get an encryption of mq/B^j.
+            let level = level_matrix.decomposition_level().0;
+            let half_scale = Scalar::ONE << (Scalar::BITS - 1 - base_log.0 * level);
+            accumulator
+                .get_mut_body()
+                .as_mut_tensor()
+                .fill_with_element(half_scale.wrapping_neg());
+            self.bootstrap::<Vec<Scalar>, Vec<Scalar>, Vec<Scalar>>(
+                &mut extracted,
+                &shifted,
+                &accumulator,
+            );
+            let body = extracted.get_mut_body();
+            body.0 = body.0.wrapping_add(half_scale);
+
+            // Each row of the level matrix is obtained with its own private functional packing
+            // keyswitch.
+            for (row, key) in level_matrix.row_iter_mut().zip(pfpksk_list.key_iter()) {
+                key.keyswitch_ciphertext::<Vec<Scalar>, _, _>(&mut row.into_glwe(), &extracted);
+            }
+        }
+    }
+}
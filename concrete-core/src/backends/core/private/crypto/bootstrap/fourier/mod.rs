@@ -8,17 +8,15 @@ use super::surrogate::SurrogateBsk;
 use crate::backends::core::private::crypto::bootstrap::standard::StandardBootstrapKey;
 use crate::backends::core::private::crypto::bootstrap::surrogate::BskKind;
 use crate::backends::core::private::crypto::bootstrap::Bootstrap;
-use crate::backends::core::private::crypto::ggsw::GgswCiphertext;
-use crate::backends::core::private::crypto::glwe::GlweCiphertext;
-use crate::backends::core::private::crypto::lwe::LweCiphertext;
-use crate::backends::core::private::math::decomposition::SignedDecomposer;
+use crate::backends::core::private::crypto::ggsw::{fourier_external_product, GgswCiphertext};
+use crate::backends::core::private::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
 use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
 use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
-use crate::backends::core::private::utils::{zip, zip_args};
 use concrete_commons::numeric::{CastInto, Numeric};
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, MonomialDegree,
@@ -26,6 +24,9 @@ use concrete_commons::parameters::{
 };
 use std::marker::PhantomData;
 
+#[cfg(feature = "multithread")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
+
 #[cfg(test)]
 mod tests;
 
@@ -525,6 +526,276 @@ where
             })
     }
 
+    /// Bootstraps each ciphertext of an input LWE list, and writes the results in the output LWE
+    /// list.
+    ///
+    /// The accumulators list either contains one accumulator per input ciphertext, or a single
+    /// accumulator which is shared by all the bootstraps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    ///     LweDimension, LweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweList;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweList;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let bsk: FourierBootstrapKey<_, u32> = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     GlweSize(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let lwe_in = LweList::allocate(0u32, LweSize(5), CiphertextCount(10));
+    /// let mut lwe_out = LweList::allocate(0u32, LweSize(257), CiphertextCount(10));
+    /// let accumulators = GlweList::allocate(
+    ///     0u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(1),
+    ///     CiphertextCount(1),
+    /// );
+    /// bsk.bootstrap_list(&mut lwe_out, &lwe_in, &accumulators);
+    /// assert_eq!(lwe_out.count(), CiphertextCount(10));
+    /// ```
+    pub fn bootstrap_list<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweList<C1>,
+        lwe_in: &LweList<C2>,
+        accumulators: &GlweList<C3>,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        LweList<C1>: AsMutTensor<Element = Scalar>,
+        LweList<C2>: AsRefTensor<Element = Scalar>,
+        GlweList<C3>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(lwe_out.count().0 => lwe_in.count().0);
+        let accumulator_count = accumulators.ciphertext_count().0;
+        assert!(
+            accumulator_count == 1 || accumulator_count == lwe_in.count().0,
+            "The accumulator count must be one, or equal to the input ciphertext count."
+        );
+        let accumulator_size = self.glwe_size.0 * self.poly_size.0;
+        let accumulators = accumulators.as_tensor().as_slice();
+        let poly_size = self.poly_size;
+        for (i, (mut out, inp)) in lwe_out
+            .ciphertext_iter_mut()
+            .zip(lwe_in.ciphertext_iter())
+            .enumerate()
+        {
+            let start = if accumulator_count == 1 {
+                0
+            } else {
+                i * accumulator_size
+            };
+            let accumulator = GlweCiphertext::from_container(
+                &accumulators[start..start + accumulator_size],
+                poly_size,
+            );
+            self.bootstrap(&mut out, &inp, &accumulator);
+        }
+    }
+
+    /// Bootstraps each ciphertext of an input LWE list, and writes the results in the output LWE
+    /// list, using all the available threads.
+    ///
+    /// The accumulators list either contains one accumulator per input ciphertext, or a single
+    /// accumulator which is shared by all the bootstraps.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "multithread" feature
+    /// gate. Since the fft buffers of the key can not be shared between threads, each thread
+    /// works with its own view of the key data, along with its own buffers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    ///     LweDimension, LweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweList;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweList;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let bsk: FourierBootstrapKey<_, u32> = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     GlweSize(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let lwe_in = LweList::allocate(0u32, LweSize(5), CiphertextCount(10));
+    /// let mut lwe_out = LweList::allocate(0u32, LweSize(257), CiphertextCount(10));
+    /// let accumulators = GlweList::allocate(
+    ///     0u32,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(1),
+    ///     CiphertextCount(10),
+    /// );
+    /// bsk.par_bootstrap_list(&mut lwe_out, &lwe_in, &accumulators);
+    /// assert_eq!(lwe_out.count(), CiphertextCount(10));
+    /// ```
+    #[cfg(feature = "multithread")]
+    pub fn par_bootstrap_list<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweList<C1>,
+        lwe_in: &LweList<C2>,
+        accumulators: &GlweList<C3>,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        LweList<C1>: AsMutTensor<Element = Scalar>,
+        LweList<C2>: AsRefTensor<Element = Scalar>,
+        GlweList<C3>: AsRefTensor<Element = Scalar>,
+        Scalar: Sync + Send,
+    {
+        ck_dim_eq!(lwe_out.count().0 => lwe_in.count().0);
+        let accumulator_count = accumulators.ciphertext_count().0;
+        assert!(
+            accumulator_count == 1 || accumulator_count == lwe_in.count().0,
+            "The accumulator count must be one, or equal to the input ciphertext count."
+        );
+        let accumulator_size = self.glwe_size.0 * self.poly_size.0;
+        let accumulators = accumulators.as_tensor().as_slice();
+        let key_data = self.as_tensor().as_slice();
+        let glwe_size = self.glwe_size;
+        let poly_size = self.poly_size;
+        let decomp_level = self.decomp_level;
+        let decomp_base_log = self.decomp_base_log;
+        let lwe_out_size = lwe_out.lwe_size().0;
+        let lwe_in_size = lwe_in.lwe_size().0;
+        lwe_out
+            .as_mut_tensor()
+            .par_subtensor_iter_mut(lwe_out_size)
+            .zip(lwe_in.as_tensor().par_subtensor_iter(lwe_in_size))
+            .enumerate()
+            .for_each_init(
+                || {
+                    FourierBootstrapKey::<_, Scalar>::from_container(
+                        key_data,
+                        glwe_size,
+                        poly_size,
+                        decomp_level,
+                        decomp_base_log,
+                    )
+                },
+                |bsk, (i, (out, inp))| {
+                    let start = if accumulator_count == 1 {
+                        0
+                    } else {
+                        i * accumulator_size
+                    };
+                    let accumulator = GlweCiphertext::from_container(
+                        &accumulators[start..start + accumulator_size],
+                        poly_size,
+                    );
+                    let mut out = LweCiphertext::from_container(out.into_container());
+                    let inp = LweCiphertext::from_container(inp.into_container());
+                    bsk.bootstrap(&mut out, &inp, &accumulator);
+                },
+            );
+    }
+
+    /// Bootstraps an LWE ciphertext with several lookup tables at once, and writes the results in
+    /// the output LWE list.
+    ///
+    /// Every lookup table is given as a polynomial whose coefficients are the (not encoded) output
+    /// values, the $i$-th output ciphertext then encrypting the value picked in the $i$-th
+    /// polynomial multiplied by `delta`. A single blind rotation is performed, on the common
+    /// accumulator $\frac{\Delta}{2}\sum_{j=0}^{N-1}X^j$. Each output is then obtained by
+    /// multiplying the rotated accumulator by the small integer polynomial $(1-X)\cdot T_i(X)$,
+    /// $T_i$ being the $i$-th lookup table, before extracting the constant coefficient.
+    ///
+    /// The noise of the blind rotation is amplified by the norm of $(1-X)\cdot T_i(X)$, which
+    /// stays small as long as the lookup tables take few distinct small values. Note that `delta`
+    /// should be even.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension,
+    ///     LweSize, PolynomialCount, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// use concrete_core::backends::core::private::math::polynomial::PolynomialList;
+    /// let bsk: FourierBootstrapKey<_, u32> = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     GlweSize(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let lwe_in = LweCiphertext::allocate(0u32, LweSize(5));
+    /// let mut lwe_out = LweList::allocate(0u32, LweSize(257), CiphertextCount(3));
+    /// let luts = PolynomialList::allocate(0u32, PolynomialCount(3), PolynomialSize(256));
+    /// bsk.multi_value_bootstrap(&mut lwe_out, &lwe_in, &luts, 1 << 28);
+    /// assert_eq!(lwe_out.count(), CiphertextCount(3));
+    /// ```
+    pub fn multi_value_bootstrap<C1, C2, C3>(
+        &self,
+        lwe_out: &mut LweList<C1>,
+        lwe_in: &LweCiphertext<C2>,
+        luts: &PolynomialList<C3>,
+        delta: Scalar,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        LweList<C1>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+        PolynomialList<C3>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(lwe_out.count().0 => luts.polynomial_count().0);
+        ck_dim_eq!(self.poly_size.0 => luts.polynomial_size().0);
+        ck_dim_eq!(lwe_out.lwe_size().0 => self.output_lwe_dimension().to_lwe_size().0);
+
+        // We blindly rotate the common accumulator, which is a trivial encryption of
+        // delta/2 * (1 + X + ... + X^{N-1}).
+        let mut local_accumulator = self.lut_buffer.borrow_mut();
+        local_accumulator
+            .as_mut_tensor()
+            .fill_with_element(Scalar::ZERO);
+        local_accumulator
+            .get_mut_body()
+            .as_mut_tensor()
+            .fill_with_element(delta >> 1);
+        self.blind_rotate(&mut *local_accumulator, lwe_in);
+
+        // Since (1 - X) * delta/2 * (1 + X + ... + X^{N-1}) = delta mod (X^N + 1), multiplying the
+        // rotated accumulator by (1 - X) * T(X) gives the rotation of delta * T(X).
+        let poly_size = self.poly_size.0;
+        let mut lut_factor = Polynomial::allocate(Scalar::ZERO, self.poly_size);
+        let mut rotated_lut =
+            GlweCiphertext::allocate(Scalar::ZERO, self.poly_size, self.glwe_size);
+        for (mut out, lut) in lwe_out.ciphertext_iter_mut().zip(luts.polynomial_iter()) {
+            let lut = lut.as_tensor();
+            for (j, coefficient) in lut_factor.coefficient_iter_mut().enumerate() {
+                let previous = if j == 0 {
+                    lut.get_element(poly_size - 1).wrapping_neg()
+                } else {
+                    *lut.get_element(j - 1)
+                };
+                *coefficient = lut.get_element(j).wrapping_sub(previous);
+            }
+            for (mut rotated_poly, accumulator_poly) in rotated_lut
+                .as_mut_polynomial_list()
+                .polynomial_iter_mut()
+                .zip(local_accumulator.as_polynomial_list().polynomial_iter())
+            {
+                rotated_poly.fill_with_karatsuba_mul(&accumulator_poly, &lut_factor);
+            }
+            constant_sample_extract(&mut out, &rotated_lut);
+        }
+    }
+
     fn external_product<C1, C2, C3>(
         &self,
         output: &mut GlweCiphertext<C1>,
@@ -551,163 +822,18 @@ where
             output.size()
         );
 
-        // We mutably borrow the fft buffers. Note that this incurs a (small) cost, which is why
-        // we only perform it once and keep the value around. We reset the output buffer.
-        let first_fft_buffer = &mut *self.fft_first_buffer.borrow_mut();
-        let second_fft_buffer = &mut *self.fft_second_buffer.borrow_mut();
-        let output_fft_buffer = &mut *self.fft_output_buffer.borrow_mut();
-        output_fft_buffer.fill_with_element(Complex64::new(0., 0.));
-
-        // We also mutably borrow a standard domain buffer to store the rounded input.
-        let rounded_input_glwe = &mut *self.rounded_buffer.borrow_mut();
-
-        // We round the input mask and body
-        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level);
-        decomposer.fill_tensor_with_closest_representable(rounded_input_glwe, glwe);
-
-        // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
-        // In this section, we perform the external product in the fourier domain, and accumulate
-        // the result in the output_fft_buffer variable.
-        let mut decomposition = decomposer.decompose_tensor(rounded_input_glwe);
-        // We loop through the levels (we reverse to match the order of the decomposition iterator.)
-        for ggsw_decomp_matrix in ggsw.level_matrix_iter().rev() {
-            // We retrieve the decomposition of this level.
-            let glwe_decomp_term = decomposition.next_term().unwrap();
-            debug_assert_eq!(
-                ggsw_decomp_matrix.decomposition_level(),
-                glwe_decomp_term.level()
-            );
-            // For each levels we have to add the result of the vector-matrix product between the
-            // decomposition of the glwe, and the ggsw level matrix to the output. To do so, we
-            // iteratively add to the output, the product between every lines of the matrix, and
-            // the corresponding (scalar) polynomial in the glwe decomposition:
-            //
-            //                ggsw_mat                        ggsw_mat
-            //   glwe_dec   | - - - - | <        glwe_dec   | - - - - |
-            //  | - - - | x | - - - - |         | - - - | x | - - - - | <
-            //    ^         | - - - - |             ^       | - - - - |
-            //
-            //        t = 1                           t = 2                     ...
-            // When possible we iterate two times in a row, to benefit from the fact that fft can
-            // transform two polynomials at once.
-            let mut iterator = zip!(
-                ggsw_decomp_matrix.row_iter(),
-                glwe_decomp_term
-                    .as_tensor()
-                    .subtensor_iter(self.poly_size.0)
-                    .map(Polynomial::from_tensor)
-            );
-
-            //---------------------------------------------------------------- VECTOR-MATRIX PRODUCT
-            loop {
-                match (iterator.next(), iterator.next()) {
-                    // Two iterates are available, we use the fast fft.
-                    (Some(first), Some(second)) => {
-                        // We unpack the iterator values
-                        let zip_args!(first_ggsw_row, first_glwe_poly) = first;
-                        let zip_args!(second_ggsw_row, second_glwe_poly) = second;
-                        // We perform the forward fft transform for the glwe polynomials
-                        self.fft.forward_two_as_integer(
-                            first_fft_buffer,
-                            second_fft_buffer,
-                            &first_glwe_poly,
-                            &second_glwe_poly,
-                        );
-                        // Now we loop through the polynomials of the output, and add the
-                        // corresponding product of polynomials.
-                        let iterator = zip!(
-                            first_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            second_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            output_fft_buffer
-                                .as_mut_tensor()
-                                .subtensor_iter_mut(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor)
-                        );
-                        for zip_args!(first_ggsw_poly, second_ggsw_poly, mut output_poly) in
-                            iterator
-                        {
-                            output_poly.update_with_two_multiply_accumulate(
-                                &first_ggsw_poly,
-                                first_fft_buffer,
-                                &second_ggsw_poly,
-                                second_fft_buffer,
-                            );
-                        }
-                    }
-                    // We reach the  end of the loop and one element remains.
-                    (Some(first), None) => {
-                        // We unpack the iterator values
-                        let (first_ggsw_row, first_glwe_poly) = first;
-                        // We perform the forward fft transform for the glwe polynomial
-                        self.fft
-                            .forward_as_integer(first_fft_buffer, &first_glwe_poly);
-                        // Now we loop through the polynomials of the output, and add the
-                        // corresponding product of polynomials.
-                        let iterator = zip!(
-                            first_ggsw_row
-                                .as_tensor()
-                                .subtensor_iter(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor),
-                            output_fft_buffer
-                                .subtensor_iter_mut(self.poly_size.0)
-                                .map(FourierPolynomial::from_tensor)
-                        );
-                        for zip_args!(first_ggsw_poly, mut output_poly) in iterator {
-                            output_poly.update_with_multiply_accumulate(
-                                &first_ggsw_poly,
-                                first_fft_buffer,
-                            );
-                        }
-                    }
-                    // The loop is over, we can exit.
-                    _ => break,
-                }
-            }
-        }
-
-        // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
-        // In this section, we bring the result from the fourier domain, back to the standard
-        // domain, and add it to the output.
-        //
-        // We iterate over the polynomials in the output. Again, when possible, we process two
-        // iterations simultaneously to benefit from the fft acceleration.
-        let mut _output_bind = output.as_mut_polynomial_list();
-        let mut iterator = zip!(
-            _output_bind.polynomial_iter_mut(),
-            output_fft_buffer
-                .subtensor_iter_mut(self.poly_size.0)
-                .map(FourierPolynomial::from_tensor)
+        // We mutably borrow the fft buffers, as well as a standard domain buffer to store the
+        // rounded input, and perform the external product.
+        fourier_external_product(
+            &self.fft,
+            &mut self.fft_first_buffer.borrow_mut(),
+            &mut self.fft_second_buffer.borrow_mut(),
+            &mut self.fft_output_buffer.borrow_mut(),
+            &mut self.rounded_buffer.borrow_mut(),
+            output,
+            ggsw,
+            glwe,
         );
-        loop {
-            match (iterator.next(), iterator.next()) {
-                (Some(first), Some(second)) => {
-                    // We unpack the iterates
-                    let zip_args!(mut first_output, mut first_fourier) = first;
-                    let zip_args!(mut second_output, mut second_fourier) = second;
-                    // We perform the backward transform
-                    self.fft.add_backward_two_as_torus(
-                        &mut first_output,
-                        &mut second_output,
-                        &mut first_fourier,
-                        &mut second_fourier,
-                    );
-                }
-                (Some(first), None) => {
-                    // We unpack the iterates
-                    let (mut first_output, mut first_fourier) = first;
-                    // We perform the backward transform
-                    self.fft
-                        .add_backward_as_torus(&mut first_output, &mut first_fourier);
-                }
-                _ => break,
-            }
-        }
     }
 
     // This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
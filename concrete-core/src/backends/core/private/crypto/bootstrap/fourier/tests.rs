@@ -5,8 +5,9 @@ use concrete_npe as npe;
 use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
 use concrete_commons::numeric::{CastFrom, CastInto, Numeric};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, LweSize,
-    PlaintextCount, PolynomialSize,
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount,
+    FunctionalPackingKeyswitchKeyCount, GlweDimension, LweDimension, LweSize, PlaintextCount,
+    PolynomialCount, PolynomialSize,
 };
 
 use crate::backends::core::private::crypto::bootstrap::fourier::constant_sample_extract;
@@ -14,13 +15,17 @@ use crate::backends::core::private::crypto::bootstrap::{
     Bootstrap, FourierBootstrapKey, StandardBootstrapKey,
 };
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
-use crate::backends::core::private::crypto::glwe::GlweCiphertext;
-use crate::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, GgswCiphertext};
+use crate::backends::core::private::crypto::glwe::{
+    GlweCiphertext, GlweList, PrivateFunctionalPackingKeyswitchKeyList,
+};
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
 use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::polynomial::PolynomialList;
 use crate::backends::core::private::math::random::RandomGenerator;
 use crate::backends::core::private::math::tensor::{
     AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
@@ -622,6 +627,367 @@ where
     }
 }
 
+fn test_bootstrap_list<T: UnsignedTorus + Debug + Sync + Send>() {
+    // define settings
+    let nb_ct = 8;
+    let polynomial_size = PolynomialSize(256);
+    let rlwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-29.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let rlwe_sk =
+        GlweSecretKey::generate_binary(rlwe_dimension, polynomial_size, &mut secret_generator);
+    let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &rlwe_sk, std, &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        rlwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+
+    // random inputs and accumulators
+    let mut lwe_in =
+        LweList::allocate(T::ZERO, lwe_dimension.to_lwe_size(), CiphertextCount(nb_ct));
+    random_generator.fill_tensor_with_random_uniform(&mut lwe_in);
+    let mut accumulators = GlweList::allocate(
+        T::ZERO,
+        polynomial_size,
+        rlwe_dimension,
+        CiphertextCount(nb_ct),
+    );
+    random_generator.fill_tensor_with_random_uniform(&mut accumulators);
+    let output_size = LweSize(rlwe_dimension.0 * polynomial_size.0 + 1);
+
+    // the list bootstrap must match the bootstrap of each ciphertext
+    let mut expected = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+    for ((mut out, inp), acc) in expected
+        .ciphertext_iter_mut()
+        .zip(lwe_in.ciphertext_iter())
+        .zip(accumulators.ciphertext_iter())
+    {
+        fourier_bsk.bootstrap(&mut out, &inp, &acc);
+    }
+    let mut lwe_out = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+    fourier_bsk.bootstrap_list(&mut lwe_out, &lwe_in, &accumulators);
+    assert_eq!(lwe_out.as_tensor(), expected.as_tensor());
+    #[cfg(feature = "multithread")]
+    {
+        let mut lwe_out = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+        fourier_bsk.par_bootstrap_list(&mut lwe_out, &lwe_in, &accumulators);
+        assert_eq!(lwe_out.as_tensor(), expected.as_tensor());
+    }
+
+    // a single accumulator is shared by all the bootstraps
+    let shared = GlweList::from_container(
+        accumulators
+            .ciphertext_iter()
+            .next()
+            .unwrap()
+            .into_tensor()
+            .into_container()
+            .to_vec(),
+        rlwe_dimension,
+        polynomial_size,
+    );
+    let mut expected = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+    for (mut out, inp) in expected.ciphertext_iter_mut().zip(lwe_in.ciphertext_iter()) {
+        fourier_bsk.bootstrap(&mut out, &inp, &shared.ciphertext_iter().next().unwrap());
+    }
+    let mut lwe_out = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+    fourier_bsk.bootstrap_list(&mut lwe_out, &lwe_in, &shared);
+    assert_eq!(lwe_out.as_tensor(), expected.as_tensor());
+    #[cfg(feature = "multithread")]
+    {
+        let mut lwe_out = LweList::allocate(T::ZERO, output_size, CiphertextCount(nb_ct));
+        fourier_bsk.par_bootstrap_list(&mut lwe_out, &lwe_in, &shared);
+        assert_eq!(lwe_out.as_tensor(), expected.as_tensor());
+    }
+}
+
+fn test_circuit_bootstrap<T: UnsignedTorus + Debug>() {
+    // define settings
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let bsk_level = DecompositionLevelCount(3);
+    let bsk_base_log = DecompositionBaseLog(7);
+    let pfpksk_level = DecompositionLevelCount(5);
+    let pfpksk_base_log = DecompositionBaseLog(4);
+    let ggsw_level = DecompositionLevelCount(3);
+    let ggsw_base_log = DecompositionBaseLog(4);
+    let std = LogStandardDev::from_log_standard_dev(-(<T as Numeric>::BITS as f64) + 2.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generate the keys
+    let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    let bsk_glwe_sk =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let ggsw_glwe_sk =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let bsk_lwe_sk = LweSecretKey::binary_from_container(bsk_glwe_sk.as_tensor().as_slice());
+
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        bsk_level,
+        bsk_base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &bsk_glwe_sk, std, &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        bsk_level,
+        bsk_base_log,
+        lwe_dimension,
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+
+    let mut pfpksk_list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+        T::ZERO,
+        pfpksk_level,
+        pfpksk_base_log,
+        LweDimension(glwe_dimension.0 * polynomial_size.0),
+        glwe_dimension,
+        polynomial_size,
+        FunctionalPackingKeyswitchKeyCount(glwe_dimension.to_glwe_size().0),
+    );
+    pfpksk_list.fill_with_circuit_bootstrap_keys(
+        &bsk_lwe_sk,
+        &ggsw_glwe_sk,
+        std,
+        &mut encryption_generator,
+    );
+
+    for bit in [T::ZERO, T::ONE] {
+        // encrypt the bit in the most significant bit
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        lwe_sk.encrypt_lwe(
+            &mut lwe_in,
+            &Plaintext(bit << (<T as Numeric>::BITS - 1)),
+            std,
+            &mut encryption_generator,
+        );
+
+        // circuit bootstrap
+        let mut ggsw = GgswCiphertext::allocate(
+            T::ZERO,
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            ggsw_level,
+            ggsw_base_log,
+        );
+        fourier_bsk.circuit_bootstrap(&mut ggsw, &lwe_in, &pfpksk_list);
+        let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+            Complex64::new(0., 0.),
+            polynomial_size,
+            glwe_dimension.to_glwe_size(),
+            ggsw_level,
+            ggsw_base_log,
+        );
+        fourier_ggsw.fill_with_forward_fourier(&ggsw);
+
+        // the external product with the output ggsw multiplies a random message by the bit
+        let mut messages = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        random_generator.fill_tensor_with_random_uniform_binary(&mut messages);
+        let mut encoded = messages.clone();
+        encoded
+            .as_mut_tensor()
+            .update_with_wrapping_scalar_mul(&(T::ONE << (<T as Numeric>::BITS - 1)));
+        let mut glwe =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+        ggsw_glwe_sk.encrypt_glwe(&mut glwe, &encoded, std, &mut encryption_generator);
+        let mut output =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+        fourier_ggsw.external_product(&mut output, &glwe);
+
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        ggsw_glwe_sk.decrypt_glwe(&mut decrypted, &output);
+        for (decrypted, message) in decrypted
+            .as_tensor()
+            .iter()
+            .zip(messages.as_tensor().iter())
+        {
+            let decoded = decrypted.wrapping_add(T::ONE << (<T as Numeric>::BITS - 2))
+                >> (<T as Numeric>::BITS - 1);
+            assert_eq!(decoded, *message * bit);
+        }
+    }
+}
+
+fn test_vertical_packing<T: UnsignedTorus + Debug>() {
+    // define settings
+    let polynomial_size = PolynomialSize(256);
+    let glwe_dimension = GlweDimension(1);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-(<T as Numeric>::BITS as f64) + 2.);
+    // more bits than log2(polynomial_size), so that the cmux tree is used
+    let bit_count = 10;
+    let message_bits = 4;
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let glwe_sk =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let lwe_sk = LweSecretKey::binary_from_container(glwe_sk.as_tensor().as_slice());
+
+    // the lookup table contains random messages encoded in the most significant bits
+    let shift = <T as Numeric>::BITS - message_bits;
+    let mut lut = PlaintextList::allocate(T::ZERO, PlaintextCount(1 << bit_count));
+    for plaintext in lut.plaintext_iter_mut() {
+        let random: T = random_generator.random_uniform();
+        plaintext.0 = (random >> shift) << shift;
+    }
+
+    for _ in 0..3 {
+        // encrypt the bits of a random index, from the most significant to the least significant
+        let index = random_generator.random_uniform::<u16>() as usize % (1 << bit_count);
+        let ggsws: Vec<_> = (0..bit_count)
+            .rev()
+            .map(|i| {
+                let bit = if (index >> i) & 1 == 1 {
+                    T::ONE
+                } else {
+                    T::ZERO
+                };
+                let mut ggsw = GgswCiphertext::allocate(
+                    T::ZERO,
+                    polynomial_size,
+                    glwe_dimension.to_glwe_size(),
+                    level,
+                    base_log,
+                );
+                glwe_sk.encrypt_constant_ggsw(
+                    &mut ggsw,
+                    &Plaintext(bit),
+                    std,
+                    &mut encryption_generator,
+                );
+                let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+                    Complex64::new(0., 0.),
+                    polynomial_size,
+                    glwe_dimension.to_glwe_size(),
+                    level,
+                    base_log,
+                );
+                fourier_ggsw.fill_with_forward_fourier(&ggsw);
+                fourier_ggsw
+            })
+            .collect();
+        let ggsw_refs: Vec<_> = ggsws.iter().collect();
+
+        // vertical packing
+        let mut lwe_out =
+            LweCiphertext::allocate(T::ZERO, LweSize(glwe_dimension.0 * polynomial_size.0 + 1));
+        lwe_out.fill_with_vertical_packing(&ggsw_refs, &lut);
+
+        // decrypt and round to the closest message
+        let mut decrypted = Plaintext(T::ZERO);
+        lwe_sk.decrypt_lwe(&mut decrypted, &lwe_out);
+        let decoded = (decrypted.0.wrapping_add(T::ONE << (shift - 1)) >> shift) << shift;
+        assert_eq!(decoded, *lut.as_tensor().get_element(index));
+    }
+}
+
+fn test_multi_value_bootstrap<T: UnsignedTorus + Debug>() {
+    // define settings
+    let polynomial_size = PolynomialSize(1024);
+    let glwe_dimension = GlweDimension(1);
+    let lwe_dimension = LweDimension(10);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-(<T as Numeric>::BITS as f64) + 2.);
+    // messages of 3 bits, with one bit of padding
+    let message_count = 8;
+    let shift = <T as Numeric>::BITS - 4;
+    let box_size = polynomial_size.0 / message_count;
+    let functions: Vec<fn(usize) -> usize> = vec![|m| m, |m| 7 - m, |m| (m * m) % 8];
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let lwe_sk = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    let glwe_sk =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let flattened_key = LweSecretKey::binary_from_container(glwe_sk.as_tensor().as_slice());
+    let mut coef_bsk = StandardBootstrapKey::allocate(
+        T::ZERO,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    coef_bsk.fill_with_new_key(&lwe_sk, &glwe_sk, std, &mut encryption_generator);
+    let mut fourier_bsk = FourierBootstrapKey::allocate(
+        Complex64::new(0., 0.),
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        level,
+        base_log,
+        lwe_dimension,
+    );
+    fourier_bsk.fill_with_forward_fourier(&coef_bsk);
+
+    // the lookup tables contain the function values, constant on each box
+    let mut luts =
+        PolynomialList::allocate(T::ZERO, PolynomialCount(functions.len()), polynomial_size);
+    for (mut lut, function) in luts.polynomial_iter_mut().zip(functions.iter()) {
+        for (j, coefficient) in lut.coefficient_iter_mut().enumerate() {
+            *coefficient = T::cast_from(function(j / box_size) as f64);
+        }
+    }
+
+    for message in 0..message_count {
+        // encrypt the message, shifted to the middle of its box
+        let mut lwe_in = LweCiphertext::allocate(T::ZERO, lwe_dimension.to_lwe_size());
+        let plaintext = (T::cast_from(message as f64) << shift).wrapping_add(T::ONE << (shift - 1));
+        lwe_sk.encrypt_lwe(
+            &mut lwe_in,
+            &Plaintext(plaintext),
+            std,
+            &mut encryption_generator,
+        );
+
+        // multi-value bootstrap
+        let mut lwe_out = LweList::allocate(
+            T::ZERO,
+            LweSize(glwe_dimension.0 * polynomial_size.0 + 1),
+            CiphertextCount(functions.len()),
+        );
+        fourier_bsk.multi_value_bootstrap(&mut lwe_out, &lwe_in, &luts, T::ONE << shift);
+
+        for (lwe, function) in lwe_out.ciphertext_iter().zip(functions.iter()) {
+            let mut decrypted = Plaintext(T::ZERO);
+            flattened_key.decrypt_lwe(&mut decrypted, &lwe);
+            let decoded = decrypted.0.wrapping_add(T::ONE << (shift - 1)) >> shift;
+            assert_eq!(decoded, T::cast_from(function(message) as f64));
+        }
+    }
+}
+
 #[test]
 pub fn test_bootstrap_drift_u32() {
     test_bootstrap_drift::<u32>();
@@ -681,3 +1047,43 @@ pub fn test_sample_extract_u32() {
 pub fn test_sample_extract_u64() {
     test_sample_extract::<u64>();
 }
+
+#[test]
+pub fn test_bootstrap_list_u32() {
+    test_bootstrap_list::<u32>();
+}
+
+#[test]
+pub fn test_bootstrap_list_u64() {
+    test_bootstrap_list::<u64>();
+}
+
+#[test]
+pub fn test_circuit_bootstrap_u32() {
+    test_circuit_bootstrap::<u32>();
+}
+
+#[test]
+pub fn test_circuit_bootstrap_u64() {
+    test_circuit_bootstrap::<u64>();
+}
+
+#[test]
+pub fn test_multi_value_bootstrap_u32() {
+    test_multi_value_bootstrap::<u32>();
+}
+
+#[test]
+pub fn test_multi_value_bootstrap_u64() {
+    test_multi_value_bootstrap::<u64>();
+}
+
+#[test]
+pub fn test_vertical_packing_u32() {
+    test_vertical_packing::<u32>();
+}
+
+#[test]
+pub fn test_vertical_packing_u64() {
+    test_vertical_packing::<u64>();
+}
@@ -11,9 +11,11 @@ use crate::backends::core::private::crypto::lwe::LweCiphertext;
 use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
 use crate::backends::core::private::math::torus::UnsignedTorus;
 
+mod circuit_bootstrap;
 mod fourier;
 mod standard;
 mod surrogate;
+mod vertical_packing;
 
 /// A trait for bootstrap keys types performing a bootstrap operation.
 pub trait Bootstrap {
@@ -191,6 +193,60 @@ mod test {
         }
     }
 
+    fn test_bsk_gen_thread_count_independence<T: UnsignedTorus + Send + Sync>() {
+        let (lwe_dim, glwe_dim, poly_size) = (LweDimension(8), GlweDimension(2), PolynomialSize(8));
+        let (level, base_log) = (DecompositionLevelCount(3), DecompositionBaseLog(4));
+        let mask_seed = crate::backends::core::private::test_tools::any_usize() as u128;
+        let noise_seed = crate::backends::core::private::test_tools::any_usize() as u128;
+        let secret_seed = crate::backends::core::private::test_tools::any_usize() as u128;
+
+        let mut secret_generator = SecretRandomGenerator::new(Some(secret_seed));
+        let lwe_sk = LweSecretKey::generate_binary(lwe_dim, &mut secret_generator);
+        let glwe_sk = GlweSecretKey::generate_binary(glwe_dim, poly_size, &mut secret_generator);
+
+        let bsks = [1, 2, 4]
+            .iter()
+            .map(|n_threads| {
+                let mut bsk = StandardBootstrapKey::allocate(
+                    T::ZERO,
+                    glwe_dim.to_glwe_size(),
+                    poly_size,
+                    level,
+                    base_log,
+                    lwe_dim,
+                );
+                let mut encryption_generator =
+                    EncryptionRandomGenerator::new_with_seeds(mask_seed, noise_seed);
+                rayon::ThreadPoolBuilder::new()
+                    .num_threads(*n_threads)
+                    .build()
+                    .unwrap()
+                    .install(|| {
+                        bsk.par_fill_with_new_key(
+                            &lwe_sk,
+                            &glwe_sk,
+                            StandardDev::from_standard_dev(10.),
+                            &mut encryption_generator,
+                        )
+                    });
+                bsk
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(bsks[0], bsks[1]);
+        assert_eq!(bsks[0], bsks[2]);
+    }
+
+    #[test]
+    fn test_bsk_gen_thread_count_independence_u32() {
+        test_bsk_gen_thread_count_independence::<u32>()
+    }
+
+    #[test]
+    fn test_bsk_gen_thread_count_independence_u64() {
+        test_bsk_gen_thread_count_independence::<u64>()
+    }
+
     #[test]
     fn test_bsk_gen_equivalence_u32() {
         test_bsk_gen_equivalence::<u32>()
@@ -1,7 +1,9 @@
+use crate::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
 use crate::backends::core::private::crypto::encoding::Plaintext;
 use crate::backends::core::private::crypto::ggsw::GgswCiphertext;
 use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
 use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
 use crate::backends::core::private::math::polynomial::Polynomial;
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
@@ -698,4 +700,73 @@ impl<Cont> StandardBootstrapKey<Cont> {
             .subtensor_iter_mut(poly_size)
             .map(|chunk| Polynomial::from_container(chunk.into_container()))
     }
+
+    /// Fills a bootstrapping key in coefficient domain with the backward fourier transform of a
+    /// bootstrapping key in the fourier domain.
+    ///
+    /// # Note
+    ///
+    /// The coefficients are rounded to the closest torus element after the backward transform. For
+    /// 64 bits keys, the precision of the fourier domain representation may not be sufficient to
+    /// exactly recover the key in coefficient domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::bootstrap::{
+    ///     FourierBootstrapKey, StandardBootstrapKey,
+    /// };
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let bsk = StandardBootstrapKey::allocate(
+    ///     9u32,
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// let mut frr_bsk = FourierBootstrapKey::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// frr_bsk.fill_with_forward_fourier(&bsk);
+    /// let mut coef_bsk = StandardBootstrapKey::allocate(
+    ///     0u32,
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(5),
+    ///     LweDimension(4),
+    /// );
+    /// coef_bsk.fill_with_backward_fourier(&frr_bsk);
+    /// assert_eq!(coef_bsk, bsk);
+    /// ```
+    pub fn fill_with_backward_fourier<InputCont, Scalar>(
+        &mut self,
+        fourier_bsk: &FourierBootstrapKey<InputCont, Scalar>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        FourierBootstrapKey<InputCont, Scalar>: AsRefTensor<Element = Complex64>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.as_tensor().len() => fourier_bsk.as_tensor().len());
+        let fft = Fft::new(self.poly_size);
+        let mut fft_buffer = FourierPolynomial::allocate(Complex64::new(0., 0.), self.poly_size);
+        let poly_size = self.poly_size.0;
+        self.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let iterator = self
+            .poly_iter_mut()
+            .zip(fourier_bsk.as_tensor().subtensor_iter(poly_size));
+        for (mut coef_poly, fourier_poly) in iterator {
+            fft_buffer.as_mut_tensor().fill_with_copy(&fourier_poly);
+            fft.add_backward_as_torus(&mut coef_poly, &mut fft_buffer);
+        }
+    }
 }
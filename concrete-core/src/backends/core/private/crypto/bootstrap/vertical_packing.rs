@@ -0,0 +1,126 @@
+use concrete_commons::parameters::MonomialDegree;
+
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::lwe::LweCiphertext;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+impl<Cont> LweCiphertext<Cont> {
+    /// Fills the current ciphertext with the entry of a lookup table selected by an index
+    /// encrypted bitwise in a list of GGSW ciphertexts.
+    ///
+    /// The GGSW ciphertexts encrypt the bits of the index, from the most significant to the least
+    /// significant one, and `lut` must contain exactly $2^{n}$ plaintexts, $n$ being the number of
+    /// GGSW ciphertexts. The lookup table is split in chunks of $N$ entries, $N$ being the
+    /// polynomial size of the GGSW ciphertexts, which are trivially encrypted as GLWE
+    /// ciphertexts. The most significant bits select the right chunk through a tree of cmuxes,
+    /// and the remaining $\log_2(N)$ bits (at most) are used to blindly rotate it so that the
+    /// selected entry lands on the constant coefficient, which is then extracted.
+    ///
+    /// The current ciphertext must have an LWE dimension equal to the GLWE dimension of the GGSW
+    /// ciphertexts times their polynomial size, and is encrypted under the flattened GLWE key of
+    /// the GGSW ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweSize, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(2),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// let ggsw_list = vec![&ggsw; 10];
+    /// let lut = PlaintextList::allocate(0u32, PlaintextCount(1 << 10));
+    /// let mut lwe_out = LweCiphertext::allocate(0u32, LweSize(257));
+    /// lwe_out.fill_with_vertical_packing(&ggsw_list, &lut);
+    /// ```
+    pub fn fill_with_vertical_packing<GgswCont, LutCont, Scalar>(
+        &mut self,
+        ggsw_list: &[&FourierGgswCiphertext<GgswCont, Scalar>],
+        lut: &PlaintextList<LutCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        FourierGgswCiphertext<GgswCont, Scalar>: AsRefTensor<Element = Complex64>,
+        PlaintextList<LutCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let first_ggsw = ggsw_list
+            .first()
+            .expect("The vertical packing needs at least one GGSW ciphertext.");
+        let poly_size = first_ggsw.polynomial_size();
+        let glwe_size = first_ggsw.glwe_size();
+        for ggsw in ggsw_list.iter() {
+            ck_dim_eq!(ggsw.polynomial_size().0 => poly_size.0);
+            ck_dim_eq!(ggsw.glwe_size().0 => glwe_size.0);
+        }
+        ck_dim_eq!(
+            self.lwe_size().to_lwe_dimension().0 =>
+            glwe_size.to_glwe_dimension().0 * poly_size.0
+        );
+        assert_eq!(
+            Some(lut.count().0),
+            1usize.checked_shl(ggsw_list.len() as u32),
+            "The lookup table must contain 2^n entries, n being the number of GGSW ciphertexts."
+        );
+
+        // The least significant bits are used for the blind rotation, and the others select the
+        // chunk of the lookup table to rotate.
+        let rotation_bit_count =
+            std::cmp::min(ggsw_list.len(), poly_size.0.trailing_zeros() as usize);
+        let (tree_ggsws, rotation_ggsws) = ggsw_list.split_at(ggsw_list.len() - rotation_bit_count);
+
+        // We trivially encrypt every chunk of the lookup table in the body of a GLWE ciphertext.
+        let mut candidates: Vec<GlweCiphertext<Vec<Scalar>>> = lut
+            .as_tensor()
+            .as_slice()
+            .chunks(poly_size.0)
+            .map(|chunk| {
+                let mut glwe = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+                glwe.get_mut_body().as_mut_tensor().as_mut_slice()[..chunk.len()]
+                    .copy_from_slice(chunk);
+                glwe
+            })
+            .collect();
+
+        // We walk down the cmux tree, starting with the least significant bit of the tree, which
+        // selects between consecutive chunks.
+        for ggsw in tree_ggsws.iter().rev() {
+            let mut previous = candidates.into_iter();
+            candidates = Vec::with_capacity(previous.len() / 2usize);
+            while let (Some(mut ct0), Some(mut ct1)) = (previous.next(), previous.next()) {
+                ggsw.cmux(&mut ct0, &mut ct1);
+                candidates.push(ct0);
+            }
+        }
+        let mut accumulator = candidates.pop().unwrap();
+
+        // We blindly rotate the selected chunk by X^{-index}, one bit at a time.
+        let mut buffer = GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size);
+        for (bit_index, ggsw) in rotation_ggsws.iter().rev().enumerate() {
+            buffer
+                .as_mut_tensor()
+                .fill_with_copy(accumulator.as_tensor());
+            buffer
+                .as_mut_polynomial_list()
+                .update_with_wrapping_monic_monomial_div(MonomialDegree(1 << bit_index));
+            ggsw.cmux(&mut accumulator, &mut buffer);
+        }
+
+        accumulator.fill_lwe_with_sample_extraction(self, MonomialDegree(0));
+    }
+}
@@ -3,6 +3,7 @@ use crate::backends::core::private::math::torus::{FromTorus, IntoTorus, Unsigned
 
 use super::{Cleartext, CleartextList, Plaintext, PlaintextList};
 use concrete_commons::numeric::{FloatingPoint, Numeric};
+use serde::{Deserialize, Serialize};
 
 /// A trait for types that encode cleartext to plaintext.
 ///
@@ -76,6 +77,7 @@ pub trait Encoder<Enc: Numeric> {
 }
 
 /// An encoder for real cleartexts
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct RealEncoder<T: FloatingPoint> {
     /// The offset of the encoding
     pub offset: T,
@@ -124,3 +126,160 @@ where
             .fill_with_one(encoded.as_tensor(), |e| self.decode(Plaintext(*e)).0);
     }
 }
+
+/// An encoder for real cleartexts taken in a bounded interval, with a fixed precision and a number
+/// of padding bits.
+///
+/// The interval `[min, max]` is discretized in `2^nb_bit_precision` evenly spaced values, both
+/// bounds included, and mapped to the torus using a [`RealEncoder`]. The `nb_bit_padding` most
+/// significant bits of the encoded values are kept to zero, which leaves room for the carries of
+/// subsequent homomorphic operations. Decoding rounds the input to the closest encoded value, which
+/// removes the noise which may have been added to it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FloatEncoder {
+    real_encoder: RealEncoder<f64>,
+    nb_bit_precision: usize,
+    nb_bit_padding: usize,
+}
+
+impl FloatEncoder {
+    /// Creates a new float encoder for the `[min, max]` interval.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new(0., 10., 4, 1);
+    /// assert_eq!(encoder.min(), 0.);
+    /// assert_eq!(encoder.max(), 10.);
+    /// assert_eq!(encoder.nb_bit_precision(), 4);
+    /// assert_eq!(encoder.nb_bit_padding(), 1);
+    /// ```
+    pub fn new(min: f64, max: f64, nb_bit_precision: usize, nb_bit_padding: usize) -> Self {
+        debug_assert!(
+            min < max,
+            "The min value must be smaller than the max value."
+        );
+        debug_assert!(
+            nb_bit_precision > 0,
+            "The precision must be strictly positive."
+        );
+        let grid_size = f64::powi(2., nb_bit_precision as i32);
+        let delta =
+            (max - min) * grid_size / (grid_size - 1.) * f64::powi(2., nb_bit_padding as i32);
+        FloatEncoder {
+            real_encoder: RealEncoder { offset: min, delta },
+            nb_bit_precision,
+            nb_bit_padding,
+        }
+    }
+
+    /// Returns the lower bound of the interval.
+    pub fn min(&self) -> f64 {
+        self.real_encoder.offset
+    }
+
+    /// Returns the upper bound of the interval.
+    pub fn max(&self) -> f64 {
+        let grid_size = f64::powi(2., self.nb_bit_precision as i32);
+        let padded_delta = self.real_encoder.delta / f64::powi(2., self.nb_bit_padding as i32);
+        self.real_encoder.offset + padded_delta * (grid_size - 1.) / grid_size
+    }
+
+    /// Returns the number of bits of precision of the encoder.
+    pub fn nb_bit_precision(&self) -> usize {
+        self.nb_bit_precision
+    }
+
+    /// Returns the number of bits of padding of the encoder.
+    pub fn nb_bit_padding(&self) -> usize {
+        self.nb_bit_padding
+    }
+
+    /// Returns whether the encoded values fit in the given scalar type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new(0., 10., 30, 2);
+    /// assert!(!encoder.fits_in::<u32>());
+    /// assert!(encoder.fits_in::<u64>());
+    /// ```
+    pub fn fits_in<Scalar: UnsignedTorus>(&self) -> bool {
+        self.nb_bit_precision + self.nb_bit_padding < Scalar::BITS
+    }
+
+    /// Returns whether a value is contained in the interval of the encoder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new(0., 10., 4, 1);
+    /// assert!(encoder.is_in_interval(10.));
+    /// assert!(!encoder.is_in_interval(10.5));
+    /// ```
+    pub fn is_in_interval(&self, value: f64) -> bool {
+        self.min() <= value && value <= self.max()
+    }
+
+    // Rounds a torus element to the closest multiple of the encoding step.
+    fn round<Scalar: UnsignedTorus>(&self, value: Scalar) -> Scalar {
+        let shift = Scalar::BITS - self.nb_bit_precision - self.nb_bit_padding;
+        let half_step = Scalar::ONE << (shift - 1);
+        (value.wrapping_add(half_step) >> shift) << shift
+    }
+}
+
+impl<EncScalar> Encoder<EncScalar> for FloatEncoder
+where
+    EncScalar: UnsignedTorus,
+{
+    type Raw = f64;
+
+    /// Encodes a single cleartext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// let encoder = FloatEncoder::new(0., 15., 4, 1);
+    /// let encoded: Plaintext<u32> = encoder.encode(Cleartext(3.));
+    /// assert_eq!(encoded, Plaintext(3 << 27));
+    /// let decoded = encoder.decode(Plaintext(encoded.0 + 1000));
+    /// assert!((decoded.0 - 3.).abs() < 1e-6);
+    /// ```
+    fn encode(&self, raw: Cleartext<f64>) -> Plaintext<EncScalar> {
+        Plaintext(self.round(self.real_encoder.encode(raw).0))
+    }
+
+    fn decode(&self, encoded: Plaintext<EncScalar>) -> Cleartext<f64> {
+        self.real_encoder.decode(Plaintext(self.round(encoded.0)))
+    }
+
+    fn encode_list<RawCont, EncCont>(
+        &self,
+        encoded: &mut PlaintextList<EncCont>,
+        raw: &CleartextList<RawCont>,
+    ) where
+        CleartextList<RawCont>: AsRefTensor<Element = f64>,
+        PlaintextList<EncCont>: AsMutTensor<Element = EncScalar>,
+    {
+        encoded
+            .as_mut_tensor()
+            .fill_with_one(raw.as_tensor(), |r| self.encode(Cleartext(*r)).0);
+    }
+
+    fn decode_list<RawCont, EncCont>(
+        &self,
+        raw: &mut CleartextList<RawCont>,
+        encoded: &PlaintextList<EncCont>,
+    ) where
+        CleartextList<RawCont>: AsMutTensor<Element = f64>,
+        PlaintextList<EncCont>: AsRefTensor<Element = EncScalar>,
+    {
+        raw.as_mut_tensor()
+            .fill_with_one(encoded.as_tensor(), |e| self.decode(Plaintext(*e)).0);
+    }
+}
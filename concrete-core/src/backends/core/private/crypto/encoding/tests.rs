@@ -1,8 +1,10 @@
 use crate::backends::core::private::crypto::encoding::{
-    Cleartext, Encoder, Plaintext, RealEncoder,
+    Cleartext, Encoder, FloatEncoder, Plaintext, RealEncoder,
 };
 use crate::backends::core::private::math::torus::UnsignedTorus;
-use crate::backends::core::private::test_tools::{any_uint, random_uint_between};
+use crate::backends::core::private::test_tools::{
+    any_uint, random_uint_between, random_usize_between,
+};
 
 fn test_encoding_decoding<T: UnsignedTorus>() {
     //! Encodes and decodes random messages
@@ -48,3 +50,42 @@ fn test_encoding_decoding_u32() {
 fn test_encoding_decoding_u64() {
     test_encoding_decoding::<u64>()
 }
+
+fn test_float_encoding_decoding<T: UnsignedTorus>() {
+    //! Encodes, perturbs and decodes random messages taken on the encoder grid
+    let n_tests = 1000;
+    for _i in 0..n_tests {
+        // draws a random encoder whose encodings fit in T
+        let nb_bit_padding = random_usize_between(0..4);
+        let nb_bit_precision = random_usize_between(1..8);
+        let min = -(random_usize_between(0..1000) as f64);
+        let max = min + random_usize_between(1..1000) as f64;
+        let encoder = FloatEncoder::new(min, max, nb_bit_precision, nb_bit_padding);
+        assert!(encoder.fits_in::<T>());
+
+        // generates a random message on the grid of the encoder
+        let step = (max - min) / (f64::powi(2., nb_bit_precision as i32) - 1.);
+        let index = random_usize_between(0..(1 << nb_bit_precision));
+        let m = min + index as f64 * step;
+        assert!(encoder.is_in_interval(m));
+
+        // encodes, adds a small error, and decodes
+        let encoding: Plaintext<T> = encoder.encode(Cleartext(m));
+        let shift = T::BITS - nb_bit_precision - nb_bit_padding;
+        let error = random_uint_between(T::ZERO..(T::ONE << (shift - 1)));
+        let decoding = encoder.decode(Plaintext(encoding.0.wrapping_add(error)));
+
+        // test
+        assert_delta_scalar_float!(m, decoding.0, step / 1000.);
+    }
+}
+
+#[test]
+fn test_float_encoding_decoding_u32() {
+    test_float_encoding_decoding::<u32>()
+}
+
+#[test]
+fn test_float_encoding_decoding_u64() {
+    test_float_encoding_decoding::<u64>()
+}
@@ -1,3 +1,4 @@
+use crate::backends::core::private::crypto::encoding::Plaintext;
 use crate::backends::core::private::crypto::glwe::GlweList;
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
@@ -6,6 +7,8 @@ use crate::backends::core::private::math::tensor::{
 use super::GgswLevelMatrix;
 
 use crate::backends::core::private::math::decomposition::DecompositionLevel;
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
 };
@@ -432,4 +435,49 @@ impl<Cont> GgswCiphertext<Cont> {
                 )
             })
     }
+
+    /// Fills the ciphertext with a trivial encryption of a constant plaintext, that is, an
+    /// encryption with null masks and no noise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::Plaintext;
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let mut ggsw = GgswCiphertext::allocate(
+    ///     9 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(2),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// ggsw.fill_with_trivial_encryption(&Plaintext(1));
+    /// // Each of the 6 rows holds the constant scaled by its level, on its diagonal.
+    /// let non_zero: Vec<u32> = ggsw.as_tensor().iter().copied().filter(|a| *a != 0).collect();
+    /// assert_eq!(non_zero, vec![1 << 28, 1 << 28, 1 << 28, 1 << 24, 1 << 24, 1 << 24]);
+    /// ```
+    pub fn fill_with_trivial_encryption<Scalar>(&mut self, encoded: &Plaintext<Scalar>)
+    where
+        Self: AsMutTensor<Element = Scalar>,
+        Cont: AsMutSlice<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        self.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let base_log = self.decomposition_base_log();
+        for mut matrix in self.level_matrix_iter_mut() {
+            let decomposition = encoded.0
+                * (Scalar::ONE
+                    << (<Scalar as Numeric>::BITS
+                        - (base_log.0 * (matrix.decomposition_level().0))));
+            for (index, row) in matrix.row_iter_mut().enumerate() {
+                let mut polynomial_list = row.into_glwe().into_polynomial_list();
+                let mut level_polynomial = polynomial_list.get_mut_polynomial(index);
+                *level_polynomial.as_mut_tensor().first_mut() = decomposition;
+            }
+        }
+    }
 }
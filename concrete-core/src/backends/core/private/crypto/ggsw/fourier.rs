@@ -0,0 +1,638 @@
+use std::cell::RefCell;
+
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::ggsw::GgswCiphertext;
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::math::decomposition::SignedDecomposer;
+use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use crate::backends::core::private::utils::{zip, zip_args};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+/// A GGSW ciphertext in the fourier domain.
+#[derive(Debug, Clone)]
+pub struct FourierGgswCiphertext<Cont, Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    // The tensor containing the actual data of the ciphertext.
+    tensor: Tensor<Cont>,
+    // The size of the polynomials
+    poly_size: PolynomialSize,
+    // The size of the GLWE
+    glwe_size: GlweSize,
+    // The decomposition base log
+    decomp_base_log: DecompositionBaseLog,
+    // The fft plan is stored here. This way, we don't pay the price of allocating it every
+    // time we need to compute an external product with the same ciphertext.
+    fft: Fft,
+    // The buffers used to perform the fft are also stored in the ciphertext. Again, the same
+    // logic apply, and we don't have to allocate them multiple times.
+    fft_first_buffer: RefCell<FourierPolynomial<AlignedVec<Complex64>>>,
+    fft_second_buffer: RefCell<FourierPolynomial<AlignedVec<Complex64>>>,
+    fft_output_buffer: RefCell<Tensor<AlignedVec<Complex64>>>,
+    // This buffer is used to store the rounded input of the external product.
+    rounded_buffer: RefCell<GlweCiphertext<Vec<Scalar>>>,
+}
+
+impl<Scalar> FourierGgswCiphertext<AlignedVec<Complex64>, Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Allocates a new GGSW ciphertext in the fourier domain whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Complex64,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let mut tensor = Tensor::from_container(AlignedVec::new(
+            decomp_level.0 * glwe_size.0 * glwe_size.0 * poly_size.0,
+        ));
+        tensor.as_mut_tensor().fill_with_element(value);
+        FourierGgswCiphertext::from_tensor(tensor, glwe_size, poly_size, decomp_base_log)
+    }
+}
+
+impl<Cont, Scalar> FourierGgswCiphertext<Cont, Scalar>
+where
+    Scalar: UnsignedTorus,
+{
+    /// Creates a GGSW ciphertext in the fourier domain from an existing container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::from_container(
+    ///     vec![Complex64::new(0., 0.); 7 * 7 * 256 * 3],
+    ///     GlweSize(7),
+    ///     PolynomialSize(256),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice<Element = Complex64>,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => glwe_size.0 * glwe_size.0, poly_size.0);
+        FourierGgswCiphertext::from_tensor(tensor, glwe_size, poly_size, decomp_base_log)
+    }
+
+    fn from_tensor(
+        tensor: Tensor<Cont>,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let fft = Fft::new(poly_size);
+        let fft_first_buffer = RefCell::new(FourierPolynomial::allocate(
+            Complex64::new(0., 0.),
+            poly_size,
+        ));
+        let fft_second_buffer = RefCell::new(FourierPolynomial::allocate(
+            Complex64::new(0., 0.),
+            poly_size,
+        ));
+        let fft_output_buffer = RefCell::new(Tensor::from_container(AlignedVec::new(
+            poly_size.0 * glwe_size.0,
+        )));
+        let rounded_buffer =
+            RefCell::new(GlweCiphertext::allocate(Scalar::ZERO, poly_size, glwe_size));
+        FourierGgswCiphertext {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_base_log,
+            fft,
+            fft_first_buffer,
+            fft_second_buffer,
+            fft_output_buffer,
+            rounded_buffer,
+        }
+    }
+
+    /// Returns the size of the glwe ciphertexts composing the ggsw ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    /// ```
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    /// ```
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() =>
+            self.glwe_size.0 * self.glwe_size.0,
+            self.poly_size.0
+        );
+        DecompositionLevelCount(
+            self.as_tensor().len() / (self.glwe_size.0 * self.glwe_size.0 * self.poly_size.0),
+        )
+    }
+
+    /// Returns the logarithm of the base used for the gadget decomposition.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills a GGSW ciphertext in the fourier domain with the fourier transform of a GGSW
+    /// ciphertext in the coefficient domain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::{
+    ///     FourierGgswCiphertext, GgswCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw = GgswCiphertext::allocate(
+    ///     9u32,
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// fourier_ggsw.fill_with_forward_fourier(&ggsw);
+    /// ```
+    pub fn fill_with_forward_fourier<InputCont>(&mut self, coef_ggsw: &GgswCiphertext<InputCont>)
+    where
+        Cont: AsMutSlice<Element = Complex64>,
+        GgswCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(self.poly_size => coef_ggsw.polynomial_size());
+        ck_dim_eq!(self.glwe_size => coef_ggsw.glwe_size());
+        ck_dim_eq!(self.as_tensor().len() => coef_ggsw.as_tensor().len());
+
+        // We retrieve a buffer for the fft.
+        let fft_buffer = &mut self.fft_first_buffer.borrow_mut();
+
+        // We move every polynomials to the fourier domain.
+        let iterator = self
+            .tensor
+            .subtensor_iter_mut(self.poly_size.0)
+            .map(|t| FourierPolynomial::from_container(t.into_container()))
+            .zip(
+                coef_ggsw
+                    .as_tensor()
+                    .subtensor_iter(self.poly_size.0)
+                    .map(Polynomial::from_tensor),
+            );
+        for (mut fourier_poly, coef_poly) in iterator {
+            self.fft.forward_as_torus(fft_buffer, &coef_poly);
+            fourier_poly
+                .as_mut_tensor()
+                .fill_with_one(fft_buffer.as_tensor(), |a| *a);
+        }
+    }
+
+    /// Computes the external product between the ggsw ciphertext and a glwe ciphertext, and adds
+    /// the result to the output glwe ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let glwe = GlweCiphertext::allocate(1u32 << 20, PolynomialSize(256), GlweSize(3));
+    /// let mut output = GlweCiphertext::allocate(0u32, PolynomialSize(256), GlweSize(3));
+    /// ggsw.external_product(&mut output, &glwe);
+    /// ```
+    pub fn external_product<C1, C2>(
+        &self,
+        output: &mut GlweCiphertext<C1>,
+        glwe: &GlweCiphertext<C2>,
+    ) where
+        Self: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C2>: AsRefTensor<Element = Scalar>,
+    {
+        let ggsw = GgswCiphertext::from_container(
+            self.as_tensor().as_slice(),
+            self.glwe_size,
+            self.poly_size,
+            self.decomp_base_log,
+        );
+        fourier_external_product(
+            &self.fft,
+            &mut self.fft_first_buffer.borrow_mut(),
+            &mut self.fft_second_buffer.borrow_mut(),
+            &mut self.fft_output_buffer.borrow_mut(),
+            &mut *self.rounded_buffer.borrow_mut(),
+            output,
+            &ggsw,
+            glwe,
+        );
+    }
+
+    /// Computes a controlled mux between two glwe ciphertexts, using the ggsw ciphertext as
+    /// selector.
+    ///
+    /// The result is written in `ct0`, which ends up containing an encryption of the message of
+    /// `ct0` if the ggsw encrypts 0, and of the message of `ct1` if the ggsw encrypts 1. Note that
+    /// `ct1` is used as a temporary buffer, and is mutated by the operation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::FourierGgswCiphertext;
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweCiphertext;
+    /// use concrete_core::backends::core::private::math::fft::Complex64;
+    /// let ggsw: FourierGgswCiphertext<_, u32> = FourierGgswCiphertext::allocate(
+    ///     Complex64::new(0., 0.),
+    ///     PolynomialSize(256),
+    ///     GlweSize(3),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// let mut ct0 = GlweCiphertext::allocate(1u32 << 20, PolynomialSize(256), GlweSize(3));
+    /// let mut ct1 = GlweCiphertext::allocate(2u32 << 20, PolynomialSize(256), GlweSize(3));
+    /// ggsw.cmux(&mut ct0, &mut ct1);
+    /// ```
+    pub fn cmux<C0, C1>(&self, ct0: &mut GlweCiphertext<C0>, ct1: &mut GlweCiphertext<C1>)
+    where
+        Self: AsRefTensor<Element = Complex64>,
+        GlweCiphertext<C0>: AsMutTensor<Element = Scalar>,
+        GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    {
+        ct1.as_mut_tensor()
+            .update_with_wrapping_sub(ct0.as_tensor());
+        self.external_product(ct0, ct1);
+    }
+}
+
+/// Computes the external product between a ggsw ciphertext in the fourier domain and a glwe
+/// ciphertext, and adds the result to the output glwe ciphertext.
+///
+/// The fft plan and buffers are provided by the caller, so that the types performing external
+/// products (ggsw ciphertexts and bootstrap keys) can reuse their own.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn fourier_external_product<C1, C2, C3, Scalar>(
+    fft: &Fft,
+    first_fft_buffer: &mut FourierPolynomial<AlignedVec<Complex64>>,
+    second_fft_buffer: &mut FourierPolynomial<AlignedVec<Complex64>>,
+    output_fft_buffer: &mut Tensor<AlignedVec<Complex64>>,
+    rounded_input_glwe: &mut GlweCiphertext<Vec<Scalar>>,
+    output: &mut GlweCiphertext<C1>,
+    ggsw: &GgswCiphertext<C2>,
+    glwe: &GlweCiphertext<C3>,
+) where
+    GlweCiphertext<C1>: AsMutTensor<Element = Scalar>,
+    GgswCiphertext<C2>: AsRefTensor<Element = Complex64>,
+    GlweCiphertext<C3>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus,
+{
+    // We check that the polynomial sizes match
+    ck_dim_eq!(
+        fft.polynomial_size() =>
+        glwe.polynomial_size(),
+        ggsw.polynomial_size(),
+        output.polynomial_size()
+    );
+    // We check that the glwe sizes match
+    ck_dim_eq!(
+        ggsw.glwe_size() =>
+        glwe.size(),
+        output.size()
+    );
+    let poly_size = fft.polynomial_size();
+
+    // We reset the output buffer.
+    output_fft_buffer.fill_with_element(Complex64::new(0., 0.));
+
+    // We round the input mask and body
+    let decomposer = SignedDecomposer::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+    );
+    decomposer.fill_tensor_with_closest_representable(rounded_input_glwe, glwe);
+
+    // ------------------------------------------------------ EXTERNAL PRODUCT IN FOURIER DOMAIN
+    // In this section, we perform the external product in the fourier domain, and accumulate
+    // the result in the output_fft_buffer variable.
+    let mut decomposition = decomposer.decompose_tensor(rounded_input_glwe);
+    // We loop through the levels (we reverse to match the order of the decomposition iterator.)
+    for ggsw_decomp_matrix in ggsw.level_matrix_iter().rev() {
+        // We retrieve the decomposition of this level.
+        let glwe_decomp_term = decomposition.next_term().unwrap();
+        debug_assert_eq!(
+            ggsw_decomp_matrix.decomposition_level(),
+            glwe_decomp_term.level()
+        );
+        // For each levels we have to add the result of the vector-matrix product between the
+        // decomposition of the glwe, and the ggsw level matrix to the output. To do so, we
+        // iteratively add to the output, the product between every lines of the matrix, and
+        // the corresponding (scalar) polynomial in the glwe decomposition:
+        //
+        //                ggsw_mat                        ggsw_mat
+        //   glwe_dec   | - - - - | <        glwe_dec   | - - - - |
+        //  | - - - | x | - - - - |         | - - - | x | - - - - | <
+        //    ^         | - - - - |             ^       | - - - - |
+        //
+        //        t = 1                           t = 2                     ...
+        // When possible we iterate two times in a row, to benefit from the fact that fft can
+        // transform two polynomials at once.
+        let mut iterator = zip!(
+            ggsw_decomp_matrix.row_iter(),
+            glwe_decomp_term
+                .as_tensor()
+                .subtensor_iter(poly_size.0)
+                .map(Polynomial::from_tensor)
+        );
+
+        //---------------------------------------------------------------- VECTOR-MATRIX PRODUCT
+        loop {
+            match (iterator.next(), iterator.next()) {
+                // Two iterates are available, we use the fast fft.
+                (Some(first), Some(second)) => {
+                    // We unpack the iterator values
+                    let zip_args!(first_ggsw_row, first_glwe_poly) = first;
+                    let zip_args!(second_ggsw_row, second_glwe_poly) = second;
+                    // We perform the forward fft transform for the glwe polynomials
+                    fft.forward_two_as_integer(
+                        first_fft_buffer,
+                        second_fft_buffer,
+                        &first_glwe_poly,
+                        &second_glwe_poly,
+                    );
+                    // Now we loop through the polynomials of the output, and add the
+                    // corresponding product of polynomials.
+                    let iterator = zip!(
+                        first_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        second_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        output_fft_buffer
+                            .as_mut_tensor()
+                            .subtensor_iter_mut(poly_size.0)
+                            .map(FourierPolynomial::from_tensor)
+                    );
+                    for zip_args!(first_ggsw_poly, second_ggsw_poly, mut output_poly) in iterator {
+                        output_poly.update_with_two_multiply_accumulate(
+                            &first_ggsw_poly,
+                            first_fft_buffer,
+                            &second_ggsw_poly,
+                            second_fft_buffer,
+                        );
+                    }
+                }
+                // We reach the  end of the loop and one element remains.
+                (Some(first), None) => {
+                    // We unpack the iterator values
+                    let (first_ggsw_row, first_glwe_poly) = first;
+                    // We perform the forward fft transform for the glwe polynomial
+                    fft.forward_as_integer(first_fft_buffer, &first_glwe_poly);
+                    // Now we loop through the polynomials of the output, and add the
+                    // corresponding product of polynomials.
+                    let iterator = zip!(
+                        first_ggsw_row
+                            .as_tensor()
+                            .subtensor_iter(poly_size.0)
+                            .map(FourierPolynomial::from_tensor),
+                        output_fft_buffer
+                            .subtensor_iter_mut(poly_size.0)
+                            .map(FourierPolynomial::from_tensor)
+                    );
+                    for zip_args!(first_ggsw_poly, mut output_poly) in iterator {
+                        output_poly
+                            .update_with_multiply_accumulate(&first_ggsw_poly, first_fft_buffer);
+                    }
+                }
+                // The loop is over, we can exit.
+                _ => break,
+            }
+        }
+    }
+
+    // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+    // In this section, we bring the result from the fourier domain, back to the standard
+    // domain, and add it to the output.
+    //
+    // We iterate over the polynomials in the output. Again, when possible, we process two
+    // iterations simultaneously to benefit from the fft acceleration.
+    let mut _output_bind = output.as_mut_polynomial_list();
+    let mut iterator = zip!(
+        _output_bind.polynomial_iter_mut(),
+        output_fft_buffer
+            .subtensor_iter_mut(poly_size.0)
+            .map(FourierPolynomial::from_tensor)
+    );
+    loop {
+        match (iterator.next(), iterator.next()) {
+            (Some(first), Some(second)) => {
+                // We unpack the iterates
+                let zip_args!(mut first_output, mut first_fourier) = first;
+                let zip_args!(mut second_output, mut second_fourier) = second;
+                // We perform the backward transform
+                fft.add_backward_two_as_torus(
+                    &mut first_output,
+                    &mut second_output,
+                    &mut first_fourier,
+                    &mut second_fourier,
+                );
+            }
+            (Some(first), None) => {
+                // We unpack the iterates
+                let (mut first_output, mut first_fourier) = first;
+                // We perform the backward transform
+                fft.add_backward_as_torus(&mut first_output, &mut first_fourier);
+            }
+            _ => break,
+        }
+    }
+}
+
+impl<Cont, Scalar> PartialEq for FourierGgswCiphertext<Cont, Scalar>
+where
+    Cont: PartialEq,
+    Scalar: UnsignedTorus,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.tensor == other.tensor
+            && self.poly_size == other.poly_size
+            && self.glwe_size == other.glwe_size
+            && self.decomp_base_log == other.decomp_base_log
+    }
+}
+
+impl<Element, Cont, Scalar> AsRefTensor for FourierGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsRefSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_tensor(&self) -> &Tensor<Self::Container> {
+        &self.tensor
+    }
+}
+
+impl<Element, Cont, Scalar> AsMutTensor for FourierGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsMutSlice<Element = Element>,
+    Scalar: UnsignedTorus,
+{
+    type Element = Element;
+    type Container = Cont;
+    fn as_mut_tensor(&mut self) -> &mut Tensor<<Self as AsMutTensor>::Container> {
+        &mut self.tensor
+    }
+}
+
+impl<Cont, Scalar> IntoTensor for FourierGgswCiphertext<Cont, Scalar>
+where
+    Cont: AsRefSlice,
+    Scalar: UnsignedTorus,
+{
+    type Element = <Cont as AsRefSlice>::Element;
+    type Container = Cont;
+    fn into_tensor(self) -> Tensor<Self::Container> {
+        self.tensor
+    }
+}
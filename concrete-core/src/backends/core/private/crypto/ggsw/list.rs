@@ -0,0 +1,238 @@
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+
+use super::GgswCiphertext;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize, PolynomialSize,
+};
+
+/// A list of ciphertexts encoded with the GGSW scheme.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgswList<Cont> {
+    tensor: Tensor<Cont>,
+    poly_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomp_level: DecompositionLevelCount,
+    decomp_base_log: DecompositionBaseLog,
+}
+
+tensor_traits!(GgswList);
+
+impl<Scalar> GgswList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates storage for an owned [`GgswList`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswList;
+    /// let list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), GgswCiphertextCount(5));
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(list.glwe_size(), GlweSize(7));
+    /// assert_eq!(list.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(list.decomposition_base_log(), DecompositionBaseLog(4));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        ciphertext_count: GgswCiphertextCount,
+    ) -> Self {
+        GgswList {
+            tensor: Tensor::from_container(vec![
+                value;
+                ciphertext_count.0
+                    * decomp_level.0
+                    * glwe_size.0
+                    * glwe_size.0
+                    * poly_size.0
+            ]),
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+}
+
+impl<Cont> GgswList<Cont> {
+    /// Creates a list from a container of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswList;
+    /// let list = GgswList::from_container(
+    ///     vec![0 as u8; 5 * 3 * 7 * 7 * 10],
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), GgswCiphertextCount(5));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomp_level: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => decomp_level.0, glwe_size.0 * glwe_size.0, poly_size.0);
+        GgswList {
+            tensor,
+            poly_size,
+            glwe_size,
+            decomp_level,
+            decomp_base_log,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// See [`GgswList::allocate`] for an example.
+    pub fn ciphertext_count(&self) -> GgswCiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        GgswCiphertextCount(self.as_tensor().len() / self.ciphertext_size())
+    }
+
+    /// Returns the size of the glwe ciphertexts composing the ggsw ciphertexts of the list.
+    ///
+    /// See [`GgswList::allocate`] for an example.
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    /// Returns the size of the polynomials used in the ciphertexts of the list.
+    ///
+    /// See [`GgswList::allocate`] for an example.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of decomposition levels used in the ciphertexts of the list.
+    ///
+    /// See [`GgswList::allocate`] for an example.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the ciphertexts.
+    ///
+    /// See [`GgswList::allocate`] for an example.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns an iterator over borrowed ciphertexts of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswList;
+    /// let list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// for ggsw in list.ciphertext_iter() {
+    ///     assert_eq!(ggsw.polynomial_size(), PolynomialSize(10));
+    ///     assert_eq!(ggsw.glwe_size(), GlweSize(7));
+    ///     assert_eq!(ggsw.decomposition_level_count(), DecompositionLevelCount(3));
+    ///     assert_eq!(ggsw.decomposition_base_log(), DecompositionBaseLog(4));
+    /// }
+    /// assert_eq!(list.ciphertext_iter().count(), 5);
+    /// ```
+    pub fn ciphertext_iter(
+        &self,
+    ) -> impl Iterator<Item = GgswCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let (glwe_size, poly_size, base_log) =
+            (self.glwe_size, self.poly_size, self.decomp_base_log);
+        self.as_tensor()
+            .subtensor_iter(self.ciphertext_size())
+            .map(move |sub| {
+                GgswCiphertext::from_container(sub.into_container(), glwe_size, poly_size, base_log)
+            })
+    }
+
+    /// Returns an iterator over mutably borrowed ciphertexts of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweSize,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::ggsw::GgswList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut list = GgswList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweSize(7),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(4),
+    ///     GgswCiphertextCount(5),
+    /// );
+    /// for mut ggsw in list.ciphertext_iter_mut() {
+    ///     ggsw.as_mut_tensor().fill_with_element(9);
+    /// }
+    /// assert!(list.as_tensor().iter().all(|a| *a == 9));
+    /// ```
+    pub fn ciphertext_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GgswCiphertext<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let (glwe_size, poly_size, base_log) =
+            (self.glwe_size, self.poly_size, self.decomp_base_log);
+        let ciphertext_size = self.ciphertext_size();
+        self.as_mut_tensor()
+            .subtensor_iter_mut(ciphertext_size)
+            .map(move |sub| {
+                GgswCiphertext::from_container(sub.into_container(), glwe_size, poly_size, base_log)
+            })
+    }
+
+    fn ciphertext_size(&self) -> usize {
+        self.decomp_level.0 * self.glwe_size.0 * self.glwe_size.0 * self.poly_size.0
+    }
+}
@@ -3,5 +3,14 @@
 mod ciphertext;
 pub use ciphertext::*;
 
+mod fourier;
+pub use fourier::*;
+
 mod levels;
 pub use levels::*;
+
+mod list;
+pub use list::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,163 @@
+use concrete_commons::dispersion::LogStandardDev;
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount, PolynomialSize,
+};
+use concrete_fftw::array::AlignedVec;
+
+use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
+use crate::backends::core::private::crypto::ggsw::{FourierGgswCiphertext, GgswCiphertext};
+use crate::backends::core::private::crypto::glwe::GlweCiphertext;
+use crate::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::fft::Complex64;
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::AsRefTensor;
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+// The number of bits of message encoded in the msb of the glwe plaintexts.
+const MESSAGE_BITS: usize = 4;
+
+// Generates a plaintext list of random messages encoded in the msb.
+fn random_messages<T: UnsignedTorus>(
+    generator: &mut RandomGenerator,
+    count: PlaintextCount,
+) -> PlaintextList<Vec<T>> {
+    let shift = <T as Numeric>::BITS - MESSAGE_BITS;
+    let mut plaintexts = PlaintextList::allocate(T::ZERO, count);
+    for plaintext in plaintexts.plaintext_iter_mut() {
+        let random: T = generator.random_uniform();
+        plaintext.0 = (random >> shift) << shift;
+    }
+    plaintexts
+}
+
+// Rounds a decrypted plaintext list to the closest encoded messages.
+fn decode_messages<T: UnsignedTorus>(plaintexts: &mut PlaintextList<Vec<T>>) {
+    let shift = <T as Numeric>::BITS - MESSAGE_BITS;
+    for plaintext in plaintexts.plaintext_iter_mut() {
+        let rounded = plaintext.0.wrapping_add(T::ONE << (shift - 1)) >> shift;
+        plaintext.0 = rounded << shift;
+    }
+}
+
+// Encrypts a constant in a ggsw ciphertext, and converts it to the fourier domain.
+fn encrypt_fourier_ggsw<T: UnsignedTorus>(
+    key: &GlweSecretKey<BinaryKeyKind, Vec<T>>,
+    value: T,
+    level: DecompositionLevelCount,
+    base_log: DecompositionBaseLog,
+    generator: &mut EncryptionRandomGenerator,
+) -> FourierGgswCiphertext<AlignedVec<Complex64>, T> {
+    let std = LogStandardDev::from_log_standard_dev(-29.);
+    let mut ggsw = GgswCiphertext::allocate(
+        T::ZERO,
+        key.polynomial_size(),
+        key.key_size().to_glwe_size(),
+        level,
+        base_log,
+    );
+    key.encrypt_constant_ggsw(&mut ggsw, &Plaintext(value), std, generator);
+    let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+        Complex64::new(0., 0.),
+        key.polynomial_size(),
+        key.key_size().to_glwe_size(),
+        level,
+        base_log,
+    );
+    fourier_ggsw.fill_with_forward_fourier(&ggsw);
+    fourier_ggsw
+}
+
+fn test_external_product<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let glwe_dimension = GlweDimension(1);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-29.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let key =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let messages = random_messages::<T>(&mut random_generator, PlaintextCount(polynomial_size.0));
+    let mut glwe =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    key.encrypt_glwe(&mut glwe, &messages, std, &mut encryption_generator);
+
+    for selector in [T::ZERO, T::ONE] {
+        let ggsw = encrypt_fourier_ggsw(&key, selector, level, base_log, &mut encryption_generator);
+        let mut output =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+        ggsw.external_product(&mut output, &glwe);
+
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        key.decrypt_glwe(&mut decrypted, &output);
+        decode_messages(&mut decrypted);
+
+        if selector == T::ZERO {
+            assert!(decrypted.as_tensor().iter().all(|a| *a == T::ZERO));
+        } else {
+            assert_eq!(decrypted, messages);
+        }
+    }
+}
+
+fn test_cmux<T: UnsignedTorus>() {
+    let polynomial_size = PolynomialSize(512);
+    let glwe_dimension = GlweDimension(1);
+    let level = DecompositionLevelCount(3);
+    let base_log = DecompositionBaseLog(7);
+    let std = LogStandardDev::from_log_standard_dev(-29.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    let key =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    let messages_0 = random_messages::<T>(&mut random_generator, PlaintextCount(polynomial_size.0));
+    let messages_1 = random_messages::<T>(&mut random_generator, PlaintextCount(polynomial_size.0));
+    let mut glwe_0 =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    let mut glwe_1 =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    key.encrypt_glwe(&mut glwe_0, &messages_0, std, &mut encryption_generator);
+    key.encrypt_glwe(&mut glwe_1, &messages_1, std, &mut encryption_generator);
+
+    for (selector, expected) in [(T::ZERO, &messages_0), (T::ONE, &messages_1)] {
+        let ggsw = encrypt_fourier_ggsw(&key, selector, level, base_log, &mut encryption_generator);
+        let mut ct0 = glwe_0.clone();
+        let mut ct1 = glwe_1.clone();
+        ggsw.cmux(&mut ct0, &mut ct1);
+
+        let mut decrypted = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+        key.decrypt_glwe(&mut decrypted, &ct0);
+        decode_messages(&mut decrypted);
+
+        assert_eq!(&decrypted, expected);
+    }
+}
+
+#[test]
+fn test_external_product_u32() {
+    test_external_product::<u32>();
+}
+
+#[test]
+fn test_external_product_u64() {
+    test_external_product::<u64>();
+}
+
+#[test]
+fn test_cmux_u32() {
+    test_cmux::<u32>();
+}
+
+#[test]
+fn test_cmux_u64() {
+    test_cmux::<u64>();
+}
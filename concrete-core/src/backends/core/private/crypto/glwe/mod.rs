@@ -4,6 +4,13 @@ pub use body::*;
 pub use ciphertext::*;
 pub use list::*;
 pub use mask::*;
+pub use packing_keyswitch::*;
+pub use private_functional_packing_keyswitch::*;
+pub use private_functional_packing_keyswitch_list::*;
+pub use relinearization_key::*;
+pub use seeded_ciphertext::*;
+pub use seeded_list::*;
+pub use tensor_product::*;
 
 #[cfg(test)]
 mod tests;
@@ -12,3 +19,10 @@ mod body;
 mod ciphertext;
 mod list;
 mod mask;
+mod packing_keyswitch;
+mod private_functional_packing_keyswitch;
+mod private_functional_packing_keyswitch_list;
+mod relinearization_key;
+mod seeded_ciphertext;
+mod seeded_list;
+mod tensor_product;
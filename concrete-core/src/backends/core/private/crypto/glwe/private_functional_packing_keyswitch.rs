@@ -0,0 +1,573 @@
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, LweDimension,
+    MonomialDegree, PlaintextCount, PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{GlweCiphertext, GlweList};
+
+/// An LWE to GLWE private functional packing keyswitch key.
+///
+/// A private functional packing keyswitch key allows to switch a set of LWE ciphertexts encrypted
+/// under an LWE key $\mathbf{s}$ of size $n$ into a single GLWE ciphertext encrypted under a GLWE
+/// key $\mathbf{S}$, while applying a secret linear map $f: m \mapsto m\cdot P(X)$ to the input
+/// plaintexts. The polynomial $P$ is only known to the owner of the secret keys, and is hidden in
+/// the key.
+///
+/// The key is composed of $n+1$ blocks of $l$ GLWE ciphertexts. Writing $K_i = -s_i$ for $i<n$
+/// and $K_n = 1$, the $j$-th ciphertext of the $i$-th block encrypts under $\mathbf{S}$ the
+/// polynomial $K_i\cdot P(X)\frac{q}{B^j}$, where $B$ is the decomposition base and $l$ the number
+/// of decomposition levels.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PrivateFunctionalPackingKeyswitchKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(PrivateFunctionalPackingKeyswitchKey);
+
+impl<Scalar> PrivateFunctionalPackingKeyswitchKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a private functional packing keyswitch key whose masks and bodies are all
+    /// `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a private functional packing keyswitch key, but merely
+    /// allocates a container of the right size. See
+    /// [`PrivateFunctionalPackingKeyswitchKey::fill_with_private_functional_packing_keyswitch_key`]
+    /// to fill the container with a proper key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKey;
+    /// let pfpksk = PrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(
+    ///     pfpksk.decomposition_levels_count(),
+    ///     DecompositionLevelCount(10)
+    /// );
+    /// assert_eq!(pfpksk.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pfpksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(pfpksk.output_polynomial_size(), PolynomialSize(256));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> Self {
+        PrivateFunctionalPackingKeyswitchKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * output_glwe_dimension.to_glwe_size().0
+                    * output_polynomial_size.0
+                    * input_dimension.to_lwe_size().0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+}
+
+impl<Cont> PrivateFunctionalPackingKeyswitchKey<Cont> {
+    /// Creates a private functional packing keyswitch key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a private functional packing keyswitch key, but merely wraps
+    /// the container in the proper type. It assumes that either the container already contains a
+    /// proper key, or that
+    /// [`PrivateFunctionalPackingKeyswitchKey::fill_with_private_functional_packing_keyswitch_key`]
+    /// will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKey;
+    /// let pfpksk = PrivateFunctionalPackingKeyswitchKey::from_container(
+    ///     vec![0 as u8; 10 * 3 * 256 * 11],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(pfpksk.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(pfpksk.output_glwe_key_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> PrivateFunctionalPackingKeyswitchKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            output_glwe_dimension.to_glwe_size().0 * output_polynomial_size.0,
+            decomp_size.0
+        );
+        PrivateFunctionalPackingKeyswitchKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the output GLWE key.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        LweDimension(
+            self.as_tensor().len()
+                / (self.output_glwe_size.0
+                    * self.output_polynomial_size.0
+                    * self.decomp_level_count.0)
+                - 1,
+        )
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current private functional packing keyswitch key container with an actual key
+    /// constructed from an input LWE key, an output GLWE key, and the polynomial $P$ defining the
+    /// linear map $m \mapsto m\cdot P(X)$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     MonomialDegree, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKey;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::polynomial::Polynomial;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let input_key = LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    /// let mut polynomial = Polynomial::allocate(0 as u32, PolynomialSize(256));
+    /// *polynomial.get_mut_monomial(MonomialDegree(0)).get_mut_coefficient() = 1;
+    ///
+    /// let mut pfpksk = PrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// pfpksk.fill_with_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// assert!(!pfpksk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_private_functional_packing_keyswitch_key<
+        InKeyKind,
+        OutKeyKind,
+        InKeyCont,
+        OutKeyCont,
+        PolyCont,
+        Scalar,
+    >(
+        &mut self,
+        input_lwe_key: &LweSecretKey<InKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<OutKeyKind, OutKeyCont>,
+        polynomial: &Polynomial<PolyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        InKeyKind: KeyKind,
+        OutKeyKind: KeyKind,
+        LweSecretKey<InKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Polynomial<PolyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => polynomial.polynomial_size().0);
+
+        // We instantiate a buffer
+        let mut messages = PlaintextList::allocate(
+            Scalar::ZERO,
+            PlaintextCount(self.decomp_level_count.0 * self.output_polynomial_size.0),
+        );
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let polynomial_size = self.output_polynomial_size;
+
+        // The last block is associated with the body of the input ciphertexts
+        let key_elements = input_lwe_key
+            .as_tensor()
+            .iter()
+            .map(|s| s.wrapping_neg())
+            .chain(std::iter::once(Scalar::ONE));
+
+        // loop over the input key blocks
+        for (key_element, mut keyswitch_key_block) in key_elements.zip(self.block_iter_mut()) {
+            // We fill the buffer with the scaled product of the polynomial and the key element
+            for (level, mut message) in (1..=decomp_level_count.0).map(DecompositionLevel).zip(
+                messages
+                    .as_mut_tensor()
+                    .subtensor_iter_mut(polynomial_size.0),
+            ) {
+                message.fill_with_one(polynomial.as_tensor(), |coefficient| {
+                    DecompositionTerm::new(
+                        level,
+                        decomp_base_log,
+                        coefficient.wrapping_mul(key_element),
+                    )
+                    .to_recomposition_summand()
+                });
+            }
+
+            // We encrypt the buffer
+            output_glwe_key.encrypt_glwe_list(
+                &mut keyswitch_key_block,
+                &messages,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    /// Switches a single LWE ciphertext to a GLWE ciphertext, while applying the secret linear
+    /// map to the input plaintext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    ///     MonomialDegree, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, PrivateFunctionalPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCiphertext;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::polynomial::Polynomial;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let input_key = LweSecretKey::generate_binary(LweDimension(512), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// // The linear map multiplies the input by X^2
+    /// let mut polynomial = Polynomial::allocate(0 as u64, PolynomialSize(256));
+    /// *polynomial.get_mut_monomial(MonomialDegree(2)).get_mut_coefficient() = 1;
+    ///
+    /// let mut pfpksk = PrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    ///     LweDimension(512),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// pfpksk.fill_with_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let plaintext = Plaintext(3_u64 << 60);
+    /// let mut ciphertext = LweCiphertext::allocate(0u64, LweDimension(512).to_lwe_size());
+    /// input_key.encrypt_lwe(&mut ciphertext, &plaintext, noise, &mut encryption_generator);
+    ///
+    /// let mut switched = GlweCiphertext::allocate(
+    ///     0u64,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    /// );
+    /// pfpksk.keyswitch_ciphertext(&mut switched, &ciphertext);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(256));
+    /// output_key.decrypt_glwe(&mut decrypted, &switched);
+    /// for (i, coefficient) in decrypted.as_tensor().iter().enumerate() {
+    ///     let expected = if i == 2 { 3 } else { 0 };
+    ///     assert_eq!((coefficient.wrapping_add(1 << 59)) >> 60, expected);
+    /// }
+    /// ```
+    pub fn keyswitch_ciphertext<InCont, OutCont, Scalar>(
+        &self,
+        after: &mut GlweCiphertext<OutCont>,
+        before: &LweCiphertext<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.input_lwe_key_dimension().0 => before.get_mask().mask_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => after.mask_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => after.polynomial_size().0);
+
+        // We reset the output
+        after.as_mut_tensor().fill_with_element(Scalar::ZERO);
+
+        // We instantiate a decomposer
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let ciphertext_size = self.output_glwe_size.0 * self.output_polynomial_size.0;
+
+        // loop over the whole input ciphertext, the body being associated with the last block
+        for (block, input_element) in self.block_iter().zip(before.as_tensor().iter()) {
+            let block_tensor = block.as_tensor();
+
+            // loop over the levels of the decomposition, the terms being output from the last one
+            for term in decomposer.decompose(*input_element) {
+                let level_key_ciphertext = block_tensor.get_sub(
+                    (term.level().0 - 1) * ciphertext_size..term.level().0 * ciphertext_size,
+                );
+                after
+                    .as_mut_tensor()
+                    .update_with_wrapping_add_element_mul(&level_key_ciphertext, term.value());
+            }
+        }
+    }
+
+    /// Packs a list of LWE ciphertexts into a single GLWE ciphertext, while applying the secret
+    /// linear map. The $k$-th input plaintext $m_k$ contributes $m_k\cdot P(X)\cdot X^k$ to the
+    /// output plaintext.
+    ///
+    /// The number of input ciphertexts must not exceed the size of the output polynomials.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension,
+    ///     LweDimension, MonomialDegree, PlaintextCount, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, PrivateFunctionalPackingKeyswitchKey,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::lwe::LweList;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::polynomial::Polynomial;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let noise = LogStandardDev::from_log_standard_dev(-40.);
+    /// let input_key = LweSecretKey::generate_binary(LweDimension(512), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// // The linear map multiplies the inputs by 2
+    /// let mut polynomial = Polynomial::allocate(0 as u64, PolynomialSize(256));
+    /// *polynomial.get_mut_monomial(MonomialDegree(0)).get_mut_coefficient() = 2;
+    ///
+    /// let mut pfpksk = PrivateFunctionalPackingKeyswitchKey::allocate(
+    ///     0 as u64,
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(10),
+    ///     LweDimension(512),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// pfpksk.fill_with_private_functional_packing_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     &polynomial,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let plaintexts = PlaintextList::from_container(vec![1_u64 << 60, 2 << 60, 3 << 60]);
+    /// let mut ciphertexts = LweList::allocate(
+    ///     0u64,
+    ///     LweDimension(512).to_lwe_size(),
+    ///     CiphertextCount(3),
+    /// );
+    /// input_key.encrypt_lwe_list(
+    ///     &mut ciphertexts,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut packed = GlweCiphertext::allocate(
+    ///     0u64,
+    ///     PolynomialSize(256),
+    ///     GlweDimension(2).to_glwe_size(),
+    /// );
+    /// pfpksk.private_functional_packing_keyswitch(&mut packed, &ciphertexts);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(256));
+    /// output_key.decrypt_glwe(&mut decrypted, &packed);
+    /// for (i, coefficient) in decrypted.as_tensor().iter().enumerate() {
+    ///     let expected = if i < 3 { 2 * (i as u64 + 1) } else { 0 };
+    ///     assert_eq!((coefficient.wrapping_add(1 << 59)) >> 60, expected);
+    /// }
+    /// ```
+    pub fn private_functional_packing_keyswitch<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        assert!(input.count().0 <= output.polynomial_size().0);
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+        let mut buffer = GlweCiphertext::allocate(
+            Scalar::ZERO,
+            self.output_polynomial_size,
+            self.output_glwe_size,
+        );
+
+        // We switch each ciphertext, and rotate the result to the right coefficient
+        for (degree, input_ciphertext) in input.ciphertext_iter().enumerate() {
+            self.keyswitch_ciphertext::<_, Vec<Scalar>, _>(&mut buffer, &input_ciphertext);
+            buffer
+                .as_mut_polynomial_list()
+                .update_with_wrapping_monic_monomial_mul(MonomialDegree(degree));
+            output
+                .as_mut_tensor()
+                .update_with_wrapping_add(buffer.as_tensor());
+        }
+    }
+
+    // Iterates over the blocks of GLWE ciphertexts associated with the input key elements.
+    fn block_iter(&self) -> impl Iterator<Item = GlweList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let size =
+            self.decomp_level_count.0 * self.output_glwe_size.0 * self.output_polynomial_size.0;
+        let glwe_dimension = self.output_glwe_size.to_glwe_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        self.as_tensor().subtensor_iter(size).map(move |sub| {
+            GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+        })
+    }
+
+    // Iterates over the mutable blocks of GLWE ciphertexts associated with the input key elements.
+    fn block_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweList<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let size =
+            self.decomp_level_count.0 * self.output_glwe_size.0 * self.output_polynomial_size.0;
+        let glwe_dimension = self.output_glwe_size.to_glwe_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(size)
+            .map(move |sub| {
+                GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+            })
+    }
+}
@@ -0,0 +1,386 @@
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, GlweSize, LweDimension, MonomialDegree, PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::polynomial::Polynomial;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::PrivateFunctionalPackingKeyswitchKey;
+
+/// A list of private functional packing keyswitch keys sharing the same parameters.
+///
+/// Such a list is used by the circuit bootstrap, which needs one private functional packing
+/// keyswitch key for each row of the output GGSW level matrices.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    input_lwe_dimension: LweDimension,
+    output_glwe_size: GlweSize,
+    output_polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(PrivateFunctionalPackingKeyswitchKeyList);
+
+impl<Scalar> PrivateFunctionalPackingKeyswitchKeyList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a list of private functional packing keyswitch keys whose masks and bodies are
+    /// all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate the keys, but merely allocates a container of the right
+    /// size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// let list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    /// );
+    /// assert_eq!(list.decomposition_levels_count(), DecompositionLevelCount(10));
+    /// assert_eq!(list.decomposition_base_log(), DecompositionBaseLog(16));
+    /// assert_eq!(list.input_lwe_key_dimension(), LweDimension(10));
+    /// assert_eq!(list.output_glwe_key_dimension(), GlweDimension(2));
+    /// assert_eq!(list.output_polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(list.key_count(), FunctionalPackingKeyswitchKeyCount(3));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        decomp_size: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+        input_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+        key_count: FunctionalPackingKeyswitchKeyCount,
+    ) -> Self {
+        PrivateFunctionalPackingKeyswitchKeyList {
+            tensor: Tensor::from_container(vec![
+                value;
+                decomp_size.0
+                    * output_glwe_dimension.to_glwe_size().0
+                    * output_polynomial_size.0
+                    * input_dimension.to_lwe_size().0
+                    * key_count.0
+            ]),
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_lwe_dimension: input_dimension,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+}
+
+impl<Cont> PrivateFunctionalPackingKeyswitchKeyList<Cont> {
+    /// Creates a list of private functional packing keyswitch keys from a container.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// let list = PrivateFunctionalPackingKeyswitchKeyList::from_container(
+    ///     vec![0 as u8; 10 * 3 * 256 * 11 * 3],
+    ///     DecompositionBaseLog(16),
+    ///     DecompositionLevelCount(10),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    /// );
+    /// assert_eq!(list.key_count(), FunctionalPackingKeyswitchKeyCount(3));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_size: DecompositionLevelCount,
+        input_dimension: LweDimension,
+        output_glwe_dimension: GlweDimension,
+        output_polynomial_size: PolynomialSize,
+    ) -> PrivateFunctionalPackingKeyswitchKeyList<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() =>
+            output_glwe_dimension.to_glwe_size().0 * output_polynomial_size.0,
+            decomp_size.0,
+            input_dimension.to_lwe_size().0
+        );
+        PrivateFunctionalPackingKeyswitchKeyList {
+            tensor,
+            decomp_base_log,
+            decomp_level_count: decomp_size,
+            input_lwe_dimension: input_dimension,
+            output_glwe_size: output_glwe_dimension.to_glwe_size(),
+            output_polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the output GLWE key.
+    pub fn output_glwe_key_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the output GLWE key.
+    pub fn output_polynomial_size(&self) -> PolynomialSize {
+        self.output_polynomial_size
+    }
+
+    /// Returns the dimension of the input LWE key.
+    pub fn input_lwe_key_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    /// Returns the number of levels used for the decomposition of the input key bits.
+    pub fn decomposition_levels_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the input key bits.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Returns the number of keys contained in the list.
+    pub fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount
+    where
+        Self: AsRefTensor,
+    {
+        FunctionalPackingKeyswitchKeyCount(self.as_tensor().len() / self.key_len())
+    }
+
+    /// Returns an iterator over the keys of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// let list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    /// );
+    /// for key in list.key_iter() {
+    ///     assert_eq!(key.input_lwe_key_dimension(), LweDimension(10));
+    ///     assert_eq!(key.output_glwe_key_dimension(), GlweDimension(2));
+    /// }
+    /// assert_eq!(list.key_iter().count(), 3);
+    /// ```
+    pub fn key_iter(
+        &self,
+    ) -> impl Iterator<Item = PrivateFunctionalPackingKeyswitchKey<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let base_log = self.decomp_base_log;
+        let level_count = self.decomp_level_count;
+        let glwe_dimension = self.output_glwe_key_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        self.as_tensor()
+            .subtensor_iter(self.key_len())
+            .map(move |sub| {
+                PrivateFunctionalPackingKeyswitchKey::from_container(
+                    sub.into_container(),
+                    base_log,
+                    level_count,
+                    glwe_dimension,
+                    polynomial_size,
+                )
+            })
+    }
+
+    /// Returns an iterator over mutable views of the keys of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0 as u8,
+    ///     DecompositionLevelCount(10),
+    ///     DecompositionBaseLog(16),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    /// );
+    /// for mut key in list.key_iter_mut() {
+    ///     key.as_mut_tensor().fill_with_element(9);
+    /// }
+    /// assert!(list.as_tensor().iter().all(|a| *a == 9));
+    /// ```
+    pub fn key_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = PrivateFunctionalPackingKeyswitchKey<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let key_len = self.key_len();
+        let base_log = self.decomp_base_log;
+        let level_count = self.decomp_level_count;
+        let glwe_dimension = self.output_glwe_key_dimension();
+        let polynomial_size = self.output_polynomial_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(key_len)
+            .map(move |sub| {
+                PrivateFunctionalPackingKeyswitchKey::from_container(
+                    sub.into_container(),
+                    base_log,
+                    level_count,
+                    glwe_dimension,
+                    polynomial_size,
+                )
+            })
+    }
+
+    /// Fills the list with the private functional packing keyswitch keys used by the circuit
+    /// bootstrap.
+    ///
+    /// The list must contain $k+1$ keys, where $k$ is the dimension of the output GLWE key
+    /// $\mathbf{S}$. For $i<k$, the $i$-th key applies the map $m \mapsto -m\cdot S_i(X)$, and the
+    /// last key applies the identity map $m \mapsto m$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    ///     GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let input_key = LweSecretKey::generate_binary(LweDimension(10), &mut secret_generator);
+    /// let output_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut list = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+    ///     0 as u32,
+    ///     DecompositionLevelCount(5),
+    ///     DecompositionBaseLog(3),
+    ///     LweDimension(10),
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     FunctionalPackingKeyswitchKeyCount(3),
+    /// );
+    /// list.fill_with_circuit_bootstrap_keys(
+    ///     &input_key,
+    ///     &output_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// assert!(!list.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_circuit_bootstrap_keys<InKeyKind, OutKeyKind, InKeyCont, OutKeyCont, Scalar>(
+        &mut self,
+        input_lwe_key: &LweSecretKey<InKeyKind, InKeyCont>,
+        output_glwe_key: &GlweSecretKey<OutKeyKind, OutKeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        InKeyKind: KeyKind,
+        OutKeyKind: KeyKind,
+        LweSecretKey<InKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        GlweSecretKey<OutKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.key_count().0 => output_glwe_key.key_size().to_glwe_size().0);
+        ck_dim_eq!(self.input_lwe_dimension.0 => input_lwe_key.key_size().0);
+        ck_dim_eq!(self.output_glwe_key_dimension().0 => output_glwe_key.key_size().0);
+        ck_dim_eq!(self.output_polynomial_size.0 => output_glwe_key.polynomial_size().0);
+
+        // The last key applies the identity map
+        let mut identity = Polynomial::allocate(Scalar::ZERO, self.output_polynomial_size);
+        *identity
+            .get_mut_monomial(MonomialDegree(0))
+            .get_mut_coefficient() = Scalar::ONE;
+
+        let mut polynomial = Polynomial::allocate(Scalar::ZERO, self.output_polynomial_size);
+        let key_polynomials = output_glwe_key.as_polynomial_list();
+        let mut keys = self.key_iter_mut();
+
+        // The first keys multiply by the opposite of the output key polynomials
+        for (key_polynomial, mut key) in key_polynomials.polynomial_iter().zip(&mut keys) {
+            polynomial
+                .as_mut_tensor()
+                .fill_with_one(key_polynomial.as_tensor(), |s| s.wrapping_neg());
+            key.fill_with_private_functional_packing_keyswitch_key(
+                input_lwe_key,
+                output_glwe_key,
+                &polynomial,
+                noise_parameters,
+                generator,
+            );
+        }
+        for mut key in keys {
+            key.fill_with_private_functional_packing_keyswitch_key(
+                input_lwe_key,
+                output_glwe_key,
+                &identity,
+                noise_parameters,
+                generator,
+            );
+        }
+    }
+
+    // Returns the number of scalars in a single key of the list.
+    fn key_len(&self) -> usize {
+        self.decomp_level_count.0
+            * self.output_glwe_size.0
+            * self.output_polynomial_size.0
+            * self.input_lwe_dimension.to_lwe_size().0
+    }
+}
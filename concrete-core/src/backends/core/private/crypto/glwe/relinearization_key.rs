@@ -0,0 +1,446 @@
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize, PlaintextCount,
+    PolynomialSize,
+};
+
+use crate::backends::core::private::crypto::encoding::PlaintextList;
+use crate::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use crate::backends::core::private::crypto::secret::GlweSecretKey;
+use crate::backends::core::private::math::decomposition::{
+    DecompositionLevel, DecompositionTerm, SignedDecomposer,
+};
+use crate::backends::core::private::math::fft::{Complex64, Fft, FourierPolynomial};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{tensor_product_polynomial_count, GlweCiphertext, GlweList};
+
+/// A GLWE relinearization key.
+///
+/// A relinearization key allows to turn the tensor product of two GLWE ciphertexts encrypted
+/// under a GLWE key $\mathbf{S} = (S_1, \dots, S_k)$, which is encrypted under the quadratic key
+/// $(S_iS_j)_{i \leq j}$, back into a GLWE ciphertext encrypted under $\mathbf{S}$.
+///
+/// The key is composed of $\frac{k(k+1)}{2}$ blocks of $l$ GLWE ciphertexts, one block for every
+/// pair $i \leq j$ in lexicographic order. The $m$-th ciphertext of a block encrypts under
+/// $\mathbf{S}$ the polynomial $S_iS_j\frac{q}{B^m}$, where $B$ is the decomposition base and $l$
+/// the number of decomposition levels.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct GlweRelinearizationKey<Cont> {
+    tensor: Tensor<Cont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+}
+
+tensor_traits!(GlweRelinearizationKey);
+
+impl<Scalar> GlweRelinearizationKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a relinearization key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a relinearization key, but merely allocates a container
+    /// of the right size. See [`GlweRelinearizationKey::fill_with_relinearization_key`] to fill
+    /// the container with a proper key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// assert_eq!(rlk.polynomial_size(), PolynomialSize(256));
+    /// assert_eq!(rlk.decomposition_level_count(), DecompositionLevelCount(3));
+    /// assert_eq!(rlk.decomposition_base_log(), DecompositionBaseLog(7));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> Self {
+        let glwe_size = glwe_dimension.to_glwe_size();
+        GlweRelinearizationKey {
+            tensor: Tensor::from_container(vec![
+                value;
+                glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+                    * decomp_level_count.0
+                    * glwe_size.0
+                    * polynomial_size.0
+            ]),
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        }
+    }
+}
+
+impl<Cont> GlweRelinearizationKey<Cont> {
+    /// Creates a relinearization key from a container.
+    ///
+    /// # Notes
+    ///
+    /// This method does not create a relinearization key, but merely wraps the container in the
+    /// proper type. It assumes that either the container already contains a proper key, or that
+    /// [`GlweRelinearizationKey::fill_with_relinearization_key`] will be called right after.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// let rlk = GlweRelinearizationKey::from_container(
+    ///     vec![0 as u32; 3 * 3 * 3 * 256],
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// assert_eq!(rlk.glwe_dimension(), GlweDimension(2));
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomp_level_count: DecompositionLevelCount,
+        decomp_base_log: DecompositionBaseLog,
+    ) -> GlweRelinearizationKey<Cont>
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        let glwe_size = glwe_dimension.to_glwe_size();
+        ck_dim_eq!(
+            tensor.len() =>
+            glwe_dimension.0 * (glwe_dimension.0 + 1) / 2
+                * decomp_level_count.0
+                * glwe_size.0
+                * polynomial_size.0
+        );
+        GlweRelinearizationKey {
+            tensor,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        }
+    }
+
+    /// Returns the dimension of the GLWE key.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_size.to_glwe_dimension()
+    }
+
+    /// Returns the size of the polynomials of the GLWE key.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    /// Returns the number of levels used for the decomposition of the tensor product.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Returns the logarithm of the base used for the decomposition of the tensor product.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Fills the current relinearization key container with an actual relinearization key
+    /// constructed from a GLWE key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     &mut secret_generator,
+    /// );
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0 as u32,
+    ///     GlweDimension(2),
+    ///     PolynomialSize(256),
+    ///     DecompositionLevelCount(3),
+    ///     DecompositionBaseLog(7),
+    /// );
+    /// rlk.fill_with_relinearization_key(
+    ///     &key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// assert!(!rlk.as_tensor().iter().all(|a| *a == 0));
+    /// ```
+    pub fn fill_with_relinearization_key<Kind, KeyCont, Scalar>(
+        &mut self,
+        glwe_key: &GlweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        Kind: KeyKind,
+        GlweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_dimension().0 => glwe_key.key_size().0);
+        ck_dim_eq!(self.polynomial_size.0 => glwe_key.polynomial_size().0);
+
+        // We instantiate the buffers
+        let mut messages = PlaintextList::allocate(
+            Scalar::ZERO,
+            PlaintextCount(self.decomp_level_count.0 * self.polynomial_size.0),
+        );
+        let mut key_product = Polynomial::allocate(Scalar::ZERO, self.polynomial_size);
+
+        // We retrieve decomposition arguments
+        let decomp_level_count = self.decomp_level_count;
+        let decomp_base_log = self.decomp_base_log;
+        let polynomial_size = self.polynomial_size;
+
+        let key_polynomials = glwe_key.as_polynomial_list();
+        let glwe_dimension = self.glwe_dimension().0;
+        let key_pairs = (0..glwe_dimension).flat_map(|i| (i..glwe_dimension).map(move |j| (i, j)));
+        for ((i, j), mut block) in key_pairs.zip(self.block_iter_mut()) {
+            key_product.fill_with_wrapping_mul(
+                &key_polynomials.get_polynomial(i),
+                &key_polynomials.get_polynomial(j),
+            );
+
+            // We fill the buffer with the key product, multiplied by the powers of the base
+            for (level, mut message) in (1..=decomp_level_count.0).map(DecompositionLevel).zip(
+                messages
+                    .as_mut_tensor()
+                    .subtensor_iter_mut(polynomial_size.0),
+            ) {
+                message.fill_with_one(key_product.as_tensor(), |coefficient| {
+                    DecompositionTerm::new(level, decomp_base_log, *coefficient)
+                        .to_recomposition_summand()
+                });
+            }
+
+            // We encrypt the buffer
+            glwe_key.encrypt_glwe_list(&mut block, &messages, noise_parameters, generator);
+        }
+    }
+
+    /// Relinearizes the tensor product of two GLWE ciphertexts, and writes the result in the
+    /// `output` GLWE ciphertext.
+    ///
+    /// The `tensor_product` polynomial list must have the layout produced by
+    /// [`GlweCiphertext::tensor_product`]. Every polynomial associated with a product $S_iS_j$
+    /// is decomposed, and multiplied in the fourier domain with the corresponding block of the
+    /// key, the results being added to the linear part of the tensor product.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PlaintextCount,
+    ///     PolynomialSize,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     tensor_product_polynomial_count, GlweCiphertext, GlweRelinearizationKey,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::GlweSecretKey;
+    /// use concrete_core::backends::core::private::math::polynomial::PolynomialList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let noise = LogStandardDev::from_log_standard_dev(-50.);
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(256);
+    /// let key = GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+    ///
+    /// let mut rlk = GlweRelinearizationKey::allocate(
+    ///     0u64,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     DecompositionLevelCount(4),
+    ///     DecompositionBaseLog(10),
+    /// );
+    /// rlk.fill_with_relinearization_key(&key, noise, &mut encryption_generator);
+    ///
+    /// // We encrypt 3 and 5 in the constant coefficients, with a scaling factor of 2^56.
+    /// let mut messages = PlaintextList::allocate(0u64, PlaintextCount(256));
+    /// *messages.as_mut_tensor().get_element_mut(0) = 3 << 56;
+    /// let mut lhs = GlweCiphertext::allocate(0u64, polynomial_size, glwe_dimension.to_glwe_size());
+    /// key.encrypt_glwe(&mut lhs, &messages, noise, &mut encryption_generator);
+    /// *messages.as_mut_tensor().get_element_mut(0) = 5 << 56;
+    /// let mut rhs = GlweCiphertext::allocate(0u64, polynomial_size, glwe_dimension.to_glwe_size());
+    /// key.encrypt_glwe(&mut rhs, &messages, noise, &mut encryption_generator);
+    ///
+    /// let mut tensor_product = PolynomialList::allocate(
+    ///     0u64,
+    ///     tensor_product_polynomial_count(glwe_dimension.to_glwe_size()),
+    ///     polynomial_size,
+    /// );
+    /// lhs.tensor_product(&rhs, &mut tensor_product, 1 << 56);
+    /// let mut product = GlweCiphertext::allocate(0u64, polynomial_size, glwe_dimension.to_glwe_size());
+    /// rlk.relinearize(&mut product, &tensor_product);
+    ///
+    /// let mut decrypted = PlaintextList::allocate(0u64, PlaintextCount(256));
+    /// key.decrypt_glwe(&mut decrypted, &product);
+    /// let constant = *decrypted.as_tensor().first();
+    /// assert_eq!((constant.wrapping_add(1 << 55)) >> 56, 15);
+    /// ```
+    pub fn relinearize<OutCont, InCont, Scalar>(
+        &self,
+        output: &mut GlweCiphertext<OutCont>,
+        tensor_product: &PolynomialList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        PolynomialList<InCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.glwe_size.0 => output.size().0);
+        ck_dim_eq!(
+            self.polynomial_size.0 =>
+            output.polynomial_size().0,
+            tensor_product.polynomial_size().0
+        );
+        ck_dim_eq!(
+            tensor_product.polynomial_count().0 =>
+            tensor_product_polynomial_count(self.glwe_size).0
+        );
+        let poly_size = self.polynomial_size;
+
+        // The linear part of the tensor product has the same layout as a GLWE ciphertext.
+        let glwe_dimension = self.glwe_dimension().0;
+        let quadratic_len = glwe_dimension * (glwe_dimension + 1) / 2 * poly_size.0;
+        output
+            .as_mut_tensor()
+            .as_mut_slice()
+            .copy_from_slice(&tensor_product.as_tensor().as_slice()[quadratic_len..]);
+
+        let fft = Fft::new(poly_size);
+        let decomposer = SignedDecomposer::new(self.decomp_base_log, self.decomp_level_count);
+        let mut fourier_term = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut fourier_key = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut fourier_output: Vec<_> = (0..self.glwe_size.0)
+            .map(|_| FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size))
+            .collect();
+        let ciphertext_size = self.glwe_size.0 * poly_size.0;
+
+        for (quadratic_polynomial, block) in tensor_product
+            .polynomial_iter()
+            .take(glwe_dimension * (glwe_dimension + 1) / 2)
+            .zip(self.block_iter())
+        {
+            let block_tensor = block.as_tensor();
+            let mut decomposition = decomposer.decompose_tensor(&quadratic_polynomial);
+            // loop over the levels of the decomposition, the terms being output from the last one
+            while let Some(term) = decomposition.next_term() {
+                let level_key_ciphertext = GlweCiphertext::from_container(
+                    block_tensor
+                        .get_sub(
+                            (term.level().0 - 1) * ciphertext_size
+                                ..term.level().0 * ciphertext_size,
+                        )
+                        .into_container(),
+                    poly_size,
+                );
+                fft.forward_as_integer(
+                    &mut fourier_term,
+                    &Polynomial::from_container(term.as_tensor().as_slice()),
+                );
+                for (key_polynomial, fourier_output_polynomial) in level_key_ciphertext
+                    .as_polynomial_list()
+                    .polynomial_iter()
+                    .zip(fourier_output.iter_mut())
+                {
+                    fft.forward_as_torus(&mut fourier_key, &key_polynomial);
+                    fourier_output_polynomial
+                        .update_with_multiply_accumulate(&fourier_key, &fourier_term);
+                }
+            }
+        }
+
+        // We bring the products back to the standard domain, and add them to the output.
+        for (mut output_polynomial, fourier_output_polynomial) in output
+            .as_mut_polynomial_list()
+            .polynomial_iter_mut()
+            .zip(fourier_output.iter_mut())
+        {
+            fft.add_backward_as_torus(&mut output_polynomial, fourier_output_polynomial);
+        }
+    }
+
+    // Iterates over the blocks of GLWE ciphertexts associated with the products of key
+    // polynomials.
+    fn block_iter(&self) -> impl Iterator<Item = GlweList<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        let size = self.decomp_level_count.0 * self.glwe_size.0 * self.polynomial_size.0;
+        let glwe_dimension = self.glwe_size.to_glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_tensor().subtensor_iter(size).map(move |sub| {
+            GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+        })
+    }
+
+    // Iterates over the mutable blocks of GLWE ciphertexts associated with the products of key
+    // polynomials.
+    fn block_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweList<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        let size = self.decomp_level_count.0 * self.glwe_size.0 * self.polynomial_size.0;
+        let glwe_dimension = self.glwe_size.to_glwe_dimension();
+        let polynomial_size = self.polynomial_size;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(size)
+            .map(move |sub| {
+                GlweList::from_container(sub.into_container(), glwe_dimension, polynomial_size)
+            })
+    }
+}
@@ -0,0 +1,217 @@
+use super::{GlweBody, GlweCiphertext};
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutTensor, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+use serde::{Deserialize, Serialize};
+
+/// A seeded GLWE ciphertext.
+///
+/// Only the body of the ciphertext is stored, along with the seed of the random generator used
+/// to sample the mask. The mask can be regenerated from the seed, when the ciphertext is expanded
+/// into a regular [`GlweCiphertext`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GlweSeededCiphertext<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) seed: u128,
+}
+
+tensor_traits!(GlweSeededCiphertext);
+
+impl<Scalar> GlweSeededCiphertext<Vec<Scalar>> {
+    /// Allocates a new seeded GLWE ciphertext, whose body coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe_ciphertext =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// assert_eq!(glwe_ciphertext.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(glwe_ciphertext.mask_size(), GlweDimension(99));
+    /// assert_eq!(glwe_ciphertext.size(), GlweSize(100));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        seed: u128,
+    ) -> GlweSeededCiphertext<Vec<Scalar>>
+    where
+        Scalar: Copy,
+    {
+        GlweSeededCiphertext::from_container(vec![value; poly_size.0], glwe_dimension, seed)
+    }
+}
+
+impl<Cont> GlweSeededCiphertext<Cont> {
+    /// Creates a new seeded GLWE ciphertext from an existing container of body coefficients.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe = GlweSeededCiphertext::from_container(vec![0 as u8; 10], GlweDimension(109), 42);
+    /// assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(glwe.mask_size(), GlweDimension(109));
+    /// assert_eq!(glwe.seed(), 42);
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        glwe_dimension: GlweDimension,
+        seed: u128,
+    ) -> GlweSeededCiphertext<Cont> {
+        GlweSeededCiphertext {
+            tensor: Tensor::from_container(cont),
+            glwe_dimension,
+            seed,
+        }
+    }
+
+    /// Returns the size of the expanded ciphertext, e.g. the number of masks + 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// assert_eq!(glwe.size(), GlweSize(100));
+    /// ```
+    pub fn size(&self) -> GlweSize {
+        self.glwe_dimension.to_glwe_size()
+    }
+
+    /// Returns the number of masks of the expanded ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// assert_eq!(glwe.mask_size(), GlweDimension(99));
+    /// ```
+    pub fn mask_size(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Returns the size of the polynomials used in the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// assert_eq!(glwe.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize
+    where
+        Self: AsRefTensor,
+    {
+        PolynomialSize(self.as_tensor().len())
+    }
+
+    /// Returns the seed used to generate the mask of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// assert_eq!(glwe.seed(), 42);
+    /// ```
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns a borrowed [`GlweBody`] from the current ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// let glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// let body = glwe.get_body();
+    /// assert_eq!(body.as_polynomial().polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn get_body(&self) -> GlweBody<&[<Self as AsRefTensor>::Element]>
+    where
+        Self: AsRefTensor,
+    {
+        GlweBody {
+            tensor: self.as_tensor().get_sub(..),
+        }
+    }
+
+    /// Returns a mutably borrowed [`GlweBody`] from the current ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededCiphertext;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut glwe =
+    ///     GlweSeededCiphertext::allocate(0 as u8, PolynomialSize(10), GlweDimension(99), 42);
+    /// let mut body = glwe.get_mut_body();
+    /// body.as_mut_tensor().fill_with_element(9);
+    /// let body = glwe.get_body();
+    /// assert!(body.as_tensor().iter().all(|a| *a == 9));
+    /// ```
+    pub fn get_mut_body(&mut self) -> GlweBody<&mut [<Self as AsRefTensor>::Element]>
+    where
+        Self: AsMutTensor,
+    {
+        GlweBody {
+            tensor: self.as_mut_tensor().get_sub_mut(..),
+        }
+    }
+
+    /// Expands the seeded ciphertext into a regular ciphertext, by regenerating its mask from the
+    /// seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, GlweSeededCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let seeded =
+    ///     GlweSeededCiphertext::allocate(9 as u32, PolynomialSize(10), GlweDimension(99), 42);
+    /// let mut glwe = GlweCiphertext::allocate(0 as u32, PolynomialSize(10), seeded.size());
+    /// seeded.expand_into(&mut glwe);
+    /// assert!(glwe.get_body().as_tensor().iter().all(|a| *a == 9));
+    /// let mut other = GlweCiphertext::allocate(0 as u32, PolynomialSize(10), seeded.size());
+    /// seeded.expand_into(&mut other);
+    /// assert_eq!(glwe, other);
+    /// ```
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut GlweCiphertext<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.polynomial_size().0 => self.polynomial_size().0);
+        ck_dim_eq!(output.mask_size().0 => self.mask_size().0);
+        let (mut output_body, mut output_mask) = output.get_mut_body_and_mask();
+        let mut generator = RandomGenerator::new(Some(self.seed));
+        generator.fill_tensor_with_random_uniform(&mut output_mask);
+        output_body.as_mut_tensor().fill_with_copy(self.as_tensor());
+    }
+}
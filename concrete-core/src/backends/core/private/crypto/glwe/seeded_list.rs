@@ -0,0 +1,337 @@
+use serde::{Deserialize, Serialize};
+
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{GlweBody, GlweList};
+use concrete_commons::parameters::{CiphertextCount, GlweDimension, GlweSize, PolynomialSize};
+
+/// A list of seeded ciphertexts encoded with the GLWE scheme.
+///
+/// Only the bodies of the ciphertexts are stored, along with one seed per ciphertext. The mask of
+/// each ciphertext is sampled from a random generator seeded with its own seed, which allows to
+/// expand the ciphertexts independently of each other.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GlweSeededList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) glwe_dimension: GlweDimension,
+    pub(crate) poly_size: PolynomialSize,
+    pub(crate) seeds: Vec<u128>,
+}
+
+tensor_traits!(GlweSeededList);
+
+impl<Scalar> GlweSeededList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates storage for an owned [`GlweSeededList`], with one ciphertext for each of the
+    /// `seeds`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(30));
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(10));
+    /// assert_eq!(list.glwe_size(), GlweSize(21));
+    /// assert_eq!(list.glwe_dimension(), GlweDimension(20));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        seeds: Vec<u128>,
+    ) -> Self {
+        GlweSeededList {
+            tensor: Tensor::from_container(vec![value; poly_size.0 * seeds.len()]),
+            glwe_dimension,
+            poly_size,
+            seeds,
+        }
+    }
+}
+
+impl<Cont> GlweSeededList<Cont> {
+    /// Creates a list from a container of body coefficients and the seeds of the ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::from_container(
+    ///     vec![0 as u8; 10 * 30],
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     vec![42; 30],
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(30));
+    /// assert_eq!(list.seeds(), &[42; 30]);
+    /// ```
+    pub fn from_container(
+        cont: Cont,
+        poly_size: PolynomialSize,
+        glwe_dimension: GlweDimension,
+        seeds: Vec<u128>,
+    ) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_div!(tensor.len() => poly_size.0);
+        ck_dim_eq!(tensor.len() / poly_size.0 => seeds.len());
+        GlweSeededList {
+            tensor,
+            glwe_dimension,
+            poly_size,
+            seeds,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// assert_eq!(list.ciphertext_count(), CiphertextCount(30));
+    /// ```
+    pub fn ciphertext_count(&self) -> CiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.poly_size.0);
+        CiphertextCount(self.as_tensor().len() / self.poly_size.0)
+    }
+
+    /// Returns the size of the expanded glwe ciphertexts contained in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// assert_eq!(list.glwe_size(), GlweSize(21));
+    /// ```
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_dimension.to_glwe_size()
+    }
+
+    /// Returns the number of coefficients of the polynomials used for the list ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// assert_eq!(list.polynomial_size(), PolynomialSize(10));
+    /// ```
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Returns the number of masks of the expanded ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// assert_eq!(list.glwe_dimension(), GlweDimension(20));
+    /// ```
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_dimension
+    }
+
+    /// Returns the seeds used to generate the masks of the ciphertexts, in the order of the
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list =
+    ///     GlweSeededList::allocate(0 as u8, PolynomialSize(10), GlweDimension(20), vec![4, 2]);
+    /// assert_eq!(list.seeds(), &[4, 2]);
+    /// ```
+    pub fn seeds(&self) -> &[u128] {
+        &self.seeds
+    }
+
+    /// Returns an iterator over the bodies of the ciphertexts borrowed from the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// let list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// for body in list.body_iter() {
+    ///     assert_eq!(body.as_polynomial().polynomial_size(), PolynomialSize(10));
+    /// }
+    /// assert_eq!(list.body_iter().count(), 30);
+    /// ```
+    pub fn body_iter(&self) -> impl Iterator<Item = GlweBody<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.poly_size.0);
+        self.as_tensor()
+            .subtensor_iter(self.poly_size.0)
+            .map(|tensor| GlweBody { tensor })
+    }
+
+    /// Returns an iterator over the bodies of the ciphertexts mutably borrowed from the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut list = GlweSeededList::allocate(
+    ///     0 as u8,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// for mut body in list.body_iter_mut() {
+    ///     body.as_mut_tensor().fill_with_element(9);
+    /// }
+    /// for body in list.body_iter() {
+    ///     assert!(body.as_tensor().iter().all(|a| *a == 9));
+    /// }
+    /// assert_eq!(list.body_iter_mut().count(), 30);
+    /// ```
+    pub fn body_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = GlweBody<&mut [<Self as AsMutTensor>::Element]>>
+    where
+        Self: AsMutTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.poly_size.0);
+        let poly_size = self.poly_size.0;
+        self.as_mut_tensor()
+            .subtensor_iter_mut(poly_size)
+            .map(|tensor| GlweBody { tensor })
+    }
+
+    /// Returns an iterator over the bodies of the ciphertexts mutably borrowed from the list,
+    /// along with their seeds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::GlweSeededList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut list =
+    ///     GlweSeededList::allocate(0 as u8, PolynomialSize(10), GlweDimension(20), vec![4, 2]);
+    /// for (mut body, seed) in list.seeded_body_iter_mut() {
+    ///     body.as_mut_tensor().fill_with_element(seed as u8);
+    /// }
+    /// let mut bodies = list.body_iter();
+    /// assert!(bodies.next().unwrap().as_tensor().iter().all(|a| *a == 4));
+    /// assert!(bodies.next().unwrap().as_tensor().iter().all(|a| *a == 2));
+    /// ```
+    pub fn seeded_body_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (GlweBody<&mut [<Tensor<Cont> as AsMutSlice>::Element]>, u128)>
+    where
+        Tensor<Cont>: AsMutSlice,
+    {
+        ck_dim_div!(self.tensor.len() => self.poly_size.0);
+        self.tensor
+            .subtensor_iter_mut(self.poly_size.0)
+            .map(|tensor| GlweBody { tensor })
+            .zip(self.seeds.iter().copied())
+    }
+
+    /// Expands the seeded list into a regular list, by regenerating the mask of each ciphertext
+    /// from its seed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::{GlweList, GlweSeededList};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let seeded = GlweSeededList::allocate(
+    ///     9 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     (0..30).collect(),
+    /// );
+    /// let mut list = GlweList::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(10),
+    ///     GlweDimension(20),
+    ///     CiphertextCount(30),
+    /// );
+    /// seeded.expand_into(&mut list);
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     assert!(ciphertext.get_body().as_tensor().iter().all(|a| *a == 9));
+    /// }
+    /// ```
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut GlweList<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.polynomial_size().0 => self.polynomial_size().0);
+        ck_dim_eq!(output.glwe_dimension().0 => self.glwe_dimension().0);
+        ck_dim_eq!(output.ciphertext_count().0 => self.ciphertext_count().0);
+        let iterator = output
+            .ciphertext_iter_mut()
+            .zip(self.body_iter().zip(self.seeds.iter()));
+        for (mut output_ct, (body, seed)) in iterator {
+            let (mut output_body, mut output_mask) = output_ct.get_mut_body_and_mask();
+            let mut generator = RandomGenerator::new(Some(*seed));
+            generator.fill_tensor_with_random_uniform(&mut output_mask);
+            output_body.as_mut_tensor().fill_with_copy(body.as_tensor());
+        }
+    }
+}
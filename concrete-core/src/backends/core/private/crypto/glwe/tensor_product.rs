@@ -0,0 +1,221 @@
+use concrete_commons::numeric::CastFrom;
+use concrete_commons::parameters::{GlweSize, PolynomialCount, PolynomialSize};
+
+use crate::backends::core::private::math::fft::{AlignedVec, Complex64, Fft, FourierPolynomial};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor, AsRefTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::GlweCiphertext;
+
+// The coefficients of the polynomials are split in limbs of this many bits before being
+// multiplied in the fourier domain, so that the products of limbs stay small enough to be
+// computed exactly with double precision floating point numbers.
+const LIMB_BITS: usize = 16;
+
+/// Returns the number of polynomials in the tensor product of two GLWE ciphertexts of size
+/// `glwe_size`.
+///
+/// # Example
+///
+/// ```
+/// use concrete_commons::parameters::{GlweSize, PolynomialCount};
+/// use concrete_core::backends::core::private::crypto::glwe::tensor_product_polynomial_count;
+/// assert_eq!(tensor_product_polynomial_count(GlweSize(2)), PolynomialCount(3));
+/// assert_eq!(tensor_product_polynomial_count(GlweSize(3)), PolynomialCount(6));
+/// ```
+pub fn tensor_product_polynomial_count(glwe_size: GlweSize) -> PolynomialCount {
+    PolynomialCount(glwe_size.0 * (glwe_size.0 + 1) / 2)
+}
+
+impl<Cont> GlweCiphertext<Cont> {
+    /// Computes the tensor product of the current ciphertext with another one, divided by
+    /// `delta`, and writes the result in the `output` polynomial list.
+    ///
+    /// If the current ciphertext $(A_1, \dots, A_k, B)$ and the `other` ciphertext
+    /// $(A'_1, \dots, A'_k, B')$ encrypt $\Delta_1 M$ and $\Delta_2 M'$ under a secret key
+    /// $(S_1, \dots, S_k)$, the output contains, with every product computed over the
+    /// integers before being rounded:
+    ///
+    /// + The $\frac{k(k+1)}{2}$ polynomials $\left\lfloor\frac{A_iA'_j + A_jA'_i}{\Delta}
+    ///   \right\rceil$ associated with the products $S_iS_j$, for $i \leq j$ in lexicographic
+    ///   order (the sum being replaced by $A_iA'_i$ when $i = j$).
+    /// + The $k$ polynomials $\left\lfloor\frac{A_iB' + BA'_i}{\Delta}\right\rceil$ associated
+    ///   with the $S_i$.
+    /// + The polynomial $\left\lfloor\frac{BB'}{\Delta}\right\rceil$.
+    ///
+    /// Those polynomials form an encryption of $\frac{\Delta_1\Delta_2}{\Delta}MM'$ under the
+    /// quadratic secret key $(S_iS_j)_{i \leq j}$, which can be brought back to a regular GLWE
+    /// ciphertext with a [`GlweRelinearizationKey`](`super::GlweRelinearizationKey`).
+    ///
+    /// The coefficients are lifted to signed integers, and split in limbs of 16 bits whose
+    /// products are computed with the fft, so that the tensor product is exact. The scaling
+    /// factor `delta` must be a power of two, usually the smallest of $\Delta_1$ and $\Delta_2$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{GlweSize, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     tensor_product_polynomial_count, GlweCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::polynomial::PolynomialList;
+    /// use concrete_core::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor};
+    /// let mut lhs = GlweCiphertext::allocate(0u64, PolynomialSize(256), GlweSize(3));
+    /// let mut rhs = GlweCiphertext::allocate(0u64, PolynomialSize(256), GlweSize(3));
+    /// // We trivially encrypt 3 * 2^40 and 5 * 2^40 in the constant coefficients.
+    /// *lhs.get_mut_body().as_mut_tensor().get_element_mut(0) = 3 << 40;
+    /// *rhs.get_mut_body().as_mut_tensor().get_element_mut(0) = 5 << 40;
+    /// let mut output = PolynomialList::allocate(
+    ///     0u64,
+    ///     tensor_product_polynomial_count(GlweSize(3)),
+    ///     PolynomialSize(256),
+    /// );
+    /// lhs.tensor_product(&rhs, &mut output, 1 << 40);
+    /// let body = output.get_polynomial(5);
+    /// assert_eq!(*body.as_tensor().first(), 15 << 40);
+    /// ```
+    pub fn tensor_product<OtherCont, OutCont, Scalar>(
+        &self,
+        other: &GlweCiphertext<OtherCont>,
+        output: &mut PolynomialList<OutCont>,
+        delta: Scalar,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweCiphertext<OtherCont>: AsRefTensor<Element = Scalar>,
+        PolynomialList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + CastFrom<u128>,
+        u128: CastFrom<Scalar>,
+    {
+        ck_dim_eq!(self.size().0 => other.size().0);
+        ck_dim_eq!(
+            self.polynomial_size().0 =>
+            other.polynomial_size().0,
+            output.polynomial_size().0
+        );
+        ck_dim_eq!(output.polynomial_count().0 => tensor_product_polynomial_count(self.size()).0);
+        let delta = u128::cast_from(delta);
+        assert!(
+            delta.is_power_of_two(),
+            "The tensor product scaling factor must be a power of two."
+        );
+
+        let poly_size = self.polynomial_size();
+        let limb_count = Scalar::BITS / LIMB_BITS;
+        let fft = Fft::new(poly_size);
+        let lhs_limbs = fourier_limbs::<&[Scalar], _>(&fft, &self.as_polynomial_list(), limb_count);
+        let rhs_limbs =
+            fourier_limbs::<&[Scalar], _>(&fft, &other.as_polynomial_list(), limb_count);
+
+        // We list, for each output polynomial, the pairs of input polynomials whose products
+        // must be summed, the body being the last polynomial of the ciphertexts.
+        let mask_size = self.mask_size().0;
+        let mut product_pairs: Vec<Vec<(usize, usize)>> = Vec::new();
+        for i in 0..mask_size {
+            product_pairs.push(vec![(i, i)]);
+            for j in i + 1..mask_size {
+                product_pairs.push(vec![(i, j), (j, i)]);
+            }
+        }
+        for i in 0..mask_size {
+            product_pairs.push(vec![(i, mask_size), (mask_size, i)]);
+        }
+        product_pairs.push(vec![(mask_size, mask_size)]);
+
+        let mut fourier_product = FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+        let mut limb_product = Polynomial::allocate(0u128, poly_size);
+        let mut product = Polynomial::allocate(0u128, poly_size);
+        for (pairs, mut output_poly) in product_pairs.iter().zip(output.polynomial_iter_mut()) {
+            // We accumulate the products of the limbs, grouped by weight, in a 128 bits
+            // polynomial. Wrapping around 2^128 is fine, since only the product modulo
+            // delta * 2^BITS is needed to compute the output.
+            product.coefficient_iter_mut().for_each(|coef| *coef = 0);
+            for weight in 0..2 * limb_count - 1 {
+                fourier_product
+                    .coefficient_iter_mut()
+                    .for_each(|coef| *coef = Complex64::new(0., 0.));
+                for (lhs_index, rhs_index) in pairs.iter() {
+                    let lhs_range =
+                        weight.saturating_sub(limb_count - 1)..=weight.min(limb_count - 1);
+                    for lhs_limb in lhs_range {
+                        fourier_product.update_with_multiply_accumulate(
+                            &lhs_limbs[*lhs_index][lhs_limb],
+                            &rhs_limbs[*rhs_index][weight - lhs_limb],
+                        );
+                    }
+                }
+                limb_product
+                    .coefficient_iter_mut()
+                    .for_each(|coef| *coef = 0);
+                fft.add_backward_as_integer(&mut limb_product, &mut fourier_product);
+                for (coef, limb_coef) in product
+                    .coefficient_iter_mut()
+                    .zip(limb_product.coefficient_iter())
+                {
+                    *coef = coef.wrapping_add(limb_coef.wrapping_shl((weight * LIMB_BITS) as u32));
+                }
+            }
+
+            // We divide by delta with rounding.
+            let delta_log = delta.trailing_zeros();
+            for (output_coef, coef) in output_poly
+                .coefficient_iter_mut()
+                .zip(product.coefficient_iter())
+            {
+                *output_coef = Scalar::cast_from(coef.wrapping_add(delta >> 1) >> delta_log);
+            }
+        }
+    }
+}
+
+// Splits the polynomials of a list in balanced limbs of `LIMB_BITS` bits, and returns the fourier
+// transforms of those limbs, the least significant limb coming first.
+fn fourier_limbs<Cont, Scalar>(
+    fft: &Fft,
+    polynomials: &PolynomialList<Cont>,
+    limb_count: usize,
+) -> Vec<Vec<FourierPolynomial<AlignedVec<Complex64>>>>
+where
+    PolynomialList<Cont>: AsRefTensor<Element = Scalar>,
+    Scalar: UnsignedTorus + CastFrom<u128>,
+    u128: CastFrom<Scalar>,
+{
+    let poly_size: PolynomialSize = polynomials.polynomial_size();
+    let mut limbs: Vec<Polynomial<Vec<Scalar>>> = (0..limb_count)
+        .map(|_| Polynomial::allocate(Scalar::ZERO, poly_size))
+        .collect();
+    polynomials
+        .polynomial_iter()
+        .map(|polynomial| {
+            for (degree, coef) in polynomial.coefficient_iter().enumerate() {
+                // We lift the coefficient to a signed integer in [-2^(BITS-1), 2^(BITS-1)).
+                let mut value = u128::cast_from(*coef) as i128;
+                if value >= 1 << (Scalar::BITS - 1) {
+                    value -= 1 << Scalar::BITS;
+                }
+                for (index, limb) in limbs.iter_mut().enumerate() {
+                    // The last limb takes what remains, which is at most 2^(LIMB_BITS-1) in
+                    // absolute value.
+                    let digit = if index == limb_count - 1 {
+                        value
+                    } else {
+                        let half_base = 1 << (LIMB_BITS - 1);
+                        ((value + half_base) & ((1 << LIMB_BITS) - 1)) - half_base
+                    };
+                    value = (value - digit) >> LIMB_BITS;
+                    *limb.as_mut_tensor().get_element_mut(degree) =
+                        Scalar::cast_from(digit as u128);
+                }
+            }
+            limbs
+                .iter()
+                .map(|limb| {
+                    let mut fourier_limb =
+                        FourierPolynomial::allocate(Complex64::new(0., 0.), poly_size);
+                    fft.forward_as_integer(&mut fourier_limb, limb);
+                    fourier_limb
+                })
+                .collect()
+        })
+        .collect()
+}
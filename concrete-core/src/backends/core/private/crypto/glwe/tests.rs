@@ -1,14 +1,30 @@
+use concrete_commons::dispersion::{DispersionParameter, LogStandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::numeric::CastFrom;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PlaintextCount,
+    PolynomialSize,
+};
+use concrete_npe as npe;
+
 use crate::backends::core::private::crypto::encoding::PlaintextList;
-use crate::backends::core::private::crypto::glwe::GlweList;
+use crate::backends::core::private::crypto::glwe::{
+    tensor_product_polynomial_count, GlweCiphertext, GlweList, GlweRelinearizationKey,
+    GlweSeededList, PackingKeyswitchKey,
+};
+use crate::backends::core::private::crypto::lwe::LweList;
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::backends::core::private::crypto::secret::GlweSecretKey;
-use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::crypto::secret::{GlweSecretKey, LweSecretKey};
+use crate::backends::core::private::math::polynomial::{Polynomial, PolynomialList};
+use crate::backends::core::private::math::random::{
+    RandomGenerable, RandomGenerator, UniformLsb, UniformMsb,
+};
+use crate::backends::core::private::math::tensor::AsRefTensor;
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::test_tools;
 use crate::backends::core::private::test_tools::assert_delta_std_dev;
-use concrete_commons::dispersion::LogStandardDev;
 
 fn test_glwe<T: UnsignedTorus>() {
     // random settings
@@ -56,3 +72,282 @@ fn test_glwe_encrypt_decrypt_u32() {
 fn test_glwe_encrypt_decrypt_u64() {
     test_glwe::<u64>();
 }
+
+fn test_seeded_glwe<T: UnsignedTorus>() {
+    // random settings
+    let nb_ct = test_tools::random_ciphertext_count(200);
+    let dimension = test_tools::random_glwe_dimension(200);
+    let polynomial_size = test_tools::random_polynomial_size(200);
+    let noise_parameter = LogStandardDev::from_log_standard_dev(-20.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generates a secret key
+    let sk = GlweSecretKey::generate_binary(dimension, polynomial_size, &mut secret_generator);
+
+    // generates random plaintexts
+    let plaintexts = PlaintextList::from_tensor(
+        random_generator.random_uniform_tensor(nb_ct.0 * polynomial_size.0),
+    );
+
+    // encrypts in a seeded list
+    let seeds = (0..nb_ct.0)
+        .map(|_| random_generator.random_uniform())
+        .collect();
+    let mut seeded_ciphertext =
+        GlweSeededList::allocate(T::ZERO, polynomial_size, dimension, seeds);
+    sk.encrypt_seeded_glwe_list(
+        &mut seeded_ciphertext,
+        &plaintexts,
+        noise_parameter,
+        &mut encryption_generator,
+    );
+
+    // expands the seeded list
+    let mut ciphertext = GlweList::allocate(T::ZERO, polynomial_size, dimension, nb_ct);
+    seeded_ciphertext.expand_into(&mut ciphertext);
+
+    // decrypts
+    let mut decryptions = PlaintextList::from_tensor(
+        random_generator.random_uniform_tensor(nb_ct.0 * polynomial_size.0),
+    );
+    sk.decrypt_glwe_list(&mut decryptions, &ciphertext);
+
+    // test
+    assert_delta_std_dev(&plaintexts, &decryptions, noise_parameter);
+}
+
+#[test]
+fn test_seeded_glwe_encrypt_decrypt_u32() {
+    test_seeded_glwe::<u32>();
+}
+
+#[test]
+fn test_seeded_glwe_encrypt_decrypt_u64() {
+    test_seeded_glwe::<u64>();
+}
+
+fn test_packing_keyswitch<T: UnsignedTorus + RandomGenerable<UniformMsb>>() {
+    //! create a packing KSK and pack some LWE samples in a GLWE ciphertext
+    //! warning: not a randomized test for the parameters
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // fix a set of parameters
+    let n_bit_msg = 8; // bit precision of the plaintext
+    let nb_ct = test_tools::random_ciphertext_count(100); // number of messages to pack
+    let base_log = DecompositionBaseLog(3); // a parameter of the gadget matrix
+    let level_count = DecompositionLevelCount(8); // a parameter of the gadget matrix
+    let messages = PlaintextList::from_tensor(
+        random_generator.random_uniform_n_msb_tensor(nb_ct.0, n_bit_msg),
+    );
+    let std_input = LogStandardDev::from_log_standard_dev(-10.);
+    let std_ksk = LogStandardDev::from_log_standard_dev(-25.);
+
+    // set parameters related to the input LWE key and the output GLWE key
+    let lwe_dimension = LweDimension(600);
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(512);
+    let sk_before = LweSecretKey::generate_binary(lwe_dimension, &mut secret_generator);
+    let sk_after =
+        GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+
+    // packing keyswitch key generation
+    let mut pksk = PackingKeyswitchKey::allocate(
+        T::ZERO,
+        level_count,
+        base_log,
+        lwe_dimension,
+        glwe_dimension,
+        polynomial_size,
+    );
+    pksk.fill_with_packing_keyswitch_key(&sk_before, &sk_after, std_ksk, &mut encryption_generator);
+
+    // encrypts with the input key our messages
+    let mut ciphertexts = LweList::allocate(T::ZERO, lwe_dimension.to_lwe_size(), nb_ct);
+    sk_before.encrypt_lwe_list(
+        &mut ciphertexts,
+        &messages,
+        std_input,
+        &mut encryption_generator,
+    );
+
+    // packs the ciphertexts
+    let mut packed =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    pksk.packing_keyswitch(&mut packed, &ciphertexts);
+
+    // decryption with the output key
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk_after.decrypt_glwe(&mut decryptions, &packed);
+    let packed_messages = PlaintextList::from_container(
+        decryptions
+            .as_tensor()
+            .iter()
+            .take(nb_ct.0)
+            .copied()
+            .collect::<Vec<_>>(),
+    );
+
+    // every coefficient gets the constant term noise of its own ciphertext, and the non-constant
+    // terms noise of the other ones
+    let constant_variance = npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+        T,
+        _,
+        _,
+        BinaryKeyKind,
+    >(lwe_dimension, std_input, std_ksk, base_log, level_count);
+    let non_constant_variance = npe::estimate_keyswitch_noise_lwe_to_glwe_with_non_constant_terms::<
+        T,
+        _,
+    >(lwe_dimension, std_ksk, base_log, level_count);
+    let output_variance = Variance(
+        constant_variance.get_variance()
+            + (nb_ct.0 - 1) as f64 * non_constant_variance.get_variance(),
+    );
+
+    assert_delta_std_dev(&messages, &packed_messages, output_variance);
+}
+
+#[test]
+fn test_packing_keyswitch_u32() {
+    test_packing_keyswitch::<u32>();
+}
+
+#[test]
+fn test_packing_keyswitch_u64() {
+    test_packing_keyswitch::<u64>();
+}
+
+fn test_glwe_multiplication<T>()
+where
+    T: UnsignedTorus + RandomGenerable<UniformLsb> + CastFrom<u128>,
+    u128: CastFrom<T>,
+{
+    //! multiply two GLWE ciphertexts and relinearize the result
+    //! warning: not a randomized test for the parameters
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // fix a set of parameters
+    let n_bit_msg = 2; // bit precision of the message coefficients
+    let delta_log = T::BITS - 4; // the scaling factor of both inputs and of the output
+    let glwe_dimension = GlweDimension(2);
+    let polynomial_size = PolynomialSize(256);
+    let (base_log, level_count, std_input, std_rlk) = if T::BITS == 32 {
+        (
+            DecompositionBaseLog(6),
+            DecompositionLevelCount(4),
+            LogStandardDev::from_log_standard_dev(-16.),
+            LogStandardDev::from_log_standard_dev(-25.),
+        )
+    } else {
+        (
+            DecompositionBaseLog(10),
+            DecompositionLevelCount(4),
+            LogStandardDev::from_log_standard_dev(-32.),
+            LogStandardDev::from_log_standard_dev(-50.),
+        )
+    };
+    let sk = GlweSecretKey::generate_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+
+    // relinearization key generation
+    let mut rlk = GlweRelinearizationKey::allocate(
+        T::ZERO,
+        glwe_dimension,
+        polynomial_size,
+        level_count,
+        base_log,
+    );
+    rlk.fill_with_relinearization_key(&sk, std_rlk, &mut encryption_generator);
+
+    // encrypts two random polynomials with small coefficients
+    let messages_1 = Polynomial::from_tensor(
+        random_generator.random_uniform_n_lsb_tensor::<T>(polynomial_size.0, n_bit_msg),
+    );
+    let messages_2 = Polynomial::from_tensor(
+        random_generator.random_uniform_n_lsb_tensor::<T>(polynomial_size.0, n_bit_msg),
+    );
+    let mut ciphertexts = Vec::new();
+    for messages in [&messages_1, &messages_2].iter() {
+        let plaintexts = PlaintextList::from_container(
+            messages
+                .coefficient_iter()
+                .map(|m| *m << delta_log)
+                .collect::<Vec<_>>(),
+        );
+        let mut ciphertext =
+            GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+        sk.encrypt_glwe(
+            &mut ciphertext,
+            &plaintexts,
+            std_input,
+            &mut encryption_generator,
+        );
+        ciphertexts.push(ciphertext);
+    }
+
+    // multiplies the ciphertexts
+    let mut tensor_product = PolynomialList::allocate(
+        T::ZERO,
+        tensor_product_polynomial_count(glwe_dimension.to_glwe_size()),
+        polynomial_size,
+    );
+    ciphertexts[0].tensor_product(&ciphertexts[1], &mut tensor_product, T::ONE << delta_log);
+    let mut product =
+        GlweCiphertext::allocate(T::ZERO, polynomial_size, glwe_dimension.to_glwe_size());
+    rlk.relinearize(&mut product, &tensor_product);
+
+    // decryption
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(polynomial_size.0));
+    sk.decrypt_glwe(&mut decryptions, &product);
+
+    // the expected plaintext is the product of the messages, with the same scaling factor
+    let mut expected = Polynomial::allocate(T::ZERO, polynomial_size);
+    expected.fill_with_wrapping_mul(&messages_1, &messages_2);
+    let expected = PlaintextList::from_container(
+        expected
+            .coefficient_iter()
+            .map(|m| *m << delta_log)
+            .collect::<Vec<_>>(),
+    );
+
+    let delta = (1u128 << delta_log) as f64;
+    let max_msg = ((1 << n_bit_msg) - 1) as f64;
+    // the tensor product and relinearization estimates are modular variances
+    let tensor_product_variance = npe::estimate_tensor_product_noise::<T, _, _, BinaryKeyKind>(
+        polynomial_size,
+        glwe_dimension,
+        std_input,
+        std_input,
+        delta,
+        delta,
+        max_msg,
+        max_msg,
+    );
+    let relinearization_variance = npe::estimate_relinearization_noise::<T, _, BinaryKeyKind>(
+        polynomial_size,
+        glwe_dimension,
+        std_rlk,
+        base_log,
+        level_count,
+    );
+    let output_variance = Variance::from_modular_variance::<T>(
+        tensor_product_variance.get_variance() + relinearization_variance.get_variance(),
+    );
+
+    assert_delta_std_dev(&expected, &decryptions, output_variance);
+}
+
+#[test]
+fn test_glwe_multiplication_u32() {
+    test_glwe_multiplication::<u32>();
+}
+
+#[test]
+fn test_glwe_multiplication_u64() {
+    test_glwe_multiplication::<u64>();
+}
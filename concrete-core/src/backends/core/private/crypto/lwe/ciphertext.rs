@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use crate::backends::core::private::crypto::encoding::{Cleartext, CleartextList, Plaintext};
 use crate::backends::core::private::crypto::secret::LweSecretKey;
 use crate::backends::core::private::math::tensor::{
-    tensor_traits, AsMutTensor, AsRefTensor, Tensor,
+    ck_dim_eq, tensor_traits, AsMutTensor, AsRefTensor, Tensor,
 };
 
 use super::LweList;
@@ -600,6 +600,72 @@ impl<Cont> LweCiphertext<Cont> {
     {
         glwe.fill_lwe_with_sample_extraction(self, n_th);
     }
+
+    /// Fills the ciphertext with the expansion of a ciphertext encrypted under a key which is
+    /// expanded to a binary key, with `width` pairs of binary coefficients per coefficient.
+    ///
+    /// Every element $a\_i$ of the input mask is mapped to the elements
+    /// $(a\_i, -a\_i, 2a\_i, -2a\_i, \cdots, 2^{width-1}a\_i, -2^{width-1}a\_i)$, and the body is
+    /// copied as is. The output ciphertext is a valid ciphertext under the expanded key, and must
+    /// thus have a mask `2 * width` times larger than the one of the input ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::LweSecretKey;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    ///
+    /// let secret_key = LweSecretKey::generate_ternary(LweDimension(256), &mut secret_generator);
+    /// let expanded_key = secret_key.binary_expansion();
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    /// let plaintext = Plaintext(1_u32 << 30);
+    /// let mut ciphertext = LweCiphertext::allocate(0_u32, LweSize(257));
+    /// secret_key.encrypt_lwe(&mut ciphertext, &plaintext, noise, &mut encryption_generator);
+    ///
+    /// let mut expanded = LweCiphertext::allocate(0_u32, LweSize(513));
+    /// expanded.fill_with_mask_expansion(&ciphertext, 1);
+    ///
+    /// let mut decrypted = Plaintext(0_u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    /// let mut expanded_decrypted = Plaintext(0_u32);
+    /// expanded_key.decrypt_lwe(&mut expanded_decrypted, &expanded);
+    /// assert_eq!(decrypted, expanded_decrypted);
+    /// ```
+    pub fn fill_with_mask_expansion<InputCont, Scalar>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+        width: usize,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.lwe_size().0 - 1 => 2 * width * (input.lwe_size().0 - 1));
+        let (output_body, mut output_mask) = self.get_mut_body_and_mask();
+        let (input_body, input_mask) = input.get_body_and_mask();
+        output_body.0 = input_body.0;
+        for (mut output_chunk, input_element) in output_mask
+            .as_mut_tensor()
+            .subtensor_iter_mut(2 * width)
+            .zip(input_mask.mask_element_iter())
+        {
+            for (bit, mut pair) in output_chunk.subtensor_iter_mut(2).enumerate() {
+                let shifted = input_element.wrapping_shl(bit as u32);
+                *pair.get_element_mut(0) = shifted;
+                *pair.get_element_mut(1) = shifted.wrapping_neg();
+            }
+        }
+    }
 }
 
 /// The mask of an LWE encrypted ciphertext.
@@ -712,7 +778,7 @@ impl<Cont> LweMask<Cont> {
         self.as_tensor().fold_with_one(
             key.as_tensor(),
             <Scalar as Numeric>::ZERO,
-            |ac, s_i, o_i| ac.wrapping_add(*s_i * *o_i),
+            |ac, s_i, o_i| ac.wrapping_add(s_i.wrapping_mul(*o_i)),
         )
     }
 }
@@ -0,0 +1,415 @@
+use serde::{Deserialize, Serialize};
+
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+use concrete_commons::numeric::{CastFrom, UnsignedInteger};
+use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweDimension, LweSize};
+
+use super::{LweCiphertext, LweList};
+
+/// An LWE ciphertext whose coefficients were switched to a smaller power of two modulus.
+///
+/// The coefficients are stored as integers modulo $2^m$, $m$ being the modulus log of the
+/// ciphertext, in a container whose scalar type may be smaller than the one of the original
+/// ciphertext. This allows to shrink a ciphertext before storing or sending it, at the price of
+/// the noise added by the rounding. The ciphertext can be lifted back to a regular
+/// [`LweCiphertext`] to be operated on.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LweCompressedCiphertext<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) modulus_log: CiphertextModulusLog,
+}
+
+tensor_traits!(LweCompressedCiphertext);
+
+impl<Scalar: Copy> LweCompressedCiphertext<Vec<Scalar>> {
+    /// Allocates a new compressed ciphertext whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+    /// let ct = LweCompressedCiphertext::allocate(0 as u32, LweSize(4), CiphertextModulusLog(12));
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// assert_eq!(ct.modulus_log(), CiphertextModulusLog(12));
+    /// ```
+    pub fn allocate(value: Scalar, size: LweSize, modulus_log: CiphertextModulusLog) -> Self {
+        LweCompressedCiphertext {
+            tensor: Tensor::from_container(vec![value; size.0]),
+            modulus_log,
+        }
+    }
+}
+
+impl<Cont> LweCompressedCiphertext<Cont> {
+    /// Creates a compressed ciphertext from a container of coefficients modulo $2^m$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+    /// let ct = LweCompressedCiphertext::from_container(vec![0 as u32; 4], CiphertextModulusLog(12));
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// ```
+    pub fn from_container(cont: Cont, modulus_log: CiphertextModulusLog) -> Self {
+        LweCompressedCiphertext {
+            tensor: Tensor::from_container(cont),
+            modulus_log,
+        }
+    }
+
+    /// Returns the size of the ciphertext, e.g. the size of the mask + 1 for the body.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+    /// let ct = LweCompressedCiphertext::allocate(0 as u32, LweSize(4), CiphertextModulusLog(12));
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize
+    where
+        Self: AsRefTensor,
+    {
+        LweSize(self.as_tensor().len())
+    }
+
+    /// Returns the dimension of the mask of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+    /// let ct = LweCompressedCiphertext::allocate(0 as u32, LweSize(4), CiphertextModulusLog(12));
+    /// assert_eq!(ct.lwe_dimension(), LweDimension(3));
+    /// ```
+    pub fn lwe_dimension(&self) -> LweDimension
+    where
+        Self: AsRefTensor,
+    {
+        self.lwe_size().to_lwe_dimension()
+    }
+
+    /// Returns the logarithm of the modulus of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+    /// let ct = LweCompressedCiphertext::allocate(0 as u32, LweSize(4), CiphertextModulusLog(12));
+    /// assert_eq!(ct.modulus_log(), CiphertextModulusLog(12));
+    /// ```
+    pub fn modulus_log(&self) -> CiphertextModulusLog {
+        self.modulus_log
+    }
+
+    /// Fills the current ciphertext with the modulus switch of an LWE ciphertext.
+    ///
+    /// Every coefficient $c$ of the input, defined modulo $q$, is replaced by
+    /// $\left\lfloor\frac{2^m c}{q}\right\rceil \bmod 2^m$. The modulus log must be smaller than
+    /// the precision of the input, and fit in the scalar type of the current ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     LweCiphertext, LweCompressedCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let ct = LweCiphertext::from_container(vec![3 << 60, (1 << 52) - 1, u64::MAX]);
+    /// let mut compressed =
+    ///     LweCompressedCiphertext::allocate(0 as u32, LweSize(3), CiphertextModulusLog(12));
+    /// compressed.fill_with_modulus_switch(&ct);
+    /// assert_eq!(compressed.as_tensor().as_slice(), &[3 << 8, 1, 0]);
+    /// ```
+    pub fn fill_with_modulus_switch<InputCont, Scalar, InputScalar>(
+        &mut self,
+        input: &LweCiphertext<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweCiphertext<InputCont>: AsRefTensor<Element = InputScalar>,
+        Scalar: UnsignedInteger + CastFrom<InputScalar>,
+        InputScalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.lwe_size().0 => input.lwe_size().0);
+        let modulus_log = self.modulus_log;
+        self.as_mut_tensor()
+            .fill_with_one(input.as_tensor(), |coef| switch_modulus(*coef, modulus_log));
+    }
+
+    /// Lifts the current ciphertext back to the modulus of the `output` LWE ciphertext.
+    ///
+    /// Every coefficient $c$ modulo $2^m$ is replaced by $\frac{qc}{2^m}$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     LweCiphertext, LweCompressedCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let compressed =
+    ///     LweCompressedCiphertext::from_container(vec![3 << 8, 1, 0 as u32], CiphertextModulusLog(12));
+    /// let mut ct = LweCiphertext::allocate(0 as u64, LweSize(3));
+    /// compressed.lift_into(&mut ct);
+    /// assert_eq!(ct.as_tensor().as_slice(), &[3 << 60, 1 << 52, 0]);
+    /// ```
+    pub fn lift_into<OutputCont, Scalar, OutputScalar>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = OutputScalar>,
+        Scalar: UnsignedInteger,
+        OutputScalar: UnsignedTorus + CastFrom<Scalar>,
+    {
+        ck_dim_eq!(self.lwe_size().0 => output.lwe_size().0);
+        output
+            .as_mut_tensor()
+            .fill_with_one(self.as_tensor(), |coef| {
+                lift_modulus(*coef, self.modulus_log)
+            });
+    }
+}
+
+/// A list of LWE ciphertexts whose coefficients were switched to a smaller power of two modulus.
+///
+/// See [`LweCompressedCiphertext`] for more details.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LweCompressedList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) lwe_size: LweSize,
+    pub(crate) modulus_log: CiphertextModulusLog,
+}
+
+tensor_traits!(LweCompressedList);
+
+impl<Scalar: Copy> LweCompressedList<Vec<Scalar>> {
+    /// Allocates a list of compressed ciphertexts whose coefficients are all `value`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedList;
+    /// let list = LweCompressedList::allocate(
+    ///     0 as u32,
+    ///     LweSize(10),
+    ///     CiphertextCount(20),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// assert_eq!(list.lwe_size(), LweSize(10));
+    /// assert_eq!(list.modulus_log(), CiphertextModulusLog(12));
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        lwe_size: LweSize,
+        lwe_count: CiphertextCount,
+        modulus_log: CiphertextModulusLog,
+    ) -> Self {
+        LweCompressedList {
+            tensor: Tensor::from_container(vec![value; lwe_size.0 * lwe_count.0]),
+            lwe_size,
+            modulus_log,
+        }
+    }
+}
+
+impl<Cont> LweCompressedList<Cont> {
+    /// Creates a list from a container, a lwe size and a modulus log.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedList;
+    /// let list = LweCompressedList::from_container(
+    ///     vec![0 as u32; 200],
+    ///     LweSize(10),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// ```
+    pub fn from_container(cont: Cont, lwe_size: LweSize, modulus_log: CiphertextModulusLog) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        ck_dim_div!(cont.as_slice().len() => lwe_size.0);
+        LweCompressedList {
+            tensor: Tensor::from_container(cont),
+            lwe_size,
+            modulus_log,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedList;
+    /// let list = LweCompressedList::from_container(
+    ///     vec![0 as u32; 200],
+    ///     LweSize(10),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// ```
+    pub fn count(&self) -> CiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        CiphertextCount(self.as_tensor().len() / self.lwe_size.0)
+    }
+
+    /// Returns the size of the ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedList;
+    /// let list = LweCompressedList::from_container(
+    ///     vec![0 as u32; 200],
+    ///     LweSize(10),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// assert_eq!(list.lwe_size(), LweSize(10));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the logarithm of the modulus of the ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweCompressedList;
+    /// let list = LweCompressedList::from_container(
+    ///     vec![0 as u32; 200],
+    ///     LweSize(10),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// assert_eq!(list.modulus_log(), CiphertextModulusLog(12));
+    /// ```
+    pub fn modulus_log(&self) -> CiphertextModulusLog {
+        self.modulus_log
+    }
+
+    /// Fills the current list with the modulus switches of the ciphertexts of an LWE list.
+    ///
+    /// See [`LweCompressedCiphertext::fill_with_modulus_switch`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweCompressedList, LweList};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let list = LweList::from_container(vec![3u64 << 60; 20], LweSize(10));
+    /// let mut compressed = LweCompressedList::allocate(
+    ///     0 as u32,
+    ///     LweSize(10),
+    ///     CiphertextCount(2),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// compressed.fill_with_modulus_switch(&list);
+    /// assert!(compressed.as_tensor().iter().all(|coef| *coef == 3 << 8));
+    /// ```
+    pub fn fill_with_modulus_switch<InputCont, Scalar, InputScalar>(
+        &mut self,
+        input: &LweList<InputCont>,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweList<InputCont>: AsRefTensor<Element = InputScalar>,
+        Scalar: UnsignedInteger + CastFrom<InputScalar>,
+        InputScalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.lwe_size.0 => input.lwe_size().0);
+        ck_dim_eq!(self.count().0 => input.count().0);
+        let modulus_log = self.modulus_log;
+        self.as_mut_tensor()
+            .fill_with_one(input.as_tensor(), |coef| switch_modulus(*coef, modulus_log));
+    }
+
+    /// Lifts the ciphertexts of the current list back to the modulus of the `output` LWE list.
+    ///
+    /// See [`LweCompressedCiphertext::lift_into`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, CiphertextModulusLog, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweCompressedList, LweList};
+    /// use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+    /// let compressed = LweCompressedList::from_container(
+    ///     vec![3u32 << 8; 20],
+    ///     LweSize(10),
+    ///     CiphertextModulusLog(12),
+    /// );
+    /// let mut list = LweList::allocate(0 as u64, LweSize(10), CiphertextCount(2));
+    /// compressed.lift_into(&mut list);
+    /// assert!(list.as_tensor().iter().all(|coef| *coef == 3 << 60));
+    /// ```
+    pub fn lift_into<OutputCont, Scalar, OutputScalar>(&self, output: &mut LweList<OutputCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<OutputCont>: AsMutTensor<Element = OutputScalar>,
+        Scalar: UnsignedInteger,
+        OutputScalar: UnsignedTorus + CastFrom<Scalar>,
+    {
+        ck_dim_eq!(self.lwe_size.0 => output.lwe_size().0);
+        ck_dim_eq!(self.count().0 => output.count().0);
+        output
+            .as_mut_tensor()
+            .fill_with_one(self.as_tensor(), |coef| {
+                lift_modulus(*coef, self.modulus_log)
+            });
+    }
+}
+
+// Rounds a coefficient modulo 2^BITS to the closest coefficient modulo 2^modulus_log.
+fn switch_modulus<Scalar, InputScalar>(
+    coef: InputScalar,
+    modulus_log: CiphertextModulusLog,
+) -> Scalar
+where
+    Scalar: UnsignedInteger + CastFrom<InputScalar>,
+    InputScalar: UnsignedTorus,
+{
+    assert!(
+        modulus_log.0 > 0 && modulus_log.0 < InputScalar::BITS && modulus_log.0 <= Scalar::BITS,
+        "The modulus log must be non-zero, smaller than the input precision, and fit in the \
+        output scalar type."
+    );
+    // We keep one more bit than needed, which is used to round to the closest value.
+    let rounded = ((coef >> (InputScalar::BITS - modulus_log.0 - 1)) + InputScalar::ONE) >> 1;
+    let mask = (InputScalar::ONE << modulus_log.0) - InputScalar::ONE;
+    Scalar::cast_from(rounded & mask)
+}
+
+// Maps a coefficient modulo 2^modulus_log back to a coefficient modulo 2^BITS.
+fn lift_modulus<Scalar, OutputScalar>(
+    coef: Scalar,
+    modulus_log: CiphertextModulusLog,
+) -> OutputScalar
+where
+    Scalar: UnsignedInteger,
+    OutputScalar: UnsignedTorus + CastFrom<Scalar>,
+{
+    OutputScalar::cast_from(coef) << (OutputScalar::BITS - modulus_log.0)
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 use concrete_commons::dispersion::DispersionParameter;
-use concrete_commons::key_kinds::BinaryKeyKind;
+use concrete_commons::key_kinds::KeyKind;
 use concrete_commons::numeric::SignedInteger;
 use concrete_commons::parameters::{
     CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension, LweSize,
@@ -20,6 +20,9 @@ use crate::backends::core::private::math::torus::UnsignedTorus;
 
 use super::{LweCiphertext, LweList};
 
+#[cfg(feature = "multithread")]
+use rayon::{iter::IndexedParallelIterator, prelude::*};
+
 /// An Lwe Keyswithing key.
 ///
 /// A keyswitching key allows to change the key of a cipher text. Lets assume the following
@@ -327,16 +330,18 @@ impl<Cont> LweKeyswitchKey<Cont> {
     ///
     /// assert!(!ksk.as_tensor().iter().all(|a| *a == 0));
     /// ```
-    pub fn fill_with_keyswitch_key<InKeyCont, OutKeyCont, Scalar>(
+    pub fn fill_with_keyswitch_key<InKeyKind, OutKeyKind, InKeyCont, OutKeyCont, Scalar>(
         &mut self,
-        before_key: &LweSecretKey<BinaryKeyKind, InKeyCont>,
-        after_key: &LweSecretKey<BinaryKeyKind, OutKeyCont>,
+        before_key: &LweSecretKey<InKeyKind, InKeyCont>,
+        after_key: &LweSecretKey<OutKeyKind, OutKeyCont>,
         noise_parameters: impl DispersionParameter,
         generator: &mut EncryptionRandomGenerator,
     ) where
         Self: AsMutTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
-        LweSecretKey<BinaryKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
+        InKeyKind: KeyKind,
+        OutKeyKind: KeyKind,
+        LweSecretKey<InKeyKind, InKeyCont>: AsRefTensor<Element = Scalar>,
+        LweSecretKey<OutKeyKind, OutKeyCont>: AsRefTensor<Element = Scalar>,
         Scalar: UnsignedTorus,
     {
         // We instantiate a buffer
@@ -556,6 +561,8 @@ impl<Cont> LweKeyswitchKey<Cont> {
         }
     }
 
+    /// Keyswitches each ciphertext of an input LWE list, and writes the results in the output LWE
+    /// list.
     pub fn keyswitch_list<InCont, OutCont, Scalar>(
         &self,
         output: &mut LweList<OutCont>,
@@ -574,6 +581,39 @@ impl<Cont> LweKeyswitchKey<Cont> {
             self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
         }
     }
+
+    /// Keyswitches each ciphertext of an input LWE list, and writes the results in the output LWE
+    /// list, using all the available threads.
+    ///
+    /// # Note
+    ///
+    /// This method uses _rayon_ internally, and is hidden behind the "multithread" feature
+    /// gate.
+    #[cfg(feature = "multithread")]
+    pub fn par_keyswitch_list<InCont, OutCont, Scalar>(
+        &self,
+        output: &mut LweList<OutCont>,
+        input: &LweList<InCont>,
+    ) where
+        Self: AsRefTensor<Element = Scalar> + Sync,
+        LweList<InCont>: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus + Sync + Send,
+    {
+        ck_dim_eq!(input.count().0 => output.count().0);
+        let input_size = input.lwe_size().0;
+        let output_size = output.lwe_size().0;
+        output
+            .as_mut_tensor()
+            .par_subtensor_iter_mut(output_size)
+            .zip(input.as_tensor().par_subtensor_iter(input_size))
+            .for_each(|(output_cipher, input_cipher)| {
+                let mut output_cipher =
+                    LweCiphertext::from_container(output_cipher.into_container());
+                let input_cipher = LweCiphertext::from_container(input_cipher.into_container());
+                self.keyswitch_ciphertext(&mut output_cipher, &input_cipher);
+            });
+    }
 }
 
 /// The encryption of a single bit of the output key.
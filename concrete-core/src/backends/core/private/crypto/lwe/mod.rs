@@ -1,11 +1,19 @@
 //! LWE encryption scheme.
 pub use ciphertext::*;
+pub use compressed::*;
 pub use keyswitch::*;
 pub use list::*;
+pub use public_key::*;
+pub use seeded_ciphertext::*;
+pub use seeded_list::*;
 
 #[cfg(test)]
 mod tests;
 
 mod ciphertext;
+mod compressed;
 mod keyswitch;
 mod list;
+mod public_key;
+mod seeded_ciphertext;
+mod seeded_list;
@@ -0,0 +1,289 @@
+use serde::{Deserialize, Serialize};
+
+use concrete_commons::dispersion::DispersionParameter;
+use concrete_commons::key_kinds::KeyKind;
+use concrete_commons::parameters::{LwePublicKeyZeroEncryptionCount, LweSize, PlaintextCount};
+
+use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
+use crate::backends::core::private::crypto::secret::generators::{
+    EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::backends::core::private::crypto::secret::LweSecretKey;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_div, ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweCiphertext, LweList};
+
+/// An LWE public key.
+///
+/// A public key is made of $m$ encryptions of zero $(\mathbf{a}_i, b_i)$ under a secret key
+/// $\mathbf{s}$. Anyone holding it can encrypt a plaintext $\mu$ under $\mathbf{s}$, without
+/// knowing $\mathbf{s}$, by summing a random subset of those encryptions and adding $\mu$ to the
+/// body of the result:
+///
+/// $$
+/// \left(\sum_{i} r_i\mathbf{a}_i, \sum_{i} r_ib_i + \mu\right)
+/// \textrm{ with } r_i \in \\{0, 1\\}.
+/// $$
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LwePublicKey<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) lwe_size: LweSize,
+}
+
+tensor_traits!(LwePublicKey);
+
+impl<Scalar> LwePublicKey<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a public key whose masks and bodies are all `value`.
+    ///
+    /// # Note
+    ///
+    /// This function does *not* generate a public key, but merely allocates a container of the
+    /// right size. See [`LwePublicKey::fill_with_new_key`] to fill the container with a proper
+    /// public key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LwePublicKeyZeroEncryptionCount, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LwePublicKey;
+    /// let public_key =
+    ///     LwePublicKey::allocate(0 as u8, LweSize(10), LwePublicKeyZeroEncryptionCount(20));
+    /// assert_eq!(public_key.lwe_size(), LweSize(10));
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     LwePublicKeyZeroEncryptionCount(20)
+    /// );
+    /// ```
+    pub fn allocate(
+        value: Scalar,
+        lwe_size: LweSize,
+        zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Self {
+        LwePublicKey {
+            tensor: Tensor::from_container(vec![value; lwe_size.0 * zero_encryption_count.0]),
+            lwe_size,
+        }
+    }
+}
+
+impl<Cont> LwePublicKey<Cont> {
+    /// Creates a public key from a container and an lwe size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LwePublicKeyZeroEncryptionCount, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LwePublicKey;
+    /// let public_key = LwePublicKey::from_container(vec![0 as u8; 200], LweSize(10));
+    /// assert_eq!(public_key.lwe_size(), LweSize(10));
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     LwePublicKeyZeroEncryptionCount(20)
+    /// );
+    /// ```
+    pub fn from_container(cont: Cont, lwe_size: LweSize) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        ck_dim_div!(cont.as_slice().len() => lwe_size.0);
+        LwePublicKey {
+            tensor: Tensor::from_container(cont),
+            lwe_size,
+        }
+    }
+
+    /// Returns the size of the lwe ciphertexts contained in the public key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::LwePublicKey;
+    /// let public_key = LwePublicKey::from_container(vec![0 as u8; 200], LweSize(10));
+    /// assert_eq!(public_key.lwe_size(), LweSize(10));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Returns the number of encryptions of zero contained in the public key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LwePublicKeyZeroEncryptionCount, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LwePublicKey;
+    /// let public_key = LwePublicKey::from_container(vec![0 as u8; 200], LweSize(10));
+    /// assert_eq!(
+    ///     public_key.zero_encryption_count(),
+    ///     LwePublicKeyZeroEncryptionCount(20)
+    /// );
+    /// ```
+    pub fn zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount
+    where
+        Self: AsRefTensor,
+    {
+        ck_dim_div!(self.as_tensor().len() => self.lwe_size.0);
+        LwePublicKeyZeroEncryptionCount(self.as_tensor().len() / self.lwe_size.0)
+    }
+
+    /// Returns an iterator over the encryptions of zero contained in the public key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweSize;
+    /// use concrete_core::backends::core::private::crypto::lwe::LwePublicKey;
+    /// let public_key = LwePublicKey::from_container(vec![0 as u8; 200], LweSize(10));
+    /// for ciphertext in public_key.ciphertext_iter() {
+    ///     assert_eq!(ciphertext.lwe_size(), LweSize(10));
+    /// }
+    /// assert_eq!(public_key.ciphertext_iter().count(), 20);
+    /// ```
+    pub fn ciphertext_iter(
+        &self,
+    ) -> impl Iterator<Item = LweCiphertext<&[<Self as AsRefTensor>::Element]>>
+    where
+        Self: AsRefTensor,
+    {
+        self.as_tensor()
+            .subtensor_iter(self.lwe_size.0)
+            .map(|sub| LweCiphertext::from_container(sub.into_container()))
+    }
+
+    /// Fills the current public key with encryptions of zero under the `secret_key` key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let mut public_key = LwePublicKey::allocate(
+    ///     0u32,
+    ///     LweDimension(256).to_lwe_size(),
+    ///     LwePublicKeyZeroEncryptionCount(10),
+    /// );
+    /// public_key.fill_with_new_key(
+    ///     &secret_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// for ciphertext in public_key.ciphertext_iter() {
+    ///     let mut decrypted = Plaintext(0u32);
+    ///     secret_key.decrypt_lwe(&mut decrypted, &ciphertext);
+    ///     assert!(decrypted.0 < 1 << 24 || decrypted.0.wrapping_neg() < 1 << 24);
+    /// }
+    /// ```
+    pub fn fill_with_new_key<Kind, KeyCont, Scalar>(
+        &mut self,
+        secret_key: &LweSecretKey<Kind, KeyCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsMutTensor<Element = Scalar>,
+        LweSecretKey<Kind, KeyCont>: AsRefTensor<Element = Scalar>,
+        Kind: KeyKind,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(self.lwe_size.to_lwe_dimension().0 => secret_key.key_size().0);
+        let zeros =
+            PlaintextList::allocate(Scalar::ZERO, PlaintextCount(self.zero_encryption_count().0));
+        let lwe_size = self.lwe_size;
+        let mut encryptions =
+            LweList::from_container(self.as_mut_tensor().as_mut_slice(), lwe_size);
+        secret_key.encrypt_lwe_list(&mut encryptions, &zeros, noise_parameters, generator);
+    }
+
+    /// Encrypts a plaintext with the current public key.
+    ///
+    /// The random subset of encryptions of zero which is summed is drawn from the secret
+    /// generator, since it must remain hidden for the encryption to be secure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let mut public_key = LwePublicKey::allocate(
+    ///     0u32,
+    ///     LweSize(257),
+    ///     LwePublicKeyZeroEncryptionCount(10),
+    /// );
+    /// public_key.fill_with_new_key(
+    ///     &secret_key,
+    ///     LogStandardDev::from_log_standard_dev(-15.),
+    ///     &mut encryption_generator,
+    /// );
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 10.,
+    /// };
+    ///
+    /// let clear = Cleartext(2. as f32);
+    /// let plain: Plaintext<u32> = encoder.encode(clear);
+    /// let mut encrypted = LweCiphertext::allocate(0u32, LweSize(257));
+    /// public_key.encrypt_lwe(&mut encrypted, &plain, &mut secret_generator);
+    ///
+    /// let mut decrypted = Plaintext(0u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &encrypted);
+    /// let decoded = encoder.decode(decrypted);
+    ///
+    /// assert!((decoded.0 - clear.0).abs() < 0.1);
+    /// ```
+    pub fn encrypt_lwe<OutputCont, Scalar>(
+        &self,
+        output: &mut LweCiphertext<OutputCont>,
+        encoded: &Plaintext<Scalar>,
+        generator: &mut SecretRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweCiphertext<OutputCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_size().0 => self.lwe_size.0);
+        output.as_mut_tensor().fill_with_element(Scalar::ZERO);
+
+        // sum a random subset of the encryptions of zero
+        let choices: Tensor<Vec<Scalar>> =
+            generator.random_binary_tensor(self.zero_encryption_count().0);
+        for (choice, encryption) in choices.iter().zip(self.ciphertext_iter()) {
+            if *choice == Scalar::ONE {
+                output
+                    .as_mut_tensor()
+                    .update_with_wrapping_add(encryption.as_tensor());
+            }
+        }
+
+        // add the encoded message
+        let body = output.get_mut_body();
+        body.0 = body.0.wrapping_add(encoded.0);
+    }
+}
@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{ck_dim_eq, AsMutTensor};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweBody, LweCiphertext};
+use concrete_commons::parameters::{LweDimension, LweSize};
+
+/// A seeded ciphertext encrypted using the LWE scheme.
+///
+/// Only the body of the ciphertext is stored, along with the seed of the random generator used
+/// to sample the mask. The mask can be regenerated from the seed, when the ciphertext is expanded
+/// into a regular [`LweCiphertext`].
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LweSeededCiphertext<Scalar> {
+    pub(crate) body: Scalar,
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) seed: u128,
+}
+
+impl<Scalar: Copy> LweSeededCiphertext<Scalar> {
+    /// Allocates a new seeded ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// assert_eq!(ct.lwe_dimension(), LweDimension(3));
+    /// assert_eq!(ct.seed(), 42);
+    /// ```
+    pub fn allocate(value: Scalar, lwe_dimension: LweDimension, seed: u128) -> Self {
+        LweSeededCiphertext {
+            body: value,
+            lwe_dimension,
+            seed,
+        }
+    }
+
+    /// Returns the size of the expanded cipher, e.g. the size of the mask + 1 for the body.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.lwe_size(), LweSize(4));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_dimension.to_lwe_size()
+    }
+
+    /// Returns the dimension of the mask of the expanded cipher.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.lwe_dimension(), LweDimension(3));
+    /// ```
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Returns the seed used to generate the mask of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededCiphertext;
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.seed(), 42);
+    /// ```
+    pub fn seed(&self) -> u128 {
+        self.seed
+    }
+
+    /// Returns the body of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweSeededCiphertext};
+    /// let ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// assert_eq!(ct.get_body(), LweBody(0 as u8));
+    /// ```
+    pub fn get_body(&self) -> LweBody<Scalar> {
+        LweBody(self.body)
+    }
+
+    /// Returns the mutable body of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweSeededCiphertext};
+    /// let mut ct = LweSeededCiphertext::allocate(0 as u8, LweDimension(3), 42);
+    /// *ct.get_mut_body() = 9;
+    /// assert_eq!(ct.get_body(), LweBody(9 as u8));
+    /// ```
+    pub fn get_mut_body(&mut self) -> &mut Scalar {
+        &mut self.body
+    }
+
+    /// Expands the seeded ciphertext into a regular ciphertext, by regenerating its mask from the
+    /// seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::{
+    ///     LweBody, LweCiphertext, LweSeededCiphertext,
+    /// };
+    /// let seeded = LweSeededCiphertext::allocate(9 as u32, LweDimension(3), 42);
+    /// let mut ct = LweCiphertext::allocate(0 as u32, LweSize(4));
+    /// seeded.expand_into(&mut ct);
+    /// assert_eq!(ct.get_body(), &LweBody(9 as u32));
+    /// let mut other = LweCiphertext::allocate(0 as u32, LweSize(4));
+    /// seeded.expand_into(&mut other);
+    /// assert_eq!(ct, other);
+    /// ```
+    pub fn expand_into<Cont>(&self, output: &mut LweCiphertext<Cont>)
+    where
+        LweCiphertext<Cont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_size().0 => self.lwe_size().0);
+        let (output_body, mut output_mask) = output.get_mut_body_and_mask();
+        let mut generator = RandomGenerator::new(Some(self.seed));
+        generator.fill_tensor_with_random_uniform(&mut output_mask);
+        output_body.0 = self.body;
+    }
+}
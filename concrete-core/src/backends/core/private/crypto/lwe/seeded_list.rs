@@ -0,0 +1,239 @@
+use serde::{Deserialize, Serialize};
+
+use crate::backends::core::private::math::random::RandomGenerator;
+use crate::backends::core::private::math::tensor::{
+    ck_dim_eq, tensor_traits, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, Tensor,
+};
+use crate::backends::core::private::math::torus::UnsignedTorus;
+
+use super::{LweBody, LweList};
+use concrete_commons::parameters::{CiphertextCount, LweDimension, LweSize};
+
+/// A list of seeded ciphertexts encoded with the LWE scheme.
+///
+/// Only the bodies of the ciphertexts are stored, along with one seed per ciphertext. The mask of
+/// each ciphertext is sampled from a random generator seeded with its own seed, which allows to
+/// expand the ciphertexts independently of each other.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LweSeededList<Cont> {
+    pub(crate) tensor: Tensor<Cont>,
+    pub(crate) lwe_dimension: LweDimension,
+    pub(crate) seeds: Vec<u128>,
+}
+
+tensor_traits!(LweSeededList);
+
+impl<Scalar> LweSeededList<Vec<Scalar>>
+where
+    Scalar: Copy,
+{
+    /// Allocates a list of seeded lwe ciphertexts whose bodies have the value `value`, one for
+    /// each of the `seeds`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// assert_eq!(list.lwe_dimension(), LweDimension(10));
+    /// ```
+    pub fn allocate(value: Scalar, lwe_dimension: LweDimension, seeds: Vec<u128>) -> Self {
+        LweSeededList {
+            tensor: Tensor::from_container(vec![value; seeds.len()]),
+            lwe_dimension,
+            seeds,
+        }
+    }
+}
+
+impl<Cont> LweSeededList<Cont> {
+    /// Creates a list from a container of bodies, a lwe dimension and the seeds of the
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::from_container(vec![0 as u8; 20], LweDimension(10), vec![42; 20]);
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// assert_eq!(list.seeds(), &[42; 20]);
+    /// ```
+    pub fn from_container(cont: Cont, lwe_dimension: LweDimension, seeds: Vec<u128>) -> Self
+    where
+        Cont: AsRefSlice,
+    {
+        let tensor = Tensor::from_container(cont);
+        ck_dim_eq!(tensor.len() => seeds.len());
+        LweSeededList {
+            tensor,
+            lwe_dimension,
+            seeds,
+        }
+    }
+
+    /// Returns the number of ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{CiphertextCount, LweDimension};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// assert_eq!(list.count(), CiphertextCount(20));
+    /// ```
+    pub fn count(&self) -> CiphertextCount
+    where
+        Self: AsRefTensor,
+    {
+        CiphertextCount(self.as_tensor().len())
+    }
+
+    /// Returns the size of the expanded ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// assert_eq!(list.lwe_size(), LweSize(11));
+    /// ```
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_dimension.to_lwe_size()
+    }
+
+    /// Returns the number of masks of the expanded ciphertexts in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// assert_eq!(list.lwe_dimension(), LweDimension(10));
+    /// ```
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Returns the seeds used to generate the masks of the ciphertexts, in the order of the
+    /// ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::LweSeededList;
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), vec![4, 2]);
+    /// assert_eq!(list.seeds(), &[4, 2]);
+    /// ```
+    pub fn seeds(&self) -> &[u128] {
+        &self.seeds
+    }
+
+    /// Returns an iterator over the bodies of the ciphertexts of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweSeededList};
+    /// let list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// for body in list.body_iter() {
+    ///     assert_eq!(body, LweBody(0));
+    /// }
+    /// assert_eq!(list.body_iter().count(), 20);
+    /// ```
+    pub fn body_iter(&self) -> impl Iterator<Item = LweBody<<Self as AsRefTensor>::Element>> + '_
+    where
+        Self: AsRefTensor,
+        <Self as AsRefTensor>::Element: Copy,
+    {
+        self.as_tensor().iter().map(|body| LweBody(*body))
+    }
+
+    /// Returns an iterator over the mutable bodies of the ciphertexts of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweSeededList};
+    /// let mut list = LweSeededList::allocate(0 as u8, LweDimension(10), (0..20).collect());
+    /// for body in list.body_iter_mut() {
+    ///     *body = 2;
+    /// }
+    /// for body in list.body_iter() {
+    ///     assert_eq!(body, LweBody(2));
+    /// }
+    /// ```
+    pub fn body_iter_mut(&mut self) -> impl Iterator<Item = &mut <Self as AsMutTensor>::Element>
+    where
+        Self: AsMutTensor,
+    {
+        self.as_mut_tensor().iter_mut()
+    }
+
+    /// Returns an iterator over the mutable bodies of the ciphertexts of the list, along with
+    /// their seeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweSeededList};
+    /// let mut list = LweSeededList::allocate(0 as u8, LweDimension(10), vec![4, 2]);
+    /// for (body, seed) in list.seeded_body_iter_mut() {
+    ///     *body = seed as u8;
+    /// }
+    /// assert_eq!(list.body_iter().collect::<Vec<_>>(), vec![LweBody(4), LweBody(2)]);
+    /// ```
+    pub fn seeded_body_iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = (&mut <Tensor<Cont> as AsMutSlice>::Element, u128)>
+    where
+        Tensor<Cont>: AsMutSlice,
+    {
+        self.tensor.iter_mut().zip(self.seeds.iter().copied())
+    }
+
+    /// Expands the seeded list into a regular list, by regenerating the mask of each ciphertext
+    /// from its seed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::lwe::{LweBody, LweList, LweSeededList};
+    /// let seeded = LweSeededList::allocate(9 as u32, LweDimension(10), (0..20).collect());
+    /// let mut list = LweList::allocate(0 as u32, seeded.lwe_size(), seeded.count());
+    /// seeded.expand_into(&mut list);
+    /// for ciphertext in list.ciphertext_iter() {
+    ///     assert_eq!(ciphertext.get_body(), &LweBody(9));
+    /// }
+    /// let mut other = LweList::allocate(0 as u32, seeded.lwe_size(), seeded.count());
+    /// seeded.expand_into(&mut other);
+    /// assert_eq!(list, other);
+    /// ```
+    pub fn expand_into<OutCont, Scalar>(&self, output: &mut LweList<OutCont>)
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        LweList<OutCont>: AsMutTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_size().0 => self.lwe_size().0);
+        ck_dim_eq!(output.count().0 => self.count().0);
+        let iterator = output
+            .ciphertext_iter_mut()
+            .zip(self.as_tensor().iter().zip(self.seeds.iter()));
+        for (mut output_ct, (body, seed)) in iterator {
+            let (output_body, mut output_mask) = output_ct.get_mut_body_and_mask();
+            let mut generator = RandomGenerator::new(Some(*seed));
+            generator.fill_tensor_with_random_uniform(&mut output_mask);
+            output_body.0 = *body;
+        }
+    }
+}
@@ -2,21 +2,23 @@ use concrete_commons::dispersion::{LogStandardDev, Variance};
 use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::numeric::{CastFrom, Numeric, SignedInteger};
 use concrete_commons::parameters::{
-    CiphertextCount, CleartextCount, DecompositionBaseLog, DecompositionLevelCount, LweDimension,
-    PlaintextCount,
+    CiphertextCount, CiphertextModulusLog, CleartextCount, DecompositionBaseLog,
+    DecompositionLevelCount, LweDimension, LwePublicKeyZeroEncryptionCount, PlaintextCount,
 };
 use concrete_npe as npe;
 
 use crate::backends::core::private::crypto::encoding::{
     Cleartext, CleartextList, Plaintext, PlaintextList,
 };
-use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweKeyswitchKey, LweList};
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext, LweCompressedList, LweKeyswitchKey, LweList, LwePublicKey, LweSeededList,
+};
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::backends::core::private::crypto::secret::LweSecretKey;
 use crate::backends::core::private::math::random::{RandomGenerable, RandomGenerator, UniformMsb};
-use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefTensor, Tensor};
+use crate::backends::core::private::math::tensor::{AsMutTensor, AsRefSlice, AsRefTensor, Tensor};
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use crate::backends::core::private::test_tools::{
     assert_delta_std_dev, assert_noise_distribution, random_ciphertext_count, random_lwe_dimension,
@@ -159,6 +161,191 @@ fn test_encrypt_decrypt_u64() {
     test_encrypt_decrypt::<u64>()
 }
 
+fn test_seeded_encrypt_decrypt<T: UnsignedTorus>() {
+    //! encrypts a bunch of messages into a seeded list, expands it and decrypts them
+    //! warning: std_dev is not randomized
+    //! only assert with assert_delta_std_dev
+    // generate random settings
+    let nb_ct = random_ciphertext_count(1000);
+    let dimension = random_lwe_dimension(1000);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generate the secret key
+    let sk = LweSecretKey::generate_binary(dimension, &mut secret_generator);
+
+    // generate random messages
+    let messages = PlaintextList::from_tensor(random_generator.random_uniform_tensor(nb_ct.0));
+
+    // encryption in a seeded list
+    let seeds = (0..nb_ct.0)
+        .map(|_| random_generator.random_uniform())
+        .collect();
+    let mut seeded_ciphertexts = LweSeededList::allocate(T::ZERO, dimension, seeds);
+    sk.encrypt_seeded_lwe_list(
+        &mut seeded_ciphertexts,
+        &messages,
+        std_dev,
+        &mut encryption_generator,
+    );
+
+    // expansion of the seeded list
+    let mut ciphertexts = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    seeded_ciphertexts.expand_into(&mut ciphertexts);
+
+    // make sure that the mask of each ciphertext only depends on its own seed
+    let iterator = ciphertexts
+        .ciphertext_iter()
+        .zip(seeded_ciphertexts.seeds().iter());
+    for (ciphertext, seed) in iterator {
+        let mut mask = Tensor::allocate(T::ZERO, dimension.0);
+        RandomGenerator::new(Some(*seed)).fill_tensor_with_random_uniform(&mut mask);
+        assert_eq!(ciphertext.get_mask().as_tensor().as_slice(), mask.as_slice());
+    }
+
+    // decryption
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(nb_ct.0));
+    sk.decrypt_lwe_list(&mut decryptions, &ciphertexts);
+
+    // make sure that after decryption we recover the original plaintext
+    if nb_ct.0 < 7 {
+        assert_delta_std_dev(&messages, &decryptions, std_dev);
+    } else {
+        assert_noise_distribution(&messages, &decryptions, std_dev);
+    }
+}
+
+#[test]
+fn test_seeded_encrypt_decrypt_u32() {
+    test_seeded_encrypt_decrypt::<u32>()
+}
+
+#[test]
+fn test_seeded_encrypt_decrypt_u64() {
+    test_seeded_encrypt_decrypt::<u64>()
+}
+
+fn test_modulus_switch<T>()
+where
+    T: UnsignedTorus + CastFrom<u32>,
+    u32: CastFrom<T>,
+{
+    //! encrypts a bunch of messages, switches them to a smaller modulus, lifts them back and
+    //! decrypts them
+    //! warning: std_dev is not randomized
+    // generate random settings
+    let nb_ct = random_ciphertext_count(1000);
+    let dimension = random_lwe_dimension(1000);
+    let modulus_log = CiphertextModulusLog(20);
+    let std_dev = LogStandardDev::from_log_standard_dev(-25.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generate the secret key
+    let sk = LweSecretKey::generate_binary(dimension, &mut secret_generator);
+
+    // generate random messages
+    let messages = PlaintextList::from_tensor(random_generator.random_uniform_tensor(nb_ct.0));
+
+    // encryption
+    let mut ciphertexts = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    sk.encrypt_lwe_list(
+        &mut ciphertexts,
+        &messages,
+        std_dev,
+        &mut encryption_generator,
+    );
+
+    // modulus switch in a 32 bits container, and lift back
+    let mut compressed =
+        LweCompressedList::allocate(0u32, dimension.to_lwe_size(), nb_ct, modulus_log);
+    compressed.fill_with_modulus_switch(&ciphertexts);
+    let mut lifted = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    compressed.lift_into(&mut lifted);
+
+    // decryption
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(nb_ct.0));
+    sk.decrypt_lwe_list(&mut decryptions, &lifted);
+
+    // the estimated variance is expressed in the units of the smaller modulus
+    let output_variance = npe::estimate_modulus_switching_noise_with_binary_key::<T, _>(
+        dimension,
+        modulus_log.0,
+        std_dev,
+    );
+    let output_variance = Variance(output_variance.0 / 2_f64.powi(2 * modulus_log.0 as i32));
+
+    // make sure that after decryption we recover the original plaintext
+    if nb_ct.0 < 7 {
+        assert_delta_std_dev(&messages, &decryptions, output_variance);
+    } else {
+        assert_noise_distribution(&messages, &decryptions, output_variance);
+    }
+}
+
+#[test]
+fn test_modulus_switch_u32() {
+    test_modulus_switch::<u32>()
+}
+
+#[test]
+fn test_modulus_switch_u64() {
+    test_modulus_switch::<u64>()
+}
+
+fn test_public_key_encrypt_decrypt<T: UnsignedTorus>() {
+    //! encrypts a bunch of messages with a public key and decrypts them
+    //! warning: std_dev is not randomized
+    //! only assert with assert_delta_std_dev, as the noises of the ciphertexts are correlated
+    // generate random settings
+    let nb_ct = random_ciphertext_count(100);
+    let dimension = random_lwe_dimension(1000);
+    let zero_encryption_count = LwePublicKeyZeroEncryptionCount(random_ciphertext_count(1000).0);
+    let std_dev = LogStandardDev::from_log_standard_dev(-30.);
+    let mut random_generator = RandomGenerator::new(None);
+    let mut secret_generator = SecretRandomGenerator::new(None);
+    let mut encryption_generator = EncryptionRandomGenerator::new(None);
+
+    // generate the secret key and the public key
+    let sk = LweSecretKey::generate_binary(dimension, &mut secret_generator);
+    let mut pk = LwePublicKey::allocate(T::ZERO, dimension.to_lwe_size(), zero_encryption_count);
+    pk.fill_with_new_key(&sk, std_dev, &mut encryption_generator);
+
+    // generate random messages
+    let messages = PlaintextList::from_tensor(random_generator.random_uniform_tensor(nb_ct.0));
+
+    // encryption with the public key
+    let mut ciphertexts = LweList::allocate(T::ZERO, dimension.to_lwe_size(), nb_ct);
+    for (mut ciphertext, message) in ciphertexts
+        .ciphertext_iter_mut()
+        .zip(messages.plaintext_iter())
+    {
+        pk.encrypt_lwe(&mut ciphertext, message, &mut secret_generator);
+    }
+
+    // decryption
+    let mut decryptions = PlaintextList::allocate(T::ZERO, PlaintextCount(nb_ct.0));
+    sk.decrypt_lwe_list(&mut decryptions, &ciphertexts);
+
+    // calls the NPE to find out the amount of noise after the encryption
+    let output_variance =
+        npe::estimate_lwe_public_key_encryption_noise::<T, _>(std_dev, zero_encryption_count);
+    assert_delta_std_dev(&messages, &decryptions, output_variance);
+}
+
+#[test]
+fn test_public_key_encrypt_decrypt_u32() {
+    test_public_key_encrypt_decrypt::<u32>()
+}
+
+#[test]
+fn test_public_key_encrypt_decrypt_u64() {
+    test_public_key_encrypt_decrypt::<u64>()
+}
+
 fn test_multisum_npe<T>()
 where
     T: UnsignedTorus + RandomGenerable<UniformMsb> + CastFrom<usize>,
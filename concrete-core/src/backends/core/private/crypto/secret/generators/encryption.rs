@@ -28,6 +28,20 @@ impl EncryptionRandomGenerator {
         }
     }
 
+    /// Creates a new encryption generator, seeding both the mask and the noise generators with the
+    /// given values.
+    ///
+    /// # Note
+    ///
+    /// Since the noise generator is seeded, the encryptions performed with this generator are
+    /// reproducible, and are only as secret as the seeds.
+    pub fn new_with_seeds(mask_seed: u128, noise_seed: u128) -> EncryptionRandomGenerator {
+        EncryptionRandomGenerator {
+            mask: RandomGenerator::new(Some(mask_seed)),
+            noise: RandomGenerator::new(Some(noise_seed)),
+        }
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[allow(dead_code)]
     pub(crate) fn seed_noise_generator(&mut self, seed: u128) {
@@ -215,6 +229,11 @@ impl EncryptionRandomGenerator {
         self.mask.fill_tensor_with_random_uniform(output)
     }
 
+    // Samples a seed for the mask generator of a seeded ciphertext, using the mask generator.
+    pub(crate) fn random_mask_seed(&mut self) -> u128 {
+        self.mask.random_uniform()
+    }
+
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
     where
@@ -1,6 +1,8 @@
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::ggsw::GgswCiphertext;
-use crate::backends::core::private::crypto::glwe::{GlweCiphertext, GlweList};
+use crate::backends::core::private::crypto::glwe::{
+    GlweBody, GlweCiphertext, GlweList, GlweSeededCiphertext, GlweSeededList,
+};
 use crate::backends::core::private::crypto::secret::LweSecretKey;
 use crate::backends::core::private::math::tensor::{
     ck_dim_div, ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
@@ -10,14 +12,16 @@ use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
 use crate::backends::core::private::math::polynomial::PolynomialList;
-use crate::backends::core::private::math::random::{Gaussian, RandomGenerable};
+use crate::backends::core::private::math::random::{Gaussian, RandomGenerable, RandomGenerator};
 use crate::backends::core::private::math::torus::UnsignedTorus;
 use concrete_commons::dispersion::DispersionParameter;
 use concrete_commons::key_kinds::{
     BinaryKeyKind, GaussianKeyKind, KeyKind, TernaryKeyKind, UniformKeyKind,
 };
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweDimension, PlaintextCount, PolynomialSize};
+use concrete_commons::parameters::{
+    GlweDimension, PlaintextCount, PolynomialCount, PolynomialSize,
+};
 #[cfg(feature = "multithread")]
 use rayon::{iter::IndexedParallelIterator, prelude::*};
 use serde::{Deserialize, Serialize};
@@ -632,6 +636,197 @@ where
         }
     }
 
+    /// Encrypts a single seeded GLWE ciphertext.
+    ///
+    /// The mask of the ciphertext is sampled from a random generator seeded with the seed of the
+    /// `encrypted` ciphertext, and only the body is stored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{
+    ///     GlweCiphertext, GlweSeededCiphertext,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(5),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts =
+    ///     PlaintextList::from_container(vec![100000 as u32, 200000, 300000, 400000, 500000]);
+    /// let mut seeded =
+    ///     GlweSeededCiphertext::allocate(0 as u32, PolynomialSize(5), GlweDimension(256), 42);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_seeded_glwe(&mut seeded, &plaintexts, noise, &mut encryption_generator);
+    /// let mut ciphertext = GlweCiphertext::allocate(0 as u32, PolynomialSize(5), seeded.size());
+    /// seeded.expand_into(&mut ciphertext);
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32, 0, 0, 0, 0]);
+    /// secret_key.decrypt_glwe(&mut decrypted, &ciphertext);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let d0 = dec.0.wrapping_sub(plain.0);
+    ///     let d1 = plain.0.wrapping_sub(dec.0);
+    ///     let dist = std::cmp::min(d0, d1);
+    ///     assert!(dist < 400, "dist: {:?}", dist);
+    /// }
+    /// ```
+    pub fn encrypt_seeded_glwe<Cont1, Cont2, Scalar>(
+        &self,
+        encrypted: &mut GlweSeededCiphertext<Cont1>,
+        encoded: &PlaintextList<Cont2>,
+        noise_parameter: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweSeededCiphertext<Cont1>: AsMutTensor<Element = Scalar>,
+        PlaintextList<Cont2>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(encoded.count().0 => encrypted.polynomial_size().0);
+        ck_dim_eq!(encrypted.mask_size().0 => self.key_size().0);
+        let mut mask_generator = RandomGenerator::new(Some(encrypted.seed()));
+        let mut masks = PolynomialList::allocate(
+            Scalar::ZERO,
+            PolynomialCount(self.key_size().0),
+            self.poly_size,
+        );
+        self.fill_seeded_glwe_body(
+            &mut encrypted.get_mut_body(),
+            &mut masks,
+            encoded,
+            noise_parameter,
+            &mut mask_generator,
+            generator,
+        );
+    }
+
+    /// Encrypts a list of seeded GLWE ciphertexts.
+    ///
+    /// The mask of each ciphertext is sampled from a random generator seeded with the seed of
+    /// this ciphertext in the `encrypt` list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::PlaintextList;
+    /// use concrete_core::backends::core::private::crypto::glwe::{GlweList, GlweSeededList};
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = GlweSecretKey::generate_binary(
+    ///     GlweDimension(256),
+    ///     PolynomialSize(2),
+    ///     &mut secret_generator,
+    /// );
+    /// let noise = LogStandardDev::from_log_standard_dev(-25.);
+    /// let plaintexts = PlaintextList::from_container(vec![1000 as u32, 2000, 3000, 4000]);
+    /// let mut seeded = GlweSeededList::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     vec![4, 2],
+    /// );
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_seeded_glwe_list(
+    ///     &mut seeded,
+    ///     &plaintexts,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    /// let mut ciphertexts = GlweList::allocate(
+    ///     0 as u32,
+    ///     PolynomialSize(2),
+    ///     GlweDimension(256),
+    ///     CiphertextCount(2),
+    /// );
+    /// seeded.expand_into(&mut ciphertexts);
+    /// let mut decrypted = PlaintextList::from_container(vec![0 as u32, 0, 0, 0]);
+    /// secret_key.decrypt_glwe_list(&mut decrypted, &ciphertexts);
+    /// for (dec, plain) in decrypted.plaintext_iter().zip(plaintexts.plaintext_iter()) {
+    ///     let d0 = dec.0.wrapping_sub(plain.0);
+    ///     let d1 = plain.0.wrapping_sub(dec.0);
+    ///     let dist = std::cmp::min(d0, d1);
+    ///     assert!(dist < 400, "dist: {:?}", dist);
+    /// }
+    /// ```
+    pub fn encrypt_seeded_glwe_list<CiphCont, EncCont, Scalar>(
+        &self,
+        encrypt: &mut GlweSeededList<CiphCont>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweSeededList<CiphCont>: AsMutTensor<Element = Scalar>,
+        Tensor<CiphCont>: AsMutSlice<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+        for<'a> PlaintextList<&'a [Scalar]>: AsRefTensor<Element = Scalar>,
+    {
+        ck_dim_eq!(encrypt.ciphertext_count().0 * encrypt.polynomial_size().0 => encoded.count().0);
+        ck_dim_eq!(encrypt.glwe_dimension().0 => self.key_size().0);
+
+        let mut masks = PolynomialList::allocate(
+            Scalar::ZERO,
+            PolynomialCount(self.key_size().0),
+            self.poly_size,
+        );
+        let count = PlaintextCount(encrypt.polynomial_size().0);
+        let iterator = encrypt
+            .seeded_body_iter_mut()
+            .zip(encoded.sublist_iter(count));
+        for ((mut body, seed), encoded) in iterator {
+            // the mask of each ciphertext is regenerated from its own seed
+            let mut mask_generator = RandomGenerator::new(Some(seed));
+            self.fill_seeded_glwe_body(
+                &mut body,
+                &mut masks,
+                &encoded,
+                noise_parameters,
+                &mut mask_generator,
+                generator,
+            );
+        }
+    }
+
+    // Samples a fresh mask from the seeded mask generator, and fills the body of a seeded GLWE
+    // ciphertext with the associated encryption of the encoded message.
+    fn fill_seeded_glwe_body<BodyCont, EncCont, Scalar>(
+        &self,
+        body: &mut GlweBody<BodyCont>,
+        masks: &mut PolynomialList<Vec<Scalar>>,
+        encoded: &PlaintextList<EncCont>,
+        noise_parameters: impl DispersionParameter,
+        mask_generator: &mut RandomGenerator,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        GlweBody<BodyCont>: AsMutTensor<Element = Scalar>,
+        PlaintextList<EncCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        mask_generator.fill_tensor_with_random_uniform(masks);
+        generator.fill_tensor_with_random_noise(body, noise_parameters);
+        body.as_mut_polynomial()
+            .update_with_wrapping_add_multisum(masks, &self.as_polynomial_list());
+        body.as_mut_polynomial()
+            .update_with_wrapping_add(&encoded.as_polynomial());
+    }
+
     /// Encrypts a list of GLWE ciphertexts, with a zero plaintext.
     ///
     /// # Example
@@ -2,11 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::backends::core::private::crypto::encoding::{Plaintext, PlaintextList};
 use crate::backends::core::private::crypto::gsw::GswCiphertext;
-use crate::backends::core::private::crypto::lwe::{LweCiphertext, LweList};
+use crate::backends::core::private::crypto::lwe::{
+    LweCiphertext, LweList, LweMask, LweSeededCiphertext, LweSeededList,
+};
 use crate::backends::core::private::crypto::secret::generators::{
     EncryptionRandomGenerator, SecretRandomGenerator,
 };
-use crate::backends::core::private::math::random::{Gaussian, RandomGenerable};
+use crate::backends::core::private::math::random::{Gaussian, RandomGenerable, RandomGenerator};
 use crate::backends::core::private::math::tensor::{
     ck_dim_eq, AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor, Tensor,
 };
@@ -196,6 +198,41 @@ impl<Cont> LweSecretKey<TernaryKeyKind, Cont> {
             kind: PhantomData,
         }
     }
+
+    /// Returns the binary expansion of a ternary lwe secret key.
+    ///
+    /// Every coefficient $s\_i\in\\{-1,0,1\\}$ of the key is mapped to a pair of binary
+    /// coefficients $(s\_i^+, s\_i^-)$ such that $s\_i = s\_i^+ - s\_i^-$, which makes the output
+    /// key twice as large as the input key. A ciphertext encrypted under the ternary key with mask
+    /// $(a\_0, \cdots, a\_{n-1})$ is a valid ciphertext under the expanded key, once its mask is
+    /// expanded to $(a\_0, -a\_0, \cdots, a\_{n-1}, -a\_{n-1})$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let secret_key = LweSecretKey::ternary_from_container(vec![0u32, 1, u32::MAX]);
+    /// let expanded = secret_key.binary_expansion();
+    /// assert_eq!(expanded.key_size(), LweDimension(6));
+    /// assert_eq!(expanded.as_tensor().as_slice(), &[0, 0, 1, 0, 0, 1]);
+    /// ```
+    pub fn binary_expansion<Scalar>(&self) -> LweSecretKey<BinaryKeyKind, Vec<Scalar>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut expanded = Vec::with_capacity(2 * self.as_tensor().len());
+        for coefficient in self.as_tensor().iter() {
+            let positive = *coefficient == Scalar::ONE;
+            let negative = coefficient.wrapping_neg() == Scalar::ONE;
+            expanded.push(if positive { Scalar::ONE } else { Scalar::ZERO });
+            expanded.push(if negative { Scalar::ONE } else { Scalar::ZERO });
+        }
+        LweSecretKey::binary_from_container(expanded)
+    }
 }
 
 impl<Cont> LweSecretKey<GaussianKeyKind, Cont> {
@@ -225,6 +262,59 @@ impl<Cont> LweSecretKey<GaussianKeyKind, Cont> {
             kind: PhantomData,
         }
     }
+
+    /// Returns the signed binary expansion of a gaussian lwe secret key, with `width` bits per
+    /// coefficient, or `None` if a coefficient does not fit in `width` bits.
+    ///
+    /// Every coefficient $s\_i$ of the key is mapped to `width` pairs of binary coefficients
+    /// $(s\_{i,j}^+, s\_{i,j}^-)$ such that $s\_i = \sum\_j 2^j(s\_{i,j}^+ - s\_{i,j}^-)$, which
+    /// makes the output key `2 * width` times larger than the input key. A ciphertext encrypted
+    /// under the gaussian key is a valid ciphertext under the expanded key once its mask is
+    /// expanded with [`LweCiphertext::fill_with_mask_expansion`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    /// use concrete_core::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+    /// let secret_key = LweSecretKey::gaussian_from_container(vec![3u32, 0, 2u32.wrapping_neg()]);
+    /// let expanded = secret_key.binary_expansion(2).unwrap();
+    /// assert_eq!(expanded.key_size(), LweDimension(12));
+    /// assert_eq!(
+    ///     expanded.as_tensor().as_slice(),
+    ///     &[1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1]
+    /// );
+    /// assert!(secret_key.binary_expansion::<u32>(1).is_none());
+    /// ```
+    pub fn binary_expansion<Scalar>(
+        &self,
+        width: usize,
+    ) -> Option<LweSecretKey<BinaryKeyKind, Vec<Scalar>>>
+    where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        let mut expanded = Vec::with_capacity(2 * width * self.as_tensor().len());
+        for coefficient in self.as_tensor().iter() {
+            let negative = coefficient.into_signed() < Scalar::Signed::ZERO;
+            let magnitude = if negative {
+                coefficient.wrapping_neg()
+            } else {
+                *coefficient
+            };
+            if width < Scalar::BITS && magnitude >> width != Scalar::ZERO {
+                return None;
+            }
+            for bit in 0..width {
+                let set = (magnitude >> bit) & Scalar::ONE;
+                expanded.push(if negative { Scalar::ZERO } else { set });
+                expanded.push(if negative { set } else { Scalar::ZERO });
+            }
+        }
+        Some(LweSecretKey::binary_from_container(expanded))
+    }
 }
 
 impl<Cont> LweSecretKey<UniformKeyKind, Cont> {
@@ -410,6 +500,155 @@ where
         }
     }
 
+    /// Encrypts a single seeded ciphertext.
+    ///
+    /// The mask of the ciphertext is sampled from a random generator seeded with the seed of the
+    /// `output` ciphertext, and only the body is stored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{LweDimension, LweSize};
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 10.,
+    /// };
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let clear = Cleartext(2. as f32);
+    /// let plain: Plaintext<u32> = encoder.encode(clear);
+    /// let mut seeded = LweSeededCiphertext::allocate(0u32, LweDimension(256), 42);
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_seeded_lwe(&mut seeded, &plain, noise, &mut encryption_generator);
+    ///
+    /// let mut encrypted = LweCiphertext::allocate(0u32, LweSize(257));
+    /// seeded.expand_into(&mut encrypted);
+    /// let mut decrypted = Plaintext(0u32);
+    /// secret_key.decrypt_lwe(&mut decrypted, &encrypted);
+    /// let decoded = encoder.decode(decrypted);
+    ///
+    /// assert!((decoded.0 - clear.0).abs() < 0.1);
+    /// ```
+    pub fn encrypt_seeded_lwe<Scalar>(
+        &self,
+        output: &mut LweSeededCiphertext<Scalar>,
+        encoded: &Plaintext<Scalar>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.lwe_dimension().0 => self.key_size().0);
+
+        // regenerate the uniformly random mask from the seed
+        let mut mask_generator = RandomGenerator::new(Some(output.seed()));
+        let mut mask = LweMask::from_container(vec![Scalar::ZERO; self.key_size().0]);
+        mask_generator.fill_tensor_with_random_uniform(&mut mask);
+
+        // generate an error from the normal distribution described by std_dev
+        let mut body: Scalar = generator.random_noise(noise_parameters);
+
+        // compute the multisum between the secret key and the mask, and add the encoded message
+        body = body.wrapping_add(mask.compute_multisum(self));
+        body = body.wrapping_add(encoded.0);
+        *output.get_mut_body() = body;
+    }
+
+    /// Encrypts a list of seeded ciphertexts.
+    ///
+    /// The mask of each ciphertext is sampled from a random generator seeded with the seed of this
+    /// ciphertext in the `output` list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_commons::dispersion::LogStandardDev;
+    /// use concrete_commons::parameters::{
+    ///     CiphertextCount, CleartextCount, LweDimension, LweSize, PlaintextCount,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::encoding::*;
+    /// use concrete_core::backends::core::private::crypto::lwe::*;
+    /// use concrete_core::backends::core::private::crypto::secret::generators::{
+    ///     EncryptionRandomGenerator, SecretRandomGenerator,
+    /// };
+    /// use concrete_core::backends::core::private::crypto::secret::*;
+    /// use concrete_core::backends::core::private::crypto::*;
+    ///
+    /// let mut secret_generator = SecretRandomGenerator::new(None);
+    /// let secret_key = LweSecretKey::generate_binary(LweDimension(256), &mut secret_generator);
+    /// let encoder = RealEncoder {
+    ///     offset: 0. as f32,
+    ///     delta: 10.,
+    /// };
+    /// let noise = LogStandardDev::from_log_standard_dev(-15.);
+    ///
+    /// let clear_values = CleartextList::allocate(2. as f32, CleartextCount(100));
+    /// let mut plain_values = PlaintextList::allocate(0u32, PlaintextCount(100));
+    /// encoder.encode_list(&mut plain_values, &clear_values);
+    /// let mut seeded_values =
+    ///     LweSeededList::allocate(0u32, LweDimension(256), (0..100).collect());
+    /// let mut encryption_generator = EncryptionRandomGenerator::new(None);
+    /// secret_key.encrypt_seeded_lwe_list(
+    ///     &mut seeded_values,
+    ///     &plain_values,
+    ///     noise,
+    ///     &mut encryption_generator,
+    /// );
+    ///
+    /// let mut encrypted_values = LweList::allocate(0u32, LweSize(257), CiphertextCount(100));
+    /// seeded_values.expand_into(&mut encrypted_values);
+    /// let mut decrypted_values = PlaintextList::allocate(0u32, PlaintextCount(100));
+    /// secret_key.decrypt_lwe_list(&mut decrypted_values, &encrypted_values);
+    /// let mut decoded_values = CleartextList::allocate(0. as f32, CleartextCount(100));
+    /// encoder.decode_list(&mut decoded_values, &decrypted_values);
+    /// for (clear, decoded) in clear_values
+    ///     .cleartext_iter()
+    ///     .zip(decoded_values.cleartext_iter())
+    /// {
+    ///     assert!((clear.0 - decoded.0).abs() < 0.1);
+    /// }
+    /// ```
+    pub fn encrypt_seeded_lwe_list<OutputCont, InputCont, Scalar>(
+        &self,
+        output: &mut LweSeededList<OutputCont>,
+        encoded: &PlaintextList<InputCont>,
+        noise_parameters: impl DispersionParameter,
+        generator: &mut EncryptionRandomGenerator,
+    ) where
+        Self: AsRefTensor<Element = Scalar>,
+        LweSeededList<OutputCont>: AsMutTensor<Element = Scalar>,
+        Tensor<OutputCont>: AsMutSlice<Element = Scalar>,
+        PlaintextList<InputCont>: AsRefTensor<Element = Scalar>,
+        Scalar: UnsignedTorus,
+    {
+        ck_dim_eq!(output.count().0 => encoded.count().0);
+        ck_dim_eq!(output.lwe_dimension().0 => self.key_size().0);
+
+        let mut mask = LweMask::from_container(vec![Scalar::ZERO; self.key_size().0]);
+        let iterator = output.seeded_body_iter_mut().zip(encoded.plaintext_iter());
+        for ((body, seed), message) in iterator {
+            // each mask is regenerated from the seed of its ciphertext
+            let mut mask_generator = RandomGenerator::new(Some(seed));
+            mask_generator.fill_tensor_with_random_uniform(&mut mask);
+            *body = generator
+                .random_noise::<Scalar>(noise_parameters)
+                .wrapping_add(mask.compute_multisum(self))
+                .wrapping_add(message.0);
+        }
+    }
+
     /// Encrypts a single ciphertext with null masks.
     ///
     /// # Example
@@ -78,6 +78,53 @@ implement!(u32);
 implement!(u64);
 implement!(u128);
 
+/// A trait that converts a torus element in unsigned integer representation to the closest torus
+/// element in an unsigned integer representation with a different precision.
+///
+/// # Note
+///
+/// Increasing the precision is exact, while decreasing it rounds to the closest representable
+/// torus element.
+///
+/// # Example
+///
+/// ```
+/// use concrete_core::backends::core::private::math::torus::CastTorusInto;
+/// let input = 3_u32 << 29;
+/// let output: u64 = input.cast_torus_into();
+/// assert_eq!(output, 3_u64 << 61);
+/// let input = (3_u64 << 61) + (1 << 31);
+/// let output: u32 = input.cast_torus_into();
+/// assert_eq!(output, (3_u32 << 29) + 1);
+/// ```
+pub trait CastTorusInto<Output>: Sized
+where
+    Self: UnsignedInteger,
+    Output: UnsignedInteger,
+{
+    /// Consumes `self` and returns the closest torus element in the output representation.
+    fn cast_torus_into(self) -> Output;
+}
+
+impl<Input, Output> CastTorusInto<Output> for Input
+where
+    Input: UnsignedInteger + CastInto<Output>,
+    Output: UnsignedInteger,
+{
+    fn cast_torus_into(self) -> Output {
+        if <Output as Numeric>::BITS >= <Input as Numeric>::BITS {
+            let output: Output = self.cast_into();
+            output << (<Output as Numeric>::BITS - <Input as Numeric>::BITS)
+        } else {
+            let shift = <Input as Numeric>::BITS - <Output as Numeric>::BITS;
+            // We keep the most significant discarded bit to round to the closest value. The
+            // eventual carry out of the output precision wraps around the torus.
+            let rounded = (self >> (shift - 1)).wrapping_add(Input::ONE) >> 1;
+            rounded.cast_into()
+        }
+    }
+}
+
 /// A marker trait for unsigned integer types that can be used in ciphertexts, keys etc.
 pub trait UnsignedTorus:
     UnsignedInteger
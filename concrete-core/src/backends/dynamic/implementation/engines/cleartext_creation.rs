@@ -0,0 +1,44 @@
+use crate::backends::core::entities::Cleartext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::specification::engines::{CleartextCreationEngine, CleartextCreationError};
+
+/// # Description:
+/// Implementation of [`CleartextCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl CleartextCreationEngine<u64, Cleartext64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_cleartext(
+        &mut self,
+        value: &u64,
+    ) -> Result<Cleartext64, CleartextCreationError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .create_cleartext(value)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .create_cleartext(value)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn create_cleartext_unchecked(&mut self, value: &u64) -> Cleartext64 {
+        match self {
+            DynEngine::Core(engine) => engine.create_cleartext_unchecked(value),
+            DynEngine::Cleartext(engine) => engine.create_cleartext_unchecked(value),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::backends::core::entities::Cleartext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::specification::engines::{CleartextRetrievalEngine, CleartextRetrievalError};
+
+/// # Description:
+/// Implementation of [`CleartextRetrievalEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl CleartextRetrievalEngine<Cleartext64, u64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input: u64 = 3;
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&input)?;
+    /// let output: u64 = engine.retrieve_cleartext(&cleartext)?;
+    /// #
+    /// assert_eq!(output, 3_u64);
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn retrieve_cleartext(
+        &mut self,
+        cleartext: &Cleartext64,
+    ) -> Result<u64, CleartextRetrievalError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .retrieve_cleartext(cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .retrieve_cleartext(cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn retrieve_cleartext_unchecked(&mut self, cleartext: &Cleartext64) -> u64 {
+        match self {
+            DynEngine::Core(engine) => engine.retrieve_cleartext_unchecked(cleartext),
+            DynEngine::Cleartext(engine) => engine.retrieve_cleartext_unchecked(cleartext),
+        }
+    }
+}
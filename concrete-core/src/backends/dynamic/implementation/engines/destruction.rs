@@ -0,0 +1,316 @@
+use crate::backends::core::entities::{Cleartext64, Plaintext64, PlaintextVector64};
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{
+    DynGlweCiphertext64, DynGlweSecretKey64, DynLweBootstrapKey64, DynLweCiphertext64,
+    DynLweKeyswitchKey64, DynLweSecretKey64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<Cleartext64> for DynEngine {
+    fn destroy(&mut self, entity: Cleartext64) -> Result<(), DestructionError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: Cleartext64) {
+        match self {
+            DynEngine::Core(engine) => engine.destroy_unchecked(entity),
+            DynEngine::Cleartext(engine) => engine.destroy_unchecked(entity),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynGlweCiphertext64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynGlweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynGlweCiphertext64::Core(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynGlweCiphertext64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynGlweCiphertext64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynGlweCiphertext64::Core(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (DynEngine::Cleartext(engine), DynGlweCiphertext64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynGlweSecretKey64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynGlweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynGlweSecretKey64::Core(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynGlweSecretKey64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynGlweSecretKey64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynGlweSecretKey64::Core(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (DynEngine::Cleartext(engine), DynGlweSecretKey64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynLweBootstrapKey64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweBootstrapKey64::Core(entity)) => engine
+                .destroy(*entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweBootstrapKey64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynLweBootstrapKey64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweBootstrapKey64::Core(entity)) => {
+                engine.destroy_unchecked(*entity)
+            }
+            (DynEngine::Cleartext(engine), DynLweBootstrapKey64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynLweCiphertext64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynLweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynLweCiphertext64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynLweKeyswitchKey64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweKeyswitchKey64::Core(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweKeyswitchKey64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynLweKeyswitchKey64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweKeyswitchKey64::Core(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (DynEngine::Cleartext(engine), DynLweKeyswitchKey64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<DynLweSecretKey64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: DynLweSecretKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweSecretKey64::Core(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweSecretKey64::Cleartext(entity)) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, entity) => Err(DestructionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[entity.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn destroy_unchecked(&mut self, entity: DynLweSecretKey64) {
+        match (self, entity) {
+            (DynEngine::Core(engine), DynLweSecretKey64::Core(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (DynEngine::Cleartext(engine), DynLweSecretKey64::Cleartext(entity)) => {
+                engine.destroy_unchecked(entity)
+            }
+            (engine, entity) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[entity.backend()])
+            ),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<Plaintext64> for DynEngine {
+    fn destroy(&mut self, entity: Plaintext64) -> Result<(), DestructionError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: Plaintext64) {
+        match self {
+            DynEngine::Core(engine) => engine.destroy_unchecked(entity),
+            DynEngine::Cleartext(engine) => engine.destroy_unchecked(entity),
+        }
+    }
+}
+
+/// # Description:
+/// Implementation of [`DestructionEngine`] for [`DynEngine`] that operates on 64 bits integers.
+impl DestructionEngine<PlaintextVector64> for DynEngine {
+    fn destroy(
+        &mut self,
+        entity: PlaintextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .destroy(entity)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: PlaintextVector64) {
+        match self {
+            DynEngine::Core(engine) => engine.destroy_unchecked(entity),
+            DynEngine::Cleartext(engine) => engine.destroy_unchecked(entity),
+        }
+    }
+}
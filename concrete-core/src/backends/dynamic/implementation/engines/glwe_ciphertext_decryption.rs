@@ -0,0 +1,98 @@
+use crate::backends::core::entities::PlaintextVector64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{DynGlweCiphertext64, DynGlweSecretKey64};
+use crate::specification::engines::{
+    GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`GlweCiphertextDecryptionEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextDecryptionEngine<DynGlweSecretKey64, DynGlweCiphertext64, PlaintextVector64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector = engine.decrypt_glwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector, plaintext_vector);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_glwe_ciphertext(
+        &mut self,
+        key: &DynGlweSecretKey64,
+        input: &DynGlweCiphertext64,
+    ) -> Result<PlaintextVector64, GlweCiphertextDecryptionError<Self::EngineError>> {
+        match (self, key, input) {
+            (
+                DynEngine::Core(engine),
+                DynGlweSecretKey64::Core(key),
+                DynGlweCiphertext64::Core(input),
+            ) => engine
+                .decrypt_glwe_ciphertext(key, input)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynGlweSecretKey64::Cleartext(key),
+                DynGlweCiphertext64::Cleartext(input),
+            ) => engine
+                .decrypt_glwe_ciphertext(key, input)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, key, input) => Err(GlweCiphertextDecryptionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[key.backend(), input.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn decrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &DynGlweSecretKey64,
+        input: &DynGlweCiphertext64,
+    ) -> PlaintextVector64 {
+        match (self, key, input) {
+            (
+                DynEngine::Core(engine),
+                DynGlweSecretKey64::Core(key),
+                DynGlweCiphertext64::Core(input),
+            ) => engine.decrypt_glwe_ciphertext_unchecked(key, input),
+            (
+                DynEngine::Cleartext(engine),
+                DynGlweSecretKey64::Cleartext(key),
+                DynGlweCiphertext64::Cleartext(input),
+            ) => engine.decrypt_glwe_ciphertext_unchecked(key, input),
+            (engine, key, input) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[key.backend(), input.backend()])
+            ),
+        }
+    }
+}
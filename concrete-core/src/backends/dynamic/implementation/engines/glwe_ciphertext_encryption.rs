@@ -0,0 +1,88 @@
+use crate::backends::core::entities::PlaintextVector64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{DynGlweCiphertext64, DynGlweSecretKey64};
+use crate::specification::engines::{
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`GlweCiphertextEncryptionEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl GlweCiphertextEncryptionEngine<DynGlweSecretKey64, PlaintextVector64, DynGlweCiphertext64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; polynomial_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_glwe_ciphertext(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(ciphertext.polynomial_size(), polynomial_size);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_glwe_ciphertext(
+        &mut self,
+        key: &DynGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<DynGlweCiphertext64, GlweCiphertextEncryptionError<Self::EngineError>> {
+        match (self, key) {
+            (DynEngine::Core(engine), DynGlweSecretKey64::Core(key)) => engine
+                .encrypt_glwe_ciphertext(key, input, noise)
+                .map(DynGlweCiphertext64::Core)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynGlweSecretKey64::Cleartext(key)) => engine
+                .encrypt_glwe_ciphertext(key, input, noise)
+                .map(DynGlweCiphertext64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, key) => Err(GlweCiphertextEncryptionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[key.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn encrypt_glwe_ciphertext_unchecked(
+        &mut self,
+        key: &DynGlweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> DynGlweCiphertext64 {
+        match (self, key) {
+            (DynEngine::Core(engine), DynGlweSecretKey64::Core(key)) => DynGlweCiphertext64::Core(
+                engine.encrypt_glwe_ciphertext_unchecked(key, input, noise),
+            ),
+            (DynEngine::Cleartext(engine), DynGlweSecretKey64::Cleartext(key)) => {
+                DynGlweCiphertext64::Cleartext(
+                    engine.encrypt_glwe_ciphertext_unchecked(key, input, noise),
+                )
+            }
+            (engine, key) => panic!("{}", DynError::mismatch(engine.backend(), &[key.backend()])),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::DynGlweSecretKey64;
+use crate::specification::engines::{GlweSecretKeyCreationEngine, GlweSecretKeyCreationError};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// # Description:
+/// Implementation of [`GlweSecretKeyCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl GlweSecretKeyCreationEngine<DynGlweSecretKey64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let glwe_dimension = GlweDimension(2);
+    /// let polynomial_size = PolynomialSize(4);
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let glwe_secret_key: DynGlweSecretKey64 =
+    ///     engine.create_glwe_secret_key(glwe_dimension, polynomial_size)?;
+    /// #
+    /// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+    /// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+    /// engine.destroy(glwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_glwe_secret_key(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<DynGlweSecretKey64, GlweSecretKeyCreationError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .map(DynGlweSecretKey64::Core)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .create_glwe_secret_key(glwe_dimension, polynomial_size)
+                .map(DynGlweSecretKey64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn create_glwe_secret_key_unchecked(
+        &mut self,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> DynGlweSecretKey64 {
+        match self {
+            DynEngine::Core(engine) => DynGlweSecretKey64::Core(
+                engine.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size),
+            ),
+            DynEngine::Cleartext(engine) => DynGlweSecretKey64::Cleartext(
+                engine.create_glwe_secret_key_unchecked(glwe_dimension, polynomial_size),
+            ),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{
+    DynGlweSecretKey64, DynLweBootstrapKey64, DynLweSecretKey64,
+};
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweBootstrapKeyCreationEngine<DynLweSecretKey64, DynGlweSecretKey64, DynLweBootstrapKey64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let lwe_sk: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: DynGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: DynLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.glwe_dimension(), glwe_dim);
+    /// assert_eq!(bsk.polynomial_size(), poly_size);
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.decomposition_base_log(), dec_bl);
+    /// assert_eq!(bsk.decomposition_level_count(), dec_lc);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &DynLweSecretKey64,
+        output_key: &DynGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<DynLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>> {
+        match (self, input_key, output_key) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(input_key),
+                DynGlweSecretKey64::Core(output_key),
+            ) => engine
+                .create_lwe_bootstrap_key(
+                    input_key,
+                    output_key,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                )
+                .map(|key| DynLweBootstrapKey64::Core(Box::new(key)))
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(input_key),
+                DynGlweSecretKey64::Cleartext(output_key),
+            ) => engine
+                .create_lwe_bootstrap_key(
+                    input_key,
+                    output_key,
+                    decomposition_base_log,
+                    decomposition_level_count,
+                    noise,
+                )
+                .map(DynLweBootstrapKey64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, input_key, output_key) => {
+                Err(LweBootstrapKeyCreationError::Engine(DynError::mismatch(
+                    engine.backend(),
+                    &[input_key.backend(), output_key.backend()],
+                )))
+            }
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &DynLweSecretKey64,
+        output_key: &DynGlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> DynLweBootstrapKey64 {
+        match (self, input_key, output_key) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(input_key),
+                DynGlweSecretKey64::Core(output_key),
+            ) => DynLweBootstrapKey64::Core(Box::new(engine.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            ))),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(input_key),
+                DynGlweSecretKey64::Cleartext(output_key),
+            ) => DynLweBootstrapKey64::Cleartext(engine.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )),
+            (engine, input_key, output_key) => panic!(
+                "{}",
+                DynError::mismatch(
+                    engine.backend(),
+                    &[input_key.backend(), output_key.backend()]
+                )
+            ),
+        }
+    }
+}
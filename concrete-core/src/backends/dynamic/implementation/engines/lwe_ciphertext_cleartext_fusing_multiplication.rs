@@ -0,0 +1,86 @@
+use crate::backends::core::entities::Cleartext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::DynLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<DynLweCiphertext64, Cleartext64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let cleartext_input = 12_u64;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let cleartext: Cleartext64 = engine.create_cleartext(&cleartext_input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(cleartext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        match (self, output) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(output)) => engine
+                .fuse_mul_lwe_ciphertext_cleartext(output, input)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(output)) => engine
+                .fuse_mul_lwe_ciphertext_cleartext(output, input)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, output) => Err(LweCiphertextCleartextFusingMultiplicationError::Engine(
+                DynError::mismatch(engine.backend(), &[output.backend()]),
+            )),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        match (self, output) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(output)) => {
+                engine.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input)
+            }
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(output)) => {
+                engine.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input)
+            }
+            (engine, output) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[output.backend()])
+            ),
+        }
+    }
+}
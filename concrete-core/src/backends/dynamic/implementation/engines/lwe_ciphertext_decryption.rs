@@ -0,0 +1,96 @@
+use crate::backends::core::entities::Plaintext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{DynLweCiphertext64, DynLweSecretKey64};
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDecryptionEngine<DynLweSecretKey64, DynLweCiphertext64, Plaintext64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // The encryption is exact when no noise is added
+    /// let noise = Variance(0.);
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext, plaintext);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// engine.destroy(decrypted_plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &DynLweSecretKey64,
+        input: &DynLweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        match (self, key, input) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(key),
+                DynLweCiphertext64::Core(input),
+            ) => engine
+                .decrypt_lwe_ciphertext(key, input)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(key),
+                DynLweCiphertext64::Cleartext(input),
+            ) => engine
+                .decrypt_lwe_ciphertext(key, input)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, key, input) => Err(LweCiphertextDecryptionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[key.backend(), input.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &DynLweSecretKey64,
+        input: &DynLweCiphertext64,
+    ) -> Plaintext64 {
+        match (self, key, input) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(key),
+                DynLweCiphertext64::Core(input),
+            ) => engine.decrypt_lwe_ciphertext_unchecked(key, input),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(key),
+                DynLweCiphertext64::Cleartext(input),
+            ) => engine.decrypt_lwe_ciphertext_unchecked(key, input),
+            (engine, key, input) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[key.backend(), input.backend()])
+            ),
+        }
+    }
+}
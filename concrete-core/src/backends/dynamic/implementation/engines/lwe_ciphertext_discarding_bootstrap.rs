@@ -0,0 +1,151 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{
+    DynGlweCiphertext64, DynLweBootstrapKey64, DynLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        DynLweBootstrapKey64,
+        DynGlweCiphertext64,
+        DynLweCiphertext64,
+        DynLweCiphertext64,
+    > for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let lwe_sk: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: DynGlweSecretKey64 = engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: DynLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// let lwe_sk_output: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let plaintext_vector = engine.create_plaintext_vector(&lut)?;
+    /// let acc = engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, noise)?;
+    /// let input = engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.zero_encrypt_lwe_ciphertext(&lwe_sk_output, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    ///
+    /// engine.destroy(lwe_sk)?;
+    /// engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(lwe_sk_output)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(plaintext_vector)?;
+    /// engine.destroy(acc)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+        acc: &DynGlweCiphertext64,
+        bsk: &DynLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        match (self, output, input, acc, bsk) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+                DynGlweCiphertext64::Core(acc),
+                DynLweBootstrapKey64::Core(bsk),
+            ) => engine
+                .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+                DynGlweCiphertext64::Cleartext(acc),
+                DynLweBootstrapKey64::Cleartext(bsk),
+            ) => engine
+                .discard_bootstrap_lwe_ciphertext(output, input, acc, bsk)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, output, input, acc, bsk) => Err(
+                LweCiphertextDiscardingBootstrapError::Engine(DynError::mismatch(
+                    engine.backend(),
+                    &[
+                        output.backend(),
+                        input.backend(),
+                        acc.backend(),
+                        bsk.backend(),
+                    ],
+                )),
+            ),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+        acc: &DynGlweCiphertext64,
+        bsk: &DynLweBootstrapKey64,
+    ) {
+        match (self, output, input, acc, bsk) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+                DynGlweCiphertext64::Core(acc),
+                DynLweBootstrapKey64::Core(bsk),
+            ) => engine.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+                DynGlweCiphertext64::Cleartext(acc),
+                DynLweBootstrapKey64::Cleartext(bsk),
+            ) => engine.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk),
+            (engine, output, input, acc, bsk) => panic!(
+                "{}",
+                DynError::mismatch(
+                    engine.backend(),
+                    &[
+                        output.backend(),
+                        input.backend(),
+                        acc.backend(),
+                        bsk.backend()
+                    ]
+                )
+            ),
+        }
+    }
+}
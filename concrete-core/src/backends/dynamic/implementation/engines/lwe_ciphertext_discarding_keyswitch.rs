@@ -0,0 +1,128 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{
+    DynLweCiphertext64, DynLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        DynLweKeyswitchKey64,
+        DynLweCiphertext64,
+        DynLweCiphertext64,
+    > for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let input_key: DynLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: DynLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let ciphertext_1 = engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.zero_encrypt_lwe_ciphertext(&output_key, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+        ksk: &DynLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        match (self, output, input, ksk) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+                DynLweKeyswitchKey64::Core(ksk),
+            ) => engine
+                .discard_keyswitch_lwe_ciphertext(output, input, ksk)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+                DynLweKeyswitchKey64::Cleartext(ksk),
+            ) => engine
+                .discard_keyswitch_lwe_ciphertext(output, input, ksk)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, output, input, ksk) => Err(LweCiphertextDiscardingKeyswitchError::Engine(
+                DynError::mismatch(
+                    engine.backend(),
+                    &[output.backend(), input.backend(), ksk.backend()],
+                ),
+            )),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+        ksk: &DynLweKeyswitchKey64,
+    ) {
+        match (self, output, input, ksk) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+                DynLweKeyswitchKey64::Core(ksk),
+            ) => engine.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+                DynLweKeyswitchKey64::Cleartext(ksk),
+            ) => engine.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk),
+            (engine, output, input, ksk) => panic!(
+                "{}",
+                DynError::mismatch(
+                    engine.backend(),
+                    &[output.backend(), input.backend(), ksk.backend()]
+                )
+            ),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::backends::core::entities::Plaintext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{DynLweCiphertext64, DynLweSecretKey64};
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextEncryptionEngine<DynLweSecretKey64, Plaintext64, DynLweCiphertext64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &DynLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<DynLweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        match (self, key) {
+            (DynEngine::Core(engine), DynLweSecretKey64::Core(key)) => engine
+                .encrypt_lwe_ciphertext(key, input, noise)
+                .map(DynLweCiphertext64::Core)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweSecretKey64::Cleartext(key)) => engine
+                .encrypt_lwe_ciphertext(key, input, noise)
+                .map(DynLweCiphertext64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, key) => Err(LweCiphertextEncryptionError::Engine(DynError::mismatch(
+                engine.backend(),
+                &[key.backend()],
+            ))),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &DynLweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> DynLweCiphertext64 {
+        match (self, key) {
+            (DynEngine::Core(engine), DynLweSecretKey64::Core(key)) => {
+                DynLweCiphertext64::Core(engine.encrypt_lwe_ciphertext_unchecked(key, input, noise))
+            }
+            (DynEngine::Cleartext(engine), DynLweSecretKey64::Cleartext(key)) => {
+                DynLweCiphertext64::Cleartext(
+                    engine.encrypt_lwe_ciphertext_unchecked(key, input, noise),
+                )
+            }
+            (engine, key) => panic!("{}", DynError::mismatch(engine.backend(), &[key.backend()])),
+        }
+    }
+}
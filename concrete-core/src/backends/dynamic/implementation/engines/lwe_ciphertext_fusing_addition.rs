@@ -0,0 +1,96 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::DynLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextFusingAdditionEngine<DynLweCiphertext64, DynLweCiphertext64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 7_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = engine.create_plaintext(&input_2)?;
+    /// let mut ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext_1)?;
+    /// engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        match (self, output, input) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+            ) => engine
+                .fuse_add_lwe_ciphertext(output, input)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+            ) => engine
+                .fuse_add_lwe_ciphertext(output, input)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, output, input) => Err(LweCiphertextFusingAdditionError::Engine(
+                DynError::mismatch(engine.backend(), &[output.backend(), input.backend()]),
+            )),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut DynLweCiphertext64,
+        input: &DynLweCiphertext64,
+    ) {
+        match (self, output, input) {
+            (
+                DynEngine::Core(engine),
+                DynLweCiphertext64::Core(output),
+                DynLweCiphertext64::Core(input),
+            ) => engine.fuse_add_lwe_ciphertext_unchecked(output, input),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweCiphertext64::Cleartext(output),
+                DynLweCiphertext64::Cleartext(input),
+            ) => engine.fuse_add_lwe_ciphertext_unchecked(output, input),
+            (engine, output, input) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[output.backend(), input.backend()])
+            ),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::DynLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextFusingNegationEngine, LweCiphertextFusingNegationError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingNegationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextFusingNegationEngine<DynLweCiphertext64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = engine.create_plaintext(&input)?;
+    /// let mut ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_neg_lwe_ciphertext(&mut ciphertext)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    ///
+    /// engine.destroy(key)?;
+    /// engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_neg_lwe_ciphertext(
+        &mut self,
+        input: &mut DynLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingNegationError<Self::EngineError>> {
+        match (self, input) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(input)) => engine
+                .fuse_neg_lwe_ciphertext(input)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(input)) => engine
+                .fuse_neg_lwe_ciphertext(input)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, input) => Err(LweCiphertextFusingNegationError::Engine(
+                DynError::mismatch(engine.backend(), &[input.backend()]),
+            )),
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn fuse_neg_lwe_ciphertext_unchecked(&mut self, input: &mut DynLweCiphertext64) {
+        match (self, input) {
+            (DynEngine::Core(engine), DynLweCiphertext64::Core(input)) => {
+                engine.fuse_neg_lwe_ciphertext_unchecked(input)
+            }
+            (DynEngine::Cleartext(engine), DynLweCiphertext64::Cleartext(input)) => {
+                engine.fuse_neg_lwe_ciphertext_unchecked(input)
+            }
+            (engine, input) => panic!(
+                "{}",
+                DynError::mismatch(engine.backend(), &[input.backend()])
+            ),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::{DynLweKeyswitchKey64, DynLweSecretKey64};
+use crate::specification::engines::{LweKeyswitchKeyCreationEngine, LweKeyswitchKeyCreationError};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweKeyswitchKeyCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweKeyswitchKeyCreationEngine<DynLweSecretKey64, DynLweSecretKey64, DynLweKeyswitchKey64>
+    for DynEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(4);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let input_key: DynLweSecretKey64 = engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: DynLweSecretKey64 = engine.create_lwe_secret_key(output_lwe_dimension)?;
+    ///
+    /// let keyswitch_key: DynLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     noise,
+    /// )?;
+    /// #
+    /// assert_eq!(
+    ///     keyswitch_key.decomposition_level_count(),
+    ///     decomposition_level_count
+    /// );
+    /// assert_eq!(keyswitch_key.decomposition_base_log(), decomposition_base_log);
+    /// assert_eq!(keyswitch_key.input_lwe_dimension(), input_lwe_dimension);
+    /// assert_eq!(keyswitch_key.output_lwe_dimension(), output_lwe_dimension);
+    ///
+    /// engine.destroy(input_key)?;
+    /// engine.destroy(output_key)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_keyswitch_key(
+        &mut self,
+        input_key: &DynLweSecretKey64,
+        output_key: &DynLweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<DynLweKeyswitchKey64, LweKeyswitchKeyCreationError<Self::EngineError>> {
+        match (self, input_key, output_key) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(input_key),
+                DynLweSecretKey64::Core(output_key),
+            ) => engine
+                .create_lwe_keyswitch_key(
+                    input_key,
+                    output_key,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                    noise,
+                )
+                .map(DynLweKeyswitchKey64::Core)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(input_key),
+                DynLweSecretKey64::Cleartext(output_key),
+            ) => engine
+                .create_lwe_keyswitch_key(
+                    input_key,
+                    output_key,
+                    decomposition_level_count,
+                    decomposition_base_log,
+                    noise,
+                )
+                .map(DynLweKeyswitchKey64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+            (engine, input_key, output_key) => {
+                Err(LweKeyswitchKeyCreationError::Engine(DynError::mismatch(
+                    engine.backend(),
+                    &[input_key.backend(), output_key.backend()],
+                )))
+            }
+        }
+    }
+
+    /// # Safety:
+    /// This method panics if an entity is not held by the backend of the engine, see
+    /// [`DynEngine`](DynEngine#safety).
+    unsafe fn create_lwe_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &DynLweSecretKey64,
+        output_key: &DynLweSecretKey64,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> DynLweKeyswitchKey64 {
+        match (self, input_key, output_key) {
+            (
+                DynEngine::Core(engine),
+                DynLweSecretKey64::Core(input_key),
+                DynLweSecretKey64::Core(output_key),
+            ) => DynLweKeyswitchKey64::Core(engine.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )),
+            (
+                DynEngine::Cleartext(engine),
+                DynLweSecretKey64::Cleartext(input_key),
+                DynLweSecretKey64::Cleartext(output_key),
+            ) => DynLweKeyswitchKey64::Cleartext(engine.create_lwe_keyswitch_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_level_count,
+                decomposition_base_log,
+                noise,
+            )),
+            (engine, input_key, output_key) => panic!(
+                "{}",
+                DynError::mismatch(
+                    engine.backend(),
+                    &[input_key.backend(), output_key.backend()]
+                )
+            ),
+        }
+    }
+}
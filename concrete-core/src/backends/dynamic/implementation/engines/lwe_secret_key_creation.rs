@@ -0,0 +1,57 @@
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::backends::dynamic::implementation::entities::DynLweSecretKey64;
+use crate::specification::engines::{LweSecretKeyCreationEngine, LweSecretKeyCreationError};
+use concrete_commons::parameters::LweDimension;
+
+/// # Description:
+/// Implementation of [`LweSecretKeyCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl LweSecretKeyCreationEngine<DynLweSecretKey64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let lwe_dimension = LweDimension(6);
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let lwe_secret_key: DynLweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+    /// #
+    /// assert_eq!(lwe_secret_key.lwe_dimension(), lwe_dimension);
+    /// engine.destroy(lwe_secret_key)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_secret_key(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> Result<DynLweSecretKey64, LweSecretKeyCreationError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .create_lwe_secret_key(lwe_dimension)
+                .map(DynLweSecretKey64::Core)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .create_lwe_secret_key(lwe_dimension)
+                .map(DynLweSecretKey64::Cleartext)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn create_lwe_secret_key_unchecked(
+        &mut self,
+        lwe_dimension: LweDimension,
+    ) -> DynLweSecretKey64 {
+        match self {
+            DynEngine::Core(engine) => {
+                DynLweSecretKey64::Core(engine.create_lwe_secret_key_unchecked(lwe_dimension))
+            }
+            DynEngine::Cleartext(engine) => {
+                DynLweSecretKey64::Cleartext(engine.create_lwe_secret_key_unchecked(lwe_dimension))
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the dynamic backend.
+
+use crate::backends::cleartext::engines::{CleartextEngine, CleartextError};
+use crate::backends::core::engines::{CoreEngine, CoreError};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The backends the [`DynEngine`] can select at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynBackend {
+    /// The `core` backend.
+    Core,
+    /// The `cleartext` mock backend.
+    Cleartext,
+}
+
+impl Display for DynBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynBackend::Core => write!(f, "core"),
+            DynBackend::Cleartext => write!(f, "cleartext"),
+        }
+    }
+}
+
+impl FromStr for DynBackend {
+    type Err = DynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "core" => Ok(DynBackend::Core),
+            "cleartext" => Ok(DynBackend::Cleartext),
+            _ => Err(DynError::UnknownBackend(s.to_string())),
+        }
+    }
+}
+
+/// The error which can occur in the execution of FHE operations, due to the dynamic
+/// implementation.
+///
+/// # Note:
+///
+/// Apart from the errors of the selected backend, those errors are raised when the entities
+/// given to an operation are not held by the backend of the engine.
+#[derive(Debug)]
+pub enum DynError {
+    Core(CoreError),
+    Cleartext(CleartextError),
+    BackendMismatch {
+        expected: DynBackend,
+        found: DynBackend,
+    },
+    UnknownBackend(String),
+}
+impl Display for DynError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynError::Core(error) => write!(f, "{}", error),
+            DynError::Cleartext(error) => write!(f, "{}", error),
+            DynError::BackendMismatch { expected, found } => {
+                write!(
+                    f,
+                    "An entity of the `{}` backend was given to the `{}` engine.",
+                    found, expected
+                )
+            }
+            DynError::UnknownBackend(name) => {
+                write!(f, "The backend `{}` is not known.", name)
+            }
+        }
+    }
+}
+impl Error for DynError {}
+
+impl DynError {
+    // Returns the mismatch error between the backend of the engine and the first entity backend
+    // differing from it.
+    pub(crate) fn mismatch(expected: DynBackend, entities: &[DynBackend]) -> DynError {
+        let found = entities
+            .iter()
+            .copied()
+            .find(|backend| *backend != expected)
+            .expect("No entity backend differs from the engine backend.");
+        DynError::BackendMismatch { expected, found }
+    }
+}
+
+/// The main engine exposed by the dynamic backend.
+///
+/// This engine forwards the operations to a backend selected at runtime, which makes it possible
+/// to choose the backend from a configuration, without monomorphizing the application for each
+/// backend. The entities it creates carry the backend which holds them, and an operation fails
+/// with a [`DynError::BackendMismatch`] error when given an entity of another backend.
+///
+/// # Note:
+///
+/// Only a subset of the operations of the selected backends are forwarded, on 64 bits entities
+/// encrypted under binary keys. See the [module documentation](crate::backends::dynamic) for the
+/// list of the supported entities and operations.
+///
+/// # Safety:
+///
+/// The unchecked entry points can not return this error. Instead, they panic with the message of
+/// the [`DynError::BackendMismatch`] error when given an entity of another backend, before any
+/// operation of the selected backend is executed.
+///
+/// # Example:
+/// ```
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // The backend would typically be read from a configuration.
+/// let backend: DynBackend = "cleartext".parse()?;
+/// let mut engine = DynEngine::with_backend(backend)?;
+/// assert_eq!(engine.backend(), DynBackend::Cleartext);
+///
+/// let key: DynLweSecretKey64 = engine.create_lwe_secret_key(LweDimension(2))?;
+/// let plaintext = engine.create_plaintext(&(3_u64 << 50))?;
+/// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, Variance(0.))?;
+/// assert_eq!(ciphertext.backend(), DynBackend::Cleartext);
+///
+/// // Entities can not be mixed across backends.
+/// let mut other_engine = DynEngine::with_backend(DynBackend::Core)?;
+/// let result = other_engine.decrypt_lwe_ciphertext(&key, &ciphertext);
+/// assert!(matches!(
+///     result,
+///     Err(LweCiphertextDecryptionError::Engine(
+///         DynError::BackendMismatch { .. }
+///     ))
+/// ));
+///
+/// engine.destroy(key)?;
+/// engine.destroy(plaintext)?;
+/// engine.destroy(ciphertext)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub enum DynEngine {
+    /// An engine of the `core` backend.
+    Core(Box<CoreEngine>),
+    /// An engine of the `cleartext` backend.
+    Cleartext(Box<CleartextEngine>),
+}
+
+impl DynEngine {
+    /// Creates a new engine, forwarding the operations to the given backend.
+    pub fn with_backend(backend: DynBackend) -> Result<DynEngine, DynError> {
+        match backend {
+            DynBackend::Core => CoreEngine::new()
+                .map(|engine| DynEngine::Core(Box::new(engine)))
+                .map_err(DynError::Core),
+            DynBackend::Cleartext => CleartextEngine::new()
+                .map(|engine| DynEngine::Cleartext(Box::new(engine)))
+                .map_err(DynError::Cleartext),
+        }
+    }
+
+    /// Returns the backend the operations are forwarded to.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynEngine::Core(_) => DynBackend::Core,
+            DynEngine::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
+
+impl AbstractEngineSeal for DynEngine {}
+impl AbstractEngine for DynEngine {
+    type EngineError = DynError;
+
+    /// Creates a new engine forwarding the operations to the `core` backend.
+    fn new() -> Result<Self, Self::EngineError> {
+        DynEngine::with_backend(DynBackend::Core)
+    }
+}
+
+mod cleartext_creation;
+mod cleartext_retrieval;
+mod destruction;
+mod glwe_ciphertext_decryption;
+mod glwe_ciphertext_encryption;
+mod glwe_secret_key_creation;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_fusing_negation;
+mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_creation;
+mod lwe_secret_key_creation;
+mod plaintext_creation;
+mod plaintext_vector_creation;
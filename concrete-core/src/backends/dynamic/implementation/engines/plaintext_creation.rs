@@ -0,0 +1,45 @@
+use crate::backends::core::entities::Plaintext64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::specification::engines::{PlaintextCreationEngine, PlaintextCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl PlaintextCreationEngine<u64, Plaintext64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let plaintext: Plaintext64 = engine.create_plaintext(&input)?;
+    /// engine.destroy(plaintext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext(
+        &mut self,
+        value: &u64,
+    ) -> Result<Plaintext64, PlaintextCreationError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .create_plaintext(value)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .create_plaintext(value)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn create_plaintext_unchecked(&mut self, value: &u64) -> Plaintext64 {
+        match self {
+            DynEngine::Core(engine) => engine.create_plaintext_unchecked(value),
+            DynEngine::Cleartext(engine) => engine.create_plaintext_unchecked(value),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::backends::core::entities::PlaintextVector64;
+use crate::backends::dynamic::implementation::engines::{DynEngine, DynError};
+use crate::specification::engines::{PlaintextVectorCreationEngine, PlaintextVectorCreationError};
+
+/// # Description:
+/// Implementation of [`PlaintextVectorCreationEngine`] for [`DynEngine`] that operates on
+/// 64 bits integers.
+impl PlaintextVectorCreationEngine<u64, PlaintextVector64> for DynEngine {
+    /// # Example:
+    /// ```
+    /// use concrete_commons::parameters::PlaintextCount;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    ///
+    /// let mut engine = DynEngine::new()?;
+    /// let plaintext_vector: PlaintextVector64 = engine.create_plaintext_vector(&input)?;
+    /// #
+    /// assert_eq!(plaintext_vector.plaintext_count(), PlaintextCount(3));
+    /// engine.destroy(plaintext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_plaintext_vector(
+        &mut self,
+        values: &[u64],
+    ) -> Result<PlaintextVector64, PlaintextVectorCreationError<Self::EngineError>> {
+        match self {
+            DynEngine::Core(engine) => engine
+                .create_plaintext_vector(values)
+                .map_err(|error| error.map_engine_error(DynError::Core)),
+            DynEngine::Cleartext(engine) => engine
+                .create_plaintext_vector(values)
+                .map_err(|error| error.map_engine_error(DynError::Cleartext)),
+        }
+    }
+
+    unsafe fn create_plaintext_vector_unchecked(&mut self, values: &[u64]) -> PlaintextVector64 {
+        match self {
+            DynEngine::Core(engine) => engine.create_plaintext_vector_unchecked(values),
+            DynEngine::Cleartext(engine) => engine.create_plaintext_vector_unchecked(values),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::backends::cleartext::entities::CleartextGlweCiphertext64;
+use crate::backends::core::entities::GlweCiphertext64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, GlweCiphertextEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE ciphertext with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynGlweCiphertext64 {
+    /// A ciphertext of the `core` backend.
+    Core(GlweCiphertext64),
+    /// A ciphertext of the `cleartext` backend.
+    Cleartext(CleartextGlweCiphertext64),
+}
+impl AbstractEntity for DynGlweCiphertext64 {
+    type Kind = GlweCiphertextKind;
+}
+impl GlweCiphertextEntity for DynGlweCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        match self {
+            DynGlweCiphertext64::Core(ciphertext) => ciphertext.glwe_dimension(),
+            DynGlweCiphertext64::Cleartext(ciphertext) => ciphertext.glwe_dimension(),
+        }
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            DynGlweCiphertext64::Core(ciphertext) => ciphertext.polynomial_size(),
+            DynGlweCiphertext64::Cleartext(ciphertext) => ciphertext.polynomial_size(),
+        }
+    }
+}
+
+impl DynGlweCiphertext64 {
+    /// Returns the backend holding the ciphertext.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynGlweCiphertext64::Core(_) => DynBackend::Core,
+            DynGlweCiphertext64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
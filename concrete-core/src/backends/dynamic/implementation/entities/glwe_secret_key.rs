@@ -0,0 +1,46 @@
+use crate::backends::cleartext::entities::CleartextGlweSecretKey64;
+use crate::backends::core::entities::GlweSecretKey64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, GlweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, GlweSecretKeyEntity};
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A structure representing a GLWE secret key with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynGlweSecretKey64 {
+    /// A key of the `core` backend.
+    Core(GlweSecretKey64),
+    /// A key of the `cleartext` backend.
+    Cleartext(CleartextGlweSecretKey64),
+}
+impl AbstractEntity for DynGlweSecretKey64 {
+    type Kind = GlweSecretKeyKind;
+}
+impl GlweSecretKeyEntity for DynGlweSecretKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        match self {
+            DynGlweSecretKey64::Core(key) => key.glwe_dimension(),
+            DynGlweSecretKey64::Cleartext(key) => key.glwe_dimension(),
+        }
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            DynGlweSecretKey64::Core(key) => key.polynomial_size(),
+            DynGlweSecretKey64::Cleartext(key) => key.polynomial_size(),
+        }
+    }
+}
+
+impl DynGlweSecretKey64 {
+    /// Returns the backend holding the key.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynGlweSecretKey64::Core(_) => DynBackend::Core,
+            DynGlweSecretKey64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
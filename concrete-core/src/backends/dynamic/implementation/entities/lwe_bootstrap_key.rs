@@ -0,0 +1,70 @@
+use crate::backends::cleartext::entities::CleartextLweBootstrapKey64;
+use crate::backends::core::entities::FourierLweBootstrapKey64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynLweBootstrapKey64 {
+    /// A key of the `core` backend.
+    Core(Box<FourierLweBootstrapKey64>),
+    /// A key of the `cleartext` backend.
+    Cleartext(CleartextLweBootstrapKey64),
+}
+impl AbstractEntity for DynLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for DynLweBootstrapKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        match self {
+            DynLweBootstrapKey64::Core(key) => key.glwe_dimension(),
+            DynLweBootstrapKey64::Cleartext(key) => key.glwe_dimension(),
+        }
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            DynLweBootstrapKey64::Core(key) => key.polynomial_size(),
+            DynLweBootstrapKey64::Cleartext(key) => key.polynomial_size(),
+        }
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        match self {
+            DynLweBootstrapKey64::Core(key) => key.input_lwe_dimension(),
+            DynLweBootstrapKey64::Cleartext(key) => key.input_lwe_dimension(),
+        }
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match self {
+            DynLweBootstrapKey64::Core(key) => key.decomposition_base_log(),
+            DynLweBootstrapKey64::Cleartext(key) => key.decomposition_base_log(),
+        }
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match self {
+            DynLweBootstrapKey64::Core(key) => key.decomposition_level_count(),
+            DynLweBootstrapKey64::Cleartext(key) => key.decomposition_level_count(),
+        }
+    }
+}
+
+impl DynLweBootstrapKey64 {
+    /// Returns the backend holding the key.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynLweBootstrapKey64::Core(_) => DynBackend::Core,
+            DynLweBootstrapKey64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
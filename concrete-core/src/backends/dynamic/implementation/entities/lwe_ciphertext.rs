@@ -0,0 +1,39 @@
+use crate::backends::cleartext::entities::CleartextLweCiphertext64;
+use crate::backends::core::entities::LweCiphertext64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynLweCiphertext64 {
+    /// A ciphertext of the `core` backend.
+    Core(LweCiphertext64),
+    /// A ciphertext of the `cleartext` backend.
+    Cleartext(CleartextLweCiphertext64),
+}
+impl AbstractEntity for DynLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for DynLweCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        match self {
+            DynLweCiphertext64::Core(ciphertext) => ciphertext.lwe_dimension(),
+            DynLweCiphertext64::Cleartext(ciphertext) => ciphertext.lwe_dimension(),
+        }
+    }
+}
+
+impl DynLweCiphertext64 {
+    /// Returns the backend holding the ciphertext.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynLweCiphertext64::Core(_) => DynBackend::Core,
+            DynLweCiphertext64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
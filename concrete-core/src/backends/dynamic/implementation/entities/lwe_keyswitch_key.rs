@@ -0,0 +1,61 @@
+use crate::backends::cleartext::entities::CleartextLweKeyswitchKey64;
+use crate::backends::core::entities::LweKeyswitchKey64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynLweKeyswitchKey64 {
+    /// A key of the `core` backend.
+    Core(LweKeyswitchKey64),
+    /// A key of the `cleartext` backend.
+    Cleartext(CleartextLweKeyswitchKey64),
+}
+impl AbstractEntity for DynLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for DynLweKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        match self {
+            DynLweKeyswitchKey64::Core(key) => key.input_lwe_dimension(),
+            DynLweKeyswitchKey64::Cleartext(key) => key.input_lwe_dimension(),
+        }
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        match self {
+            DynLweKeyswitchKey64::Core(key) => key.output_lwe_dimension(),
+            DynLweKeyswitchKey64::Cleartext(key) => key.output_lwe_dimension(),
+        }
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        match self {
+            DynLweKeyswitchKey64::Core(key) => key.decomposition_level_count(),
+            DynLweKeyswitchKey64::Cleartext(key) => key.decomposition_level_count(),
+        }
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        match self {
+            DynLweKeyswitchKey64::Core(key) => key.decomposition_base_log(),
+            DynLweKeyswitchKey64::Cleartext(key) => key.decomposition_base_log(),
+        }
+    }
+}
+
+impl DynLweKeyswitchKey64 {
+    /// Returns the backend holding the key.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynLweKeyswitchKey64::Core(_) => DynBackend::Core,
+            DynLweKeyswitchKey64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use crate::backends::cleartext::entities::CleartextLweSecretKey64;
+use crate::backends::core::entities::LweSecretKey64;
+use crate::backends::dynamic::implementation::engines::DynBackend;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweSecretKeyKind};
+use crate::specification::entities::{AbstractEntity, LweSecretKeyEntity};
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE secret key with 64 bits of precision, held by one of the
+/// backends of the [`DynEngine`](crate::backends::dynamic::engines::DynEngine).
+#[derive(Debug, Clone, PartialEq)]
+pub enum DynLweSecretKey64 {
+    /// A key of the `core` backend.
+    Core(LweSecretKey64),
+    /// A key of the `cleartext` backend.
+    Cleartext(CleartextLweSecretKey64),
+}
+impl AbstractEntity for DynLweSecretKey64 {
+    type Kind = LweSecretKeyKind;
+}
+impl LweSecretKeyEntity for DynLweSecretKey64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        match self {
+            DynLweSecretKey64::Core(key) => key.lwe_dimension(),
+            DynLweSecretKey64::Cleartext(key) => key.lwe_dimension(),
+        }
+    }
+}
+
+impl DynLweSecretKey64 {
+    /// Returns the backend holding the key.
+    pub fn backend(&self) -> DynBackend {
+        match self {
+            DynLweSecretKey64::Core(_) => DynBackend::Core,
+            DynLweSecretKey64::Cleartext(_) => DynBackend::Cleartext,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! A module containing the dynamic backend implementation.
+//!
+//! This module contains a backend forwarding the operations to another backend selected at
+//! runtime, which makes it possible for plugins and configuration-driven services to choose their
+//! backend without being generic over the engines. Its entities carry the backend which holds them,
+//! and mixing entities of different backends in an operation results in an explicit error.
+//!
+//! The plaintexts and cleartexts used along with this backend are the ones of the `core` backend.
+//! As of now, the `core` and `cleartext` backends can be selected.
+//!
+//! # Scope:
+//!
+//! This backend only forwards the operations needed to evaluate a programmable bootstrapping based
+//! circuit, and does not implement most of the engine traits shared by the `core` and `cleartext`
+//! backends. Only the following entities are supported, all of them with 64 bits of precision and
+//! binary keys:
+//!
+//! + LWE and GLWE secret keys, LWE and GLWE ciphertexts.
+//! + LWE keyswitch keys and LWE bootstrap keys in the standard domain.
+//!
+//! The supported operations are the creation of those keys, the encryption and decryption of the
+//! LWE and GLWE ciphertexts, the fusing addition, negation, plaintext addition and cleartext
+//! multiplication of LWE ciphertexts, and the discarding keyswitch and bootstrap of LWE
+//! ciphertexts. The ciphertext vectors, the 32 bits entities, the other key flavors, the seeded and
+//! compressed entities, the views and the serialization are not supported, and must be used through
+//! the engines of the backends themselves. Supporting another entity amounts to adding an enum
+//! holding one variant per backend, and engine implementations matching on those variants.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
@@ -0,0 +1,37 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    DestructionEngine, DestructionError, EntityDeserializationEngine, EntityDeserializationError,
+    EntitySerializationEngine, EntitySerializationError,
+};
+use crate::specification::entities::AbstractEntity;
+
+instrument_engine!(
+    DestructionEngine<Entity>
+    where {
+        Entity: AbstractEntity,
+    }
+    fn destroy, destroy_unchecked(entity: Entity) -> Result<(), DestructionError>
+    records []
+);
+
+instrument_engine!(
+    EntityDeserializationEngine<Serialized, Entity>
+    where {
+        Entity: AbstractEntity,
+    }
+    fn deserialize, deserialize_unchecked(
+        serialized: Serialized,
+    ) -> Result<Entity, EntityDeserializationError>
+    records []
+);
+
+instrument_engine!(
+    EntitySerializationEngine<Entity, Serialized>
+    where {
+        Entity: AbstractEntity,
+    }
+    fn serialize, serialize_unchecked(
+        entity: &Entity,
+    ) -> Result<Serialized, EntitySerializationError>
+    records []
+);
@@ -0,0 +1,117 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    GgswCiphertextConversionEngine, GgswCiphertextConversionError,
+    GgswCiphertextScalarDiscardingEncryptionEngine, GgswCiphertextScalarDiscardingEncryptionError,
+    GgswCiphertextScalarEncryptionEngine, GgswCiphertextScalarEncryptionError,
+    GgswCiphertextScalarTrivialEncryptionEngine, GgswCiphertextScalarTrivialEncryptionError,
+    GgswCiphertextVectorScalarEncryptionEngine, GgswCiphertextVectorScalarEncryptionError,
+};
+use crate::specification::entities::{
+    GgswCiphertextEntity, GgswCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextEntity,
+    PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+instrument_engine!(
+    GgswCiphertextConversionEngine<Input, Output>
+    where {
+        Input: GgswCiphertextEntity,
+        Output: GgswCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_ggsw_ciphertext, convert_ggsw_ciphertext_unchecked(
+        input: &Input,
+    ) -> Result<Output, GgswCiphertextConversionError>
+    records [
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.decomposition_level_count,
+        input.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GgswCiphertextScalarDiscardingEncryptionEngine<SecretKey, Plaintext, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: GgswCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn discard_encrypt_scalar_ggsw_ciphertext, discard_encrypt_scalar_ggsw_ciphertext_unchecked(
+        key: &SecretKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<(), GgswCiphertextScalarDiscardingEncryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        output.glwe_dimension,
+        output.polynomial_size,
+        output.decomposition_level_count,
+        output.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GgswCiphertextScalarEncryptionEngine<SecretKey, Plaintext, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: GgswCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_scalar_ggsw_ciphertext, encrypt_scalar_ggsw_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<Ciphertext, GgswCiphertextScalarEncryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GgswCiphertextScalarTrivialEncryptionEngine<Plaintext, Ciphertext>
+    where {
+        Plaintext: PlaintextEntity,
+        Ciphertext: GgswCiphertextEntity,
+    }
+    fn trivially_encrypt_scalar_ggsw_ciphertext, trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, GgswCiphertextScalarTrivialEncryptionError>
+    records [polynomial_size, glwe_size, decomposition_level_count, decomposition_base_log]
+);
+
+instrument_engine!(
+    GgswCiphertextVectorScalarEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: GgswCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_scalar_ggsw_ciphertext_vector, encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CiphertextVector, GgswCiphertextVectorScalarEncryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        input.plaintext_count,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
@@ -0,0 +1,433 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    GlweCiphertextConsumingRetrievalEngine, GlweCiphertextConsumingRetrievalError,
+    GlweCiphertextConversionEngine, GlweCiphertextConversionError, GlweCiphertextCreationEngine,
+    GlweCiphertextCreationError, GlweCiphertextDecryptionEngine, GlweCiphertextDecryptionError,
+    GlweCiphertextDiscardingConversionEngine, GlweCiphertextDiscardingConversionError,
+    GlweCiphertextDiscardingDecryptionEngine, GlweCiphertextDiscardingDecryptionError,
+    GlweCiphertextDiscardingEncryptionEngine, GlweCiphertextDiscardingEncryptionError,
+    GlweCiphertextDiscardingMultiplicationEngine, GlweCiphertextDiscardingMultiplicationError,
+    GlweCiphertextEncryptionEngine, GlweCiphertextEncryptionError,
+    GlweCiphertextGgswCiphertextExternalProductEngine,
+    GlweCiphertextGgswCiphertextExternalProductError, GlweCiphertextVectorConversionEngine,
+    GlweCiphertextVectorConversionError, GlweCiphertextVectorDecryptionEngine,
+    GlweCiphertextVectorDecryptionError, GlweCiphertextVectorDiscardingConversionEngine,
+    GlweCiphertextVectorDiscardingConversionError, GlweCiphertextVectorDiscardingDecryptionEngine,
+    GlweCiphertextVectorDiscardingDecryptionError, GlweCiphertextVectorDiscardingEncryptionEngine,
+    GlweCiphertextVectorDiscardingEncryptionError, GlweCiphertextVectorEncryptionEngine,
+    GlweCiphertextVectorEncryptionError, GlweCiphertextVectorZeroEncryptionEngine,
+    GlweCiphertextVectorZeroEncryptionError, GlweCiphertextZeroEncryptionEngine,
+    GlweCiphertextZeroEncryptionError, GlweCiphertextsGgswCiphertextCmuxEngine,
+    GlweCiphertextsGgswCiphertextCmuxError, GlweSeededCiphertextEncryptionEngine,
+    GlweSeededCiphertextEncryptionError, GlweSeededCiphertextExpansionEngine,
+    GlweSeededCiphertextExpansionError, GlweSeededCiphertextVectorEncryptionEngine,
+    GlweSeededCiphertextVectorEncryptionError, GlweSeededCiphertextVectorExpansionEngine,
+    GlweSeededCiphertextVectorExpansionError,
+};
+use crate::specification::entities::{
+    GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextVectorEntity,
+    GlweRelinearizationKeyEntity, GlweSecretKeyEntity, GlweSeededCiphertextEntity,
+    GlweSeededCiphertextVectorEntity, PlaintextEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{GlweCiphertextCount, PolynomialSize};
+
+instrument_engine!(
+    GlweCiphertextConsumingRetrievalEngine<Ciphertext, Container>
+    where {
+        Ciphertext: GlweCiphertextEntity,
+    }
+    fn consume_retrieve_glwe_ciphertext, consume_retrieve_glwe_ciphertext_unchecked(
+        ciphertext: Ciphertext,
+    ) -> Result<Container, GlweCiphertextConsumingRetrievalError>
+    records [ciphertext.glwe_dimension, ciphertext.polynomial_size]
+);
+
+instrument_engine!(
+    GlweCiphertextConversionEngine<Input, Output>
+    where {
+        Input: GlweCiphertextEntity,
+        Output: GlweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_glwe_ciphertext, convert_glwe_ciphertext_unchecked(
+        input: &Input,
+    ) -> Result<Output, GlweCiphertextConversionError>
+    records [input.glwe_dimension, input.polynomial_size]
+);
+
+instrument_engine!(
+    GlweCiphertextCreationEngine<Container, Ciphertext>
+    where {
+        Ciphertext: GlweCiphertextEntity,
+    }
+    fn create_glwe_ciphertext, create_glwe_ciphertext_unchecked(
+        container: Container,
+        polynomial_size: PolynomialSize,
+    ) -> Result<Ciphertext, GlweCiphertextCreationError>
+    records [polynomial_size]
+);
+
+instrument_engine!(
+    GlweCiphertextDecryptionEngine<SecretKey, Ciphertext, PlaintextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn decrypt_glwe_ciphertext, decrypt_glwe_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<PlaintextVector, GlweCiphertextDecryptionError>
+    records [key.glwe_dimension, key.polynomial_size, input.glwe_dimension, input.polynomial_size]
+);
+
+instrument_engine!(
+    GlweCiphertextDiscardingConversionEngine<Input, Output>
+    where {
+        Input: GlweCiphertextEntity,
+        Output: GlweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_glwe_ciphertext, discard_convert_glwe_ciphertext_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), GlweCiphertextDiscardingConversionError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        input.glwe_dimension,
+        input.polynomial_size,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextDiscardingDecryptionEngine<SecretKey, Ciphertext, PlaintextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn discard_decrypt_glwe_ciphertext, discard_decrypt_glwe_ciphertext_unchecked(
+        key: &SecretKey,
+        output: &mut PlaintextVector,
+        input: &Ciphertext,
+    ) -> Result<(), GlweCiphertextDiscardingDecryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        output.plaintext_count,
+        input.glwe_dimension,
+        input.polynomial_size,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextDiscardingEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn discard_encrypt_glwe_ciphertext, discard_encrypt_glwe_ciphertext_unchecked(
+        key: &SecretKey,
+        output: &mut Ciphertext,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextDiscardingEncryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        output.glwe_dimension,
+        output.polynomial_size,
+        input.plaintext_count,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextDiscardingMultiplicationEngine<
+        InputCiphertext1,
+        InputCiphertext2,
+        RelinearizationKey,
+        Delta,
+        OutputCiphertext,
+    >
+    where {
+        InputCiphertext1: GlweCiphertextEntity,
+        InputCiphertext2: GlweCiphertextEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+        RelinearizationKey: GlweRelinearizationKeyEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+        Delta: PlaintextEntity,
+        OutputCiphertext: GlweCiphertextEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+    }
+    fn discard_mul_glwe_ciphertext, discard_mul_glwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        delta: &Delta,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        input_1.glwe_dimension,
+        input_1.polynomial_size,
+        input_2.glwe_dimension,
+        input_2.polynomial_size,
+        rlk.glwe_dimension,
+        rlk.polynomial_size,
+        rlk.decomposition_level_count,
+        rlk.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_glwe_ciphertext, encrypt_glwe_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<Ciphertext, GlweCiphertextEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size, input.plaintext_count]
+);
+
+instrument_engine!(
+    GlweCiphertextGgswCiphertextExternalProductEngine<GlweInput, GgswInput, Output>
+    where {
+        GlweInput: GlweCiphertextEntity,
+        GgswInput: GgswCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+        Output: GlweCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+    }
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext,
+        compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<Output, GlweCiphertextGgswCiphertextExternalProductError>
+    records [
+        glwe_input.glwe_dimension,
+        glwe_input.polynomial_size,
+        ggsw_input.glwe_dimension,
+        ggsw_input.polynomial_size,
+        ggsw_input.decomposition_level_count,
+        ggsw_input.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorConversionEngine<Input, Output>
+    where {
+        Input: GlweCiphertextVectorEntity,
+        Output: GlweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_glwe_ciphertext_vector, convert_glwe_ciphertext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, GlweCiphertextVectorConversionError>
+    records [input.glwe_dimension, input.polynomial_size, input.glwe_ciphertext_count]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorDecryptionEngine<SecretKey, CiphertextVector, PlaintextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn decrypt_glwe_ciphertext_vector, decrypt_glwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &CiphertextVector,
+    ) -> Result<PlaintextVector, GlweCiphertextVectorDecryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.glwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorDiscardingConversionEngine<Input, Output>
+    where {
+        Input: GlweCiphertextVectorEntity,
+        Output: GlweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_glwe_ciphertext_vector, discard_convert_glwe_ciphertext_vector_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), GlweCiphertextVectorDiscardingConversionError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        output.glwe_ciphertext_count,
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.glwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorDiscardingDecryptionEngine<SecretKey, CiphertextVector, PlaintextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn discard_decrypt_glwe_ciphertext_vector, discard_decrypt_glwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        output: &mut PlaintextVector,
+        input: &CiphertextVector,
+    ) -> Result<(), GlweCiphertextVectorDiscardingDecryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        output.plaintext_count,
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.glwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorDiscardingEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn discard_encrypt_glwe_ciphertext_vector, discard_encrypt_glwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<(), GlweCiphertextVectorDiscardingEncryptionError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        output.glwe_dimension,
+        output.polynomial_size,
+        output.glwe_ciphertext_count,
+        input.plaintext_count,
+    ]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_glwe_ciphertext_vector, encrypt_glwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<CiphertextVector, GlweCiphertextVectorEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size, input.plaintext_count]
+);
+
+instrument_engine!(
+    GlweCiphertextVectorZeroEncryptionEngine<SecretKey, CiphertextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        CiphertextVector: GlweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn zero_encrypt_glwe_ciphertext_vector, zero_encrypt_glwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        noise: Variance,
+        count: GlweCiphertextCount,
+    ) -> Result<CiphertextVector, GlweCiphertextVectorZeroEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size, count]
+);
+
+instrument_engine!(
+    GlweCiphertextZeroEncryptionEngine<SecretKey, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn zero_encrypt_glwe_ciphertext, zero_encrypt_glwe_ciphertext_unchecked(
+        key: &SecretKey,
+        noise: Variance,
+    ) -> Result<Ciphertext, GlweCiphertextZeroEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size]
+);
+
+instrument_engine!(
+    GlweCiphertextsGgswCiphertextCmuxEngine<GlweInput, GgswInput, Output>
+    where {
+        GlweInput: GlweCiphertextEntity,
+        GgswInput: GgswCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+        Output: GlweCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+    }
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext, cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<Output, GlweCiphertextsGgswCiphertextCmuxError>
+    records [
+        glwe_input_0.glwe_dimension,
+        glwe_input_0.polynomial_size,
+        glwe_input_1.glwe_dimension,
+        glwe_input_1.polynomial_size,
+        ggsw_input.glwe_dimension,
+        ggsw_input.polynomial_size,
+        ggsw_input.decomposition_level_count,
+        ggsw_input.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GlweSeededCiphertextEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        Ciphertext: GlweSeededCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_glwe_seeded_ciphertext, encrypt_glwe_seeded_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<Ciphertext, GlweSeededCiphertextEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size, input.plaintext_count]
+);
+
+instrument_engine!(
+    GlweSeededCiphertextExpansionEngine<Input, Output>
+    where {
+        Input: GlweSeededCiphertextEntity,
+        Output: GlweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn expand_glwe_seeded_ciphertext, expand_glwe_seeded_ciphertext_unchecked(
+        input: &Input,
+    ) -> Result<Output, GlweSeededCiphertextExpansionError>
+    records [input.glwe_dimension, input.polynomial_size]
+);
+
+instrument_engine!(
+    GlweSeededCiphertextVectorEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: GlweSeededCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_glwe_seeded_ciphertext_vector, encrypt_glwe_seeded_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<CiphertextVector, GlweSeededCiphertextVectorEncryptionError>
+    records [key.glwe_dimension, key.polynomial_size, input.plaintext_count]
+);
+
+instrument_engine!(
+    GlweSeededCiphertextVectorExpansionEngine<Input, Output>
+    where {
+        Input: GlweSeededCiphertextVectorEntity,
+        Output: GlweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn expand_glwe_seeded_ciphertext_vector, expand_glwe_seeded_ciphertext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, GlweSeededCiphertextVectorExpansionError>
+    records [input.glwe_dimension, input.polynomial_size, input.glwe_ciphertext_count]
+);
@@ -0,0 +1,601 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError,
+    GlweRelinearizationKeyCreationEngine, GlweRelinearizationKeyCreationError,
+    GlweSecretKeyConversionEngine, GlweSecretKeyConversionError, GlweSecretKeyCreationEngine,
+    GlweSecretKeyCreationError, GlweSecretKeyDiscardingConversionEngine,
+    GlweSecretKeyDiscardingConversionError, LweBootstrapKeyConsumingRetrievalEngine,
+    LweBootstrapKeyConsumingRetrievalError, LweBootstrapKeyConversionEngine,
+    LweBootstrapKeyConversionError, LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError,
+    LweBootstrapKeyCreationFromContainerEngine, LweBootstrapKeyCreationFromContainerError,
+    LweBootstrapKeyDiscardingConversionEngine, LweBootstrapKeyDiscardingConversionError,
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+    LweCiphertextPublicKeyEncryptionEngine, LweCiphertextPublicKeyEncryptionError,
+    LweCiphertextVectorDiscardingKeyswitchEngine, LweCiphertextVectorDiscardingKeyswitchError,
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError,
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine,
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError,
+    LweKeyswitchKeyConsumingRetrievalEngine, LweKeyswitchKeyConsumingRetrievalError,
+    LweKeyswitchKeyConversionEngine, LweKeyswitchKeyConversionError, LweKeyswitchKeyCreationEngine,
+    LweKeyswitchKeyCreationError, LweKeyswitchKeyCreationFromContainerEngine,
+    LweKeyswitchKeyCreationFromContainerError, LweKeyswitchKeyDiscardingConversionEngine,
+    LweKeyswitchKeyDiscardingConversionError, LwePublicKeyCreationEngine,
+    LwePublicKeyCreationError, LweSecretKeyConversionEngine, LweSecretKeyConversionError,
+    LweSecretKeyCreationEngine, LweSecretKeyCreationError, LweSecretKeyDiscardingConversionEngine,
+    LweSecretKeyDiscardingConversionError, PackingKeyswitchKeyCreationEngine,
+    PackingKeyswitchKeyCreationError, PrivateFunctionalPackingKeyswitchKeyCreationEngine,
+    PrivateFunctionalPackingKeyswitchKeyCreationError,
+};
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, CleartextVectorEntity,
+    GlweCiphertextEntity, GlweRelinearizationKeyEntity, GlweSecretKeyEntity, LweBootstrapKeyEntity,
+    LweCiphertextEntity, LweCiphertextVectorEntity, LweKeyswitchKeyEntity, LwePublicKeyEntity,
+    LweSecretKeyEntity, PackingKeyswitchKeyEntity, PlaintextEntity,
+    PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LwePublicKeyZeroEncryptionCount, PolynomialSize,
+};
+
+instrument_engine!(
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    >
+    where {
+        InputSecretKey: GlweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+            CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity<
+                InputKeyFlavor = InputSecretKey::KeyFlavor,
+                OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+            >,
+    }
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys,
+        create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError,
+    >
+    records [
+        input_key.glwe_dimension,
+        input_key.polynomial_size,
+        output_key.glwe_dimension,
+        output_key.polynomial_size,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GlweRelinearizationKeyCreationEngine<SecretKey, RelinearizationKey>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        RelinearizationKey: GlweRelinearizationKeyEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn create_glwe_relinearization_key, create_glwe_relinearization_key_unchecked(
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<RelinearizationKey, GlweRelinearizationKeyCreationError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    GlweSecretKeyConversionEngine<Input, Output>
+    where {
+        Input: GlweSecretKeyEntity,
+        Output: GlweSecretKeyEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_glwe_secret_key, convert_glwe_secret_key_unchecked(
+        input: &Input,
+    ) -> Result<Output, GlweSecretKeyConversionError>
+    records [input.glwe_dimension, input.polynomial_size]
+);
+
+instrument_engine!(
+    GlweSecretKeyCreationEngine<SecretKey>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+    }
+    fn create_glwe_secret_key, create_glwe_secret_key_unchecked(
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+    ) -> Result<SecretKey, GlweSecretKeyCreationError>
+    records [glwe_dimension, polynomial_size]
+);
+
+instrument_engine!(
+    GlweSecretKeyDiscardingConversionEngine<Input, Output>
+    where {
+        Input: GlweSecretKeyEntity,
+        Output: GlweSecretKeyEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_glwe_secret_key, discard_convert_glwe_secret_key_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), GlweSecretKeyDiscardingConversionError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        input.glwe_dimension,
+        input.polynomial_size,
+    ]
+);
+
+instrument_engine!(
+    LweBootstrapKeyConsumingRetrievalEngine<BootstrapKey, Container>
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+    }
+    fn consume_retrieve_lwe_bootstrap_key, consume_retrieve_lwe_bootstrap_key_unchecked(
+        bootstrap_key: BootstrapKey,
+    ) -> Result<Container, LweBootstrapKeyConsumingRetrievalError>
+    records [
+        bootstrap_key.glwe_dimension,
+        bootstrap_key.polynomial_size,
+        bootstrap_key.input_lwe_dimension,
+        bootstrap_key.output_lwe_dimension,
+        bootstrap_key.decomposition_base_log,
+        bootstrap_key.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweBootstrapKeyConversionEngine<InputKey, OutputKey>
+    where {
+        InputKey: LweBootstrapKeyEntity,
+        OutputKey:
+            LweBootstrapKeyEntity<
+                InputKeyFlavor = InputKey::InputKeyFlavor,
+                OutputKeyFlavor = InputKey::OutputKeyFlavor,
+            >,
+    }
+    fn convert_lwe_bootstrap_key, convert_lwe_bootstrap_key_unchecked(
+        input: &InputKey,
+    ) -> Result<OutputKey, LweBootstrapKeyConversionError>
+    records [
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.input_lwe_dimension,
+        input.output_lwe_dimension,
+        input.decomposition_base_log,
+        input.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweBootstrapKeyCreationEngine<LweSecretKey, GlweSecretKey, BootstrapKey>
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+        LweSecretKey: LweSecretKeyEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+        GlweSecretKey: GlweSecretKeyEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+    }
+    fn create_lwe_bootstrap_key, create_lwe_bootstrap_key_unchecked(
+        input_key: &LweSecretKey,
+        output_key: &GlweSecretKey,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<BootstrapKey, LweBootstrapKeyCreationError>
+    records [
+        input_key.lwe_dimension,
+        output_key.glwe_dimension,
+        output_key.polynomial_size,
+        decomposition_base_log,
+        decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweBootstrapKeyCreationFromContainerEngine<Container, BootstrapKey>
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+    }
+    fn create_lwe_bootstrap_key_from_container, create_lwe_bootstrap_key_from_container_unchecked(
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<BootstrapKey, LweBootstrapKeyCreationFromContainerError>
+    records [glwe_dimension, polynomial_size, decomposition_base_log, decomposition_level_count]
+);
+
+instrument_engine!(
+    LweBootstrapKeyDiscardingConversionEngine<Input, Output>
+    where {
+        Input: LweBootstrapKeyEntity,
+        Output:
+            LweBootstrapKeyEntity<
+                InputKeyFlavor = Input::InputKeyFlavor,
+                OutputKeyFlavor = Input::OutputKeyFlavor,
+            >,
+    }
+    fn discard_convert_lwe_bootstrap_key, discard_convert_lwe_bootstrap_key_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweBootstrapKeyDiscardingConversionError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        output.input_lwe_dimension,
+        output.output_lwe_dimension,
+        output.decomposition_base_log,
+        output.decomposition_level_count,
+        input.glwe_dimension,
+        input.polynomial_size,
+        input.input_lwe_dimension,
+        input.output_lwe_dimension,
+        input.decomposition_base_log,
+        input.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingKeyswitchEngine<KeyswitchKey, InputCiphertext, OutputCiphertext>
+    where {
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        InputCiphertext: LweCiphertextEntity<KeyFlavor = KeyswitchKey::InputKeyFlavor>,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = KeyswitchKey::OutputKeyFlavor>,
+    }
+    fn discard_keyswitch_lwe_ciphertext, discard_keyswitch_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError>
+    records [
+        output.lwe_dimension,
+        input.lwe_dimension,
+        ksk.input_lwe_dimension,
+        ksk.output_lwe_dimension,
+        ksk.decomposition_level_count,
+        ksk.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>
+    where {
+        PublicKey: LwePublicKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = PublicKey::KeyFlavor>,
+    }
+    fn encrypt_lwe_ciphertext_with_public_key, encrypt_lwe_ciphertext_with_public_key_unchecked(
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextPublicKeyEncryptionError>
+    records [key.lwe_dimension, key.lwe_zero_encryption_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingKeyswitchEngine<
+        KeyswitchKey,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >
+    where {
+        KeyswitchKey: LweKeyswitchKeyEntity,
+        InputCiphertextVector: LweCiphertextVectorEntity<KeyFlavor = KeyswitchKey::InputKeyFlavor>,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = KeyswitchKey::OutputKeyFlavor>,
+    }
+    fn discard_keyswitch_lwe_ciphertext_vector, discard_keyswitch_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        ksk: &KeyswitchKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingKeyswitchError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+        ksk.input_lwe_dimension,
+        ksk.output_lwe_dimension,
+        ksk.decomposition_level_count,
+        ksk.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+        PackingKeyswitchKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    >
+    where {
+        PackingKeyswitchKey: PackingKeyswitchKeyEntity,
+        InputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = PackingKeyswitchKey::InputKeyFlavor>,
+        OutputCiphertext: GlweCiphertextEntity<KeyFlavor = PackingKeyswitchKey::OutputKeyFlavor>,
+    }
+    fn discard_packing_keyswitch_lwe_ciphertext_vector,
+        discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pksk: &PackingKeyswitchKey,
+    ) -> Result<(), LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+        pksk.input_lwe_dimension,
+        pksk.output_glwe_dimension,
+        pksk.output_polynomial_size,
+        pksk.decomposition_level_count,
+        pksk.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+        PrivateFunctionalPackingKeyswitchKey,
+        InputCiphertextVector,
+        OutputCiphertext,
+    >
+    where {
+        PrivateFunctionalPackingKeyswitchKey: PrivateFunctionalPackingKeyswitchKeyEntity,
+        InputCiphertextVector:
+            LweCiphertextVectorEntity<
+                KeyFlavor = PrivateFunctionalPackingKeyswitchKey::InputKeyFlavor,
+            >,
+        OutputCiphertext:
+            GlweCiphertextEntity<KeyFlavor = PrivateFunctionalPackingKeyswitchKey::OutputKeyFlavor>,
+    }
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector,
+        discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError,
+    >
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+        pfpksk.input_lwe_dimension,
+        pfpksk.output_glwe_dimension,
+        pfpksk.output_polynomial_size,
+        pfpksk.decomposition_level_count,
+        pfpksk.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweKeyswitchKeyConsumingRetrievalEngine<KeyswitchKey, Container>
+    where {
+        KeyswitchKey: LweKeyswitchKeyEntity,
+    }
+    fn consume_retrieve_lwe_keyswitch_key, consume_retrieve_lwe_keyswitch_key_unchecked(
+        keyswitch_key: KeyswitchKey,
+    ) -> Result<Container, LweKeyswitchKeyConsumingRetrievalError>
+    records [
+        keyswitch_key.input_lwe_dimension,
+        keyswitch_key.output_lwe_dimension,
+        keyswitch_key.decomposition_level_count,
+        keyswitch_key.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweKeyswitchKeyConversionEngine<Input, Output>
+    where {
+        Input: LweKeyswitchKeyEntity,
+        Output:
+            LweKeyswitchKeyEntity<
+                InputKeyFlavor = Input::InputKeyFlavor,
+                OutputKeyFlavor = Input::OutputKeyFlavor,
+            >,
+    }
+    fn convert_lwe_keyswitch_key, convert_lwe_keyswitch_key_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweKeyswitchKeyConversionError>
+    records [
+        input.input_lwe_dimension,
+        input.output_lwe_dimension,
+        input.decomposition_level_count,
+        input.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweKeyswitchKeyCreationEngine<InputSecretKey, OutputSecretKey, KeyswitchKey>
+    where {
+        InputSecretKey: LweSecretKeyEntity,
+        OutputSecretKey: LweSecretKeyEntity,
+        KeyswitchKey:
+            LweKeyswitchKeyEntity<
+                InputKeyFlavor = InputSecretKey::KeyFlavor,
+                OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+            >,
+    }
+    fn create_lwe_keyswitch_key, create_lwe_keyswitch_key_unchecked(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<KeyswitchKey, LweKeyswitchKeyCreationError>
+    records [
+        input_key.lwe_dimension,
+        output_key.lwe_dimension,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LweKeyswitchKeyCreationFromContainerEngine<Container, KeyswitchKey>
+    where {
+        KeyswitchKey: LweKeyswitchKeyEntity,
+    }
+    fn create_lwe_keyswitch_key_from_container, create_lwe_keyswitch_key_from_container_unchecked(
+        container: Container,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<KeyswitchKey, LweKeyswitchKeyCreationFromContainerError>
+    records [output_lwe_dimension, decomposition_base_log, decomposition_level_count]
+);
+
+instrument_engine!(
+    LweKeyswitchKeyDiscardingConversionEngine<Input, Output>
+    where {
+        Input: LweKeyswitchKeyEntity,
+        Output:
+            LweKeyswitchKeyEntity<
+                InputKeyFlavor = Input::InputKeyFlavor,
+                OutputKeyFlavor = Input::OutputKeyFlavor,
+            >,
+    }
+    fn discard_convert_lwe_keyswitch_key, discard_convert_lwe_keyswitch_key_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweKeyswitchKeyDiscardingConversionError>
+    records [
+        output.input_lwe_dimension,
+        output.output_lwe_dimension,
+        output.decomposition_level_count,
+        output.decomposition_base_log,
+        input.input_lwe_dimension,
+        input.output_lwe_dimension,
+        input.decomposition_level_count,
+        input.decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    LwePublicKeyCreationEngine<SecretKey, PublicKey>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        PublicKey: LwePublicKeyEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn create_lwe_public_key, create_lwe_public_key_unchecked(
+        secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<PublicKey, LwePublicKeyCreationError>
+    records [secret_key.lwe_dimension, lwe_public_key_zero_encryption_count]
+);
+
+instrument_engine!(
+    LweSecretKeyConversionEngine<Input, Output>
+    where {
+        Input: LweSecretKeyEntity,
+        Output: LweSecretKeyEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_lwe_secret_key, convert_lwe_secret_key_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweSecretKeyConversionError>
+    records [input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweSecretKeyCreationEngine<SecretKey>
+    where {
+        SecretKey: LweSecretKeyEntity,
+    }
+    fn create_lwe_secret_key, create_lwe_secret_key_unchecked(
+        lwe_dimension: LweDimension,
+    ) -> Result<SecretKey, LweSecretKeyCreationError>
+    records [lwe_dimension]
+);
+
+instrument_engine!(
+    LweSecretKeyDiscardingConversionEngine<Input, Output>
+    where {
+        Input: LweSecretKeyEntity,
+        Output: LweSecretKeyEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_lwe_secret_key, discard_convert_lwe_secret_key_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweSecretKeyDiscardingConversionError>
+    records [output.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    PackingKeyswitchKeyCreationEngine<InputSecretKey, OutputSecretKey, PackingKeyswitchKey>
+    where {
+        InputSecretKey: LweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+        PackingKeyswitchKey:
+            PackingKeyswitchKeyEntity<
+                InputKeyFlavor = InputSecretKey::KeyFlavor,
+                OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+            >,
+    }
+    fn create_packing_keyswitch_key, create_packing_keyswitch_key_unchecked(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<PackingKeyswitchKey, PackingKeyswitchKeyCreationError>
+    records [
+        input_key.lwe_dimension,
+        output_key.glwe_dimension,
+        output_key.polynomial_size,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
+
+instrument_engine!(
+    PrivateFunctionalPackingKeyswitchKeyCreationEngine<
+        InputSecretKey,
+        OutputSecretKey,
+        Polynomial,
+        PrivateFunctionalPackingKeyswitchKey,
+    >
+    where {
+        InputSecretKey: LweSecretKeyEntity,
+        OutputSecretKey: GlweSecretKeyEntity,
+        Polynomial: CleartextVectorEntity,
+        PrivateFunctionalPackingKeyswitchKey:
+            PrivateFunctionalPackingKeyswitchKeyEntity<
+                InputKeyFlavor = InputSecretKey::KeyFlavor,
+                OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+            >,
+    }
+    fn create_private_functional_packing_keyswitch_key,
+        create_private_functional_packing_keyswitch_key_unchecked(
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        polynomial: &Polynomial,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        PrivateFunctionalPackingKeyswitchKey,
+        PrivateFunctionalPackingKeyswitchKeyCreationError,
+    >
+    records [
+        input_key.lwe_dimension,
+        output_key.glwe_dimension,
+        output_key.polynomial_size,
+        polynomial.cleartext_count,
+        decomposition_level_count,
+        decomposition_base_log,
+    ]
+);
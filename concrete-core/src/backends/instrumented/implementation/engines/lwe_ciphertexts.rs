@@ -0,0 +1,900 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    LweCiphertextCleartextDiscardingMultiplicationEngine,
+    LweCiphertextCleartextDiscardingMultiplicationError,
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError, LweCiphertextCompressionEngine,
+    LweCiphertextCompressionError, LweCiphertextConsumingRetrievalEngine,
+    LweCiphertextConsumingRetrievalError, LweCiphertextConversionEngine,
+    LweCiphertextConversionError, LweCiphertextCreationEngine, LweCiphertextCreationError,
+    LweCiphertextDecompressionEngine, LweCiphertextDecompressionError,
+    LweCiphertextDecryptionEngine, LweCiphertextDecryptionError,
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+    LweCiphertextDiscardingConversionEngine, LweCiphertextDiscardingConversionError,
+    LweCiphertextDiscardingDecryptionEngine, LweCiphertextDiscardingDecryptionError,
+    LweCiphertextDiscardingEncryptionEngine, LweCiphertextDiscardingEncryptionError,
+    LweCiphertextDiscardingExtractionEngine, LweCiphertextDiscardingExtractionError,
+    LweCiphertextDiscardingLoadingEngine, LweCiphertextDiscardingLoadingError,
+    LweCiphertextDiscardingNegationEngine, LweCiphertextDiscardingNegationError,
+    LweCiphertextDiscardingStoringEngine, LweCiphertextDiscardingStoringError,
+    LweCiphertextDiscardingVerticalPackingEngine, LweCiphertextDiscardingVerticalPackingError,
+    LweCiphertextEncryptionEngine, LweCiphertextEncryptionError, LweCiphertextFusingAdditionEngine,
+    LweCiphertextFusingAdditionError, LweCiphertextFusingNegationEngine,
+    LweCiphertextFusingNegationError, LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine,
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError, LweCiphertextLoadingEngine,
+    LweCiphertextLoadingError, LweCiphertextPlaintextDiscardingAdditionEngine,
+    LweCiphertextPlaintextDiscardingAdditionError, LweCiphertextPlaintextFusingAdditionEngine,
+    LweCiphertextPlaintextFusingAdditionError, LweCiphertextVectorCompressionEngine,
+    LweCiphertextVectorCompressionError, LweCiphertextVectorConsumingRetrievalEngine,
+    LweCiphertextVectorConsumingRetrievalError, LweCiphertextVectorConversionEngine,
+    LweCiphertextVectorConversionError, LweCiphertextVectorCreationEngine,
+    LweCiphertextVectorCreationError, LweCiphertextVectorDecompressionEngine,
+    LweCiphertextVectorDecompressionError, LweCiphertextVectorDecryptionEngine,
+    LweCiphertextVectorDecryptionError, LweCiphertextVectorDiscardingAdditionEngine,
+    LweCiphertextVectorDiscardingAdditionError,
+    LweCiphertextVectorDiscardingAffineTransformationEngine,
+    LweCiphertextVectorDiscardingAffineTransformationError,
+    LweCiphertextVectorDiscardingBootstrapEngine, LweCiphertextVectorDiscardingBootstrapError,
+    LweCiphertextVectorDiscardingConversionEngine, LweCiphertextVectorDiscardingConversionError,
+    LweCiphertextVectorDiscardingDecryptionEngine, LweCiphertextVectorDiscardingDecryptionError,
+    LweCiphertextVectorDiscardingEncryptionEngine, LweCiphertextVectorDiscardingEncryptionError,
+    LweCiphertextVectorDiscardingLoadingEngine, LweCiphertextVectorDiscardingLoadingError,
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine,
+    LweCiphertextVectorDiscardingMultiValueBootstrapError,
+    LweCiphertextVectorDiscardingNegationEngine, LweCiphertextVectorDiscardingNegationError,
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
+    LweCiphertextVectorFusingAdditionEngine, LweCiphertextVectorFusingAdditionError,
+    LweCiphertextVectorFusingNegationEngine, LweCiphertextVectorFusingNegationError,
+    LweCiphertextVectorLoadingEngine, LweCiphertextVectorLoadingError,
+    LweCiphertextVectorZeroEncryptionEngine, LweCiphertextVectorZeroEncryptionError,
+    LweCiphertextZeroEncryptionEngine, LweCiphertextZeroEncryptionError,
+    LweSeededCiphertextEncryptionEngine, LweSeededCiphertextEncryptionError,
+    LweSeededCiphertextExpansionEngine, LweSeededCiphertextExpansionError,
+    LweSeededCiphertextVectorEncryptionEngine, LweSeededCiphertextVectorEncryptionError,
+    LweSeededCiphertextVectorExpansionEngine, LweSeededCiphertextVectorExpansionError,
+};
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, CleartextEntity,
+    CleartextVectorEntity, GgswCiphertextEntity, GlweCiphertextEntity, GlweCiphertextVectorEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+    LweCompressedCiphertextEntity, LweCompressedCiphertextVectorEntity, LweSecretKeyEntity,
+    LweSeededCiphertextEntity, LweSeededCiphertextVectorEntity, PlaintextEntity,
+    PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    CiphertextModulusLog, LweCiphertextCount, LweCiphertextIndex, LweCiphertextRange, LweSize,
+    MonomialDegree,
+};
+
+instrument_engine!(
+    LweCiphertextCleartextDiscardingMultiplicationEngine<
+        InputCiphertext,
+        Cleartext,
+        OutputCiphertext,
+    >
+    where {
+        Cleartext: CleartextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = InputCiphertext::KeyFlavor>,
+    }
+    fn discard_mul_lwe_ciphertext_cleartext, discard_mul_lwe_ciphertext_cleartext_unchecked(
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &Cleartext,
+    ) -> Result<(), LweCiphertextCleartextDiscardingMultiplicationError>
+    records [output.lwe_dimension, input_1.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextCleartextFusingMultiplicationEngine<Ciphertext, Cleartext>
+    where {
+        Cleartext: CleartextEntity,
+        Ciphertext: LweCiphertextEntity,
+    }
+    fn fuse_mul_lwe_ciphertext_cleartext, fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        output: &mut Ciphertext,
+        input: &Cleartext,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError>
+    records [output.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextCompressionEngine<Ciphertext, CompressedCiphertext>
+    where {
+        Ciphertext: LweCiphertextEntity,
+        CompressedCiphertext: LweCompressedCiphertextEntity<KeyFlavor = Ciphertext::KeyFlavor>,
+    }
+    fn compress_lwe_ciphertext, compress_lwe_ciphertext_unchecked(
+        input: &Ciphertext,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertext, LweCiphertextCompressionError>
+    records [input.lwe_dimension, modulus_log]
+);
+
+instrument_engine!(
+    LweCiphertextConsumingRetrievalEngine<Ciphertext, Container>
+    where {
+        Ciphertext: LweCiphertextEntity,
+    }
+    fn consume_retrieve_lwe_ciphertext, consume_retrieve_lwe_ciphertext_unchecked(
+        ciphertext: Ciphertext,
+    ) -> Result<Container, LweCiphertextConsumingRetrievalError>
+    records [ciphertext.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextConversionEngine<Input, Output>
+    where {
+        Input: LweCiphertextEntity,
+        Output: LweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_lwe_ciphertext, convert_lwe_ciphertext_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweCiphertextConversionError>
+    records [input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextCreationEngine<Container, Ciphertext>
+    where {
+        Ciphertext: LweCiphertextEntity,
+    }
+    fn create_lwe_ciphertext, create_lwe_ciphertext_unchecked(
+        container: Container,
+    ) -> Result<Ciphertext, LweCiphertextCreationError>
+    records []
+);
+
+instrument_engine!(
+    LweCiphertextDecompressionEngine<CompressedCiphertext, Ciphertext>
+    where {
+        CompressedCiphertext: LweCompressedCiphertextEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = CompressedCiphertext::KeyFlavor>,
+    }
+    fn decompress_lwe_ciphertext, decompress_lwe_ciphertext_unchecked(
+        input: &CompressedCiphertext,
+    ) -> Result<Ciphertext, LweCiphertextDecompressionError>
+    records [input.lwe_dimension, input.modulus_log]
+);
+
+instrument_engine!(
+    LweCiphertextDecryptionEngine<SecretKey, Ciphertext, Plaintext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        Plaintext: PlaintextEntity,
+    }
+    fn decrypt_lwe_ciphertext, decrypt_lwe_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &Ciphertext,
+    ) -> Result<Plaintext, LweCiphertextDecryptionError>
+    records [key.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingAdditionEngine<InputCiphertext, OutputCiphertext>
+    where {
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = InputCiphertext::KeyFlavor>,
+    }
+    fn discard_add_lwe_ciphertext, discard_add_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError>
+    records [output.lwe_dimension, input_1.lwe_dimension, input_2.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingBootstrapEngine<
+        BootstrapKey,
+        Accumulator,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+        Accumulator: GlweCiphertextEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+        InputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+    }
+    fn discard_bootstrap_lwe_ciphertext, discard_bootstrap_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        acc: &Accumulator,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError>
+    records [
+        output.lwe_dimension,
+        input.lwe_dimension,
+        acc.glwe_dimension,
+        acc.polynomial_size,
+        bsk.glwe_dimension,
+        bsk.polynomial_size,
+        bsk.input_lwe_dimension,
+        bsk.output_lwe_dimension,
+        bsk.decomposition_base_log,
+        bsk.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingConversionEngine<Input, Output>
+    where {
+        Input: LweCiphertextEntity,
+        Output: LweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_lwe_ciphertext, discard_convert_lwe_ciphertext_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweCiphertextDiscardingConversionError>
+    records [output.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingDecryptionEngine<SecretKey, Ciphertext, Plaintext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        Plaintext: PlaintextEntity,
+    }
+    fn discard_decrypt_lwe_ciphertext, discard_decrypt_lwe_ciphertext_unchecked(
+        key: &SecretKey,
+        output: &mut Plaintext,
+        input: &Ciphertext,
+    ) -> Result<(), LweCiphertextDiscardingDecryptionError>
+    records [key.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingEncryptionEngine<SecretKey, Plaintext, Ciphertext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn discard_encrypt_lwe_ciphertext, discard_encrypt_lwe_ciphertext_unchecked(
+        key: &SecretKey,
+        output: &mut Ciphertext,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextDiscardingEncryptionError>
+    records [key.lwe_dimension, output.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingExtractionEngine<GlweCiphertext, LweCiphertext>
+    where {
+        GlweCiphertext: GlweCiphertextEntity,
+        LweCiphertext: LweCiphertextEntity<KeyFlavor = GlweCiphertext::KeyFlavor>,
+    }
+    fn discard_extract_lwe_ciphertext, discard_extract_lwe_ciphertext_unchecked(
+        output: &mut LweCiphertext,
+        input: &GlweCiphertext,
+        nth: MonomialDegree,
+    ) -> Result<(), LweCiphertextDiscardingExtractionError>
+    records [output.lwe_dimension, input.glwe_dimension, input.polynomial_size, nth]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingLoadingEngine<CiphertextVector, Ciphertext>
+    where {
+        Ciphertext: LweCiphertextEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = Ciphertext::KeyFlavor>,
+    }
+    fn discard_load_lwe_ciphertext, discard_load_lwe_ciphertext_unchecked(
+        ciphertext: &mut Ciphertext,
+        vector: &CiphertextVector,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingLoadingError>
+    records [ciphertext.lwe_dimension, vector.lwe_dimension, vector.lwe_ciphertext_count, i]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingNegationEngine<InputCiphertext, OutputCiphertext>
+    where {
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = InputCiphertext::KeyFlavor>,
+    }
+    fn discard_neg_lwe_ciphertext, discard_neg_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), LweCiphertextDiscardingNegationError>
+    records [output.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingStoringEngine<Ciphertext, CiphertextVector>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = CiphertextVector::KeyFlavor>,
+    }
+    fn discard_store_lwe_ciphertext, discard_store_lwe_ciphertext_unchecked(
+        vector: &mut CiphertextVector,
+        ciphertext: &Ciphertext,
+        i: LweCiphertextIndex,
+    ) -> Result<(), LweCiphertextDiscardingStoringError>
+    records [vector.lwe_dimension, vector.lwe_ciphertext_count, ciphertext.lwe_dimension, i]
+);
+
+instrument_engine!(
+    LweCiphertextDiscardingVerticalPackingEngine<GgswInput, LookupTable, OutputCiphertext>
+    where {
+        GgswInput: GgswCiphertextEntity,
+        LookupTable: PlaintextVectorEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = GgswInput::KeyFlavor>,
+    }
+    fn discard_vertical_packing_lwe_ciphertext, discard_vertical_packing_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        ggsw_inputs: &[GgswInput],
+        lut: &LookupTable,
+    ) -> Result<(), LweCiphertextDiscardingVerticalPackingError>
+    records [output.lwe_dimension, ggsw_inputs.len, lut.plaintext_count]
+);
+
+instrument_engine!(
+    LweCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_lwe_ciphertext, encrypt_lwe_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextEncryptionError>
+    records [key.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextFusingAdditionEngine<InputCiphertext, OutputCiphertext>
+    where {
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = InputCiphertext::KeyFlavor>,
+    }
+    fn fuse_add_lwe_ciphertext, fuse_add_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+    ) -> Result<(), LweCiphertextFusingAdditionError>
+    records [output.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextFusingNegationEngine<Ciphertext>
+    where {
+        Ciphertext: LweCiphertextEntity,
+    }
+    fn fuse_neg_lwe_ciphertext, fuse_neg_lwe_ciphertext_unchecked(
+        input: &mut Ciphertext,
+    ) -> Result<(), LweCiphertextFusingNegationError>
+    records [input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+        BootstrapKey,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        InputCiphertext,
+        OutputCiphertext,
+    >
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+            CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity<
+                InputKeyFlavor = BootstrapKey::OutputKeyFlavor,
+            >,
+        InputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+        OutputCiphertext:
+            GgswCiphertextEntity<
+                KeyFlavor = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys::OutputKeyFlavor,
+            >,
+    }
+    fn discard_circuit_bootstrap_lwe_ciphertext, discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    ) -> Result<(), LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError>
+    records [
+        output.glwe_dimension,
+        output.polynomial_size,
+        output.decomposition_level_count,
+        output.decomposition_base_log,
+        input.lwe_dimension,
+        bsk.glwe_dimension,
+        bsk.polynomial_size,
+        bsk.input_lwe_dimension,
+        bsk.output_lwe_dimension,
+        bsk.decomposition_base_log,
+        bsk.decomposition_level_count,
+        cbs_pfpksk.input_lwe_dimension,
+        cbs_pfpksk.output_glwe_dimension,
+        cbs_pfpksk.output_polynomial_size,
+        cbs_pfpksk.decomposition_level_count,
+        cbs_pfpksk.decomposition_base_log,
+        cbs_pfpksk.key_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextLoadingEngine<CiphertextVector, Ciphertext>
+    where {
+        Ciphertext: LweCiphertextEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = Ciphertext::KeyFlavor>,
+    }
+    fn load_lwe_ciphertext, load_lwe_ciphertext_unchecked(
+        vector: &CiphertextVector,
+        i: LweCiphertextIndex,
+    ) -> Result<Ciphertext, LweCiphertextLoadingError>
+    records [vector.lwe_dimension, vector.lwe_ciphertext_count, i]
+);
+
+instrument_engine!(
+    LweCiphertextPlaintextDiscardingAdditionEngine<InputCiphertext, Plaintext, OutputCiphertext>
+    where {
+        Plaintext: PlaintextEntity,
+        InputCiphertext: LweCiphertextEntity,
+        OutputCiphertext: LweCiphertextEntity<KeyFlavor = InputCiphertext::KeyFlavor>,
+    }
+    fn discard_add_lwe_ciphertext_plaintext, discard_add_lwe_ciphertext_plaintext_unchecked(
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext,
+        input_2: &Plaintext,
+    ) -> Result<(), LweCiphertextPlaintextDiscardingAdditionError>
+    records [output.lwe_dimension, input_1.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextPlaintextFusingAdditionEngine<Ciphertext, Plaintext>
+    where {
+        Plaintext: PlaintextEntity,
+        Ciphertext: LweCiphertextEntity,
+    }
+    fn fuse_add_lwe_ciphertext_plaintext, fuse_add_lwe_ciphertext_plaintext_unchecked(
+        output: &mut Ciphertext,
+        input: &Plaintext,
+    ) -> Result<(), LweCiphertextPlaintextFusingAdditionError>
+    records [output.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextVectorCompressionEngine<CiphertextVector, CompressedCiphertextVector>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+        CompressedCiphertextVector:
+            LweCompressedCiphertextVectorEntity<KeyFlavor = CiphertextVector::KeyFlavor>,
+    }
+    fn compress_lwe_ciphertext_vector, compress_lwe_ciphertext_vector_unchecked(
+        input: &CiphertextVector,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertextVector, LweCiphertextVectorCompressionError>
+    records [input.lwe_dimension, input.lwe_ciphertext_count, modulus_log]
+);
+
+instrument_engine!(
+    LweCiphertextVectorConsumingRetrievalEngine<CiphertextVector, Container>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+    }
+    fn consume_retrieve_lwe_ciphertext_vector, consume_retrieve_lwe_ciphertext_vector_unchecked(
+        ciphertext_vector: CiphertextVector,
+    ) -> Result<Container, LweCiphertextVectorConsumingRetrievalError>
+    records [ciphertext_vector.lwe_dimension, ciphertext_vector.lwe_ciphertext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorConversionEngine<Input, Output>
+    where {
+        Input: LweCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn convert_lwe_ciphertext_vector, convert_lwe_ciphertext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweCiphertextVectorConversionError>
+    records [input.lwe_dimension, input.lwe_ciphertext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorCreationEngine<Container, CiphertextVector>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+    }
+    fn create_lwe_ciphertext_vector, create_lwe_ciphertext_vector_unchecked(
+        container: Container,
+        lwe_size: LweSize,
+    ) -> Result<CiphertextVector, LweCiphertextVectorCreationError>
+    records [lwe_size]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDecompressionEngine<CompressedCiphertextVector, CiphertextVector>
+    where {
+        CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+        CiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = CompressedCiphertextVector::KeyFlavor>,
+    }
+    fn decompress_lwe_ciphertext_vector, decompress_lwe_ciphertext_vector_unchecked(
+        input: &CompressedCiphertextVector,
+    ) -> Result<CiphertextVector, LweCiphertextVectorDecompressionError>
+    records [input.lwe_dimension, input.lwe_ciphertext_count, input.modulus_log]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDecryptionEngine<SecretKey, CiphertextVector, PlaintextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn decrypt_lwe_ciphertext_vector, decrypt_lwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &CiphertextVector,
+    ) -> Result<PlaintextVector, LweCiphertextVectorDecryptionError>
+    records [key.lwe_dimension, input.lwe_dimension, input.lwe_ciphertext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingAdditionEngine<InputCiphertextVector, OutputCiphertextVector>
+    where {
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = InputCiphertextVector::KeyFlavor>,
+    }
+    fn discard_add_lwe_ciphertext_vector, discard_add_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertextVector,
+        input_1: &InputCiphertextVector,
+        input_2: &InputCiphertextVector,
+    ) -> Result<(), LweCiphertextVectorDiscardingAdditionError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input_1.lwe_dimension,
+        input_1.lwe_ciphertext_count,
+        input_2.lwe_dimension,
+        input_2.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        CiphertextVector,
+        CleartextVector,
+        Plaintext,
+        OutputCiphertext,
+    >
+    where {
+        OutputCiphertext: LweCiphertextEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = OutputCiphertext::KeyFlavor>,
+        CleartextVector: CleartextVectorEntity,
+        Plaintext: PlaintextEntity,
+    }
+    fn discard_affine_transform_lwe_ciphertext_vector,
+        discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertext,
+        inputs: &CiphertextVector,
+        weights: &CleartextVector,
+        bias: &Plaintext,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError>
+    records [
+        output.lwe_dimension,
+        inputs.lwe_dimension,
+        inputs.lwe_ciphertext_count,
+        weights.cleartext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingBootstrapEngine<
+        BootstrapKey,
+        AccumulatorVector,
+        InputCiphertextVector,
+        OutputCiphertextVector,
+    >
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+        AccumulatorVector: GlweCiphertextVectorEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+        InputCiphertextVector: LweCiphertextVectorEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+    }
+    fn discard_bootstrap_lwe_ciphertext_vector, discard_bootstrap_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+        acc: &AccumulatorVector,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingBootstrapError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+        acc.glwe_dimension,
+        acc.polynomial_size,
+        acc.glwe_ciphertext_count,
+        bsk.glwe_dimension,
+        bsk.polynomial_size,
+        bsk.input_lwe_dimension,
+        bsk.output_lwe_dimension,
+        bsk.decomposition_base_log,
+        bsk.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingConversionEngine<Input, Output>
+    where {
+        Input: LweCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn discard_convert_lwe_ciphertext_vector, discard_convert_lwe_ciphertext_vector_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), LweCiphertextVectorDiscardingConversionError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingDecryptionEngine<SecretKey, CiphertextVector, PlaintextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn discard_decrypt_lwe_ciphertext_vector, discard_decrypt_lwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        output: &mut PlaintextVector,
+        input: &CiphertextVector,
+    ) -> Result<(), LweCiphertextVectorDiscardingDecryptionError>
+    records [
+        key.lwe_dimension,
+        output.plaintext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn discard_encrypt_lwe_ciphertext_vector, discard_encrypt_lwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        output: &mut CiphertextVector,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<(), LweCiphertextVectorDiscardingEncryptionError>
+    records [
+        key.lwe_dimension,
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.plaintext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingLoadingEngine<InputCiphertextVector, OutputCiphertextVector>
+    where {
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = InputCiphertextVector::KeyFlavor>,
+    }
+    fn discard_load_lwe_ciphertext_vector, discard_load_lwe_ciphertext_vector_unchecked(
+        output_vector: &mut OutputCiphertextVector,
+        input_vector: &InputCiphertextVector,
+        output_range: LweCiphertextRange,
+        input_range: LweCiphertextRange,
+    ) -> Result<(), LweCiphertextVectorDiscardingLoadingError>
+    records [
+        output_vector.lwe_dimension,
+        output_vector.lwe_ciphertext_count,
+        input_vector.lwe_dimension,
+        input_vector.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+        BootstrapKey,
+        LookupTables,
+        Delta,
+        InputCiphertext,
+        OutputCiphertextVector,
+    >
+    where {
+        BootstrapKey: LweBootstrapKeyEntity,
+        LookupTables: CleartextVectorEntity,
+        Delta: PlaintextEntity,
+        InputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+    }
+    fn discard_multi_value_bootstrap_lwe_ciphertext,
+        discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        luts: &LookupTables,
+        delta: &Delta,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingMultiValueBootstrapError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        luts.cleartext_count,
+        bsk.glwe_dimension,
+        bsk.polynomial_size,
+        bsk.input_lwe_dimension,
+        bsk.output_lwe_dimension,
+        bsk.decomposition_base_log,
+        bsk.decomposition_level_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorDiscardingNegationEngine<InputCiphertextVector, OutputCiphertextVector>
+    where {
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = InputCiphertextVector::KeyFlavor>,
+    }
+    fn discard_neg_lwe_ciphertext_vector, discard_neg_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), LweCiphertextVectorDiscardingNegationError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_lwe_ciphertext_vector, encrypt_lwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<CiphertextVector, LweCiphertextVectorEncryptionError>
+    records [key.lwe_dimension, input.plaintext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorFusingAdditionEngine<InputCiphertextVector, OutputCiphertextVector>
+    where {
+        InputCiphertextVector: LweCiphertextVectorEntity,
+        OutputCiphertextVector:
+            LweCiphertextVectorEntity<KeyFlavor = InputCiphertextVector::KeyFlavor>,
+    }
+    fn fuse_add_lwe_ciphertext_vector, fuse_add_lwe_ciphertext_vector_unchecked(
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertextVector,
+    ) -> Result<(), LweCiphertextVectorFusingAdditionError>
+    records [
+        output.lwe_dimension,
+        output.lwe_ciphertext_count,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextVectorFusingNegationEngine<CiphertextVector>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+    }
+    fn fuse_neg_lwe_ciphertext_vector, fuse_neg_lwe_ciphertext_vector_unchecked(
+        input: &mut CiphertextVector,
+    ) -> Result<(), LweCiphertextVectorFusingNegationError>
+    records [input.lwe_dimension, input.lwe_ciphertext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorLoadingEngine<CiphertextVector, SubCiphertextVector>
+    where {
+        CiphertextVector: LweCiphertextVectorEntity,
+        SubCiphertextVector: LweCiphertextVectorEntity<KeyFlavor = CiphertextVector::KeyFlavor>,
+    }
+    fn load_lwe_ciphertext_vector, load_lwe_ciphertext_vector_unchecked(
+        vector: &CiphertextVector,
+        range: LweCiphertextRange,
+    ) -> Result<SubCiphertextVector, LweCiphertextVectorLoadingError>
+    records [vector.lwe_dimension, vector.lwe_ciphertext_count]
+);
+
+instrument_engine!(
+    LweCiphertextVectorZeroEncryptionEngine<SecretKey, CiphertextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn zero_encrypt_lwe_ciphertext_vector, zero_encrypt_lwe_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        noise: Variance,
+        count: LweCiphertextCount,
+    ) -> Result<CiphertextVector, LweCiphertextVectorZeroEncryptionError>
+    records [key.lwe_dimension, count]
+);
+
+instrument_engine!(
+    LweCiphertextZeroEncryptionEngine<SecretKey, Ciphertext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn zero_encrypt_lwe_ciphertext, zero_encrypt_lwe_ciphertext_unchecked(
+        key: &SecretKey,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweCiphertextZeroEncryptionError>
+    records [key.lwe_dimension]
+);
+
+instrument_engine!(
+    LweSeededCiphertextEncryptionEngine<SecretKey, Plaintext, Ciphertext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Plaintext: PlaintextEntity,
+        Ciphertext: LweSeededCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_lwe_seeded_ciphertext, encrypt_lwe_seeded_ciphertext_unchecked(
+        key: &SecretKey,
+        input: &Plaintext,
+        noise: Variance,
+    ) -> Result<Ciphertext, LweSeededCiphertextEncryptionError>
+    records [key.lwe_dimension]
+);
+
+instrument_engine!(
+    LweSeededCiphertextExpansionEngine<Input, Output>
+    where {
+        Input: LweSeededCiphertextEntity,
+        Output: LweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn expand_lwe_seeded_ciphertext, expand_lwe_seeded_ciphertext_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweSeededCiphertextExpansionError>
+    records [input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweSeededCiphertextVectorEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CiphertextVector: LweSeededCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    }
+    fn encrypt_lwe_seeded_ciphertext_vector, encrypt_lwe_seeded_ciphertext_vector_unchecked(
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<CiphertextVector, LweSeededCiphertextVectorEncryptionError>
+    records [key.lwe_dimension, input.plaintext_count]
+);
+
+instrument_engine!(
+    LweSeededCiphertextVectorExpansionEngine<Input, Output>
+    where {
+        Input: LweSeededCiphertextVectorEntity,
+        Output: LweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+    }
+    fn expand_lwe_seeded_ciphertext_vector, expand_lwe_seeded_ciphertext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, LweSeededCiphertextVectorExpansionError>
+    records [input.lwe_dimension, input.lwe_ciphertext_count]
+);
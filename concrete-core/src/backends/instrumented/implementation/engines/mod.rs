@@ -0,0 +1,282 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the instrumented
+//! backend.
+
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Instant;
+
+// The number of buckets of the histograms. The bucket `i` counts the calls which lasted between
+// `2^(i-1)` and `2^i` nanoseconds, which covers the durations up to several centuries.
+const HISTOGRAM_BUCKETS: usize = 64;
+
+/// A report of the operations executed by an [`InstrumentedEngine`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InstrumentationReport {
+    /// The statistics of the operations, sorted by decreasing total duration.
+    pub operations: Vec<OperationReport>,
+}
+
+impl InstrumentationReport {
+    /// Exports the report as a JSON string.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to serialize the report.")
+    }
+}
+
+/// The statistics of an operation, executed with a given parameter set.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OperationReport {
+    /// The name of the engine trait of the operation.
+    pub operation: String,
+    /// The parameters of the inputs of the operation, formatted as `name=value` pairs.
+    pub parameters: String,
+    /// The number of calls to the operation.
+    pub calls: u64,
+    /// The cumulated wall-clock duration of the calls, in nanoseconds.
+    pub total_nanos: u64,
+    /// The non-empty buckets of the wall-clock duration histogram of the calls.
+    pub histogram: Vec<HistogramBucket>,
+}
+
+/// A bucket of a duration histogram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HistogramBucket {
+    /// The inclusive lower bound of the durations counted in the bucket, in nanoseconds.
+    pub lower_bound_nanos: u64,
+    /// The exclusive upper bound of the durations counted in the bucket, in nanoseconds.
+    pub upper_bound_nanos: u64,
+    /// The number of calls whose duration falls in the bucket.
+    pub count: u64,
+}
+
+// The statistics recorded for an operation and a parameter set.
+struct OperationRecord {
+    calls: u64,
+    total_nanos: u64,
+    histogram: [u64; HISTOGRAM_BUCKETS],
+}
+
+/// An engine recording the operations it forwards to an inner engine.
+///
+/// This engine implements every engine trait implemented by its inner engine. For each call, it
+/// measures the wall-clock duration of the operation, and records it along with the name of the
+/// operation and the parameters of its inputs. The statistics can then be exported with the
+/// [`report`](`InstrumentedEngine::report`) method.
+///
+/// # Example
+///
+/// ```
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // Here a hard-set encoding is applied (shift by 50 bits)
+/// let input = 3_u64 << 50;
+/// let noise = Variance(2_f64.powf(-25.));
+///
+/// let mut engine = InstrumentedEngine::<CoreEngine>::new()?;
+/// let key: LweSecretKey64 = engine.create_lwe_secret_key(LweDimension(2))?;
+/// let plaintext = engine.create_plaintext(&input)?;
+/// let ciphertext = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+/// let mut output = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+/// engine.fuse_add_lwe_ciphertext(&mut output, &ciphertext)?;
+///
+/// let report = engine.report();
+/// let encryption = report
+///     .operations
+///     .iter()
+///     .find(|operation| operation.operation == "LweCiphertextEncryptionEngine")
+///     .unwrap();
+/// assert_eq!(encryption.calls, 2);
+/// assert_eq!(encryption.parameters, "key.lwe_dimension=2");
+/// assert!(report.to_json().contains("LweCiphertextFusingAdditionEngine"));
+///
+/// engine.destroy(key)?;
+/// engine.destroy(plaintext)?;
+/// engine.destroy(ciphertext)?;
+/// engine.destroy(output)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub struct InstrumentedEngine<Inner: AbstractEngine> {
+    inner: Inner,
+    records: HashMap<(&'static str, String), OperationRecord>,
+}
+
+impl<Inner: AbstractEngine> InstrumentedEngine<Inner> {
+    /// Creates an instrumented engine forwarding the operations to an existing engine.
+    pub fn from_engine(inner: Inner) -> InstrumentedEngine<Inner> {
+        InstrumentedEngine {
+            inner,
+            records: HashMap::new(),
+        }
+    }
+
+    /// Returns a reference to the inner engine.
+    pub fn inner(&self) -> &Inner {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the inner engine.
+    ///
+    /// # Note:
+    ///
+    /// The operations executed directly on the inner engine are not recorded.
+    pub fn inner_mut(&mut self) -> &mut Inner {
+        &mut self.inner
+    }
+
+    /// Consumes the instrumented engine, and returns the inner engine.
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+
+    /// Returns a report of the operations recorded since the creation of the engine, or since the
+    /// last call to [`reset`](`InstrumentedEngine::reset`).
+    pub fn report(&self) -> InstrumentationReport {
+        let mut operations: Vec<OperationReport> = self
+            .records
+            .iter()
+            .map(|((operation, parameters), record)| OperationReport {
+                operation: operation.to_string(),
+                parameters: parameters.clone(),
+                calls: record.calls,
+                total_nanos: record.total_nanos,
+                histogram: record
+                    .histogram
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, count)| **count != 0)
+                    .map(|(bucket, count)| HistogramBucket {
+                        lower_bound_nanos: if bucket == 0 { 0 } else { 1 << (bucket - 1) },
+                        upper_bound_nanos: 1 << bucket,
+                        count: *count,
+                    })
+                    .collect(),
+            })
+            .collect();
+        operations.sort_by(|a, b| {
+            b.total_nanos
+                .cmp(&a.total_nanos)
+                .then_with(|| a.operation.cmp(&b.operation))
+                .then_with(|| a.parameters.cmp(&b.parameters))
+        });
+        InstrumentationReport { operations }
+    }
+
+    /// Discards the operations recorded so far.
+    pub fn reset(&mut self) {
+        self.records.clear();
+    }
+
+    // Forwards an operation to the inner engine, and records its wall-clock duration along with
+    // the parameters of its inputs, formatted as `name=value` pairs.
+    fn instrument<Output>(
+        &mut self,
+        operation: &'static str,
+        parameters: &[(&str, usize)],
+        forward: impl FnOnce(&mut Inner) -> Output,
+    ) -> Output {
+        let parameters = parameters
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let start = Instant::now();
+        let output = forward(&mut self.inner);
+        let nanos = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let bucket = ((u64::BITS - nanos.leading_zeros()) as usize).min(HISTOGRAM_BUCKETS - 1);
+        let record = self
+            .records
+            .entry((operation, parameters))
+            .or_insert(OperationRecord {
+                calls: 0,
+                total_nanos: 0,
+                histogram: [0; HISTOGRAM_BUCKETS],
+            });
+        record.calls += 1;
+        record.total_nanos = record.total_nanos.saturating_add(nanos);
+        record.histogram[bucket] += 1;
+        output
+    }
+}
+
+impl<Inner: AbstractEngine> AbstractEngineSeal for InstrumentedEngine<Inner> {}
+impl<Inner: AbstractEngine> AbstractEngine for InstrumentedEngine<Inner> {
+    type EngineError = Inner::EngineError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(InstrumentedEngine::from_engine(Inner::new()?))
+    }
+}
+
+// Implements an engine trait for the `InstrumentedEngine`, by forwarding its two methods to the
+// inner engine. The parameters recorded for each call are listed after `records`, either as an
+// `input.accessor` pair calling an accessor of an entity, as an `input.len` pair for a slice, or as
+// a single `input` for a parameter passed directly.
+macro_rules! instrument_engine {
+    (@parameter $input:ident.len) => {
+        (concat!(stringify!($input), ".len"), $input.len())
+    };
+    (@parameter $input:ident.$accessor:ident) => {
+        (
+            concat!(stringify!($input), ".", stringify!($accessor)),
+            $input.$accessor().0,
+        )
+    };
+    (@parameter $input:ident) => {
+        (stringify!($input), $input.0)
+    };
+    (
+        $trait:ident<$($generic:ident),* $(,)?> where { $($bounds:tt)* }
+        fn $checked:ident, $unchecked:ident($($arg:ident: $arg_type:ty),* $(,)?)
+            -> Result<$output:ty, $error:ident $(,)?>
+        records [$($input:ident $(.$accessor:ident)?),* $(,)?]
+    ) => {
+        /// # Description:
+        #[doc = concat!(
+            "Implementation of [`",
+            stringify!($trait),
+            "`] for [`InstrumentedEngine`], which records the calls forwarded to the inner engine."
+        )]
+        impl<Inner, $($generic),*> $trait<$($generic),*> for InstrumentedEngine<Inner>
+        where
+            Inner: $trait<$($generic),*>,
+            $($bounds)*
+        {
+            fn $checked(
+                &mut self,
+                $($arg: $arg_type),*
+            ) -> Result<$output, $error<Self::EngineError>> {
+                self.instrument(
+                    stringify!($trait),
+                    &[$(instrument_engine!(@parameter $input $(.$accessor)?)),*],
+                    |inner| <Inner as $trait<$($generic),*>>::$checked(inner, $($arg),*),
+                )
+            }
+
+            unsafe fn $unchecked(&mut self, $($arg: $arg_type),*) -> $output {
+                self.instrument(
+                    stringify!($trait),
+                    &[$(instrument_engine!(@parameter $input $(.$accessor)?)),*],
+                    |inner| <Inner as $trait<$($generic),*>>::$unchecked(inner, $($arg),*),
+                )
+            }
+        }
+    };
+}
+
+mod entities;
+mod ggsw_ciphertexts;
+mod glwe_ciphertexts;
+mod keys;
+mod lwe_ciphertexts;
+#[cfg(feature = "noise_measurement")]
+mod noise_measurement;
+mod plaintexts;
@@ -0,0 +1,67 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    GlweCiphertextNoiseMeasurementEngine, GlweCiphertextNoiseMeasurementError,
+    LweCiphertextNoiseMeasurementEngine, LweCiphertextNoiseMeasurementError,
+    LweCiphertextVectorNoiseMeasurementEngine, LweCiphertextVectorNoiseMeasurementError,
+    NoiseMeasurement,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+    LweSecretKeyEntity, PlaintextEntity, PlaintextVectorEntity,
+};
+
+instrument_engine!(
+    GlweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, PlaintextVector>
+    where {
+        SecretKey: GlweSecretKeyEntity,
+        Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn measure_glwe_ciphertext_noise, measure_glwe_ciphertext_noise_unchecked(
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Result<Vec<NoiseMeasurement>, GlweCiphertextNoiseMeasurementError>
+    records [
+        key.glwe_dimension,
+        key.polynomial_size,
+        input.glwe_dimension,
+        input.polynomial_size,
+        expected.plaintext_count,
+    ]
+);
+
+instrument_engine!(
+    LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, Plaintext>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        Plaintext: PlaintextEntity,
+    }
+    fn measure_lwe_ciphertext_noise, measure_lwe_ciphertext_noise_unchecked(
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError>
+    records [key.lwe_dimension, input.lwe_dimension]
+);
+
+instrument_engine!(
+    LweCiphertextVectorNoiseMeasurementEngine<SecretKey, CiphertextVector, PlaintextVector>
+    where {
+        SecretKey: LweSecretKeyEntity,
+        CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn measure_lwe_ciphertext_vector_noise, measure_lwe_ciphertext_vector_noise_unchecked(
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError>
+    records [
+        key.lwe_dimension,
+        input.lwe_dimension,
+        input.lwe_ciphertext_count,
+        expected.plaintext_count,
+    ]
+);
@@ -0,0 +1,266 @@
+use crate::backends::instrumented::implementation::engines::InstrumentedEngine;
+use crate::specification::engines::{
+    CleartextConversionEngine, CleartextConversionError, CleartextCreationEngine,
+    CleartextCreationError, CleartextDiscardingConversionEngine,
+    CleartextDiscardingConversionError, CleartextEncodingEngine, CleartextEncodingError,
+    CleartextRetrievalEngine, CleartextRetrievalError, CleartextVectorConversionEngine,
+    CleartextVectorConversionError, CleartextVectorCreationEngine, CleartextVectorCreationError,
+    CleartextVectorDiscardingConversionEngine, CleartextVectorDiscardingConversionError,
+    CleartextVectorEncodingEngine, CleartextVectorEncodingError, CleartextVectorRetrievalEngine,
+    CleartextVectorRetrievalError, EncoderCreationEngine, EncoderCreationError,
+    EncoderVectorCreationEngine, EncoderVectorCreationError, PlaintextConversionEngine,
+    PlaintextConversionError, PlaintextCreationEngine, PlaintextCreationError,
+    PlaintextDecodingEngine, PlaintextDecodingError, PlaintextDiscardingConversionEngine,
+    PlaintextDiscardingConversionError, PlaintextVectorConversionEngine,
+    PlaintextVectorConversionError, PlaintextVectorCreationEngine, PlaintextVectorCreationError,
+    PlaintextVectorDecodingEngine, PlaintextVectorDecodingError,
+    PlaintextVectorDiscardingConversionEngine, PlaintextVectorDiscardingConversionError,
+};
+use crate::specification::entities::{
+    CleartextEntity, CleartextVectorEntity, EncoderEntity, EncoderVectorEntity, PlaintextEntity,
+    PlaintextVectorEntity,
+};
+
+instrument_engine!(
+    CleartextConversionEngine<Input, Output>
+    where {
+        Input: CleartextEntity,
+        Output: CleartextEntity,
+    }
+    fn convert_cleartext, convert_cleartext_unchecked(
+        input: &Input,
+    ) -> Result<Output, CleartextConversionError>
+    records []
+);
+
+instrument_engine!(
+    CleartextCreationEngine<Value, Cleartext>
+    where {
+        Cleartext: CleartextEntity,
+    }
+    fn create_cleartext, create_cleartext_unchecked(
+        value: &Value,
+    ) -> Result<Cleartext, CleartextCreationError>
+    records []
+);
+
+instrument_engine!(
+    CleartextDiscardingConversionEngine<Input, Output>
+    where {
+        Input: CleartextEntity,
+        Output: CleartextEntity,
+    }
+    fn discard_convert_cleartext, discard_convert_cleartext_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), CleartextDiscardingConversionError>
+    records []
+);
+
+instrument_engine!(
+    CleartextEncodingEngine<Encoder, Cleartext, Plaintext>
+    where {
+        Encoder: EncoderEntity,
+        Cleartext: CleartextEntity,
+        Plaintext: PlaintextEntity,
+    }
+    fn encode_cleartext, encode_cleartext_unchecked(
+        encoder: &Encoder,
+        cleartext: &Cleartext,
+    ) -> Result<Plaintext, CleartextEncodingError>
+    records []
+);
+
+instrument_engine!(
+    CleartextRetrievalEngine<Cleartext, Value>
+    where {
+        Cleartext: CleartextEntity,
+    }
+    fn retrieve_cleartext, retrieve_cleartext_unchecked(
+        cleartext: &Cleartext,
+    ) -> Result<Value, CleartextRetrievalError>
+    records []
+);
+
+instrument_engine!(
+    CleartextVectorConversionEngine<Input, Output>
+    where {
+        Input: CleartextVectorEntity,
+        Output: CleartextVectorEntity,
+    }
+    fn convert_cleartext_vector, convert_cleartext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, CleartextVectorConversionError>
+    records [input.cleartext_count]
+);
+
+instrument_engine!(
+    CleartextVectorCreationEngine<Value, CleartextVector>
+    where {
+        CleartextVector: CleartextVectorEntity,
+    }
+    fn create_cleartext_vector, create_cleartext_vector_unchecked(
+        values: &[Value],
+    ) -> Result<CleartextVector, CleartextVectorCreationError>
+    records [values.len]
+);
+
+instrument_engine!(
+    CleartextVectorDiscardingConversionEngine<Input, Output>
+    where {
+        Input: CleartextVectorEntity,
+        Output: CleartextVectorEntity,
+    }
+    fn discard_convert_cleartext_vector, discard_convert_cleartext_vector_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), CleartextVectorDiscardingConversionError>
+    records [output.cleartext_count, input.cleartext_count]
+);
+
+instrument_engine!(
+    CleartextVectorEncodingEngine<EncoderVector, CleartextVector, PlaintextVector>
+    where {
+        EncoderVector: EncoderVectorEntity,
+        CleartextVector: CleartextVectorEntity,
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn encode_cleartext_vector, encode_cleartext_vector_unchecked(
+        encoder_vector: &EncoderVector,
+        cleartext_vector: &CleartextVector,
+    ) -> Result<PlaintextVector, CleartextVectorEncodingError>
+    records [encoder_vector.encoder_count, cleartext_vector.cleartext_count]
+);
+
+instrument_engine!(
+    CleartextVectorRetrievalEngine<CleartextVector, Value>
+    where {
+        CleartextVector: CleartextVectorEntity,
+    }
+    fn retrieve_cleartext_vector, retrieve_cleartext_vector_unchecked(
+        cleartext: &CleartextVector,
+    ) -> Result<Vec<Value>, CleartextVectorRetrievalError>
+    records [cleartext.cleartext_count]
+);
+
+instrument_engine!(
+    EncoderCreationEngine<Config, Encoder>
+    where {
+        Encoder: EncoderEntity,
+    }
+    fn create_encoder, create_encoder_unchecked(
+        config: &Config,
+    ) -> Result<Encoder, EncoderCreationError>
+    records []
+);
+
+instrument_engine!(
+    EncoderVectorCreationEngine<Config, EncoderVector>
+    where {
+        EncoderVector: EncoderVectorEntity,
+    }
+    fn create_encoder_vector, create_encoder_vector_unchecked(
+        config: &[Config],
+    ) -> Result<EncoderVector, EncoderVectorCreationError>
+    records [config.len]
+);
+
+instrument_engine!(
+    PlaintextConversionEngine<Input, Output>
+    where {
+        Input: PlaintextEntity,
+        Output: PlaintextEntity,
+    }
+    fn convert_plaintext, convert_plaintext_unchecked(
+        input: &Input,
+    ) -> Result<Output, PlaintextConversionError>
+    records []
+);
+
+instrument_engine!(
+    PlaintextCreationEngine<Value, Plaintext>
+    where {
+        Plaintext: PlaintextEntity,
+    }
+    fn create_plaintext, create_plaintext_unchecked(
+        value: &Value,
+    ) -> Result<Plaintext, PlaintextCreationError>
+    records []
+);
+
+instrument_engine!(
+    PlaintextDecodingEngine<Encoder, Plaintext, Cleartext>
+    where {
+        Plaintext: PlaintextEntity,
+        Cleartext: CleartextEntity,
+        Encoder: EncoderEntity,
+    }
+    fn decode_plaintext, decode_plaintext_unchecked(
+        encoder: &Encoder,
+        input: &Plaintext,
+    ) -> Result<Cleartext, PlaintextDecodingError>
+    records []
+);
+
+instrument_engine!(
+    PlaintextDiscardingConversionEngine<Input, Output>
+    where {
+        Input: PlaintextEntity,
+        Output: PlaintextEntity,
+    }
+    fn discard_convert_plaintext, discard_convert_plaintext_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), PlaintextDiscardingConversionError>
+    records []
+);
+
+instrument_engine!(
+    PlaintextVectorConversionEngine<Input, Output>
+    where {
+        Input: PlaintextVectorEntity,
+        Output: PlaintextVectorEntity,
+    }
+    fn convert_plaintext_vector, convert_plaintext_vector_unchecked(
+        input: &Input,
+    ) -> Result<Output, PlaintextVectorConversionError>
+    records [input.plaintext_count]
+);
+
+instrument_engine!(
+    PlaintextVectorCreationEngine<Value, PlaintextVector>
+    where {
+        PlaintextVector: PlaintextVectorEntity,
+    }
+    fn create_plaintext_vector, create_plaintext_vector_unchecked(
+        values: &[Value],
+    ) -> Result<PlaintextVector, PlaintextVectorCreationError>
+    records [values.len]
+);
+
+instrument_engine!(
+    PlaintextVectorDecodingEngine<EncoderVector, PlaintextVector, CleartextVector>
+    where {
+        EncoderVector: EncoderVectorEntity,
+        PlaintextVector: PlaintextVectorEntity,
+        CleartextVector: CleartextVectorEntity,
+    }
+    fn decode_plaintext_vector, decode_plaintext_vector_unchecked(
+        encoder: &EncoderVector,
+        input: &PlaintextVector,
+    ) -> Result<CleartextVector, PlaintextVectorDecodingError>
+    records [encoder.encoder_count, input.plaintext_count]
+);
+
+instrument_engine!(
+    PlaintextVectorDiscardingConversionEngine<Input, Output>
+    where {
+        Input: PlaintextVectorEntity,
+        Output: PlaintextVectorEntity,
+    }
+    fn discard_convert_plaintext_vector, discard_convert_plaintext_vector_unchecked(
+        output: &mut Output,
+        input: &Input,
+    ) -> Result<(), PlaintextVectorDiscardingConversionError>
+    records [output.plaintext_count, input.plaintext_count]
+);
@@ -0,0 +1,12 @@
+//! A module containing the instrumented backend implementation.
+//!
+//! This module contains a backend wrapping any other engine, which records the number of calls
+//! and the wall-clock duration of the operations it forwards, for each operation and parameter
+//! set. It makes it possible to profile an application without modifying the backend it uses.
+//!
+//! This backend has no entities of its own: the entities used along with it are the ones of the
+//! inner engine.
+
+mod implementation;
+
+pub use implementation::engines;
@@ -1,9 +1,25 @@
 //! A module containing various backends implementing the `concrete` FHE scheme.
 //!
 //! This module contains all the backends implementing the concrete specification. As of now we
-//! support the following backend:
+//! support the following backends:
 //!
+//! + `cleartext` : A mock backend, whose ciphertexts hold their phase in clear, meant to quickly
+//!   test applications.
 //! + `core` : A single threaded CPU backend geared towards x86_64 architectures.
+//! + `dynamic` : A backend forwarding a subset of the 64 bits operations to the `core` or `cleartext`
+//!   backend, selected at runtime.
+//! + `instrumented` : A backend wrapping any other engine, which records the calls and durations
+//!   of the operations.
+//! + `noise_tracked` : A backend wrapping the `core` backend, which tracks the variance of the
+//!   noise of its ciphertexts.
 
+#[cfg(feature = "backend_cleartext")]
+pub mod cleartext;
 #[cfg(feature = "backend_core")]
 pub mod core;
+#[cfg(feature = "backend_dyn")]
+pub mod dynamic;
+#[cfg(feature = "backend_instrumented")]
+pub mod instrumented;
+#[cfg(feature = "backend_noise_tracked")]
+pub mod noise_tracked;
@@ -0,0 +1,62 @@
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::{
+    NoiseTrackedFourierLweBootstrapKey64, NoiseTrackedLweCiphertext64,
+    NoiseTrackedLweCiphertextVector64, NoiseTrackedLweKeyswitchKey64,
+};
+use crate::specification::engines::{DestructionEngine, DestructionError};
+
+impl DestructionEngine<NoiseTrackedLweCiphertext64> for NoiseTrackedEngine {
+    fn destroy(
+        &mut self,
+        entity: NoiseTrackedLweCiphertext64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: NoiseTrackedLweCiphertext64) {
+        self.core.destroy_unchecked(entity.ciphertext);
+    }
+}
+
+impl DestructionEngine<NoiseTrackedLweCiphertextVector64> for NoiseTrackedEngine {
+    fn destroy(
+        &mut self,
+        entity: NoiseTrackedLweCiphertextVector64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: NoiseTrackedLweCiphertextVector64) {
+        self.core.destroy_unchecked(entity.ciphertexts);
+    }
+}
+
+impl DestructionEngine<NoiseTrackedLweKeyswitchKey64> for NoiseTrackedEngine {
+    fn destroy(
+        &mut self,
+        entity: NoiseTrackedLweKeyswitchKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: NoiseTrackedLweKeyswitchKey64) {
+        self.core.destroy_unchecked(entity.key);
+    }
+}
+
+impl DestructionEngine<NoiseTrackedFourierLweBootstrapKey64> for NoiseTrackedEngine {
+    fn destroy(
+        &mut self,
+        entity: NoiseTrackedFourierLweBootstrapKey64,
+    ) -> Result<(), DestructionError<Self::EngineError>> {
+        unsafe { self.destroy_unchecked(entity) };
+        Ok(())
+    }
+
+    unsafe fn destroy_unchecked(&mut self, entity: NoiseTrackedFourierLweBootstrapKey64) {
+        self.core.destroy_unchecked(entity.key);
+    }
+}
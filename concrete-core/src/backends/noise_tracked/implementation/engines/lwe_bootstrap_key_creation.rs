@@ -0,0 +1,100 @@
+use crate::backends::core::entities::{GlweSecretKey64, LweSecretKey64};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedFourierLweBootstrapKey64;
+use crate::specification::engines::{LweBootstrapKeyCreationEngine, LweBootstrapKeyCreationError};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+/// # Description:
+/// Implementation of [`LweBootstrapKeyCreationEngine`] for [`NoiseTrackedEngine`] that operates
+/// on 64 bits integers. It outputs a bootstrap key in the fourier domain.
+impl
+    LweBootstrapKeyCreationEngine<
+        LweSecretKey64,
+        GlweSecretKey64,
+        NoiseTrackedFourierLweBootstrapKey64,
+    > for NoiseTrackedEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, glwe_dim, poly_size) = (LweDimension(4), GlweDimension(6), PolynomialSize(256));
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    ///
+    /// let bsk: NoiseTrackedFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, noise)?;
+    /// #
+    /// assert_eq!(bsk.input_lwe_dimension(), lwe_dim);
+    /// assert_eq!(bsk.output_lwe_dimension(), LweDimension(glwe_dim.0 * poly_size.0));
+    /// assert_eq!(bsk.variance(), noise);
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// engine.destroy(bsk)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn create_lwe_bootstrap_key(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> Result<NoiseTrackedFourierLweBootstrapKey64, LweBootstrapKeyCreationError<Self::EngineError>>
+    {
+        if decomposition_base_log.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionBaseLog);
+        }
+        if decomposition_level_count.0 == 0 {
+            return Err(LweBootstrapKeyCreationError::NullDecompositionLevelCount);
+        }
+        if decomposition_base_log.0 * decomposition_level_count.0 > 64 {
+            return Err(LweBootstrapKeyCreationError::DecompositionTooLarge);
+        }
+        Ok(unsafe {
+            self.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            )
+        })
+    }
+
+    unsafe fn create_lwe_bootstrap_key_unchecked(
+        &mut self,
+        input_key: &LweSecretKey64,
+        output_key: &GlweSecretKey64,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        noise: Variance,
+    ) -> NoiseTrackedFourierLweBootstrapKey64 {
+        NoiseTrackedFourierLweBootstrapKey64 {
+            key: self.core.create_lwe_bootstrap_key_unchecked(
+                input_key,
+                output_key,
+                decomposition_base_log,
+                decomposition_level_count,
+                noise,
+            ),
+            variance: noise,
+        }
+    }
+}
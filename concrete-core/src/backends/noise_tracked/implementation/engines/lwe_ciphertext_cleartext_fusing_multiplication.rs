@@ -0,0 +1,87 @@
+use crate::backends::core::entities::Cleartext64;
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextCleartextFusingMultiplicationEngine,
+    LweCiphertextCleartextFusingMultiplicationError,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// # Description:
+/// Implementation of [`LweCiphertextCleartextFusingMultiplicationEngine`] for
+/// [`NoiseTrackedEngine`] that operates on 64 bits integers.
+impl LweCiphertextCleartextFusingMultiplicationEngine<NoiseTrackedLweCiphertext64, Cleartext64>
+    for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let cleartext: Cleartext64 = core_engine.create_cleartext(&12_u64)?;
+    /// let large_cleartext: Cleartext64 = core_engine.create_cleartext(&1024_u64)?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let mut ciphertext: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// engine.fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &cleartext)?;
+    /// #
+    /// assert_eq!(ciphertext.variance(), Variance(144. * 2_f64.powf(-25.)));
+    ///
+    /// // The noise would be too large for a message of 4 bits after this multiplication.
+    /// assert!(engine
+    ///     .fuse_mul_lwe_ciphertext_cleartext(&mut ciphertext, &large_cleartext)
+    ///     .is_err());
+    ///
+    /// core_engine.destroy(cleartext)?;
+    /// core_engine.destroy(large_cleartext)?;
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_mul_lwe_ciphertext_cleartext(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &Cleartext64,
+    ) -> Result<(), LweCiphertextCleartextFusingMultiplicationError<Self::EngineError>> {
+        self.check_variance(predict_variance(output.variance, input))
+            .map_err(LweCiphertextCleartextFusingMultiplicationError::Engine)?;
+        unsafe { self.fuse_mul_lwe_ciphertext_cleartext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_mul_lwe_ciphertext_cleartext_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &Cleartext64,
+    ) {
+        self.core
+            .fuse_mul_lwe_ciphertext_cleartext_unchecked(&mut output.ciphertext, input);
+        output.variance = predict_variance(output.variance, input);
+    }
+}
+
+// Predicts the variance of the product of a ciphertext with the given variance and a cleartext.
+fn predict_variance(variance: Variance, cleartext: &Cleartext64) -> Variance {
+    // The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_integer_plaintext_multiplication_noise::<u64, _>(
+        variance,
+        cleartext.0 .0,
+    );
+    Variance::from_modular_variance::<u64>(modular_variance.get_variance())
+}
@@ -0,0 +1,59 @@
+use crate::backends::core::entities::{LweSecretKey64, Plaintext64};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertext64;
+use crate::specification::engines::{LweCiphertextDecryptionEngine, LweCiphertextDecryptionError};
+
+/// # Description:
+/// Implementation of [`LweCiphertextDecryptionEngine`] for [`NoiseTrackedEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextDecryptionEngine<LweSecretKey64, NoiseTrackedLweCiphertext64, Plaintext64>
+    for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    ///
+    /// let decrypted_plaintext = engine.decrypt_lwe_ciphertext(&key, &ciphertext)?;
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(decrypted_plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NoiseTrackedLweCiphertext64,
+    ) -> Result<Plaintext64, LweCiphertextDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NoiseTrackedLweCiphertext64,
+    ) -> Plaintext64 {
+        self.core
+            .decrypt_lwe_ciphertext_unchecked(key, &input.ciphertext)
+    }
+}
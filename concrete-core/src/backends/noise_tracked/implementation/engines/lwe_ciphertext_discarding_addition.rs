@@ -0,0 +1,86 @@
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextDiscardingAdditionEngine, LweCiphertextDiscardingAdditionError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingAdditionEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextDiscardingAdditionEngine<NoiseTrackedLweCiphertext64, NoiseTrackedLweCiphertext64>
+    for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 5_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let ciphertext_1: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    /// let mut ciphertext_3 = engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    ///
+    /// engine.discard_add_lwe_ciphertext(&mut ciphertext_3, &ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_3.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// engine.destroy(ciphertext_3)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input_1: &NoiseTrackedLweCiphertext64,
+        input_2: &NoiseTrackedLweCiphertext64,
+    ) -> Result<(), LweCiphertextDiscardingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input_1.lwe_dimension()
+            || output.lwe_dimension() != input_2.lwe_dimension()
+        {
+            return Err(LweCiphertextDiscardingAdditionError::LweDimensionMismatch);
+        }
+        let variance =
+            concrete_npe::estimate_addition_noise::<u64, _, _>(input_1.variance, input_2.variance);
+        self.check_variance(variance)
+            .map_err(LweCiphertextDiscardingAdditionError::Engine)?;
+        unsafe { self.discard_add_lwe_ciphertext_unchecked(output, input_1, input_2) };
+        Ok(())
+    }
+
+    unsafe fn discard_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input_1: &NoiseTrackedLweCiphertext64,
+        input_2: &NoiseTrackedLweCiphertext64,
+    ) {
+        self.core.discard_add_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input_1.ciphertext,
+            &input_2.ciphertext,
+        );
+        output.variance =
+            concrete_npe::estimate_addition_noise::<u64, _, _>(input_1.variance, input_2.variance);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::backends::core::entities::GlweCiphertext64;
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::{
+    NoiseTrackedFourierLweBootstrapKey64, NoiseTrackedLweCiphertext64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingBootstrapEngine, LweCiphertextDiscardingBootstrapError,
+};
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingBootstrapEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+///
+/// The accumulator is assumed to be noiseless, which is the case when it is a trivial encryption
+/// of the lookup table. The output noise then only depends on the bootstrap key.
+impl
+    LweCiphertextDiscardingBootstrapEngine<
+        NoiseTrackedFourierLweBootstrapKey64,
+        GlweCiphertext64,
+        NoiseTrackedLweCiphertext64,
+        NoiseTrackedLweCiphertext64,
+    > for NoiseTrackedEngine
+{
+    /// # Example
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let (lwe_dim, lwe_dim_output, glwe_dim, poly_size) = (
+    ///     LweDimension(4),
+    ///     LweDimension(1024),
+    ///     GlweDimension(1),
+    ///     PolynomialSize(1024),
+    /// );
+    /// let (dec_lc, dec_bl) = (DecompositionLevelCount(3), DecompositionBaseLog(5));
+    /// // A constant function is applied during the bootstrap
+    /// let lut = vec![8_u64 << 50; poly_size.0];
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let bsk_noise = Variance(2_f64.powf(-50.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let lwe_sk: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim)?;
+    /// let glwe_sk: GlweSecretKey64 = core_engine.create_glwe_secret_key(glwe_dim, poly_size)?;
+    /// let bsk: NoiseTrackedFourierLweBootstrapKey64 =
+    ///     engine.create_lwe_bootstrap_key(&lwe_sk, &glwe_sk, dec_bl, dec_lc, bsk_noise)?;
+    /// let lwe_sk_output: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dim_output)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&lut)?;
+    /// // The accumulator is encrypted without noise.
+    /// let acc = core_engine.encrypt_glwe_ciphertext(&glwe_sk, &plaintext_vector, Variance(0.))?;
+    /// let input: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&lwe_sk, &plaintext, noise)?;
+    /// let mut output = engine.encrypt_lwe_ciphertext(&lwe_sk_output, &plaintext, noise)?;
+    ///
+    /// engine.discard_bootstrap_lwe_ciphertext(&mut output, &input, &acc, &bsk)?;
+    /// #
+    /// assert_eq!(output.lwe_dimension(), lwe_dim_output);
+    /// // The output variance only depends on the parameters of the bootstrap key.
+    /// assert!(output.variance().0 < 2_f64.powf(-20.));
+    ///
+    /// core_engine.destroy(lwe_sk)?;
+    /// core_engine.destroy(glwe_sk)?;
+    /// core_engine.destroy(lwe_sk_output)?;
+    /// core_engine.destroy(plaintext)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(acc)?;
+    /// engine.destroy(bsk)?;
+    /// engine.destroy(input)?;
+    /// engine.destroy(output)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NoiseTrackedFourierLweBootstrapKey64,
+    ) -> Result<(), LweCiphertextDiscardingBootstrapError<Self::EngineError>> {
+        if input.lwe_dimension() != bsk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::InputLweDimensionMismatch);
+        }
+        if acc.polynomial_size() != bsk.polynomial_size() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorPolynomialSizeMismatch);
+        }
+        if acc.glwe_dimension() != bsk.glwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::AccumulatorGlweDimensionMismatch);
+        }
+        if output.lwe_dimension() != bsk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingBootstrapError::OutputLweDimensionMismatch);
+        }
+        self.check_variance(predict_variance(bsk))
+            .map_err(LweCiphertextDiscardingBootstrapError::Engine)?;
+        unsafe { self.discard_bootstrap_lwe_ciphertext_unchecked(output, input, acc, bsk) };
+        Ok(())
+    }
+
+    unsafe fn discard_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+        acc: &GlweCiphertext64,
+        bsk: &NoiseTrackedFourierLweBootstrapKey64,
+    ) {
+        self.core.discard_bootstrap_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            acc,
+            &bsk.key,
+        );
+        output.variance = predict_variance(bsk);
+    }
+}
+
+// Predicts the variance of a ciphertext bootstrapped with the given key.
+fn predict_variance(bsk: &NoiseTrackedFourierLweBootstrapKey64) -> Variance {
+    // The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+        bsk.input_lwe_dimension(),
+        bsk.polynomial_size(),
+        bsk.glwe_dimension(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+        bsk.variance,
+    );
+    Variance::from_modular_variance::<u64>(modular_variance.get_variance())
+}
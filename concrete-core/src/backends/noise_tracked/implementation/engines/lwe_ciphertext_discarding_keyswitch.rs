@@ -0,0 +1,126 @@
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::{
+    NoiseTrackedLweCiphertext64, NoiseTrackedLweKeyswitchKey64,
+};
+use crate::specification::engines::{
+    LweCiphertextDiscardingKeyswitchEngine, LweCiphertextDiscardingKeyswitchError,
+};
+use crate::specification::entities::{LweCiphertextEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
+
+/// # Description:
+/// Implementation of [`LweCiphertextDiscardingKeyswitchEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextDiscardingKeyswitchEngine<
+        NoiseTrackedLweKeyswitchKey64,
+        NoiseTrackedLweCiphertext64,
+        NoiseTrackedLweCiphertext64,
+    > for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{
+    ///     DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+    /// };
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let input_lwe_dimension = LweDimension(6);
+    /// let output_lwe_dimension = LweDimension(3);
+    /// let decomposition_level_count = DecompositionLevelCount(2);
+    /// let decomposition_base_log = DecompositionBaseLog(8);
+    /// let noise = Variance(2_f64.powf(-25.));
+    /// let ksk_noise = Variance(2_f64.powf(-50.));
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let input_key: LweSecretKey64 = core_engine.create_lwe_secret_key(input_lwe_dimension)?;
+    /// let output_key: LweSecretKey64 = core_engine.create_lwe_secret_key(output_lwe_dimension)?;
+    /// let keyswitch_key: NoiseTrackedLweKeyswitchKey64 = engine.create_lwe_keyswitch_key(
+    ///     &input_key,
+    ///     &output_key,
+    ///     decomposition_level_count,
+    ///     decomposition_base_log,
+    ///     ksk_noise,
+    /// )?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    /// let ciphertext_1: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&input_key, &plaintext, noise)?;
+    /// let mut ciphertext_2 = engine.encrypt_lwe_ciphertext(&output_key, &plaintext, noise)?;
+    ///
+    /// engine.discard_keyswitch_lwe_ciphertext(&mut ciphertext_2, &ciphertext_1, &keyswitch_key)?;
+    /// #
+    /// assert_eq!(ciphertext_2.lwe_dimension(), output_lwe_dimension);
+    /// // The keyswitch adds noise to the input ciphertext.
+    /// assert!(ciphertext_2.variance().0 > ciphertext_1.variance().0);
+    ///
+    /// core_engine.destroy(input_key)?;
+    /// core_engine.destroy(output_key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(keyswitch_key)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_keyswitch_lwe_ciphertext(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+        ksk: &NoiseTrackedLweKeyswitchKey64,
+    ) -> Result<(), LweCiphertextDiscardingKeyswitchError<Self::EngineError>> {
+        if input.lwe_dimension() != ksk.input_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::InputLweDimensionMismatch);
+        }
+        if output.lwe_dimension() != ksk.output_lwe_dimension() {
+            return Err(LweCiphertextDiscardingKeyswitchError::OutputLweDimensionMismatch);
+        }
+        self.check_variance(predict_variance(input, ksk))
+            .map_err(LweCiphertextDiscardingKeyswitchError::Engine)?;
+        unsafe { self.discard_keyswitch_lwe_ciphertext_unchecked(output, input, ksk) };
+        Ok(())
+    }
+
+    unsafe fn discard_keyswitch_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+        ksk: &NoiseTrackedLweKeyswitchKey64,
+    ) {
+        self.core.discard_keyswitch_lwe_ciphertext_unchecked(
+            &mut output.ciphertext,
+            &input.ciphertext,
+            &ksk.key,
+        );
+        output.variance = predict_variance(input, ksk);
+    }
+}
+
+// Predicts the variance of a ciphertext keyswitched with the given key.
+fn predict_variance(
+    input: &NoiseTrackedLweCiphertext64,
+    ksk: &NoiseTrackedLweKeyswitchKey64,
+) -> Variance {
+    // The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_keyswitch_noise_lwe_to_glwe_with_constant_terms::<
+        u64,
+        _,
+        _,
+        BinaryKeyKind,
+    >(
+        ksk.input_lwe_dimension(),
+        input.variance,
+        ksk.variance,
+        ksk.decomposition_base_log(),
+        ksk.decomposition_level_count(),
+    );
+    Variance::from_modular_variance::<u64>(modular_variance.get_variance())
+}
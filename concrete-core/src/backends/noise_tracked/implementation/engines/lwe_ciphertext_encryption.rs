@@ -0,0 +1,69 @@
+use crate::backends::core::entities::{LweSecretKey64, Plaintext64};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertext64;
+use crate::specification::engines::{LweCiphertextEncryptionEngine, LweCiphertextEncryptionError};
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextEncryptionEngine`] for [`NoiseTrackedEngine`] that operates on
+/// 64 bits integers.
+impl LweCiphertextEncryptionEngine<LweSecretKey64, Plaintext64, NoiseTrackedLweCiphertext64>
+    for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = 3_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext = core_engine.create_plaintext(&input)?;
+    ///
+    /// let ciphertext: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+    /// #
+    /// assert_eq!(ciphertext.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(ciphertext.variance(), noise);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext)?;
+    /// engine.destroy(ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> Result<NoiseTrackedLweCiphertext64, LweCiphertextEncryptionError<Self::EngineError>> {
+        self.check_variance(noise)
+            .map_err(LweCiphertextEncryptionError::Engine)?;
+        Ok(unsafe { self.encrypt_lwe_ciphertext_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &Plaintext64,
+        noise: Variance,
+    ) -> NoiseTrackedLweCiphertext64 {
+        NoiseTrackedLweCiphertext64 {
+            ciphertext: self
+                .core
+                .encrypt_lwe_ciphertext_unchecked(key, input, noise),
+            variance: noise,
+        }
+    }
+}
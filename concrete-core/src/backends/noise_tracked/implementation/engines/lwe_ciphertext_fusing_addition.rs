@@ -0,0 +1,77 @@
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertext64;
+use crate::specification::engines::{
+    LweCiphertextFusingAdditionEngine, LweCiphertextFusingAdditionError,
+};
+use crate::specification::entities::LweCiphertextEntity;
+
+/// # Description:
+/// Implementation of [`LweCiphertextFusingAdditionEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+impl LweCiphertextFusingAdditionEngine<NoiseTrackedLweCiphertext64, NoiseTrackedLweCiphertext64>
+    for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input_1 = 3_u64 << 50;
+    /// let input_2 = 5_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_1 = core_engine.create_plaintext(&input_1)?;
+    /// let plaintext_2 = core_engine.create_plaintext(&input_2)?;
+    /// let mut ciphertext_1: NoiseTrackedLweCiphertext64 =
+    ///     engine.encrypt_lwe_ciphertext(&key, &plaintext_1, noise)?;
+    /// let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext_2, noise)?;
+    ///
+    /// engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+    /// #
+    /// assert_eq!(ciphertext_1.variance(), Variance(2_f64.powf(-24.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_1)?;
+    /// core_engine.destroy(plaintext_2)?;
+    /// engine.destroy(ciphertext_1)?;
+    /// engine.destroy(ciphertext_2)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn fuse_add_lwe_ciphertext(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+    ) -> Result<(), LweCiphertextFusingAdditionError<Self::EngineError>> {
+        if output.lwe_dimension() != input.lwe_dimension() {
+            return Err(LweCiphertextFusingAdditionError::LweDimensionMismatch);
+        }
+        let variance =
+            concrete_npe::estimate_addition_noise::<u64, _, _>(output.variance, input.variance);
+        self.check_variance(variance)
+            .map_err(LweCiphertextFusingAdditionError::Engine)?;
+        unsafe { self.fuse_add_lwe_ciphertext_unchecked(output, input) };
+        Ok(())
+    }
+
+    unsafe fn fuse_add_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        input: &NoiseTrackedLweCiphertext64,
+    ) {
+        self.core
+            .fuse_add_lwe_ciphertext_unchecked(&mut output.ciphertext, &input.ciphertext);
+        output.variance =
+            concrete_npe::estimate_addition_noise::<u64, _, _>(output.variance, input.variance);
+    }
+}
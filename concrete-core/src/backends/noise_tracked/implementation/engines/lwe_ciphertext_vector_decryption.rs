@@ -0,0 +1,68 @@
+use crate::backends::core::entities::{LweSecretKey64, PlaintextVector64};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertextVector64;
+use crate::specification::engines::{
+    LweCiphertextVectorDecryptionEngine, LweCiphertextVectorDecryptionError,
+};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDecryptionEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorDecryptionEngine<
+        LweSecretKey64,
+        NoiseTrackedLweCiphertextVector64,
+        PlaintextVector64,
+    > for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweDimension, PlaintextCount};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 18];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    /// let ciphertext_vector: NoiseTrackedLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    ///
+    /// let decrypted_plaintext_vector =
+    ///     engine.decrypt_lwe_ciphertext_vector(&key, &ciphertext_vector)?;
+    /// #
+    /// assert_eq!(decrypted_plaintext_vector.plaintext_count(), PlaintextCount(18));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// core_engine.destroy(decrypted_plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn decrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NoiseTrackedLweCiphertextVector64,
+    ) -> Result<PlaintextVector64, LweCiphertextVectorDecryptionError<Self::EngineError>> {
+        Ok(unsafe { self.decrypt_lwe_ciphertext_vector_unchecked(key, input) })
+    }
+
+    unsafe fn decrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &NoiseTrackedLweCiphertextVector64,
+    ) -> PlaintextVector64 {
+        self.core
+            .decrypt_lwe_ciphertext_vector_unchecked(key, &input.ciphertexts)
+    }
+}
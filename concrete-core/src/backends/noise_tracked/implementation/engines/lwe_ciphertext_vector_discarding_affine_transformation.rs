@@ -0,0 +1,130 @@
+use crate::backends::core::entities::{CleartextVector64, Plaintext64};
+use crate::backends::core::private::math::tensor::{AsRefSlice, AsRefTensor};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::{
+    NoiseTrackedLweCiphertext64, NoiseTrackedLweCiphertextVector64,
+};
+use crate::specification::engines::{
+    LweCiphertextVectorDiscardingAffineTransformationEngine,
+    LweCiphertextVectorDiscardingAffineTransformationError,
+};
+use crate::specification::entities::{
+    CleartextVectorEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+};
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorDiscardingAffineTransformationEngine`] for
+/// [`NoiseTrackedEngine`] that operates on 64 bits integers.
+impl
+    LweCiphertextVectorDiscardingAffineTransformationEngine<
+        NoiseTrackedLweCiphertextVector64,
+        CleartextVector64,
+        Plaintext64,
+        NoiseTrackedLweCiphertext64,
+    > for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::LweDimension;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(2);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let inputs = vec![3_u64 << 50; 3];
+    /// let weights_input = vec![2_u64, 3, 4];
+    /// let bias_input = 8_u64 << 50;
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let weights: CleartextVector64 = core_engine.create_cleartext_vector(&weights_input)?;
+    /// let bias: Plaintext64 = core_engine.create_plaintext(&bias_input)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&inputs)?;
+    /// let ciphertext_vector: NoiseTrackedLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// let mut output_ciphertext = engine.encrypt_lwe_ciphertext(&key, &bias, noise)?;
+    ///
+    /// engine.discard_affine_transform_lwe_ciphertext_vector(
+    ///     &mut output_ciphertext,
+    ///     &ciphertext_vector,
+    ///     &weights,
+    ///     &bias,
+    /// )?;
+    /// #
+    /// // The weights contribute to the output variance by the sum of their squares.
+    /// assert_eq!(output_ciphertext.variance(), Variance(29. * 2_f64.powf(-25.)));
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(weights)?;
+    /// core_engine.destroy(bias)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// engine.destroy(output_ciphertext)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn discard_affine_transform_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        inputs: &NoiseTrackedLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) -> Result<(), LweCiphertextVectorDiscardingAffineTransformationError<Self::EngineError>> {
+        if output.lwe_dimension() != inputs.lwe_dimension() {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::LweDimensionMismatch,
+            );
+        }
+        if inputs.lwe_ciphertext_count().0 != weights.cleartext_count().0 {
+            return Err(
+                LweCiphertextVectorDiscardingAffineTransformationError::CleartextCountMismatch,
+            );
+        }
+        self.check_variance(predict_variance(inputs, weights))
+            .map_err(LweCiphertextVectorDiscardingAffineTransformationError::Engine)?;
+        unsafe {
+            self.discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                output, inputs, weights, bias,
+            )
+        };
+        Ok(())
+    }
+
+    unsafe fn discard_affine_transform_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut NoiseTrackedLweCiphertext64,
+        inputs: &NoiseTrackedLweCiphertextVector64,
+        weights: &CleartextVector64,
+        bias: &Plaintext64,
+    ) {
+        self.core
+            .discard_affine_transform_lwe_ciphertext_vector_unchecked(
+                &mut output.ciphertext,
+                &inputs.ciphertexts,
+                weights,
+                bias,
+            );
+        output.variance = predict_variance(inputs, weights);
+    }
+}
+
+// Predicts the variance of the weighted sum of the ciphertexts of a vector. The bias being a
+// plaintext, it does not contribute to the noise.
+fn predict_variance(
+    inputs: &NoiseTrackedLweCiphertextVector64,
+    weights: &CleartextVector64,
+) -> Variance {
+    // The estimator returns a variance in the integer representation of the torus.
+    let modular_variance = concrete_npe::estimate_weighted_sum_noise::<u64, _>(
+        &inputs.variances,
+        weights.0.as_tensor().as_slice(),
+    );
+    Variance::from_modular_variance::<u64>(modular_variance.get_variance())
+}
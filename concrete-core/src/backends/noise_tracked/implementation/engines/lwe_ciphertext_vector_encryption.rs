@@ -0,0 +1,79 @@
+use crate::backends::core::entities::{LweSecretKey64, PlaintextVector64};
+use crate::backends::noise_tracked::implementation::engines::NoiseTrackedEngine;
+use crate::backends::noise_tracked::implementation::entities::NoiseTrackedLweCiphertextVector64;
+use crate::specification::engines::{
+    LweCiphertextVectorEncryptionEngine, LweCiphertextVectorEncryptionError,
+};
+use crate::specification::entities::PlaintextVectorEntity;
+use concrete_commons::dispersion::Variance;
+
+/// # Description:
+/// Implementation of [`LweCiphertextVectorEncryptionEngine`] for [`NoiseTrackedEngine`] that
+/// operates on 64 bits integers.
+impl
+    LweCiphertextVectorEncryptionEngine<
+        LweSecretKey64,
+        PlaintextVector64,
+        NoiseTrackedLweCiphertextVector64,
+    > for NoiseTrackedEngine
+{
+    /// # Example:
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+    /// let lwe_dimension = LweDimension(6);
+    /// // Here a hard-set encoding is applied (shift by 50 bits)
+    /// let input = vec![3_u64 << 50; 3];
+    /// let noise = Variance(2_f64.powf(-25.));
+    ///
+    /// let mut core_engine = CoreEngine::new()?;
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// let key: LweSecretKey64 = core_engine.create_lwe_secret_key(lwe_dimension)?;
+    /// let plaintext_vector = core_engine.create_plaintext_vector(&input)?;
+    ///
+    /// let ciphertext_vector: NoiseTrackedLweCiphertextVector64 =
+    ///     engine.encrypt_lwe_ciphertext_vector(&key, &plaintext_vector, noise)?;
+    /// #
+    /// assert_eq!(ciphertext_vector.lwe_ciphertext_count(), LweCiphertextCount(3));
+    /// assert_eq!(ciphertext_vector.variances(), &[noise; 3]);
+    ///
+    /// core_engine.destroy(key)?;
+    /// core_engine.destroy(plaintext_vector)?;
+    /// engine.destroy(ciphertext_vector)?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn encrypt_lwe_ciphertext_vector(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> Result<
+        NoiseTrackedLweCiphertextVector64,
+        LweCiphertextVectorEncryptionError<Self::EngineError>,
+    > {
+        self.check_variance(noise)
+            .map_err(LweCiphertextVectorEncryptionError::Engine)?;
+        Ok(unsafe { self.encrypt_lwe_ciphertext_vector_unchecked(key, input, noise) })
+    }
+
+    unsafe fn encrypt_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        key: &LweSecretKey64,
+        input: &PlaintextVector64,
+        noise: Variance,
+    ) -> NoiseTrackedLweCiphertextVector64 {
+        NoiseTrackedLweCiphertextVector64 {
+            ciphertexts: self
+                .core
+                .encrypt_lwe_ciphertext_vector_unchecked(key, input, noise),
+            variances: vec![noise; input.plaintext_count().0],
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! A module containing the [engines](crate::specification::engines) exposed by the noise tracked
+//! backend.
+
+use crate::backends::core::engines::{CoreEngine, CoreError};
+use crate::specification::engines::sealed::AbstractEngineSeal;
+use crate::specification::engines::AbstractEngine;
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The error which can occur in the execution of FHE operations, due to the noise tracked
+/// implementation.
+#[derive(Debug)]
+pub enum NoiseTrackedError {
+    Core(CoreError),
+    FailureProbabilityExceeded,
+}
+impl Display for NoiseTrackedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoiseTrackedError::Core(error) => {
+                write!(f, "Error occurred in the core engine: {}", error)
+            }
+            NoiseTrackedError::FailureProbabilityExceeded => {
+                write!(
+                    f,
+                    "The predicted failure probability of the output exceeds the threshold."
+                )
+            }
+        }
+    }
+}
+impl Error for NoiseTrackedError {}
+
+/// The threshold above which the [`NoiseTrackedEngine`] refuses to perform an operation.
+///
+/// The messages are assumed to be encoded in the `message_bits` most significant bits of the
+/// torus, padding included. A message gets corrupted when the noise of its ciphertext exceeds half
+/// the distance between two encoded messages, which happens with a probability depending on the
+/// variance of the noise. By default, this probability must stay below $2^{-40}$ for messages of
+/// 4 bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FailureThreshold {
+    /// The number of most significant bits used to encode the messages, padding included.
+    pub message_bits: usize,
+    /// The maximal probability for the noise of a ciphertext to corrupt its message.
+    pub max_failure_probability: f64,
+}
+
+impl Default for FailureThreshold {
+    fn default() -> Self {
+        FailureThreshold {
+            message_bits: 4,
+            max_failure_probability: 2_f64.powi(-40),
+        }
+    }
+}
+
+/// The main engine exposed by the noise tracked backend.
+///
+/// This engine delegates the computations to a [`CoreEngine`], and predicts the variance of the
+/// noise of their outputs.
+pub struct NoiseTrackedEngine {
+    core: CoreEngine,
+    threshold: FailureThreshold,
+}
+
+impl NoiseTrackedEngine {
+    /// Returns the failure threshold of the engine.
+    pub fn failure_threshold(&self) -> FailureThreshold {
+        self.threshold
+    }
+
+    /// Sets the failure threshold of the engine.
+    pub fn set_failure_threshold(&mut self, threshold: FailureThreshold) {
+        self.threshold = threshold;
+    }
+
+    /// Returns the probability for a noise of the given variance to corrupt a message, under the
+    /// failure threshold of the engine.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_commons::dispersion::Variance;
+    /// use concrete_core::prelude::*;
+    /// # use std::error::Error;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// let mut engine = NoiseTrackedEngine::new()?;
+    /// engine.set_failure_threshold(FailureThreshold {
+    ///     message_bits: 2,
+    ///     max_failure_probability: 2_f64.powi(-40),
+    /// });
+    /// // The messages are corrupted by errors larger than 1/8, which is 2.5 standard deviations.
+    /// let probability = engine.failure_probability(Variance(0.05_f64.powi(2)));
+    /// assert!((probability - 0.0124).abs() < 1e-4);
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn failure_probability<D: DispersionParameter>(&self, dispersion: D) -> f64 {
+        let half_step = 2_f64.powi(-(self.threshold.message_bits as i32) - 1);
+        erfc(half_step / (2. * dispersion.get_variance()).sqrt())
+    }
+
+    // Checks that the failure probability of a ciphertext with the given noise variance is below
+    // the threshold.
+    fn check_variance(&self, variance: Variance) -> Result<(), NoiseTrackedError> {
+        if self.failure_probability(variance) > self.threshold.max_failure_probability {
+            Err(NoiseTrackedError::FailureProbabilityExceeded)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl AbstractEngineSeal for NoiseTrackedEngine {}
+impl AbstractEngine for NoiseTrackedEngine {
+    type EngineError = NoiseTrackedError;
+
+    fn new() -> Result<Self, Self::EngineError> {
+        Ok(NoiseTrackedEngine {
+            core: CoreEngine::new().map_err(NoiseTrackedError::Core)?,
+            threshold: FailureThreshold::default(),
+        })
+    }
+}
+
+// Computes the complementary error function, using the Chebyshev approximation given in
+// Numerical Recipes, whose relative error is below 1.2e-7 everywhere.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1. / (1. + 0.5 * z);
+    let coefficients = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ];
+    let polynomial = coefficients
+        .iter()
+        .rev()
+        .fold(0., |acc, coefficient| coefficient + t * acc);
+    let result = t * (-z * z + polynomial).exp();
+    if x >= 0. {
+        result
+    } else {
+        2. - result
+    }
+}
+
+mod destruction;
+mod lwe_bootstrap_key_creation;
+mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_decryption;
+mod lwe_ciphertext_discarding_addition;
+mod lwe_ciphertext_discarding_bootstrap;
+mod lwe_ciphertext_discarding_keyswitch;
+mod lwe_ciphertext_encryption;
+mod lwe_ciphertext_fusing_addition;
+mod lwe_ciphertext_vector_decryption;
+mod lwe_ciphertext_vector_discarding_affine_transformation;
+mod lwe_ciphertext_vector_encryption;
+mod lwe_keyswitch_key_creation;
@@ -0,0 +1,49 @@
+use crate::backends::core::entities::FourierLweBootstrapKey64;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweBootstrapKeyKind};
+use crate::specification::entities::{AbstractEntity, LweBootstrapKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A structure representing an LWE bootstrap key with 64 bits of precision, in the fourier
+/// domain, along with the variance of the noise used to encrypt it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseTrackedFourierLweBootstrapKey64 {
+    pub(crate) key: FourierLweBootstrapKey64,
+    pub(crate) variance: Variance,
+}
+impl AbstractEntity for NoiseTrackedFourierLweBootstrapKey64 {
+    type Kind = LweBootstrapKeyKind;
+}
+impl LweBootstrapKeyEntity for NoiseTrackedFourierLweBootstrapKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn glwe_dimension(&self) -> GlweDimension {
+        self.key.glwe_dimension()
+    }
+
+    fn polynomial_size(&self) -> PolynomialSize {
+        self.key.polynomial_size()
+    }
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+}
+
+impl NoiseTrackedFourierLweBootstrapKey64 {
+    /// Returns the variance of the noise used to encrypt the key.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
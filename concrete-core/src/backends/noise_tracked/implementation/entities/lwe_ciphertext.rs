@@ -0,0 +1,30 @@
+use crate::backends::core::entities::LweCiphertext64;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCiphertextKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LweDimension;
+
+/// A structure representing an LWE ciphertext with 64 bits of precision, along with the
+/// predicted variance of its noise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseTrackedLweCiphertext64 {
+    pub(crate) ciphertext: LweCiphertext64,
+    pub(crate) variance: Variance,
+}
+impl AbstractEntity for NoiseTrackedLweCiphertext64 {
+    type Kind = LweCiphertextKind;
+}
+impl LweCiphertextEntity for NoiseTrackedLweCiphertext64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertext.lwe_dimension()
+    }
+}
+
+impl NoiseTrackedLweCiphertext64 {
+    /// Returns the predicted variance of the noise of the ciphertext.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
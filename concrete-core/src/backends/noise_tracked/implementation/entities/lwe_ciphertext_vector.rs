@@ -0,0 +1,34 @@
+use crate::backends::core::entities::LweCiphertextVector64;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweCiphertextVectorKind};
+use crate::specification::entities::{AbstractEntity, LweCiphertextVectorEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A structure representing a vector of LWE ciphertexts with 64 bits of precision, along with
+/// the predicted variances of their noises.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseTrackedLweCiphertextVector64 {
+    pub(crate) ciphertexts: LweCiphertextVector64,
+    pub(crate) variances: Vec<Variance>,
+}
+impl AbstractEntity for NoiseTrackedLweCiphertextVector64 {
+    type Kind = LweCiphertextVectorKind;
+}
+impl LweCiphertextVectorEntity for NoiseTrackedLweCiphertextVector64 {
+    type KeyFlavor = BinaryKeyFlavor;
+
+    fn lwe_dimension(&self) -> LweDimension {
+        self.ciphertexts.lwe_dimension()
+    }
+
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        self.ciphertexts.lwe_ciphertext_count()
+    }
+}
+
+impl NoiseTrackedLweCiphertextVector64 {
+    /// Returns the predicted variances of the noises of the ciphertexts.
+    pub fn variances(&self) -> &[Variance] {
+        &self.variances
+    }
+}
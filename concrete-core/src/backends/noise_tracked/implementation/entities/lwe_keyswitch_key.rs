@@ -0,0 +1,43 @@
+use crate::backends::core::entities::LweKeyswitchKey64;
+use crate::specification::entities::markers::{BinaryKeyFlavor, LweKeyswitchKeyKind};
+use crate::specification::entities::{AbstractEntity, LweKeyswitchKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+/// A structure representing an LWE keyswitch key with 64 bits of precision, along with the
+/// variance of the noise used to encrypt it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoiseTrackedLweKeyswitchKey64 {
+    pub(crate) key: LweKeyswitchKey64,
+    pub(crate) variance: Variance,
+}
+impl AbstractEntity for NoiseTrackedLweKeyswitchKey64 {
+    type Kind = LweKeyswitchKeyKind;
+}
+impl LweKeyswitchKeyEntity for NoiseTrackedLweKeyswitchKey64 {
+    type InputKeyFlavor = BinaryKeyFlavor;
+    type OutputKeyFlavor = BinaryKeyFlavor;
+
+    fn input_lwe_dimension(&self) -> LweDimension {
+        self.key.input_lwe_dimension()
+    }
+
+    fn output_lwe_dimension(&self) -> LweDimension {
+        self.key.output_lwe_dimension()
+    }
+
+    fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.key.decomposition_level_count()
+    }
+
+    fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.key.decomposition_base_log()
+    }
+}
+
+impl NoiseTrackedLweKeyswitchKey64 {
+    /// Returns the variance of the noise used to encrypt the key.
+    pub fn variance(&self) -> Variance {
+        self.variance
+    }
+}
@@ -0,0 +1,15 @@
+//! A module containing the noise tracked backend implementation.
+//!
+//! This module contains a backend wrapping the `core` backend, whose ciphertexts carry the
+//! variance of their noise. This variance is propagated through the operations using the
+//! estimators of the `concrete-npe` crate, and an operation fails when the probability that the
+//! noise of its output corrupts the message exceeds a configurable
+//! [threshold](engines::FailureThreshold).
+//!
+//! The secret keys, plaintexts, cleartexts and accumulators used along with this backend are the
+//! ones of the `core` backend. As of now, only 64 bits ciphertexts encrypted under binary keys are
+//! supported.
+
+mod implementation;
+
+pub use implementation::{engines, entities};
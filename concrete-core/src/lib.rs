@@ -32,7 +32,20 @@
 //!
 //! The different backends can be activated using the feature flags `backend_*`. The `backend_core`
 //! contains an engine executing operations on a single thread of the cpu. It is activated by
-//! default.
+//! default. The `backend_noise_tracked` wraps the `core` backend, and tracks the variance of the
+//! noise of the ciphertexts using the estimators of the `concrete-npe` crate. The
+//! `backend_cleartext` contains a mock engine which does not perform any actual cryptography, and
+//! can be used to quickly test the logic of applications written generically over the engines.
+//! The `backend_dyn` contains an engine forwarding the operations to one of the previous backends,
+//! selected at runtime. Finally, the `backend_instrumented` wraps any other engine, and records the
+//! number of calls and the durations of the operations it executes.
+//!
+//! # Measuring the noise
+//!
+//! The `noise_measurement` feature flag exposes engines measuring the noise of ciphertexts, given
+//! the secret key and the expected plaintexts. Since they need the secret key, those engines are
+//! only meant to be used for debugging, or to check empirically that a set of parameters matches
+//! the noise predicted by `concrete-npe`.
 //!
 //! # Navigating the code
 //!
@@ -3,7 +3,21 @@
 pub use super::specification::engines::*;
 pub use super::specification::entities::*;
 
+#[cfg(feature = "backend_cleartext")]
+pub use super::backends::cleartext::engines::*;
+#[cfg(feature = "backend_cleartext")]
+pub use super::backends::cleartext::entities::*;
 #[cfg(feature = "backend_core")]
 pub use super::backends::core::engines::*;
 #[cfg(feature = "backend_core")]
 pub use super::backends::core::entities::*;
+#[cfg(feature = "backend_dyn")]
+pub use super::backends::dynamic::engines::*;
+#[cfg(feature = "backend_dyn")]
+pub use super::backends::dynamic::entities::*;
+#[cfg(feature = "backend_instrumented")]
+pub use super::backends::instrumented::engines::*;
+#[cfg(feature = "backend_noise_tracked")]
+pub use super::backends::noise_tracked::engines::*;
+#[cfg(feature = "backend_noise_tracked")]
+pub use super::backends::noise_tracked::entities::*;
@@ -0,0 +1,74 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, GlweSecretKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError
+    for CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+/// A trait for engines creating the private functional packing keyswitch keys used in the
+/// circuit bootstrap.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates the $k+1$ private functional packing
+/// keyswitch keys allowing to switch LWE ciphertexts encrypted under the `input_key` GLWE secret
+/// key (seen as an LWE secret key), into the rows of a GGSW ciphertext encrypted under the
+/// `output_key` GLWE secret key $\mathbf{S}$ of dimension $k$. For $i<k$, the $i$-th key applies
+/// the linear map $m \mapsto -m\cdot S_i(X)$, and the last key applies the identity map.
+///
+/// The `input_key` is expected to be the output key of the bootstrap key used in the circuit
+/// bootstrap.
+///
+/// # Formal Definition
+pub trait CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationEngine<
+    InputSecretKey,
+    OutputSecretKey,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+>: AbstractEngine where
+    InputSecretKey: GlweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity<
+            InputKeyFlavor = InputSecretKey::KeyFlavor,
+            OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+        >,
+{
+    /// Creates the private functional packing keyswitch keys of the circuit bootstrap.
+    fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError<Self::EngineError>,
+    >;
+
+    /// Unsafely creates the private functional packing keyswitch keys of the circuit bootstrap.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysCreationError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn create_circuit_bootstrap_private_functional_packing_keyswitch_keys_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys;
+}
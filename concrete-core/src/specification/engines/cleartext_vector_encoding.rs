@@ -38,9 +38,9 @@ where
     /// For the _general_ safety concerns regarding this operation, refer to the different variants
     /// of [`CleartextVectorEncodingError`]. For safety concerns _specific_ to an
     /// engine, refer to the implementer safety section.
-    unsafe fn encode_cleartext_unchecked(
+    unsafe fn encode_cleartext_vector_unchecked(
         &mut self,
-        encoder: &EncoderVector,
-        cleartext: &CleartextVector,
+        encoder_vector: &EncoderVector,
+        cleartext_vector: &CleartextVector,
     ) -> PlaintextVector;
 }
@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::EncoderVectorEntity;
+
+engine_error! {
+    EncoderVectorCreationError for EncoderVectorCreationEngine @
+    EmptyInput => "The input slice must not be empty."
+}
+
+/// A trait for engines creating encoder vectors from configurations.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an encoder vector from the `config`
+/// slice of configurations.
+///
+/// # Formal Definition
+pub trait EncoderVectorCreationEngine<Config, EncoderVector>: AbstractEngine
+where
+    EncoderVector: EncoderVectorEntity,
+{
+    /// Creates an encoder vector from a slice of configs.
+    fn create_encoder_vector(
+        &mut self,
+        config: &[Config],
+    ) -> Result<EncoderVector, EncoderVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an encoder vector from a slice of configs.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EncoderVectorCreationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn create_encoder_vector_unchecked(&mut self, config: &[Config]) -> EncoderVector;
+}
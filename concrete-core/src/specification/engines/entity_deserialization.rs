@@ -0,0 +1,46 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::AbstractEntity;
+
+engine_error! {
+    EntityDeserializationError for EntityDeserializationEngine @
+    MalformedHeader => "The header of the serialized entity could not be read.",
+    UnsupportedVersion => "The format version of the serialized entity is not supported.",
+    KindMismatch => "The kind of the serialized entity does not match the kind of the output \
+                     entity.",
+    KeyFlavorMismatch => "The key flavor of the serialized entity does not match the key flavor \
+                          of the output entity.",
+    PrecisionMismatch => "The scalar type of the serialized entity does not match the scalar \
+                          type of the output entity.",
+    MalformedPayload => "The payload of the serialized entity is not consistent with its header."
+}
+
+/// A trait for engines deserializing entities.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an entity from the `serialized`
+/// value, which must have been produced by an
+/// [`EntitySerializationEngine`](super::EntitySerializationEngine) implementation of the same
+/// engine. Values whose kind, key flavor, precision or format version do not match the output
+/// entity are rejected.
+///
+/// # Formal Definition
+pub trait EntityDeserializationEngine<Serialized, Entity>: AbstractEngine
+where
+    Entity: AbstractEntity,
+{
+    /// Deserializes an entity.
+    fn deserialize(
+        &mut self,
+        serialized: Serialized,
+    ) -> Result<Entity, EntityDeserializationError<Self::EngineError>>;
+
+    /// Unsafely deserializes an entity.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`EntityDeserializationError`]. For safety concerns _specific_ to an engine, refer to the
+    /// implementer safety section.
+    unsafe fn deserialize_unchecked(&mut self, serialized: Serialized) -> Entity;
+}
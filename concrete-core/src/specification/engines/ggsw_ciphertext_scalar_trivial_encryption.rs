@@ -0,0 +1,60 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, PlaintextEntity};
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+
+engine_error! {
+    GgswCiphertextScalarTrivialEncryptionError for GgswCiphertextScalarTrivialEncryptionEngine @
+    NullDecompositionBaseLog => "The ciphertext decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The ciphertext decomposition level count must be greater than \
+                                    zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+/// A trait for engines trivially encrypting GGSW ciphertexts containing a single plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext containing the
+/// trivial encryption of the `input` plaintext, using the `decomposition_level_count` levels of
+/// `decomposition_base_log` bits.
+///
+/// A trivial encryption uses null masks and no noise. As such, it can be decrypted by any key of
+/// the right shape, and does not offer any security: it is only useful to feed cleartext data to
+/// homomorphic operations.
+///
+/// # Formal Definition
+pub trait GgswCiphertextScalarTrivialEncryptionEngine<Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    Plaintext: PlaintextEntity,
+    Ciphertext: GgswCiphertextEntity,
+{
+    /// Trivially encrypts a plaintext into a GGSW ciphertext.
+    fn trivially_encrypt_scalar_ggsw_ciphertext(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, GgswCiphertextScalarTrivialEncryptionError<Self::EngineError>>;
+
+    /// Unsafely trivially encrypts a plaintext into a GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextScalarTrivialEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn trivially_encrypt_scalar_ggsw_ciphertext_unchecked(
+        &mut self,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        input: &Plaintext,
+    ) -> Ciphertext;
+}
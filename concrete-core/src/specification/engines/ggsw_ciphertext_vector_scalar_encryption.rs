@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GgswCiphertextVectorEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GgswCiphertextVectorScalarEncryptionError for GgswCiphertextVectorScalarEncryptionEngine @
+    NullDecompositionBaseLog => "The ciphertext decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The ciphertext decomposition level count must be greater than \
+                                    zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+/// A trait for engines encrypting GGSW ciphertext vectors, each ciphertext containing a single
+/// plaintext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GGSW ciphertext vector containing
+/// the element-wise encryptions of the `input` plaintext vector under the `key` secret key, using
+/// the `decomposition_level_count` levels of `decomposition_base_log` bits.
+///
+/// # Formal Definition
+pub trait GgswCiphertextVectorScalarEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GgswCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+{
+    /// Encrypts a plaintext vector into a GGSW ciphertext vector.
+    fn encrypt_scalar_ggsw_ciphertext_vector(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> Result<CiphertextVector, GgswCiphertextVectorScalarEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext vector into a GGSW ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GgswCiphertextVectorScalarEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_scalar_ggsw_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+    ) -> CiphertextVector;
+}
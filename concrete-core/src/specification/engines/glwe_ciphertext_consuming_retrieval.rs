@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+
+engine_error! {
+    GlweCiphertextConsumingRetrievalError for GlweCiphertextConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext` GLWE ciphertext, and
+/// returns the container of scalars it was holding. The layout of the container is the one expected
+/// by the [`GlweCiphertextCreationEngine`](super::GlweCiphertextCreationEngine).
+///
+/// # Formal Definition
+pub trait GlweCiphertextConsumingRetrievalEngine<Ciphertext, Container>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Retrieves the container of a GLWE ciphertext, consuming it.
+    fn consume_retrieve_glwe_ciphertext(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Result<Container, GlweCiphertextConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of a GLWE ciphertext, consuming it.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_glwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Container;
+}
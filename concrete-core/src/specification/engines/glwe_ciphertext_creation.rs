@@ -0,0 +1,46 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::GlweCiphertextEntity;
+use concrete_commons::parameters::PolynomialSize;
+
+engine_error! {
+    GlweCiphertextCreationError for GlweCiphertextCreationEngine @
+    EmptyContainer => "The input container must not be empty.",
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    InvalidContainerSize => "The length of the input container must be a multiple of the \
+                             polynomial size."
+}
+
+/// A trait for engines creating GLWE ciphertexts from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE ciphertext from the
+/// `container` of scalars, which holds the coefficients of the mask polynomials followed by the
+/// ones of the body polynomial, each of size `polynomial_size`. Depending on the container, the
+/// ciphertext can borrow the scalars instead of copying them.
+///
+/// # Formal Definition
+pub trait GlweCiphertextCreationEngine<Container, Ciphertext>: AbstractEngine
+where
+    Ciphertext: GlweCiphertextEntity,
+{
+    /// Creates a GLWE ciphertext from a container of scalars.
+    fn create_glwe_ciphertext(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+    ) -> Result<Ciphertext, GlweCiphertextCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE ciphertext from a container of scalars.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextCreationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn create_glwe_ciphertext_unchecked(
+        &mut self,
+        container: Container,
+        polynomial_size: PolynomialSize,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,66 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweRelinearizationKeyEntity, PlaintextEntity,
+};
+
+engine_error! {
+    GlweCiphertextDiscardingMultiplicationError for GlweCiphertextDiscardingMultiplicationEngine @
+    GlweDimensionMismatch => "The input and output ciphertexts and the relinearization key must \
+                              have the same GLWE dimension.",
+    PolynomialSizeMismatch => "The input and output ciphertexts and the relinearization key must \
+                               have the same polynomial size.",
+    InvalidScalingFactor => "The scaling factor must be a power of two."
+}
+
+/// A trait for engines multiplying (discarding) two GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext
+/// with the product of the `input_1` and `input_2` GLWE ciphertexts. The tensor product of the
+/// inputs is divided by the `delta` scaling factor, which must be a power of two, and then
+/// relinearized with the `rlk` relinearization key. If the inputs encrypt $\Delta_1 M_1$ and
+/// $\Delta_2 M_2$, the output encrypts $\frac{\Delta_1\Delta_2}{\Delta}M_1M_2$, $\Delta$ being
+/// usually the smallest of $\Delta_1$ and $\Delta_2$.
+///
+/// # Formal Definition
+pub trait GlweCiphertextDiscardingMultiplicationEngine<
+    InputCiphertext1,
+    InputCiphertext2,
+    RelinearizationKey,
+    Delta,
+    OutputCiphertext,
+>: AbstractEngine where
+    InputCiphertext1: GlweCiphertextEntity,
+    InputCiphertext2: GlweCiphertextEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+    RelinearizationKey: GlweRelinearizationKeyEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+    Delta: PlaintextEntity,
+    OutputCiphertext: GlweCiphertextEntity<KeyFlavor = InputCiphertext1::KeyFlavor>,
+{
+    /// Multiplies two GLWE ciphertexts.
+    fn discard_mul_glwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        delta: &Delta,
+    ) -> Result<(), GlweCiphertextDiscardingMultiplicationError<Self::EngineError>>;
+
+    /// Unsafely multiplies two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextDiscardingMultiplicationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_mul_glwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input_1: &InputCiphertext1,
+        input_2: &InputCiphertext2,
+        rlk: &RelinearizationKey,
+        delta: &Delta,
+    );
+}
@@ -0,0 +1,47 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextGgswCiphertextExternalProductError for GlweCiphertextGgswCiphertextExternalProductEngine @
+    PolynomialSizeMismatch => "The GLWE and GGSW ciphertexts must have the same polynomial size.",
+    GlweDimensionMismatch => "The GLWE and GGSW ciphertexts must have the same GLWE dimension."
+}
+
+/// A trait for engines computing the external product between a GLWE ciphertext and a GGSW
+/// ciphertext.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// result of the external product between the `glwe_input` GLWE ciphertext and the `ggsw_input`
+/// GGSW ciphertext. If the GGSW ciphertext encrypts a small integer, and the GLWE ciphertext
+/// encrypts a polynomial, the output GLWE ciphertext encrypts their product.
+///
+/// # Formal Definition
+pub trait GlweCiphertextGgswCiphertextExternalProductEngine<GlweInput, GgswInput, Output>:
+    AbstractEngine
+where
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+    Output: GlweCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+{
+    /// Computes the external product between a GLWE and a GGSW ciphertext.
+    fn compute_external_product_glwe_ciphertext_ggsw_ciphertext(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<Output, GlweCiphertextGgswCiphertextExternalProductError<Self::EngineError>>;
+
+    /// Unsafely computes the external product between a GLWE and a GGSW ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextGgswCiphertextExternalProductError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn compute_external_product_glwe_ciphertext_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Output;
+}
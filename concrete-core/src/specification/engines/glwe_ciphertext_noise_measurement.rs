@@ -0,0 +1,53 @@
+use super::engine_error;
+use crate::specification::engines::{AbstractEngine, NoiseMeasurement};
+use crate::specification::entities::{
+    GlweCiphertextEntity, GlweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    GlweCiphertextNoiseMeasurementError for GlweCiphertextNoiseMeasurementEngine @
+    GlweDimensionMismatch => "The input and secret key GLWE dimension must be the same.",
+    PolynomialSizeMismatch => "The input and secret key polynomial size must be the same.",
+    PlaintextCountMismatch => "The expected plaintext count and the input polynomial size must be \
+                               the same."
+}
+
+/// A trait for engines measuring the noise of GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` GLWE ciphertext with the
+/// `key` GLWE secret key, and measures the differences between the coefficients of the decrypted
+/// plaintext polynomial and the `expected` plaintexts, one measurement per coefficient. It is
+/// meant to be used for debugging, or to validate a set of parameters empirically with
+/// [`compare_noise_variance`](super::compare_noise_variance).
+///
+/// # Formal Definition
+pub trait GlweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, PlaintextVector>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    Ciphertext: GlweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise of a GLWE ciphertext.
+    fn measure_glwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Result<Vec<NoiseMeasurement>, GlweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of a GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_glwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &PlaintextVector,
+    ) -> Vec<NoiseMeasurement>;
+}
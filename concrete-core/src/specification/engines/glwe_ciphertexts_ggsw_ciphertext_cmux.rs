@@ -0,0 +1,50 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GgswCiphertextEntity, GlweCiphertextEntity};
+
+engine_error! {
+    GlweCiphertextsGgswCiphertextCmuxError for GlweCiphertextsGgswCiphertextCmuxEngine @
+    PolynomialSizeMismatch => "The GLWE and GGSW ciphertexts must have the same polynomial size.",
+    GlweDimensionMismatch => "The GLWE and GGSW ciphertexts must have the same GLWE dimension."
+}
+
+/// A trait for engines computing a controlled multiplexer (cmux) between two GLWE ciphertexts,
+/// using a GGSW ciphertext as selector.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// result of the cmux between the `glwe_input_0` and `glwe_input_1` GLWE ciphertexts, selected
+/// by the `ggsw_input` GGSW ciphertext. If the GGSW ciphertext encrypts 0, the output GLWE
+/// ciphertext encrypts the same polynomial as `glwe_input_0`. If it encrypts 1, the output
+/// encrypts the same polynomial as `glwe_input_1`.
+///
+/// # Formal Definition
+pub trait GlweCiphertextsGgswCiphertextCmuxEngine<GlweInput, GgswInput, Output>:
+    AbstractEngine
+where
+    GlweInput: GlweCiphertextEntity,
+    GgswInput: GgswCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+    Output: GlweCiphertextEntity<KeyFlavor = GlweInput::KeyFlavor>,
+{
+    /// Computes the cmux between two GLWE ciphertexts.
+    fn cmux_glwe_ciphertexts_ggsw_ciphertext(
+        &mut self,
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Result<Output, GlweCiphertextsGgswCiphertextCmuxError<Self::EngineError>>;
+
+    /// Unsafely computes the cmux between two GLWE ciphertexts.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweCiphertextsGgswCiphertextCmuxError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn cmux_glwe_ciphertexts_ggsw_ciphertext_unchecked(
+        &mut self,
+        glwe_input_0: &GlweInput,
+        glwe_input_1: &GlweInput,
+        ggsw_input: &GgswInput,
+    ) -> Output;
+}
@@ -0,0 +1,53 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{GlweRelinearizationKeyEntity, GlweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    GlweRelinearizationKeyCreationError for GlweRelinearizationKeyCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext."
+}
+
+/// A trait for engines creating GLWE relinearization keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a GLWE relinearization key allowing
+/// to turn the tensor product of two GLWE ciphertexts encrypted under the `key` GLWE secret key,
+/// back into a GLWE ciphertext encrypted under the same key.
+///
+/// # Formal Definition
+pub trait GlweRelinearizationKeyCreationEngine<SecretKey, RelinearizationKey>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    RelinearizationKey: GlweRelinearizationKeyEntity<KeyFlavor = SecretKey::KeyFlavor>,
+{
+    /// Creates a GLWE relinearization key.
+    fn create_glwe_relinearization_key(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<RelinearizationKey, GlweRelinearizationKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates a GLWE relinearization key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweRelinearizationKeyCreationError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn create_glwe_relinearization_key_unchecked(
+        &mut self,
+        key: &SecretKey,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> RelinearizationKey;
+}
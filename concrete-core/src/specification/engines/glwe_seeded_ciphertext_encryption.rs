@@ -0,0 +1,50 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweSecretKeyEntity, GlweSeededCiphertextEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    GlweSeededCiphertextEncryptionError for GlweSeededCiphertextEncryptionEngine @
+    PlaintextCountMismatch => "The plaintext count of the input vector and the key polynomial size \
+                               must be the same."
+}
+
+/// A trait for engines encrypting seeded GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded GLWE ciphertext containing
+/// the encryptions of the `input` plaintext vector, under the `key` secret key. The mask of the
+/// ciphertext is generated from a fresh seed, and only the seed and the body are stored.
+///
+/// # Formal Definition
+pub trait GlweSeededCiphertextEncryptionEngine<SecretKey, PlaintextVector, Ciphertext>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    Ciphertext: GlweSeededCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+{
+    /// Encrypts a plaintext vector into a seeded GLWE ciphertext.
+    fn encrypt_glwe_seeded_ciphertext(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<Ciphertext, GlweSeededCiphertextEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a plaintext vector into a seeded GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextEncryptionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn encrypt_glwe_seeded_ciphertext_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Ciphertext;
+}
@@ -0,0 +1,35 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{GlweCiphertextEntity, GlweSeededCiphertextEntity};
+
+engine_error! {
+    GlweSeededCiphertextExpansionError for GlweSeededCiphertextExpansionEngine @
+}
+
+/// A trait for engines expanding seeded GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext containing the
+/// expansion of the `input` seeded GLWE ciphertext, by regenerating its mask from the stored seed.
+///
+/// # Formal Definition
+pub trait GlweSeededCiphertextExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweSeededCiphertextEntity,
+    Output: GlweCiphertextEntity<KeyFlavor = Input::KeyFlavor>,
+{
+    /// Expands a seeded GLWE ciphertext.
+    fn expand_glwe_seeded_ciphertext(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GlweSeededCiphertextExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded GLWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextExpansionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn expand_glwe_seeded_ciphertext_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -0,0 +1,51 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweSecretKeyEntity, GlweSeededCiphertextVectorEntity, PlaintextVectorEntity,
+};
+use concrete_commons::dispersion::Variance;
+
+engine_error! {
+    GlweSeededCiphertextVectorEncryptionError for GlweSeededCiphertextVectorEncryptionEngine @
+    PlaintextCountMismatch => "The key polynomial size must divide the plaintext count of the input \
+                               vector."
+}
+
+/// A trait for engines encrypting seeded GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a seeded GLWE ciphertext vector
+/// containing the piece-wise encryptions of the `input` plaintext vector, under the `key` secret
+/// key. The mask of each ciphertext is generated from its own fresh seed, and only the seeds and
+/// the bodies are stored.
+///
+/// # Formal Definition
+pub trait GlweSeededCiphertextVectorEncryptionEngine<SecretKey, PlaintextVector, CiphertextVector>:
+    AbstractEngine
+where
+    SecretKey: GlweSecretKeyEntity,
+    PlaintextVector: PlaintextVectorEntity,
+    CiphertextVector: GlweSeededCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+{
+    /// Encrypts a seeded GLWE ciphertext vector.
+    fn encrypt_glwe_seeded_ciphertext_vector(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> Result<CiphertextVector, GlweSeededCiphertextVectorEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts a seeded GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextVectorEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_glwe_seeded_ciphertext_vector_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &PlaintextVector,
+        noise: Variance,
+    ) -> CiphertextVector;
+}
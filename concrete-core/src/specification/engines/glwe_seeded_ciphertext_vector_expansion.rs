@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    GlweCiphertextVectorEntity, GlweSeededCiphertextVectorEntity,
+};
+
+engine_error! {
+    GlweSeededCiphertextVectorExpansionError for GlweSeededCiphertextVectorExpansionEngine @
+}
+
+/// A trait for engines expanding seeded GLWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a GLWE ciphertext vector containing
+/// the expansion of the `input` seeded GLWE ciphertext vector, by regenerating the masks of its
+/// ciphertexts from their stored seeds.
+///
+/// # Formal Definition
+pub trait GlweSeededCiphertextVectorExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: GlweSeededCiphertextVectorEntity,
+    Output: GlweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+{
+    /// Expands a seeded GLWE ciphertext vector.
+    fn expand_glwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, GlweSeededCiphertextVectorExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded GLWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`GlweSeededCiphertextVectorExpansionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn expand_glwe_seeded_ciphertext_vector_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -4,6 +4,8 @@ use crate::specification::entities::LweBootstrapKeyEntity;
 
 engine_error! {
     LweBootstrapKeyConversionError for LweBootstrapKeyConversionEngine @
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be \
+                              smaller than the precision of the output key."
 }
 
 /// A trait for engines converting LWE bootstrap keys.
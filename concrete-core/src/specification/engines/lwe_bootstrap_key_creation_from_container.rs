@@ -0,0 +1,62 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweBootstrapKeyEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+engine_error! {
+    LweBootstrapKeyCreationFromContainerError for LweBootstrapKeyCreationFromContainerEngine @
+    EmptyContainer => "The input container must not be empty.",
+    NullPolynomialSize => "The polynomial size must be greater than zero.",
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be smaller \
+                              than the precision of the ciphertext.",
+    InvalidContainerSize => "The length of the input container must be a multiple of the \
+                             decomposition level count times the squared GLWE size times the \
+                             polynomial size."
+}
+
+/// A trait for engines creating LWE bootstrap keys from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE bootstrap key from the
+/// `container` of coefficients, which holds, for each coefficient of the input LWE secret key, a
+/// GGSW ciphertext of `decomposition_level_count` levels, made of GLWE ciphertexts of dimension
+/// `glwe_dimension` and polynomials of size `polynomial_size`. The key takes ownership of the
+/// container, whose layout is the one returned by the
+/// [`LweBootstrapKeyConsumingRetrievalEngine`](super::LweBootstrapKeyConsumingRetrievalEngine).
+///
+/// # Formal Definition
+pub trait LweBootstrapKeyCreationFromContainerEngine<Container, BootstrapKey>:
+    AbstractEngine
+where
+    BootstrapKey: LweBootstrapKeyEntity,
+{
+    /// Creates an LWE bootstrap key from a container of coefficients.
+    fn create_lwe_bootstrap_key_from_container(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<BootstrapKey, LweBootstrapKeyCreationFromContainerError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE bootstrap key from a container of coefficients.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweBootstrapKeyCreationFromContainerError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn create_lwe_bootstrap_key_from_container_unchecked(
+        &mut self,
+        container: Container,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> BootstrapKey;
+}
@@ -8,7 +8,9 @@ engine_error! {
     GlweDimensionMismatch => "The two keys must have the same GLWE dimension.",
     PolynomialSizeMismatch => "The two keys must have the same polynomial size.",
     DecompositionBaseLogMismatch => "The two keys must have the same base logarithms.",
-    DecompositionLevelCountMismatch => "The two keys must have the same level counts."
+    DecompositionLevelCountMismatch => "The two keys must have the same level counts.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be \
+                              smaller than the precision of the output key."
 }
 
 /// A trait for engines converting (discarding) LWE bootstrap keys .
@@ -0,0 +1,48 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweCompressedCiphertextEntity};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextCompressionError for LweCiphertextCompressionEngine @
+    NullModulusLog => "The modulus log must be greater than zero.",
+    ModulusLogTooLarge => "The modulus log must be smaller than the precision of the input \
+                           ciphertext, and fit in the compressed ciphertext."
+}
+
+/// A trait for engines compressing LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a compressed LWE ciphertext
+/// containing the modulus switch of the `input` LWE ciphertext to the $2^m$ modulus, $m$ being
+/// the `modulus_log` value.
+///
+/// # Formal Definition
+///
+/// Every coefficient $c$ of the input ciphertext, defined modulo $q$, is replaced by
+/// $\left\lfloor\frac{2^m c}{q}\right\rceil \bmod 2^m$.
+pub trait LweCiphertextCompressionEngine<Ciphertext, CompressedCiphertext>: AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+    CompressedCiphertext: LweCompressedCiphertextEntity<KeyFlavor = Ciphertext::KeyFlavor>,
+{
+    /// Compresses an LWE ciphertext.
+    fn compress_lwe_ciphertext(
+        &mut self,
+        input: &Ciphertext,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertext, LweCiphertextCompressionError<Self::EngineError>>;
+
+    /// Unsafely compresses an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCompressionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn compress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &Ciphertext,
+        modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertext;
+}
@@ -0,0 +1,38 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+
+engine_error! {
+    LweCiphertextConsumingRetrievalError for LweCiphertextConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `ciphertext` LWE ciphertext, and
+/// returns the container of scalars it was holding. The layout of the container is the one expected
+/// by the [`LweCiphertextCreationEngine`](super::LweCiphertextCreationEngine).
+///
+/// # Formal Definition
+pub trait LweCiphertextConsumingRetrievalEngine<Ciphertext, Container>: AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Retrieves the container of an LWE ciphertext, consuming it.
+    fn consume_retrieve_lwe_ciphertext(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Result<Container, LweCiphertextConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of an LWE ciphertext, consuming it.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_ciphertext_unchecked(
+        &mut self,
+        ciphertext: Ciphertext,
+    ) -> Container;
+}
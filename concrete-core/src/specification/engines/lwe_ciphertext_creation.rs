@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextEntity;
+
+engine_error! {
+    LweCiphertextCreationError for LweCiphertextCreationEngine @
+    EmptyContainer => "The input container must not be empty."
+}
+
+/// A trait for engines creating LWE ciphertexts from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext from the `container`
+/// of scalars, whose last element is the body of the ciphertext. Depending on the container, the
+/// ciphertext can borrow the scalars instead of copying them.
+///
+/// # Formal Definition
+pub trait LweCiphertextCreationEngine<Container, Ciphertext>: AbstractEngine
+where
+    Ciphertext: LweCiphertextEntity,
+{
+    /// Creates an LWE ciphertext from a container of scalars.
+    fn create_lwe_ciphertext(
+        &mut self,
+        container: Container,
+    ) -> Result<Ciphertext, LweCiphertextCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext from a container of scalars.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextCreationError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn create_lwe_ciphertext_unchecked(&mut self, container: Container) -> Ciphertext;
+}
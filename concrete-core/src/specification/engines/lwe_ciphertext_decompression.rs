@@ -0,0 +1,42 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LweCompressedCiphertextEntity};
+
+engine_error! {
+    LweCiphertextDecompressionError for LweCiphertextDecompressionEngine @
+}
+
+/// A trait for engines decompressing LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// lift of the `input` compressed LWE ciphertext back to the modulus of the output ciphertext.
+///
+/// # Formal Definition
+///
+/// Every coefficient $c$ of the input ciphertext, defined modulo $2^m$, is replaced by
+/// $\frac{qc}{2^m}$.
+pub trait LweCiphertextDecompressionEngine<CompressedCiphertext, Ciphertext>:
+    AbstractEngine
+where
+    CompressedCiphertext: LweCompressedCiphertextEntity,
+    Ciphertext: LweCiphertextEntity<KeyFlavor = CompressedCiphertext::KeyFlavor>,
+{
+    /// Decompresses an LWE ciphertext.
+    fn decompress_lwe_ciphertext(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Result<Ciphertext, LweCiphertextDecompressionError<Self::EngineError>>;
+
+    /// Unsafely decompresses an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDecompressionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn decompress_lwe_ciphertext_unchecked(
+        &mut self,
+        input: &CompressedCiphertext,
+    ) -> Ciphertext;
+}
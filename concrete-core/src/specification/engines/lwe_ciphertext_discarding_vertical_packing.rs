@@ -0,0 +1,59 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GgswCiphertextEntity, LweCiphertextEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextDiscardingVerticalPackingError for LweCiphertextDiscardingVerticalPackingEngine @
+    EmptyInput => "The input GGSW ciphertext slice must not be empty.",
+    GlweDimensionMismatch => "All the input GGSW ciphertexts must have the same GLWE dimension.",
+    PolynomialSizeMismatch => "All the input GGSW ciphertexts must have the same polynomial size.",
+    OutputLweDimensionMismatch => "The output ciphertext dimension and the input GGSW ciphertexts \
+                                   size (GLWE dimension * polynomial size) must be the same.",
+    PlaintextCountMismatch => "The lookup table must contain 2^n plaintexts, n being the number \
+                               of input GGSW ciphertexts."
+}
+
+/// A trait for engines performing a (discarding) vertical packing lookup in a table of
+/// plaintexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext with
+/// an encryption of `lut[index]`, where the bits of `index` are encrypted in the `ggsw_inputs`
+/// GGSW ciphertexts, from the most significant to the least significant one. The `lut` lookup
+/// table must contain $2^n$ plaintexts, $n$ being the number of GGSW ciphertexts, and the output
+/// ciphertext is encrypted under the GLWE secret key of the GGSW ciphertexts, seen as an LWE
+/// secret key.
+///
+/// # Formal Definition
+pub trait LweCiphertextDiscardingVerticalPackingEngine<GgswInput, LookupTable, OutputCiphertext>:
+    AbstractEngine
+where
+    GgswInput: GgswCiphertextEntity,
+    LookupTable: PlaintextVectorEntity,
+    OutputCiphertext: LweCiphertextEntity<KeyFlavor = GgswInput::KeyFlavor>,
+{
+    /// Performs a vertical packing lookup.
+    fn discard_vertical_packing_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        ggsw_inputs: &[GgswInput],
+        lut: &LookupTable,
+    ) -> Result<(), LweCiphertextDiscardingVerticalPackingError<Self::EngineError>>;
+
+    /// Unsafely performs a vertical packing lookup.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextDiscardingVerticalPackingError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn discard_vertical_packing_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        ggsw_inputs: &[GgswInput],
+        lut: &LookupTable,
+    );
+}
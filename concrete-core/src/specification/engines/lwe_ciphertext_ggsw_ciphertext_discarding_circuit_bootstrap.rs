@@ -0,0 +1,76 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity, GgswCiphertextEntity,
+    LweBootstrapKeyEntity, LweCiphertextEntity,
+};
+
+engine_error! {
+    LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError
+    for LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and bootstrap key input LWE dimension must \
+                                  be the same.",
+    KeysInputLweDimensionMismatch => "The bootstrap key output LWE dimension (GLWE dimension * \
+                                      polynomial size) and the keyswitch keys input LWE dimension \
+                                      must be the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and keyswitch keys output GLWE \
+                                    dimension must be the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and keyswitch keys output polynomial \
+                                     size must be the same.",
+    KeyCountMismatch => "The number of keyswitch keys must be the output GLWE dimension plus \
+                         one.",
+    DecompositionTooLarge => "The output decomposition precision (base log * level count) must be \
+                              smaller than the precision of the ciphertext."
+}
+
+/// A trait for engines circuit bootstrapping (discarding) LWE ciphertexts into GGSW ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GGSW ciphertext with
+/// the circuit bootstrap of the `input` LWE ciphertext, using the `bsk` bootstrap key and the
+/// `cbs_pfpksk` circuit bootstrap private functional packing keyswitch keys. The input ciphertext
+/// must encrypt a bit $m$ encoded in its most significant bit, and the output GGSW ciphertext then
+/// encrypts the constant $m$ under the output key of `cbs_pfpksk`.
+///
+/// # Formal Definition
+pub trait LweCiphertextGgswCiphertextDiscardingCircuitBootstrapEngine<
+    BootstrapKey,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    InputCiphertext,
+    OutputCiphertext,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys:
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity<
+            InputKeyFlavor = BootstrapKey::OutputKeyFlavor,
+        >,
+    InputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+    OutputCiphertext: GgswCiphertextEntity<
+        KeyFlavor = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys::OutputKeyFlavor,
+    >,
+{
+    /// Circuit bootstrap an LWE ciphertext.
+    fn discard_circuit_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    ) -> Result<(), LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError<Self::EngineError>>;
+
+    /// Unsafely circuit bootstrap an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextGgswCiphertextDiscardingCircuitBootstrapError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_circuit_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertext,
+        bsk: &BootstrapKey,
+        cbs_pfpksk: &CircuitBootstrapPrivateFunctionalPackingKeyswitchKeys,
+    );
+}
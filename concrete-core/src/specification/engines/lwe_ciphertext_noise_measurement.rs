@@ -0,0 +1,47 @@
+use super::engine_error;
+use crate::specification::engines::{AbstractEngine, NoiseMeasurement};
+use crate::specification::entities::{LweCiphertextEntity, LweSecretKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextNoiseMeasurementError for LweCiphertextNoiseMeasurementEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same."
+}
+
+/// A trait for engines measuring the noise of LWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext with the
+/// `key` LWE secret key, and measures the difference between the decrypted plaintext and the
+/// `expected` plaintext. It is meant to be used for debugging, or to validate a set of parameters
+/// empirically with [`compare_noise_variance`](super::compare_noise_variance).
+///
+/// # Formal Definition
+pub trait LweCiphertextNoiseMeasurementEngine<SecretKey, Ciphertext, Plaintext>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    Ciphertext: LweCiphertextEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    Plaintext: PlaintextEntity,
+{
+    /// Measures the noise of an LWE ciphertext.
+    fn measure_lwe_ciphertext_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> Result<NoiseMeasurement, LweCiphertextNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextNoiseMeasurementError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &Ciphertext,
+        expected: &Plaintext,
+    ) -> NoiseMeasurement;
+}
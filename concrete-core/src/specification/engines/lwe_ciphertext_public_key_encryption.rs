@@ -0,0 +1,44 @@
+use super::engine_error;
+
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextEntity, LwePublicKeyEntity, PlaintextEntity};
+
+engine_error! {
+    LweCiphertextPublicKeyEncryptionError for LweCiphertextPublicKeyEncryptionEngine @
+}
+
+/// A trait for engines encrypting LWE ciphertexts using a public key.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext containing the
+/// encryption of the `input` plaintext under the secret key the `key` public key was created
+/// from.
+///
+/// # Formal Definition
+pub trait LweCiphertextPublicKeyEncryptionEngine<PublicKey, Plaintext, Ciphertext>:
+    AbstractEngine
+where
+    PublicKey: LwePublicKeyEntity,
+    Plaintext: PlaintextEntity,
+    Ciphertext: LweCiphertextEntity<KeyFlavor = PublicKey::KeyFlavor>,
+{
+    /// Encrypts an LWE ciphertext using a public key.
+    fn encrypt_lwe_ciphertext_with_public_key(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Result<Ciphertext, LweCiphertextPublicKeyEncryptionError<Self::EngineError>>;
+
+    /// Unsafely encrypts an LWE ciphertext using a public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextPublicKeyEncryptionError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn encrypt_lwe_ciphertext_with_public_key_unchecked(
+        &mut self,
+        key: &PublicKey,
+        input: &Plaintext,
+    ) -> Ciphertext;
+}
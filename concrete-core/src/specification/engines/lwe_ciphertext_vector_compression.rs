@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweCompressedCiphertextVectorEntity,
+};
+use concrete_commons::parameters::CiphertextModulusLog;
+
+engine_error! {
+    LweCiphertextVectorCompressionError for LweCiphertextVectorCompressionEngine @
+    NullModulusLog => "The modulus log must be greater than zero.",
+    ModulusLogTooLarge => "The modulus log must be smaller than the precision of the input \
+                           ciphertexts, and fit in the compressed ciphertexts."
+}
+
+/// A trait for engines compressing LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates a compressed LWE ciphertext
+/// vector containing the modulus switches of the `input` LWE ciphertext vector to the $2^m$
+/// modulus, $m$ being the `modulus_log` value.
+///
+/// # Formal Definition
+///
+/// Every coefficient $c$ of the input ciphertexts, defined modulo $q$, is replaced by
+/// $\left\lfloor\frac{2^m c}{q}\right\rceil \bmod 2^m$.
+pub trait LweCiphertextVectorCompressionEngine<CiphertextVector, CompressedCiphertextVector>:
+    AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+    CompressedCiphertextVector:
+        LweCompressedCiphertextVectorEntity<KeyFlavor = CiphertextVector::KeyFlavor>,
+{
+    /// Compresses an LWE ciphertext vector.
+    fn compress_lwe_ciphertext_vector(
+        &mut self,
+        input: &CiphertextVector,
+        modulus_log: CiphertextModulusLog,
+    ) -> Result<CompressedCiphertextVector, LweCiphertextVectorCompressionError<Self::EngineError>>;
+
+    /// Unsafely compresses an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorCompressionError`]. For safety concerns _specific_ to an engine, refer to
+    /// the implementer safety section.
+    unsafe fn compress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CiphertextVector,
+        modulus_log: CiphertextModulusLog,
+    ) -> CompressedCiphertextVector;
+}
@@ -0,0 +1,45 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweCiphertextVectorEntity;
+use concrete_commons::parameters::LweSize;
+
+engine_error! {
+    LweCiphertextVectorCreationError for LweCiphertextVectorCreationEngine @
+    EmptyContainer => "The input container must not be empty.",
+    NullLweSize => "The LWE size must be greater than zero.",
+    InvalidContainerSize => "The length of the input container must be a multiple of the LWE \
+                             size."
+}
+
+/// A trait for engines creating LWE ciphertext vectors from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE ciphertext vector from the
+/// `container` of scalars, which holds the ciphertexts of size `lwe_size` one after the other.
+/// Depending on the container, the vector can borrow the scalars instead of copying them.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorCreationEngine<Container, CiphertextVector>: AbstractEngine
+where
+    CiphertextVector: LweCiphertextVectorEntity,
+{
+    /// Creates an LWE ciphertext vector from a container of scalars.
+    fn create_lwe_ciphertext_vector(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> Result<CiphertextVector, LweCiphertextVectorCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE ciphertext vector from a container of scalars.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorCreationError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn create_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        container: Container,
+        lwe_size: LweSize,
+    ) -> CiphertextVector;
+}
@@ -0,0 +1,45 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweCompressedCiphertextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDecompressionError for LweCiphertextVectorDecompressionEngine @
+}
+
+/// A trait for engines decompressing LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the lifts of the `input` compressed LWE ciphertext vector back to the modulus of the output
+/// ciphertexts.
+///
+/// # Formal Definition
+///
+/// Every coefficient $c$ of the input ciphertexts, defined modulo $2^m$, is replaced by
+/// $\frac{qc}{2^m}$.
+pub trait LweCiphertextVectorDecompressionEngine<CompressedCiphertextVector, CiphertextVector>:
+    AbstractEngine
+where
+    CompressedCiphertextVector: LweCompressedCiphertextVectorEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = CompressedCiphertextVector::KeyFlavor>,
+{
+    /// Decompresses an LWE ciphertext vector.
+    fn decompress_lwe_ciphertext_vector(
+        &mut self,
+        input: &CompressedCiphertextVector,
+    ) -> Result<CiphertextVector, LweCiphertextVectorDecompressionError<Self::EngineError>>;
+
+    /// Unsafely decompresses an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDecompressionError`]. For safety concerns _specific_ to an engine, refer
+    /// to the implementer safety section.
+    unsafe fn decompress_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        input: &CompressedCiphertextVector,
+    ) -> CiphertextVector;
+}
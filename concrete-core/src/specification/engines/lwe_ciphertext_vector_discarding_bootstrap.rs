@@ -11,7 +11,7 @@ engine_error! {
     OutputLweDimensionMismatch => "The output vector and key output LWE dimension must be the same.",
     AccumulatorGlweDimensionMismatch => "The accumulator vector and key GLWE dimension must be the same.",
     AccumulatorPolynomialSizeMismatch => "The accumulator vector and key polynomial size must be the same.",
-    AccumulatorCountMismatch => "The accumulator count and input ciphertext count must be the same.",
+    AccumulatorCountMismatch => "The accumulator count must be one, or equal to the input ciphertext count.",
     CiphertextCountMismatch => "The input and output ciphertext count must be the same."
 }
 
@@ -21,7 +21,8 @@ engine_error! {
 ///
 /// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
 /// with the element-wise bootstrap of the `input` LWE ciphertext vector, using the `acc`
-/// accumulator as lookup-table, and the `bsk` bootstrap key.
+/// accumulator as lookup-table, and the `bsk` bootstrap key. The `acc` vector either contains one
+/// accumulator per input ciphertext, or a single accumulator shared by every bootstrap.
 ///
 /// # Formal Definition
 pub trait LweCiphertextVectorDiscardingBootstrapEngine<
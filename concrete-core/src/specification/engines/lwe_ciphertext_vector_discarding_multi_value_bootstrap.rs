@@ -0,0 +1,69 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    CleartextVectorEntity, LweBootstrapKeyEntity, LweCiphertextEntity, LweCiphertextVectorEntity,
+    PlaintextEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorDiscardingMultiValueBootstrapError
+    for LweCiphertextVectorDiscardingMultiValueBootstrapEngine @
+    InputLweDimensionMismatch => "The input ciphertext and key LWE dimension must be the same.",
+    OutputLweDimensionMismatch => "The output ciphertexts dimension and key size (dimension * \
+                                   polynomial size) must be the same.",
+    CleartextCountMismatch => "The lookup tables must contain as many cleartexts as the number of \
+                               output ciphertexts times the key polynomial size."
+}
+
+/// A trait for engines bootstrapping (discarding) an LWE ciphertext with several lookup tables
+/// at once.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` LWE ciphertext vector
+/// with the bootstraps of the `input` LWE ciphertext, using the `bsk` bootstrap key. The `luts`
+/// cleartext vector contains one lookup table of polynomial size cleartexts per output
+/// ciphertext, and the $i$-th output ciphertext encrypts the cleartext selected in the $i$-th
+/// lookup table, multiplied by the `delta` plaintext. All the outputs share a single blind
+/// rotation.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorDiscardingMultiValueBootstrapEngine<
+    BootstrapKey,
+    LookupTables,
+    Delta,
+    InputCiphertext,
+    OutputCiphertextVector,
+>: AbstractEngine where
+    BootstrapKey: LweBootstrapKeyEntity,
+    LookupTables: CleartextVectorEntity,
+    Delta: PlaintextEntity,
+    InputCiphertext: LweCiphertextEntity<KeyFlavor = BootstrapKey::InputKeyFlavor>,
+    OutputCiphertextVector: LweCiphertextVectorEntity<KeyFlavor = BootstrapKey::OutputKeyFlavor>,
+{
+    /// Bootstraps an LWE ciphertext with several lookup tables.
+    fn discard_multi_value_bootstrap_lwe_ciphertext(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        luts: &LookupTables,
+        delta: &Delta,
+        bsk: &BootstrapKey,
+    ) -> Result<(), LweCiphertextVectorDiscardingMultiValueBootstrapError<Self::EngineError>>;
+
+    /// Unsafely bootstraps an LWE ciphertext with several lookup tables.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorDiscardingMultiValueBootstrapError`]. For safety concerns
+    /// _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_multi_value_bootstrap_lwe_ciphertext_unchecked(
+        &mut self,
+        output: &mut OutputCiphertextVector,
+        input: &InputCiphertext,
+        luts: &LookupTables,
+        delta: &Delta,
+        bsk: &BootstrapKey,
+    );
+}
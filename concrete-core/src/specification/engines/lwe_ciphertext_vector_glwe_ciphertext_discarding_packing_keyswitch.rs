@@ -0,0 +1,65 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity, PackingKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError
+    for LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector and key input LWE dimension must be \
+                                  the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and key output GLWE dimension must be \
+                                    the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and key output polynomial size must be \
+                                     the same.",
+    CiphertextCountTooLarge => "The input ciphertext count must not exceed the output polynomial \
+                                size."
+}
+
+/// A trait for engines packing keyswitching (discarding) LWE ciphertext vectors into GLWE
+/// ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the packing keyswitch of the `input` LWE ciphertext vector, under the `pksk` packing keyswitch
+/// key. The plaintext encrypted in the $k$-th input ciphertext ends up in the $k$-th coefficient
+/// of the output plaintext, and the remaining coefficients are zero.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchEngine<
+    PackingKeyswitchKey,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    PackingKeyswitchKey: PackingKeyswitchKeyEntity,
+    InputCiphertextVector:
+        LweCiphertextVectorEntity<KeyFlavor = PackingKeyswitchKey::InputKeyFlavor>,
+    OutputCiphertext: GlweCiphertextEntity<KeyFlavor = PackingKeyswitchKey::OutputKeyFlavor>,
+{
+    /// Packing keyswitch an LWE ciphertext vector.
+    fn discard_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pksk: &PackingKeyswitchKey,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError<Self::EngineError>,
+    >;
+
+    /// Unsafely packing keyswitch an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingPackingKeyswitchError`]. For safety
+    /// concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pksk: &PackingKeyswitchKey,
+    );
+}
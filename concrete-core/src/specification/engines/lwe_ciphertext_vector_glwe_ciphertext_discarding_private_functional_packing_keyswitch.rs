@@ -0,0 +1,70 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    GlweCiphertextEntity, LweCiphertextVectorEntity, PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError
+    for LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine @
+    InputLweDimensionMismatch => "The input ciphertext vector and key input LWE dimension must be \
+                                  the same.",
+    OutputGlweDimensionMismatch => "The output ciphertext and key output GLWE dimension must be \
+                                    the same.",
+    OutputPolynomialSizeMismatch => "The output ciphertext and key output polynomial size must be \
+                                     the same.",
+    CiphertextCountTooLarge => "The input ciphertext count must not exceed the output polynomial \
+                                size."
+}
+
+/// A trait for engines private functional packing keyswitching (discarding) LWE ciphertext vectors
+/// into GLWE ciphertexts.
+///
+/// # Semantics
+///
+/// This [discarding](super#operation-semantics) operation fills the `output` GLWE ciphertext with
+/// the private functional packing keyswitch of the `input` LWE ciphertext vector, under the
+/// `pfpksk` private functional packing keyswitch key. If $P$ is the polynomial hidden in the key,
+/// the plaintext $m_k$ encrypted in the $k$-th input ciphertext contributes $m_k\cdot P(X)\cdot
+/// X^k$ to the output plaintext.
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchEngine<
+    PrivateFunctionalPackingKeyswitchKey,
+    InputCiphertextVector,
+    OutputCiphertext,
+>: AbstractEngine where
+    PrivateFunctionalPackingKeyswitchKey: PrivateFunctionalPackingKeyswitchKeyEntity,
+    InputCiphertextVector: LweCiphertextVectorEntity<
+        KeyFlavor = PrivateFunctionalPackingKeyswitchKey::InputKeyFlavor,
+    >,
+    OutputCiphertext:
+        GlweCiphertextEntity<KeyFlavor = PrivateFunctionalPackingKeyswitchKey::OutputKeyFlavor>,
+{
+    /// Private functional packing keyswitch an LWE ciphertext vector.
+    fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey,
+    ) -> Result<
+        (),
+        LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError<
+            Self::EngineError,
+        >,
+    >;
+
+    /// Unsafely private functional packing keyswitch an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorGlweCiphertextDiscardingPrivateFunctionalPackingKeyswitchError`].
+    /// For safety concerns _specific_ to an engine, refer to the implementer safety section.
+    unsafe fn discard_private_functional_packing_keyswitch_lwe_ciphertext_vector_unchecked(
+        &mut self,
+        output: &mut OutputCiphertext,
+        input: &InputCiphertextVector,
+        pfpksk: &PrivateFunctionalPackingKeyswitchKey,
+    );
+}
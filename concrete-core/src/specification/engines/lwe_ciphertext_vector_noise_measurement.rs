@@ -0,0 +1,52 @@
+use super::engine_error;
+use crate::specification::engines::{AbstractEngine, NoiseMeasurement};
+use crate::specification::entities::{
+    LweCiphertextVectorEntity, LweSecretKeyEntity, PlaintextVectorEntity,
+};
+
+engine_error! {
+    LweCiphertextVectorNoiseMeasurementError for LweCiphertextVectorNoiseMeasurementEngine @
+    LweDimensionMismatch => "The input and secret key LWE dimension must be the same.",
+    PlaintextCountMismatch => "The expected plaintext count and the input ciphertext count must \
+                               be the same."
+}
+
+/// A trait for engines measuring the noise of LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation decrypts the `input` LWE ciphertext vector
+/// with the `key` LWE secret key, and measures the differences between the decrypted plaintexts
+/// and the `expected` plaintexts, one measurement per ciphertext. It is meant to be used for
+/// debugging, or to validate a set of parameters empirically with
+/// [`compare_noise_variance`](super::compare_noise_variance).
+///
+/// # Formal Definition
+pub trait LweCiphertextVectorNoiseMeasurementEngine<SecretKey, CiphertextVector, PlaintextVector>:
+    AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    CiphertextVector: LweCiphertextVectorEntity<KeyFlavor = SecretKey::KeyFlavor>,
+    PlaintextVector: PlaintextVectorEntity,
+{
+    /// Measures the noise of an LWE ciphertext vector.
+    fn measure_lwe_ciphertext_vector_noise(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Result<Vec<NoiseMeasurement>, LweCiphertextVectorNoiseMeasurementError<Self::EngineError>>;
+
+    /// Unsafely measures the noise of an LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweCiphertextVectorNoiseMeasurementError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn measure_lwe_ciphertext_vector_noise_unchecked(
+        &mut self,
+        key: &SecretKey,
+        input: &CiphertextVector,
+        expected: &PlaintextVector,
+    ) -> Vec<NoiseMeasurement>;
+}
@@ -0,0 +1,39 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweKeyswitchKeyEntity;
+
+engine_error! {
+    LweKeyswitchKeyConsumingRetrievalError for LweKeyswitchKeyConsumingRetrievalEngine @
+}
+
+/// A trait for engines retrieving the containers of LWE keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation consumes the `keyswitch_key` LWE keyswitch key,
+/// and returns the container of scalars it was holding. The layout of the container is the one
+/// expected by the [`LweKeyswitchKeyCreationFromContainerEngine`](super::LweKeyswitchKeyCreationFro
+/// mContainerEngine).
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyConsumingRetrievalEngine<KeyswitchKey, Container>: AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+{
+    /// Retrieves the container of an LWE keyswitch key, consuming it.
+    fn consume_retrieve_lwe_keyswitch_key(
+        &mut self,
+        keyswitch_key: KeyswitchKey,
+    ) -> Result<Container, LweKeyswitchKeyConsumingRetrievalError<Self::EngineError>>;
+
+    /// Unsafely retrieves the container of an LWE keyswitch key, consuming it.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyConsumingRetrievalError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn consume_retrieve_lwe_keyswitch_key_unchecked(
+        &mut self,
+        keyswitch_key: KeyswitchKey,
+    ) -> Container;
+}
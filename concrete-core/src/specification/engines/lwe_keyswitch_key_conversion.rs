@@ -4,6 +4,8 @@ use crate::specification::entities::LweKeyswitchKeyEntity;
 
 engine_error! {
     LweKeyswitchKeyConversionError for LweKeyswitchKeyConversionEngine @
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be \
+                              smaller than the precision of the output key."
 }
 
 /// A trait for engines converting LWE keyswitch keys.
@@ -0,0 +1,55 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::LweKeyswitchKeyEntity;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
+
+engine_error! {
+    LweKeyswitchKeyCreationFromContainerError for LweKeyswitchKeyCreationFromContainerEngine @
+    EmptyContainer => "The input container must not be empty.",
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be smaller \
+                              than the precision of the ciphertext.",
+    InvalidContainerSize => "The length of the input container must be a multiple of the \
+                             decomposition level count times the output LWE size."
+}
+
+/// A trait for engines creating LWE keyswitch keys from existing containers.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE keyswitch key from the
+/// `container` of scalars, which holds, for each coefficient of the input LWE secret key, the
+/// `decomposition_level_count` LWE ciphertexts of dimension `output_lwe_dimension` one after the
+/// other. The key takes ownership of the container, whose layout is the one returned by the
+/// [`LweKeyswitchKeyConsumingRetrievalEngine`](super::LweKeyswitchKeyConsumingRetrievalEngine).
+///
+/// # Formal Definition
+pub trait LweKeyswitchKeyCreationFromContainerEngine<Container, KeyswitchKey>:
+    AbstractEngine
+where
+    KeyswitchKey: LweKeyswitchKeyEntity,
+{
+    /// Creates an LWE keyswitch key from a container of scalars.
+    fn create_lwe_keyswitch_key_from_container(
+        &mut self,
+        container: Container,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Result<KeyswitchKey, LweKeyswitchKeyCreationFromContainerError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE keyswitch key from a container of scalars.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweKeyswitchKeyCreationFromContainerError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn create_lwe_keyswitch_key_from_container_unchecked(
+        &mut self,
+        container: Container,
+        output_lwe_dimension: LweDimension,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> KeyswitchKey;
+}
@@ -7,7 +7,9 @@ engine_error! {
     InputLweDimensionMismatch => "The two keys must have the same input LWE dimension.",
     OutputLweDimensionMismatch => "The two keys must have the same output LWE dimension.",
     DecompositionBaseLogMismatch => "The two keys must have the same base logarithms.",
-    DecompositionLevelCountMismatch => "The two keys must have the same level counts."
+    DecompositionLevelCountMismatch => "The two keys must have the same level counts.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must be \
+                              smaller than the precision of the output key."
 }
 
 /// A trait for engines converting (discarding) LWE keyswitch keys .
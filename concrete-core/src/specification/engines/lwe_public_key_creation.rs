@@ -0,0 +1,48 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{LwePublicKeyEntity, LweSecretKeyEntity};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+
+engine_error! {
+    LwePublicKeyCreationError for LwePublicKeyCreationEngine @
+    NullPublicKeyZeroEncryptionCount => "The number of encryptions of zero in the public key \
+                                         must be greater than zero."
+}
+
+/// A trait for engines creating LWE public keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates an LWE public key made of
+/// `lwe_public_key_zero_encryption_count` encryptions of zero under the `secret_key` LWE secret
+/// key.
+///
+/// # Formal Definition
+pub trait LwePublicKeyCreationEngine<SecretKey, PublicKey>: AbstractEngine
+where
+    SecretKey: LweSecretKeyEntity,
+    PublicKey: LwePublicKeyEntity<KeyFlavor = SecretKey::KeyFlavor>,
+{
+    /// Creates an LWE public key.
+    fn create_lwe_public_key(
+        &mut self,
+        secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> Result<PublicKey, LwePublicKeyCreationError<Self::EngineError>>;
+
+    /// Unsafely creates an LWE public key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LwePublicKeyCreationError`]. For safety concerns _specific_ to an
+    /// engine, refer to the implementer safety section.
+    unsafe fn create_lwe_public_key_unchecked(
+        &mut self,
+        secret_key: &SecretKey,
+        noise: Variance,
+        lwe_public_key_zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+    ) -> PublicKey;
+}
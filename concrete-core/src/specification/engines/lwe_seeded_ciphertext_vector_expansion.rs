@@ -0,0 +1,36 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+use crate::specification::entities::{LweCiphertextVectorEntity, LweSeededCiphertextVectorEntity};
+
+engine_error! {
+    LweSeededCiphertextVectorExpansionError for LweSeededCiphertextVectorExpansionEngine @
+}
+
+/// A trait for engines expanding seeded LWE ciphertext vectors.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation generates an LWE ciphertext vector containing
+/// the expansion of the `input` seeded LWE ciphertext vector, by regenerating the masks of its
+/// ciphertexts from their stored seeds.
+///
+/// # Formal Definition
+pub trait LweSeededCiphertextVectorExpansionEngine<Input, Output>: AbstractEngine
+where
+    Input: LweSeededCiphertextVectorEntity,
+    Output: LweCiphertextVectorEntity<KeyFlavor = Input::KeyFlavor>,
+{
+    /// Expands a seeded LWE ciphertext vector.
+    fn expand_lwe_seeded_ciphertext_vector(
+        &mut self,
+        input: &Input,
+    ) -> Result<Output, LweSeededCiphertextVectorExpansionError<Self::EngineError>>;
+
+    /// Unsafely expands a seeded LWE ciphertext vector.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`LweSeededCiphertextVectorExpansionError`]. For safety concerns _specific_ to an engine,
+    /// refer to the implementer safety section.
+    unsafe fn expand_lwe_seeded_ciphertext_vector_unchecked(&mut self, input: &Input) -> Output;
+}
@@ -113,25 +113,59 @@ macro_rules! engine_error{
             }
         }
         impl<EngineError: std::error::Error> std::error::Error for $name<EngineError>{}
+        impl<EngineError: std::error::Error> $name<EngineError>{
+            #[doc="Converts the _specific_ error with the given function, leaving the _generic_"]
+            #[doc="errors untouched."]
+            pub fn map_engine_error<OutputError, F>(self, f: F) -> $name<OutputError>
+            where
+                OutputError: std::error::Error,
+                F: FnOnce(EngineError) -> OutputError,
+            {
+                match self {
+                    $(
+                        Self::$variants => $name::$variants,
+                    )*
+                    Self::Engine(error) => $name::Engine(f(error)),
+                }
+            }
+        }
     }
 }
 pub(crate) use engine_error;
 
+mod circuit_bootstrap_private_functional_packing_keyswitch_keys_creation;
 mod cleartext_conversion;
 mod cleartext_creation;
 mod cleartext_discarding_conversion;
 mod cleartext_encoding;
+mod cleartext_retrieval;
 mod cleartext_vector_conversion;
 mod cleartext_vector_creation;
 mod cleartext_vector_discarding_conversion;
 mod cleartext_vector_encoding;
+mod cleartext_vector_retrieval;
 mod destruction;
+mod encoder_creation;
+mod encoder_vector_creation;
+mod entity_deserialization;
+mod entity_serialization;
+mod ggsw_ciphertext_conversion;
+mod ggsw_ciphertext_scalar_discarding_encryption;
+mod ggsw_ciphertext_scalar_encryption;
+mod ggsw_ciphertext_scalar_trivial_encryption;
+mod ggsw_ciphertext_vector_scalar_encryption;
+mod glwe_ciphertext_consuming_retrieval;
 mod glwe_ciphertext_conversion;
+mod glwe_ciphertext_creation;
 mod glwe_ciphertext_decryption;
 mod glwe_ciphertext_discarding_conversion;
 mod glwe_ciphertext_discarding_decryption;
 mod glwe_ciphertext_discarding_encryption;
+mod glwe_ciphertext_discarding_multiplication;
 mod glwe_ciphertext_encryption;
+mod glwe_ciphertext_ggsw_ciphertext_external_product;
+#[cfg(feature = "noise_measurement")]
+mod glwe_ciphertext_noise_measurement;
 mod glwe_ciphertext_vector_conversion;
 mod glwe_ciphertext_vector_decryption;
 mod glwe_ciphertext_vector_discarding_conversion;
@@ -140,15 +174,27 @@ mod glwe_ciphertext_vector_discarding_encryption;
 mod glwe_ciphertext_vector_encryption;
 mod glwe_ciphertext_vector_zero_encryption;
 mod glwe_ciphertext_zero_encryption;
+mod glwe_ciphertexts_ggsw_ciphertext_cmux;
+mod glwe_relinearization_key_creation;
 mod glwe_secret_key_conversion;
 mod glwe_secret_key_creation;
 mod glwe_secret_key_discarding_conversion;
+mod glwe_seeded_ciphertext_encryption;
+mod glwe_seeded_ciphertext_expansion;
+mod glwe_seeded_ciphertext_vector_encryption;
+mod glwe_seeded_ciphertext_vector_expansion;
+mod lwe_bootstrap_key_consuming_retrieval;
 mod lwe_bootstrap_key_conversion;
 mod lwe_bootstrap_key_creation;
+mod lwe_bootstrap_key_creation_from_container;
 mod lwe_bootstrap_key_discarding_conversion;
 mod lwe_ciphertext_cleartext_discarding_multiplication;
 mod lwe_ciphertext_cleartext_fusing_multiplication;
+mod lwe_ciphertext_compression;
+mod lwe_ciphertext_consuming_retrieval;
 mod lwe_ciphertext_conversion;
+mod lwe_ciphertext_creation;
+mod lwe_ciphertext_decompression;
 mod lwe_ciphertext_decryption;
 mod lwe_ciphertext_discarding_addition;
 mod lwe_ciphertext_discarding_bootstrap;
@@ -160,13 +206,22 @@ mod lwe_ciphertext_discarding_keyswitch;
 mod lwe_ciphertext_discarding_loading;
 mod lwe_ciphertext_discarding_negation;
 mod lwe_ciphertext_discarding_storing;
+mod lwe_ciphertext_discarding_vertical_packing;
 mod lwe_ciphertext_encryption;
 mod lwe_ciphertext_fusing_addition;
 mod lwe_ciphertext_fusing_negation;
+mod lwe_ciphertext_ggsw_ciphertext_discarding_circuit_bootstrap;
 mod lwe_ciphertext_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_noise_measurement;
 mod lwe_ciphertext_plaintext_discarding_addition;
 mod lwe_ciphertext_plaintext_fusing_addition;
+mod lwe_ciphertext_public_key_encryption;
+mod lwe_ciphertext_vector_compression;
+mod lwe_ciphertext_vector_consuming_retrieval;
 mod lwe_ciphertext_vector_conversion;
+mod lwe_ciphertext_vector_creation;
+mod lwe_ciphertext_vector_decompression;
 mod lwe_ciphertext_vector_decryption;
 mod lwe_ciphertext_vector_discarding_addition;
 mod lwe_ciphertext_vector_discarding_affine_transformation;
@@ -176,19 +231,34 @@ mod lwe_ciphertext_vector_discarding_decryption;
 mod lwe_ciphertext_vector_discarding_encryption;
 mod lwe_ciphertext_vector_discarding_keyswitch;
 mod lwe_ciphertext_vector_discarding_loading;
+mod lwe_ciphertext_vector_discarding_multi_value_bootstrap;
 mod lwe_ciphertext_vector_discarding_negation;
 mod lwe_ciphertext_vector_encryption;
 mod lwe_ciphertext_vector_fusing_addition;
 mod lwe_ciphertext_vector_fusing_negation;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch;
+mod lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch;
 mod lwe_ciphertext_vector_loading;
+#[cfg(feature = "noise_measurement")]
+mod lwe_ciphertext_vector_noise_measurement;
 mod lwe_ciphertext_vector_zero_encryption;
 mod lwe_ciphertext_zero_encryption;
+mod lwe_keyswitch_key_consuming_retrieval;
 mod lwe_keyswitch_key_conversion;
 mod lwe_keyswitch_key_creation;
+mod lwe_keyswitch_key_creation_from_container;
 mod lwe_keyswitch_key_discarding_conversion;
+mod lwe_public_key_creation;
 mod lwe_secret_key_conversion;
 mod lwe_secret_key_creation;
 mod lwe_secret_key_discarding_conversion;
+mod lwe_seeded_ciphertext_encryption;
+mod lwe_seeded_ciphertext_expansion;
+mod lwe_seeded_ciphertext_vector_encryption;
+mod lwe_seeded_ciphertext_vector_expansion;
+#[cfg(feature = "noise_measurement")]
+mod noise_measurement;
+mod packing_keyswitch_key_creation;
 mod plaintext_conversion;
 mod plaintext_creation;
 mod plaintext_decoding;
@@ -197,22 +267,41 @@ mod plaintext_vector_conversion;
 mod plaintext_vector_creation;
 mod plaintext_vector_decoding;
 mod plaintext_vector_discarding_conversion;
+mod private_functional_packing_keyswitch_key_creation;
 
+pub use circuit_bootstrap_private_functional_packing_keyswitch_keys_creation::*;
 pub use cleartext_conversion::*;
 pub use cleartext_creation::*;
 pub use cleartext_discarding_conversion::*;
 pub use cleartext_encoding::*;
+pub use cleartext_retrieval::*;
 pub use cleartext_vector_conversion::*;
 pub use cleartext_vector_creation::*;
 pub use cleartext_vector_discarding_conversion::*;
 pub use cleartext_vector_encoding::*;
+pub use cleartext_vector_retrieval::*;
 pub use destruction::*;
+pub use encoder_creation::*;
+pub use encoder_vector_creation::*;
+pub use entity_deserialization::*;
+pub use entity_serialization::*;
+pub use ggsw_ciphertext_conversion::*;
+pub use ggsw_ciphertext_scalar_discarding_encryption::*;
+pub use ggsw_ciphertext_scalar_encryption::*;
+pub use ggsw_ciphertext_scalar_trivial_encryption::*;
+pub use ggsw_ciphertext_vector_scalar_encryption::*;
+pub use glwe_ciphertext_consuming_retrieval::*;
 pub use glwe_ciphertext_conversion::*;
+pub use glwe_ciphertext_creation::*;
 pub use glwe_ciphertext_decryption::*;
 pub use glwe_ciphertext_discarding_conversion::*;
 pub use glwe_ciphertext_discarding_decryption::*;
 pub use glwe_ciphertext_discarding_encryption::*;
+pub use glwe_ciphertext_discarding_multiplication::*;
 pub use glwe_ciphertext_encryption::*;
+pub use glwe_ciphertext_ggsw_ciphertext_external_product::*;
+#[cfg(feature = "noise_measurement")]
+pub use glwe_ciphertext_noise_measurement::*;
 pub use glwe_ciphertext_vector_conversion::*;
 pub use glwe_ciphertext_vector_decryption::*;
 pub use glwe_ciphertext_vector_discarding_conversion::*;
@@ -221,15 +310,27 @@ pub use glwe_ciphertext_vector_discarding_encryption::*;
 pub use glwe_ciphertext_vector_encryption::*;
 pub use glwe_ciphertext_vector_zero_encryption::*;
 pub use glwe_ciphertext_zero_encryption::*;
+pub use glwe_ciphertexts_ggsw_ciphertext_cmux::*;
+pub use glwe_relinearization_key_creation::*;
 pub use glwe_secret_key_conversion::*;
 pub use glwe_secret_key_creation::*;
 pub use glwe_secret_key_discarding_conversion::*;
+pub use glwe_seeded_ciphertext_encryption::*;
+pub use glwe_seeded_ciphertext_expansion::*;
+pub use glwe_seeded_ciphertext_vector_encryption::*;
+pub use glwe_seeded_ciphertext_vector_expansion::*;
+pub use lwe_bootstrap_key_consuming_retrieval::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_creation::*;
+pub use lwe_bootstrap_key_creation_from_container::*;
 pub use lwe_bootstrap_key_discarding_conversion::*;
 pub use lwe_ciphertext_cleartext_discarding_multiplication::*;
 pub use lwe_ciphertext_cleartext_fusing_multiplication::*;
+pub use lwe_ciphertext_compression::*;
+pub use lwe_ciphertext_consuming_retrieval::*;
 pub use lwe_ciphertext_conversion::*;
+pub use lwe_ciphertext_creation::*;
+pub use lwe_ciphertext_decompression::*;
 pub use lwe_ciphertext_decryption::*;
 pub use lwe_ciphertext_discarding_addition::*;
 pub use lwe_ciphertext_discarding_bootstrap::*;
@@ -241,13 +342,22 @@ pub use lwe_ciphertext_discarding_keyswitch::*;
 pub use lwe_ciphertext_discarding_loading::*;
 pub use lwe_ciphertext_discarding_negation::*;
 pub use lwe_ciphertext_discarding_storing::*;
+pub use lwe_ciphertext_discarding_vertical_packing::*;
 pub use lwe_ciphertext_encryption::*;
 pub use lwe_ciphertext_fusing_addition::*;
 pub use lwe_ciphertext_fusing_negation::*;
+pub use lwe_ciphertext_ggsw_ciphertext_discarding_circuit_bootstrap::*;
 pub use lwe_ciphertext_loading::*;
+#[cfg(feature = "noise_measurement")]
+pub use lwe_ciphertext_noise_measurement::*;
 pub use lwe_ciphertext_plaintext_discarding_addition::*;
 pub use lwe_ciphertext_plaintext_fusing_addition::*;
+pub use lwe_ciphertext_public_key_encryption::*;
+pub use lwe_ciphertext_vector_compression::*;
+pub use lwe_ciphertext_vector_consuming_retrieval::*;
 pub use lwe_ciphertext_vector_conversion::*;
+pub use lwe_ciphertext_vector_creation::*;
+pub use lwe_ciphertext_vector_decompression::*;
 pub use lwe_ciphertext_vector_decryption::*;
 pub use lwe_ciphertext_vector_discarding_addition::*;
 pub use lwe_ciphertext_vector_discarding_affine_transformation::*;
@@ -257,19 +367,34 @@ pub use lwe_ciphertext_vector_discarding_decryption::*;
 pub use lwe_ciphertext_vector_discarding_encryption::*;
 pub use lwe_ciphertext_vector_discarding_keyswitch::*;
 pub use lwe_ciphertext_vector_discarding_loading::*;
+pub use lwe_ciphertext_vector_discarding_multi_value_bootstrap::*;
 pub use lwe_ciphertext_vector_discarding_negation::*;
 pub use lwe_ciphertext_vector_encryption::*;
 pub use lwe_ciphertext_vector_fusing_addition::*;
 pub use lwe_ciphertext_vector_fusing_negation::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_packing_keyswitch::*;
+pub use lwe_ciphertext_vector_glwe_ciphertext_discarding_private_functional_packing_keyswitch::*;
 pub use lwe_ciphertext_vector_loading::*;
+#[cfg(feature = "noise_measurement")]
+pub use lwe_ciphertext_vector_noise_measurement::*;
 pub use lwe_ciphertext_vector_zero_encryption::*;
 pub use lwe_ciphertext_zero_encryption::*;
+pub use lwe_keyswitch_key_consuming_retrieval::*;
 pub use lwe_keyswitch_key_conversion::*;
 pub use lwe_keyswitch_key_creation::*;
+pub use lwe_keyswitch_key_creation_from_container::*;
 pub use lwe_keyswitch_key_discarding_conversion::*;
+pub use lwe_public_key_creation::*;
 pub use lwe_secret_key_conversion::*;
 pub use lwe_secret_key_creation::*;
 pub use lwe_secret_key_discarding_conversion::*;
+pub use lwe_seeded_ciphertext_encryption::*;
+pub use lwe_seeded_ciphertext_expansion::*;
+pub use lwe_seeded_ciphertext_vector_encryption::*;
+pub use lwe_seeded_ciphertext_vector_expansion::*;
+#[cfg(feature = "noise_measurement")]
+pub use noise_measurement::*;
+pub use packing_keyswitch_key_creation::*;
 pub use plaintext_conversion::*;
 pub use plaintext_creation::*;
 pub use plaintext_decoding::*;
@@ -278,3 +403,4 @@ pub use plaintext_vector_conversion::*;
 pub use plaintext_vector_creation::*;
 pub use plaintext_vector_decoding::*;
 pub use plaintext_vector_discarding_conversion::*;
+pub use private_functional_packing_keyswitch_key_creation::*;
@@ -0,0 +1,134 @@
+use concrete_commons::dispersion::{DispersionParameter, Variance};
+
+/// The error carried by a ciphertext coefficient, as measured by a noise measurement engine.
+///
+/// The error is the difference between the decrypted plaintext and the expected one, lifted to
+/// a signed integer centered around zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoiseMeasurement {
+    /// The signed error, in the integer representation of the torus.
+    pub error: i64,
+    /// The number of bits needed to represent the absolute value of the error.
+    pub bit_size: usize,
+    /// The precision of the ciphertext, e.g. the number of bits of its integer representation.
+    pub precision: usize,
+}
+
+impl NoiseMeasurement {
+    /// Creates a measurement from a signed error and the precision of the measured ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_core::prelude::NoiseMeasurement;
+    /// let measurement = NoiseMeasurement::from_error(-5, 32);
+    /// assert_eq!(measurement.error, -5);
+    /// assert_eq!(measurement.bit_size, 3);
+    /// assert_eq!(measurement.torus_error(), -5. / 2_f64.powi(32));
+    /// ```
+    pub fn from_error(error: i64, precision: usize) -> NoiseMeasurement {
+        NoiseMeasurement {
+            error,
+            bit_size: (64 - error.unsigned_abs().leading_zeros()) as usize,
+            precision,
+        }
+    }
+
+    /// Returns the error as a fraction of the torus.
+    pub fn torus_error(&self) -> f64 {
+        self.error as f64 / 2_f64.powi(self.precision as i32)
+    }
+}
+
+/// The comparison of the empirical variance of a set of measured errors, to a predicted
+/// variance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseVarianceComparison {
+    /// The empirical variance of the measured errors, as a fraction of the torus.
+    pub measured: Variance,
+    /// The predicted variance, as a fraction of the torus.
+    pub predicted: Variance,
+    /// The number of measured errors.
+    pub sample_count: usize,
+}
+
+impl NoiseVarianceComparison {
+    /// Returns the ratio between the measured and the predicted variance.
+    pub fn ratio(&self) -> f64 {
+        self.measured.0 / self.predicted.0
+    }
+
+    /// Returns whether the measured variance is consistent with the predicted one.
+    ///
+    /// The errors being centered, the empirical variance of $n$ gaussian errors deviates from the
+    /// actual variance by a relative standard deviation of $\sqrt{2/n}$. The measured variance is
+    /// considered consistent with the prediction if their ratio is within four of those relative
+    /// standard deviations of one.
+    pub fn is_consistent(&self) -> bool {
+        let tolerance = 4. * (2. / self.sample_count as f64).sqrt();
+        (self.ratio() - 1.).abs() <= tolerance
+    }
+}
+
+/// Compares the empirical variance of the `measurements` to the `predicted` dispersion, usually
+/// computed with the `concrete-npe` crate.
+///
+/// # Example
+///
+/// ```
+/// use concrete_commons::dispersion::Variance;
+/// use concrete_commons::parameters::LweDimension;
+/// use concrete_core::prelude::*;
+/// # use std::error::Error;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// // DISCLAIMER: the parameters used here are only for test purpose, and are not secure.
+/// let lwe_dimension = LweDimension(630);
+/// // Here a hard-set encoding is applied (shift by 50 bits)
+/// let input = 3_u64 << 50;
+/// let noise = Variance(2_f64.powf(-40.));
+///
+/// let mut engine = CoreEngine::new()?;
+/// let key: LweSecretKey64 = engine.create_lwe_secret_key(lwe_dimension)?;
+/// let plaintext = engine.create_plaintext(&input)?;
+/// let expected = engine.create_plaintext(&(6_u64 << 50))?;
+///
+/// // We measure the noise of many sums of two fresh ciphertexts.
+/// let mut measurements = Vec::new();
+/// for _ in 0..1000 {
+///     let mut ciphertext_1 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+///     let ciphertext_2 = engine.encrypt_lwe_ciphertext(&key, &plaintext, noise)?;
+///     engine.fuse_add_lwe_ciphertext(&mut ciphertext_1, &ciphertext_2)?;
+///     measurements.push(engine.measure_lwe_ciphertext_noise(&key, &ciphertext_1, &expected)?);
+///     engine.destroy(ciphertext_1)?;
+///     engine.destroy(ciphertext_2)?;
+/// }
+///
+/// // And compare their variance to the prediction of `concrete-npe`.
+/// let predicted = concrete_npe::estimate_addition_noise::<u64, _, _>(noise, noise);
+/// let comparison = compare_noise_variance(&measurements, predicted);
+/// assert!(comparison.is_consistent());
+///
+/// engine.destroy(key)?;
+/// engine.destroy(plaintext)?;
+/// engine.destroy(expected)?;
+/// #
+/// # Ok(())
+/// # }
+/// ```
+pub fn compare_noise_variance(
+    measurements: &[NoiseMeasurement],
+    predicted: impl DispersionParameter,
+) -> NoiseVarianceComparison {
+    let sample_count = measurements.len();
+    let measured = measurements
+        .iter()
+        .map(|measurement| measurement.torus_error().powi(2))
+        .sum::<f64>()
+        / sample_count as f64;
+    NoiseVarianceComparison {
+        measured: Variance(measured),
+        predicted: Variance(predicted.get_variance()),
+        sample_count,
+    }
+}
@@ -35,7 +35,7 @@ where
     /// implementer safety section.
     unsafe fn decode_plaintext_unchecked(
         &mut self,
-        input: &Plaintext,
         encoder: &Encoder,
+        input: &Plaintext,
     ) -> Cleartext;
 }
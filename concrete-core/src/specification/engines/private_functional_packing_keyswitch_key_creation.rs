@@ -0,0 +1,76 @@
+use super::engine_error;
+use crate::specification::engines::AbstractEngine;
+
+use crate::specification::entities::{
+    CleartextVectorEntity, GlweSecretKeyEntity, LweSecretKeyEntity,
+    PrivateFunctionalPackingKeyswitchKeyEntity,
+};
+use concrete_commons::dispersion::Variance;
+use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+engine_error! {
+    PrivateFunctionalPackingKeyswitchKeyCreationError
+    for PrivateFunctionalPackingKeyswitchKeyCreationEngine @
+    NullDecompositionBaseLog => "The key decomposition base log must be greater than zero.",
+    NullDecompositionLevelCount => "The key decomposition level count must be greater than zero.",
+    DecompositionTooLarge => "The decomposition precision (base log * level count) must not exceed \
+                              the precision of the ciphertext.",
+    PolynomialSizeMismatch => "The number of coefficients of the linear map polynomial and the \
+                               output key polynomial size must be the same."
+}
+
+/// A trait for engines creating private functional packing keyswitch keys.
+///
+/// # Semantics
+///
+/// This [pure](super#operation-semantics) operation creates a private functional packing
+/// keyswitch key allowing to pack LWE ciphertexts encrypted under the `input_key` LWE secret key,
+/// into a GLWE ciphertext encrypted under the `output_key` GLWE secret key, while applying the
+/// linear map $m \mapsto m\cdot P(X)$ to the encrypted plaintexts. The coefficients of $P$ are
+/// given by the `polynomial` cleartext vector, from the constant term up.
+///
+/// # Formal Definition
+pub trait PrivateFunctionalPackingKeyswitchKeyCreationEngine<
+    InputSecretKey,
+    OutputSecretKey,
+    Polynomial,
+    PrivateFunctionalPackingKeyswitchKey,
+>: AbstractEngine where
+    InputSecretKey: LweSecretKeyEntity,
+    OutputSecretKey: GlweSecretKeyEntity,
+    Polynomial: CleartextVectorEntity,
+    PrivateFunctionalPackingKeyswitchKey: PrivateFunctionalPackingKeyswitchKeyEntity<
+        InputKeyFlavor = InputSecretKey::KeyFlavor,
+        OutputKeyFlavor = OutputSecretKey::KeyFlavor,
+    >,
+{
+    /// Creates a private functional packing keyswitch key.
+    fn create_private_functional_packing_keyswitch_key(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        polynomial: &Polynomial,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> Result<
+        PrivateFunctionalPackingKeyswitchKey,
+        PrivateFunctionalPackingKeyswitchKeyCreationError<Self::EngineError>,
+    >;
+
+    /// Unsafely creates a private functional packing keyswitch key.
+    ///
+    /// # Safety
+    /// For the _general_ safety concerns regarding this operation, refer to the different variants
+    /// of [`PrivateFunctionalPackingKeyswitchKeyCreationError`]. For safety concerns _specific_ to
+    /// an engine, refer to the implementer safety section.
+    unsafe fn create_private_functional_packing_keyswitch_key_unchecked(
+        &mut self,
+        input_key: &InputSecretKey,
+        output_key: &OutputSecretKey,
+        polynomial: &Polynomial,
+        decomposition_level_count: DecompositionLevelCount,
+        decomposition_base_log: DecompositionBaseLog,
+        noise: Variance,
+    ) -> PrivateFunctionalPackingKeyswitchKey;
+}
@@ -0,0 +1,48 @@
+use crate::specification::entities::markers::{
+    CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind, KeyFlavorMarker,
+};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying the private functional packing keyswitch keys used in
+/// the circuit bootstrap.
+///
+/// The circuit bootstrap turns an LWE ciphertext encrypting a bit into a GGSW ciphertext. It
+/// relies on one private functional packing keyswitch key per row of the output GGSW level
+/// matrices, all of them being stored in a single entity. It is associated with two
+/// [`KeyFlavorMarker`] types:
+///
+/// + The [`InputKeyFlavor`](`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity::InputKeyFlavor`)
+///   type conveys the flavor of the output key of the bootstrap key used in the circuit bootstrap.
+/// + The [`OutputKeyFlavor`](`CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity::OutputKeyFlavor`)
+///   type conveys the flavor of the GLWE secret key of the output GGSW ciphertexts.
+pub trait CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysEntity:
+    AbstractEntity<Kind = CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind>
+{
+    /// The flavor of key the bootstrapped ciphertexts are encrypted with.
+    type InputKeyFlavor: KeyFlavorMarker;
+
+    /// The flavor of the key the output GGSW ciphertexts are encrypted with.
+    type OutputKeyFlavor: KeyFlavorMarker;
+
+    /// Returns the input LWE dimension of the keys.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the keys.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial size of the keys.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the keys.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the keys.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+
+    /// Returns the number of private functional packing keyswitch keys.
+    fn key_count(&self) -> FunctionalPackingKeyswitchKeyCount;
+}
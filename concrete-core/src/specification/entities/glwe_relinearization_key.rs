@@ -0,0 +1,28 @@
+use crate::specification::entities::markers::{GlweRelinearizationKeyKind, KeyFlavorMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a GLWE relinearization key.
+///
+/// A GLWE relinearization key allows to turn the tensor product of two GLWE ciphertexts back
+/// into a GLWE ciphertext encrypted under the original key. It is associated with a
+/// [`KeyFlavor`](`GlweRelinearizationKeyEntity::KeyFlavor`) type, which conveys the flavor of
+/// this secret key.
+pub trait GlweRelinearizationKeyEntity: AbstractEntity<Kind = GlweRelinearizationKeyKind> {
+    /// The flavor of the GLWE secret key the relinearization key was created from.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the GLWE dimension of the key.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the key.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -0,0 +1,22 @@
+use crate::specification::entities::markers::{GlweSeededCiphertextKind, KeyFlavorMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying a seeded GLWE ciphertext.
+///
+/// A seeded GLWE ciphertext only stores the body of the ciphertext, along with the seed used to
+/// generate its mask.
+///
+/// A seeded GLWE ciphertext is associated with a
+/// [`KeyFlavor`](`GlweSeededCiphertextEntity::KeyFlavor`) type, which conveys the flavor of secret
+/// key it was encrypted with.
+pub trait GlweSeededCiphertextEntity: AbstractEntity<Kind = GlweSeededCiphertextKind> {
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the GLWE dimension of the ciphertext.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertext.
+    fn polynomial_size(&self) -> PolynomialSize;
+}
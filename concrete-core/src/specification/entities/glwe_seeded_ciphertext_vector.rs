@@ -0,0 +1,28 @@
+use crate::specification::entities::markers::{GlweSeededCiphertextVectorKind, KeyFlavorMarker};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{GlweCiphertextCount, GlweDimension, PolynomialSize};
+
+/// A trait implemented by types embodying a seeded GLWE ciphertext vector.
+///
+/// A seeded GLWE ciphertext vector only stores the bodies of the ciphertexts, along with one seed
+/// per ciphertext, used to generate its mask. Each ciphertext can thus be expanded independently
+/// of the others.
+///
+/// A seeded GLWE ciphertext vector is associated with a
+/// [`KeyFlavor`](`GlweSeededCiphertextVectorEntity::KeyFlavor`) type, which conveys the flavor of
+/// secret key it was encrypted with.
+pub trait GlweSeededCiphertextVectorEntity:
+    AbstractEntity<Kind = GlweSeededCiphertextVectorKind>
+{
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the GLWE dimension of the ciphertexts.
+    fn glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the polynomial size of the ciphertexts.
+    fn polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of ciphertexts in the vector.
+    fn glwe_ciphertext_count(&self) -> GlweCiphertextCount;
+}
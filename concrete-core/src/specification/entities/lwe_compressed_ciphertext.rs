@@ -0,0 +1,24 @@
+use crate::specification::entities::markers::{KeyFlavorMarker, LweCompressedCiphertextKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{CiphertextModulusLog, LweDimension};
+
+/// A trait implemented by types embodying a compressed LWE ciphertext.
+///
+/// A compressed LWE ciphertext stores the coefficients of an LWE ciphertext switched to a smaller
+/// power of two modulus, which makes it smaller to store or transmit.
+///
+/// A compressed LWE ciphertext is associated with a
+/// [`KeyFlavor`](`LweCompressedCiphertextEntity::KeyFlavor`) type, which conveys the flavor of
+/// secret key it was encrypted with.
+pub trait LweCompressedCiphertextEntity:
+    AbstractEntity<Kind = LweCompressedCiphertextKind>
+{
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the logarithm of the modulus of the ciphertext.
+    fn modulus_log(&self) -> CiphertextModulusLog;
+}
@@ -0,0 +1,27 @@
+use crate::specification::entities::markers::{KeyFlavorMarker, LweCompressedCiphertextVectorKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{CiphertextModulusLog, LweCiphertextCount, LweDimension};
+
+/// A trait implemented by types embodying a compressed LWE ciphertext vector.
+///
+/// A compressed LWE ciphertext vector stores the coefficients of LWE ciphertexts switched to a
+/// smaller power of two modulus, which makes them smaller to store or transmit.
+///
+/// A compressed LWE ciphertext vector is associated with a
+/// [`KeyFlavor`](`LweCompressedCiphertextVectorEntity::KeyFlavor`) type, which conveys the flavor
+/// of secret key it was encrypted with.
+pub trait LweCompressedCiphertextVectorEntity:
+    AbstractEntity<Kind = LweCompressedCiphertextVectorKind>
+{
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the LWE dimension of the ciphertexts.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of ciphertexts contained in the vector.
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount;
+
+    /// Returns the logarithm of the modulus of the ciphertexts.
+    fn modulus_log(&self) -> CiphertextModulusLog;
+}
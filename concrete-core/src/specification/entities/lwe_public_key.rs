@@ -0,0 +1,18 @@
+use crate::specification::entities::markers::{KeyFlavorMarker, LwePublicKeyKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// A trait implemented by types embodying an LWE public key.
+///
+/// An LWE public key is associated with a [`KeyFlavor`](`LwePublicKeyEntity::KeyFlavor`) type,
+/// which conveys the flavor of the secret key it was created from.
+pub trait LwePublicKeyEntity: AbstractEntity<Kind = LwePublicKeyKind> {
+    /// The flavor of key the public key was created from.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the LWE dimension of the key.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of encryptions of zero contained in the key.
+    fn lwe_zero_encryption_count(&self) -> LwePublicKeyZeroEncryptionCount;
+}
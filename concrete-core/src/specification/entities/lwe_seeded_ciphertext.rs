@@ -0,0 +1,19 @@
+use crate::specification::entities::markers::{KeyFlavorMarker, LweSeededCiphertextKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::LweDimension;
+
+/// A trait implemented by types embodying a seeded LWE ciphertext.
+///
+/// A seeded LWE ciphertext only stores the body of the ciphertext, along with the seed used to
+/// generate its mask.
+///
+/// A seeded LWE ciphertext is associated with a
+/// [`KeyFlavor`](`LweSeededCiphertextEntity::KeyFlavor`) type, which conveys the flavor of secret
+/// key it was encrypted with.
+pub trait LweSeededCiphertextEntity: AbstractEntity<Kind = LweSeededCiphertextKind> {
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the LWE dimension of the ciphertext.
+    fn lwe_dimension(&self) -> LweDimension;
+}
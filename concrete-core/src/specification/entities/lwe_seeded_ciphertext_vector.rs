@@ -0,0 +1,25 @@
+use crate::specification::entities::markers::{KeyFlavorMarker, LweSeededCiphertextVectorKind};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{LweCiphertextCount, LweDimension};
+
+/// A trait implemented by types embodying a seeded LWE ciphertext vector.
+///
+/// A seeded LWE ciphertext vector only stores the bodies of the ciphertexts, along with one seed
+/// per ciphertext, used to generate its mask. Each ciphertext can thus be expanded independently
+/// of the others.
+///
+/// A seeded LWE ciphertext vector is associated with a
+/// [`KeyFlavor`](`LweSeededCiphertextVectorEntity::KeyFlavor`) type, which conveys the flavor of
+/// secret key it was encrypted with.
+pub trait LweSeededCiphertextVectorEntity:
+    AbstractEntity<Kind = LweSeededCiphertextVectorKind>
+{
+    /// The flavor of key the ciphertext was encrypted with.
+    type KeyFlavor: KeyFlavorMarker;
+
+    /// Returns the LWE dimension of the ciphertexts.
+    fn lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the number of ciphertexts contained in the vector.
+    fn lwe_ciphertext_count(&self) -> LweCiphertextCount;
+}
@@ -42,6 +42,21 @@ entity_kind_marker! {
             => "An empty type representing the GLWE ciphertext kind in the type system.",
         GlweCiphertextVectorKind
             => "An empty type representing the GLWE ciphertext vector kind in the type system.",
+        LweSeededCiphertextKind
+            => "An empty type representing the seeded LWE ciphertext kind in the type system.",
+        LweSeededCiphertextVectorKind
+            => "An empty type representing the seeded LWE ciphertext vector kind in the type \
+            system.",
+        LweCompressedCiphertextKind
+            => "An empty type representing the compressed LWE ciphertext kind in the type system.",
+        LweCompressedCiphertextVectorKind
+            => "An empty type representing the compressed LWE ciphertext vector kind in the type \
+            system.",
+        GlweSeededCiphertextKind
+            => "An empty type representing the seeded GLWE ciphertext kind in the type system.",
+        GlweSeededCiphertextVectorKind
+            => "An empty type representing the seeded GLWE ciphertext vector kind in the type \
+            system.",
         GgswCiphertextKind
             => "An empty type representing the GGSW ciphertext kind in the type system.",
         GgswCiphertextVectorKind
@@ -54,10 +69,22 @@ entity_kind_marker! {
             => "An empty type representing the LWE secret key kind in the type system.",
         GlweSecretKeyKind
             => "An empty type representing the GLWE secret key kind in the type system.",
+        GlweRelinearizationKeyKind
+            => "An empty type representing the GLWE relinearization key kind in the type system.",
         LweKeyswitchKeyKind
             => "An empty type representing the LWE keyswitch key kind in the type system.",
         LweBootstrapKeyKind
             => "An empty type representing the LWE bootstrap key kind in the type system.",
+        LwePublicKeyKind
+            => "An empty type representing the LWE public key kind in the type system.",
+        PackingKeyswitchKeyKind
+            => "An empty type representing the packing keyswitch key kind in the type system.",
+        PrivateFunctionalPackingKeyswitchKeyKind
+            => "An empty type representing the private functional packing keyswitch key kind in \
+            the type system.",
+        CircuitBootstrapPrivateFunctionalPackingKeyswitchKeysKind
+            => "An empty type representing the circuit bootstrap private functional packing \
+            keyswitch keys kind in the type system.",
         EncoderKind
             => "An empty type representing the encoder kind in the type system.",
         EncoderVectorKind
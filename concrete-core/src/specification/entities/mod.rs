@@ -34,6 +34,7 @@ pub trait AbstractEntity: Debug + PartialEq {
     type Kind: EntityKindMarker;
 }
 
+mod circuit_bootstrap_private_functional_packing_keyswitch_keys;
 mod cleartext;
 mod cleartext_vector;
 mod encoder;
@@ -42,17 +43,28 @@ mod ggsw_ciphertext;
 mod ggsw_ciphertext_vector;
 mod glwe_ciphertext;
 mod glwe_ciphertext_vector;
+mod glwe_relinearization_key;
 mod glwe_secret_key;
+mod glwe_seeded_ciphertext;
+mod glwe_seeded_ciphertext_vector;
 mod gsw_ciphertext;
 mod gsw_ciphertext_vector;
 mod lwe_bootstrap_key;
 mod lwe_ciphertext;
 mod lwe_ciphertext_vector;
+mod lwe_compressed_ciphertext;
+mod lwe_compressed_ciphertext_vector;
 mod lwe_keyswitch_key;
+mod lwe_public_key;
 mod lwe_secret_key;
+mod lwe_seeded_ciphertext;
+mod lwe_seeded_ciphertext_vector;
+mod packing_keyswitch_key;
 mod plaintext;
 mod plaintext_vector;
+mod private_functional_packing_keyswitch_key;
 
+pub use circuit_bootstrap_private_functional_packing_keyswitch_keys::*;
 pub use cleartext::*;
 pub use cleartext_vector::*;
 pub use encoder::*;
@@ -61,13 +73,23 @@ pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_vector::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_vector::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
+pub use glwe_seeded_ciphertext::*;
+pub use glwe_seeded_ciphertext_vector::*;
 pub use gsw_ciphertext::*;
 pub use gsw_ciphertext_vector::*;
 pub use lwe_bootstrap_key::*;
 pub use lwe_ciphertext::*;
 pub use lwe_ciphertext_vector::*;
+pub use lwe_compressed_ciphertext::*;
+pub use lwe_compressed_ciphertext_vector::*;
 pub use lwe_keyswitch_key::*;
+pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_seeded_ciphertext::*;
+pub use lwe_seeded_ciphertext_vector::*;
+pub use packing_keyswitch_key::*;
 pub use plaintext::*;
 pub use plaintext_vector::*;
+pub use private_functional_packing_keyswitch_key::*;
@@ -0,0 +1,42 @@
+use crate::specification::entities::markers::{
+    KeyFlavorMarker, PrivateFunctionalPackingKeyswitchKeyKind,
+};
+use crate::specification::entities::AbstractEntity;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// A trait implemented by types embodying a private functional packing keyswitch key.
+///
+/// A private functional packing keyswitch key allows to pack several LWE ciphertexts into a single
+/// GLWE ciphertext, while applying a secret linear map to the encrypted plaintexts. It is
+/// associated with two [`KeyFlavorMarker`] types:
+///
+/// + The [`InputKeyFlavor`](`PrivateFunctionalPackingKeyswitchKeyEntity::InputKeyFlavor`) type
+///   conveys the flavor of the input LWE secret key.
+/// + The [`OutputKeyFlavor`](`PrivateFunctionalPackingKeyswitchKeyEntity::OutputKeyFlavor`) type
+///   conveys the flavor of the output GLWE secret key.
+pub trait PrivateFunctionalPackingKeyswitchKeyEntity:
+    AbstractEntity<Kind = PrivateFunctionalPackingKeyswitchKeyKind>
+{
+    /// The flavor of key the input ciphertexts are encrypted with.
+    type InputKeyFlavor: KeyFlavorMarker;
+
+    /// The flavor of the key the output ciphertext is encrypted with.
+    type OutputKeyFlavor: KeyFlavorMarker;
+
+    /// Returns the input LWE dimension of the key.
+    fn input_lwe_dimension(&self) -> LweDimension;
+
+    /// Returns the output GLWE dimension of the key.
+    fn output_glwe_dimension(&self) -> GlweDimension;
+
+    /// Returns the output polynomial size of the key.
+    fn output_polynomial_size(&self) -> PolynomialSize;
+
+    /// Returns the number of decomposition levels of the key.
+    fn decomposition_level_count(&self) -> DecompositionLevelCount;
+
+    /// Returns the logarithm of the base used in the key.
+    fn decomposition_base_log(&self) -> DecompositionBaseLog;
+}
@@ -3,7 +3,8 @@
 use concrete_commons::dispersion::{DispersionParameter, Variance};
 use concrete_commons::numeric::{CastInto, UnsignedInteger};
 use concrete_commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
+    LwePublicKeyZeroEncryptionCount, PolynomialSize,
 };
 
 use super::*;
@@ -101,12 +102,42 @@ where
     let mut var_res: f64 = 0.;
 
     for (dispersion, &w) in dispersion_list.iter().zip(weights) {
-        var_res += estimate_integer_plaintext_multiplication_noise(*dispersion, w)
-            .get_modular_variance::<T>();
+        // The variance of the product is already expressed in the integer representation.
+        var_res += estimate_integer_plaintext_multiplication_noise(*dispersion, w).get_variance();
     }
     Variance(var_res)
 }
 
+/// Computes the dispersion of a fresh LWE encryption performed with a public key made of $m$
+/// encryptions of zero, i.e. $\sigma_{out}^2 = \frac{m}{2} \sigma_{pk}^2$.
+///
+/// Each encryption of zero is added to the output with probability one half, so only half of
+/// the public key noises contribute, on average, to the output noise.
+/// # Example
+/// ```rust
+/// use concrete_commons::dispersion::{DispersionParameter, Variance};
+/// use concrete_commons::parameters::LwePublicKeyZeroEncryptionCount;
+/// use concrete_npe::estimate_lwe_public_key_encryption_noise;
+/// let dispersion_public_key = Variance(f64::powi(2., -50));
+/// let zero_encryption_count = LwePublicKeyZeroEncryptionCount(1024);
+/// let var_out = estimate_lwe_public_key_encryption_noise::<u64, _>(
+///     dispersion_public_key,
+///     zero_encryption_count,
+/// );
+/// assert!((f64::powi(2., -41) - var_out.get_variance()).abs() < f64::powi(2., -60));
+/// ```
+pub fn estimate_lwe_public_key_encryption_noise<T, D>(
+    dispersion_public_key: D,
+    zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+) -> Variance
+where
+    T: UnsignedInteger,
+    D: DispersionParameter,
+{
+    let m = zero_encryption_count.0 as f64;
+    Variance::from_modular_variance::<T>(m / 2. * dispersion_public_key.get_modular_variance::<T>())
+}
+
 /// Computes the dispersion of a multiplication
 /// between an RLWE ciphertext and a scalar polynomial.
 /// # Example
@@ -599,7 +630,7 @@ where
     let l = level.0 as f64;
     let k = rlwe_mask_size.0 as f64;
     let big_n = poly_size.0 as f64;
-    let b = (1 << base_log.0) as f64;
+    let b = 2f64.powi(base_log.0 as i32);
     let b2l = f64::powf(b, 2. * l);
 
     let res_1 =
@@ -709,7 +740,7 @@ where
 {
     let n = lwe_mask_size.0 as f64;
     let k = rlwe_mask_size.0 as f64;
-    let b = base_log.0 as f64;
+    let b = 2f64.powi(base_log.0 as i32);
     let l = level.0 as f64;
     let b2l = f64::powf(b, 2. * l) as f64;
     let big_n = poly_size.0 as f64;
@@ -726,3 +757,48 @@ where
         + n / 16. * square(1. - k * big_n * K::expectation_key_coefficient());
     Variance(res_1 + res_2)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use concrete_commons::key_kinds::BinaryKeyKind;
+
+    #[test]
+    fn weighted_sum_noise_does_not_rescale_the_products() {
+        let variances = vec![Variance(f64::powi(2., -30)), Variance(f64::powi(2., -32))];
+        let weights: Vec<u64> = vec![20, 10];
+        let var_out = estimate_weighted_sum_noise(&variances, &weights);
+        // 20^2 * 2^(128-30) + 10^2 * 2^(128-32), the previous estimate was 2^128 times larger.
+        let expected = 1700. * f64::powi(2., 96);
+        assert_eq!(var_out.get_variance(), expected);
+        assert_ne!(var_out.get_variance(), expected * f64::powi(2., 128));
+    }
+
+    #[test]
+    fn pbs_noise_uses_the_decomposition_base() {
+        let var_out = estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+            LweDimension(1),
+            PolynomialSize(1),
+            GlweDimension(1),
+            DecompositionBaseLog(7),
+            DecompositionLevelCount(1),
+            Variance(f64::powi(2., -40)),
+        );
+        // The previous estimate used a base of 7 instead of 2^7 and returned about 4.3403e35.
+        let expected = 1.298_919_418_195_529e33;
+        assert!(((var_out.get_variance() - expected) / expected).abs() < 1e-10);
+    }
+
+    #[test]
+    fn pbs_noise_accepts_large_decomposition_base_logs() {
+        let var_out = estimate_pbs_noise::<u64, _, BinaryKeyKind>(
+            LweDimension(1),
+            PolynomialSize(1),
+            GlweDimension(1),
+            DecompositionBaseLog(32),
+            DecompositionLevelCount(2),
+            Variance(f64::powi(2., -40)),
+        );
+        assert!(var_out.get_variance().is_finite());
+    }
+}
@@ -8,8 +8,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::error::CryptoAPIError;
 use crate::Torus;
-use concrete_core::crypto;
-use concrete_core::math::decomposition::SignedDecomposer;
+use concrete_core::backends::core::private::crypto;
+use concrete_core::backends::core::private::math::decomposition::SignedDecomposer;
 use concrete_npe as npe;
 
 use super::{read_from_file, write_to_file};
@@ -491,7 +491,7 @@ impl Encoder {
     /// let plaintext = encoder.encode_outside_interval_operators(m).unwrap();
     /// ```
     pub fn encode_outside_interval_operators(&self, m: f64) -> Result<Torus, CryptoAPIError> {
-        use concrete_core::crypto::encoding::Encoder as CoreEncoder;
+        use concrete_core::backends::core::private::crypto::encoding::Encoder as CoreEncoder;
 
         // check if the encoder is valid
         if !self.is_valid() {
@@ -544,7 +544,7 @@ impl Encoder {
     /// let new_message = encoder.decode_core(plaintext).unwrap();
     /// ```
     pub fn decode_core(&self, pt: Torus) -> Result<f64, CryptoAPIError> {
-        use concrete_core::crypto::encoding::Encoder as CoreEncoder;
+        use concrete_core::backends::core::private::crypto::encoding::Encoder as CoreEncoder;
 
         // check valid encoder
         if !self.is_valid() {
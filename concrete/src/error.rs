@@ -106,6 +106,11 @@ pub enum CryptoAPIError {
         polynomial_size: usize,
         description: String,
     },
+    ModulusLogError {
+        modulus_log: usize,
+        max_modulus_log: usize,
+        description: String,
+    },
 }
 impl fmt::Display for CryptoAPIError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -154,6 +159,9 @@ impl fmt::Display for CryptoAPIError {
                 writeln!(f, "\n{}", description)
             }
             CryptoAPIError::LweToRlweError { description, .. } => writeln!(f, "\n{}", description),
+            CryptoAPIError::ModulusLogError { description, .. } => {
+                writeln!(f, "\n{}", description)
+            }
         }
     }
 }
@@ -205,6 +213,9 @@ impl fmt::Debug for CryptoAPIError {
                 writeln!(f, "\n{}", description)
             }
             CryptoAPIError::LweToRlweError { description, .. } => writeln!(f, "\n{}", description),
+            CryptoAPIError::ModulusLogError { description, .. } => {
+                writeln!(f, "\n{}", description)
+            }
         }
     }
 }
@@ -234,6 +245,7 @@ impl Error for CryptoAPIError {
             CryptoAPIError::WrongSizeError { description, .. } => description,
             CryptoAPIError::NotEnoughValidEncoderError { description, .. } => description,
             CryptoAPIError::LweToRlweError { description, .. } => description,
+            CryptoAPIError::ModulusLogError { description, .. } => description,
         }
     }
 }
@@ -606,3 +618,20 @@ macro_rules! LweToRlweError {
         }
     };
 }
+
+#[macro_export]
+macro_rules! ModulusLogError {
+    ($modulus_log: expr, $max_modulus_log: expr) => {
+        CryptoAPIError::ModulusLogError {
+            modulus_log: $modulus_log,
+            max_modulus_log: $max_modulus_log,
+            description: format!(
+                "{}: modulus_log (= {}) should be between 1 and {}\n{:#?}\n",
+                "Invalid modulus log".red().bold(),
+                $modulus_log,
+                $max_modulus_log,
+                Backtrace::new()
+            ),
+        }
+    };
+}
@@ -8,18 +8,21 @@ use crate::traits::GenericAdd;
 use crate::{read_from_file, write_to_file, Torus};
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_commons::dispersion::StandardDev;
+use concrete_commons::dispersion::{DispersionParameter, StandardDev, Variance};
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{GlweSize, LweSize, PolynomialSize};
-use concrete_core::crypto::bootstrap::Bootstrap;
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::{
+use concrete_commons::parameters::{
+    CiphertextCount, GlweSize, LweDimension, LweSize, PolynomialSize,
+};
+use concrete_core::backends::core::private::crypto::bootstrap::Bootstrap;
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::{
     crypto::{
         self,
         encoding::{Cleartext, Plaintext},
         glwe::GlweCiphertext,
-        lwe::LweCiphertext,
+        lwe::{LweCiphertext, LweList},
     },
+    math::polynomial::PolynomialList,
     math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor},
 };
 use concrete_npe as npe;
@@ -1876,6 +1879,168 @@ impl LWE {
         Ok(lwe)
     }
 
+    /// Compute several programmable bootstraps of the same input with a single blind rotation
+    /// The outputs are rounded to the precision of the output encoder, and the noise of each of
+    /// them grows with the variations of its function, so the output encoder should have a small precision
+    /// Functions that are not continuous on the input interval need an input encoder in a rounding context
+    ///
+    /// # Argument
+    /// * `bsk` - the bootstrapping key
+    /// * `functions` - the functions to evaluate
+    /// * `encoder_output` - the encoder of the outputs
+    ///
+    /// # Output
+    /// * a vector of LWE structs, one per function
+    /// * DimensionError - if the bootstrapping key and the input ciphertext have incompatible dimensions
+    /// * PrecisionError - if the output encoder has too many bits of padding and precision
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // params
+    /// let (min, max): (f64, f64) = (-8., 7.);
+    /// let precision = 4;
+    /// let padding = 1;
+    /// let level: usize = 8;
+    /// let base_log: usize = 4;
+    ///
+    /// // encoder
+    /// let encoder_input = Encoder::new_rounding_context(min, max, precision, padding).unwrap();
+    /// let encoder_output = Encoder::new(0., 7., 3, padding).unwrap();
+    ///
+    /// // a message
+    /// let message: f64 = -6.;
+    ///
+    /// // generate secret keys
+    /// let rlwe_secret_key = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let secret_key_before = LWESecretKey::new(&LWE128_630);
+    /// let secret_key_after = rlwe_secret_key.to_lwe_secret_key();
+    ///
+    /// // bootstrapping key
+    /// let bootstrapping_key =
+    ///     LWEBSK::new(&secret_key_before, &rlwe_secret_key, base_log, level);
+    ///
+    /// // encode and encrypt
+    /// let ciphertext_before =
+    ///     LWE::encode_encrypt(&secret_key_before, message, &encoder_input).unwrap();
+    ///
+    /// // an activation and a sign
+    /// let functions: [fn(f64) -> f64; 2] = [|x| f64::max(0., x), |x| (x >= 0.) as u8 as f64];
+    /// let ciphertexts_out = ciphertext_before
+    ///     .bootstrap_with_functions(&bootstrapping_key, &functions, &encoder_output)
+    ///     .unwrap();
+    /// ```
+    pub fn bootstrap_with_functions<F: Fn(f64) -> f64>(
+        &self,
+        bsk: &crate::LWEBSK,
+        functions: &[F],
+        encoder_output: &crate::Encoder,
+    ) -> Result<Vec<crate::LWE>, CryptoAPIError> {
+        // check bsk compatibility
+        if self.dimension != bsk.get_lwe_dimension() {
+            return Err(DimensionError!(self.dimension, bsk.get_lwe_dimension()));
+        }
+
+        // generate the look up tables (throw error if a bit of padding is missing)
+        let (tables, delta) =
+            bsk.generate_multi_value_look_up_tables(&self.encoder, encoder_output, functions)?;
+        let luts =
+            PolynomialList::from_container(tables.as_slice(), PolynomialSize(bsk.polynomial_size));
+
+        // allocate the result
+        let mut result = LweList::allocate(
+            0,
+            LweSize(bsk.dimension * bsk.polynomial_size + 1),
+            CiphertextCount(functions.len()),
+        );
+
+        if self.encoder.nb_bit_padding > 1 {
+            // remove the padding but one bit
+            let mut self_clone = self.clone();
+            self_clone.remove_padding_inplace(self.encoder.nb_bit_padding - 1)?;
+
+            // compute the bootstrap
+            bsk.ciphertexts.multi_value_bootstrap(
+                &mut result,
+                &self_clone.ciphertext,
+                &luts,
+                delta,
+            );
+        } else {
+            // compute the bootstrap
+            bsk.ciphertexts
+                .multi_value_bootstrap(&mut result, &self.ciphertext, &luts, delta);
+        }
+
+        // compute the variance of a bootstrap (without the drift)
+        let bootstrap_var: f64 = <Torus as npe::cross::Cross>::bootstrap(
+            self.dimension,
+            bsk.dimension,
+            bsk.level,
+            bsk.base_log,
+            bsk.polynomial_size,
+            bsk.variance,
+        );
+
+        // calls the NPE to find out the amount of noise after rounding the input ciphertext (drift)
+        let nb_rounding_noise_bit: usize =
+            (npe::lwe::log2_rounding_noise(self.dimension)).ceil() as usize + 1;
+
+        let mut lwes: Vec<crate::LWE> = Vec::with_capacity(functions.len());
+        for (ct, table) in result
+            .ciphertext_iter()
+            .zip(tables.chunks(bsk.polynomial_size))
+        {
+            // compute the new variance (without the drift)
+            let new_var = crate::LWEBSK::get_multi_value_variance(bootstrap_var, table);
+
+            // create the output encoder
+            let mut new_encoder_output: crate::Encoder = encoder_output.clone();
+
+            // update the precision in case of the output noise (without drift) is too big and overlap the message
+            let nb_bit_overlap: usize =
+                new_encoder_output.update_precision_from_variance(new_var)?;
+            if nb_bit_overlap > 0 {
+                println!(
+                    "{}: {} bit(s) of precision lost over {} bit(s) of message originally. Consider increasing the number of level and/or decreasing the log base.",
+                    "Loss of precision during bootstrap".red().bold(),
+                    nb_bit_overlap, self.encoder.nb_bit_precision
+                );
+            }
+
+            // deals with the drift error
+            if nb_rounding_noise_bit + 1 + new_encoder_output.nb_bit_precision
+                > bsk.get_polynomial_size_log() + 1
+            {
+                let nb_bit_loss = 1 + new_encoder_output.nb_bit_precision + nb_rounding_noise_bit
+                    - bsk.get_polynomial_size_log()
+                    - 1;
+
+                new_encoder_output.nb_bit_precision = i32::max(
+                    new_encoder_output.nb_bit_precision as i32 - nb_bit_loss as i32,
+                    0i32,
+                ) as usize;
+                // drift
+                println!(
+                    "{}: {} bit(s) of precision lost over {} bit(s) of message originally ({} bits are affected by the noise). Consider increasing the polynomial size of the RLWE secret key.",
+                    "Loss of precision during bootstrap due to the rounding".red().bold(),
+                    nb_bit_loss, self.encoder.nb_bit_precision,nb_rounding_noise_bit
+                );
+            }
+
+            // construct the output
+            lwes.push(crate::LWE {
+                variance: new_var,
+                ciphertext: LweCiphertext::from_container(ct.as_tensor().as_slice().to_vec()),
+                dimension: bsk.polynomial_size * bsk.dimension,
+                encoder: new_encoder_output,
+            });
+        }
+
+        Ok(lwes)
+    }
+
     /// Multiply two LWE ciphertexts thanks to two bootstrapping procedures
     /// need to have 2 bits of padding at least
     ///
@@ -2036,6 +2201,81 @@ impl LWE {
 
         Ok(())
     }
+
+    /// Switch the ciphertext to a smaller power of two modulus, so that it takes less space to
+    /// store or to send
+    /// The rounding adds some noise, so the precision of the output encoder may be reduced
+    ///
+    /// # Arguments
+    /// * `modulus_log` - the log2 of the new modulus, at most 32
+    ///
+    /// # Output
+    /// * a compressed LWE
+    /// * ModulusLogError - if the modulus log is zero or does not fit in 32 bits
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // encoder
+    /// let encoder = Encoder::new(-2., 6., 4, 4).unwrap();
+    ///
+    /// // generate a secret key
+    /// let secret_key = LWESecretKey::new(&LWE128_1024);
+    ///
+    /// // encode and encrypt
+    /// let ciphertext = LWE::encode_encrypt(&secret_key, -1., &encoder).unwrap();
+    ///
+    /// // compress the ciphertext to 32 bits coefficients, and lift it back to the torus
+    /// let compressed = ciphertext.compress(32).unwrap();
+    /// let decompressed = LWE::decompress(&compressed);
+    ///
+    /// // the decryption is unchanged
+    /// let decryption = decompressed.decrypt_decode_round(&secret_key).unwrap();
+    /// assert_eq!(
+    ///     decryption,
+    ///     ciphertext.decrypt_decode_round(&secret_key).unwrap()
+    /// );
+    /// ```
+    pub fn compress(&self, modulus_log: usize) -> Result<crate::LWECompressed, CryptoAPIError> {
+        // allocate the compressed ciphertext and switch the modulus
+        let mut res = crate::LWECompressed::zero(self.dimension, modulus_log)?;
+        res.ciphertext.fill_with_modulus_switch(&self.ciphertext);
+
+        // call to the NPE to estimate the new variance, which it expresses in the units of the
+        // smaller modulus
+        res.variance = npe_operators::estimate_modulus_switching_noise_with_binary_key::<Torus, _>(
+            LweDimension(self.dimension),
+            modulus_log,
+            Variance(self.variance),
+        )
+        .get_variance()
+            / f64::powi(2., 2 * modulus_log as i32);
+
+        // update the encoder precision based on the variance
+        res.encoder = self.encoder.clone();
+        res.encoder.update_precision_from_variance(res.variance)?;
+
+        Ok(res)
+    }
+
+    /// Lift a compressed LWE back to the torus, so that it can be operated on
+    ///
+    /// # Arguments
+    /// * `ct` - a compressed LWE
+    ///
+    /// # Output
+    /// * a new LWE
+    pub fn decompress(ct: &crate::LWECompressed) -> LWE {
+        let mut res = LWE {
+            ciphertext: crypto::lwe::LweCiphertext::allocate(0, LweSize(ct.dimension + 1)),
+            variance: ct.variance,
+            dimension: ct.dimension,
+            encoder: ct.encoder.clone(),
+        };
+        ct.ciphertext.lift_into(&mut res.ciphertext);
+        res
+    }
 }
 
 /// Print needed pieces of information about an LWE
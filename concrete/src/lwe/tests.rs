@@ -518,6 +518,40 @@ fn test_encode_encrypt_x_keyswitch_x_decrypt() {
     }
 }
 
+#[test]
+fn test_encode_encrypt_x_compress_x_decompress_x_decrypt() {
+    // random settings
+    let (min, max) = generate_random_interval!();
+    let (precision, padding) = generate_precision_padding!(8, 8);
+    let modulus_log: usize = random_index!(8) + 25;
+
+    // encoder
+    let encoder = crate::Encoder::new(min, max, precision, padding).unwrap();
+
+    // generate a secret key
+    let secret_key = crate::LWESecretKey::new(&crate::LWE128_1024);
+
+    for _ in 0..100 {
+        // a message
+        let message: f64 = random_message!(min, max);
+
+        // encode and encrypt
+        let ciphertext = crate::LWE::encode_encrypt(&secret_key, message, &encoder).unwrap();
+
+        // compression and decompression
+        let compressed = ciphertext.compress(modulus_log).unwrap();
+        let decompressed = crate::LWE::decompress(&compressed);
+
+        // decryption
+        let decryption: f64 = decompressed.decrypt_decode_round(&secret_key).unwrap();
+
+        // test
+        assert_eq_granularity!(message, decryption, decompressed.encoder);
+        assert_eq!(compressed.get_modulus_log(), modulus_log);
+        assert_eq!(precision, decompressed.encoder.nb_bit_precision);
+    }
+}
+
 #[test]
 fn test_encode_encrypt_x_bootstrap_x_decrypt() {
     // random settings
@@ -558,6 +592,55 @@ fn test_encode_encrypt_x_bootstrap_x_decrypt() {
     }
 }
 
+#[test]
+fn test_encode_encrypt_x_bootstrap_with_functions_x_decrypt() {
+    // settings
+    let (min, max) = (-8., 7.);
+    let padding: usize = 1;
+    let precision: usize = 4;
+    let base_log: usize = 4;
+    let level: usize = 8;
+
+    // encoders
+    let encoder_input = crate::Encoder::new_rounding_context(min, max, precision, padding).unwrap();
+    let encoder_output = crate::Encoder::new(0., 7., 3, padding).unwrap();
+
+    // secret keys
+    let rlwe_secret_key = crate::RLWESecretKey::new(&crate::RLWE128_1024_1);
+    let secret_key_input = crate::LWESecretKey::new(&crate::LWE128_630);
+    let secret_key_output = rlwe_secret_key.to_lwe_secret_key();
+
+    // bootstrapping key
+    let bootstrapping_key =
+        crate::LWEBSK::new(&secret_key_input, &rlwe_secret_key, base_log, level);
+
+    // an activation and a sign
+    let functions: [fn(f64) -> f64; 2] = [|x| f64::max(0., x), |x| (x >= 0.) as u8 as f64];
+
+    for _ in 0..20 {
+        // a random message
+        let message: f64 = random_index!(16) as f64 + min;
+
+        // encode and encrypt
+        let ciphertext_input =
+            crate::LWE::encode_encrypt(&secret_key_input, message, &encoder_input).unwrap();
+
+        // bootstrap
+        let ciphertexts_output = ciphertext_input
+            .bootstrap_with_functions(&bootstrapping_key, &functions, &encoder_output)
+            .unwrap();
+        assert_eq!(ciphertexts_output.len(), functions.len());
+
+        // decrypt
+        for (ciphertext_output, f) in ciphertexts_output.iter().zip(functions.iter()) {
+            let decryption = ciphertext_output
+                .decrypt_decode_round(&secret_key_output)
+                .unwrap();
+            assert_eq!(f(message), decryption);
+        }
+    }
+}
+
 #[test]
 fn test_encode_encrypt_x_mul_from_bootstrap_x_decrypt() {
     // random settings for the first encoder and some messages
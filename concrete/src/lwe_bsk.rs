@@ -4,15 +4,18 @@ use colored::Colorize;
 use concrete_commons::dispersion::StandardDev;
 use concrete_commons::numeric::Numeric;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize};
-use concrete_core::{
+use concrete_core::backends::core::private::{
     math::tensor::{AsMutTensor, AsRefTensor},
     math::{fft::Complex64, tensor::Tensor},
 };
-use concrete_core::crypto::bootstrap::{FourierBootstrapKey,StandardBootstrapKey};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::math::fft::AlignedVec;
+use concrete_core::backends::core::private::crypto::bootstrap::{
+    FourierBootstrapKey, StandardBootstrapKey,
+};
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::math::fft::AlignedVec;
 use crate::error::CryptoAPIError;
 use crate::Torus;
+use concrete_npe as npe;
 
 use serde::{Serialize, Deserialize};
 
@@ -98,6 +101,73 @@ impl LWEBSK {
         Ok(result)
     }
 
+    /// Build the lookup tables of several functions from two encoders, to be evaluated with a
+    /// single multi-value bootstrap
+    /// The outputs are rounded to the precision of the output encoder, and stored as integers
+    /// counting the steps of size delta = 2^(64 - nb_bit_padding - nb_bit_precision) of its encoding
+    ///
+    /// # Argument
+    /// * `encoder_input` - the encoder of the input (of the bootstrap)
+    /// * `encoder_output` - the encoder of the outputs (of the bootstrap)
+    /// * `functions` - a slice of functions
+    ///
+    /// # Output
+    /// * a vector of Torus containing the concatenation of the lookup tables
+    /// * the step delta of the encoding of the outputs
+    pub fn generate_multi_value_look_up_tables<F: Fn(f64) -> f64>(
+        &self,
+        encoder_input: &crate::Encoder,
+        encoder_output: &crate::Encoder,
+        functions: &[F],
+    ) -> Result<(Vec<Torus>, Torus), CryptoAPIError> {
+        // check that delta is even
+        if encoder_output.nb_bit_padding + encoder_output.nb_bit_precision
+            >= <Torus as Numeric>::BITS
+        {
+            return Err(PrecisionError!());
+        }
+        let log_delta: usize = <Torus as Numeric>::BITS
+            - encoder_output.nb_bit_padding
+            - encoder_output.nb_bit_precision;
+
+        let mut result: Vec<Torus> = Vec::with_capacity(functions.len() * self.polynomial_size);
+        for f in functions.iter() {
+            let lut = self.generate_functional_look_up_table(encoder_input, encoder_output, f)?;
+
+            // round the (possibly negated) encodings to a signed number of steps
+            result.extend(lut.iter().map(|encoded| {
+                (encoded.wrapping_add(1 << (log_delta - 1)) as i64 >> log_delta) as Torus
+            }));
+        }
+        Ok((result, 1 << log_delta))
+    }
+
+    /// Return the variance of an output of a multi-value bootstrap
+    /// The noise of the blind rotation is multiplied by the polynomial (1 - X) * T(X), where T is
+    /// the lookup table of the output
+    ///
+    /// # Argument
+    /// * `variance` - the variance of a bootstrap with the same key
+    /// * `table` - the lookup table of the output, as output by generate_multi_value_look_up_tables
+    ///
+    /// # Output
+    /// * the variance of the output
+    pub fn get_multi_value_variance(variance: f64, table: &[Torus]) -> f64 {
+        let factor: Vec<Torus> = table
+            .iter()
+            .enumerate()
+            .map(|(j, coefficient)| {
+                let previous = if j == 0 {
+                    table[table.len() - 1].wrapping_neg()
+                } else {
+                    table[j - 1]
+                };
+                coefficient.wrapping_sub(previous)
+            })
+            .collect();
+        <Torus as npe::RLWE>::scalar_polynomial_mult(variance, &factor)
+    }
+
     /// Build a lookup table for the identity function from two encoders
     ///
     /// # Argument
@@ -0,0 +1,150 @@
+use super::{read_from_file, write_to_file};
+use crate::Torus;
+use concrete_commons::dispersion::StandardDev;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount,
+    GlweDimension, LweDimension, PolynomialSize,
+};
+use concrete_core::backends::core::private::crypto::glwe::PrivateFunctionalPackingKeyswitchKeyList;
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// Structure containing the private functional packing keyswitch keys used by a circuit
+/// bootstrap to build GGSW ciphertexts out of the output of a bootstrap.
+///
+/// # Attributes
+/// * `ciphertexts` - the list of private functional packing keyswitch keys, one per row of a GGSW level matrix
+/// * `variance` - the variance of the noise of the keys
+/// * `dimension` - the size of the RLWE mask
+/// * `polynomial_size` - the number of coefficients in a polynomial
+/// * `base_log` - the log2 of the decomposition base
+/// * `level` - the number of levels of the decomposition
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct LWECBSK {
+    pub ciphertexts: PrivateFunctionalPackingKeyswitchKeyList<Vec<Torus>>,
+    pub variance: f64,
+    pub dimension: usize,
+    pub polynomial_size: usize,
+    pub base_log: usize,
+    pub level: usize,
+}
+
+impl LWECBSK {
+    /// Return the dimension of an LWE we can keyswitch with this key, i.e. the dimension of
+    /// the output of a bootstrap with a key built from the same RLWE secret key
+    pub fn get_lwe_dimension(&self) -> usize {
+        self.dimension * self.polynomial_size
+    }
+
+    /// Generate an empty circuit bootstrapping key
+    ///
+    /// # Argument
+    /// * `sk` - an RLWE secret key (output of the bootstrap and of the circuit bootstrap)
+    /// * `base_log` - the log2 of the decomposition base
+    /// * `level` - the number of levels of the decomposition
+    ///
+    /// # Output
+    /// * an LWECBSK
+    pub fn zero(sk: &crate::RLWESecretKey, base_log: usize, level: usize) -> LWECBSK {
+        let ciphertexts = PrivateFunctionalPackingKeyswitchKeyList::allocate(
+            0_u64,
+            DecompositionLevelCount(level),
+            DecompositionBaseLog(base_log),
+            LweDimension(sk.dimension * sk.polynomial_size),
+            GlweDimension(sk.dimension),
+            PolynomialSize(sk.polynomial_size),
+            FunctionalPackingKeyswitchKeyCount(sk.dimension + 1),
+        );
+
+        LWECBSK {
+            ciphertexts,
+            variance: f64::powi(sk.std_dev, 2),
+            dimension: sk.dimension,
+            polynomial_size: sk.polynomial_size,
+            base_log,
+            level,
+        }
+    }
+
+    /// Generate a valid circuit bootstrapping key
+    ///
+    /// The keys switch from the LWE secret key obtained by flattening `sk` back to `sk`, so
+    /// that they can be used together with a bootstrapping key whose output key is `sk`.
+    ///
+    /// # Argument
+    /// * `sk` - an RLWE secret key (output of the bootstrap and of the circuit bootstrap)
+    /// * `base_log` - the log2 of the decomposition base
+    /// * `level` - the number of levels of the decomposition
+    ///
+    /// # Output
+    /// * an LWECBSK
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// let rlwe_secret_key = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let cbsk = LWECBSK::new(&rlwe_secret_key, 10, 2);
+    /// ```
+    pub fn new(sk: &crate::RLWESecretKey, base_log: usize, level: usize) -> LWECBSK {
+        let mut res = LWECBSK::zero(sk, base_log, level);
+        res.ciphertexts.fill_with_circuit_bootstrap_keys(
+            &sk.to_lwe_secret_key().val,
+            &sk.val,
+            StandardDev::from_standard_dev(sk.std_dev),
+            &mut EncryptionRandomGenerator::new(None),
+        );
+        res
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        write_to_file(path, self)
+    }
+
+    pub fn load(path: &str) -> Result<LWECBSK, Box<dyn Error>> {
+        read_from_file(path)
+    }
+}
+
+/// Print needed pieces of information about an LWECBSK
+impl fmt::Display for LWECBSK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = 2;
+        let mut to_be_print: String = "".to_string();
+
+        to_be_print += " LWECBSK {\n         -> samples = [";
+
+        if self.ciphertexts.as_tensor().len() <= 2 * n {
+            for elt in self.ciphertexts.as_tensor().iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        } else {
+            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+            to_be_print += "...";
+
+            for elt in self
+                .ciphertexts
+                .as_tensor()
+                .get_sub(self.ciphertexts.as_tensor().len() - n..)
+                .iter()
+            {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        }
+        to_be_print += "]\n";
+
+        to_be_print = to_be_print + &format!("         -> variance = {}\n", self.variance);
+        to_be_print = to_be_print + &format!("         -> dimension = {}\n", self.dimension);
+        to_be_print =
+            to_be_print + &format!("         -> polynomial_size = {}\n", self.polynomial_size);
+        to_be_print = to_be_print + &format!("         -> base_log = {}\n", self.base_log);
+        to_be_print = to_be_print + &format!("         -> level = {}\n", self.level);
+        to_be_print += "       }";
+        writeln!(f, "{}", to_be_print)
+    }
+}
@@ -0,0 +1,116 @@
+//! compressed lwe ciphertext module
+
+use crate::error::CryptoAPIError;
+use crate::{read_from_file, write_to_file};
+use backtrace::Backtrace;
+use colored::Colorize;
+use concrete_commons::numeric::Numeric;
+use concrete_commons::parameters::{CiphertextModulusLog, LweSize};
+use concrete_core::backends::core::private::crypto::lwe::LweCompressedCiphertext;
+use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// Structure containing a single LWE ciphertext switched to a smaller power of two modulus,
+/// whose coefficients are stored on 32 bits.
+///
+/// # Attributes
+/// * `ciphertext` - the compressed LWE ciphertext
+/// * `variance` - the variance of the noise of the LWE ciphertext, including the rounding noise
+/// * `dimension` - the length the LWE mask
+/// * `encoder` - the encoder of the LWE ciphertext
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LWECompressed {
+    pub ciphertext: LweCompressedCiphertext<Vec<u32>>,
+    pub variance: f64,
+    pub dimension: usize,
+    pub encoder: crate::Encoder,
+}
+
+impl LWECompressed {
+    /// Instantiate a new compressed LWE filled with zeros from a dimension and a modulus log
+    ///
+    /// # Arguments
+    /// * `dimension` - the length the LWE mask
+    /// * `modulus_log` - the log2 of the modulus of the coefficients, at most 32
+    ///
+    /// # Output
+    /// * a new instantiation of a compressed LWE
+    /// * ModulusLogError - if the modulus log is zero or does not fit in 32 bits
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // creates a compressed LWE ciphertext with a dimension of 630 and a modulus of 2^32
+    /// let empty_ciphertext = LWECompressed::zero(630, 32).unwrap();
+    /// ```
+    pub fn zero(dimension: usize, modulus_log: usize) -> Result<LWECompressed, CryptoAPIError> {
+        let max_modulus_log = <u32 as Numeric>::BITS;
+        if modulus_log == 0 || modulus_log > max_modulus_log {
+            return Err(ModulusLogError!(modulus_log, max_modulus_log));
+        }
+        Ok(LWECompressed {
+            ciphertext: LweCompressedCiphertext::allocate(
+                0,
+                LweSize(dimension + 1),
+                CiphertextModulusLog(modulus_log),
+            ),
+            variance: 0.,
+            dimension,
+            encoder: crate::Encoder::zero(),
+        })
+    }
+
+    /// Return the log2 of the modulus of the coefficients
+    pub fn get_modulus_log(&self) -> usize {
+        self.ciphertext.modulus_log().0
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        write_to_file(path, self)
+    }
+
+    pub fn load(path: &str) -> Result<LWECompressed, Box<dyn Error>> {
+        read_from_file(path)
+    }
+}
+
+/// Print needed pieces of information about a compressed LWE
+impl fmt::Display for LWECompressed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = 2;
+        let mut to_be_print: String = "".to_string();
+
+        to_be_print += " LWECompressed {\n         -> samples = [";
+
+        if self.ciphertext.as_tensor().len() <= 2 * n {
+            for elt in self.ciphertext.as_tensor().iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        } else {
+            for elt in self.ciphertext.as_tensor().get_sub(0..n).iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+            to_be_print += "...";
+
+            for elt in self
+                .ciphertext
+                .as_tensor()
+                .get_sub(self.ciphertext.as_tensor().len() - n..)
+                .iter()
+            {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        }
+        to_be_print += "]\n";
+
+        to_be_print += &format!("         -> variance = {}\n", self.variance);
+        to_be_print = to_be_print + &format!("         -> dimension = {}\n", self.dimension);
+        to_be_print =
+            to_be_print + &format!("         -> modulus_log = {}\n", self.get_modulus_log());
+        to_be_print += "       }";
+        writeln!(f, "{}", to_be_print)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::Torus;
-use concrete_core::{
+use concrete_core::backends::core::private::{
     crypto,
     math::tensor::Tensor,
     math::tensor::{AsMutTensor, AsRefTensor},
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use concrete_commons::dispersion::StandardDev;
 use concrete_commons::parameters::{DecompositionBaseLog, DecompositionLevelCount, LweDimension};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct LWEKSK {
@@ -2,7 +2,7 @@ use super::{read_from_file, write_to_file};
 use crate::error::CryptoAPIError;
 use backtrace::Backtrace;
 use colored::Colorize;
-use concrete_core::{
+use concrete_core::backends::core::private::{
     crypto::secret::{GlweSecretKey, LweSecretKey},
     math::tensor::IntoTensor,
 };
@@ -11,7 +11,7 @@ use std::error::Error;
 use std::fmt;
 use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::parameters::{LweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::SecretRandomGenerator;
+use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct LWESecretKey {
@@ -0,0 +1,137 @@
+use super::{read_from_file, write_to_file};
+use crate::Torus;
+use concrete_commons::dispersion::StandardDev;
+use concrete_commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, PolynomialSize,
+};
+use concrete_core::backends::core::private::crypto::glwe::GlweRelinearizationKey;
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::math::tensor::AsRefTensor;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+
+/// Structure containing a relinearization key, used to bring the tensor product of two RLWE
+/// ciphertexts back to an RLWE ciphertext encrypted under the original secret key.
+///
+/// # Attributes
+/// * `ciphertexts` - the RLWE encryptions of the products of the secret key polynomials
+/// * `variance` - the variance of the noise of the key
+/// * `dimension` - the size of the RLWE mask
+/// * `polynomial_size` - the number of coefficients in a polynomial
+/// * `base_log` - the log2 of the decomposition base
+/// * `level` - the number of levels of the decomposition
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct RLWERLK {
+    pub ciphertexts: GlweRelinearizationKey<Vec<Torus>>,
+    pub variance: f64,
+    pub dimension: usize,
+    pub polynomial_size: usize,
+    pub base_log: usize,
+    pub level: usize,
+}
+
+impl RLWERLK {
+    /// Generate an empty relinearization key
+    ///
+    /// # Argument
+    /// * `sk` - an RLWE secret key
+    /// * `base_log` - the log2 of the decomposition base
+    /// * `level` - the number of levels of the decomposition
+    ///
+    /// # Output
+    /// * an RLWERLK
+    pub fn zero(sk: &crate::RLWESecretKey, base_log: usize, level: usize) -> RLWERLK {
+        let ciphertexts = GlweRelinearizationKey::allocate(
+            0_u64,
+            GlweDimension(sk.dimension),
+            PolynomialSize(sk.polynomial_size),
+            DecompositionLevelCount(level),
+            DecompositionBaseLog(base_log),
+        );
+
+        RLWERLK {
+            ciphertexts,
+            variance: f64::powi(sk.std_dev, 2),
+            dimension: sk.dimension,
+            polynomial_size: sk.polynomial_size,
+            base_log,
+            level,
+        }
+    }
+
+    /// Generate a valid relinearization key
+    ///
+    /// # Argument
+    /// * `sk` - an RLWE secret key
+    /// * `base_log` - the log2 of the decomposition base
+    /// * `level` - the number of levels of the decomposition
+    ///
+    /// # Output
+    /// * an RLWERLK
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// let rlwe_secret_key = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let rlk = RLWERLK::new(&rlwe_secret_key, 10, 4);
+    /// ```
+    pub fn new(sk: &crate::RLWESecretKey, base_log: usize, level: usize) -> RLWERLK {
+        let mut res = RLWERLK::zero(sk, base_log, level);
+        res.ciphertexts.fill_with_relinearization_key(
+            &sk.val,
+            StandardDev::from_standard_dev(sk.std_dev),
+            &mut EncryptionRandomGenerator::new(None),
+        );
+        res
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        write_to_file(path, self)
+    }
+
+    pub fn load(path: &str) -> Result<RLWERLK, Box<dyn Error>> {
+        read_from_file(path)
+    }
+}
+
+/// Print needed pieces of information about an RLWERLK
+impl fmt::Display for RLWERLK {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = 2;
+        let mut to_be_print: String = "".to_string();
+
+        to_be_print += " RLWERLK {\n         -> samples = [";
+
+        if self.ciphertexts.as_tensor().len() <= 2 * n {
+            for elt in self.ciphertexts.as_tensor().iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        } else {
+            for elt in self.ciphertexts.as_tensor().get_sub(0..n).iter() {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+            to_be_print += "...";
+
+            for elt in self
+                .ciphertexts
+                .as_tensor()
+                .get_sub(self.ciphertexts.as_tensor().len() - n..)
+                .iter()
+            {
+                to_be_print = to_be_print + &format!("{}, ", *elt);
+            }
+        }
+        to_be_print += "]\n";
+
+        to_be_print = to_be_print + &format!("         -> variance = {}\n", self.variance);
+        to_be_print = to_be_print + &format!("         -> dimension = {}\n", self.dimension);
+        to_be_print =
+            to_be_print + &format!("         -> polynomial_size = {}\n", self.polynomial_size);
+        to_be_print = to_be_print + &format!("         -> base_log = {}\n", self.base_log);
+        to_be_print = to_be_print + &format!("         -> level = {}\n", self.level);
+        to_be_print += "       }";
+        writeln!(f, "{}", to_be_print)
+    }
+}
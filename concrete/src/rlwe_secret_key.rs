@@ -1,5 +1,5 @@
 use super::{read_from_file, write_to_file};
-use concrete_core::{
+use concrete_core::backends::core::private::{
     crypto::secret::{GlweSecretKey, LweSecretKey},
     math::tensor::IntoTensor,
 };
@@ -8,7 +8,7 @@ use std::error::Error;
 use std::fmt;
 use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::parameters::{GlweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::SecretRandomGenerator;
+use concrete_core::backends::core::private::crypto::secret::generators::SecretRandomGenerator;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
 pub struct RLWESecretKey {
@@ -4,9 +4,9 @@ use std::path::Path;
 use concrete_commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
 };
-use concrete_core::crypto::bootstrap::FourierBootstrapKey;
-use concrete_core::crypto::lwe::LweKeyswitchKey;
-use concrete_core::math::fft::Complex64;
+use concrete_core::backends::core::private::crypto::bootstrap::FourierBootstrapKey;
+use concrete_core::backends::core::private::crypto::lwe::LweKeyswitchKey;
+use concrete_core::backends::core::private::math::fft::Complex64;
 
 use crate::{
     Encoder, LWEParams, LWESecretKey, RLWEParams, RLWESecretKey, VectorLWE, LWEBSK, LWEKSK,
@@ -9,19 +9,25 @@ use backtrace::Backtrace;
 use colored::Colorize;
 use concrete_commons::dispersion::StandardDev;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{CiphertextCount, GlweSize, LweSize, PolynomialSize};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
-use concrete_core::{
+use concrete_commons::parameters::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweSize,
+    PolynomialSize,
+};
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_core::backends::core::private::{
     crypto::{
         encoding::PlaintextList,
+        ggsw::{FourierGgswCiphertext, GgswCiphertext},
         glwe::GlweCiphertext,
         lwe::{LweCiphertext, LweList},
     },
+    math::fft::Complex64,
+    math::polynomial::PolynomialList,
     math::tensor::Tensor,
     math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor, IntoTensor},
 };
 
-use concrete_core::crypto::bootstrap::Bootstrap;
+use concrete_core::backends::core::private::crypto::bootstrap::Bootstrap;
 
 use concrete_npe as npe;
 use concrete_npe::{ LWE};
@@ -2164,6 +2170,185 @@ impl VectorLWE {
         Ok(lwe)
     }
 
+    /// Compute several programmable bootstraps of the n-th LWE ciphertext of the structure with
+    /// a single blind rotation
+    /// The outputs are rounded to the precision of the output encoder, and the noise of each of
+    /// them grows with the variations of its function, so the output encoder should have a small precision
+    /// Functions that are not continuous on the input interval need an input encoder in a rounding context
+    ///
+    /// # Argument
+    /// * `bsk` - the bootstrapping key
+    /// * `functions` - the functions to evaluate
+    /// * `encoder_output` - the encoder of the outputs
+    /// * `n` - the index of the ciphertext to bootstrap
+    ///
+    /// # Output
+    /// * a VectorLWE struct containing one ciphertext per function
+    /// * IndexError - if the requested ciphertext does not exist
+    /// * DimensionError - if the bootstrapping key and the input ciphertext have incompatible dimensions
+    /// * PrecisionError - if the output encoder has too many bits of padding and precision
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // params
+    /// let (min, max): (f64, f64) = (-8., 7.);
+    /// let precision = 4;
+    /// let padding = 1;
+    /// let level: usize = 8;
+    /// let base_log: usize = 4;
+    ///
+    /// // encoder
+    /// let encoder_input = Encoder::new_rounding_context(min, max, precision, padding).unwrap();
+    /// let encoder_output = Encoder::new(0., 7., 3, padding).unwrap();
+    ///
+    /// // a list of messages
+    /// let messages: Vec<f64> = vec![-6., 5., -1.];
+    ///
+    /// // generate secret keys
+    /// let rlwe_secret_key = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let secret_key_before = LWESecretKey::new(&LWE128_630);
+    /// let secret_key_after = rlwe_secret_key.to_lwe_secret_key();
+    ///
+    /// // bootstrapping key
+    /// let bootstrapping_key =
+    ///     LWEBSK::new(&secret_key_before, &rlwe_secret_key, base_log, level);
+    ///
+    /// // a list of messages that we encrypt
+    /// let ciphertext_before =
+    ///     VectorLWE::encode_encrypt(&secret_key_before, &messages, &encoder_input).unwrap();
+    ///
+    /// // an activation and a sign
+    /// let functions: [fn(f64) -> f64; 2] = [|x| f64::max(0., x), |x| (x >= 0.) as u8 as f64];
+    /// let ciphertext_out = ciphertext_before
+    ///     .bootstrap_nth_with_functions(&bootstrapping_key, &functions, &encoder_output, 1)
+    ///     .unwrap();
+    /// ```
+    pub fn bootstrap_nth_with_functions<F: Fn(f64) -> f64>(
+        &self,
+        bsk: &crate::LWEBSK,
+        functions: &[F],
+        encoder_output: &crate::Encoder,
+        n: usize,
+    ) -> Result<crate::VectorLWE, CryptoAPIError> {
+        // check the index n
+        if n >= self.nb_ciphertexts {
+            return Err(IndexError!(self.nb_ciphertexts, n));
+        }
+        // check bsk compatibility
+        if self.dimension != bsk.get_lwe_dimension() {
+            return Err(DimensionError!(self.dimension, bsk.get_lwe_dimension()));
+        }
+
+        // generate the look up tables (throw error if a bit of padding is missing)
+        let (tables, delta) =
+            bsk.generate_multi_value_look_up_tables(&self.encoders[n], encoder_output, functions)?;
+        let luts =
+            PolynomialList::from_container(tables.as_slice(), PolynomialSize(bsk.polynomial_size));
+
+        // allocate the result
+        let mut result = LweList::allocate(
+            0,
+            LweSize(bsk.dimension * bsk.polynomial_size + 1),
+            CiphertextCount(functions.len()),
+        );
+
+        // copy the ciphertext to bootstrap
+        let mut ct_clone = self
+            .ciphertexts
+            .as_tensor()
+            .get_sub(n * (self.get_ciphertext_size())..((n + 1) * (self.get_ciphertext_size())))
+            .iter()
+            .copied()
+            .collect::<Vec<Torus>>();
+
+        // shift of some bits to the left to keep a single bit of padding
+        Tensor::from_container(ct_clone.as_mut_slice())
+            .update_with_scalar_shl(&(self.encoders[n].nb_bit_padding - 1));
+
+        // compute the bootstrap
+        bsk.ciphertexts.multi_value_bootstrap(
+            &mut result,
+            &LweCiphertext::from_container(ct_clone),
+            &luts,
+            delta,
+        );
+
+        // compute the variance of a bootstrap (without the drift)
+        let bootstrap_var = <Torus as npe::Cross>::bootstrap(
+            self.dimension,
+            bsk.dimension,
+            bsk.level,
+            bsk.base_log,
+            bsk.polynomial_size,
+            bsk.variance,
+        );
+
+        // calls the NPE to find out the amount of noise after rounding the input ciphertext (drift)
+        let nb_rounding_noise_bit: usize =
+            (npe::lwe::log2_rounding_noise(self.dimension)).ceil() as usize + 1;
+
+        let mut new_variances: Vec<f64> = Vec::with_capacity(functions.len());
+        let mut new_encoders: Vec<crate::Encoder> = Vec::with_capacity(functions.len());
+        for table in tables.chunks(bsk.polynomial_size) {
+            // compute the new variance (without the drift)
+            let new_var = crate::LWEBSK::get_multi_value_variance(bootstrap_var, table);
+
+            // create the output encoder
+            let mut new_encoder_output: crate::Encoder = encoder_output.clone();
+
+            // update the precision in case of the output noise (without drift) is too big and overlap the message
+            let nb_bit_overlap: usize =
+                new_encoder_output.update_precision_from_variance(new_var)?;
+            if nb_bit_overlap > 0 {
+                println!(
+                    "{}: {} bit(s) of precision lost over {} bit(s) of message originally. Consider increasing the number of level and/or decreasing the log base.",
+                    "Loss of precision during bootstrap".red().bold(),
+                    nb_bit_overlap, self.encoders[n].nb_bit_precision
+                );
+            }
+
+            // deals with the drift error
+            if nb_rounding_noise_bit
+                + self.encoders[n].nb_bit_padding
+                + new_encoder_output.nb_bit_precision
+                > bsk.get_polynomial_size_log() + 1
+            {
+                let nb_bit_loss = self.encoders[n].nb_bit_padding
+                    + new_encoder_output.nb_bit_precision
+                    + nb_rounding_noise_bit
+                    - bsk.get_polynomial_size_log()
+                    - 1;
+
+                new_encoder_output.nb_bit_precision = i32::max(
+                    new_encoder_output.nb_bit_precision as i32 - nb_bit_loss as i32,
+                    0i32,
+                ) as usize;
+                // drift
+                println!(
+                    "{}: {} bit(s) of precision lost over {} bit(s) of message originally ({} bits are affected by the noise). Consider increasing the polynomial size of the RLWE secret key.",
+                    "Loss of precision during bootstrap due to the rounding".red().bold(),
+                    nb_bit_loss, self.encoders[n].nb_bit_precision,nb_rounding_noise_bit
+                );
+            }
+
+            new_variances.push(new_var);
+            new_encoders.push(new_encoder_output);
+        }
+
+        // construct the output
+        let lwe = crate::VectorLWE {
+            variances: new_variances,
+            ciphertexts: result,
+            dimension: bsk.polynomial_size * bsk.dimension,
+            nb_ciphertexts: functions.len(),
+            encoders: new_encoders,
+        };
+
+        Ok(lwe)
+    }
+
     /// Multiply two LWE ciphertexts thanks to two bootstrapping procedures
     /// need to have 2 bits of padding at least
     ///
@@ -2268,6 +2453,204 @@ impl VectorLWE {
         Ok(square1)
     }
 
+    /// Look up a table at an index encrypted bitwise, thanks to a circuit bootstrap of each
+    /// ciphertext followed by a vertical packing
+    /// The i-th ciphertext encrypts the i-th bit of the index, from the most significant to the least significant one,
+    /// and must use an encoder with the interval [0, 1] and 1 bit of precision
+    ///
+    /// # Argument
+    /// * `bsk` - the bootstrapping key used in the circuit bootstrap
+    /// * `cbsk` - the circuit bootstrapping key, built from the output RLWE secret key of `bsk`
+    /// * `base_log` - the log2 of the decomposition base of the GGSW ciphertexts output by the circuit bootstrap
+    /// * `level` - the number of levels of the decomposition of the GGSW ciphertexts (`base_log * level` must be smaller than 64)
+    /// * `table` - the table to look up, with 2^n entries, n being the number of ciphertexts in self
+    /// * `encoder_output` - the encoder of the output
+    ///
+    /// # Output
+    /// * a VectorLWE struct containing one ciphertext, encrypted under the LWE secret key obtained from the output RLWE secret key of `bsk`
+    /// * ZeroCiphertextsInStructureError - if there is no ciphertext in self
+    /// * DimensionError - if the keys and the input ciphertexts have incompatible dimensions
+    /// * PolynomialSizeError - if the keys have different polynomial sizes
+    /// * WrongSizeError - if the table does not have 2^n entries
+    /// * InvalidEncoderError - if one of the input ciphertexts does not encrypt a bit
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // encoders
+    /// let encoder_input = Encoder::new(0., 1., 1, 1).unwrap();
+    /// let encoder_output = Encoder::new(0., 7., 3, 1).unwrap();
+    ///
+    /// // generate secret keys with a small noise
+    /// let rlwe_secret_key = RLWESecretKey::new_raw(512, 1, f64::powi(2., -50));
+    /// let secret_key_before = LWESecretKey::new_raw(256, f64::powi(2., -30));
+    /// let secret_key_after = rlwe_secret_key.to_lwe_secret_key();
+    ///
+    /// // bootstrapping keys
+    /// let bootstrapping_key = LWEBSK::new(&secret_key_before, &rlwe_secret_key, 8, 4);
+    /// let circuit_bootstrapping_key = LWECBSK::new(&rlwe_secret_key, 10, 3);
+    ///
+    /// // the bits of the index 5, from the most significant one
+    /// let ciphertext_before =
+    ///     VectorLWE::encode_encrypt(&secret_key_before, &[1., 0., 1.], &encoder_input).unwrap();
+    ///
+    /// let table: Vec<f64> = vec![7., 6., 5., 4., 3., 2., 1., 0.];
+    /// let ciphertext_out = ciphertext_before
+    ///     .vertical_packing(
+    ///         &bootstrapping_key,
+    ///         &circuit_bootstrapping_key,
+    ///         4,
+    ///         6,
+    ///         &table,
+    ///         &encoder_output,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let decryption = ciphertext_out.decrypt_decode(&secret_key_after).unwrap();
+    /// ```
+    pub fn vertical_packing(
+        &self,
+        bsk: &crate::LWEBSK,
+        cbsk: &crate::LWECBSK,
+        base_log: usize,
+        level: usize,
+        table: &[f64],
+        encoder_output: &crate::Encoder,
+    ) -> Result<crate::VectorLWE, CryptoAPIError> {
+        // check that there is at least one bit
+        if self.nb_ciphertexts == 0 {
+            return Err(ZeroCiphertextsInStructureError!(self.nb_ciphertexts));
+        }
+        // check bsk and cbsk compatibility
+        if self.dimension != bsk.get_lwe_dimension() {
+            return Err(DimensionError!(self.dimension, bsk.get_lwe_dimension()));
+        }
+        if bsk.dimension != cbsk.dimension {
+            return Err(DimensionError!(bsk.dimension, cbsk.dimension));
+        }
+        if bsk.polynomial_size != cbsk.polynomial_size {
+            return Err(PolynomialSizeError!(
+                bsk.polynomial_size,
+                cbsk.polynomial_size
+            ));
+        }
+        // check the size of the table
+        if Some(table.len()) != 1usize.checked_shl(self.nb_ciphertexts as u32) {
+            return Err(WrongSizeError!(table.len()));
+        }
+        // check that every ciphertext encrypts a bit
+        for encoder in self.encoders.iter() {
+            if encoder.nb_bit_precision != 1 || encoder.o != 0. || encoder.delta != 2. {
+                return Err(InvalidEncoderError!(
+                    encoder.nb_bit_precision,
+                    encoder.delta
+                ));
+            }
+        }
+
+        // encode the table
+        let lut = PlaintextList::from_container(
+            table
+                .iter()
+                .map(|m| encoder_output.encode_core(*m))
+                .collect::<Result<Vec<Torus>, CryptoAPIError>>()?,
+        );
+
+        // circuit bootstrap every bit
+        let poly_size = PolynomialSize(bsk.polynomial_size);
+        let glwe_size = GlweSize(bsk.dimension + 1);
+        let mut ggsws = Vec::with_capacity(self.nb_ciphertexts);
+        for (ct, encoder) in self.ciphertexts.ciphertext_iter().zip(self.encoders.iter()) {
+            // remove the padding so that the bit is encoded as m * q / 2
+            let mut ct_clone = LweCiphertext::from_container(ct.as_tensor().as_slice().to_vec());
+            ct_clone
+                .as_mut_tensor()
+                .update_with_scalar_shl(&encoder.nb_bit_padding);
+
+            let mut ggsw = GgswCiphertext::allocate(
+                0,
+                poly_size,
+                glwe_size,
+                DecompositionLevelCount(level),
+                DecompositionBaseLog(base_log),
+            );
+            bsk.ciphertexts
+                .circuit_bootstrap(&mut ggsw, &ct_clone, &cbsk.ciphertexts);
+
+            let mut fourier_ggsw = FourierGgswCiphertext::allocate(
+                Complex64::new(0., 0.),
+                poly_size,
+                glwe_size,
+                DecompositionLevelCount(level),
+                DecompositionBaseLog(base_log),
+            );
+            fourier_ggsw.fill_with_forward_fourier(&ggsw);
+            ggsws.push(fourier_ggsw);
+        }
+
+        // look up the table
+        let mut result =
+            LweCiphertext::allocate(0, LweSize(bsk.dimension * bsk.polynomial_size + 1));
+        result.fill_with_vertical_packing(&ggsws.iter().collect::<Vec<_>>(), &lut);
+
+        // compute the new variance: a bootstrap and a keyswitch for the GGSW ciphertexts, then
+        // one level of cmux per bit
+        let var_bootstrap = <Torus as npe::Cross>::bootstrap(
+            self.dimension,
+            bsk.dimension,
+            bsk.level,
+            bsk.base_log,
+            bsk.polynomial_size,
+            bsk.variance,
+        );
+        let var_ggsw = <Torus as LWE>::key_switch(
+            cbsk.get_lwe_dimension(),
+            cbsk.level,
+            cbsk.base_log,
+            cbsk.variance,
+            var_bootstrap,
+        );
+        let new_var = (0..self.nb_ciphertexts).fold(0., |var, _| {
+            <Torus as npe::Cross>::cmux(
+                var,
+                var,
+                var_ggsw,
+                bsk.dimension,
+                bsk.polynomial_size,
+                base_log,
+                level,
+            )
+        });
+
+        // create the output encoder
+        let mut new_encoder_output: crate::Encoder = encoder_output.clone();
+
+        // update the precision in case of the output noise is too big and overlap the message
+        let nb_bit_overlap: usize = new_encoder_output.update_precision_from_variance(new_var)?;
+        if nb_bit_overlap > 0 {
+            println!(
+                "{}: {} bit(s) of precision lost over {} bit(s) of message originally. Consider increasing the number of level and/or decreasing the log base.",
+                "Loss of precision during vertical packing".red().bold(),
+                nb_bit_overlap, encoder_output.nb_bit_precision
+            );
+        }
+
+        // construct the output
+        let lwe = crate::VectorLWE {
+            variances: vec![new_var; 1],
+            ciphertexts: LweList::from_container(
+                result.into_tensor().into_container(),
+                LweSize(bsk.polynomial_size * bsk.dimension + 1),
+            ),
+            dimension: bsk.polynomial_size * bsk.dimension,
+            nb_ciphertexts: 1,
+            encoders: vec![new_encoder_output; 1],
+        };
+
+        Ok(lwe)
+    }
+
     /// Return the size of one LWE ciphertext with the parameters of self
     ///
     /// # Output
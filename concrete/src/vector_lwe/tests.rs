@@ -604,6 +604,53 @@ fn test_encode_encrypt_x_bootstrap_nth_x_decrypt() {
     }
 }
 
+#[test]
+fn test_encode_encrypt_x_bootstrap_nth_with_functions_x_decrypt() {
+    // settings
+    let (min, max) = (-8., 7.);
+    let nb_messages: usize = random_index!(30) + 10;
+
+    // encoders
+    let encoder_input = crate::Encoder::new_rounding_context(min, max, 4, 1).unwrap();
+    let encoder_output = crate::Encoder::new(0., 7., 3, 1).unwrap();
+
+    // secret keys
+    let rlwe_secret_key = crate::RLWESecretKey::new(&crate::RLWE128_1024_1);
+    let secret_key_input = crate::LWESecretKey::new(&crate::LWE128_630);
+    let secret_key_output = rlwe_secret_key.to_lwe_secret_key();
+
+    // bootstrapping key
+    let bootstrapping_key = crate::LWEBSK::new(&secret_key_input, &rlwe_secret_key, 4, 8);
+
+    // an activation and a sign
+    let functions: [fn(f64) -> f64; 2] = [|x| f64::max(0., x), |x| (x >= 0.) as u8 as f64];
+
+    // messages
+    let messages: Vec<f64> = (0..nb_messages)
+        .map(|_| random_index!(16) as f64 + min)
+        .collect();
+
+    // encode and encrypt
+    let ciphertext_input =
+        crate::VectorLWE::encode_encrypt(&secret_key_input, &messages, &encoder_input).unwrap();
+
+    for (index, message) in messages.iter().enumerate() {
+        // bootstrap
+        let ciphertext_output = ciphertext_input
+            .bootstrap_nth_with_functions(&bootstrapping_key, &functions, &encoder_output, index)
+            .unwrap();
+        assert_eq!(ciphertext_output.nb_ciphertexts, functions.len());
+
+        // decrypt
+        let decryption = ciphertext_output
+            .decrypt_decode_round(&secret_key_output)
+            .unwrap();
+        for (f, d) in functions.iter().zip(decryption.iter()) {
+            assert_eq!(f(*message), *d);
+        }
+    }
+}
+
 #[test]
 fn test_encode_encrypt_x_mul_from_bootstrap_nth_nth_x_decrypt() {
     let nb_messages: usize = 1;
@@ -657,6 +704,55 @@ fn test_encode_encrypt_x_mul_from_bootstrap_nth_nth_x_decrypt() {
     );
 }
 
+#[test]
+fn test_encode_encrypt_x_vertical_packing_x_decrypt() {
+    // random settings
+    let nb_bits: usize = random_index!(3) + 1;
+    let index: usize = random_index!(1 << nb_bits);
+
+    // encoders
+    let encoder_input = crate::Encoder::new(0., 1., 1, 1).unwrap();
+    let encoder_output = crate::Encoder::new(0., 7., 3, 1).unwrap();
+
+    // secret keys with a small noise so that the circuit bootstrap is exact
+    let rlwe_secret_key = crate::RLWESecretKey::new_raw(512, 1, f64::powi(2., -50));
+    let secret_key_input = crate::LWESecretKey::new_raw(256, f64::powi(2., -30));
+    let secret_key_output = rlwe_secret_key.to_lwe_secret_key();
+
+    // bootstrapping keys
+    let bsk = crate::LWEBSK::new(&secret_key_input, &rlwe_secret_key, 8, 4);
+    let cbsk = crate::LWECBSK::new(&rlwe_secret_key, 10, 3);
+
+    // the bits of the index, from the most significant one
+    let bits: Vec<f64> = (0..nb_bits)
+        .rev()
+        .map(|i| ((index >> i) & 1) as f64)
+        .collect();
+
+    // a random table
+    let table: Vec<f64> = (0..(1 << nb_bits))
+        .map(|_| random_index!(8) as f64)
+        .collect();
+
+    // encode and encrypt
+    let ciphertext_input =
+        crate::VectorLWE::encode_encrypt(&secret_key_input, &bits, &encoder_input).unwrap();
+
+    // look up the table
+    let ciphertext_output = ciphertext_input
+        .vertical_packing(&bsk, &cbsk, 4, 6, &table, &encoder_output)
+        .unwrap();
+
+    // decrypt
+    let decryption = ciphertext_output
+        .decrypt_decode_round(&secret_key_output)
+        .unwrap();
+
+    // test
+    assert_eq!(table[index], decryption[0]);
+    assert_eq!(ciphertext_output.dimension, 512);
+}
+
 #[test]
 fn test_encode_encrypt_x_add_with_new_min_inplace_x_decrypt() {
     // random number of messages
@@ -7,17 +7,25 @@ use backtrace::Backtrace;
 use colored::Colorize;
 use itertools::izip;
 use serde::{Deserialize, Serialize};
-use concrete_core::{
-    crypto::{encoding::PlaintextList, glwe::GlweList},
+use concrete_core::backends::core::private::{
+    crypto::{
+        encoding::PlaintextList,
+        glwe::{tensor_product_polynomial_count, GlweList},
+    },
+    math::polynomial::PolynomialList,
     math::tensor::{AsMutSlice, AsMutTensor, AsRefSlice, AsRefTensor},
 };
 use concrete_npe as npe;
 use crate::error::CryptoAPIError;
 use crate::{read_from_file, write_to_file, Torus};
-use concrete_commons::dispersion::StandardDev;
+use concrete_commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use concrete_commons::key_kinds::BinaryKeyKind;
 use concrete_commons::numeric::Numeric;
-use concrete_commons::parameters::{CiphertextCount, GlweDimension, PolynomialSize};
-use concrete_core::crypto::secret::generators::EncryptionRandomGenerator;
+use concrete_commons::parameters::{
+    CiphertextCount, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, GlweSize,
+    PolynomialSize,
+};
+use concrete_core::backends::core::private::crypto::secret::generators::EncryptionRandomGenerator;
 
 #[cfg(test)]
 mod tests;
@@ -1484,6 +1492,190 @@ impl VectorRLWE {
         Ok(())
     }
 
+    /// Multiply the RLWE ciphertexts of two VectorRLWE together with a tensor product followed by a relinearization
+    /// The i-th output ciphertext encrypts the product modulo X^N + 1 of the polynomials encrypted in the i-th ciphertexts of self and ct
+    /// All the valid encoders of both inputs must have an offset of zero, the same number of bits of padding and the same interval size,
+    /// which has to be a power of two, and the coefficients with an invalid encoder are considered as zeros
+    /// The output coefficients are computed modulo the interval size times 2^nb_bit_padding, so no bit of padding is left in the output encoders
+    ///
+    /// # Argument
+    /// * `ct` - an VectorRLWE struct
+    /// * `rlk` - the relinearization key
+    ///
+    /// # Output
+    /// * a new VectorRLWE
+    /// * DimensionError - if the ciphertexts or the relinearization key have incompatible dimensions
+    /// * PolynomialSizeError - if the ciphertexts or the relinearization key have incompatible polynomial size
+    /// * NbCTError - if the ciphertexts do not contain the same number of RLWE ciphertexts
+    /// * InvalidEncoderError - if there is no valid encoder, or if one of them has an offset or an interval size which is not a power of two
+    /// * PaddingError - if the ciphertexts ave incompatible paddings
+    /// * DeltaError - if the ciphertexts have incompatile deltas
+    ///
+    /// # Example
+    /// ```rust
+    /// use concrete::*;
+    ///
+    /// // generate a secret key and a relinearization key
+    /// let sk = RLWESecretKey::new(&RLWE128_1024_1);
+    /// let rlk = RLWERLK::new(&sk, 10, 4);
+    ///
+    /// // an encoder for the integers between 0 and 3
+    /// let encoder = Encoder::new(0., 3., 2, 4).unwrap();
+    ///
+    /// // encode and encrypt the polynomials 1 + 2X and 3 + X
+    /// let ct1 = VectorRLWE::encode_encrypt_packed(&sk, &[1., 2.], &encoder).unwrap();
+    /// let ct2 = VectorRLWE::encode_encrypt_packed(&sk, &[3., 1.], &encoder).unwrap();
+    ///
+    /// // the product is 3 + 7X + 2X^2
+    /// let product = ct1.mul_with_relinearization(&ct2, &rlk).unwrap();
+    /// let decryptions = product.decrypt_decode_round(&sk).unwrap();
+    /// assert_eq!(&decryptions[..3], &[3., 7., 2.]);
+    /// ```
+    pub fn mul_with_relinearization(
+        &self,
+        ct: &crate::VectorRLWE,
+        rlk: &crate::RLWERLK,
+    ) -> Result<crate::VectorRLWE, CryptoAPIError> {
+        // check the dimensions
+        if self.dimension != ct.dimension {
+            return Err(DimensionError!(self.dimension, ct.dimension));
+        } else if self.dimension != rlk.dimension {
+            return Err(DimensionError!(self.dimension, rlk.dimension));
+        }
+        // check the polynomial sizes
+        if self.polynomial_size != ct.polynomial_size {
+            return Err(PolynomialSizeError!(
+                self.polynomial_size,
+                ct.polynomial_size
+            ));
+        } else if self.polynomial_size != rlk.polynomial_size {
+            return Err(PolynomialSizeError!(
+                self.polynomial_size,
+                rlk.polynomial_size
+            ));
+        }
+        // check the number of ciphertexts
+        if self.nb_ciphertexts != ct.nb_ciphertexts {
+            return Err(NbCTError!(self.nb_ciphertexts, ct.nb_ciphertexts));
+        }
+
+        // find the common encoder
+        let encoder = match self
+            .encoders
+            .iter()
+            .chain(ct.encoders.iter())
+            .find(|enc| enc.is_valid())
+        {
+            Some(enc) => enc.clone(),
+            None => {
+                return Err(InvalidEncoderError!(
+                    self.encoders[0].nb_bit_precision,
+                    self.encoders[0].delta
+                ))
+            }
+        };
+        let log_delta = encoder.delta.log2().round() as usize;
+        if f64::powi(2., log_delta as i32) != encoder.delta
+            || log_delta + encoder.nb_bit_padding >= <Torus as Numeric>::BITS
+        {
+            return Err(InvalidEncoderError!(
+                encoder.nb_bit_precision,
+                encoder.delta
+            ));
+        }
+
+        // check the Encoder lists
+        for enc in self
+            .encoders
+            .iter()
+            .chain(ct.encoders.iter())
+            .filter(|enc| enc.is_valid())
+        {
+            // check the offsets
+            if enc.o != 0. {
+                return Err(InvalidEncoderError!(enc.nb_bit_precision, enc.delta));
+            }
+            // check nb bit padding
+            else if enc.nb_bit_padding != encoder.nb_bit_padding {
+                return Err(PaddingError!(enc.nb_bit_padding, encoder.nb_bit_padding));
+            }
+            // check the deltas
+            else if !deltas_eq!(enc.delta, encoder.delta) {
+                return Err(DeltaError!(enc.delta, encoder.delta));
+            }
+        }
+
+        // the scaling factor of the encoding
+        let scale: Torus = 1 << (<Torus as Numeric>::BITS - encoder.nb_bit_padding - log_delta);
+
+        // compute the tensor products and relinearize them
+        let mut res = VectorRLWE::zero(self.polynomial_size, self.dimension, self.nb_ciphertexts)?;
+        let mut tensor_product = PolynomialList::allocate(
+            0,
+            tensor_product_polynomial_count(GlweSize(self.dimension + 1)),
+            PolynomialSize(self.polynomial_size),
+        );
+        for (mut res_ct, self_ct, ct_ct) in izip!(
+            res.ciphertexts.ciphertext_iter_mut(),
+            self.ciphertexts.ciphertext_iter(),
+            ct.ciphertexts.ciphertext_iter()
+        ) {
+            self_ct.tensor_product(&ct_ct, &mut tensor_product, scale);
+            rlk.ciphertexts.relinearize(&mut res_ct, &tensor_product);
+        }
+
+        // the output encoder uses the bits of padding
+        let mut encoder_output = encoder.clone();
+        encoder_output.delta *= f64::powi(2., encoder.nb_bit_padding as i32);
+        encoder_output.nb_bit_precision += encoder.nb_bit_padding;
+        encoder_output.nb_bit_padding = 0;
+
+        // update the variances and the encoders
+        for (res_vars, res_encs, self_vars, ct_vars) in izip!(
+            res.variances.chunks_mut(self.polynomial_size),
+            res.encoders.chunks_mut(self.polynomial_size),
+            self.variances.chunks(self.polynomial_size),
+            ct.variances.chunks(self.polynomial_size)
+        ) {
+            // call to the NPE to estimate the new variance, the estimators return modular
+            // variances which are summed here rather than with `estimate_multiplication_noise`,
+            // which converts them once more
+            let tensor_product_var =
+                npe_operators::estimate_tensor_product_noise::<Torus, _, _, BinaryKeyKind>(
+                    PolynomialSize(self.polynomial_size),
+                    GlweDimension(self.dimension),
+                    Variance(self_vars.iter().copied().fold(0., f64::max)),
+                    Variance(ct_vars.iter().copied().fold(0., f64::max)),
+                    scale as f64,
+                    scale as f64,
+                    encoder.delta,
+                    encoder.delta,
+                );
+            let relinearization_var =
+                npe_operators::estimate_relinearization_noise::<Torus, _, BinaryKeyKind>(
+                    PolynomialSize(self.polynomial_size),
+                    GlweDimension(self.dimension),
+                    Variance(rlk.variance),
+                    DecompositionBaseLog(rlk.base_log),
+                    DecompositionLevelCount(rlk.level),
+                );
+            let new_var = Variance::from_modular_variance::<Torus>(
+                tensor_product_var.get_variance() + relinearization_var.get_variance(),
+            )
+            .get_variance();
+
+            for (var, enc) in res_vars.iter_mut().zip(res_encs.iter_mut()) {
+                *var = new_var;
+                enc.copy(&encoder_output);
+
+                // update the encoder precision based on the variance
+                enc.update_precision_from_variance(new_var)?;
+            }
+        }
+
+        Ok(res)
+    }
+
     /// Return the number of valid encoders (i.e. how many messages are carried in those RLWE ciphertexts)
     pub fn nb_valid(&self) -> usize {
         let mut res: usize = 0;
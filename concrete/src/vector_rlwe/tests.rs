@@ -470,6 +470,55 @@ fn test_encode_encrypt_packed_x_sub_with_padding_inplace_x_decrypt() {
     assert_eq!(cpt, nb_messages);
 }
 
+#[test]
+fn test_encode_encrypt_packed_x_mul_with_relinearization_x_decrypt() {
+    // generate a secret key and a relinearization key
+    let sk = crate::RLWESecretKey::new(&crate::RLWE128_1024_1);
+    let rlk = crate::RLWERLK::new(&sk, 10, 4);
+
+    // an encoder for small integers
+    let precision: usize = 2;
+    let padding: usize = 4;
+    let max = f64::powi(2., precision as i32) - 1.;
+    let encoder = crate::Encoder::new(0., max, precision, padding).unwrap();
+
+    // two short polynomials with random integer coefficients
+    let nb_messages: usize = random_index!(3) + 1;
+    let messages1: Vec<f64> = (0..nb_messages)
+        .map(|_| random_index!(max as usize + 1) as f64)
+        .collect();
+    let messages2: Vec<f64> = (0..nb_messages)
+        .map(|_| random_index!(max as usize + 1) as f64)
+        .collect();
+
+    // encode and encrypt
+    let ciphertext1 = crate::VectorRLWE::encode_encrypt_packed(&sk, &messages1, &encoder).unwrap();
+    let ciphertext2 = crate::VectorRLWE::encode_encrypt_packed(&sk, &messages2, &encoder).unwrap();
+
+    // multiplication
+    let product = ciphertext1
+        .mul_with_relinearization(&ciphertext2, &rlk)
+        .unwrap();
+
+    // decryption
+    let decryptions: Vec<f64> = product.decrypt_decode_round(&sk).unwrap();
+    assert_eq!(decryptions.len(), sk.polynomial_size);
+
+    // the product of the polynomials, which does not wrap around X^N + 1
+    let mut expected: Vec<f64> = vec![0.; sk.polynomial_size];
+    for (i, m1) in messages1.iter().enumerate() {
+        for (j, m2) in messages2.iter().enumerate() {
+            expected[i + j] += m1 * m2;
+        }
+    }
+
+    for (e, d, enc) in izip!(expected.iter(), decryptions.iter(), product.encoders.iter()) {
+        assert_eq!(e, d);
+        assert_eq!(enc.nb_bit_padding, 0);
+        assert_eq!(enc.nb_bit_precision, precision + padding);
+    }
+}
+
 #[test]
 fn test_encode_encrypt_on_cst_x_mul_constant_static_encoder_inplace_x_decrypt_with_encoders() {
     // generate a secret key